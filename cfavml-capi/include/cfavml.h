@@ -0,0 +1,239 @@
+/*
+ * The C ABI for CFAVML.
+ *
+ * Every routine assumes:
+ *
+ * - Each non-null input pointer points to `len` initialized values.
+ * - Each non-null output pointer is valid for writing `len` values, or a single value
+ *   for the routines producing a single result.
+ * - An output pointer is either exactly equal to one of the input pointers or does not
+ *   overlap the inputs at all.
+ *
+ * Null pointers and zero lengths are reported via the returned `CfavmlStatus`.
+ */
+
+#ifndef CFAVML_H
+#define CFAVML_H
+
+/* This file is generated by cbindgen, do not edit it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The status code returned by every routine.
+ */
+typedef enum CfavmlStatus {
+  /**
+   * The operation completed successfully and the output has been written.
+   */
+  CFAVML_STATUS_OK = 0,
+  /**
+   * One of the provided pointers was null.
+   */
+  CFAVML_STATUS_NULL_POINTER = 1,
+  /**
+   * The provided length was zero.
+   */
+  CFAVML_STATUS_ZERO_LENGTH = 2,
+  /**
+   * The routine panicked, the output may be partially written.
+   */
+  CFAVML_STATUS_PANIC = 3,
+} CfavmlStatus;
+
+/**
+ * Calculates the dot product of `f32` vectors `a` and `b`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_f32_dot(size_t len, const float *a, const float *b, float *out);
+
+/**
+ * Calculates the cosine distance of `f32` vectors `a` and `b`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_f32_cosine(size_t len, const float *a, const float *b, float *out);
+
+/**
+ * Calculates the squared Euclidean distance of `f32` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_f32_squared_euclidean(size_t len,
+                                               const float *a,
+                                               const float *b,
+                                               float *out);
+
+/**
+ * Performs an element wise addition of `f32` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_f32_add_vector(size_t len, const float *a, const float *b, float *out);
+
+/**
+ * Performs an element wise subtraction of `f32` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_f32_sub_vector(size_t len, const float *a, const float *b, float *out);
+
+/**
+ * Performs an element wise multiplication of `f32` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_f32_mul_vector(size_t len, const float *a, const float *b, float *out);
+
+/**
+ * Calculates the sum of `f32` vector `a`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_f32_sum(size_t len, const float *a, float *out);
+
+/**
+ * Calculates the minimum value of `f32` vector `a`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_f32_min(size_t len, const float *a, float *out);
+
+/**
+ * Calculates the maximum value of `f32` vector `a`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_f32_max(size_t len, const float *a, float *out);
+
+/**
+ * Calculates the dot product of `f64` vectors `a` and `b`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_f64_dot(size_t len, const double *a, const double *b, double *out);
+
+/**
+ * Calculates the cosine distance of `f64` vectors `a` and `b`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_f64_cosine(size_t len, const double *a, const double *b, double *out);
+
+/**
+ * Calculates the squared Euclidean distance of `f64` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_f64_squared_euclidean(size_t len,
+                                               const double *a,
+                                               const double *b,
+                                               double *out);
+
+/**
+ * Performs an element wise addition of `f64` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_f64_add_vector(size_t len, const double *a, const double *b, double *out);
+
+/**
+ * Performs an element wise subtraction of `f64` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_f64_sub_vector(size_t len, const double *a, const double *b, double *out);
+
+/**
+ * Performs an element wise multiplication of `f64` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_f64_mul_vector(size_t len, const double *a, const double *b, double *out);
+
+/**
+ * Calculates the sum of `f64` vector `a`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_f64_sum(size_t len, const double *a, double *out);
+
+/**
+ * Calculates the minimum value of `f64` vector `a`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_f64_min(size_t len, const double *a, double *out);
+
+/**
+ * Calculates the maximum value of `f64` vector `a`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_f64_max(size_t len, const double *a, double *out);
+
+/**
+ * Calculates the dot product of `i8` vectors `a` and `b`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_i8_dot(size_t len, const int8_t *a, const int8_t *b, int8_t *out);
+
+/**
+ * Calculates the squared Euclidean distance of `i8` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_i8_squared_euclidean(size_t len,
+                                              const int8_t *a,
+                                              const int8_t *b,
+                                              int8_t *out);
+
+/**
+ * Performs an element wise addition of `i8` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_i8_add_vector(size_t len, const int8_t *a, const int8_t *b, int8_t *out);
+
+/**
+ * Performs an element wise subtraction of `i8` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_i8_sub_vector(size_t len, const int8_t *a, const int8_t *b, int8_t *out);
+
+/**
+ * Performs an element wise multiplication of `i8` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_i8_mul_vector(size_t len, const int8_t *a, const int8_t *b, int8_t *out);
+
+/**
+ * Calculates the sum of `i8` vector `a`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_i8_sum(size_t len, const int8_t *a, int8_t *out);
+
+/**
+ * Calculates the minimum value of `i8` vector `a`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_i8_min(size_t len, const int8_t *a, int8_t *out);
+
+/**
+ * Calculates the maximum value of `i8` vector `a`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_i8_max(size_t len, const int8_t *a, int8_t *out);
+
+/**
+ * Calculates the dot product of `u8` vectors `a` and `b`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_u8_dot(size_t len, const uint8_t *a, const uint8_t *b, uint8_t *out);
+
+/**
+ * Calculates the squared Euclidean distance of `u8` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_u8_squared_euclidean(size_t len,
+                                              const uint8_t *a,
+                                              const uint8_t *b,
+                                              uint8_t *out);
+
+/**
+ * Performs an element wise addition of `u8` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_u8_add_vector(size_t len,
+                                       const uint8_t *a,
+                                       const uint8_t *b,
+                                       uint8_t *out);
+
+/**
+ * Performs an element wise subtraction of `u8` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_u8_sub_vector(size_t len,
+                                       const uint8_t *a,
+                                       const uint8_t *b,
+                                       uint8_t *out);
+
+/**
+ * Performs an element wise multiplication of `u8` vectors `a` and `b` into `out`.
+ */
+enum CfavmlStatus cfavml_u8_mul_vector(size_t len,
+                                       const uint8_t *a,
+                                       const uint8_t *b,
+                                       uint8_t *out);
+
+/**
+ * Calculates the sum of `u8` vector `a`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_u8_sum(size_t len, const uint8_t *a, uint8_t *out);
+
+/**
+ * Calculates the minimum value of `u8` vector `a`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_u8_min(size_t len, const uint8_t *a, uint8_t *out);
+
+/**
+ * Calculates the maximum value of `u8` vector `a`, writing the result to `out`.
+ */
+enum CfavmlStatus cfavml_u8_max(size_t len, const uint8_t *a, uint8_t *out);
+
+#endif  /* CFAVML_H */
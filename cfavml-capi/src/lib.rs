@@ -0,0 +1,657 @@
+//! A C ABI over the safe, runtime dispatched routines of CFAVML.
+//!
+//! Each routine takes the length of the vectors followed by the raw pointers to the inputs
+//! and outputs, returning a [CfavmlStatus] rather than panicking across the FFI boundary.
+//!
+//! The generated header for these functions can be found in `include/cfavml.h`.
+//!
+//! # Safety
+//!
+//! Every routine assumes:
+//!
+//! - Each non-null input pointer points to `len` initialized values.
+//! - Each non-null output pointer is valid for writing `len` values, or a single value
+//!   for the routines producing a single result.
+//! - An output pointer is either exactly equal to one of the input pointers or does not
+//!   overlap the inputs at all.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::slice;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// The status code returned by every routine.
+pub enum CfavmlStatus {
+    /// The operation completed successfully and the output has been written.
+    Ok = 0,
+    /// One of the provided pointers was null.
+    NullPointer = 1,
+    /// The provided length was zero.
+    ZeroLength = 2,
+    /// The routine panicked, the output may be partially written.
+    Panic = 3,
+}
+
+#[inline]
+/// Validates the inputs and runs `op` catching any panics.
+fn run_checked(len: usize, has_null: bool, op: impl FnOnce()) -> CfavmlStatus {
+    if has_null {
+        return CfavmlStatus::NullPointer;
+    } else if len == 0 {
+        return CfavmlStatus::ZeroLength;
+    }
+
+    // The output is only ever partially written if a panic occurs, which
+    // is reflected in the returned status.
+    match panic::catch_unwind(AssertUnwindSafe(op)) {
+        Ok(()) => CfavmlStatus::Ok,
+        Err(_) => CfavmlStatus::Panic,
+    }
+}
+
+#[inline]
+/// Runs a routine taking two vectors and producing a single value.
+unsafe fn distance_op<T: Copy>(
+    len: usize,
+    a: *const T,
+    b: *const T,
+    out: *mut T,
+    op: impl FnOnce(&[T], &[T]) -> T,
+) -> CfavmlStatus {
+    run_checked(len, a.is_null() || b.is_null() || out.is_null(), || {
+        let a = slice::from_raw_parts(a, len);
+        let b = slice::from_raw_parts(b, len);
+        out.write(op(a, b));
+    })
+}
+
+#[inline]
+/// Runs a routine taking two vectors and producing a vector.
+unsafe fn vertical_op<T: Copy>(
+    len: usize,
+    a: *const T,
+    b: *const T,
+    out: *mut T,
+    op: impl FnOnce(&[T], &[T], &mut [T]),
+) -> CfavmlStatus {
+    run_checked(len, a.is_null() || b.is_null() || out.is_null(), || {
+        // The output may alias the inputs, so the inputs are copied out
+        // before a mutable slice is created over the output.
+        if out.cast_const() == a || out.cast_const() == b {
+            let a = slice::from_raw_parts(a, len).to_vec();
+            let b = slice::from_raw_parts(b, len).to_vec();
+            op(&a, &b, slice::from_raw_parts_mut(out, len));
+        } else {
+            let a = slice::from_raw_parts(a, len);
+            let b = slice::from_raw_parts(b, len);
+            op(a, b, slice::from_raw_parts_mut(out, len));
+        }
+    })
+}
+
+#[inline]
+/// Runs a routine taking a single vector and producing a single value.
+unsafe fn agg_op<T: Copy>(
+    len: usize,
+    a: *const T,
+    out: *mut T,
+    op: impl FnOnce(&[T]) -> T,
+) -> CfavmlStatus {
+    run_checked(len, a.is_null() || out.is_null(), || {
+        let a = slice::from_raw_parts(a, len);
+        out.write(op(a));
+    })
+}
+
+// f32 routines
+
+#[no_mangle]
+/// Calculates the dot product of `f32` vectors `a` and `b`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f32_dot(
+    len: usize,
+    a: *const f32,
+    b: *const f32,
+    out: *mut f32,
+) -> CfavmlStatus {
+    distance_op(len, a, b, out, |a, b| cfavml::dot(a, b))
+}
+
+#[no_mangle]
+/// Calculates the cosine distance of `f32` vectors `a` and `b`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f32_cosine(
+    len: usize,
+    a: *const f32,
+    b: *const f32,
+    out: *mut f32,
+) -> CfavmlStatus {
+    distance_op(len, a, b, out, |a, b| cfavml::cosine(a, b))
+}
+
+#[no_mangle]
+/// Calculates the squared Euclidean distance of `f32` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f32_squared_euclidean(
+    len: usize,
+    a: *const f32,
+    b: *const f32,
+    out: *mut f32,
+) -> CfavmlStatus {
+    distance_op(len, a, b, out, |a, b| cfavml::squared_euclidean(a, b))
+}
+
+#[no_mangle]
+/// Performs an element wise addition of `f32` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f32_add_vector(
+    len: usize,
+    a: *const f32,
+    b: *const f32,
+    out: *mut f32,
+) -> CfavmlStatus {
+    vertical_op(len, a, b, out, |a, b, out| cfavml::add_vertical(a, b, out))
+}
+
+#[no_mangle]
+/// Performs an element wise subtraction of `f32` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f32_sub_vector(
+    len: usize,
+    a: *const f32,
+    b: *const f32,
+    out: *mut f32,
+) -> CfavmlStatus {
+    vertical_op(len, a, b, out, |a, b, out| cfavml::sub_vertical(a, b, out))
+}
+
+#[no_mangle]
+/// Performs an element wise multiplication of `f32` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f32_mul_vector(
+    len: usize,
+    a: *const f32,
+    b: *const f32,
+    out: *mut f32,
+) -> CfavmlStatus {
+    vertical_op(len, a, b, out, |a, b, out| cfavml::mul_vertical(a, b, out))
+}
+
+#[no_mangle]
+/// Calculates the sum of `f32` vector `a`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f32_sum(
+    len: usize,
+    a: *const f32,
+    out: *mut f32,
+) -> CfavmlStatus {
+    agg_op(len, a, out, |a| cfavml::sum(a))
+}
+
+#[no_mangle]
+/// Calculates the minimum value of `f32` vector `a`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f32_min(
+    len: usize,
+    a: *const f32,
+    out: *mut f32,
+) -> CfavmlStatus {
+    agg_op(len, a, out, |a| cfavml::min(a))
+}
+
+#[no_mangle]
+/// Calculates the maximum value of `f32` vector `a`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f32_max(
+    len: usize,
+    a: *const f32,
+    out: *mut f32,
+) -> CfavmlStatus {
+    agg_op(len, a, out, |a| cfavml::max(a))
+}
+
+// f64 routines
+
+#[no_mangle]
+/// Calculates the dot product of `f64` vectors `a` and `b`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f64_dot(
+    len: usize,
+    a: *const f64,
+    b: *const f64,
+    out: *mut f64,
+) -> CfavmlStatus {
+    distance_op(len, a, b, out, |a, b| cfavml::dot(a, b))
+}
+
+#[no_mangle]
+/// Calculates the cosine distance of `f64` vectors `a` and `b`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f64_cosine(
+    len: usize,
+    a: *const f64,
+    b: *const f64,
+    out: *mut f64,
+) -> CfavmlStatus {
+    distance_op(len, a, b, out, |a, b| cfavml::cosine(a, b))
+}
+
+#[no_mangle]
+/// Calculates the squared Euclidean distance of `f64` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f64_squared_euclidean(
+    len: usize,
+    a: *const f64,
+    b: *const f64,
+    out: *mut f64,
+) -> CfavmlStatus {
+    distance_op(len, a, b, out, |a, b| cfavml::squared_euclidean(a, b))
+}
+
+#[no_mangle]
+/// Performs an element wise addition of `f64` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f64_add_vector(
+    len: usize,
+    a: *const f64,
+    b: *const f64,
+    out: *mut f64,
+) -> CfavmlStatus {
+    vertical_op(len, a, b, out, |a, b, out| cfavml::add_vertical(a, b, out))
+}
+
+#[no_mangle]
+/// Performs an element wise subtraction of `f64` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f64_sub_vector(
+    len: usize,
+    a: *const f64,
+    b: *const f64,
+    out: *mut f64,
+) -> CfavmlStatus {
+    vertical_op(len, a, b, out, |a, b, out| cfavml::sub_vertical(a, b, out))
+}
+
+#[no_mangle]
+/// Performs an element wise multiplication of `f64` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f64_mul_vector(
+    len: usize,
+    a: *const f64,
+    b: *const f64,
+    out: *mut f64,
+) -> CfavmlStatus {
+    vertical_op(len, a, b, out, |a, b, out| cfavml::mul_vertical(a, b, out))
+}
+
+#[no_mangle]
+/// Calculates the sum of `f64` vector `a`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f64_sum(
+    len: usize,
+    a: *const f64,
+    out: *mut f64,
+) -> CfavmlStatus {
+    agg_op(len, a, out, |a| cfavml::sum(a))
+}
+
+#[no_mangle]
+/// Calculates the minimum value of `f64` vector `a`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f64_min(
+    len: usize,
+    a: *const f64,
+    out: *mut f64,
+) -> CfavmlStatus {
+    agg_op(len, a, out, |a| cfavml::min(a))
+}
+
+#[no_mangle]
+/// Calculates the maximum value of `f64` vector `a`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_f64_max(
+    len: usize,
+    a: *const f64,
+    out: *mut f64,
+) -> CfavmlStatus {
+    agg_op(len, a, out, |a| cfavml::max(a))
+}
+
+// i8 routines
+
+#[no_mangle]
+/// Calculates the dot product of `i8` vectors `a` and `b`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_i8_dot(
+    len: usize,
+    a: *const i8,
+    b: *const i8,
+    out: *mut i8,
+) -> CfavmlStatus {
+    distance_op(len, a, b, out, |a, b| cfavml::dot(a, b))
+}
+
+#[no_mangle]
+/// Calculates the squared Euclidean distance of `i8` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_i8_squared_euclidean(
+    len: usize,
+    a: *const i8,
+    b: *const i8,
+    out: *mut i8,
+) -> CfavmlStatus {
+    distance_op(len, a, b, out, |a, b| cfavml::squared_euclidean(a, b))
+}
+
+#[no_mangle]
+/// Performs an element wise addition of `i8` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_i8_add_vector(
+    len: usize,
+    a: *const i8,
+    b: *const i8,
+    out: *mut i8,
+) -> CfavmlStatus {
+    vertical_op(len, a, b, out, |a, b, out| cfavml::add_vertical(a, b, out))
+}
+
+#[no_mangle]
+/// Performs an element wise subtraction of `i8` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_i8_sub_vector(
+    len: usize,
+    a: *const i8,
+    b: *const i8,
+    out: *mut i8,
+) -> CfavmlStatus {
+    vertical_op(len, a, b, out, |a, b, out| cfavml::sub_vertical(a, b, out))
+}
+
+#[no_mangle]
+/// Performs an element wise multiplication of `i8` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_i8_mul_vector(
+    len: usize,
+    a: *const i8,
+    b: *const i8,
+    out: *mut i8,
+) -> CfavmlStatus {
+    vertical_op(len, a, b, out, |a, b, out| cfavml::mul_vertical(a, b, out))
+}
+
+#[no_mangle]
+/// Calculates the sum of `i8` vector `a`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_i8_sum(
+    len: usize,
+    a: *const i8,
+    out: *mut i8,
+) -> CfavmlStatus {
+    agg_op(len, a, out, |a| cfavml::sum(a))
+}
+
+#[no_mangle]
+/// Calculates the minimum value of `i8` vector `a`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_i8_min(
+    len: usize,
+    a: *const i8,
+    out: *mut i8,
+) -> CfavmlStatus {
+    agg_op(len, a, out, |a| cfavml::min(a))
+}
+
+#[no_mangle]
+/// Calculates the maximum value of `i8` vector `a`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_i8_max(
+    len: usize,
+    a: *const i8,
+    out: *mut i8,
+) -> CfavmlStatus {
+    agg_op(len, a, out, |a| cfavml::max(a))
+}
+
+// u8 routines
+
+#[no_mangle]
+/// Calculates the dot product of `u8` vectors `a` and `b`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_u8_dot(
+    len: usize,
+    a: *const u8,
+    b: *const u8,
+    out: *mut u8,
+) -> CfavmlStatus {
+    distance_op(len, a, b, out, |a, b| cfavml::dot(a, b))
+}
+
+#[no_mangle]
+/// Calculates the squared Euclidean distance of `u8` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_u8_squared_euclidean(
+    len: usize,
+    a: *const u8,
+    b: *const u8,
+    out: *mut u8,
+) -> CfavmlStatus {
+    distance_op(len, a, b, out, |a, b| cfavml::squared_euclidean(a, b))
+}
+
+#[no_mangle]
+/// Performs an element wise addition of `u8` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_u8_add_vector(
+    len: usize,
+    a: *const u8,
+    b: *const u8,
+    out: *mut u8,
+) -> CfavmlStatus {
+    vertical_op(len, a, b, out, |a, b, out| cfavml::add_vertical(a, b, out))
+}
+
+#[no_mangle]
+/// Performs an element wise subtraction of `u8` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_u8_sub_vector(
+    len: usize,
+    a: *const u8,
+    b: *const u8,
+    out: *mut u8,
+) -> CfavmlStatus {
+    vertical_op(len, a, b, out, |a, b, out| cfavml::sub_vertical(a, b, out))
+}
+
+#[no_mangle]
+/// Performs an element wise multiplication of `u8` vectors `a` and `b` into `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_u8_mul_vector(
+    len: usize,
+    a: *const u8,
+    b: *const u8,
+    out: *mut u8,
+) -> CfavmlStatus {
+    vertical_op(len, a, b, out, |a, b, out| cfavml::mul_vertical(a, b, out))
+}
+
+#[no_mangle]
+/// Calculates the sum of `u8` vector `a`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_u8_sum(
+    len: usize,
+    a: *const u8,
+    out: *mut u8,
+) -> CfavmlStatus {
+    agg_op(len, a, out, |a| cfavml::sum(a))
+}
+
+#[no_mangle]
+/// Calculates the minimum value of `u8` vector `a`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_u8_min(
+    len: usize,
+    a: *const u8,
+    out: *mut u8,
+) -> CfavmlStatus {
+    agg_op(len, a, out, |a| cfavml::min(a))
+}
+
+#[no_mangle]
+/// Calculates the maximum value of `u8` vector `a`, writing the result to `out`.
+///
+/// # Safety
+///
+/// See the [crate level](crate#safety) safety requirements.
+pub unsafe extern "C" fn cfavml_u8_max(
+    len: usize,
+    a: *const u8,
+    out: *mut u8,
+) -> CfavmlStatus {
+    agg_op(len, a, out, |a| cfavml::max(a))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::*;
+
+    #[test]
+    fn test_status_codes() {
+        let a = [1.0f32, 2.0, 3.0];
+        let mut out = 0.0f32;
+
+        let status =
+            unsafe { cfavml_f32_dot(a.len(), a.as_ptr(), ptr::null(), &mut out) };
+        assert_eq!(status, CfavmlStatus::NullPointer);
+
+        let status =
+            unsafe { cfavml_f32_dot(a.len(), a.as_ptr(), a.as_ptr(), ptr::null_mut()) };
+        assert_eq!(status, CfavmlStatus::NullPointer);
+
+        let status = unsafe { cfavml_f32_dot(0, a.as_ptr(), a.as_ptr(), &mut out) };
+        assert_eq!(status, CfavmlStatus::ZeroLength);
+
+        let status =
+            unsafe { cfavml_f32_dot(a.len(), a.as_ptr(), a.as_ptr(), &mut out) };
+        assert_eq!(status, CfavmlStatus::Ok);
+        assert_eq!(out, 14.0);
+    }
+
+    #[test]
+    fn test_aliased_vertical_output() {
+        let mut a = [1u8, 2, 3, 4, 5];
+        let b = [5u8, 4, 3, 2, 1];
+
+        let status = unsafe {
+            cfavml_u8_add_vector(a.len(), a.as_ptr(), b.as_ptr(), a.as_mut_ptr())
+        };
+        assert_eq!(status, CfavmlStatus::Ok);
+        assert_eq!(a, [6; 5]);
+    }
+
+    #[test]
+    fn test_panic_is_caught() {
+        let status = run_checked(1, false, || panic!("oops"));
+        assert_eq!(status, CfavmlStatus::Panic);
+    }
+}
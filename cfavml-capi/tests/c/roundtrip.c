@@ -0,0 +1,121 @@
+// Calls each of the exported routines and checks the results against a simple scalar
+// implementation, returning a non-zero exit code on failure.
+#include <math.h>
+#include <stdio.h>
+
+#include "cfavml.h"
+
+#define LEN 37
+
+static int failures = 0;
+
+#define CHECK(cond)                                                    \
+    do {                                                               \
+        if (!(cond)) {                                                 \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,     \
+                    __LINE__, #cond);                                  \
+            failures++;                                                \
+        }                                                              \
+    } while (0)
+
+static int is_close(double a, double b) {
+    return fabs(a - b) <= 1e-4 * fmax(1.0, fmax(fabs(a), fabs(b)));
+}
+
+static void test_status_codes(void) {
+    float a[LEN] = {0};
+    float out = 0.0f;
+
+    CHECK(cfavml_f32_dot(LEN, a, NULL, &out) == CFAVML_STATUS_NULL_POINTER);
+    CHECK(cfavml_f32_dot(LEN, a, a, NULL) == CFAVML_STATUS_NULL_POINTER);
+    CHECK(cfavml_f32_dot(0, a, a, &out) == CFAVML_STATUS_ZERO_LENGTH);
+    CHECK(cfavml_f32_sum(0, a, &out) == CFAVML_STATUS_ZERO_LENGTH);
+    CHECK(cfavml_f32_add_vector(LEN, a, a, NULL) == CFAVML_STATUS_NULL_POINTER);
+}
+
+// Checks the routines shared by every type, `a` and `b` are filled with small values
+// so that none of the results overflow an `int8_t`.
+#define TEST_COMMON_OPS(T, NAME)                                              \
+    static void test_##NAME##_ops(void) {                                     \
+        T a[LEN];                                                             \
+        T b[LEN];                                                             \
+        T vec_out[LEN];                                                       \
+        T out;                                                                \
+        double dot = 0, euclidean = 0, sum = 0;                               \
+        T min = (T)100, max = (T)0;                                           \
+        for (int i = 0; i < LEN; i++) {                                       \
+            a[i] = (T)(i % 3);                                                \
+            b[i] = (T)(i % 2 + 1);                                            \
+            dot += (double)a[i] * (double)b[i];                               \
+            euclidean += ((double)a[i] - b[i]) * ((double)a[i] - b[i]);       \
+            sum += a[i];                                                      \
+            min = a[i] < min ? a[i] : min;                                    \
+            max = a[i] > max ? a[i] : max;                                    \
+        }                                                                     \
+                                                                              \
+        CHECK(cfavml_##NAME##_dot(LEN, a, b, &out) == CFAVML_STATUS_OK);      \
+        CHECK(is_close(out, dot));                                            \
+        CHECK(cfavml_##NAME##_squared_euclidean(LEN, a, b, &out) ==           \
+              CFAVML_STATUS_OK);                                              \
+        CHECK(is_close(out, euclidean));                                      \
+        CHECK(cfavml_##NAME##_sum(LEN, a, &out) == CFAVML_STATUS_OK);         \
+        CHECK(is_close(out, sum));                                            \
+        CHECK(cfavml_##NAME##_min(LEN, a, &out) == CFAVML_STATUS_OK);         \
+        CHECK(out == min);                                                    \
+        CHECK(cfavml_##NAME##_max(LEN, a, &out) == CFAVML_STATUS_OK);         \
+        CHECK(out == max);                                                    \
+                                                                              \
+        CHECK(cfavml_##NAME##_add_vector(LEN, a, b, vec_out) ==               \
+              CFAVML_STATUS_OK);                                              \
+        for (int i = 0; i < LEN; i++) CHECK(vec_out[i] == (T)(a[i] + b[i]));  \
+        CHECK(cfavml_##NAME##_sub_vector(LEN, a, b, vec_out) ==               \
+              CFAVML_STATUS_OK);                                              \
+        for (int i = 0; i < LEN; i++) CHECK(vec_out[i] == (T)(a[i] - b[i]));  \
+        CHECK(cfavml_##NAME##_mul_vector(LEN, a, b, vec_out) ==               \
+              CFAVML_STATUS_OK);                                              \
+        for (int i = 0; i < LEN; i++) CHECK(vec_out[i] == (T)(a[i] * b[i]));  \
+                                                                              \
+        /* The output is allowed to be the same buffer as an input. */        \
+        CHECK(cfavml_##NAME##_add_vector(LEN, a, b, a) == CFAVML_STATUS_OK);  \
+        for (int i = 0; i < LEN; i++)                                         \
+            CHECK(a[i] == (T)(i % 3 + i % 2 + 1));                            \
+    }
+
+TEST_COMMON_OPS(float, f32)
+TEST_COMMON_OPS(double, f64)
+TEST_COMMON_OPS(int8_t, i8)
+TEST_COMMON_OPS(uint8_t, u8)
+
+static void test_cosine(void) {
+    float a32[LEN], b32[LEN], out32;
+    double a64[LEN], b64[LEN], out64;
+    double dot = 0, norm_a = 0, norm_b = 0;
+    for (int i = 0; i < LEN; i++) {
+        a32[i] = a64[i] = (i % 7) * 0.5 + 1.0;
+        b32[i] = b64[i] = (i % 4) * 0.25 + 1.0;
+        dot += a64[i] * b64[i];
+        norm_a += a64[i] * a64[i];
+        norm_b += b64[i] * b64[i];
+    }
+    double expected = 1.0 - dot / (sqrt(norm_a) * sqrt(norm_b));
+
+    CHECK(cfavml_f32_cosine(LEN, a32, b32, &out32) == CFAVML_STATUS_OK);
+    CHECK(is_close(out32, expected));
+    CHECK(cfavml_f64_cosine(LEN, a64, b64, &out64) == CFAVML_STATUS_OK);
+    CHECK(is_close(out64, expected));
+}
+
+int main(void) {
+    test_status_codes();
+    test_f32_ops();
+    test_f64_ops();
+    test_i8_ops();
+    test_u8_ops();
+    test_cosine();
+
+    if (failures != 0) {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+    return 0;
+}
@@ -0,0 +1,38 @@
+//! Compiles and runs `tests/c/roundtrip.c` against the static library.
+#![cfg(unix)]
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn test_c_roundtrip() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    // The static library is built alongside the test binary in `target/<profile>/deps`.
+    let test_exe = std::env::current_exe().expect("Get test executable path");
+    let static_lib = test_exe.parent().unwrap().join("libcfavml_capi.a");
+    assert!(
+        static_lib.exists(),
+        "Static library not found at {}",
+        static_lib.display(),
+    );
+
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("cfavml_c_roundtrip");
+    let compiler = std::env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(compiler)
+        .arg("-std=c99")
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-I")
+        .arg(crate_dir.join("include"))
+        .arg(crate_dir.join("tests").join("c").join("roundtrip.c"))
+        .arg(&static_lib)
+        .args(["-lm", "-lpthread", "-ldl", "-o"])
+        .arg(&program)
+        .status()
+        .expect("Run C compiler");
+    assert!(status.success(), "Failed to compile the C test program");
+
+    let status = Command::new(&program).status().expect("Run C test program");
+    assert!(status.success(), "C test program failed");
+}
@@ -0,0 +1,31 @@
+//! Checks the committed C header matches the exported functions.
+//!
+//! Run with `CFAVML_BLESS_HEADER=1` to regenerate the header.
+use std::path::Path;
+
+#[test]
+fn test_header_up_to_date() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let header_path = crate_dir.join("include").join("cfavml.h");
+
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+        .expect("Load cbindgen config");
+    let mut generated = Vec::new();
+    cbindgen::Builder::new()
+        .with_src(crate_dir.join("src").join("lib.rs"))
+        .with_config(config)
+        .generate()
+        .expect("Generate C header")
+        .write(&mut generated);
+
+    if std::env::var_os("CFAVML_BLESS_HEADER").is_some() {
+        std::fs::write(&header_path, &generated).expect("Write C header");
+        return;
+    }
+
+    let existing = std::fs::read(&header_path).unwrap_or_default();
+    assert!(
+        existing == generated,
+        "The C header is out of date, run the tests with `CFAVML_BLESS_HEADER=1` to regenerate it"
+    );
+}
@@ -0,0 +1,29 @@
+#![allow(clippy::missing_safety_doc)]
+
+use cfavml::danger::SimdRegister;
+use num_complex::Complex;
+
+/// The additional register shuffles required to perform arithmetic over complex values.
+///
+/// Complex values are stored interleaved within the register, i.e. `[re, im, re, im, ...]`.
+pub trait ComplexOps<T: Copy>: SimdRegister<Complex<T>> {
+    /// Duplicates the real component of each complex value into both halves of the value,
+    /// i.e. `[a, b] -> [a, a]`.
+    unsafe fn dup_real_values(reg: Self::Register) -> Self::Register;
+
+    /// Duplicates the imaginary component of each complex value into both halves of the value,
+    /// i.e. `[a, b] -> [b, b]`.
+    unsafe fn dup_imag_values(reg: Self::Register) -> Self::Register;
+
+    /// Swaps the real and imaginary components of each complex value,
+    /// i.e. `[a, b] -> [b, a]`.
+    unsafe fn swap_complex(reg: Self::Register) -> Self::Register;
+
+    /// Negates the imaginary component of each complex value,
+    /// i.e. `[a, b] -> [a, -b]`.
+    unsafe fn conjugate(reg: Self::Register) -> Self::Register;
+
+    /// Computes the squared magnitude `re² + im²` of each complex value and writes them
+    /// to `mem` as `elements_per_lane` real values.
+    unsafe fn write_squared_norm(mem: *mut T, reg: Self::Register);
+}
@@ -0,0 +1,77 @@
+use num_complex::Complex;
+
+use crate::danger::generic_complex_dot;
+
+macro_rules! define_complex_dot_impl {
+    (
+        name = $name:ident,
+        $t:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = concat!(
+            "Computes the Hermitian inner product `sum(a[i] * conj(b[i]))` of two `Complex<",
+            stringify!($t),
+            ">` vectors of any length.",
+        )]
+        #[doc = ""]
+        #[doc = "# Panics"]
+        #[doc = ""]
+        #[doc = "If `a` and `b` are not equal in length."]
+        #[doc = ""]
+        #[doc = "# Safety"]
+        $(
+            #[doc = ""]
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[Complex<$t>], b: &[Complex<$t>]) -> Complex<$t> {
+            generic_complex_dot::<$t, crate::danger::$imp>(a, b)
+        }
+    };
+}
+
+define_complex_dot_impl!(name = complexf32_xany_fallback_dot, f32, FallbackComplex);
+define_complex_dot_impl!(name = complexf64_xany_fallback_dot, f64, FallbackComplex);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_complex_dot_impl!(
+    name = complexf32_xany_avx2fma_dot,
+    f32,
+    Avx2Complex,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_complex_dot_impl!(
+    name = complexf64_xany_avx2fma_dot,
+    f64,
+    Avx2Complex,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_complex_dot_impl!(
+    name = complexf32_xany_avx512_dot,
+    f32,
+    Avx512Complex,
+    target_features = "avx512f"
+);
+#[cfg(target_arch = "aarch64")]
+define_complex_dot_impl!(
+    name = complexf32_xany_neon_dot,
+    f32,
+    NeonComplex,
+    target_features = "neon"
+);
+#[cfg(target_arch = "aarch64")]
+define_complex_dot_impl!(
+    name = complexf64_xany_neon_dot,
+    f64,
+    NeonComplex,
+    target_features = "neon"
+);
@@ -0,0 +1,111 @@
+use num_complex::Complex;
+
+use crate::danger::{generic_complex_squared_norm, generic_complex_squared_norm_sum};
+
+macro_rules! define_complex_squared_norm_impl {
+    (
+        name = $name:ident,
+        sum_name = $sum_name:ident,
+        $t:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = concat!(
+            "Computes the squared magnitude `re² + im²` of each value of a `Complex<",
+            stringify!($t),
+            ">` vector of any length, writing the result to `result`.",
+        )]
+        #[doc = ""]
+        #[doc = "# Panics"]
+        #[doc = ""]
+        #[doc = "If `a` and `result` are not equal in length."]
+        #[doc = ""]
+        #[doc = "# Safety"]
+        $(
+            #[doc = ""]
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[Complex<$t>], result: &mut [$t]) {
+            generic_complex_squared_norm::<$t, crate::danger::$imp>(a, result)
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = concat!(
+            "Computes the total power `sum(re² + im²)` of a `Complex<",
+            stringify!($t),
+            ">` vector of any length.",
+        )]
+        #[doc = ""]
+        #[doc = "# Safety"]
+        $(
+            #[doc = ""]
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $sum_name(a: &[Complex<$t>]) -> $t {
+            generic_complex_squared_norm_sum::<$t, crate::danger::$imp>(a)
+        }
+    };
+}
+
+define_complex_squared_norm_impl!(
+    name = complexf32_xany_fallback_squared_norm,
+    sum_name = complexf32_xany_fallback_squared_norm_sum,
+    f32,
+    FallbackComplex
+);
+define_complex_squared_norm_impl!(
+    name = complexf64_xany_fallback_squared_norm,
+    sum_name = complexf64_xany_fallback_squared_norm_sum,
+    f64,
+    FallbackComplex
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_complex_squared_norm_impl!(
+    name = complexf32_xany_avx2fma_squared_norm,
+    sum_name = complexf32_xany_avx2fma_squared_norm_sum,
+    f32,
+    Avx2Complex,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_complex_squared_norm_impl!(
+    name = complexf64_xany_avx2fma_squared_norm,
+    sum_name = complexf64_xany_avx2fma_squared_norm_sum,
+    f64,
+    Avx2Complex,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_complex_squared_norm_impl!(
+    name = complexf32_xany_avx512_squared_norm,
+    sum_name = complexf32_xany_avx512_squared_norm_sum,
+    f32,
+    Avx512Complex,
+    target_features = "avx512f"
+);
+#[cfg(target_arch = "aarch64")]
+define_complex_squared_norm_impl!(
+    name = complexf32_xany_neon_squared_norm,
+    sum_name = complexf32_xany_neon_squared_norm_sum,
+    f32,
+    NeonComplex,
+    target_features = "neon"
+);
+#[cfg(target_arch = "aarch64")]
+define_complex_squared_norm_impl!(
+    name = complexf64_xany_neon_squared_norm,
+    sum_name = complexf64_xany_neon_squared_norm_sum,
+    f64,
+    NeonComplex,
+    target_features = "neon"
+);
@@ -0,0 +1,793 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem;
+
+use cfavml::danger::SimdRegister;
+use cfavml::math::Math;
+use num_complex::Complex;
+
+use crate::danger::ComplexOps;
+use crate::math::ComplexMath;
+
+/// AVX2 enabled SIMD operations over complex values.
+///
+/// This requires the `avx2` and `fma` CPU features be enabled, the interleaved complex
+/// multiply is built on the `fmaddsub` and `fmsubadd` instructions.
+///
+/// Each `__m256` register holds 4 `Complex<f32>` values and each `__m256d` register
+/// holds 2 `Complex<f64>` values.
+pub struct Avx2Complex;
+
+impl SimdRegister<Complex<f32>> for Avx2Complex {
+    type Register = __m256;
+
+    #[inline(always)]
+    unsafe fn load(mem: *const Complex<f32>) -> Self::Register {
+        _mm256_loadu_ps(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn filled(value: Complex<f32>) -> Self::Register {
+        _mm256_setr_ps(
+            value.re, value.im, value.re, value.im, value.re, value.im, value.re,
+            value.im,
+        )
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed() -> Self::Register {
+        _mm256_setzero_ps()
+    }
+
+    #[inline(always)]
+    unsafe fn add(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm256_add_ps(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm256_sub_ps(l1, l2)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)(c+di) = (ac-bd) + (ad+bc)i`
+    unsafe fn mul(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let real = <Self as ComplexOps<f32>>::dup_real_values(l1);
+        let imag = <Self as ComplexOps<f32>>::dup_imag_values(l1);
+        let swapped = <Self as ComplexOps<f32>>::swap_complex(l2);
+
+        // [a*c - b*d, a*d + b*c]
+        _mm256_fmaddsub_ps(real, l2, _mm256_mul_ps(imag, swapped))
+    }
+
+    #[inline(always)]
+    /// `(a+bi)/(c+di) = (ac+bd)/(c²+d²) + (bc-ad)/(c²+d²) i`
+    ///
+    /// This is the same formula as the scalar division, so dividing by a zero complex
+    /// value produces the same `inf` and `NaN` components.
+    unsafe fn div(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let real = <Self as ComplexOps<f32>>::dup_real_values(l1);
+        let imag = <Self as ComplexOps<f32>>::dup_imag_values(l1);
+        let swapped = <Self as ComplexOps<f32>>::swap_complex(l2);
+
+        // [b*d + a*c, b*c - a*d]
+        let numerator = _mm256_fmsubadd_ps(imag, swapped, _mm256_mul_ps(real, l2));
+
+        // [c² + d², d² + c²]
+        let squared = _mm256_mul_ps(l2, l2);
+        let norm =
+            _mm256_add_ps(squared, <Self as ComplexOps<f32>>::swap_complex(squared));
+
+        _mm256_div_ps(numerator, norm)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)(c+di) + acc`
+    unsafe fn fmadd(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        let product = <Self as SimdRegister<Complex<f32>>>::mul(l1, l2);
+        <Self as SimdRegister<Complex<f32>>>::add(product, acc)
+    }
+
+    #[inline(always)]
+    /// Selects the value with the largest magnitude, `l2` if the magnitudes are unordered.
+    unsafe fn max(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_ps::<_CMP_GE_OQ>(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        _mm256_blendv_ps(l2, l1, mask)
+    }
+
+    #[inline(always)]
+    /// Selects the value with the smallest magnitude, `l2` if the magnitudes are unordered.
+    unsafe fn min(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_ps::<_CMP_LE_OQ>(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        _mm256_blendv_ps(l2, l1, mask)
+    }
+
+    #[inline(always)]
+    /// Both the real and imaginary components must be equal.
+    unsafe fn eq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_ps::<_CMP_EQ_OQ>(l1, l2);
+        let mask = _mm256_and_ps(mask, <Self as ComplexOps<f32>>::swap_complex(mask));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    /// Either the real or imaginary components must not be equal.
+    unsafe fn neq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_ps::<_CMP_NEQ_UQ>(l1, l2);
+        let mask = _mm256_or_ps(mask, <Self as ComplexOps<f32>>::swap_complex(mask));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_ps::<_CMP_LT_OQ>(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn lte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_ps::<_CMP_LE_OQ>(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn gt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_ps::<_CMP_GT_OQ>(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_ps::<_CMP_GE_OQ>(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    /// Selects `l2` where the mask value is the zero complex value, otherwise `l1`.
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = _mm256_cmp_ps::<_CMP_EQ_OQ>(mask, _mm256_setzero_ps());
+        let is_zero =
+            _mm256_and_ps(is_zero, <Self as ComplexOps<f32>>::swap_complex(is_zero));
+        _mm256_blendv_ps(l1, l2, is_zero)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_to_value(reg: Self::Register) -> Complex<f32> {
+        let low = _mm256_castps256_ps128(reg);
+        let high = _mm256_extractf128_ps::<1>(reg);
+
+        // [re0 + re2, im0 + im2, re1 + re3, im1 + im3]
+        let sum = _mm_add_ps(low, high);
+        // [re, im, _, _]
+        let sum = _mm_add_ps(sum, _mm_movehl_ps(sum, sum));
+
+        let re = _mm_cvtss_f32(sum);
+        let im = _mm_cvtss_f32(_mm_movehdup_ps(sum));
+        Complex::new(re, im)
+    }
+
+    #[inline(always)]
+    unsafe fn max_to_value(reg: Self::Register) -> Complex<f32> {
+        let [a, b, c, d]: [Complex<f32>; 4] = mem::transmute(reg);
+        ComplexMath::cmp_max(ComplexMath::cmp_max(a, b), ComplexMath::cmp_max(c, d))
+    }
+
+    #[inline(always)]
+    unsafe fn min_to_value(reg: Self::Register) -> Complex<f32> {
+        let [a, b, c, d]: [Complex<f32>; 4] = mem::transmute(reg);
+        ComplexMath::cmp_min(ComplexMath::cmp_min(a, b), ComplexMath::cmp_min(c, d))
+    }
+
+    #[inline(always)]
+    unsafe fn write(mem: *mut Complex<f32>, reg: Self::Register) {
+        _mm256_storeu_ps(mem.cast(), reg)
+    }
+}
+
+impl ComplexOps<f32> for Avx2Complex {
+    #[inline(always)]
+    unsafe fn dup_real_values(reg: Self::Register) -> Self::Register {
+        _mm256_moveldup_ps(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn dup_imag_values(reg: Self::Register) -> Self::Register {
+        _mm256_movehdup_ps(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn swap_complex(reg: Self::Register) -> Self::Register {
+        _mm256_permute_ps::<0b1011_0001>(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn conjugate(reg: Self::Register) -> Self::Register {
+        let sign_mask = _mm256_setr_ps(0.0, -0.0, 0.0, -0.0, 0.0, -0.0, 0.0, -0.0);
+        _mm256_xor_ps(reg, sign_mask)
+    }
+
+    #[inline(always)]
+    unsafe fn write_squared_norm(mem: *mut f32, reg: Self::Register) {
+        let squared = _mm256_mul_ps(reg, reg);
+        // [n0, n1, n0, n1, n2, n3, n2, n3]
+        let norm = _mm256_hadd_ps(squared, squared);
+
+        let low = _mm_castps_pd(_mm256_castps256_ps128(norm));
+        let high = _mm_castps_pd(_mm256_extractf128_ps::<1>(norm));
+        _mm_storeu_ps(mem, _mm_castpd_ps(_mm_unpacklo_pd(low, high)))
+    }
+}
+
+impl SimdRegister<Complex<f64>> for Avx2Complex {
+    type Register = __m256d;
+
+    #[inline(always)]
+    unsafe fn load(mem: *const Complex<f64>) -> Self::Register {
+        _mm256_loadu_pd(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn filled(value: Complex<f64>) -> Self::Register {
+        _mm256_setr_pd(value.re, value.im, value.re, value.im)
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed() -> Self::Register {
+        _mm256_setzero_pd()
+    }
+
+    #[inline(always)]
+    unsafe fn add(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm256_add_pd(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm256_sub_pd(l1, l2)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)(c+di) = (ac-bd) + (ad+bc)i`
+    unsafe fn mul(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let real = <Self as ComplexOps<f64>>::dup_real_values(l1);
+        let imag = <Self as ComplexOps<f64>>::dup_imag_values(l1);
+        let swapped = <Self as ComplexOps<f64>>::swap_complex(l2);
+
+        // [a*c - b*d, a*d + b*c]
+        _mm256_fmaddsub_pd(real, l2, _mm256_mul_pd(imag, swapped))
+    }
+
+    #[inline(always)]
+    /// `(a+bi)/(c+di) = (ac+bd)/(c²+d²) + (bc-ad)/(c²+d²) i`
+    ///
+    /// This is the same formula as the scalar division, so dividing by a zero complex
+    /// value produces the same `inf` and `NaN` components.
+    unsafe fn div(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let real = <Self as ComplexOps<f64>>::dup_real_values(l1);
+        let imag = <Self as ComplexOps<f64>>::dup_imag_values(l1);
+        let swapped = <Self as ComplexOps<f64>>::swap_complex(l2);
+
+        // [b*d + a*c, b*c - a*d]
+        let numerator = _mm256_fmsubadd_pd(imag, swapped, _mm256_mul_pd(real, l2));
+
+        // [c² + d², d² + c²]
+        let squared = _mm256_mul_pd(l2, l2);
+        let norm =
+            _mm256_add_pd(squared, <Self as ComplexOps<f64>>::swap_complex(squared));
+
+        _mm256_div_pd(numerator, norm)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)(c+di) + acc`
+    unsafe fn fmadd(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        let product = <Self as SimdRegister<Complex<f64>>>::mul(l1, l2);
+        <Self as SimdRegister<Complex<f64>>>::add(product, acc)
+    }
+
+    #[inline(always)]
+    /// Selects the value with the largest magnitude, `l2` if the magnitudes are unordered.
+    unsafe fn max(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_pd::<_CMP_GE_OQ>(f64_norm_sqr(l1), f64_norm_sqr(l2));
+        _mm256_blendv_pd(l2, l1, mask)
+    }
+
+    #[inline(always)]
+    /// Selects the value with the smallest magnitude, `l2` if the magnitudes are unordered.
+    unsafe fn min(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_pd::<_CMP_LE_OQ>(f64_norm_sqr(l1), f64_norm_sqr(l2));
+        _mm256_blendv_pd(l2, l1, mask)
+    }
+
+    #[inline(always)]
+    /// Both the real and imaginary components must be equal.
+    unsafe fn eq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_pd::<_CMP_EQ_OQ>(l1, l2);
+        let mask = _mm256_and_pd(mask, <Self as ComplexOps<f64>>::swap_complex(mask));
+        f64_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    /// Either the real or imaginary components must not be equal.
+    unsafe fn neq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_pd::<_CMP_NEQ_UQ>(l1, l2);
+        let mask = _mm256_or_pd(mask, <Self as ComplexOps<f64>>::swap_complex(mask));
+        f64_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_pd::<_CMP_LT_OQ>(f64_norm_sqr(l1), f64_norm_sqr(l2));
+        f64_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn lte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_pd::<_CMP_LE_OQ>(f64_norm_sqr(l1), f64_norm_sqr(l2));
+        f64_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn gt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_pd::<_CMP_GT_OQ>(f64_norm_sqr(l1), f64_norm_sqr(l2));
+        f64_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm256_cmp_pd::<_CMP_GE_OQ>(f64_norm_sqr(l1), f64_norm_sqr(l2));
+        f64_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    /// Selects `l2` where the mask value is the zero complex value, otherwise `l1`.
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = _mm256_cmp_pd::<_CMP_EQ_OQ>(mask, _mm256_setzero_pd());
+        let is_zero =
+            _mm256_and_pd(is_zero, <Self as ComplexOps<f64>>::swap_complex(is_zero));
+        _mm256_blendv_pd(l1, l2, is_zero)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_to_value(reg: Self::Register) -> Complex<f64> {
+        let low = _mm256_castpd256_pd128(reg);
+        let high = _mm256_extractf128_pd::<1>(reg);
+
+        // [re0 + re1, im0 + im1]
+        let sum = _mm_add_pd(low, high);
+
+        let re = _mm_cvtsd_f64(sum);
+        let im = _mm_cvtsd_f64(_mm_unpackhi_pd(sum, sum));
+        Complex::new(re, im)
+    }
+
+    #[inline(always)]
+    unsafe fn max_to_value(reg: Self::Register) -> Complex<f64> {
+        let [a, b]: [Complex<f64>; 2] = mem::transmute(reg);
+        ComplexMath::cmp_max(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn min_to_value(reg: Self::Register) -> Complex<f64> {
+        let [a, b]: [Complex<f64>; 2] = mem::transmute(reg);
+        ComplexMath::cmp_min(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn write(mem: *mut Complex<f64>, reg: Self::Register) {
+        _mm256_storeu_pd(mem.cast(), reg)
+    }
+}
+
+impl ComplexOps<f64> for Avx2Complex {
+    #[inline(always)]
+    unsafe fn dup_real_values(reg: Self::Register) -> Self::Register {
+        _mm256_movedup_pd(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn dup_imag_values(reg: Self::Register) -> Self::Register {
+        _mm256_permute_pd::<0b1111>(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn swap_complex(reg: Self::Register) -> Self::Register {
+        _mm256_permute_pd::<0b0101>(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn conjugate(reg: Self::Register) -> Self::Register {
+        let sign_mask = _mm256_setr_pd(0.0, -0.0, 0.0, -0.0);
+        _mm256_xor_pd(reg, sign_mask)
+    }
+
+    #[inline(always)]
+    unsafe fn write_squared_norm(mem: *mut f64, reg: Self::Register) {
+        let squared = _mm256_mul_pd(reg, reg);
+        // [n0, n0, n1, n1]
+        let norm = _mm256_hadd_pd(squared, squared);
+
+        let low = _mm256_castpd256_pd128(norm);
+        let high = _mm256_extractf128_pd::<1>(norm);
+        _mm_storeu_pd(mem, _mm_unpacklo_pd(low, high))
+    }
+}
+
+#[inline(always)]
+/// Computes the squared magnitude of each complex value, duplicated into both halves
+/// of the value, i.e. `[a, b] -> [a² + b², b² + a²]`.
+unsafe fn f32_norm_sqr(reg: __m256) -> __m256 {
+    let squared = _mm256_mul_ps(reg, reg);
+    _mm256_add_ps(
+        squared,
+        <Avx2Complex as ComplexOps<f32>>::swap_complex(squared),
+    )
+}
+
+#[inline(always)]
+/// Converts a comparison mask, set in both halves of each complex value, into the
+/// `1+0i` or `0+0i` values matching [ComplexMath].
+unsafe fn f32_cast_mask(mask: __m256) -> __m256 {
+    _mm256_and_ps(mask, _mm256_setr_ps(1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0))
+}
+
+#[inline(always)]
+/// Computes the squared magnitude of each complex value, duplicated into both halves
+/// of the value, i.e. `[a, b] -> [a² + b², b² + a²]`.
+unsafe fn f64_norm_sqr(reg: __m256d) -> __m256d {
+    let squared = _mm256_mul_pd(reg, reg);
+    _mm256_add_pd(
+        squared,
+        <Avx2Complex as ComplexOps<f64>>::swap_complex(squared),
+    )
+}
+
+#[inline(always)]
+/// Converts a comparison mask, set in both halves of each complex value, into the
+/// `1+0i` or `0+0i` values matching [ComplexMath].
+unsafe fn f64_cast_mask(mask: __m256d) -> __m256d {
+    _mm256_and_pd(mask, _mm256_setr_pd(1.0, 0.0, 1.0, 0.0))
+}
+
+#[cfg(all(test, target_feature = "avx2", target_feature = "fma"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{get_sample_vectors, IsClose};
+
+    macro_rules! define_arithmetic_test {
+        ($t:ident, $n:expr) => {
+            paste::paste! {
+                unsafe fn [<apply_op_ $t>](
+                    l1: &[Complex<$t>],
+                    l2: &[Complex<$t>],
+                    op: unsafe fn(
+                        <Avx2Complex as SimdRegister<Complex<$t>>>::Register,
+                        <Avx2Complex as SimdRegister<Complex<$t>>>::Register,
+                    ) -> <Avx2Complex as SimdRegister<Complex<$t>>>::Register,
+                ) -> Vec<Complex<$t>> {
+                    let mut result = Vec::with_capacity(l1.len());
+                    for (a, b) in l1.chunks_exact($n).zip(l2.chunks_exact($n)) {
+                        let a = <Avx2Complex as SimdRegister<Complex<$t>>>::load(a.as_ptr());
+                        let b = <Avx2Complex as SimdRegister<Complex<$t>>>::load(b.as_ptr());
+                        let values: [Complex<$t>; $n] = mem::transmute(op(a, b));
+                        result.extend_from_slice(&values);
+                    }
+                    result
+                }
+
+                fn [<check_ $t>](
+                    name: &str,
+                    l1: &[Complex<$t>],
+                    l2: &[Complex<$t>],
+                    actual: &[Complex<$t>],
+                    expected_op: fn(Complex<$t>, Complex<$t>) -> Complex<$t>,
+                ) {
+                    for (i, (a, b)) in l1.iter().zip(l2).enumerate() {
+                        let expected = expected_op(*a, *b);
+                        assert!(
+                            actual[i].is_close(&expected),
+                            "{name} value mismatch at {i} {:?} vs {expected:?}",
+                            actual[i],
+                        );
+                    }
+                }
+
+                #[test]
+                fn [<test_avx2_complex_ $t _arithmetic>]() {
+                    let (l1, l2) = get_sample_vectors::<$t>($n * 64);
+
+                    unsafe {
+                        let actual = [<apply_op_ $t>](&l1, &l2, <Avx2Complex as SimdRegister<Complex<$t>>>::add);
+                        [<check_ $t>]("add", &l1, &l2, &actual, ComplexMath::add);
+
+                        let actual = [<apply_op_ $t>](&l1, &l2, <Avx2Complex as SimdRegister<Complex<$t>>>::sub);
+                        [<check_ $t>]("sub", &l1, &l2, &actual, ComplexMath::sub);
+
+                        let actual = [<apply_op_ $t>](&l1, &l2, <Avx2Complex as SimdRegister<Complex<$t>>>::mul);
+                        [<check_ $t>]("mul", &l1, &l2, &actual, ComplexMath::mul);
+
+                        let actual = [<apply_op_ $t>](&l1, &l2, <Avx2Complex as SimdRegister<Complex<$t>>>::div);
+                        [<check_ $t>]("div", &l1, &l2, &actual, ComplexMath::div);
+                    }
+                }
+
+                #[test]
+                fn [<test_avx2_complex_ $t _div_special_values>]() {
+                    let values = [
+                        Complex::new(1.0, 2.0),
+                        Complex::new(0.0, 0.0),
+                        Complex::new(-3.5, 0.0),
+                        Complex::new(0.0, -1.0),
+                        Complex::new($t::INFINITY, 1.0),
+                        Complex::new($t::NAN, 0.0),
+                        Complex::new(1e-20, 1e-20),
+                        Complex::new(-2.0, 4.0),
+                    ];
+
+                    // Every value divided by every value, including the zero complex number.
+                    let mut l1 = Vec::new();
+                    let mut l2 = Vec::new();
+                    for a in values {
+                        for b in values {
+                            l1.push(a);
+                            l2.push(b);
+                        }
+                    }
+
+                    let actual = unsafe { [<apply_op_ $t>](&l1, &l2, <Avx2Complex as SimdRegister<Complex<$t>>>::div) };
+                    [<check_ $t>]("div", &l1, &l2, &actual, ComplexMath::div);
+                }
+
+                #[test]
+                fn [<test_avx2_complex_ $t _fmadd>]() {
+                    let (l1, l2) = get_sample_vectors::<$t>($n * 16);
+
+                    let mut acc = unsafe { <Avx2Complex as SimdRegister<Complex<$t>>>::zeroed() };
+                    for (a, b) in l1.chunks_exact($n).zip(l2.chunks_exact($n)) {
+                        unsafe {
+                            let a = <Avx2Complex as SimdRegister<Complex<$t>>>::load(a.as_ptr());
+                            let b = <Avx2Complex as SimdRegister<Complex<$t>>>::load(b.as_ptr());
+                            acc = <Avx2Complex as SimdRegister<Complex<$t>>>::fmadd(a, b, acc);
+                        }
+                    }
+                    let actual: [Complex<$t>; $n] = unsafe { mem::transmute(acc) };
+
+                    let mut expected = [ComplexMath::zero(); $n];
+                    for (i, (a, b)) in l1.iter().zip(&l2).enumerate() {
+                        expected[i % $n] = ComplexMath::add(ComplexMath::mul(*a, *b), expected[i % $n]);
+                    }
+
+                    for (i, (actual, expected)) in actual.iter().zip(&expected).enumerate() {
+                        assert!(
+                            actual.is_close(expected),
+                            "fmadd value mismatch at {i} {actual:?} vs {expected:?}",
+                        );
+                    }
+                }
+
+                #[test]
+                fn [<test_avx2_complex_ $t _write>]() {
+                    let (l1, _) = get_sample_vectors::<$t>($n);
+
+                    let mut actual = [ComplexMath::zero(); $n];
+                    unsafe {
+                        let reg = <Avx2Complex as SimdRegister<Complex<$t>>>::load(l1.as_ptr());
+                        <Avx2Complex as SimdRegister<Complex<$t>>>::write(actual.as_mut_ptr(), reg);
+                    }
+                    assert_eq!(actual.as_slice(), l1.as_slice());
+                }
+
+                #[test]
+                fn [<test_avx2_complex_ $t _sum_to_value>]() {
+                    let values: [Complex<$t>; $n] = core::array::from_fn(|i| {
+                        Complex::new(i as $t + 1.0, -(i as $t) * 2.0)
+                    });
+
+                    let actual = unsafe {
+                        let reg = <Avx2Complex as SimdRegister<Complex<$t>>>::load(values.as_ptr());
+                        <Avx2Complex as SimdRegister<Complex<$t>>>::sum_to_value(reg)
+                    };
+
+                    let mut expected = ComplexMath::zero();
+                    for value in values {
+                        expected = ComplexMath::add(expected, value);
+                    }
+                    assert_eq!(actual, expected);
+                }
+            }
+        };
+    }
+
+    define_arithmetic_test!(f32, 4);
+    define_arithmetic_test!(f64, 2);
+
+    macro_rules! define_cmp_test {
+        ($t:ident, $reg:ty, $n:expr) => {
+            paste::paste! {
+                fn [<$t _cmp_values>]() -> (Vec<Complex<$t>>, Vec<Complex<$t>>) {
+                    let values = [
+                        Complex::new(1.0, 2.0),
+                        Complex::new(2.0, 1.0),
+                        Complex::new(-1.0, -2.0),
+                        Complex::new(1.0, -2.0),
+                        Complex::new(0.0, 0.0),
+                        Complex::new(0.0, -3.0),
+                        Complex::new($t::NAN, 1.0),
+                        Complex::new($t::INFINITY, 0.0),
+                    ];
+
+                    // Every value compared with every value, including those of equal magnitude.
+                    let mut l1 = Vec::new();
+                    let mut l2 = Vec::new();
+                    for a in values {
+                        for b in values {
+                            l1.push(a);
+                            l2.push(b);
+                        }
+                    }
+                    (l1, l2)
+                }
+
+                #[test]
+                fn [<test_avx2_complex_ $t _cmp>]() {
+                    let (mut l1, mut l2) = [<$t _cmp_values>]();
+                    let (s1, s2) = get_sample_vectors::<$t>($n * 64);
+                    l1.extend_from_slice(&s1);
+                    l2.extend_from_slice(&s2);
+
+                    type Reg = Avx2Complex;
+                    type Ops = (
+                        &'static str,
+                        unsafe fn($reg, $reg) -> $reg,
+                        fn(Complex<$t>, Complex<$t>) -> Complex<$t>,
+                    );
+                    let ops: [Ops; 8] = [
+                        (
+                            "max",
+                            <Reg as SimdRegister<Complex<$t>>>::max,
+                            ComplexMath::cmp_max,
+                        ),
+                        (
+                            "min",
+                            <Reg as SimdRegister<Complex<$t>>>::min,
+                            ComplexMath::cmp_min,
+                        ),
+                        ("eq", <Reg as SimdRegister<Complex<$t>>>::eq, |a, b| {
+                            ComplexMath::cast_bool(ComplexMath::cmp_eq(a, b))
+                        }),
+                        ("neq", <Reg as SimdRegister<Complex<$t>>>::neq, |a, b| {
+                            ComplexMath::cast_bool(!ComplexMath::cmp_eq(a, b))
+                        }),
+                        ("lt", <Reg as SimdRegister<Complex<$t>>>::lt, |a, b| {
+                            ComplexMath::cast_bool(ComplexMath::cmp_lt(a, b))
+                        }),
+                        ("lte", <Reg as SimdRegister<Complex<$t>>>::lte, |a, b| {
+                            ComplexMath::cast_bool(ComplexMath::cmp_lte(a, b))
+                        }),
+                        ("gt", <Reg as SimdRegister<Complex<$t>>>::gt, |a, b| {
+                            ComplexMath::cast_bool(ComplexMath::cmp_gt(a, b))
+                        }),
+                        ("gte", <Reg as SimdRegister<Complex<$t>>>::gte, |a, b| {
+                            ComplexMath::cast_bool(ComplexMath::cmp_gte(a, b))
+                        }),
+                    ];
+
+                    for (name, op, expected_op) in ops {
+                        let actual = unsafe { [<apply_op_ $t>](&l1, &l2, op) };
+                        for (i, (a, b)) in l1.iter().zip(&l2).enumerate() {
+                            let expected = expected_op(*a, *b);
+                            // NaN values are carried through by max and min so compare bitwise.
+                            assert_eq!(
+                                (actual[i].re.to_bits(), actual[i].im.to_bits()),
+                                (expected.re.to_bits(), expected.im.to_bits()),
+                                "{name} value mismatch at {i} {a:?} {b:?}: {:?} vs {expected:?}",
+                                actual[i],
+                            );
+                        }
+                    }
+                }
+
+                #[test]
+                fn [<test_avx2_complex_ $t _select>]() {
+                    // A mask with only its imaginary component set is not the zero value.
+                    let mask: Vec<Complex<$t>> = [
+                        Complex::new(0.0, 0.0),
+                        Complex::new(1.0, 0.0),
+                        Complex::new(0.0, 1.0),
+                    ]
+                    .into_iter()
+                    .cycle()
+                    .take($n * 4)
+                    .collect();
+                    let (l1, l2) = get_sample_vectors::<$t>($n * 4);
+
+                    let actual = unsafe {
+                        let mut actual = Vec::new();
+                        for ((m, a), b) in mask.chunks_exact($n).zip(l1.chunks_exact($n)).zip(l2.chunks_exact($n)) {
+                            let m = <Avx2Complex as SimdRegister<Complex<$t>>>::load(m.as_ptr());
+                            let a = <Avx2Complex as SimdRegister<Complex<$t>>>::load(a.as_ptr());
+                            let b = <Avx2Complex as SimdRegister<Complex<$t>>>::load(b.as_ptr());
+                            let values: [Complex<$t>; $n] = mem::transmute(
+                                <Avx2Complex as SimdRegister<Complex<$t>>>::select(m, a, b),
+                            );
+                            actual.extend_from_slice(&values);
+                        }
+                        actual
+                    };
+
+                    for (i, value) in actual.iter().enumerate() {
+                        let expected = if mask[i] == ComplexMath::zero() { l2[i] } else { l1[i] };
+                        assert_eq!(*value, expected, "select value mismatch at {i}");
+                    }
+                }
+
+                #[test]
+                fn [<test_avx2_complex_ $t _max_min_to_value>]() {
+                    let (mut l1, mut l2) = [<$t _cmp_values>]();
+                    let (s1, s2) = get_sample_vectors::<$t>($n * 16);
+                    l1.extend_from_slice(&s1);
+                    l2.extend_from_slice(&s2);
+
+                    for values in l1.chunks_exact($n).chain(l2.chunks_exact($n)) {
+                        let (max, min) = unsafe {
+                            let reg = <Avx2Complex as SimdRegister<Complex<$t>>>::load(values.as_ptr());
+                            (
+                                <Avx2Complex as SimdRegister<Complex<$t>>>::max_to_value(reg),
+                                <Avx2Complex as SimdRegister<Complex<$t>>>::min_to_value(reg),
+                            )
+                        };
+
+                        let (expected_max, expected_min) = if $n == 4 {
+                            (
+                                ComplexMath::cmp_max(
+                                    ComplexMath::cmp_max(values[0], values[1]),
+                                    ComplexMath::cmp_max(values[2], values[3]),
+                                ),
+                                ComplexMath::cmp_min(
+                                    ComplexMath::cmp_min(values[0], values[1]),
+                                    ComplexMath::cmp_min(values[2], values[3]),
+                                ),
+                            )
+                        } else {
+                            (
+                                ComplexMath::cmp_max(values[0], values[1]),
+                                ComplexMath::cmp_min(values[0], values[1]),
+                            )
+                        };
+                        assert_eq!(
+                            (max.re.to_bits(), max.im.to_bits()),
+                            (expected_max.re.to_bits(), expected_max.im.to_bits()),
+                            "max mismatch on {values:?}",
+                        );
+                        assert_eq!(
+                            (min.re.to_bits(), min.im.to_bits()),
+                            (expected_min.re.to_bits(), expected_min.im.to_bits()),
+                            "min mismatch on {values:?}",
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    define_cmp_test!(f32, __m256, 4);
+    define_cmp_test!(f64, __m256d, 2);
+}
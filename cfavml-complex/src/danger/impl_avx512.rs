@@ -0,0 +1,620 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem;
+
+use cfavml::danger::SimdRegister;
+use num_complex::Complex;
+
+use crate::danger::{Avx2Complex, ComplexOps};
+
+/// AVX512 enabled SIMD operations over complex values.
+///
+/// This requires the `avx512f` CPU feature be enabled, the interleaved complex
+/// multiply is built on the `fmaddsub` and `fmsubadd` instructions.
+///
+/// Each `__m512` register holds 8 `Complex<f32>` values.
+pub struct Avx512Complex;
+
+impl SimdRegister<Complex<f32>> for Avx512Complex {
+    type Register = __m512;
+
+    #[inline(always)]
+    unsafe fn load(mem: *const Complex<f32>) -> Self::Register {
+        _mm512_loadu_ps(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn filled(value: Complex<f32>) -> Self::Register {
+        // A `Complex<f32>` is the same size as an `i64`, so broadcasting its bits
+        // fills the register with the interleaved `[re, im]` pairs.
+        let bits = mem::transmute::<Complex<f32>, i64>(value);
+        _mm512_castsi512_ps(_mm512_set1_epi64(bits))
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed() -> Self::Register {
+        _mm512_setzero_ps()
+    }
+
+    #[inline(always)]
+    unsafe fn add(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm512_add_ps(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm512_sub_ps(l1, l2)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)(c+di) = (ac-bd) + (ad+bc)i`
+    unsafe fn mul(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let real = <Self as ComplexOps<f32>>::dup_real_values(l1);
+        let imag = <Self as ComplexOps<f32>>::dup_imag_values(l1);
+        let swapped = <Self as ComplexOps<f32>>::swap_complex(l2);
+
+        // [a*c - b*d, a*d + b*c]
+        _mm512_fmaddsub_ps(real, l2, _mm512_mul_ps(imag, swapped))
+    }
+
+    #[inline(always)]
+    /// `(a+bi)/(c+di) = (ac+bd)/(c²+d²) + (bc-ad)/(c²+d²) i`
+    ///
+    /// This is the same formula as the scalar division, so dividing by a zero complex
+    /// value produces the same `inf` and `NaN` components.
+    unsafe fn div(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let real = <Self as ComplexOps<f32>>::dup_real_values(l1);
+        let imag = <Self as ComplexOps<f32>>::dup_imag_values(l1);
+        let swapped = <Self as ComplexOps<f32>>::swap_complex(l2);
+
+        // [b*d + a*c, b*c - a*d]
+        let numerator = _mm512_fmsubadd_ps(imag, swapped, _mm512_mul_ps(real, l2));
+
+        // [c² + d², d² + c²]
+        let squared = _mm512_mul_ps(l2, l2);
+        let norm =
+            _mm512_add_ps(squared, <Self as ComplexOps<f32>>::swap_complex(squared));
+
+        _mm512_div_ps(numerator, norm)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)(c+di) + acc`
+    unsafe fn fmadd(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        let product = <Self as SimdRegister<Complex<f32>>>::mul(l1, l2);
+        <Self as SimdRegister<Complex<f32>>>::add(product, acc)
+    }
+
+    #[inline(always)]
+    /// Selects the value with the largest magnitude, `l2` if the magnitudes are unordered.
+    unsafe fn max(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm512_cmp_ps_mask::<_CMP_GE_OQ>(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        _mm512_mask_blend_ps(mask, l2, l1)
+    }
+
+    #[inline(always)]
+    /// Selects the value with the smallest magnitude, `l2` if the magnitudes are unordered.
+    unsafe fn min(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm512_cmp_ps_mask::<_CMP_LE_OQ>(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        _mm512_mask_blend_ps(mask, l2, l1)
+    }
+
+    #[inline(always)]
+    /// Both the real and imaginary components must be equal.
+    unsafe fn eq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(l1, l2);
+        f32_cast_mask(mask & swap_mask_pairs(mask))
+    }
+
+    #[inline(always)]
+    /// Either the real or imaginary components must not be equal.
+    unsafe fn neq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm512_cmp_ps_mask::<_CMP_NEQ_UQ>(l1, l2);
+        f32_cast_mask(mask | swap_mask_pairs(mask))
+    }
+
+    #[inline(always)]
+    unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm512_cmp_ps_mask::<_CMP_LT_OQ>(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn lte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm512_cmp_ps_mask::<_CMP_LE_OQ>(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn gt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm512_cmp_ps_mask::<_CMP_GT_OQ>(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = _mm512_cmp_ps_mask::<_CMP_GE_OQ>(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    /// Selects `l2` where the mask value is the zero complex value, otherwise `l1`.
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(mask, _mm512_setzero_ps());
+        _mm512_mask_blend_ps(is_zero & swap_mask_pairs(is_zero), l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_to_value(reg: Self::Register) -> Complex<f32> {
+        let (low, high) = split_halves(reg);
+        let sum = _mm256_add_ps(low, high);
+        <Avx2Complex as SimdRegister<Complex<f32>>>::sum_to_value(sum)
+    }
+
+    #[inline(always)]
+    unsafe fn max_to_value(reg: Self::Register) -> Complex<f32> {
+        let (low, high) = split_halves(reg);
+        let max = <Avx2Complex as SimdRegister<Complex<f32>>>::max(low, high);
+        <Avx2Complex as SimdRegister<Complex<f32>>>::max_to_value(max)
+    }
+
+    #[inline(always)]
+    unsafe fn min_to_value(reg: Self::Register) -> Complex<f32> {
+        let (low, high) = split_halves(reg);
+        let min = <Avx2Complex as SimdRegister<Complex<f32>>>::min(low, high);
+        <Avx2Complex as SimdRegister<Complex<f32>>>::min_to_value(min)
+    }
+
+    #[inline(always)]
+    unsafe fn write(mem: *mut Complex<f32>, reg: Self::Register) {
+        _mm512_storeu_ps(mem.cast(), reg)
+    }
+}
+
+impl ComplexOps<f32> for Avx512Complex {
+    #[inline(always)]
+    unsafe fn dup_real_values(reg: Self::Register) -> Self::Register {
+        _mm512_moveldup_ps(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn dup_imag_values(reg: Self::Register) -> Self::Register {
+        _mm512_movehdup_ps(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn swap_complex(reg: Self::Register) -> Self::Register {
+        _mm512_permute_ps::<0b1011_0001>(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn conjugate(reg: Self::Register) -> Self::Register {
+        // `_mm512_xor_ps` requires `avx512dq`, so the sign of the imaginary half of
+        // each 64-bit value is flipped with an integer xor instead.
+        let sign_mask = _mm512_set1_epi64(i64::MIN);
+        _mm512_castsi512_ps(_mm512_xor_si512(_mm512_castps_si512(reg), sign_mask))
+    }
+
+    #[inline(always)]
+    unsafe fn write_squared_norm(mem: *mut f32, reg: Self::Register) {
+        let squared = _mm512_mul_ps(reg, reg);
+        // [n0, n0, n1, n1, ...]
+        let norm =
+            _mm512_add_ps(squared, <Self as ComplexOps<f32>>::swap_complex(squared));
+
+        // Truncating each 64-bit value keeps the low half of each pair.
+        let packed = _mm512_cvtepi64_epi32(_mm512_castps_si512(norm));
+        _mm256_storeu_si256(mem.cast(), packed)
+    }
+}
+
+#[inline(always)]
+/// Splits the register into its low and high `__m256` halves, each holding 4 values.
+unsafe fn split_halves(reg: __m512) -> (__m256, __m256) {
+    // `_mm512_extractf32x8_ps` requires `avx512dq`, the 64-bit lane version
+    // does the same split with only `avx512f`.
+    let reg = _mm512_castps_pd(reg);
+    let low = _mm256_castpd_ps(_mm512_castpd512_pd256(reg));
+    let high = _mm256_castpd_ps(_mm512_extractf64x4_pd::<1>(reg));
+    (low, high)
+}
+
+#[inline(always)]
+/// Computes the squared magnitude of each complex value, duplicated into both halves
+/// of the value, i.e. `[a, b] -> [a² + b², b² + a²]`.
+unsafe fn f32_norm_sqr(reg: __m512) -> __m512 {
+    let squared = _mm512_mul_ps(reg, reg);
+    _mm512_add_ps(
+        squared,
+        <Avx512Complex as ComplexOps<f32>>::swap_complex(squared),
+    )
+}
+
+#[inline(always)]
+/// Swaps the mask bits of the real and imaginary halves of each complex value.
+fn swap_mask_pairs(mask: __mmask16) -> __mmask16 {
+    ((mask >> 1) & 0x5555) | ((mask << 1) & 0xAAAA)
+}
+
+#[inline(always)]
+/// Converts a comparison mask, set in both halves of each complex value, into the
+/// `1+0i` or `0+0i` values matching the scalar comparisons.
+unsafe fn f32_cast_mask(mask: __mmask16) -> __m512 {
+    _mm512_maskz_mov_ps(mask & 0x5555, _mm512_set1_ps(1.0))
+}
+
+#[cfg(all(
+    test,
+    target_feature = "avx512f",
+    target_feature = "avx2",
+    target_feature = "fma"
+))]
+mod tests {
+    use super::*;
+    use crate::danger::FallbackComplex;
+    use crate::test_utils::get_sample_vectors;
+
+    const LANES: usize = 8;
+
+    type Avx2Register = <Avx2Complex as SimdRegister<Complex<f32>>>::Register;
+    type Avx512Register = <Avx512Complex as SimdRegister<Complex<f32>>>::Register;
+    type AvxOps = (
+        &'static str,
+        unsafe fn(Avx2Register, Avx2Register) -> Avx2Register,
+        unsafe fn(Avx512Register, Avx512Register) -> Avx512Register,
+    );
+
+    /// Applies the op to each block of values with both registers, the Avx2 register
+    /// processes each block as two halves.
+    unsafe fn apply_both(
+        l1: &[Complex<f32>],
+        l2: &[Complex<f32>],
+        avx2_op: unsafe fn(Avx2Register, Avx2Register) -> Avx2Register,
+        avx512_op: unsafe fn(Avx512Register, Avx512Register) -> Avx512Register,
+    ) -> (Vec<Complex<f32>>, Vec<Complex<f32>>) {
+        let mut avx2 = vec![Complex::new(0.0, 0.0); l1.len()];
+        let mut avx512 = vec![Complex::new(0.0, 0.0); l1.len()];
+
+        for (offset, (a, b)) in l1.chunks_exact(4).zip(l2.chunks_exact(4)).enumerate() {
+            let a = <Avx2Complex as SimdRegister<Complex<f32>>>::load(a.as_ptr());
+            let b = <Avx2Complex as SimdRegister<Complex<f32>>>::load(b.as_ptr());
+            <Avx2Complex as SimdRegister<Complex<f32>>>::write(
+                avx2[offset * 4..].as_mut_ptr(),
+                avx2_op(a, b),
+            );
+        }
+
+        for (offset, (a, b)) in l1
+            .chunks_exact(LANES)
+            .zip(l2.chunks_exact(LANES))
+            .enumerate()
+        {
+            let a = <Avx512Complex as SimdRegister<Complex<f32>>>::load(a.as_ptr());
+            let b = <Avx512Complex as SimdRegister<Complex<f32>>>::load(b.as_ptr());
+            <Avx512Complex as SimdRegister<Complex<f32>>>::write(
+                avx512[offset * LANES..].as_mut_ptr(),
+                avx512_op(a, b),
+            );
+        }
+
+        (avx2, avx512)
+    }
+
+    #[test]
+    fn test_avx512_complex_f32_matches_avx2() {
+        let (l1, l2) = get_sample_vectors::<f32>(LANES * 64);
+
+        let ops: [AvxOps; 4] = [
+            (
+                "add",
+                <Avx2Complex as SimdRegister<Complex<f32>>>::add,
+                <Avx512Complex as SimdRegister<Complex<f32>>>::add,
+            ),
+            (
+                "sub",
+                <Avx2Complex as SimdRegister<Complex<f32>>>::sub,
+                <Avx512Complex as SimdRegister<Complex<f32>>>::sub,
+            ),
+            (
+                "mul",
+                <Avx2Complex as SimdRegister<Complex<f32>>>::mul,
+                <Avx512Complex as SimdRegister<Complex<f32>>>::mul,
+            ),
+            (
+                "div",
+                <Avx2Complex as SimdRegister<Complex<f32>>>::div,
+                <Avx512Complex as SimdRegister<Complex<f32>>>::div,
+            ),
+        ];
+
+        for (name, avx2_op, avx512_op) in ops {
+            let (expected, actual) = unsafe { apply_both(&l1, &l2, avx2_op, avx512_op) };
+            assert_eq!(actual, expected, "{name} differs from Avx2Complex");
+        }
+    }
+
+    #[test]
+    fn test_avx512_complex_f32_fmadd_matches_avx2() {
+        let (l1, l2) = get_sample_vectors::<f32>(LANES * 16);
+
+        let (avx2, avx512) = unsafe {
+            let mut avx2_low = <Avx2Complex as SimdRegister<Complex<f32>>>::zeroed();
+            let mut avx2_high = <Avx2Complex as SimdRegister<Complex<f32>>>::zeroed();
+            let mut avx512 = <Avx512Complex as SimdRegister<Complex<f32>>>::zeroed();
+
+            for (a, b) in l1.chunks_exact(LANES).zip(l2.chunks_exact(LANES)) {
+                let a_low =
+                    <Avx2Complex as SimdRegister<Complex<f32>>>::load(a.as_ptr());
+                let b_low =
+                    <Avx2Complex as SimdRegister<Complex<f32>>>::load(b.as_ptr());
+                let a_high =
+                    <Avx2Complex as SimdRegister<Complex<f32>>>::load(a[4..].as_ptr());
+                let b_high =
+                    <Avx2Complex as SimdRegister<Complex<f32>>>::load(b[4..].as_ptr());
+                avx2_low = <Avx2Complex as SimdRegister<Complex<f32>>>::fmadd(
+                    a_low, b_low, avx2_low,
+                );
+                avx2_high = <Avx2Complex as SimdRegister<Complex<f32>>>::fmadd(
+                    a_high, b_high, avx2_high,
+                );
+
+                let a = <Avx512Complex as SimdRegister<Complex<f32>>>::load(a.as_ptr());
+                let b = <Avx512Complex as SimdRegister<Complex<f32>>>::load(b.as_ptr());
+                avx512 =
+                    <Avx512Complex as SimdRegister<Complex<f32>>>::fmadd(a, b, avx512);
+            }
+
+            let mut avx2 = [Complex::new(0.0, 0.0); LANES];
+            <Avx2Complex as SimdRegister<Complex<f32>>>::write(
+                avx2.as_mut_ptr(),
+                avx2_low,
+            );
+            <Avx2Complex as SimdRegister<Complex<f32>>>::write(
+                avx2[4..].as_mut_ptr(),
+                avx2_high,
+            );
+
+            let mut actual = [Complex::new(0.0, 0.0); LANES];
+            <Avx512Complex as SimdRegister<Complex<f32>>>::write(
+                actual.as_mut_ptr(),
+                avx512,
+            );
+
+            (avx2, actual)
+        };
+
+        assert_eq!(avx512, avx2);
+    }
+
+    #[test]
+    fn test_avx512_complex_f32_filled_and_conjugate() {
+        let value = Complex::new(1.5, -2.25);
+
+        let mut actual = [Complex::new(0.0, 0.0); LANES];
+        unsafe {
+            let reg = <Avx512Complex as SimdRegister<Complex<f32>>>::filled(value);
+            let reg = <Avx512Complex as ComplexOps<f32>>::conjugate(reg);
+            <Avx512Complex as SimdRegister<Complex<f32>>>::write(
+                actual.as_mut_ptr(),
+                reg,
+            );
+        }
+        assert_eq!(actual, [value.conj(); LANES]);
+    }
+
+    #[test]
+    fn test_avx512_complex_f32_sum_to_value() {
+        let values: [Complex<f32>; LANES] =
+            core::array::from_fn(|i| Complex::new(i as f32 + 1.0, -(i as f32) * 2.0));
+
+        let actual = unsafe {
+            let reg =
+                <Avx512Complex as SimdRegister<Complex<f32>>>::load(values.as_ptr());
+            <Avx512Complex as SimdRegister<Complex<f32>>>::sum_to_value(reg)
+        };
+        assert_eq!(actual, values.iter().sum());
+    }
+
+    type Avx512CmpOp = unsafe fn(Avx512Register, Avx512Register) -> Avx512Register;
+    type FallbackCmpOp = unsafe fn(Complex<f32>, Complex<f32>) -> Complex<f32>;
+
+    fn cmp_values() -> (Vec<Complex<f32>>, Vec<Complex<f32>>) {
+        let values = [
+            Complex::new(1.0, 2.0),
+            Complex::new(2.0, 1.0),
+            Complex::new(-1.0, -2.0),
+            Complex::new(1.0, -2.0),
+            Complex::new(0.0, 0.0),
+            Complex::new(0.0, -3.0),
+            Complex::new(f32::NAN, 1.0),
+            Complex::new(f32::INFINITY, 0.0),
+        ];
+
+        // Every value compared with every value, including those of equal magnitude.
+        let mut l1 = Vec::new();
+        let mut l2 = Vec::new();
+        for a in values {
+            for b in values {
+                l1.push(a);
+                l2.push(b);
+            }
+        }
+
+        let (s1, s2) = get_sample_vectors::<f32>(LANES * 64);
+        l1.extend_from_slice(&s1);
+        l2.extend_from_slice(&s2);
+        (l1, l2)
+    }
+
+    fn to_bits(value: Complex<f32>) -> (u32, u32) {
+        (value.re.to_bits(), value.im.to_bits())
+    }
+
+    #[test]
+    fn test_avx512_complex_f32_cmp_matches_fallback() {
+        let (l1, l2) = cmp_values();
+
+        let ops: [(&str, Avx512CmpOp, FallbackCmpOp); 8] = [
+            (
+                "max",
+                <Avx512Complex as SimdRegister<Complex<f32>>>::max,
+                <FallbackComplex as SimdRegister<Complex<f32>>>::max,
+            ),
+            (
+                "min",
+                <Avx512Complex as SimdRegister<Complex<f32>>>::min,
+                <FallbackComplex as SimdRegister<Complex<f32>>>::min,
+            ),
+            (
+                "eq",
+                <Avx512Complex as SimdRegister<Complex<f32>>>::eq,
+                <FallbackComplex as SimdRegister<Complex<f32>>>::eq,
+            ),
+            (
+                "neq",
+                <Avx512Complex as SimdRegister<Complex<f32>>>::neq,
+                <FallbackComplex as SimdRegister<Complex<f32>>>::neq,
+            ),
+            (
+                "lt",
+                <Avx512Complex as SimdRegister<Complex<f32>>>::lt,
+                <FallbackComplex as SimdRegister<Complex<f32>>>::lt,
+            ),
+            (
+                "lte",
+                <Avx512Complex as SimdRegister<Complex<f32>>>::lte,
+                <FallbackComplex as SimdRegister<Complex<f32>>>::lte,
+            ),
+            (
+                "gt",
+                <Avx512Complex as SimdRegister<Complex<f32>>>::gt,
+                <FallbackComplex as SimdRegister<Complex<f32>>>::gt,
+            ),
+            (
+                "gte",
+                <Avx512Complex as SimdRegister<Complex<f32>>>::gte,
+                <FallbackComplex as SimdRegister<Complex<f32>>>::gte,
+            ),
+        ];
+
+        for (name, avx512_op, fallback_op) in ops {
+            let mut actual = vec![Complex::new(0.0, 0.0); l1.len()];
+            for (offset, (a, b)) in l1
+                .chunks_exact(LANES)
+                .zip(l2.chunks_exact(LANES))
+                .enumerate()
+            {
+                unsafe {
+                    let a =
+                        <Avx512Complex as SimdRegister<Complex<f32>>>::load(a.as_ptr());
+                    let b =
+                        <Avx512Complex as SimdRegister<Complex<f32>>>::load(b.as_ptr());
+                    <Avx512Complex as SimdRegister<Complex<f32>>>::write(
+                        actual[offset * LANES..].as_mut_ptr(),
+                        avx512_op(a, b),
+                    );
+                }
+            }
+
+            for (i, (a, b)) in l1.iter().zip(&l2).enumerate() {
+                let expected = unsafe { fallback_op(*a, *b) };
+                // NaN values are carried through by max and min so compare bitwise.
+                assert_eq!(
+                    to_bits(actual[i]),
+                    to_bits(expected),
+                    "{name} value mismatch at {i} {a:?} {b:?}: {:?} vs {expected:?}",
+                    actual[i],
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_avx512_complex_f32_select_matches_fallback() {
+        // A mask with only its imaginary component set is not the zero value.
+        let mask = [
+            Complex::new(0.0, 0.0),
+            Complex::new(1.0, 0.0),
+            Complex::new(0.0, 1.0),
+        ]
+        .into_iter()
+        .cycle()
+        .take(LANES * 4)
+        .collect::<Vec<_>>();
+        let (l1, l2) = get_sample_vectors::<f32>(LANES * 4);
+
+        for ((m, a), b) in mask
+            .chunks_exact(LANES)
+            .zip(l1.chunks_exact(LANES))
+            .zip(l2.chunks_exact(LANES))
+        {
+            let mut actual = [Complex::new(0.0, 0.0); LANES];
+            unsafe {
+                let mask =
+                    <Avx512Complex as SimdRegister<Complex<f32>>>::load(m.as_ptr());
+                let l1 = <Avx512Complex as SimdRegister<Complex<f32>>>::load(a.as_ptr());
+                let l2 = <Avx512Complex as SimdRegister<Complex<f32>>>::load(b.as_ptr());
+                <Avx512Complex as SimdRegister<Complex<f32>>>::write(
+                    actual.as_mut_ptr(),
+                    <Avx512Complex as SimdRegister<Complex<f32>>>::select(mask, l1, l2),
+                );
+            }
+
+            for i in 0..LANES {
+                let expected = unsafe {
+                    <FallbackComplex as SimdRegister<Complex<f32>>>::select(
+                        m[i], a[i], b[i],
+                    )
+                };
+                assert_eq!(actual[i], expected, "select value mismatch at {i}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_avx512_complex_f32_max_min_to_value_matches_fallback() {
+        let (l1, l2) = cmp_values();
+
+        for values in l1.chunks_exact(LANES).chain(l2.chunks_exact(LANES)) {
+            let (max, min) = unsafe {
+                let reg =
+                    <Avx512Complex as SimdRegister<Complex<f32>>>::load(values.as_ptr());
+                (
+                    <Avx512Complex as SimdRegister<Complex<f32>>>::max_to_value(reg),
+                    <Avx512Complex as SimdRegister<Complex<f32>>>::min_to_value(reg),
+                )
+            };
+
+            // The register is reduced by halves, `[0..4]` against `[4..8]` then pairwise.
+            let reduce = |op: FallbackCmpOp| unsafe {
+                let half: [Complex<f32>; 4] =
+                    core::array::from_fn(|i| op(values[i], values[i + 4]));
+                op(op(half[0], half[1]), op(half[2], half[3]))
+            };
+            let expected_max =
+                reduce(<FallbackComplex as SimdRegister<Complex<f32>>>::max);
+            let expected_min =
+                reduce(<FallbackComplex as SimdRegister<Complex<f32>>>::min);
+            assert_eq!(
+                to_bits(max),
+                to_bits(expected_max),
+                "max mismatch on {values:?}"
+            );
+            assert_eq!(
+                to_bits(min),
+                to_bits(expected_min),
+                "min mismatch on {values:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,326 @@
+use core::ops::Neg;
+
+use cfavml::danger::SimdRegister;
+use cfavml::math::Math;
+use num_complex::Complex;
+
+use crate::danger::ComplexOps;
+use crate::math::ComplexMath;
+
+/// Fallback SIMD-like operations over complex values.
+///
+/// Each register holds a single `Complex<T>` value, with each operation being performed
+/// by the scalar [ComplexMath] implementation.
+pub struct FallbackComplex;
+
+impl<T> SimdRegister<Complex<T>> for FallbackComplex
+where
+    T: Copy,
+    ComplexMath: Math<Complex<T>>,
+{
+    type Register = Complex<T>;
+
+    #[inline(always)]
+    unsafe fn load(mem: *const Complex<T>) -> Self::Register {
+        mem.read()
+    }
+
+    #[inline(always)]
+    unsafe fn filled(value: Complex<T>) -> Self::Register {
+        value
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed() -> Self::Register {
+        ComplexMath::zero()
+    }
+
+    #[inline(always)]
+    unsafe fn add(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        ComplexMath::add(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        ComplexMath::sub(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn mul(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        ComplexMath::mul(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn div(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        ComplexMath::div(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn fmadd(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        let res = ComplexMath::mul(l1, l2);
+        ComplexMath::add(res, acc)
+    }
+
+    #[inline(always)]
+    unsafe fn max(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        ComplexMath::cmp_max(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn min(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        ComplexMath::cmp_min(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn eq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        ComplexMath::cast_bool(ComplexMath::cmp_eq(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn neq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        ComplexMath::cast_bool(!ComplexMath::cmp_eq(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        ComplexMath::cast_bool(ComplexMath::cmp_lt(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn lte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        ComplexMath::cast_bool(ComplexMath::cmp_lte(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn gt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        ComplexMath::cast_bool(ComplexMath::cmp_gt(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        ComplexMath::cast_bool(ComplexMath::cmp_gte(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        if ComplexMath::cmp_eq(mask, ComplexMath::zero()) {
+            l2
+        } else {
+            l1
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn sum_to_value(reg: Self::Register) -> Complex<T> {
+        reg
+    }
+
+    #[inline(always)]
+    unsafe fn max_to_value(reg: Self::Register) -> Complex<T> {
+        reg
+    }
+
+    #[inline(always)]
+    unsafe fn min_to_value(reg: Self::Register) -> Complex<T> {
+        reg
+    }
+
+    #[inline(always)]
+    unsafe fn write(mem: *mut Complex<T>, reg: Self::Register) {
+        mem.write(reg)
+    }
+}
+
+impl<T> ComplexOps<T> for FallbackComplex
+where
+    T: Copy + Neg<Output = T>,
+    ComplexMath: Math<Complex<T>>,
+{
+    #[inline(always)]
+    unsafe fn dup_real_values(reg: Self::Register) -> Self::Register {
+        Complex::new(reg.re, reg.re)
+    }
+
+    #[inline(always)]
+    unsafe fn dup_imag_values(reg: Self::Register) -> Self::Register {
+        Complex::new(reg.im, reg.im)
+    }
+
+    #[inline(always)]
+    unsafe fn swap_complex(reg: Self::Register) -> Self::Register {
+        Complex::new(reg.im, reg.re)
+    }
+
+    #[inline(always)]
+    unsafe fn conjugate(reg: Self::Register) -> Self::Register {
+        Complex::new(reg.re, -reg.im)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)(a-bi) = a² + b²`
+    unsafe fn write_squared_norm(mem: *mut T, reg: Self::Register) {
+        let conj = <Self as ComplexOps<T>>::conjugate(reg);
+        mem.write(ComplexMath::mul(reg, conj).re)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{get_sample_vectors, IsClose};
+
+    macro_rules! define_fallback_test {
+        ($t:ident) => {
+            paste::paste! {
+                unsafe fn [<apply_dense_op_ $t>](
+                    l1: &[Complex<$t>],
+                    l2: &[Complex<$t>],
+                    op: unsafe fn(
+                        cfavml::danger::DenseLane<Complex<$t>>,
+                        cfavml::danger::DenseLane<Complex<$t>>,
+                    ) -> cfavml::danger::DenseLane<Complex<$t>>,
+                ) -> Vec<Complex<$t>> {
+                    let mut result = vec![ComplexMath::zero(); l1.len()];
+                    let chunks = l1.chunks_exact(8).zip(l2.chunks_exact(8));
+                    for (i, (a, b)) in chunks.enumerate() {
+                        let a = <FallbackComplex as SimdRegister<Complex<$t>>>::load_dense(a.as_ptr());
+                        let b = <FallbackComplex as SimdRegister<Complex<$t>>>::load_dense(b.as_ptr());
+                        <FallbackComplex as SimdRegister<Complex<$t>>>::write_dense(
+                            result[i * 8..].as_mut_ptr(),
+                            op(a, b),
+                        );
+                    }
+                    result
+                }
+
+                fn [<check_ $t>](
+                    name: &str,
+                    l1: &[Complex<$t>],
+                    l2: &[Complex<$t>],
+                    actual: &[Complex<$t>],
+                    expected_op: fn(Complex<$t>, Complex<$t>) -> Complex<$t>,
+                ) {
+                    for (i, (a, b)) in l1.iter().zip(l2).enumerate() {
+                        let expected = expected_op(*a, *b);
+                        assert!(
+                            actual[i].is_close(&expected),
+                            "{name} value mismatch at {i} {:?} vs {expected:?}",
+                            actual[i],
+                        );
+                    }
+                }
+
+                #[test]
+                fn [<test_fallback_complex_ $t _arithmetic>]() {
+                    let (l1, l2) = get_sample_vectors::<$t>(8 * 64);
+
+                    unsafe {
+                        let actual = [<apply_dense_op_ $t>](&l1, &l2, <FallbackComplex as SimdRegister<Complex<$t>>>::add_dense);
+                        [<check_ $t>]("add", &l1, &l2, &actual, |a, b| a + b);
+
+                        let actual = [<apply_dense_op_ $t>](&l1, &l2, <FallbackComplex as SimdRegister<Complex<$t>>>::sub_dense);
+                        [<check_ $t>]("sub", &l1, &l2, &actual, |a, b| a - b);
+
+                        let actual = [<apply_dense_op_ $t>](&l1, &l2, <FallbackComplex as SimdRegister<Complex<$t>>>::mul_dense);
+                        [<check_ $t>]("mul", &l1, &l2, &actual, |a, b| a * b);
+
+                        let actual = [<apply_dense_op_ $t>](&l1, &l2, <FallbackComplex as SimdRegister<Complex<$t>>>::div_dense);
+                        [<check_ $t>]("div", &l1, &l2, &actual, |a, b| a / b);
+
+                        let actual = [<apply_dense_op_ $t>](&l1, &l2, <FallbackComplex as SimdRegister<Complex<$t>>>::max_dense);
+                        [<check_ $t>]("max", &l1, &l2, &actual, |a, b| {
+                            if a.norm_sqr() >= b.norm_sqr() { a } else { b }
+                        });
+
+                        let actual = [<apply_dense_op_ $t>](&l1, &l2, <FallbackComplex as SimdRegister<Complex<$t>>>::min_dense);
+                        [<check_ $t>]("min", &l1, &l2, &actual, |a, b| {
+                            if a.norm_sqr() <= b.norm_sqr() { a } else { b }
+                        });
+                    }
+                }
+
+                #[test]
+                fn [<test_fallback_complex_ $t _fmadd>]() {
+                    let (l1, l2) = get_sample_vectors::<$t>(8 * 16);
+
+                    let mut acc = unsafe { <FallbackComplex as SimdRegister<Complex<$t>>>::zeroed_dense() };
+                    for (a, b) in l1.chunks_exact(8).zip(l2.chunks_exact(8)) {
+                        unsafe {
+                            let a = <FallbackComplex as SimdRegister<Complex<$t>>>::load_dense(a.as_ptr());
+                            let b = <FallbackComplex as SimdRegister<Complex<$t>>>::load_dense(b.as_ptr());
+                            acc = <FallbackComplex as SimdRegister<Complex<$t>>>::fmadd_dense(a, b, acc);
+                        }
+                    }
+                    let actual = unsafe {
+                        let reg = <FallbackComplex as SimdRegister<Complex<$t>>>::sum_to_register(acc);
+                        <FallbackComplex as SimdRegister<Complex<$t>>>::sum_to_value(reg)
+                    };
+
+                    let expected = l1
+                        .iter()
+                        .zip(&l2)
+                        .fold(Complex::new(0.0, 0.0), |acc, (a, b)| acc + a * b);
+                    assert!(
+                        actual.is_close(&expected),
+                        "fmadd value mismatch {actual:?} vs {expected:?}",
+                    );
+                }
+
+                #[test]
+                fn [<test_fallback_complex_ $t _shuffles>]() {
+                    let value = Complex::new(1.5 as $t, -2.0);
+
+                    unsafe {
+                        assert_eq!(
+                            <FallbackComplex as ComplexOps<$t>>::dup_real_values(value),
+                            Complex::new(1.5, 1.5),
+                        );
+                        assert_eq!(
+                            <FallbackComplex as ComplexOps<$t>>::dup_imag_values(value),
+                            Complex::new(-2.0, -2.0),
+                        );
+                        assert_eq!(
+                            <FallbackComplex as ComplexOps<$t>>::swap_complex(value),
+                            Complex::new(-2.0, 1.5),
+                        );
+                        assert_eq!(
+                            <FallbackComplex as ComplexOps<$t>>::conjugate(value),
+                            value.conj(),
+                        );
+                    }
+                }
+
+                #[test]
+                fn [<test_fallback_complex_ $t _cmp_select>]() {
+                    let small = Complex::new(1.0 as $t, 1.0);
+                    let large = Complex::new(-3.0 as $t, 2.0);
+                    let zero = Complex::new(0.0 as $t, 0.0);
+                    let one = Complex::new(1.0 as $t, 0.0);
+
+                    unsafe {
+                        assert_eq!(<FallbackComplex as SimdRegister<Complex<$t>>>::eq(small, small), one);
+                        assert_eq!(<FallbackComplex as SimdRegister<Complex<$t>>>::neq(small, small), zero);
+                        assert_eq!(<FallbackComplex as SimdRegister<Complex<$t>>>::lt(small, large), one);
+                        assert_eq!(<FallbackComplex as SimdRegister<Complex<$t>>>::lte(large, small), zero);
+                        assert_eq!(<FallbackComplex as SimdRegister<Complex<$t>>>::gt(large, small), one);
+                        assert_eq!(<FallbackComplex as SimdRegister<Complex<$t>>>::gte(small, large), zero);
+
+                        assert_eq!(<FallbackComplex as SimdRegister<Complex<$t>>>::select(one, small, large), small);
+                        assert_eq!(<FallbackComplex as SimdRegister<Complex<$t>>>::select(zero, small, large), large);
+                    }
+                }
+            }
+        };
+    }
+
+    define_fallback_test!(f32);
+    define_fallback_test!(f64);
+}
@@ -0,0 +1,736 @@
+use core::arch::aarch64::*;
+use core::mem;
+
+use cfavml::danger::SimdRegister;
+use cfavml::math::Math;
+use num_complex::Complex;
+
+use crate::danger::ComplexOps;
+use crate::math::ComplexMath;
+
+/// NEON enabled SIMD operations over complex values.
+///
+/// The interleaved complex multiply is built on the `vtrn` and `vrev` shuffles to split
+/// and swap the real and imaginary components, combined with the fused `vfmaq`
+/// multiply-add which is always available on aarch64.
+///
+/// Each `float32x4_t` register holds 2 `Complex<f32>` values and each `float64x2_t`
+/// register holds a single `Complex<f64>` value.
+pub struct NeonComplex;
+
+impl SimdRegister<Complex<f32>> for NeonComplex {
+    type Register = float32x4_t;
+
+    #[inline(always)]
+    unsafe fn load(mem: *const Complex<f32>) -> Self::Register {
+        vld1q_f32(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn filled(value: Complex<f32>) -> Self::Register {
+        let values = [value.re, value.im, value.re, value.im];
+        vld1q_f32(values.as_ptr())
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed() -> Self::Register {
+        vdupq_n_f32(0.0)
+    }
+
+    #[inline(always)]
+    unsafe fn add(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        vaddq_f32(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        vsubq_f32(l1, l2)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)(c+di) = (ac-bd) + (ad+bc)i`
+    unsafe fn mul(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let real = <Self as ComplexOps<f32>>::dup_real_values(l1);
+        let imag = <Self as ComplexOps<f32>>::dup_imag_values(l1);
+        let swapped = <Self as ComplexOps<f32>>::swap_complex(l2);
+
+        // [-b, b]
+        let imag = vnegq_f32(<Self as ComplexOps<f32>>::conjugate(imag));
+
+        // [a*c - b*d, a*d + b*c]
+        vfmaq_f32(vmulq_f32(imag, swapped), real, l2)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)/(c+di) = (ac+bd)/(c²+d²) + (bc-ad)/(c²+d²) i`
+    ///
+    /// This is the same formula as the scalar division, so dividing by a zero complex
+    /// value produces the same `inf` and `NaN` components.
+    unsafe fn div(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let real = <Self as ComplexOps<f32>>::dup_real_values(l1);
+        let imag = <Self as ComplexOps<f32>>::dup_imag_values(l1);
+        let swapped = <Self as ComplexOps<f32>>::swap_complex(l2);
+
+        // [a, -a]
+        let real = <Self as ComplexOps<f32>>::conjugate(real);
+
+        // [b*d + a*c, b*c - a*d]
+        let numerator = vfmaq_f32(vmulq_f32(imag, swapped), real, l2);
+
+        // [c² + d², d² + c²]
+        let squared = vmulq_f32(l2, l2);
+        let norm = vaddq_f32(squared, <Self as ComplexOps<f32>>::swap_complex(squared));
+
+        vdivq_f32(numerator, norm)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)(c+di) + acc`
+    unsafe fn fmadd(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        let product = <Self as SimdRegister<Complex<f32>>>::mul(l1, l2);
+        <Self as SimdRegister<Complex<f32>>>::add(product, acc)
+    }
+
+    #[inline(always)]
+    /// Selects the value with the largest magnitude, `l2` if the magnitudes are unordered.
+    unsafe fn max(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vcgeq_f32(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        vbslq_f32(mask, l1, l2)
+    }
+
+    #[inline(always)]
+    /// Selects the value with the smallest magnitude, `l2` if the magnitudes are unordered.
+    unsafe fn min(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vcleq_f32(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        vbslq_f32(mask, l1, l2)
+    }
+
+    #[inline(always)]
+    /// Both the real and imaginary components must be equal.
+    unsafe fn eq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vceqq_f32(l1, l2);
+        let mask = vandq_u32(mask, vrev64q_u32(mask));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    /// Either the real or imaginary components must not be equal.
+    unsafe fn neq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vceqq_f32(l1, l2);
+        let mask = vmvnq_u32(vandq_u32(mask, vrev64q_u32(mask)));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vcltq_f32(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn lte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vcleq_f32(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn gt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vcgtq_f32(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vcgeq_f32(f32_norm_sqr(l1), f32_norm_sqr(l2));
+        f32_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    /// Selects `l2` where the mask value is the zero complex value, otherwise `l1`.
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = vceqzq_f32(mask);
+        let is_zero = vandq_u32(is_zero, vrev64q_u32(is_zero));
+        vbslq_f32(is_zero, l2, l1)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_to_value(reg: Self::Register) -> Complex<f32> {
+        // [re0 + re1, im0 + im1]
+        let sum = vadd_f32(vget_low_f32(reg), vget_high_f32(reg));
+        Complex::new(vget_lane_f32::<0>(sum), vget_lane_f32::<1>(sum))
+    }
+
+    #[inline(always)]
+    unsafe fn max_to_value(reg: Self::Register) -> Complex<f32> {
+        let [a, b]: [Complex<f32>; 2] = mem::transmute(reg);
+        ComplexMath::cmp_max(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn min_to_value(reg: Self::Register) -> Complex<f32> {
+        let [a, b]: [Complex<f32>; 2] = mem::transmute(reg);
+        ComplexMath::cmp_min(a, b)
+    }
+
+    #[inline(always)]
+    unsafe fn write(mem: *mut Complex<f32>, reg: Self::Register) {
+        vst1q_f32(mem.cast(), reg)
+    }
+}
+
+impl ComplexOps<f32> for NeonComplex {
+    #[inline(always)]
+    unsafe fn dup_real_values(reg: Self::Register) -> Self::Register {
+        vtrn1q_f32(reg, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn dup_imag_values(reg: Self::Register) -> Self::Register {
+        vtrn2q_f32(reg, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn swap_complex(reg: Self::Register) -> Self::Register {
+        vrev64q_f32(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn conjugate(reg: Self::Register) -> Self::Register {
+        let sign_mask = [0, 0x8000_0000, 0, 0x8000_0000u32];
+        let sign_mask = vld1q_u32(sign_mask.as_ptr());
+        vreinterpretq_f32_u32(veorq_u32(vreinterpretq_u32_f32(reg), sign_mask))
+    }
+
+    #[inline(always)]
+    unsafe fn write_squared_norm(mem: *mut f32, reg: Self::Register) {
+        let squared = vmulq_f32(reg, reg);
+        // [n0, n1, n0, n1]
+        let norm = vpaddq_f32(squared, squared);
+        vst1_f32(mem, vget_low_f32(norm))
+    }
+}
+
+impl SimdRegister<Complex<f64>> for NeonComplex {
+    type Register = float64x2_t;
+
+    #[inline(always)]
+    unsafe fn load(mem: *const Complex<f64>) -> Self::Register {
+        vld1q_f64(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn filled(value: Complex<f64>) -> Self::Register {
+        let values = [value.re, value.im];
+        vld1q_f64(values.as_ptr())
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed() -> Self::Register {
+        vdupq_n_f64(0.0)
+    }
+
+    #[inline(always)]
+    unsafe fn add(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        vaddq_f64(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        vsubq_f64(l1, l2)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)(c+di) = (ac-bd) + (ad+bc)i`
+    unsafe fn mul(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let real = <Self as ComplexOps<f64>>::dup_real_values(l1);
+        let imag = <Self as ComplexOps<f64>>::dup_imag_values(l1);
+        let swapped = <Self as ComplexOps<f64>>::swap_complex(l2);
+
+        // [-b, b]
+        let imag = vnegq_f64(<Self as ComplexOps<f64>>::conjugate(imag));
+
+        // [a*c - b*d, a*d + b*c]
+        vfmaq_f64(vmulq_f64(imag, swapped), real, l2)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)/(c+di) = (ac+bd)/(c²+d²) + (bc-ad)/(c²+d²) i`
+    ///
+    /// This is the same formula as the scalar division, so dividing by a zero complex
+    /// value produces the same `inf` and `NaN` components.
+    unsafe fn div(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let real = <Self as ComplexOps<f64>>::dup_real_values(l1);
+        let imag = <Self as ComplexOps<f64>>::dup_imag_values(l1);
+        let swapped = <Self as ComplexOps<f64>>::swap_complex(l2);
+
+        // [a, -a]
+        let real = <Self as ComplexOps<f64>>::conjugate(real);
+
+        // [b*d + a*c, b*c - a*d]
+        let numerator = vfmaq_f64(vmulq_f64(imag, swapped), real, l2);
+
+        // [c² + d², d² + c²]
+        let squared = vmulq_f64(l2, l2);
+        let norm = vaddq_f64(squared, <Self as ComplexOps<f64>>::swap_complex(squared));
+
+        vdivq_f64(numerator, norm)
+    }
+
+    #[inline(always)]
+    /// `(a+bi)(c+di) + acc`
+    unsafe fn fmadd(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        let product = <Self as SimdRegister<Complex<f64>>>::mul(l1, l2);
+        <Self as SimdRegister<Complex<f64>>>::add(product, acc)
+    }
+
+    #[inline(always)]
+    /// Selects the value with the largest magnitude, `l2` if the magnitudes are unordered.
+    unsafe fn max(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vcgeq_f64(f64_norm_sqr(l1), f64_norm_sqr(l2));
+        vbslq_f64(mask, l1, l2)
+    }
+
+    #[inline(always)]
+    /// Selects the value with the smallest magnitude, `l2` if the magnitudes are unordered.
+    unsafe fn min(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vcleq_f64(f64_norm_sqr(l1), f64_norm_sqr(l2));
+        vbslq_f64(mask, l1, l2)
+    }
+
+    #[inline(always)]
+    /// Both the real and imaginary components must be equal.
+    unsafe fn eq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vceqq_f64(l1, l2);
+        let mask = vandq_u64(mask, vextq_u64::<1>(mask, mask));
+        f64_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    /// Either the real or imaginary components must not be equal.
+    unsafe fn neq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vceqq_f64(l1, l2);
+        let mask = vandq_u64(mask, vextq_u64::<1>(mask, mask));
+        // There is no `vmvnq_u64`, so flip the mask via the `u32` lanes.
+        let mask = vreinterpretq_u64_u32(vmvnq_u32(vreinterpretq_u32_u64(mask)));
+        f64_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vcltq_f64(f64_norm_sqr(l1), f64_norm_sqr(l2));
+        f64_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn lte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vcleq_f64(f64_norm_sqr(l1), f64_norm_sqr(l2));
+        f64_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn gt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vcgtq_f64(f64_norm_sqr(l1), f64_norm_sqr(l2));
+        f64_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let mask = vcgeq_f64(f64_norm_sqr(l1), f64_norm_sqr(l2));
+        f64_cast_mask(mask)
+    }
+
+    #[inline(always)]
+    /// Selects `l2` where the mask value is the zero complex value, otherwise `l1`.
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = vceqzq_f64(mask);
+        let is_zero = vandq_u64(is_zero, vextq_u64::<1>(is_zero, is_zero));
+        vbslq_f64(is_zero, l2, l1)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_to_value(reg: Self::Register) -> Complex<f64> {
+        Complex::new(vgetq_lane_f64::<0>(reg), vgetq_lane_f64::<1>(reg))
+    }
+
+    #[inline(always)]
+    unsafe fn max_to_value(reg: Self::Register) -> Complex<f64> {
+        <Self as SimdRegister<Complex<f64>>>::sum_to_value(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn min_to_value(reg: Self::Register) -> Complex<f64> {
+        <Self as SimdRegister<Complex<f64>>>::sum_to_value(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write(mem: *mut Complex<f64>, reg: Self::Register) {
+        vst1q_f64(mem.cast(), reg)
+    }
+}
+
+impl ComplexOps<f64> for NeonComplex {
+    #[inline(always)]
+    unsafe fn dup_real_values(reg: Self::Register) -> Self::Register {
+        vtrn1q_f64(reg, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn dup_imag_values(reg: Self::Register) -> Self::Register {
+        vtrn2q_f64(reg, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn swap_complex(reg: Self::Register) -> Self::Register {
+        vextq_f64::<1>(reg, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn conjugate(reg: Self::Register) -> Self::Register {
+        let sign_mask = [0, 0x8000_0000_0000_0000u64];
+        let sign_mask = vld1q_u64(sign_mask.as_ptr());
+        vreinterpretq_f64_u64(veorq_u64(vreinterpretq_u64_f64(reg), sign_mask))
+    }
+
+    #[inline(always)]
+    unsafe fn write_squared_norm(mem: *mut f64, reg: Self::Register) {
+        let squared = vmulq_f64(reg, reg);
+        // [n0, n0]
+        let norm = vpaddq_f64(squared, squared);
+        vst1q_lane_f64::<0>(mem, norm)
+    }
+}
+
+#[inline(always)]
+/// Computes the squared magnitude `re² + im²` of each complex value, set in both its
+/// real and imaginary components.
+unsafe fn f32_norm_sqr(reg: float32x4_t) -> float32x4_t {
+    let squared = vmulq_f32(reg, reg);
+    vaddq_f32(squared, vrev64q_f32(squared))
+}
+
+#[inline(always)]
+/// Converts a comparison mask, set in both halves of each complex value, into the
+/// `1+0i` or `0+0i` values matching [ComplexMath].
+unsafe fn f32_cast_mask(mask: uint32x4_t) -> float32x4_t {
+    let one = [1.0, 0.0, 1.0, 0.0f32];
+    vreinterpretq_f32_u32(vandq_u32(
+        mask,
+        vreinterpretq_u32_f32(vld1q_f32(one.as_ptr())),
+    ))
+}
+
+#[inline(always)]
+/// Computes the squared magnitude `re² + im²` of the complex value, set in both its
+/// real and imaginary components.
+unsafe fn f64_norm_sqr(reg: float64x2_t) -> float64x2_t {
+    let squared = vmulq_f64(reg, reg);
+    vaddq_f64(squared, vextq_f64::<1>(squared, squared))
+}
+
+#[inline(always)]
+/// Converts a comparison mask, set in both halves of the complex value, into the
+/// `1+0i` or `0+0i` values matching [ComplexMath].
+unsafe fn f64_cast_mask(mask: uint64x2_t) -> float64x2_t {
+    let one = [1.0, 0.0f64];
+    vreinterpretq_f64_u64(vandq_u64(
+        mask,
+        vreinterpretq_u64_f64(vld1q_f64(one.as_ptr())),
+    ))
+}
+
+#[cfg(all(test, target_feature = "neon"))]
+mod tests {
+    use super::*;
+    use crate::danger::FallbackComplex;
+    use crate::test_utils::{get_sample_vectors, IsClose};
+
+    macro_rules! define_fallback_cmp_test {
+        ($t:ident, $n:expr) => {
+            paste::paste! {
+                unsafe fn [<apply_op_ $t>](
+                    l1: &[Complex<$t>],
+                    l2: &[Complex<$t>],
+                    op: unsafe fn(
+                        <NeonComplex as SimdRegister<Complex<$t>>>::Register,
+                        <NeonComplex as SimdRegister<Complex<$t>>>::Register,
+                    ) -> <NeonComplex as SimdRegister<Complex<$t>>>::Register,
+                ) -> Vec<Complex<$t>> {
+                    let mut result = vec![Complex::new(0.0, 0.0); l1.len()];
+                    for (i, (a, b)) in l1.chunks_exact($n).zip(l2.chunks_exact($n)).enumerate() {
+                        let a = <NeonComplex as SimdRegister<Complex<$t>>>::load(a.as_ptr());
+                        let b = <NeonComplex as SimdRegister<Complex<$t>>>::load(b.as_ptr());
+                        <NeonComplex as SimdRegister<Complex<$t>>>::write(
+                            result[i * $n..].as_mut_ptr(),
+                            op(a, b),
+                        );
+                    }
+                    result
+                }
+
+                fn [<check_ $t>](
+                    name: &str,
+                    l1: &[Complex<$t>],
+                    l2: &[Complex<$t>],
+                    actual: &[Complex<$t>],
+                    expected_op: unsafe fn(Complex<$t>, Complex<$t>) -> Complex<$t>,
+                ) {
+                    for (i, (a, b)) in l1.iter().zip(l2).enumerate() {
+                        let expected = unsafe { expected_op(*a, *b) };
+                        assert!(
+                            actual[i].is_close(&expected),
+                            "{name} value mismatch at {i} {:?} vs {expected:?}",
+                            actual[i],
+                        );
+                    }
+                }
+
+                #[test]
+                fn [<test_neon_complex_ $t _arithmetic>]() {
+                    let (l1, l2) = get_sample_vectors::<$t>($n * 64);
+
+                    unsafe {
+                        let actual = [<apply_op_ $t>](&l1, &l2, <NeonComplex as SimdRegister<Complex<$t>>>::add);
+                        [<check_ $t>]("add", &l1, &l2, &actual, <FallbackComplex as SimdRegister<Complex<$t>>>::add);
+
+                        let actual = [<apply_op_ $t>](&l1, &l2, <NeonComplex as SimdRegister<Complex<$t>>>::sub);
+                        [<check_ $t>]("sub", &l1, &l2, &actual, <FallbackComplex as SimdRegister<Complex<$t>>>::sub);
+
+                        let actual = [<apply_op_ $t>](&l1, &l2, <NeonComplex as SimdRegister<Complex<$t>>>::mul);
+                        [<check_ $t>]("mul", &l1, &l2, &actual, <FallbackComplex as SimdRegister<Complex<$t>>>::mul);
+
+                        let actual = [<apply_op_ $t>](&l1, &l2, <NeonComplex as SimdRegister<Complex<$t>>>::div);
+                        [<check_ $t>]("div", &l1, &l2, &actual, <FallbackComplex as SimdRegister<Complex<$t>>>::div);
+                    }
+                }
+
+                #[test]
+                fn [<test_neon_complex_ $t _shuffles>]() {
+                    let (l1, _) = get_sample_vectors::<$t>($n);
+
+                    unsafe {
+                        let reg = <NeonComplex as SimdRegister<Complex<$t>>>::load(l1.as_ptr());
+
+                        let mut actual = [Complex::new(0.0, 0.0); $n];
+                        let conjugated = <NeonComplex as ComplexOps<$t>>::conjugate(reg);
+                        <NeonComplex as SimdRegister<Complex<$t>>>::write(actual.as_mut_ptr(), conjugated);
+                        let expected = l1.iter().map(|v| v.conj()).collect::<Vec<_>>();
+                        assert_eq!(actual.as_slice(), expected.as_slice());
+
+                        let swapped = <NeonComplex as ComplexOps<$t>>::swap_complex(reg);
+                        <NeonComplex as SimdRegister<Complex<$t>>>::write(actual.as_mut_ptr(), swapped);
+                        let expected = l1.iter().map(|v| Complex::new(v.im, v.re)).collect::<Vec<_>>();
+                        assert_eq!(actual.as_slice(), expected.as_slice());
+
+                        let filled = <NeonComplex as SimdRegister<Complex<$t>>>::filled(l1[0]);
+                        <NeonComplex as SimdRegister<Complex<$t>>>::write(actual.as_mut_ptr(), filled);
+                        assert_eq!(actual, [l1[0]; $n]);
+
+                        let sum = <NeonComplex as SimdRegister<Complex<$t>>>::sum_to_value(reg);
+                        let expected = l1.iter().fold(Complex::new(0.0, 0.0), |acc, v| acc + v);
+                        assert!(sum.is_close(&expected), "sum mismatch {sum:?} vs {expected:?}");
+                    }
+                }
+
+                fn [<$t _cmp_values>]() -> (Vec<Complex<$t>>, Vec<Complex<$t>>) {
+                    let values = [
+                        Complex::new(1.0, 2.0),
+                        Complex::new(2.0, 1.0),
+                        Complex::new(-1.0, -2.0),
+                        Complex::new(1.0, -2.0),
+                        Complex::new(0.0, 0.0),
+                        Complex::new(0.0, -3.0),
+                        Complex::new($t::NAN, 1.0),
+                        Complex::new($t::INFINITY, 0.0),
+                    ];
+
+                    // Every value compared with every value, including those of equal magnitude.
+                    let mut l1 = Vec::new();
+                    let mut l2 = Vec::new();
+                    for a in values {
+                        for b in values {
+                            l1.push(a);
+                            l2.push(b);
+                        }
+                    }
+
+                    let (s1, s2) = get_sample_vectors::<$t>($n * 64);
+                    l1.extend_from_slice(&s1);
+                    l2.extend_from_slice(&s2);
+                    (l1, l2)
+                }
+
+                #[test]
+                fn [<test_neon_complex_ $t _cmp>]() {
+                    let (l1, l2) = [<$t _cmp_values>]();
+
+                    type Ops = (
+                        &'static str,
+                        unsafe fn(
+                            <NeonComplex as SimdRegister<Complex<$t>>>::Register,
+                            <NeonComplex as SimdRegister<Complex<$t>>>::Register,
+                        ) -> <NeonComplex as SimdRegister<Complex<$t>>>::Register,
+                        unsafe fn(Complex<$t>, Complex<$t>) -> Complex<$t>,
+                    );
+                    let ops: [Ops; 8] = [
+                        ("max", <NeonComplex as SimdRegister<Complex<$t>>>::max, <FallbackComplex as SimdRegister<Complex<$t>>>::max),
+                        ("min", <NeonComplex as SimdRegister<Complex<$t>>>::min, <FallbackComplex as SimdRegister<Complex<$t>>>::min),
+                        ("eq", <NeonComplex as SimdRegister<Complex<$t>>>::eq, <FallbackComplex as SimdRegister<Complex<$t>>>::eq),
+                        ("neq", <NeonComplex as SimdRegister<Complex<$t>>>::neq, <FallbackComplex as SimdRegister<Complex<$t>>>::neq),
+                        ("lt", <NeonComplex as SimdRegister<Complex<$t>>>::lt, <FallbackComplex as SimdRegister<Complex<$t>>>::lt),
+                        ("lte", <NeonComplex as SimdRegister<Complex<$t>>>::lte, <FallbackComplex as SimdRegister<Complex<$t>>>::lte),
+                        ("gt", <NeonComplex as SimdRegister<Complex<$t>>>::gt, <FallbackComplex as SimdRegister<Complex<$t>>>::gt),
+                        ("gte", <NeonComplex as SimdRegister<Complex<$t>>>::gte, <FallbackComplex as SimdRegister<Complex<$t>>>::gte),
+                    ];
+
+                    for (name, op, expected_op) in ops {
+                        let actual = unsafe { [<apply_op_ $t>](&l1, &l2, op) };
+                        for (i, (a, b)) in l1.iter().zip(&l2).enumerate() {
+                            let expected = unsafe { expected_op(*a, *b) };
+                            // NaN values are carried through by max and min so compare bitwise.
+                            assert_eq!(
+                                (actual[i].re.to_bits(), actual[i].im.to_bits()),
+                                (expected.re.to_bits(), expected.im.to_bits()),
+                                "{name} value mismatch at {i} {a:?} {b:?}: {:?} vs {expected:?}",
+                                actual[i],
+                            );
+                        }
+                    }
+                }
+
+                #[test]
+                fn [<test_neon_complex_ $t _select_matches_fallback>]() {
+                    // A mask with only its imaginary component set is not the zero value.
+                    let mask = [
+                        Complex::new(0.0, 0.0),
+                        Complex::new(1.0, 0.0),
+                        Complex::new(0.0, 1.0),
+                    ]
+                    .into_iter()
+                    .cycle()
+                    .take($n * 6)
+                    .collect::<Vec<Complex<$t>>>();
+                    let (l1, l2) = get_sample_vectors::<$t>($n * 6);
+
+                    for ((m, a), b) in mask.chunks_exact($n).zip(l1.chunks_exact($n)).zip(l2.chunks_exact($n)) {
+                        let mut actual = [Complex::new(0.0, 0.0); $n];
+                        unsafe {
+                            let mask = <NeonComplex as SimdRegister<Complex<$t>>>::load(m.as_ptr());
+                            let l1 = <NeonComplex as SimdRegister<Complex<$t>>>::load(a.as_ptr());
+                            let l2 = <NeonComplex as SimdRegister<Complex<$t>>>::load(b.as_ptr());
+                            <NeonComplex as SimdRegister<Complex<$t>>>::write(
+                                actual.as_mut_ptr(),
+                                <NeonComplex as SimdRegister<Complex<$t>>>::select(mask, l1, l2),
+                            );
+                        }
+
+                        for i in 0..$n {
+                            let expected = unsafe {
+                                <FallbackComplex as SimdRegister<Complex<$t>>>::select(m[i], a[i], b[i])
+                            };
+                            assert_eq!(actual[i], expected, "select value mismatch at {i}");
+                        }
+                    }
+                }
+
+                #[test]
+                fn [<test_neon_complex_ $t _max_min_to_value_matches_fallback>]() {
+                    let (l1, l2) = [<$t _cmp_values>]();
+
+                    for values in l1.chunks_exact($n).chain(l2.chunks_exact($n)) {
+                        let (max, min) = unsafe {
+                            let reg = <NeonComplex as SimdRegister<Complex<$t>>>::load(values.as_ptr());
+                            (
+                                <NeonComplex as SimdRegister<Complex<$t>>>::max_to_value(reg),
+                                <NeonComplex as SimdRegister<Complex<$t>>>::min_to_value(reg),
+                            )
+                        };
+
+                        let expected_max = values
+                            .iter()
+                            .copied()
+                            .reduce(|a, b| unsafe { <FallbackComplex as SimdRegister<Complex<$t>>>::max(a, b) })
+                            .unwrap();
+                        let expected_min = values
+                            .iter()
+                            .copied()
+                            .reduce(|a, b| unsafe { <FallbackComplex as SimdRegister<Complex<$t>>>::min(a, b) })
+                            .unwrap();
+                        assert_eq!(
+                            (max.re.to_bits(), max.im.to_bits()),
+                            (expected_max.re.to_bits(), expected_max.im.to_bits()),
+                            "max mismatch on {values:?}",
+                        );
+                        assert_eq!(
+                            (min.re.to_bits(), min.im.to_bits()),
+                            (expected_min.re.to_bits(), expected_min.im.to_bits()),
+                            "min mismatch on {values:?}",
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    define_fallback_cmp_test!(f32, 2);
+    define_fallback_cmp_test!(f64, 1);
+
+    #[test]
+    fn test_neon_complex_f32_select() {
+        let mask = [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+        let l1 = [Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+        let l2 = [Complex::new(-1.0, -2.0), Complex::new(-3.0, -4.0)];
+
+        let actual: [Complex<f32>; 2] = unsafe {
+            let mask = <NeonComplex as SimdRegister<Complex<f32>>>::load(mask.as_ptr());
+            let l1 = <NeonComplex as SimdRegister<Complex<f32>>>::load(l1.as_ptr());
+            let l2 = <NeonComplex as SimdRegister<Complex<f32>>>::load(l2.as_ptr());
+            mem::transmute(<NeonComplex as SimdRegister<Complex<f32>>>::select(
+                mask, l1, l2,
+            ))
+        };
+        assert_eq!(actual, [l2[0], l1[1]]);
+
+        // A mask with only its imaginary component set is not the zero value.
+        let mask = [Complex::new(0.0, 1.0), Complex::new(0.0, 0.0)];
+        let actual: [Complex<f32>; 2] = unsafe {
+            let mask = <NeonComplex as SimdRegister<Complex<f32>>>::load(mask.as_ptr());
+            let l1 = <NeonComplex as SimdRegister<Complex<f32>>>::load(l1.as_ptr());
+            let l2 = <NeonComplex as SimdRegister<Complex<f32>>>::load(l2.as_ptr());
+            mem::transmute(<NeonComplex as SimdRegister<Complex<f32>>>::select(
+                mask, l1, l2,
+            ))
+        };
+        assert_eq!(actual, [l1[0], l2[1]]);
+    }
+
+    #[test]
+    fn test_neon_complex_f32_max_min_to_value() {
+        let values = [Complex::new(1.0, -3.0), Complex::new(2.0, 2.0)];
+
+        let (max, min) = unsafe {
+            let reg = <NeonComplex as SimdRegister<Complex<f32>>>::load(values.as_ptr());
+            (
+                <NeonComplex as SimdRegister<Complex<f32>>>::max_to_value(reg),
+                <NeonComplex as SimdRegister<Complex<f32>>>::min_to_value(reg),
+            )
+        };
+        assert_eq!(max, values[0]);
+        assert_eq!(min, values[1]);
+    }
+}
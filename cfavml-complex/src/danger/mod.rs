@@ -0,0 +1,39 @@
+//! The unsafe SIMD register implementations over complex values.
+//!
+//! These follow the same design as `cfavml::danger`, each register type implements
+//! [SimdRegister](cfavml::danger::SimdRegister) over `Complex<T>` values, with the
+//! additional shuffles complex arithmetic requires provided by [ComplexOps].
+
+mod complex_ops;
+mod export_dot;
+mod export_squared_norm;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod impl_avx2;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+mod impl_avx512;
+mod impl_fallback;
+#[cfg(target_arch = "aarch64")]
+mod impl_neon;
+mod op_conjugate;
+mod op_dot;
+mod op_squared_norm;
+
+pub use self::complex_ops::ComplexOps;
+pub use self::export_dot::*;
+pub use self::export_squared_norm::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::impl_avx2::*;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+pub use self::impl_avx512::*;
+pub use self::impl_fallback::*;
+#[cfg(target_arch = "aarch64")]
+pub use self::impl_neon::*;
+pub use self::op_conjugate::*;
+pub use self::op_dot::*;
+pub use self::op_squared_norm::*;
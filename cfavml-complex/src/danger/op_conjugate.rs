@@ -0,0 +1,136 @@
+use core::ops::Neg;
+
+use cfavml::danger::SimdRegister;
+use num_complex::Complex;
+
+use crate::danger::ComplexOps;
+
+#[inline(always)]
+/// A generic complex conjugate implementation, negating the imaginary component of
+/// each element of `a` and writing the result to `result`.
+///
+/// # Panics
+///
+/// If `a` and `result` are not equal in length.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+pub unsafe fn generic_conjugate_vector<T, R>(a: &[Complex<T>], result: &mut [Complex<T>])
+where
+    T: Copy + Neg<Output = T>,
+    R: ComplexOps<T>,
+{
+    assert_eq!(
+        a.len(),
+        result.len(),
+        "Input vector and result vector size do not match"
+    );
+
+    let len = a.len();
+    let a_ptr = a.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(a_ptr.add(i));
+        let conj = apply_conjugate_dense::<T, R>(l1);
+        R::write_dense(result_ptr.add(i), conj);
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(a_ptr.add(i));
+        R::write(result_ptr.add(i), R::conjugate(l1));
+
+        i += R::elements_per_lane();
+    }
+
+    while i < len {
+        let value = *a.get_unchecked(i);
+        *result.get_unchecked_mut(i) = Complex::new(value.re, -value.im);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+pub(crate) unsafe fn apply_conjugate_dense<T, R>(
+    lane: cfavml::danger::DenseLane<<R as SimdRegister<Complex<T>>>::Register>,
+) -> cfavml::danger::DenseLane<<R as SimdRegister<Complex<T>>>::Register>
+where
+    T: Copy,
+    R: ComplexOps<T>,
+{
+    cfavml::danger::DenseLane {
+        a: R::conjugate(lane.a),
+        b: R::conjugate(lane.b),
+        c: R::conjugate(lane.c),
+        d: R::conjugate(lane.d),
+        e: R::conjugate(lane.e),
+        f: R::conjugate(lane.f),
+        g: R::conjugate(lane.g),
+        h: R::conjugate(lane.h),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::FallbackComplex;
+    use crate::test_utils::get_sample_vectors;
+
+    macro_rules! define_conjugate_test {
+        ($t:ident, $name:ident, $register:ty) => {
+            #[test]
+            fn $name() {
+                for len in [0, 1, 3, 17, 137, 1043] {
+                    let (a, _) = get_sample_vectors::<$t>(len);
+
+                    let mut result = vec![Complex::new(0.0, 0.0); len];
+                    unsafe {
+                        generic_conjugate_vector::<$t, $register>(&a, &mut result)
+                    };
+
+                    let expected = a.iter().map(|v| v.conj()).collect::<Vec<_>>();
+                    assert_eq!(result, expected, "conjugate mismatch on length {len}");
+                }
+            }
+        };
+    }
+
+    define_conjugate_test!(f32, test_fallback_conjugate_f32, FallbackComplex);
+    define_conjugate_test!(f64, test_fallback_conjugate_f64, FallbackComplex);
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2",
+        target_feature = "fma"
+    ))]
+    define_conjugate_test!(f32, test_avx2_conjugate_f32, crate::danger::Avx2Complex);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2",
+        target_feature = "fma"
+    ))]
+    define_conjugate_test!(f64, test_avx2_conjugate_f64, crate::danger::Avx2Complex);
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    define_conjugate_test!(f32, test_neon_conjugate_f32, crate::danger::NeonComplex);
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    define_conjugate_test!(f64, test_neon_conjugate_f64, crate::danger::NeonComplex);
+
+    #[test]
+    #[should_panic]
+    fn test_conjugate_length_mismatch_panics() {
+        let a = [Complex::new(1.0f32, 2.0)];
+        let mut result = [Complex::new(0.0f32, 0.0); 2];
+        unsafe { generic_conjugate_vector::<f32, FallbackComplex>(&a, &mut result) };
+    }
+}
@@ -0,0 +1,160 @@
+use core::ops::Neg;
+
+use cfavml::math::Math;
+use num_complex::Complex;
+
+use crate::danger::op_conjugate::apply_conjugate_dense;
+use crate::danger::ComplexOps;
+use crate::math::ComplexMath;
+
+#[inline(always)]
+/// A generic complex dot product implementation, computing the Hermitian inner
+/// product `sum(a[i] * conj(b[i]))` of vectors `a` and `b`.
+///
+/// # Panics
+///
+/// If `a` and `b` are not equal in length.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+pub unsafe fn generic_complex_dot<T, R>(a: &[Complex<T>], b: &[Complex<T>]) -> Complex<T>
+where
+    T: Copy + Neg<Output = T>,
+    R: ComplexOps<T>,
+    ComplexMath: Math<Complex<T>>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+    let len = a.len();
+    let a_ptr = a.as_ptr();
+    let b_ptr = b.as_ptr();
+
+    let offset_from = len % R::elements_per_dense();
+
+    let mut total = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(a_ptr.add(i));
+        let l2 = apply_conjugate_dense::<T, R>(R::load_dense(b_ptr.add(i)));
+        total = R::fmadd_dense(l1, l2, total);
+
+        i += R::elements_per_dense();
+    }
+
+    let mut total = R::sum_to_register(total);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(a_ptr.add(i));
+        let l2 = R::conjugate(R::load(b_ptr.add(i)));
+        total = R::fmadd(l1, l2, total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+
+    while i < len {
+        let a = *a.get_unchecked(i);
+        let b = *b.get_unchecked(i);
+        let b = Complex::new(b.re, -b.im);
+        total = ComplexMath::add(total, ComplexMath::mul(a, b));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::FallbackComplex;
+    use crate::test_utils::{get_sample_vectors, IsClose};
+
+    fn simple_complex_dot<T>(a: &[Complex<T>], b: &[Complex<T>]) -> Complex<T>
+    where
+        T: Copy + Neg<Output = T>,
+        ComplexMath: Math<Complex<T>>,
+    {
+        let mut total = ComplexMath::zero();
+        for (a, b) in a.iter().zip(b) {
+            let b = Complex::new(b.re, -b.im);
+            total = ComplexMath::add(total, ComplexMath::mul(*a, b));
+        }
+        total
+    }
+
+    macro_rules! define_complex_dot_test {
+        ($t:ident, $name:ident, $register:ty) => {
+            #[test]
+            fn $name() {
+                for len in [0, 1, 3, 17, 137, 1043] {
+                    let (a, b) = get_sample_vectors::<$t>(len);
+
+                    let value = unsafe { generic_complex_dot::<$t, $register>(&a, &b) };
+                    let expected = simple_complex_dot(&a, &b);
+                    assert!(
+                        value.is_close(&expected),
+                        "value mismatch on length {len} {value:?} vs {expected:?}"
+                    );
+                }
+            }
+        };
+    }
+
+    define_complex_dot_test!(f32, test_fallback_complex_dot_f32, FallbackComplex);
+    define_complex_dot_test!(f64, test_fallback_complex_dot_f64, FallbackComplex);
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2",
+        target_feature = "fma"
+    ))]
+    define_complex_dot_test!(f32, test_avx2_complex_dot_f32, crate::danger::Avx2Complex);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2",
+        target_feature = "fma"
+    ))]
+    define_complex_dot_test!(f64, test_avx2_complex_dot_f64, crate::danger::Avx2Complex);
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx512f",
+        feature = "stable-avx512"
+    ))]
+    define_complex_dot_test!(
+        f32,
+        test_avx512_complex_dot_f32,
+        crate::danger::Avx512Complex
+    );
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    define_complex_dot_test!(f32, test_neon_complex_dot_f32, crate::danger::NeonComplex);
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    define_complex_dot_test!(f64, test_neon_complex_dot_f64, crate::danger::NeonComplex);
+
+    #[test]
+    fn test_complex_dot_conjugates_rhs() {
+        let a = [Complex::new(1.0f64, 2.0)];
+        let b = [Complex::new(3.0f64, 4.0)];
+
+        // (1+2i)(3-4i) = 3 - 4i + 6i + 8 = 11 + 2i
+        let value = unsafe { generic_complex_dot::<f64, FallbackComplex>(&a, &b) };
+        assert_eq!(value, Complex::new(11.0, 2.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_complex_dot_length_mismatch_panics() {
+        let a = [Complex::new(1.0f32, 2.0)];
+        let b = [Complex::new(0.0f32, 0.0); 2];
+        unsafe { generic_complex_dot::<f32, FallbackComplex>(&a, &b) };
+    }
+}
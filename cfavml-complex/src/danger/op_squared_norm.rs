@@ -0,0 +1,180 @@
+use core::ops::Neg;
+
+use cfavml::math::Math;
+use num_complex::Complex;
+
+use crate::danger::{generic_complex_dot, ComplexOps};
+use crate::math::ComplexMath;
+
+#[inline(always)]
+/// A generic complex squared norm implementation, writing the squared magnitude
+/// `re² + im²` of each element of `a` to `result`.
+///
+/// # Panics
+///
+/// If `a` and `result` are not equal in length.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+pub unsafe fn generic_complex_squared_norm<T, R>(a: &[Complex<T>], result: &mut [T])
+where
+    T: Copy + Neg<Output = T>,
+    R: ComplexOps<T>,
+    ComplexMath: Math<Complex<T>>,
+{
+    assert_eq!(
+        a.len(),
+        result.len(),
+        "Input vector and result vector size do not match"
+    );
+
+    let len = a.len();
+    let a_ptr = a.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let lane = R::load_dense(a_ptr.add(i));
+        let step = R::elements_per_lane();
+        R::write_squared_norm(result_ptr.add(i), lane.a);
+        R::write_squared_norm(result_ptr.add(i + step), lane.b);
+        R::write_squared_norm(result_ptr.add(i + step * 2), lane.c);
+        R::write_squared_norm(result_ptr.add(i + step * 3), lane.d);
+        R::write_squared_norm(result_ptr.add(i + step * 4), lane.e);
+        R::write_squared_norm(result_ptr.add(i + step * 5), lane.f);
+        R::write_squared_norm(result_ptr.add(i + step * 6), lane.g);
+        R::write_squared_norm(result_ptr.add(i + step * 7), lane.h);
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(a_ptr.add(i));
+        R::write_squared_norm(result_ptr.add(i), l1);
+
+        i += R::elements_per_lane();
+    }
+
+    while i < len {
+        let value = *a.get_unchecked(i);
+        let conj = Complex::new(value.re, -value.im);
+        *result.get_unchecked_mut(i) = ComplexMath::mul(value, conj).re;
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic complex squared norm sum implementation, computing the total power
+/// `sum(re² + im²)` of the vector `a`.
+///
+/// This is the real component of the Hermitian inner product of `a` with itself,
+/// the imaginary component of which is always zero.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+pub unsafe fn generic_complex_squared_norm_sum<T, R>(a: &[Complex<T>]) -> T
+where
+    T: Copy + Neg<Output = T>,
+    R: ComplexOps<T>,
+    ComplexMath: Math<Complex<T>>,
+{
+    generic_complex_dot::<T, R>(a, a).re
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::FallbackComplex;
+    use crate::test_utils::{get_sample_vectors, IsClose};
+
+    macro_rules! define_squared_norm_test {
+        ($t:ident, $name:ident, $register:ty) => {
+            #[test]
+            fn $name() {
+                for len in [0, 1, 3, 17, 137, 1043] {
+                    let (a, _) = get_sample_vectors::<$t>(len);
+
+                    let mut result = vec![0.0; len];
+                    unsafe {
+                        generic_complex_squared_norm::<$t, $register>(&a, &mut result)
+                    };
+
+                    let expected = a.iter().map(|v| v.norm_sqr()).collect::<Vec<_>>();
+                    assert_eq!(result, expected, "squared norm mismatch on length {len}");
+
+                    let total =
+                        unsafe { generic_complex_squared_norm_sum::<$t, $register>(&a) };
+                    let expected = expected.iter().sum::<$t>();
+                    assert!(
+                        total.is_close(&expected),
+                        "squared norm sum mismatch on length {len} {total:?} vs {expected:?}"
+                    );
+                }
+            }
+        };
+    }
+
+    define_squared_norm_test!(f32, test_fallback_squared_norm_f32, FallbackComplex);
+    define_squared_norm_test!(f64, test_fallback_squared_norm_f64, FallbackComplex);
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2",
+        target_feature = "fma"
+    ))]
+    define_squared_norm_test!(
+        f32,
+        test_avx2_squared_norm_f32,
+        crate::danger::Avx2Complex
+    );
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2",
+        target_feature = "fma"
+    ))]
+    define_squared_norm_test!(
+        f64,
+        test_avx2_squared_norm_f64,
+        crate::danger::Avx2Complex
+    );
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx512f",
+        feature = "stable-avx512"
+    ))]
+    define_squared_norm_test!(
+        f32,
+        test_avx512_squared_norm_f32,
+        crate::danger::Avx512Complex
+    );
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    define_squared_norm_test!(
+        f32,
+        test_neon_squared_norm_f32,
+        crate::danger::NeonComplex
+    );
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    define_squared_norm_test!(
+        f64,
+        test_neon_squared_norm_f64,
+        crate::danger::NeonComplex
+    );
+
+    #[test]
+    #[should_panic]
+    fn test_squared_norm_length_mismatch_panics() {
+        let a = [Complex::new(1.0f32, 2.0)];
+        let mut result = [0.0f32; 2];
+        unsafe { generic_complex_squared_norm::<f32, FallbackComplex>(&a, &mut result) };
+    }
+}
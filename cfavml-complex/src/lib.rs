@@ -0,0 +1,9 @@
+#![doc = include_str!("../README.md")]
+
+pub mod danger;
+pub mod math;
+
+#[cfg(test)]
+mod test_utils;
+
+pub use num_complex::Complex;
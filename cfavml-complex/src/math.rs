@@ -0,0 +1,116 @@
+//! Scalar math operations over complex values.
+
+use cfavml::math::Math;
+use num_complex::Complex;
+
+/// Basic math operations over `Complex<f32>` and `Complex<f64>` values.
+///
+/// Complex numbers have no natural ordering, so all the ordering based operations
+/// (`cmp_lt`, `cmp_max`, etc...) compare the _magnitude_ of the values, where `min` is
+/// the value with the smallest possible magnitude, zero.
+pub struct ComplexMath;
+
+macro_rules! define_complex_math {
+    ($t:ident) => {
+        impl Math<Complex<$t>> for ComplexMath {
+            #[inline(always)]
+            fn zero() -> Complex<$t> {
+                Complex::new(0.0, 0.0)
+            }
+
+            #[inline(always)]
+            fn one() -> Complex<$t> {
+                Complex::new(1.0, 0.0)
+            }
+
+            #[inline(always)]
+            fn max() -> Complex<$t> {
+                Complex::new($t::INFINITY, $t::INFINITY)
+            }
+
+            #[inline(always)]
+            fn min() -> Complex<$t> {
+                Complex::new(0.0, 0.0)
+            }
+
+            #[inline(always)]
+            fn sqrt(a: Complex<$t>) -> Complex<$t> {
+                a.sqrt()
+            }
+
+            #[inline(always)]
+            fn abs(a: Complex<$t>) -> Complex<$t> {
+                Complex::new(a.norm(), 0.0)
+            }
+
+            #[inline(always)]
+            fn cmp_eq(a: Complex<$t>, b: Complex<$t>) -> bool {
+                a == b
+            }
+
+            #[inline(always)]
+            fn cmp_lt(a: Complex<$t>, b: Complex<$t>) -> bool {
+                a.norm_sqr() < b.norm_sqr()
+            }
+
+            #[inline(always)]
+            fn cmp_lte(a: Complex<$t>, b: Complex<$t>) -> bool {
+                a.norm_sqr() <= b.norm_sqr()
+            }
+
+            #[inline(always)]
+            fn cmp_gt(a: Complex<$t>, b: Complex<$t>) -> bool {
+                a.norm_sqr() > b.norm_sqr()
+            }
+
+            #[inline(always)]
+            fn cmp_gte(a: Complex<$t>, b: Complex<$t>) -> bool {
+                a.norm_sqr() >= b.norm_sqr()
+            }
+
+            #[inline(always)]
+            fn cmp_min(a: Complex<$t>, b: Complex<$t>) -> Complex<$t> {
+                if Self::cmp_lte(a, b) {
+                    a
+                } else {
+                    b
+                }
+            }
+
+            #[inline(always)]
+            fn cmp_max(a: Complex<$t>, b: Complex<$t>) -> Complex<$t> {
+                if Self::cmp_gte(a, b) {
+                    a
+                } else {
+                    b
+                }
+            }
+
+            #[inline(always)]
+            fn add(a: Complex<$t>, b: Complex<$t>) -> Complex<$t> {
+                a + b
+            }
+
+            #[inline(always)]
+            fn sub(a: Complex<$t>, b: Complex<$t>) -> Complex<$t> {
+                a - b
+            }
+
+            #[inline(always)]
+            fn mul(a: Complex<$t>, b: Complex<$t>) -> Complex<$t> {
+                a * b
+            }
+
+            #[inline(always)]
+            /// `(a+bi)/(c+di) = (ac+bd)/(c²+d²) + (bc-ad)/(c²+d²) i`
+            ///
+            /// Dividing by a zero complex value produces `inf` or `NaN` components.
+            fn div(a: Complex<$t>, b: Complex<$t>) -> Complex<$t> {
+                a / b
+            }
+        }
+    };
+}
+
+define_complex_math!(f32);
+define_complex_math!(f64);
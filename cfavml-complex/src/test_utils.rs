@@ -0,0 +1,55 @@
+use num_complex::Complex;
+use rand::distributions::Standard;
+use rand::prelude::Distribution;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+const SEED: u64 = 9823749823;
+
+pub fn get_sample_vectors<T>(size: usize) -> (Vec<Complex<T>>, Vec<Complex<T>>)
+where
+    Standard: Distribution<T>,
+{
+    let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+
+    let mut x = Vec::new();
+    let mut y = Vec::new();
+    for _ in 0..size {
+        x.push(Complex::new(rng.gen(), rng.gen()));
+        y.push(Complex::new(rng.gen(), rng.gen()));
+    }
+
+    (x, y)
+}
+
+/// Checks two complex values are approximately equal using a relative tolerance, `NaN`
+/// components are considered equal to each other.
+pub trait IsClose {
+    fn is_close(&self, other: &Self) -> bool;
+}
+
+macro_rules! impl_is_close {
+    ($t:ident) => {
+        impl IsClose for $t {
+            fn is_close(&self, other: &Self) -> bool {
+                if self.is_nan() || other.is_nan() {
+                    return self.is_nan() && other.is_nan();
+                } else if self == other {
+                    return true;
+                }
+
+                let scale = self.abs().max(other.abs()).max(1.0);
+                (self - other).abs() <= 0.00015 * scale
+            }
+        }
+
+        impl IsClose for Complex<$t> {
+            fn is_close(&self, other: &Self) -> bool {
+                self.re.is_close(&other.re) && self.im.is_close(&other.im)
+            }
+        }
+    };
+}
+
+impl_is_close!(f32);
+impl_is_close!(f64);
@@ -1,3 +1,6 @@
+// The benchmarks are only registered in release builds.
+#![cfg_attr(debug_assertions, allow(dead_code))]
+
 use std::hint::black_box;
 
 use cfavml_gemm::transpose::transpose_matrix;
@@ -16,7 +19,7 @@ fn main() {
 #[cfg_attr(
     not(debug_assertions),
     divan::bench(
-        types = [f32, f64],
+        types = [f32, f64, u16, u8],
         counters = [divan::counter::ItemsCount::new(DIMS * DIMS)],
     )
 )]
@@ -40,7 +43,7 @@ where
 #[cfg_attr(
     not(debug_assertions),
     divan::bench(
-        types = [f32, f64],
+        types = [f32, f64, u16, u8],
         counters = [divan::counter::ItemsCount::new(DIMS * DIMS)],
     )
 )]
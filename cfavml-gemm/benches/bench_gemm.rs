@@ -1,6 +1,8 @@
+// The benchmarks are only registered in release builds.
+#![cfg_attr(debug_assertions, allow(dead_code))]
+
 use std::hint::black_box;
 
-use cfavml::danger::*;
 use divan::Bencher;
 
 mod utils;
@@ -32,12 +34,10 @@ fn bench_gemm_cfavml(bencher: Bencher) {
         let mut result = black_box(result.clone());
 
         unsafe {
-            cfavml_gemm::danger::f32_avx2fma::matmul(
-                dims,
-                dims,
+            cfavml_gemm::f32_avx2fma_gemm(
+                (dims, dims),
+                (dims, dims),
                 l1,
-                dims,
-                dims,
                 l2,
                 &mut result,
             );
@@ -93,8 +93,8 @@ fn bench_gemm_faer(bencher: Bencher) {
 
         faer::linalg::matmul::matmul(
             &mut result,
-            &l1,
-            &l2,
+            l1,
+            l2,
             None,
             1.0,
             faer::Parallelism::None,
@@ -103,3 +103,63 @@ fn bench_gemm_faer(bencher: Bencher) {
         result
     });
 }
+
+const BATCH: usize = 32;
+const BATCH_DIMS: usize = 64;
+const BATCH_FLOP: usize = BATCH * BATCH_DIMS * BATCH_DIMS * 2 * BATCH_DIMS;
+
+#[cfg_attr(
+    not(debug_assertions),
+    divan::bench(counters = [divan::counter::ItemsCount::new(BATCH_FLOP)])
+)]
+fn bench_batched_gemm_sequential(bencher: Bencher) {
+    let (l1, l2) = utils::get_sample_vectors::<f32>(BATCH * BATCH_DIMS * BATCH_DIMS);
+    let mut result = vec![0.0; BATCH * BATCH_DIMS * BATCH_DIMS];
+
+    bencher.bench_local(|| {
+        let dims = black_box(BATCH_DIMS);
+        let l1 = black_box(&l1);
+        let l2 = black_box(&l2);
+        let result = black_box(&mut result);
+
+        unsafe {
+            cfavml_gemm::f32_avx2fma_batched_gemm(
+                black_box(BATCH),
+                (dims, dims),
+                (dims, dims),
+                l1,
+                l2,
+                result,
+            );
+        }
+    });
+}
+
+#[cfg_attr(
+    not(debug_assertions),
+    divan::bench(counters = [divan::counter::ItemsCount::new(BATCH_FLOP)])
+)]
+fn bench_batched_gemm_parallel(bencher: Bencher) {
+    let (l1, l2) = utils::get_sample_vectors::<f32>(BATCH * BATCH_DIMS * BATCH_DIMS);
+    let mut result = vec![0.0; BATCH * BATCH_DIMS * BATCH_DIMS];
+    let pool = cfavml_utils::get_or_init_pool();
+
+    bencher.bench_local(|| {
+        let dims = black_box(BATCH_DIMS);
+        let l1 = black_box(&l1);
+        let l2 = black_box(&l2);
+        let result = black_box(&mut result);
+
+        unsafe {
+            cfavml_gemm::f32_avx2fma_par_batched_gemm(
+                &pool,
+                black_box(BATCH),
+                (dims, dims),
+                (dims, dims),
+                l1,
+                l2,
+                result,
+            );
+        }
+    });
+}
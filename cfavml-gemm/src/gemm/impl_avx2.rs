@@ -0,0 +1,193 @@
+use std::arch::x86_64::*;
+
+use cfavml::danger::*;
+
+use super::impl_avx2fma::transpose_f64_tile;
+use super::{generic_gemm, write_partial_tile, GemmKernel};
+
+#[target_feature(enable = "avx2")]
+/// Performs a general matrix multiplication of `a @ b` on 32 bit integer values, writing
+/// the 64 bit result to `c`.
+///
+/// Assumes Row-Major Order, `c` is overwritten rather than accumulated into.
+///
+/// Each product is widened and accumulated as an `i64`, so the result is exact as long
+/// as the partial sums fit within an `i64`, otherwise they wrap.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `c` must match their respective shapes, being `shape_a.0 * shape_a.1`,
+/// `shape_b.0 * shape_b.1` and `shape_a.0 * shape_b.1` respectively, with `shape_a.1`
+/// being equal to `shape_b.0`.
+///
+/// This function also assumes `avx2` CPU features are available.
+pub unsafe fn i32_avx2_gemm(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[i32],
+    b: &[i32],
+    c: &mut [i64],
+) {
+    generic_gemm::<i32, i64, Avx2>(shape_a, shape_b, a, b, c)
+}
+
+impl GemmKernel<i32, i64> for Avx2 {
+    const MR: usize = 4;
+    const NR: usize = 8;
+
+    #[inline(always)]
+    /// Each step along `k` sign extends 4 rows of `A` into a single register of `i64` lanes
+    /// and broadcasts each of the 8 columns of `B`, leaving each accumulator holding a
+    /// _column_ of the tile.
+    ///
+    /// `_mm256_mul_epi32` multiplies the low 32 bits of each lane producing the full
+    /// 64 bit product, unlike `_mm256_mullo_epi32` which would truncate it.
+    unsafe fn compute_tile(
+        kc: usize,
+        a_panel: *const i32,
+        b_panel: *const i32,
+        c: *mut i64,
+        n: usize,
+        tile_size: (usize, usize),
+        accumulate: bool,
+        bias: Option<*const i64>,
+    ) {
+        let mut acc = DenseLane {
+            a: _mm256_setzero_si256(),
+            b: _mm256_setzero_si256(),
+            c: _mm256_setzero_si256(),
+            d: _mm256_setzero_si256(),
+            e: _mm256_setzero_si256(),
+            f: _mm256_setzero_si256(),
+            g: _mm256_setzero_si256(),
+            h: _mm256_setzero_si256(),
+        };
+
+        let mut kk = 0;
+        while kk < kc {
+            let a = _mm256_cvtepi32_epi64(_mm_loadu_si128(a_panel.add(kk * 4).cast()));
+            let b = b_panel.add(kk * 8);
+
+            acc.a = fmadd_epi64(a, *b.add(0), acc.a);
+            acc.b = fmadd_epi64(a, *b.add(1), acc.b);
+            acc.c = fmadd_epi64(a, *b.add(2), acc.c);
+            acc.d = fmadd_epi64(a, *b.add(3), acc.d);
+            acc.e = fmadd_epi64(a, *b.add(4), acc.e);
+            acc.f = fmadd_epi64(a, *b.add(5), acc.f);
+            acc.g = fmadd_epi64(a, *b.add(6), acc.g);
+            acc.h = fmadd_epi64(a, *b.add(7), acc.h);
+
+            kk += 1;
+        }
+
+        // The transpose only shuffles lanes, so the `f64` transpose works just as well
+        // on the `i64` lanes.
+        let (left, right) = transpose_f64_tile(DenseLane {
+            a: _mm256_castsi256_pd(acc.a),
+            b: _mm256_castsi256_pd(acc.b),
+            c: _mm256_castsi256_pd(acc.c),
+            d: _mm256_castsi256_pd(acc.d),
+            e: _mm256_castsi256_pd(acc.e),
+            f: _mm256_castsi256_pd(acc.f),
+            g: _mm256_castsi256_pd(acc.g),
+            h: _mm256_castsi256_pd(acc.h),
+        });
+
+        if tile_size == (4, 8) {
+            let bias = bias.map(|bias| {
+                (
+                    _mm256_loadu_si256(bias.cast()),
+                    _mm256_loadu_si256(bias.add(4).cast()),
+                )
+            });
+            for (i, (left, right)) in left.into_iter().zip(right).enumerate() {
+                let ptr = c.add(i * n);
+                let mut left = _mm256_castpd_si256(left);
+                let mut right = _mm256_castpd_si256(right);
+                if let Some((bias_left, bias_right)) = bias {
+                    left = _mm256_add_epi64(left, bias_left);
+                    right = _mm256_add_epi64(right, bias_right);
+                }
+                if accumulate {
+                    left = _mm256_add_epi64(_mm256_loadu_si256(ptr.cast()), left);
+                    right =
+                        _mm256_add_epi64(_mm256_loadu_si256(ptr.add(4).cast()), right);
+                }
+                _mm256_storeu_si256(ptr.cast(), left);
+                _mm256_storeu_si256(ptr.add(4).cast(), right);
+            }
+        } else {
+            let mut buffer = [0i64; 4 * 8];
+            for (i, (left, right)) in left.into_iter().zip(right).enumerate() {
+                let ptr = buffer.as_mut_ptr().add(i * 8);
+                _mm256_storeu_si256(ptr.cast(), _mm256_castpd_si256(left));
+                _mm256_storeu_si256(ptr.add(4).cast(), _mm256_castpd_si256(right));
+            }
+            write_partial_tile::<i64, 8>(c, n, tile_size, &buffer, accumulate, bias);
+        }
+    }
+}
+
+#[inline(always)]
+/// Multiplies the sign extended `i64` lanes of `a` by the broadcast `b` and adds the
+/// full 64 bit products to `acc`.
+unsafe fn fmadd_epi64(a: __m256i, b: i32, acc: __m256i) -> __m256i {
+    _mm256_add_epi64(acc, _mm256_mul_epi32(a, _mm256_set1_epi64x(b as i64)))
+}
+
+#[cfg(all(test, not(miri)))] // This is just very expensive to do
+mod tests {
+    use super::*;
+    use crate::gemm::{KC, MC, NC};
+    use crate::test_utils::{basic_gemm, get_sample_vectors};
+
+    fn run_i32_gemm_test(m: usize, k: usize, n: usize) {
+        println!("Running {m}x{k} @ {k}x{n} matrix");
+        // Values are kept to 24 bits so the products overflow an `i32` but
+        // the sums still fit within an `i64`.
+        let (a, _) = get_sample_vectors::<i32>(m * k);
+        let (_, b) = get_sample_vectors::<i32>(k * n);
+        let a = a.into_iter().map(|v| v >> 8).collect::<Vec<_>>();
+        let b = b.into_iter().map(|v| v >> 8).collect::<Vec<_>>();
+        let expected = basic_gemm::<i32, i64>((m, k), n, &a, &b);
+
+        let mut result = vec![999; m * n];
+        unsafe { i32_avx2_gemm((m, k), (k, n), &a, &b, &mut result) };
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_i32_gemm_small() {
+        run_i32_gemm_test(1, 1, 1);
+        run_i32_gemm_test(2, 2, 2);
+        run_i32_gemm_test(4, 8, 8);
+        run_i32_gemm_test(16, 8, 24);
+    }
+
+    #[test]
+    fn test_i32_gemm_non_square() {
+        run_i32_gemm_test(13, 7, 21);
+        run_i32_gemm_test(7, 13, 3);
+        run_i32_gemm_test(1, 33, 9);
+        run_i32_gemm_test(9, 33, 1);
+        run_i32_gemm_test(64, 3, 17);
+    }
+
+    #[test]
+    fn test_i32_gemm_blocked() {
+        run_i32_gemm_test(130, 260, 33);
+        run_i32_gemm_test(MC * 2 + 5, KC * 2 + 7, 19);
+        run_i32_gemm_test(3, 5, NC + 13);
+    }
+
+    #[test]
+    fn test_i32_gemm_extremes() {
+        let a = [i32::MAX, i32::MIN, -1, 1, i32::MIN, i32::MIN];
+        let b = [i32::MAX, i32::MIN, i32::MIN, i32::MAX];
+        let expected = basic_gemm::<i32, i64>((3, 2), 2, &a, &b);
+
+        let mut result = vec![0; 3 * 2];
+        unsafe { i32_avx2_gemm((3, 2), (2, 2), &a, &b, &mut result) };
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,738 @@
+use std::arch::x86_64::*;
+
+use cfavml::danger::*;
+use cfavml_utils::MaybeBorrowedPool;
+
+use super::{generic_gemm, generic_gemm_general, write_partial_tile, GemmKernel};
+use crate::transpose::{Dense4x4Lane, TransposeMatrix};
+
+#[target_feature(enable = "avx2", enable = "fma")]
+/// Performs a general matrix multiplication of `a @ b` on 32 bit values, writing
+/// the result to `c`.
+///
+/// Assumes Row-Major Order, `c` is overwritten rather than accumulated into.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `c` must match their respective shapes, being `shape_a.0 * shape_a.1`,
+/// `shape_b.0 * shape_b.1` and `shape_a.0 * shape_b.1` respectively, with `shape_a.1`
+/// being equal to `shape_b.0`.
+///
+/// This function also assumes `avx2` and `fma` CPU features are available.
+pub unsafe fn f32_avx2fma_gemm(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+) {
+    generic_gemm::<f32, f32, Avx2Fma>(shape_a, shape_b, a, b, c)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+/// Performs a general matrix multiplication of `a @ b` on 32 bit values, writing
+/// the result to `c`.
+///
+/// Assumes Column-Major Order for `a`, `b` and `c`, `c` is overwritten rather than
+/// accumulated into.
+///
+/// A column-major matrix is the row-major layout of its transpose, so this computes the
+/// row-major `b^T @ a^T`, which is the transpose of `a @ b`, without copying any operand.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `c` must match their respective shapes, being `shape_a.0 * shape_a.1`,
+/// `shape_b.0 * shape_b.1` and `shape_a.0 * shape_b.1` respectively, with `shape_a.1`
+/// being equal to `shape_b.0`.
+///
+/// This function also assumes `avx2` and `fma` CPU features are available.
+pub unsafe fn f32_avx2fma_gemm_colmajor(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+) {
+    let (m, k) = shape_a;
+    let n = shape_b.1;
+    generic_gemm::<f32, f32, Avx2Fma>((n, k), (k, m), b, a, c)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+/// Performs a general matrix multiplication of `a^T @ b` on 32 bit values, writing
+/// the result to `c`.
+///
+/// Assumes Row-Major Order, `c` is overwritten rather than accumulated into.
+///
+/// `shape_a` is the shape of `a` as stored, so `a` is a `k x m` matrix for an `m x n`
+/// result. This is the layout produced by a transposed operand, i.e. from a preceding
+/// factorization step, and is cheaper than [f32_avx2fma_gemm] as `a` does not need
+/// to be transposed internally.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `c` must match their respective shapes, being `shape_a.0 * shape_a.1`,
+/// `shape_b.0 * shape_b.1` and `shape_a.1 * shape_b.1` respectively, with `shape_a.0`
+/// being equal to `shape_b.0`.
+///
+/// This function also assumes `avx2` and `fma` CPU features are available.
+pub unsafe fn f32_avx2fma_gemm_tn(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+) {
+    generic_gemm_general::<f32, f32, Avx2Fma>(
+        shape_a, shape_b, true, false, a, b, None, c,
+    )
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+/// Performs a general matrix multiplication of `op(a) @ op(b)` on 32 bit values, writing
+/// the result to `c`, where `op` transposes the operand if `transpose_a` or `transpose_b`
+/// is set respectively.
+///
+/// Assumes Row-Major Order, `c` is overwritten rather than accumulated into.
+///
+/// `shape_a` and `shape_b` are the shapes of `a` and `b` as stored, _before_ `op` is applied.
+/// Neither operand is explicitly transposed, a transposed `b` is packed by reading the
+/// columns of `op(b)` from the rows of `b`.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `c` must match their respective shapes, being `shape_a.0 * shape_a.1`,
+/// `shape_b.0 * shape_b.1` and `m * n` respectively, where `op(a)` is `m x k` and `op(b)`
+/// is `k x n`, with the inner `k` dimensions being equal.
+///
+/// This function also assumes `avx2` and `fma` CPU features are available.
+pub unsafe fn f32_avx2fma_gemm_general(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    transpose_a: bool,
+    transpose_b: bool,
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+) {
+    generic_gemm_general::<f32, f32, Avx2Fma>(
+        shape_a,
+        shape_b,
+        transpose_a,
+        transpose_b,
+        a,
+        b,
+        None,
+        c,
+    )
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+/// Performs a general matrix multiplication of `a @ b + bias` on 32 bit values, writing
+/// the result to `c`, where `bias` is a row vector broadcast across every row of the result.
+///
+/// Assumes Row-Major Order, `c` is overwritten rather than accumulated into.
+///
+/// The bias is added by the micro-kernel as each tile of `c` is first written, which
+/// avoids the additional pass over `c` a separate broadcast addition would require.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `c` must match their respective shapes, being `shape_a.0 * shape_a.1`,
+/// `shape_b.0 * shape_b.1` and `shape_a.0 * shape_b.1` respectively, with `shape_a.1`
+/// being equal to `shape_b.0`. `bias` must be `shape_b.1` elements in length.
+///
+/// This function also assumes `avx2` and `fma` CPU features are available.
+pub unsafe fn f32_avx2fma_gemm_bias(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[f32],
+    b: &[f32],
+    bias: &[f32],
+    c: &mut [f32],
+) {
+    generic_gemm_general::<f32, f32, Avx2Fma>(
+        shape_a,
+        shape_b,
+        false,
+        false,
+        a,
+        b,
+        Some(bias),
+        c,
+    )
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+/// Performs a batch of general matrix multiplications of `a[i] @ b[i]` on 32 bit values,
+/// writing the result of each to `c[i]`.
+///
+/// `a`, `b` and `c` are logically 3D arrays of `batch` matrices, each matrix being stored
+/// contiguously one after the other in Row-Major Order, i.e. the `batch * heads` attention
+/// score matrices of a transformer. `c` is overwritten rather than accumulated into.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `c` must match their respective shapes, being
+/// `batch * shape_a.0 * shape_a.1`, `batch * shape_b.0 * shape_b.1` and
+/// `batch * shape_a.0 * shape_b.1` respectively, with `shape_a.1` being equal to `shape_b.0`.
+///
+/// This function also assumes `avx2` and `fma` CPU features are available.
+pub unsafe fn f32_avx2fma_batched_gemm(
+    batch: usize,
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+) {
+    let (a_size, b_size, c_size) = batched_sizes(batch, shape_a, shape_b, a, b, c);
+
+    for i in 0..batch {
+        generic_gemm::<f32, f32, Avx2Fma>(
+            shape_a,
+            shape_b,
+            &a[i * a_size..][..a_size],
+            &b[i * b_size..][..b_size],
+            &mut c[i * c_size..][..c_size],
+        );
+    }
+}
+
+/// Performs a batch of general matrix multiplications of `a[i] @ b[i]` on 32 bit values,
+/// writing the result of each to `c[i]`, using the threads of `pool`.
+///
+/// Each matrix multiplication of the batch is spawned onto the pool as a separate task,
+/// the results are identical to [f32_avx2fma_batched_gemm].
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `c` must match their respective shapes, being
+/// `batch * shape_a.0 * shape_a.1`, `batch * shape_b.0 * shape_b.1` and
+/// `batch * shape_a.0 * shape_b.1` respectively, with `shape_a.1` being equal to `shape_b.0`.
+///
+/// This function also assumes `avx2` and `fma` CPU features are available.
+pub unsafe fn f32_avx2fma_par_batched_gemm(
+    pool: &MaybeBorrowedPool,
+    batch: usize,
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+) {
+    let (a_size, b_size, c_size) = batched_sizes(batch, shape_a, shape_b, a, b, c);
+    if c_size == 0 {
+        return;
+    }
+
+    pool.scope(|scope| {
+        for (i, c) in c.chunks_exact_mut(c_size).enumerate() {
+            let a = &a[i * a_size..][..a_size];
+            let b = &b[i * b_size..][..b_size];
+            scope.spawn(move |_| unsafe { f32_avx2fma_gemm(shape_a, shape_b, a, b, c) });
+        }
+    });
+}
+
+/// Returns the number of elements in each matrix of `a`, `b` and `c` for a batched
+/// matrix multiplication.
+fn batched_sizes(
+    batch: usize,
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[f32],
+    b: &[f32],
+    c: &[f32],
+) -> (usize, usize, usize) {
+    let a_size = shape_a.0 * shape_a.1;
+    let b_size = shape_b.0 * shape_b.1;
+    let c_size = shape_a.0 * shape_b.1;
+    debug_assert_eq!(shape_a.1, shape_b.0, "Inner dimension missmatch");
+    debug_assert_eq!(a.len(), batch * a_size, "Shape error");
+    debug_assert_eq!(b.len(), batch * b_size, "Shape error");
+    debug_assert_eq!(c.len(), batch * c_size, "Result matrix size missmatch");
+    (a_size, b_size, c_size)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+/// Performs a general matrix multiplication of `a @ b` on 64 bit values, writing
+/// the result to `c`.
+///
+/// Assumes Row-Major Order, `c` is overwritten rather than accumulated into.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `c` must match their respective shapes, being `shape_a.0 * shape_a.1`,
+/// `shape_b.0 * shape_b.1` and `shape_a.0 * shape_b.1` respectively, with `shape_a.1`
+/// being equal to `shape_b.0`.
+///
+/// This function also assumes `avx2` and `fma` CPU features are available.
+pub unsafe fn f64_avx2fma_gemm(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[f64],
+    b: &[f64],
+    c: &mut [f64],
+) {
+    generic_gemm::<f64, f64, Avx2Fma>(shape_a, shape_b, a, b, c)
+}
+
+impl GemmKernel<f32, f32> for Avx2Fma {
+    const MR: usize = 8;
+    const NR: usize = 8;
+
+    #[inline(always)]
+    /// Each step along `k` loads 8 rows of `A` into a single register and broadcasts
+    /// each of the 8 columns of `B`, leaving each accumulator holding a _column_ of the tile,
+    /// so the tile is transposed before being written in row order.
+    unsafe fn compute_tile(
+        kc: usize,
+        a_panel: *const f32,
+        b_panel: *const f32,
+        c: *mut f32,
+        n: usize,
+        tile_size: (usize, usize),
+        accumulate: bool,
+        bias: Option<*const f32>,
+    ) {
+        let mut acc = DenseLane {
+            a: _mm256_setzero_ps(),
+            b: _mm256_setzero_ps(),
+            c: _mm256_setzero_ps(),
+            d: _mm256_setzero_ps(),
+            e: _mm256_setzero_ps(),
+            f: _mm256_setzero_ps(),
+            g: _mm256_setzero_ps(),
+            h: _mm256_setzero_ps(),
+        };
+
+        let mut kk = 0;
+        while kk < kc {
+            let a = _mm256_loadu_ps(a_panel.add(kk * 8));
+            let b = b_panel.add(kk * 8);
+
+            acc.a = _mm256_fmadd_ps(a, _mm256_broadcast_ss(&*b.add(0)), acc.a);
+            acc.b = _mm256_fmadd_ps(a, _mm256_broadcast_ss(&*b.add(1)), acc.b);
+            acc.c = _mm256_fmadd_ps(a, _mm256_broadcast_ss(&*b.add(2)), acc.c);
+            acc.d = _mm256_fmadd_ps(a, _mm256_broadcast_ss(&*b.add(3)), acc.d);
+            acc.e = _mm256_fmadd_ps(a, _mm256_broadcast_ss(&*b.add(4)), acc.e);
+            acc.f = _mm256_fmadd_ps(a, _mm256_broadcast_ss(&*b.add(5)), acc.f);
+            acc.g = _mm256_fmadd_ps(a, _mm256_broadcast_ss(&*b.add(6)), acc.g);
+            acc.h = _mm256_fmadd_ps(a, _mm256_broadcast_ss(&*b.add(7)), acc.h);
+
+            kk += 1;
+        }
+
+        let tile = <Avx2 as TransposeMatrix<f32>>::transpose_register_matrix(acc);
+        let rows = [
+            tile.a, tile.b, tile.c, tile.d, tile.e, tile.f, tile.g, tile.h,
+        ];
+
+        if tile_size == (8, 8) {
+            let bias = bias.map(|bias| _mm256_loadu_ps(bias));
+            for (i, mut row) in rows.into_iter().enumerate() {
+                let ptr = c.add(i * n);
+                if let Some(bias) = bias {
+                    row = _mm256_add_ps(row, bias);
+                }
+                if accumulate {
+                    _mm256_storeu_ps(ptr, _mm256_add_ps(_mm256_loadu_ps(ptr), row));
+                } else {
+                    _mm256_storeu_ps(ptr, row);
+                }
+            }
+        } else {
+            let mut buffer = [0.0f32; 8 * 8];
+            for (i, row) in rows.into_iter().enumerate() {
+                _mm256_storeu_ps(buffer.as_mut_ptr().add(i * 8), row);
+            }
+            write_partial_tile::<f32, 8>(c, n, tile_size, &buffer, accumulate, bias);
+        }
+    }
+}
+
+impl GemmKernel<f64, f64> for Avx2Fma {
+    const MR: usize = 4;
+    const NR: usize = 8;
+
+    #[inline(always)]
+    /// Each step along `k` loads 4 rows of `A` into a single register and broadcasts
+    /// each of the 8 columns of `B`, leaving each accumulator holding a _column_ of the tile,
+    /// so the tile is transposed as two `4x4` blocks before being written in row order.
+    unsafe fn compute_tile(
+        kc: usize,
+        a_panel: *const f64,
+        b_panel: *const f64,
+        c: *mut f64,
+        n: usize,
+        tile_size: (usize, usize),
+        accumulate: bool,
+        bias: Option<*const f64>,
+    ) {
+        let mut acc = DenseLane {
+            a: _mm256_setzero_pd(),
+            b: _mm256_setzero_pd(),
+            c: _mm256_setzero_pd(),
+            d: _mm256_setzero_pd(),
+            e: _mm256_setzero_pd(),
+            f: _mm256_setzero_pd(),
+            g: _mm256_setzero_pd(),
+            h: _mm256_setzero_pd(),
+        };
+
+        let mut kk = 0;
+        while kk < kc {
+            let a = _mm256_loadu_pd(a_panel.add(kk * 4));
+            let b = b_panel.add(kk * 8);
+
+            acc.a = _mm256_fmadd_pd(a, _mm256_broadcast_sd(&*b.add(0)), acc.a);
+            acc.b = _mm256_fmadd_pd(a, _mm256_broadcast_sd(&*b.add(1)), acc.b);
+            acc.c = _mm256_fmadd_pd(a, _mm256_broadcast_sd(&*b.add(2)), acc.c);
+            acc.d = _mm256_fmadd_pd(a, _mm256_broadcast_sd(&*b.add(3)), acc.d);
+            acc.e = _mm256_fmadd_pd(a, _mm256_broadcast_sd(&*b.add(4)), acc.e);
+            acc.f = _mm256_fmadd_pd(a, _mm256_broadcast_sd(&*b.add(5)), acc.f);
+            acc.g = _mm256_fmadd_pd(a, _mm256_broadcast_sd(&*b.add(6)), acc.g);
+            acc.h = _mm256_fmadd_pd(a, _mm256_broadcast_sd(&*b.add(7)), acc.h);
+
+            kk += 1;
+        }
+
+        let (left, right) = transpose_f64_tile(acc);
+
+        if tile_size == (4, 8) {
+            let bias =
+                bias.map(|bias| (_mm256_loadu_pd(bias), _mm256_loadu_pd(bias.add(4))));
+            for (i, (mut left, mut right)) in left.into_iter().zip(right).enumerate() {
+                let ptr = c.add(i * n);
+                if let Some((bias_left, bias_right)) = bias {
+                    left = _mm256_add_pd(left, bias_left);
+                    right = _mm256_add_pd(right, bias_right);
+                }
+                if accumulate {
+                    let left = _mm256_add_pd(_mm256_loadu_pd(ptr), left);
+                    let right = _mm256_add_pd(_mm256_loadu_pd(ptr.add(4)), right);
+                    _mm256_storeu_pd(ptr, left);
+                    _mm256_storeu_pd(ptr.add(4), right);
+                } else {
+                    _mm256_storeu_pd(ptr, left);
+                    _mm256_storeu_pd(ptr.add(4), right);
+                }
+            }
+        } else {
+            let mut buffer = [0.0f64; 4 * 8];
+            for (i, (left, right)) in left.into_iter().zip(right).enumerate() {
+                _mm256_storeu_pd(buffer.as_mut_ptr().add(i * 8), left);
+                _mm256_storeu_pd(buffer.as_mut_ptr().add(i * 8 + 4), right);
+            }
+            write_partial_tile::<f64, 8>(c, n, tile_size, &buffer, accumulate, bias);
+        }
+    }
+}
+
+#[inline(always)]
+/// Transposes the 8 column registers of a `4x8` tile, returning the left and right
+/// halves of each of the 4 rows.
+pub(super) unsafe fn transpose_f64_tile(
+    columns: DenseLane<__m256d>,
+) -> ([__m256d; 4], [__m256d; 4]) {
+    let left = <Avx2 as TransposeMatrix<f64>>::transpose_register_matrix(Dense4x4Lane {
+        a: columns.a,
+        b: columns.b,
+        c: columns.c,
+        d: columns.d,
+    });
+    let right =
+        <Avx2 as TransposeMatrix<f64>>::transpose_register_matrix(Dense4x4Lane {
+            a: columns.e,
+            b: columns.f,
+            c: columns.g,
+            d: columns.h,
+        });
+
+    (
+        [left.a, left.b, left.c, left.d],
+        [right.a, right.b, right.c, right.d],
+    )
+}
+
+#[cfg(all(test, not(miri)))] // This is just very expensive to do
+mod tests {
+    use super::*;
+    use crate::gemm::{KC, MC, NC};
+    use crate::test_utils::{basic_gemm, basic_transpose, get_sample_vectors};
+
+    macro_rules! define_gemm_tests {
+        ($t:ident, $gemm:ident, tolerance = $tolerance:expr) => {
+            paste::paste! {
+                fn [<run_ $t _gemm_test>](m: usize, k: usize, n: usize) {
+                    println!("Running {m}x{k} @ {k}x{n} matrix");
+                    let (a, _) = get_sample_vectors::<$t>(m * k);
+                    let (_, b) = get_sample_vectors::<$t>(k * n);
+                    let expected = basic_gemm::<$t, $t>((m, k), n, &a, &b);
+
+                    let mut result = vec![999.0; m * n];
+                    unsafe { $gemm((m, k), (k, n), &a, &b, &mut result) };
+
+                    for (i, (value, expected)) in result.iter().zip(expected.iter()).enumerate() {
+                        let tolerance = $tolerance * expected.abs().max(1.0);
+                        assert!(
+                            (value - expected).abs() <= tolerance,
+                            "value mismatch at ({}, {}) {value} vs {expected}",
+                            i / n,
+                            i % n,
+                        );
+                    }
+                }
+
+                #[test]
+                fn [<test_ $t _gemm_small>]() {
+                    [<run_ $t _gemm_test>](1, 1, 1);
+                    [<run_ $t _gemm_test>](2, 2, 2);
+                    [<run_ $t _gemm_test>](8, 8, 8);
+                    [<run_ $t _gemm_test>](16, 8, 24);
+                }
+
+                #[test]
+                fn [<test_ $t _gemm_non_square>]() {
+                    [<run_ $t _gemm_test>](13, 7, 21);
+                    [<run_ $t _gemm_test>](7, 13, 3);
+                    [<run_ $t _gemm_test>](1, 33, 9);
+                    [<run_ $t _gemm_test>](9, 33, 1);
+                    [<run_ $t _gemm_test>](64, 3, 17);
+                }
+
+                #[test]
+                fn [<test_ $t _gemm_blocked>]() {
+                    // Crosses the `MC` and `KC` block boundaries.
+                    [<run_ $t _gemm_test>](130, 260, 33);
+                    [<run_ $t _gemm_test>](MC * 2 + 5, KC * 2 + 7, 19);
+                    // Crosses the `NC` block boundary.
+                    [<run_ $t _gemm_test>](3, 5, NC + 13);
+                }
+
+                #[test]
+                fn [<test_ $t _gemm_empty_inner_dimension>]() {
+                    let mut result = vec![999.0; 12];
+                    unsafe { $gemm((3, 0), (0, 4), &[], &[], &mut result) };
+                    assert_eq!(result, vec![0.0; 12]);
+                }
+
+                #[test]
+                fn [<test_ $t _gemm_matches_faer>]() {
+                    let shapes = [
+                        (1, 1, 1),
+                        (8, 8, 8),
+                        (13, 7, 21),
+                        (33, 65, 17),
+                        (MC + 3, KC + 1, 9),
+                        (5, 11, NC + 7),
+                    ];
+
+                    for (m, k, n) in shapes {
+                        let (a, _) = get_sample_vectors::<$t>(m * k);
+                        let (_, b) = get_sample_vectors::<$t>(k * n);
+
+                        let a_view = faer::mat::from_row_major_slice(&a, m, k);
+                        let b_view = faer::mat::from_row_major_slice(&b, k, n);
+                        let expected = a_view * b_view;
+
+                        let mut result = vec![999.0; m * n];
+                        unsafe { $gemm((m, k), (k, n), &a, &b, &mut result) };
+
+                        for i in 0..m {
+                            for j in 0..n {
+                                let value = result[i * n + j];
+                                let expected = expected.read(i, j);
+                                let tolerance = $tolerance * expected.abs().max(1.0);
+                                assert!(
+                                    (value - expected).abs() <= tolerance,
+                                    "value mismatch for {m}x{k} @ {k}x{n} at ({i}, {j}) \
+                                    {value} vs {expected}",
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    define_gemm_tests!(f32, f32_avx2fma_gemm, tolerance = 1e-4);
+    define_gemm_tests!(f64, f64_avx2fma_gemm, tolerance = 1e-9);
+
+    fn assert_close(result: &[f32], expected: &[f32], context: &str) {
+        for (i, (value, expected)) in result.iter().zip(expected.iter()).enumerate() {
+            let tolerance = 1e-4 * expected.abs().max(1.0);
+            assert!(
+                (value - expected).abs() <= tolerance,
+                "value mismatch for {context} at {i} {value} vs {expected}",
+            );
+        }
+    }
+
+    const TRANSPOSE_SHAPES: [(usize, usize, usize); 6] = [
+        (1, 1, 1),
+        (8, 8, 8),
+        (13, 7, 21),
+        (7, 33, 3),
+        (MC + 3, KC + 1, 9),
+        (5, 11, NC + 7),
+    ];
+
+    #[test]
+    fn test_f32_gemm_general_all_transposes() {
+        for (m, k, n) in TRANSPOSE_SHAPES {
+            let (a, _) = get_sample_vectors::<f32>(m * k);
+            let (_, b) = get_sample_vectors::<f32>(k * n);
+            let expected = basic_gemm::<f32, f32>((m, k), n, &a, &b);
+
+            let a_t = basic_transpose(k, m, &a);
+            let b_t = basic_transpose(n, k, &b);
+
+            for (transpose_a, transpose_b) in
+                [(false, false), (true, false), (false, true), (true, true)]
+            {
+                let (a, shape_a) = if transpose_a {
+                    (&a_t, (k, m))
+                } else {
+                    (&a, (m, k))
+                };
+                let (b, shape_b) = if transpose_b {
+                    (&b_t, (n, k))
+                } else {
+                    (&b, (k, n))
+                };
+
+                let mut result = vec![999.0; m * n];
+                unsafe {
+                    f32_avx2fma_gemm_general(
+                        shape_a,
+                        shape_b,
+                        transpose_a,
+                        transpose_b,
+                        a,
+                        b,
+                        &mut result,
+                    )
+                };
+
+                let context = format!(
+                    "{m}x{k} @ {k}x{n} with transpose_a={transpose_a} transpose_b={transpose_b}"
+                );
+                assert_close(&result, &expected, &context);
+            }
+        }
+    }
+
+    #[test]
+    fn test_f32_gemm_tn() {
+        for (m, k, n) in TRANSPOSE_SHAPES {
+            let (a, _) = get_sample_vectors::<f32>(m * k);
+            let (_, b) = get_sample_vectors::<f32>(k * n);
+            let expected = basic_gemm::<f32, f32>((m, k), n, &a, &b);
+
+            let a_t = basic_transpose(k, m, &a);
+            let mut result = vec![999.0; m * n];
+            unsafe { f32_avx2fma_gemm_tn((k, m), (k, n), &a_t, &b, &mut result) };
+            assert_close(&result, &expected, &format!("{k}x{m}^T @ {k}x{n}"));
+        }
+    }
+
+    #[test]
+    fn test_f32_gemm_colmajor() {
+        for (m, k, n) in TRANSPOSE_SHAPES {
+            let (a, _) = get_sample_vectors::<f32>(m * k);
+            let (_, b) = get_sample_vectors::<f32>(k * n);
+            let expected = basic_gemm::<f32, f32>((m, k), n, &a, &b);
+
+            let a_col = basic_transpose(k, m, &a);
+            let b_col = basic_transpose(n, k, &b);
+            let mut result = vec![999.0; m * n];
+            unsafe {
+                f32_avx2fma_gemm_colmajor((m, k), (k, n), &a_col, &b_col, &mut result)
+            };
+
+            let result = basic_transpose(m, n, &result);
+            assert_close(
+                &result,
+                &expected,
+                &format!("{m}x{k} @ {k}x{n} column-major"),
+            );
+        }
+    }
+
+    #[test]
+    fn test_f32_gemm_bias() {
+        let shapes = [
+            (1, 1, 1),
+            (8, 8, 8),
+            (13, 7, 21),
+            (7, 33, 3),
+            (9, 0, 5),
+            // The bias must only be applied once when crossing the `KC` block boundary.
+            (MC + 3, KC * 2 + 1, 9),
+            (5, 11, NC + 7),
+        ];
+
+        for (m, k, n) in shapes {
+            let (a, bias) = get_sample_vectors::<f32>(m * k + n);
+            let (_, b) = get_sample_vectors::<f32>(k * n);
+            let (a, bias) = (&a[..m * k], &bias[..n]);
+
+            let mut expected = basic_gemm::<f32, f32>((m, k), n, a, &b);
+            for row in expected.chunks_exact_mut(n) {
+                for (value, bias) in row.iter_mut().zip(bias) {
+                    *value += bias;
+                }
+            }
+
+            let mut result = vec![999.0; m * n];
+            unsafe { f32_avx2fma_gemm_bias((m, k), (k, n), a, &b, bias, &mut result) };
+            assert_close(&result, &expected, &format!("{m}x{k} @ {k}x{n} + bias"));
+        }
+    }
+
+    #[test]
+    fn test_f32_batched_gemm() {
+        let pool = cfavml_utils::get_or_init_pool();
+
+        for (batch, (m, k, n)) in [
+            (0, (8, 8, 8)),
+            (1, (13, 7, 21)),
+            (5, (7, 33, 3)),
+            (3, (9, 0, 5)),
+        ] {
+            let (a, _) = get_sample_vectors::<f32>(batch * m * k);
+            let (_, b) = get_sample_vectors::<f32>(batch * k * n);
+
+            let mut expected = Vec::with_capacity(batch * m * n);
+            for i in 0..batch {
+                let a = &a[i * m * k..][..m * k];
+                let b = &b[i * k * n..][..k * n];
+                expected.extend(basic_gemm::<f32, f32>((m, k), n, a, b));
+            }
+
+            let context = format!("batch of {batch} {m}x{k} @ {k}x{n}");
+            let mut result = vec![999.0; batch * m * n];
+            unsafe {
+                f32_avx2fma_batched_gemm(batch, (m, k), (k, n), &a, &b, &mut result)
+            };
+            assert_close(&result, &expected, &context);
+
+            let mut par_result = vec![999.0; batch * m * n];
+            unsafe {
+                f32_avx2fma_par_batched_gemm(
+                    &pool,
+                    batch,
+                    (m, k),
+                    (k, n),
+                    &a,
+                    &b,
+                    &mut par_result,
+                )
+            };
+            assert_eq!(par_result, result, "parallel result mismatch for {context}");
+        }
+    }
+}
@@ -0,0 +1,529 @@
+use std::borrow::Cow;
+use std::ops::{Add, AddAssign, Mul};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod impl_avx2;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod impl_avx2fma;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::impl_avx2::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::impl_avx2fma::*;
+use crate::transpose::transpose_matrix;
+
+/// The number of rows of `A` packed into a single block, sized so the
+/// packed block of `A` stays resident in the L2 cache.
+pub const MC: usize = 128;
+/// The shared `K` dimension of the packed blocks of `A` and `B`.
+pub const KC: usize = 256;
+/// The number of columns of `B` packed into a single block, sized so the
+/// packed block of `B` stays resident in the L3 cache.
+pub const NC: usize = 2048;
+
+/// Performs a general matrix multiplication of `a @ b` on 32 bit float values, writing
+/// the result to `c`.
+///
+/// Assumes Row-Major Order, `c` is overwritten rather than accumulated into.
+///
+/// The fastest implementation available on the current CPU is selected at runtime.
+///
+/// # Panics
+///
+/// If the sizes of `a`, `b` and `c` do not match their respective shapes, being
+/// `shape_a.0 * shape_a.1`, `shape_b.0 * shape_b.1` and `shape_a.0 * shape_b.1`
+/// respectively, or if `shape_a.1` is not equal to `shape_b.0`.
+pub fn f32_gemm(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+) {
+    assert_gemm_shapes(shape_a, shape_b, a.len(), b.len(), c.len());
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    unsafe {
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            return f32_avx2fma_gemm(shape_a, shape_b, a, b, c);
+        }
+    }
+
+    fallback_gemm(shape_a, shape_b.1, a, b, c)
+}
+
+/// Performs a general matrix multiplication of `a @ b` on 64 bit float values, writing
+/// the result to `c`.
+///
+/// Assumes Row-Major Order, `c` is overwritten rather than accumulated into.
+///
+/// The fastest implementation available on the current CPU is selected at runtime.
+///
+/// # Panics
+///
+/// If the sizes of `a`, `b` and `c` do not match their respective shapes, being
+/// `shape_a.0 * shape_a.1`, `shape_b.0 * shape_b.1` and `shape_a.0 * shape_b.1`
+/// respectively, or if `shape_a.1` is not equal to `shape_b.0`.
+pub fn f64_gemm(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[f64],
+    b: &[f64],
+    c: &mut [f64],
+) {
+    assert_gemm_shapes(shape_a, shape_b, a.len(), b.len(), c.len());
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    unsafe {
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            return f64_avx2fma_gemm(shape_a, shape_b, a, b, c);
+        }
+    }
+
+    fallback_gemm(shape_a, shape_b.1, a, b, c)
+}
+
+/// Performs a general matrix multiplication of `a @ b` on 32 bit integer values, writing
+/// the 64 bit result to `c`.
+///
+/// Assumes Row-Major Order, `c` is overwritten rather than accumulated into.
+///
+/// Each product is widened and accumulated as an `i64`, so the result is exact as long
+/// as the partial sums fit within an `i64`.
+///
+/// The fastest implementation available on the current CPU is selected at runtime.
+///
+/// # Panics
+///
+/// If the sizes of `a`, `b` and `c` do not match their respective shapes, being
+/// `shape_a.0 * shape_a.1`, `shape_b.0 * shape_b.1` and `shape_a.0 * shape_b.1`
+/// respectively, or if `shape_a.1` is not equal to `shape_b.0`.
+pub fn i32_gemm(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[i32],
+    b: &[i32],
+    c: &mut [i64],
+) {
+    assert_gemm_shapes(shape_a, shape_b, a.len(), b.len(), c.len());
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    unsafe {
+        if is_x86_feature_detected!("avx2") {
+            return i32_avx2_gemm(shape_a, shape_b, a, b, c);
+        }
+    }
+
+    fallback_gemm(shape_a, shape_b.1, a, b, c)
+}
+
+fn assert_gemm_shapes(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a_len: usize,
+    b_len: usize,
+    c_len: usize,
+) {
+    assert_eq!(shape_a.1, shape_b.0, "Inner dimension missmatch");
+    assert_eq!(a_len, shape_a.0 * shape_a.1, "Shape error");
+    assert_eq!(b_len, shape_b.0 * shape_b.1, "Shape error");
+    assert_eq!(c_len, shape_a.0 * shape_b.1, "Result matrix size missmatch");
+}
+
+/// A naive matrix multiply used when no SIMD implementation is available, walking
+/// `k` before `j` so the rows of `b` and `c` are read sequentially.
+fn fallback_gemm<T, O>((m, k): (usize, usize), n: usize, a: &[T], b: &[T], c: &mut [O])
+where
+    T: Copy,
+    O: Copy + Default + From<T> + Add<Output = O> + Mul<Output = O>,
+{
+    c.fill(O::default());
+
+    for i in 0..m {
+        let c_row = &mut c[i * n..][..n];
+        for kk in 0..k {
+            let a_value = O::from(a[i * k + kk]);
+            let b_row = &b[kk * n..][..n];
+            for (c_value, b_value) in c_row.iter_mut().zip(b_row) {
+                *c_value = *c_value + a_value * O::from(*b_value);
+            }
+        }
+    }
+}
+
+/// The micro-kernel computing a single `MR x NR` tile of `C` for a given
+/// input type `T` and output type `O`.
+pub(crate) trait GemmKernel<T, O> {
+    /// The number of rows of `C` computed by a single micro-kernel call.
+    const MR: usize;
+    /// The number of columns of `C` computed by a single micro-kernel call.
+    const NR: usize;
+
+    /// Computes a single tile of `C` from a packed micro-panel of `A` and `B`, writing
+    /// the top-left `rows x cols` of the tile to `c` where each row is `n` elements apart.
+    ///
+    /// If `accumulate` is `true` the tile is added to the existing values of `c`,
+    /// otherwise the existing values are overwritten.
+    ///
+    /// If `bias` is provided it points to the `cols` bias values of the columns of the tile,
+    /// which are added to each row of the tile as it is written. This is only ever provided
+    /// when `accumulate` is `false`, so the bias is applied exactly once.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn compute_tile(
+        kc: usize,
+        a_panel: *const T,
+        b_panel: *const T,
+        c: *mut O,
+        n: usize,
+        tile_size: (usize, usize),
+        accumulate: bool,
+        bias: Option<*const O>,
+    );
+}
+
+/// A blocked matrix multiply of `a @ b`, writing the result to `c`.
+///
+/// `A` is transposed up front so that the rows of `A` used by each step of the
+/// micro-kernel are contiguous, which makes packing each block a set of straight copies.
+/// The packed blocks of `A` and `B` are then walked in `MR x NR` tiles by the micro-kernel.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `c` must match their respective shapes and the CPU features
+/// required by `K` must be available.
+pub(crate) unsafe fn generic_gemm<T, O, K>(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    a: &[T],
+    b: &[T],
+    c: &mut [O],
+) where
+    T: Copy + Default + 'static,
+    O: Copy + Default,
+    K: GemmKernel<T, O>,
+{
+    generic_gemm_general::<T, O, K>(shape_a, shape_b, false, false, a, b, None, c)
+}
+
+/// A blocked matrix multiply of `op(a) @ op(b)`, writing the result to `c`, where `op`
+/// transposes the operand if the respective `transpose_*` flag is set.
+///
+/// `shape_a` and `shape_b` are the shapes of `a` and `b` as stored in Row-Major Order,
+/// _before_ `op` is applied.
+///
+/// The packing routines work from the transpose of `A` and `B` as-is, so a transposed `A`
+/// is used directly, skipping the up front transposition. A transposed `B` is never
+/// transposed, instead each column of the packed panels is read from a row of `b`.
+///
+/// If `bias` is provided it is broadcast across each row of `c`, being added by the
+/// micro-kernel as each tile is first written rather than in a separate pass over `c`.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `c` must match their respective shapes, `bias` must be
+/// `n` elements in length and the CPU features required by `K` must be available.
+#[allow(clippy::too_many_arguments)]
+pub(crate) unsafe fn generic_gemm_general<T, O, K>(
+    shape_a: (usize, usize),
+    shape_b: (usize, usize),
+    transpose_a: bool,
+    transpose_b: bool,
+    a: &[T],
+    b: &[T],
+    bias: Option<&[O]>,
+    c: &mut [O],
+) where
+    T: Copy + Default + 'static,
+    O: Copy + Default,
+    K: GemmKernel<T, O>,
+{
+    let (m, k) = transposed_shape(shape_a, transpose_a);
+    let (k_b, n) = transposed_shape(shape_b, transpose_b);
+    debug_assert_eq!(k, k_b, "Inner dimension missmatch");
+    debug_assert_eq!(a.len(), m * k, "Shape error");
+    debug_assert_eq!(b.len(), k * n, "Shape error");
+    debug_assert_eq!(c.len(), m * n, "Result matrix size missmatch");
+    if let Some(bias) = bias {
+        debug_assert_eq!(bias.len(), n, "Bias size missmatch");
+    }
+
+    if m == 0 || n == 0 {
+        return;
+    } else if k == 0 {
+        match bias {
+            Some(bias) => {
+                for row in c.chunks_exact_mut(n) {
+                    row.copy_from_slice(bias);
+                }
+            },
+            None => c.fill(O::default()),
+        }
+        return;
+    }
+
+    let a_t = if transpose_a {
+        Cow::Borrowed(a)
+    } else {
+        let mut a_t = vec![T::default(); m * k];
+        transpose_matrix(k, m, a, &mut a_t);
+        Cow::Owned(a_t)
+    };
+
+    let mut packed_a = vec![T::default(); MC.div_ceil(K::MR) * K::MR * KC];
+    let mut packed_b = vec![T::default(); KC * NC.min(n.div_ceil(K::NR) * K::NR)];
+
+    let mut jc = 0;
+    while jc < n {
+        let nc = NC.min(n - jc);
+
+        let mut pc = 0;
+        while pc < k {
+            let kc = KC.min(k - pc);
+            if transpose_b {
+                pack_transposed_panels(b, k, (pc, kc), (jc, nc), K::NR, &mut packed_b);
+            } else {
+                pack_panels(b, n, (pc, kc), (jc, nc), K::NR, &mut packed_b);
+            }
+
+            let mut ic = 0;
+            while ic < m {
+                let mc = MC.min(m - ic);
+                pack_panels(&a_t, m, (pc, kc), (ic, mc), K::MR, &mut packed_a);
+
+                macro_kernel::<T, O, K>(
+                    n,
+                    (ic, mc),
+                    (jc, nc),
+                    kc,
+                    pc != 0,
+                    &packed_a,
+                    &packed_b,
+                    bias,
+                    c,
+                );
+
+                ic += mc;
+            }
+
+            pc += kc;
+        }
+
+        jc += nc;
+    }
+}
+
+/// Returns the shape of a matrix after optionally being transposed.
+fn transposed_shape((rows, cols): (usize, usize), transpose: bool) -> (usize, usize) {
+    if transpose {
+        (cols, rows)
+    } else {
+        (rows, cols)
+    }
+}
+
+#[inline(always)]
+/// Computes the `mc x nc` block of `C` starting at `(ic, jc)` from the packed
+/// blocks of `A` and `B`, one `MR x NR` tile at a time.
+///
+/// The `bias` is only passed on to the micro-kernel for the first block along `K`,
+/// the later blocks accumulate into tiles which already include it.
+#[allow(clippy::too_many_arguments)]
+unsafe fn macro_kernel<T, O, K>(
+    n: usize,
+    (ic, mc): (usize, usize),
+    (jc, nc): (usize, usize),
+    kc: usize,
+    accumulate: bool,
+    packed_a: &[T],
+    packed_b: &[T],
+    bias: Option<&[O]>,
+    c: &mut [O],
+) where
+    K: GemmKernel<T, O>,
+{
+    let packed_a_ptr = packed_a.as_ptr();
+    let packed_b_ptr = packed_b.as_ptr();
+    let c_ptr = c.as_mut_ptr();
+    let bias_ptr = bias.filter(|_| !accumulate).map(|bias| bias.as_ptr());
+
+    let mut jr = 0;
+    while jr < nc {
+        let cols = K::NR.min(nc - jr);
+        let b_panel = packed_b_ptr.add(jr * kc);
+        let tile_bias = bias_ptr.map(|bias| bias.add(jc + jr));
+
+        let mut ir = 0;
+        while ir < mc {
+            let rows = K::MR.min(mc - ir);
+            let a_panel = packed_a_ptr.add(ir * kc);
+            let offset = (ic + ir) * n + jc + jr;
+
+            K::compute_tile(
+                kc,
+                a_panel,
+                b_panel,
+                c_ptr.add(offset),
+                n,
+                (rows, cols),
+                accumulate,
+                tile_bias,
+            );
+
+            ir += K::MR;
+        }
+
+        jr += K::NR;
+    }
+}
+
+/// Packs the `kc x len` block of the row-major `data` matrix (being `width` elements wide)
+/// starting at `(pc, start)` into `panel_width` wide micro-panels, each stored `kc` steps deep.
+///
+/// This is used for both the transposed `A` and `B` matrices, any elements past the end
+/// of the block are padded with zeroes.
+fn pack_panels<T: Copy + Default>(
+    data: &[T],
+    width: usize,
+    (pc, kc): (usize, usize),
+    (start, len): (usize, usize),
+    panel_width: usize,
+    packed: &mut [T],
+) {
+    let mut offset = 0;
+    let mut panel_start = 0;
+    while panel_start < len {
+        let size = panel_width.min(len - panel_start);
+        for kk in 0..kc {
+            let data_start = (pc + kk) * width + start + panel_start;
+            let panel = &mut packed[offset..][..panel_width];
+            panel[..size].copy_from_slice(&data[data_start..][..size]);
+            panel[size..].fill(T::default());
+            offset += panel_width;
+        }
+        panel_start += panel_width;
+    }
+}
+
+/// Packs the `kc x len` block of the _transpose_ of the row-major `data` matrix
+/// (being `depth` elements wide) starting at `(pc, start)` into `panel_width` wide
+/// micro-panels, each stored `kc` steps deep.
+///
+/// This produces the same panels as [pack_panels] would from the transposed matrix,
+/// each column of a panel being a contiguous run of a row of `data`, which avoids
+/// transposing the whole matrix up front.
+fn pack_transposed_panels<T: Copy + Default>(
+    data: &[T],
+    depth: usize,
+    (pc, kc): (usize, usize),
+    (start, len): (usize, usize),
+    panel_width: usize,
+    packed: &mut [T],
+) {
+    let mut offset = 0;
+    let mut panel_start = 0;
+    while panel_start < len {
+        let size = panel_width.min(len - panel_start);
+        let panel = &mut packed[offset..][..kc * panel_width];
+        for j in 0..size {
+            let row = &data[(start + panel_start + j) * depth + pc..][..kc];
+            for (kk, &value) in row.iter().enumerate() {
+                panel[kk * panel_width + j] = value;
+            }
+        }
+        if size < panel_width {
+            for step in panel.chunks_exact_mut(panel_width) {
+                step[size..].fill(T::default());
+            }
+        }
+        offset += kc * panel_width;
+        panel_start += panel_width;
+    }
+}
+
+#[inline(always)]
+/// Writes the top-left `rows x cols` of a row-major tile buffer being `NR` elements wide
+/// to `C` where each row is `n` elements apart, used for the edges of `C`
+/// which do not fill a whole tile.
+///
+/// If `bias` is provided, the `cols` values it points to are added to each row.
+unsafe fn write_partial_tile<O: Copy + AddAssign, const NR: usize>(
+    c: *mut O,
+    n: usize,
+    (rows, cols): (usize, usize),
+    tile: &[O],
+    accumulate: bool,
+    bias: Option<*const O>,
+) {
+    for i in 0..rows {
+        for j in 0..cols {
+            let ptr = c.add(i * n + j);
+            let mut value = tile[i * NR + j];
+            if let Some(bias) = bias {
+                value += *bias.add(j);
+            }
+            if accumulate {
+                *ptr += value;
+            } else {
+                *ptr = value;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{basic_gemm, get_sample_vectors};
+
+    #[test]
+    fn test_fallback_gemm() {
+        let (a, b) = get_sample_vectors::<f64>(13 * 7);
+        let b = &b[..7 * 5];
+        let expected = basic_gemm::<f64, f64>((13, 7), 5, &a, b);
+
+        let mut result = vec![999.0; 13 * 5];
+        fallback_gemm((13, 7), 5, &a, b, &mut result);
+        for (value, expected) in result.iter().zip(expected.iter()) {
+            assert!((value - expected).abs() <= 1e-9, "{value} vs {expected}");
+        }
+
+        let a = [i32::MAX, 2, -3, i32::MIN];
+        let b = [i32::MAX, 1, i32::MIN, 1];
+        let mut result = vec![0; 4];
+        fallback_gemm((2, 2), 2, &a, &b, &mut result);
+        assert_eq!(result, basic_gemm::<i32, i64>((2, 2), 2, &a, &b));
+    }
+
+    #[test]
+    #[should_panic(expected = "Inner dimension missmatch")]
+    fn test_gemm_shape_missmatch() {
+        f32_gemm((2, 3), (2, 3), &[0.0; 6], &[0.0; 6], &mut [0.0; 6]);
+    }
+
+    #[test]
+    fn test_safe_gemm() {
+        let (a, b) = get_sample_vectors::<f32>(9 * 17);
+        let expected = basic_gemm::<f32, f32>((9, 17), 9, &a, &b[..17 * 9]);
+        let mut result = vec![0.0; 9 * 9];
+        f32_gemm((9, 17), (17, 9), &a, &b[..17 * 9], &mut result);
+        for (value, expected) in result.iter().zip(expected.iter()) {
+            assert!((value - expected).abs() <= 1e-4, "{value} vs {expected}");
+        }
+
+        let (a, b) = get_sample_vectors::<f64>(9 * 17);
+        let expected = basic_gemm::<f64, f64>((9, 17), 9, &a, &b[..17 * 9]);
+        let mut result = vec![0.0; 9 * 9];
+        f64_gemm((9, 17), (17, 9), &a, &b[..17 * 9], &mut result);
+        for (value, expected) in result.iter().zip(expected.iter()) {
+            assert!((value - expected).abs() <= 1e-9, "{value} vs {expected}");
+        }
+
+        let (a, b) = get_sample_vectors::<i32>(9 * 17);
+        let a = a.into_iter().map(|v| v >> 8).collect::<Vec<_>>();
+        let b = b.into_iter().map(|v| v >> 8).collect::<Vec<_>>();
+        let expected = basic_gemm::<i32, i64>((9, 17), 9, &a, &b[..17 * 9]);
+        let mut result = vec![0; 9 * 9];
+        i32_gemm((9, 17), (17, 9), &a, &b[..17 * 9], &mut result);
+        assert_eq!(result, expected);
+    }
+}
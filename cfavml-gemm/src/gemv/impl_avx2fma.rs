@@ -0,0 +1,191 @@
+use std::arch::x86_64::*;
+
+use cfavml::danger::*;
+
+/// The number of rows of `A` processed together, each with their own accumulator.
+const ROWS_PER_BLOCK: usize = 8;
+
+#[target_feature(enable = "avx2", enable = "fma")]
+/// Performs a matrix-vector multiplication of `a @ x` on 32 bit values, writing
+/// the result to `y`.
+///
+/// Assumes `a` is a `rows x cols` matrix in Row-Major Order, `y` is overwritten rather
+/// than accumulated into.
+///
+/// 8 rows are processed at a time, each with an independent accumulator so each
+/// load of `x` is shared between the 8 rows and the latency of each FMA is hidden.
+///
+/// # Safety
+///
+/// The size of `a` must be `rows * cols`, the size of `x` must be `cols` and
+/// the size of `y` must be `rows`.
+///
+/// This function also assumes `avx2` and `fma` CPU features are available.
+pub unsafe fn f32_avx2fma_gemv(
+    rows: usize,
+    cols: usize,
+    a: &[f32],
+    x: &[f32],
+    y: &mut [f32],
+) {
+    debug_assert_eq!(a.len(), rows * cols, "Shape error");
+    debug_assert_eq!(x.len(), cols, "Vector size missmatch");
+    debug_assert_eq!(y.len(), rows, "Result vector size missmatch");
+
+    let a_ptr = a.as_ptr();
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_mut_ptr();
+
+    let mut i = 0;
+    while i + ROWS_PER_BLOCK <= rows {
+        let sums = dot_8_rows(cols, a_ptr.add(i * cols), x_ptr);
+        _mm256_storeu_ps(y_ptr.add(i), sums);
+        i += ROWS_PER_BLOCK;
+    }
+
+    while i < rows {
+        *y_ptr.add(i) = dot_row(cols, a_ptr.add(i * cols), x_ptr);
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Computes the dot product of 8 consecutive rows starting at `a` against `x`,
+/// returning the 8 results in row order.
+unsafe fn dot_8_rows(cols: usize, a: *const f32, x: *const f32) -> __m256 {
+    let mut acc = DenseLane {
+        a: _mm256_setzero_ps(),
+        b: _mm256_setzero_ps(),
+        c: _mm256_setzero_ps(),
+        d: _mm256_setzero_ps(),
+        e: _mm256_setzero_ps(),
+        f: _mm256_setzero_ps(),
+        g: _mm256_setzero_ps(),
+        h: _mm256_setzero_ps(),
+    };
+
+    let offset_from = cols % 8;
+    let mut j = 0;
+    while j < (cols - offset_from) {
+        let x = _mm256_loadu_ps(x.add(j));
+        let a = a.add(j);
+
+        acc.a = _mm256_fmadd_ps(_mm256_loadu_ps(a), x, acc.a);
+        acc.b = _mm256_fmadd_ps(_mm256_loadu_ps(a.add(cols)), x, acc.b);
+        acc.c = _mm256_fmadd_ps(_mm256_loadu_ps(a.add(cols * 2)), x, acc.c);
+        acc.d = _mm256_fmadd_ps(_mm256_loadu_ps(a.add(cols * 3)), x, acc.d);
+        acc.e = _mm256_fmadd_ps(_mm256_loadu_ps(a.add(cols * 4)), x, acc.e);
+        acc.f = _mm256_fmadd_ps(_mm256_loadu_ps(a.add(cols * 5)), x, acc.f);
+        acc.g = _mm256_fmadd_ps(_mm256_loadu_ps(a.add(cols * 6)), x, acc.g);
+        acc.h = _mm256_fmadd_ps(_mm256_loadu_ps(a.add(cols * 7)), x, acc.h);
+
+        j += 8;
+    }
+
+    let mut sums = sum_8_registers(acc);
+
+    if j < cols {
+        let mut remainder = [0.0f32; ROWS_PER_BLOCK];
+        for (row, value) in remainder.iter_mut().enumerate() {
+            let a = a.add(row * cols);
+            for jj in j..cols {
+                *value += *a.add(jj) * *x.add(jj);
+            }
+        }
+        sums = _mm256_add_ps(sums, _mm256_loadu_ps(remainder.as_ptr()));
+    }
+
+    sums
+}
+
+#[inline(always)]
+/// Horizontally sums each of the 8 registers, returning the 8 sums in a single register.
+///
+/// The adjacent pairs are added together with `hadd` until each 128 bit half holds
+/// a partial sum of 4 of the registers, then the two halves are added together.
+unsafe fn sum_8_registers(acc: DenseLane<__m256>) -> __m256 {
+    let ab = _mm256_hadd_ps(acc.a, acc.b);
+    let cd = _mm256_hadd_ps(acc.c, acc.d);
+    let ef = _mm256_hadd_ps(acc.e, acc.f);
+    let gh = _mm256_hadd_ps(acc.g, acc.h);
+
+    let abcd = _mm256_hadd_ps(ab, cd);
+    let efgh = _mm256_hadd_ps(ef, gh);
+
+    let lo = _mm256_permute2f128_ps::<0x20>(abcd, efgh);
+    let hi = _mm256_permute2f128_ps::<0x31>(abcd, efgh);
+    _mm256_add_ps(lo, hi)
+}
+
+#[inline(always)]
+/// Computes the dot product of a single row starting at `a` against `x`.
+unsafe fn dot_row(cols: usize, a: *const f32, x: *const f32) -> f32 {
+    let mut acc = _mm256_setzero_ps();
+
+    let offset_from = cols % 8;
+    let mut j = 0;
+    while j < (cols - offset_from) {
+        acc = _mm256_fmadd_ps(_mm256_loadu_ps(a.add(j)), _mm256_loadu_ps(x.add(j)), acc);
+        j += 8;
+    }
+
+    let mut sum = <Avx2Fma as SimdRegister<f32>>::sum_to_value(acc);
+    while j < cols {
+        sum += *a.add(j) * *x.add(j);
+        j += 1;
+    }
+
+    sum
+}
+
+#[cfg(all(test, not(miri)))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{basic_gemm, get_sample_vectors};
+
+    fn run_gemv_test(rows: usize, cols: usize) {
+        println!("Running {rows}x{cols} @ {cols}x1 gemv");
+        let (a, _) = get_sample_vectors::<f32>(rows * cols);
+        let (_, x) = get_sample_vectors::<f32>(cols);
+        let expected = basic_gemm::<f32, f32>((rows, cols), 1, &a, &x);
+
+        let mut result = vec![999.0; rows];
+        unsafe { f32_avx2fma_gemv(rows, cols, &a, &x, &mut result) };
+
+        for (i, (value, expected)) in result.iter().zip(expected.iter()).enumerate() {
+            let tolerance = 1e-4 * expected.abs().max(1.0);
+            assert!(
+                (value - expected).abs() <= tolerance,
+                "value mismatch at row {i} {value} vs {expected}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_gemv_small() {
+        run_gemv_test(1, 1);
+        run_gemv_test(8, 8);
+        run_gemv_test(16, 32);
+    }
+
+    #[test]
+    fn test_gemv_non_aligned() {
+        run_gemv_test(7, 13);
+        run_gemv_test(13, 7);
+        run_gemv_test(9, 33);
+        run_gemv_test(33, 1);
+        run_gemv_test(1, 513);
+        run_gemv_test(257, 129);
+    }
+
+    #[test]
+    fn test_gemv_empty() {
+        let mut result = vec![999.0; 4];
+        unsafe { f32_avx2fma_gemv(4, 0, &[], &[], &mut result) };
+        assert_eq!(result, vec![0.0; 4]);
+
+        let mut result = vec![];
+        unsafe { f32_avx2fma_gemv(0, 4, &[], &[0.0; 4], &mut result) };
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod impl_avx2fma;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::impl_avx2fma::*;
+
+/// Performs a matrix-vector multiplication of `a @ x` on 32 bit float values, writing
+/// the result to `y`.
+///
+/// Assumes `a` is a `rows x cols` matrix in Row-Major Order, `y` is overwritten rather
+/// than accumulated into.
+///
+/// The fastest implementation available on the current CPU is selected at runtime.
+///
+/// # Panics
+///
+/// If the size of `a` is not `rows * cols`, the size of `x` is not `cols`
+/// or the size of `y` is not `rows`.
+pub fn f32_gemv(rows: usize, cols: usize, a: &[f32], x: &[f32], y: &mut [f32]) {
+    assert_gemv_shapes(rows, cols, a.len(), x.len(), y.len());
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    unsafe {
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            return f32_avx2fma_gemv(rows, cols, a, x, y);
+        }
+    }
+
+    fallback_gemv(cols, a, x, y)
+}
+
+fn assert_gemv_shapes(
+    rows: usize,
+    cols: usize,
+    a_len: usize,
+    x_len: usize,
+    y_len: usize,
+) {
+    assert_eq!(a_len, rows * cols, "Shape error");
+    assert_eq!(x_len, cols, "Vector size missmatch");
+    assert_eq!(y_len, rows, "Result vector size missmatch");
+}
+
+/// A naive matrix-vector multiply used when no SIMD implementation is available.
+fn fallback_gemv(cols: usize, a: &[f32], x: &[f32], y: &mut [f32]) {
+    for (i, y_value) in y.iter_mut().enumerate() {
+        let row = &a[i * cols..][..cols];
+        *y_value = row.iter().zip(x).map(|(a, x)| a * x).sum();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{basic_gemm, get_sample_vectors};
+
+    #[test]
+    fn test_fallback_gemv() {
+        let (a, x) = get_sample_vectors::<f32>(13 * 7);
+        let x = &x[..7];
+        let expected = basic_gemm::<f32, f32>((13, 7), 1, &a, x);
+
+        let mut result = vec![999.0; 13];
+        fallback_gemv(7, &a, x, &mut result);
+        for (value, expected) in result.iter().zip(expected.iter()) {
+            assert!((value - expected).abs() <= 1e-4, "{value} vs {expected}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector size missmatch")]
+    fn test_gemv_shape_missmatch() {
+        f32_gemv(2, 3, &[0.0; 6], &[0.0; 2], &mut [0.0; 2]);
+    }
+
+    #[test]
+    fn test_safe_gemv() {
+        let (a, x) = get_sample_vectors::<f32>(19 * 33);
+        let x = &x[..33];
+        let expected = basic_gemm::<f32, f32>((19, 33), 1, &a, x);
+
+        let mut result = vec![999.0; 19];
+        f32_gemv(19, 33, &a, x, &mut result);
+        for (value, expected) in result.iter().zip(expected.iter()) {
+            assert!((value - expected).abs() <= 1e-4, "{value} vs {expected}");
+        }
+    }
+}
@@ -1,25 +1,26 @@
 #![doc = include_str!("../README.md")]
 
 pub mod danger;
+pub mod gemm;
+pub mod gemv;
 pub mod transpose;
 
 #[cfg(test)]
 mod test_utils;
 
-/// Assumes Row-Major Order.
-pub unsafe fn f32_avx2fma_gemm(
-    shape_a: (usize, usize),
-    shape_b: (usize, usize),
-    a: &[f32],
-    b: &[f32],
-    c: &mut [f32],
-) {
-    debug_assert_eq!(b.len(), c.len(), "Result matrix size missmatch");
-    debug_assert_eq!(a.len(), shape_a.0 * shape_a.1, "Shape error");
-    debug_assert_eq!(b.len(), shape_b.0 * shape_b.1, "Shape error");
-
-    let _b_ptr = b.as_ptr();
-    let _c_ptr = c.as_mut_ptr();
-
-    let _c_shape = (shape_a.0, shape_b.1);
-}
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::gemm::{
+    f32_avx2fma_batched_gemm,
+    f32_avx2fma_gemm,
+    f32_avx2fma_gemm_bias,
+    f32_avx2fma_gemm_colmajor,
+    f32_avx2fma_gemm_general,
+    f32_avx2fma_gemm_tn,
+    f32_avx2fma_par_batched_gemm,
+    f64_avx2fma_gemm,
+    i32_avx2_gemm,
+};
+pub use self::gemm::{f32_gemm, f64_gemm, i32_gemm};
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::gemv::f32_avx2fma_gemv;
+pub use self::gemv::f32_gemv;
@@ -36,3 +36,23 @@ pub fn basic_transpose<T: Copy + Default>(
 
     result
 }
+
+pub fn basic_gemm<T, O>((m, k): (usize, usize), n: usize, a: &[T], b: &[T]) -> Vec<O>
+where
+    T: Copy,
+    O: Copy + Default + From<T> + std::ops::Add<Output = O> + std::ops::Mul<Output = O>,
+{
+    let mut result = vec![O::default(); m * n];
+
+    for i in 0..m {
+        for j in 0..n {
+            let mut acc = O::default();
+            for kk in 0..k {
+                acc = acc + O::from(a[i * k + kk]) * O::from(b[kk * n + j]);
+            }
+            result[i * n + j] = acc;
+        }
+    }
+
+    result
+}
@@ -1,8 +1,10 @@
+#![allow(clippy::identity_op)]
+
 use std::arch::x86_64::*;
 
 use cfavml::danger::*;
 
-use crate::transpose::{generic_transpose, TransposeMatrix};
+use crate::transpose::{generic_transpose, generic_transpose_strided, TransposeMatrix};
 
 #[inline]
 #[target_feature(enable = "avx2")]
@@ -23,6 +25,36 @@ pub unsafe fn f32_xany_avx2_transpose(
     generic_transpose::<f32, Avx2>(width, height, data, result)
 }
 
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Performs a matrix transposition on 32 bit values with rows `src_row_stride`
+/// elements apart, writing the result with rows `dst_row_stride` elements apart.
+///
+/// # Safety
+///
+/// The strides must be at least `width` and `height` respectively and the input
+/// and output buffers _must_ be large enough to hold the strided matrices, see
+/// [transpose_matrix_strided](crate::transpose::transpose_matrix_strided).
+///
+/// This function also assumes `avx2` CPU features are available.
+pub unsafe fn f32_xany_avx2_transpose_strided(
+    width: usize,
+    height: usize,
+    src_row_stride: usize,
+    dst_row_stride: usize,
+    data: &[f32],
+    result: &mut [f32],
+) {
+    generic_transpose_strided::<f32, Avx2>(
+        width,
+        height,
+        src_row_stride,
+        dst_row_stride,
+        data,
+        result,
+    )
+}
+
 #[inline]
 #[target_feature(enable = "avx2")]
 /// Performs a matrix transposition on 64 bit values.
@@ -42,42 +74,170 @@ pub unsafe fn f64_xany_avx2_transpose(
     generic_transpose::<f64, Avx2>(width, height, data, result)
 }
 
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Performs a matrix transposition on 64 bit values with rows `src_row_stride`
+/// elements apart, writing the result with rows `dst_row_stride` elements apart.
+///
+/// # Safety
+///
+/// The strides must be at least `width` and `height` respectively and the input
+/// and output buffers _must_ be large enough to hold the strided matrices, see
+/// [transpose_matrix_strided](crate::transpose::transpose_matrix_strided).
+///
+/// This function also assumes `avx2` CPU features are available.
+pub unsafe fn f64_xany_avx2_transpose_strided(
+    width: usize,
+    height: usize,
+    src_row_stride: usize,
+    dst_row_stride: usize,
+    data: &[f64],
+    result: &mut [f64],
+) {
+    generic_transpose_strided::<f64, Avx2>(
+        width,
+        height,
+        src_row_stride,
+        dst_row_stride,
+        data,
+        result,
+    )
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Performs a matrix transposition on 16 bit values.
+///
+/// # Safety
+///
+/// The size of the input and output buffers _must_ be equal to the calculated size by doing
+/// `width * height`.
+///
+/// This function also assumes `avx2` CPU features are available.
+pub unsafe fn u16_xany_avx2_transpose(
+    width: usize,
+    height: usize,
+    data: &[u16],
+    result: &mut [u16],
+) {
+    generic_transpose::<u16, Avx2>(width, height, data, result)
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Performs a matrix transposition on 16 bit values with rows `src_row_stride`
+/// elements apart, writing the result with rows `dst_row_stride` elements apart.
+///
+/// # Safety
+///
+/// The strides must be at least `width` and `height` respectively and the input
+/// and output buffers _must_ be large enough to hold the strided matrices, see
+/// [transpose_matrix_strided](crate::transpose::transpose_matrix_strided).
+///
+/// This function also assumes `avx2` CPU features are available.
+pub unsafe fn u16_xany_avx2_transpose_strided(
+    width: usize,
+    height: usize,
+    src_row_stride: usize,
+    dst_row_stride: usize,
+    data: &[u16],
+    result: &mut [u16],
+) {
+    generic_transpose_strided::<u16, Avx2>(
+        width,
+        height,
+        src_row_stride,
+        dst_row_stride,
+        data,
+        result,
+    )
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Performs a matrix transposition on 8 bit values.
+///
+/// # Safety
+///
+/// The size of the input and output buffers _must_ be equal to the calculated size by doing
+/// `width * height`.
+///
+/// This function also assumes `avx2` CPU features are available.
+pub unsafe fn u8_xany_avx2_transpose(
+    width: usize,
+    height: usize,
+    data: &[u8],
+    result: &mut [u8],
+) {
+    generic_transpose::<u8, Avx2>(width, height, data, result)
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+/// Performs a matrix transposition on 8 bit values with rows `src_row_stride`
+/// elements apart, writing the result with rows `dst_row_stride` elements apart.
+///
+/// # Safety
+///
+/// The strides must be at least `width` and `height` respectively and the input
+/// and output buffers _must_ be large enough to hold the strided matrices, see
+/// [transpose_matrix_strided](crate::transpose::transpose_matrix_strided).
+///
+/// This function also assumes `avx2` CPU features are available.
+pub unsafe fn u8_xany_avx2_transpose_strided(
+    width: usize,
+    height: usize,
+    src_row_stride: usize,
+    dst_row_stride: usize,
+    data: &[u8],
+    result: &mut [u8],
+) {
+    generic_transpose_strided::<u8, Avx2>(
+        width,
+        height,
+        src_row_stride,
+        dst_row_stride,
+        data,
+        result,
+    )
+}
+
 impl TransposeMatrix<f32> for Avx2 {
     type RegisterMatrix = DenseLane<Self::Register>;
 
     #[inline(always)]
     unsafe fn load_matrix(
         offset: usize,
-        width: usize,
+        row_stride: usize,
         data_ptr: *const f32,
     ) -> Self::RegisterMatrix {
         DenseLane {
             a: Self::load(data_ptr.add(offset)),
-            b: Self::load(data_ptr.add(offset + (width * 1))),
-            c: Self::load(data_ptr.add(offset + (width * 2))),
-            d: Self::load(data_ptr.add(offset + (width * 3))),
-            e: Self::load(data_ptr.add(offset + (width * 4))),
-            f: Self::load(data_ptr.add(offset + (width * 5))),
-            g: Self::load(data_ptr.add(offset + (width * 6))),
-            h: Self::load(data_ptr.add(offset + (width * 7))),
+            b: Self::load(data_ptr.add(offset + (row_stride * 1))),
+            c: Self::load(data_ptr.add(offset + (row_stride * 2))),
+            d: Self::load(data_ptr.add(offset + (row_stride * 3))),
+            e: Self::load(data_ptr.add(offset + (row_stride * 4))),
+            f: Self::load(data_ptr.add(offset + (row_stride * 5))),
+            g: Self::load(data_ptr.add(offset + (row_stride * 6))),
+            h: Self::load(data_ptr.add(offset + (row_stride * 7))),
         }
     }
 
     #[inline(always)]
     unsafe fn write_matrix(
         offset: usize,
-        height: usize,
+        row_stride: usize,
         matrix: Self::RegisterMatrix,
         result_ptr: *mut f32,
     ) {
         Self::write(result_ptr.add(offset), matrix.a);
-        Self::write(result_ptr.add(offset + (1 * height)), matrix.b);
-        Self::write(result_ptr.add(offset + (2 * height)), matrix.c);
-        Self::write(result_ptr.add(offset + (3 * height)), matrix.d);
-        Self::write(result_ptr.add(offset + (4 * height)), matrix.e);
-        Self::write(result_ptr.add(offset + (5 * height)), matrix.f);
-        Self::write(result_ptr.add(offset + (6 * height)), matrix.g);
-        Self::write(result_ptr.add(offset + (7 * height)), matrix.h);
+        Self::write(result_ptr.add(offset + (1 * row_stride)), matrix.b);
+        Self::write(result_ptr.add(offset + (2 * row_stride)), matrix.c);
+        Self::write(result_ptr.add(offset + (3 * row_stride)), matrix.d);
+        Self::write(result_ptr.add(offset + (4 * row_stride)), matrix.e);
+        Self::write(result_ptr.add(offset + (5 * row_stride)), matrix.f);
+        Self::write(result_ptr.add(offset + (6 * row_stride)), matrix.g);
+        Self::write(result_ptr.add(offset + (7 * row_stride)), matrix.h);
     }
 
     #[inline(always)]
@@ -136,28 +296,28 @@ impl TransposeMatrix<f64> for Avx2 {
     #[inline(always)]
     unsafe fn load_matrix(
         offset: usize,
-        width: usize,
+        row_stride: usize,
         data_ptr: *const f64,
     ) -> Self::RegisterMatrix {
         Dense4x4Lane {
             a: Self::load(data_ptr.add(offset)),
-            b: Self::load(data_ptr.add(offset + (width * 1))),
-            c: Self::load(data_ptr.add(offset + (width * 2))),
-            d: Self::load(data_ptr.add(offset + (width * 3))),
+            b: Self::load(data_ptr.add(offset + (row_stride * 1))),
+            c: Self::load(data_ptr.add(offset + (row_stride * 2))),
+            d: Self::load(data_ptr.add(offset + (row_stride * 3))),
         }
     }
 
     #[inline(always)]
     unsafe fn write_matrix(
         offset: usize,
-        height: usize,
+        row_stride: usize,
         matrix: Self::RegisterMatrix,
         result_ptr: *mut f64,
     ) {
         Self::write(result_ptr.add(offset), matrix.a);
-        Self::write(result_ptr.add(offset + (1 * height)), matrix.b);
-        Self::write(result_ptr.add(offset + (2 * height)), matrix.c);
-        Self::write(result_ptr.add(offset + (3 * height)), matrix.d);
+        Self::write(result_ptr.add(offset + (1 * row_stride)), matrix.b);
+        Self::write(result_ptr.add(offset + (2 * row_stride)), matrix.c);
+        Self::write(result_ptr.add(offset + (3 * row_stride)), matrix.d);
     }
 
     #[inline(always)]
@@ -183,6 +343,226 @@ impl TransposeMatrix<f64> for Avx2 {
     }
 }
 
+impl TransposeMatrix<u16> for Avx2 {
+    type RegisterMatrix = [__m256i; 16];
+
+    #[inline(always)]
+    unsafe fn load_matrix(
+        offset: usize,
+        row_stride: usize,
+        data_ptr: *const u16,
+    ) -> Self::RegisterMatrix {
+        load_rows(offset, row_stride, data_ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn write_matrix(
+        offset: usize,
+        row_stride: usize,
+        matrix: Self::RegisterMatrix,
+        result_ptr: *mut u16,
+    ) {
+        write_rows(offset, row_stride, matrix, result_ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn transpose_register_matrix(
+        matrix: Self::RegisterMatrix,
+    ) -> Self::RegisterMatrix {
+        // The unpack stages transpose the `8x8` blocks held within each 128 bit
+        // lane of the top and bottom half of the rows independently.
+        let matrix = unpack_stage(matrix, 1, |a, b| {
+            (_mm256_unpacklo_epi16(a, b), _mm256_unpackhi_epi16(a, b))
+        });
+        let matrix = unpack_stage(matrix, 2, |a, b| {
+            (_mm256_unpacklo_epi32(a, b), _mm256_unpackhi_epi32(a, b))
+        });
+        let matrix = unpack_stage(matrix, 4, |a, b| {
+            (_mm256_unpacklo_epi64(a, b), _mm256_unpackhi_epi64(a, b))
+        });
+
+        merge_lane_halves(matrix)
+    }
+}
+
+impl TransposeMatrix<u8> for Avx2 {
+    type RegisterMatrix = [__m256i; 32];
+
+    #[inline(always)]
+    unsafe fn load_matrix(
+        offset: usize,
+        row_stride: usize,
+        data_ptr: *const u8,
+    ) -> Self::RegisterMatrix {
+        load_rows(offset, row_stride, data_ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn write_matrix(
+        offset: usize,
+        row_stride: usize,
+        matrix: Self::RegisterMatrix,
+        result_ptr: *mut u8,
+    ) {
+        write_rows(offset, row_stride, matrix, result_ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn transpose_register_matrix(
+        matrix: Self::RegisterMatrix,
+    ) -> Self::RegisterMatrix {
+        // The unpack stages transpose the `16x16` blocks held within each 128 bit
+        // lane of the top and bottom half of the rows independently.
+        let matrix = unpack_stage(matrix, 1, |a, b| {
+            (_mm256_unpacklo_epi8(a, b), _mm256_unpackhi_epi8(a, b))
+        });
+        let matrix = unpack_stage(matrix, 2, |a, b| {
+            (_mm256_unpacklo_epi16(a, b), _mm256_unpackhi_epi16(a, b))
+        });
+        let matrix = unpack_stage(matrix, 4, |a, b| {
+            (_mm256_unpacklo_epi32(a, b), _mm256_unpackhi_epi32(a, b))
+        });
+        let matrix = unpack_stage(matrix, 8, |a, b| {
+            (_mm256_unpacklo_epi64(a, b), _mm256_unpackhi_epi64(a, b))
+        });
+
+        merge_lane_halves(matrix)
+    }
+}
+
+impl TransposeMatrix<i16> for Avx2 {
+    type RegisterMatrix = [__m256i; 16];
+
+    #[inline(always)]
+    unsafe fn load_matrix(
+        offset: usize,
+        row_stride: usize,
+        data_ptr: *const i16,
+    ) -> Self::RegisterMatrix {
+        load_rows(offset, row_stride, data_ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn write_matrix(
+        offset: usize,
+        row_stride: usize,
+        matrix: Self::RegisterMatrix,
+        result_ptr: *mut i16,
+    ) {
+        write_rows(offset, row_stride, matrix, result_ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn transpose_register_matrix(
+        matrix: Self::RegisterMatrix,
+    ) -> Self::RegisterMatrix {
+        // Transposition only moves the elements, so the sign is irrelevant.
+        <Avx2 as TransposeMatrix<u16>>::transpose_register_matrix(matrix)
+    }
+}
+
+impl TransposeMatrix<i8> for Avx2 {
+    type RegisterMatrix = [__m256i; 32];
+
+    #[inline(always)]
+    unsafe fn load_matrix(
+        offset: usize,
+        row_stride: usize,
+        data_ptr: *const i8,
+    ) -> Self::RegisterMatrix {
+        load_rows(offset, row_stride, data_ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn write_matrix(
+        offset: usize,
+        row_stride: usize,
+        matrix: Self::RegisterMatrix,
+        result_ptr: *mut i8,
+    ) {
+        write_rows(offset, row_stride, matrix, result_ptr)
+    }
+
+    #[inline(always)]
+    unsafe fn transpose_register_matrix(
+        matrix: Self::RegisterMatrix,
+    ) -> Self::RegisterMatrix {
+        // Transposition only moves the elements, so the sign is irrelevant.
+        <Avx2 as TransposeMatrix<u8>>::transpose_register_matrix(matrix)
+    }
+}
+
+#[inline(always)]
+/// Loads `N` rows starting at `offset`, each `row_stride` elements apart.
+unsafe fn load_rows<T, const N: usize>(
+    offset: usize,
+    row_stride: usize,
+    data_ptr: *const T,
+) -> [__m256i; N] {
+    let mut matrix = [_mm256_setzero_si256(); N];
+    for (row, reg) in matrix.iter_mut().enumerate() {
+        *reg = _mm256_loadu_si256(data_ptr.add(offset + (row_stride * row)).cast());
+    }
+    matrix
+}
+
+#[inline(always)]
+/// Writes `N` rows to `result_ptr` starting at `offset`, each `row_stride` elements
+/// apart.
+unsafe fn write_rows<T, const N: usize>(
+    offset: usize,
+    row_stride: usize,
+    matrix: [__m256i; N],
+    result_ptr: *mut T,
+) {
+    for (row, reg) in matrix.iter().enumerate() {
+        _mm256_storeu_si256(result_ptr.add(offset + (row_stride * row)).cast(), *reg);
+    }
+}
+
+#[inline(always)]
+/// Interleaves each row with the row `distance` rows below it, writing the
+/// unpacked low and high elements of the pair next to one another.
+///
+/// Applying this with a doubling `distance` and element width transposes
+/// the blocks held within each 128 bit lane.
+fn unpack_stage<const N: usize>(
+    matrix: [__m256i; N],
+    distance: usize,
+    unpack: impl Fn(__m256i, __m256i) -> (__m256i, __m256i),
+) -> [__m256i; N] {
+    let mut result = matrix;
+
+    let mut group = 0;
+    while group < N {
+        for n in 0..distance {
+            let (lo, hi) = unpack(matrix[group + n], matrix[group + n + distance]);
+            result[group + (n * 2)] = lo;
+            result[group + (n * 2) + 1] = hi;
+        }
+
+        group += distance * 2;
+    }
+
+    result
+}
+
+#[inline(always)]
+/// Combines the in-lane transposed top and bottom halves of the rows, the low lanes
+/// forming the top half of the result and the high lanes forming the bottom half.
+unsafe fn merge_lane_halves<const N: usize>(matrix: [__m256i; N]) -> [__m256i; N] {
+    let half = N / 2;
+
+    let mut result = matrix;
+    for row in 0..half {
+        let top = matrix[row];
+        let bottom = matrix[row + half];
+        result[row] = _mm256_permute2x128_si256::<0x20>(top, bottom);
+        result[row + half] = _mm256_permute2x128_si256::<0x31>(top, bottom);
+    }
+    result
+}
+
 #[allow(non_snake_case)]
 const fn _MM_SHUFFLE(z: u32, y: u32, x: u32, w: u32) -> i32 {
     ((z << 6) | (y << 4) | (x << 2) | w) as i32
@@ -191,10 +571,45 @@ const fn _MM_SHUFFLE(z: u32, y: u32, x: u32, w: u32) -> i32 {
 #[cfg(all(test, not(miri)))] // This is just very expensive to do
 mod tests {
     use super::*;
-    use crate::transpose::test_suite::run_test_suites_f32;
+    use crate::transpose::test_suite::{
+        run_random_shape_tests,
+        run_strided_shape_tests,
+        run_test_suites_f32,
+    };
 
     #[test]
     fn test_avx2_f32() {
         run_test_suites_f32::<Avx2>();
+        run_strided_shape_tests::<f32, Avx2>();
+    }
+
+    #[test]
+    fn test_avx2_f64() {
+        run_random_shape_tests::<f64, Avx2>();
+        run_strided_shape_tests::<f64, Avx2>();
+    }
+
+    #[test]
+    fn test_avx2_u16() {
+        run_random_shape_tests::<u16, Avx2>();
+        run_strided_shape_tests::<u16, Avx2>();
+    }
+
+    #[test]
+    fn test_avx2_u8() {
+        run_random_shape_tests::<u8, Avx2>();
+        run_strided_shape_tests::<u8, Avx2>();
+    }
+
+    #[test]
+    fn test_avx2_i16() {
+        run_random_shape_tests::<i16, Avx2>();
+        run_strided_shape_tests::<i16, Avx2>();
+    }
+
+    #[test]
+    fn test_avx2_i8() {
+        run_random_shape_tests::<i8, Avx2>();
+        run_strided_shape_tests::<i8, Avx2>();
     }
 }
@@ -21,17 +21,44 @@ where
         "Output buffer does not match input data"
     );
 
-    if width == 0 || height == 0 {
+    if width == 1 || height == 1 {
+        result.copy_from_slice(data);
         return;
     }
 
-    if width == 1 || height == 1 {
-        result.copy_from_slice(data);
+    transpose_matrix_strided(width, height, width, height, data, result)
+}
+
+/// Transpose a `width x height` matrix with rows `src_row_stride` elements apart,
+/// writing the `height x width` result with rows `dst_row_stride` elements apart.
+///
+/// This allows transposing a block out of (or into) a larger matrix without first
+/// copying it to a contiguous buffer. Any elements of `result` between the end of one
+/// row and the start of the next are left untouched.
+///
+/// # Panics
+///
+/// If `src_row_stride` is smaller than `width`, `dst_row_stride` is smaller than
+/// `height` or either buffer is too small to hold the strided matrix.
+pub fn transpose_matrix_strided<T>(
+    width: usize,
+    height: usize,
+    src_row_stride: usize,
+    dst_row_stride: usize,
+    data: &[T],
+    result: &mut [T],
+) where
+    T: Copy + 'static,
+{
+    assert_strided_shape(width, height, src_row_stride, dst_row_stride, data, result);
+
+    if width == 0 || height == 0 {
         return;
     }
 
     if TypeId::of::<T>() == TypeId::of::<f32>()
         || TypeId::of::<T>() == TypeId::of::<u32>()
+        || TypeId::of::<T>() == TypeId::of::<i32>()
     {
         let data = unsafe { mem::transmute::<&[T], &[f32]>(data) };
         let result = unsafe { mem::transmute::<&mut [T], &mut [f32]>(result) };
@@ -39,11 +66,19 @@ where
         #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         unsafe {
             if is_x86_feature_detected!("avx2") {
-                return f32_xany_avx2_transpose(width, height, data, result);
+                return f32_xany_avx2_transpose_strided(
+                    width,
+                    height,
+                    src_row_stride,
+                    dst_row_stride,
+                    data,
+                    result,
+                );
             }
         }
     } else if TypeId::of::<T>() == TypeId::of::<f64>()
         || TypeId::of::<T>() == TypeId::of::<u64>()
+        || TypeId::of::<T>() == TypeId::of::<i64>()
     {
         let data = unsafe { mem::transmute::<&[T], &[f64]>(data) };
         let result = unsafe { mem::transmute::<&mut [T], &mut [f64]>(result) };
@@ -51,7 +86,52 @@ where
         #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         unsafe {
             if is_x86_feature_detected!("avx2") {
-                return f64_xany_avx2_transpose(width, height, data, result);
+                return f64_xany_avx2_transpose_strided(
+                    width,
+                    height,
+                    src_row_stride,
+                    dst_row_stride,
+                    data,
+                    result,
+                );
+            }
+        }
+    } else if TypeId::of::<T>() == TypeId::of::<u16>()
+        || TypeId::of::<T>() == TypeId::of::<i16>()
+    {
+        let data = unsafe { mem::transmute::<&[T], &[u16]>(data) };
+        let result = unsafe { mem::transmute::<&mut [T], &mut [u16]>(result) };
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        unsafe {
+            if is_x86_feature_detected!("avx2") {
+                return u16_xany_avx2_transpose_strided(
+                    width,
+                    height,
+                    src_row_stride,
+                    dst_row_stride,
+                    data,
+                    result,
+                );
+            }
+        }
+    } else if TypeId::of::<T>() == TypeId::of::<u8>()
+        || TypeId::of::<T>() == TypeId::of::<i8>()
+    {
+        let data = unsafe { mem::transmute::<&[T], &[u8]>(data) };
+        let result = unsafe { mem::transmute::<&mut [T], &mut [u8]>(result) };
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        unsafe {
+            if is_x86_feature_detected!("avx2") {
+                return u8_xany_avx2_transpose_strided(
+                    width,
+                    height,
+                    src_row_stride,
+                    dst_row_stride,
+                    data,
+                    result,
+                );
             }
         }
     }
@@ -62,8 +142,8 @@ where
         let mut i = 0;
         while i < width {
             unsafe {
-                *result.get_unchecked_mut(i * height + j) =
-                    *data.get_unchecked(j * width + i);
+                *result.get_unchecked_mut(i * dst_row_stride + j) =
+                    *data.get_unchecked(j * src_row_stride + i);
             }
 
             i += 1;
@@ -73,6 +153,38 @@ where
     }
 }
 
+/// Checks the strides and buffers are large enough for the given matrix shape.
+fn assert_strided_shape<T>(
+    width: usize,
+    height: usize,
+    src_row_stride: usize,
+    dst_row_stride: usize,
+    data: &[T],
+    result: &[T],
+) {
+    assert!(
+        src_row_stride >= width,
+        "Source row stride is smaller than the matrix width"
+    );
+    assert!(
+        dst_row_stride >= height,
+        "Destination row stride is smaller than the matrix height"
+    );
+
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    assert!(
+        data.len() >= (height - 1) * src_row_stride + width,
+        "Input data shape missmatch"
+    );
+    assert!(
+        result.len() >= (width - 1) * dst_row_stride + height,
+        "Output buffer is too small for the transposed matrix"
+    );
+}
+
 /// Transpose a full width x height matrix.
 unsafe fn generic_transpose<T, R>(
     width: usize,
@@ -90,6 +202,24 @@ unsafe fn generic_transpose<T, R>(
         "Output buffer does not match input data"
     );
 
+    generic_transpose_strided::<T, R>(width, height, width, height, data, result)
+}
+
+/// Transpose a width x height matrix with rows `src_row_stride` elements apart,
+/// writing the result with rows `dst_row_stride` elements apart.
+unsafe fn generic_transpose_strided<T, R>(
+    width: usize,
+    height: usize,
+    src_row_stride: usize,
+    dst_row_stride: usize,
+    data: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T> + TransposeMatrix<T>,
+{
+    assert_strided_shape(width, height, src_row_stride, dst_row_stride, data, result);
+
     let data_ptr = data.as_ptr();
     let result_ptr = result.as_mut_ptr();
 
@@ -109,42 +239,53 @@ unsafe fn generic_transpose<T, R>(
             // benefit and was overall a net less according to benchmarks.
 
             // top-left
-            let l1 = R::load_matrix(i + j * width, width, data_ptr);
+            let l1 = R::load_matrix(i + j * src_row_stride, src_row_stride, data_ptr);
             let l1_transpose = R::transpose_register_matrix(l1);
-            R::write_matrix(j + i * height, height, l1_transpose, result_ptr);
+            R::write_matrix(
+                j + i * dst_row_stride,
+                dst_row_stride,
+                l1_transpose,
+                result_ptr,
+            );
 
             // bottom-left
-            let l1 =
-                R::load_matrix(i + (j + matrix_offset_step) * width, width, data_ptr);
+            let l1 = R::load_matrix(
+                i + (j + matrix_offset_step) * src_row_stride,
+                src_row_stride,
+                data_ptr,
+            );
             let l1_transpose = R::transpose_register_matrix(l1);
             R::write_matrix(
-                (j + matrix_offset_step) + i * height,
-                height,
+                (j + matrix_offset_step) + i * dst_row_stride,
+                dst_row_stride,
                 l1_transpose,
                 result_ptr,
             );
 
             // top-right
-            let l1 =
-                R::load_matrix((i + matrix_offset_step) + j * width, width, data_ptr);
+            let l1 = R::load_matrix(
+                (i + matrix_offset_step) + j * src_row_stride,
+                src_row_stride,
+                data_ptr,
+            );
             let l1_transpose = R::transpose_register_matrix(l1);
             R::write_matrix(
-                j + (i + matrix_offset_step) * height,
-                height,
+                j + (i + matrix_offset_step) * dst_row_stride,
+                dst_row_stride,
                 l1_transpose,
                 result_ptr,
             );
 
             // bottom-right
             let l1 = R::load_matrix(
-                (i + matrix_offset_step) + (j + matrix_offset_step) * width,
-                width,
+                (i + matrix_offset_step) + (j + matrix_offset_step) * src_row_stride,
+                src_row_stride,
                 data_ptr,
             );
             let l1_transpose = R::transpose_register_matrix(l1);
             R::write_matrix(
-                (j + matrix_offset_step) + (i + matrix_offset_step) * height,
-                height,
+                (j + matrix_offset_step) + (i + matrix_offset_step) * dst_row_stride,
+                dst_row_stride,
                 l1_transpose,
                 result_ptr,
             );
@@ -160,8 +301,8 @@ unsafe fn generic_transpose<T, R>(
     while j_remainder < (height - height_remainder) {
         let mut i = width - width_remainder;
         while i < width {
-            *result.get_unchecked_mut(i * height + j_remainder) =
-                *data.get_unchecked(j_remainder * width + i);
+            *result.get_unchecked_mut(i * dst_row_stride + j_remainder) =
+                *data.get_unchecked(j_remainder * src_row_stride + i);
 
             i += 1;
         }
@@ -173,8 +314,8 @@ unsafe fn generic_transpose<T, R>(
     while j < height {
         let mut i = 0;
         while i < width {
-            *result.get_unchecked_mut(i * height + j) =
-                *data.get_unchecked(j * width + i);
+            *result.get_unchecked_mut(i * dst_row_stride + j) =
+                *data.get_unchecked(j * src_row_stride + i);
 
             i += 1;
         }
@@ -189,24 +330,25 @@ unsafe fn generic_transpose<T, R>(
 /// process blocks in 4x4 sub-matrices, assuming sub-matrices will be
 /// made up of 4 [Self::RegisterMatrix] types in order to maximise
 /// cache locality.
-trait TransposeMatrix<T>: SimdRegister<T>
+pub(crate) trait TransposeMatrix<T>: SimdRegister<T>
 where
     T: Copy,
 {
     /// The type representing `NxN` matrix within SIMD registers.
     type RegisterMatrix;
 
-    /// Load a new `NxN` matrix into registers.
+    /// Load a new `NxN` matrix into registers, each row `row_stride` elements apart.
     unsafe fn load_matrix(
         offset: usize,
-        width: usize,
+        row_stride: usize,
         data_ptr: *const T,
     ) -> Self::RegisterMatrix;
 
-    /// Store a `NxN` matrix in the `result_ptr` buffer.
+    /// Store a `NxN` matrix in the `result_ptr` buffer, each row `row_stride`
+    /// elements apart.
     unsafe fn write_matrix(
         offset: usize,
-        height: usize,
+        row_stride: usize,
         matrix: Self::RegisterMatrix,
         result_ptr: *mut T,
     );
@@ -218,6 +360,7 @@ where
 }
 
 #[cfg(test)]
+#[allow(clippy::identity_op)]
 mod test_suite {
     use super::*;
 
@@ -259,25 +402,168 @@ mod test_suite {
         unsafe { generic_transpose::<f32, R>(3, 3, &input_matrix, &mut result) };
         assert_eq!(result, expected_matrix);
 
-        println!("Running 13x19 matrix");
-        let (input_matrix, _) = crate::test_utils::get_sample_vectors(13 * 19);
-        let expected_matrix = crate::test_utils::basic_transpose(13, 19, &input_matrix);
-        let mut result = [999.0; 13 * 19];
-        unsafe { generic_transpose::<f32, R>(13, 19, &input_matrix, &mut result) };
-        assert_eq!(&result, expected_matrix.as_slice());
-
-        println!("Running 639x63 matrix");
-        let (input_matrix, _) = crate::test_utils::get_sample_vectors(639 * 63);
-        let expected_matrix = crate::test_utils::basic_transpose(639, 63, &input_matrix);
-        let mut result = [999.0; 639 * 63];
-        unsafe { generic_transpose::<f32, R>(639, 63, &input_matrix, &mut result) };
-        assert_eq!(&result, expected_matrix.as_slice());
-
-        println!("Running 1x2 matrix");
-        let (input_matrix, _) = crate::test_utils::get_sample_vectors(1 * 2);
-        let expected_matrix = crate::test_utils::basic_transpose(1, 2, &input_matrix);
-        let mut result = [999.0; 1 * 2];
-        unsafe { generic_transpose::<f32, R>(1, 2, &input_matrix, &mut result) };
-        assert_eq!(&result, expected_matrix.as_slice());
+        run_random_shape_tests::<f32, R>();
+    }
+
+    /// Transposes randomly generated matrices of various shapes, including ones
+    /// larger than a full block of sub-matrices for every register width.
+    pub fn run_random_shape_tests<T, R>()
+    where
+        T: Copy + Default + PartialEq + std::fmt::Debug,
+        R: TransposeMatrix<T> + SimdRegister<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let shapes = [(13, 19), (639, 63), (1, 2), (64, 64), (130, 97), (97, 130)];
+        for (width, height) in shapes {
+            println!("Running {width}x{height} matrix");
+            let (input_matrix, _) =
+                crate::test_utils::get_sample_vectors(width * height);
+            let expected_matrix =
+                crate::test_utils::basic_transpose(width, height, &input_matrix);
+            let mut result = vec![T::default(); width * height];
+            unsafe {
+                generic_transpose::<T, R>(width, height, &input_matrix, &mut result)
+            };
+            assert_eq!(result, expected_matrix);
+        }
+    }
+
+    /// Transposes randomly generated blocks out of larger matrices, checking the
+    /// padding between the rows of the result is left untouched.
+    pub fn run_strided_shape_tests<T, R>()
+    where
+        T: Copy + Default + PartialEq + std::fmt::Debug,
+        R: TransposeMatrix<T> + SimdRegister<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let shapes = [(13, 19), (1, 2), (64, 64), (130, 97), (97, 130)];
+        for (width, height) in shapes {
+            let (src_row_stride, dst_row_stride) = (width + 7, height + 3);
+            println!(
+                "Running {width}x{height} matrix with strides \
+                {src_row_stride} and {dst_row_stride}"
+            );
+
+            let (data, padding) =
+                crate::test_utils::get_sample_vectors(height * src_row_stride);
+            let mut result = padding[..width * dst_row_stride].to_vec();
+            let expected_matrix = strided_transpose(
+                width,
+                height,
+                src_row_stride,
+                dst_row_stride,
+                &data,
+                &result,
+            );
+
+            unsafe {
+                generic_transpose_strided::<T, R>(
+                    width,
+                    height,
+                    src_row_stride,
+                    dst_row_stride,
+                    &data,
+                    &mut result,
+                )
+            };
+            assert_eq!(result, expected_matrix);
+        }
+    }
+
+    /// The basic strided transposition of the `width x height` block in `data`
+    /// written over a copy of `result`.
+    fn strided_transpose<T: Copy>(
+        width: usize,
+        height: usize,
+        src_row_stride: usize,
+        dst_row_stride: usize,
+        data: &[T],
+        result: &[T],
+    ) -> Vec<T> {
+        let mut result = result.to_vec();
+        for i in 0..width {
+            for j in 0..height {
+                result[i * dst_row_stride + j] = data[j * src_row_stride + i];
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_transpose_matrix_strided_all_types() {
+        fn check<T>()
+        where
+            T: Copy + Default + PartialEq + std::fmt::Debug + 'static,
+            rand::distributions::Standard: rand::distributions::Distribution<T>,
+        {
+            let (width, height, src_row_stride, dst_row_stride) = (130, 97, 150, 100);
+            let (data, padding) =
+                crate::test_utils::get_sample_vectors(height * src_row_stride);
+            let mut result = padding[..width * dst_row_stride].to_vec();
+            let expected_matrix = strided_transpose(
+                width,
+                height,
+                src_row_stride,
+                dst_row_stride,
+                &data,
+                &result,
+            );
+
+            transpose_matrix_strided(
+                width,
+                height,
+                src_row_stride,
+                dst_row_stride,
+                &data,
+                &mut result,
+            );
+            assert_eq!(result, expected_matrix);
+        }
+
+        check::<f32>();
+        check::<f64>();
+        check::<i8>();
+        check::<i16>();
+        check::<i32>();
+        check::<i64>();
+        check::<u8>();
+        check::<u16>();
+        check::<u32>();
+        check::<u64>();
+    }
+
+    #[test]
+    #[should_panic(expected = "Source row stride is smaller than the matrix width")]
+    fn test_transpose_matrix_strided_bad_stride() {
+        let data = [0.0f32; 16];
+        let mut result = [0.0f32; 16];
+        transpose_matrix_strided(4, 4, 3, 4, &data, &mut result);
+    }
+
+    #[test]
+    fn test_transpose_matrix_all_types() {
+        fn check<T>()
+        where
+            T: Copy + Default + PartialEq + std::fmt::Debug + 'static,
+            rand::distributions::Standard: rand::distributions::Distribution<T>,
+        {
+            let (input_matrix, _) = crate::test_utils::get_sample_vectors(130 * 97);
+            let expected_matrix =
+                crate::test_utils::basic_transpose(130, 97, &input_matrix);
+            let mut result = vec![T::default(); 130 * 97];
+            transpose_matrix(130, 97, &input_matrix, &mut result);
+            assert_eq!(result, expected_matrix);
+        }
+
+        check::<f32>();
+        check::<f64>();
+        check::<i8>();
+        check::<i16>();
+        check::<i32>();
+        check::<i64>();
+        check::<u8>();
+        check::<u16>();
+        check::<u32>();
+        check::<u64>();
     }
 }
@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+use nalgebra::{Dim, Matrix, RawStorage, Scalar};
+
+/// Returns the elements of the matrix in column-major order, borrowing the underlying
+/// buffer if the storage is contiguous and copying the elements otherwise.
+///
+/// This is what makes views over a sub-block of a matrix, or any other view with a
+/// custom stride, safe to pass to the `cfavml` routines which only operate on
+/// contiguous buffers.
+pub(crate) fn as_contiguous<T, R, C, S>(matrix: &Matrix<T, R, C, S>) -> Cow<'_, [T]>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    if matrix.data.is_contiguous() {
+        // SAFETY: The storage has just been checked to be contiguous.
+        Cow::Borrowed(unsafe { matrix.data.as_slice_unchecked() })
+    } else {
+        Cow::Owned(matrix.iter().cloned().collect())
+    }
+}
+
+/// Asserts the two matrices have the same number of rows and columns.
+pub(crate) fn assert_same_shape(lhs: (usize, usize), rhs: (usize, usize)) {
+    assert_eq!(
+        lhs, rhs,
+        "Cannot combine matrix of shape {lhs:?} with matrix of shape {rhs:?}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{DMatrix, DVector};
+
+    use super::*;
+
+    #[test]
+    fn test_as_contiguous() {
+        let vector = DVector::from_vec(vec![1.0f32, 2.0, 3.0]);
+        assert!(matches!(as_contiguous(&vector), Cow::Borrowed(_)));
+        assert!(matches!(
+            as_contiguous(&vector.rows(1, 2)),
+            Cow::Borrowed(_)
+        ));
+
+        let matrix = DMatrix::from_fn(4, 3, |i, j| (i * 3 + j) as f32);
+        assert!(matches!(as_contiguous(&matrix), Cow::Borrowed(_)));
+        assert!(matches!(
+            as_contiguous(&matrix.columns(1, 2)),
+            Cow::Borrowed(_)
+        ));
+
+        let block = matrix.view((1, 1), (2, 2));
+        let copied = as_contiguous(&block);
+        assert!(matches!(copied, Cow::Owned(_)));
+        assert_eq!(copied.as_ref(), block.iter().copied().collect::<Vec<_>>());
+
+        let stepped = matrix.rows_with_step(0, 2, 1);
+        assert_eq!(
+            as_contiguous(&stepped).as_ref(),
+            stepped.iter().copied().collect::<Vec<_>>(),
+        );
+    }
+}
@@ -0,0 +1,8 @@
+#![doc = include_str!("../README.md")]
+
+mod layout;
+pub mod matrix;
+pub mod vector;
+
+pub use self::matrix::{FastMatrixOps, Gemm};
+pub use self::vector::FastVectorOps;
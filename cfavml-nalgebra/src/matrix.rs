@@ -0,0 +1,196 @@
+//! Accelerated matrix multiplication over dynamically sized matrices.
+//!
+//! `nalgebra` stores matrices in column-major order while `cfavml-gemm` assumes
+//! row-major order. Rather than transposing the inputs, we make use of the fact that
+//! a column-major `m x n` buffer is the same as a row-major `n x m` buffer of its
+//! transpose.
+//!
+//! This means `C = A @ B` in column-major order can be computed by the row-major routine
+//! as `C^T = B^T @ A^T`, passing the buffers of `B` and `A` through untouched and
+//! producing the column-major buffer of `C` directly.
+
+use nalgebra::{DMatrix, Dim, Dyn, Matrix, RawStorage, Scalar};
+
+use crate::layout::as_contiguous;
+
+/// A general matrix multiply routine provided by `cfavml-gemm`.
+pub trait Gemm: Scalar + Copy + Default {
+    /// Performs a row-major matrix multiplication of `a @ b`, writing the result to `c`.
+    fn gemm(
+        shape_a: (usize, usize),
+        shape_b: (usize, usize),
+        a: &[Self],
+        b: &[Self],
+        c: &mut [Self],
+    );
+}
+
+impl Gemm for f32 {
+    #[inline]
+    fn gemm(
+        shape_a: (usize, usize),
+        shape_b: (usize, usize),
+        a: &[Self],
+        b: &[Self],
+        c: &mut [Self],
+    ) {
+        cfavml_gemm::f32_gemm(shape_a, shape_b, a, b, c)
+    }
+}
+
+impl Gemm for f64 {
+    #[inline]
+    fn gemm(
+        shape_a: (usize, usize),
+        shape_b: (usize, usize),
+        a: &[Self],
+        b: &[Self],
+        c: &mut [Self],
+    ) {
+        cfavml_gemm::f64_gemm(shape_a, shape_b, a, b, c)
+    }
+}
+
+/// Accelerated matrix multiplication over `nalgebra` matrices.
+pub trait FastMatrixOps<T> {
+    /// Performs a matrix multiplication of `self @ rhs`, the equivalent of `self * rhs`.
+    ///
+    /// Matrices which are not contiguous, i.e. a view over a sub-block of a matrix,
+    /// are copied into a temporary contiguous buffer first.
+    ///
+    /// ### Panics
+    ///
+    /// If the number of columns in `self` does not match the number of rows in `rhs`.
+    fn fast_mul<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> DMatrix<T>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>;
+}
+
+impl<T, R, C, S> FastMatrixOps<T> for Matrix<T, R, C, S>
+where
+    T: Gemm,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    fn fast_mul<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> DMatrix<T>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>,
+    {
+        let (m, k) = self.shape();
+        let (rhs_rows, n) = rhs.shape();
+        assert_eq!(
+            k, rhs_rows,
+            "Number of columns in the left hand side does not match the number of \
+             rows in the right hand side"
+        );
+
+        let a = as_contiguous(self);
+        let b = as_contiguous(rhs);
+
+        let mut result = DMatrix::from_element_generic(Dyn(m), Dyn(n), T::default());
+        T::gemm(
+            (n, k),
+            (k, m),
+            b.as_ref(),
+            a.as_ref(),
+            result.as_mut_slice(),
+        );
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZES: [usize; 7] = [1, 2, 7, 16, 33, 64, 129];
+
+    fn sample_matrix(rows: usize, cols: usize, seed: f64) -> DMatrix<f64> {
+        DMatrix::from_fn(rows, cols, |i, j| {
+            ((i * cols + j) as f64 * 0.13 + seed).sin()
+        })
+    }
+
+    fn assert_matrix_close<S1, S2>(
+        actual: &Matrix<f64, Dyn, Dyn, S1>,
+        expected: &Matrix<f64, Dyn, Dyn, S2>,
+        tolerance: f64,
+    ) where
+        S1: RawStorage<f64, Dyn, Dyn>,
+        S2: RawStorage<f64, Dyn, Dyn>,
+    {
+        assert_eq!(actual.shape(), expected.shape(), "Shape mismatch");
+        for (i, (a, b)) in actual.iter().zip(expected.iter()).enumerate() {
+            assert!(
+                (a - b).abs() <= tolerance,
+                "value mismatch at {i} {a} vs {b}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_fast_mul_f64() {
+        for m in SIZES {
+            for k in SIZES {
+                for n in [1, 5, 64, 130] {
+                    let a = sample_matrix(m, k, 0.5);
+                    let b = sample_matrix(k, n, 1.5);
+
+                    assert_matrix_close(&a.fast_mul(&b), &(&a * &b), 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fast_mul_f32() {
+        for m in SIZES {
+            for (k, n) in [(1, 3), (17, 33), (64, 8), (129, 65)] {
+                let a = sample_matrix(m, k, 0.5).cast::<f32>();
+                let b = sample_matrix(k, n, 1.5).cast::<f32>();
+
+                let actual = a.fast_mul(&b).cast::<f64>();
+                let expected = (&a * &b).cast::<f64>();
+                assert_matrix_close(&actual, &expected, 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fast_mul_views() {
+        let a = sample_matrix(40, 30, 0.5);
+        let b = sample_matrix(30, 50, 1.5);
+
+        let a_block = a.view((3, 2), (17, 21));
+        let b_block = b.view((5, 7), (21, 13));
+        assert_matrix_close(&a_block.fast_mul(&b_block), &(a_block * b_block), 1e-9);
+
+        let a_t = a.transpose();
+        let b_t = b.transpose();
+        assert_matrix_close(&b_t.fast_mul(&a_t), &(&a * &b).transpose(), 1e-9);
+
+        let column = b.column(3);
+        let expected = DMatrix::from_column_slice(40, 1, (&a * column).as_slice());
+        assert_matrix_close(&a.fast_mul(&column), &expected, 1e-9);
+    }
+
+    #[test]
+    fn test_fast_mul_empty() {
+        let a = DMatrix::<f32>::zeros(4, 0);
+        let b = DMatrix::<f32>::zeros(0, 3);
+        assert_eq!(a.fast_mul(&b), DMatrix::zeros(4, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of columns")]
+    fn test_fast_mul_shape_mismatch_panics() {
+        let a = DMatrix::<f32>::zeros(3, 4);
+        let b = DMatrix::<f32>::zeros(3, 4);
+        a.fast_mul(&b);
+    }
+}
@@ -0,0 +1,293 @@
+//! Distance and element wise arithmetic routines over vectors and matrices.
+//!
+//! Matrices are treated as a flat buffer of their elements in column-major order,
+//! which means the routines work the same over a `DVector`, `DVectorView` or `DMatrix`.
+//! Storage which is not contiguous, i.e. a view over a sub-block of a matrix, is copied
+//! into a temporary contiguous buffer before being passed to the `cfavml` routines.
+
+use cfavml::buffer::WriteOnlyBuffer;
+use cfavml::safe_trait_arithmetic_ops::ArithmeticOps;
+use cfavml::safe_trait_distance_ops::DistanceOps;
+use nalgebra::allocator::Allocator;
+use nalgebra::{DefaultAllocator, Dim, Matrix, OMatrix, RawStorage, Scalar};
+
+use crate::layout::{as_contiguous, assert_same_shape};
+
+/// Accelerated distance and arithmetic routines over `nalgebra` vectors and matrices.
+///
+/// Each routine requires both operands to have the same shape, if you want to compare
+/// a column vector against a row vector you should transpose one of them first.
+pub trait FastVectorOps<T> {
+    /// The owned matrix type produced by the element wise routines.
+    type Output;
+
+    /// Calculates the dot product of `self` and `rhs`.
+    ///
+    /// See [cfavml::dot] for more details.
+    ///
+    /// ### Panics
+    ///
+    /// If `self` and `rhs` do not match in shape.
+    fn fast_dot<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> T
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>;
+
+    /// Calculates the cosine distance of `self` and `rhs`.
+    ///
+    /// See [cfavml::cosine] for more details.
+    ///
+    /// ### Panics
+    ///
+    /// If `self` and `rhs` do not match in shape.
+    fn fast_cosine<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> T
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>;
+
+    /// Calculates the squared Euclidean distance of `self` and `rhs`.
+    ///
+    /// See [cfavml::squared_euclidean] for more details.
+    ///
+    /// ### Panics
+    ///
+    /// If `self` and `rhs` do not match in shape.
+    fn fast_squared_euclidean<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> T
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>;
+
+    /// Performs an element wise addition of `self` and `rhs`.
+    ///
+    /// See [cfavml::add_vertical] for more details.
+    ///
+    /// ### Panics
+    ///
+    /// If `self` and `rhs` do not match in shape.
+    fn fast_add<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> Self::Output
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>;
+
+    /// Performs an element wise subtraction of `rhs` from `self`.
+    ///
+    /// See [cfavml::sub_vertical] for more details.
+    ///
+    /// ### Panics
+    ///
+    /// If `self` and `rhs` do not match in shape.
+    fn fast_sub<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> Self::Output
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>;
+
+    /// Performs an element wise multiplication of `self` and `rhs`, this is the
+    /// equivalent of `nalgebra`'s `component_mul`.
+    ///
+    /// See [cfavml::mul_vertical] for more details.
+    ///
+    /// ### Panics
+    ///
+    /// If `self` and `rhs` do not match in shape.
+    fn fast_component_mul<R2, C2, S2>(
+        &self,
+        rhs: &Matrix<T, R2, C2, S2>,
+    ) -> Self::Output
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>;
+}
+
+macro_rules! distance_op {
+    ($lhs:expr, $rhs:expr, $op:path) => {{
+        assert_same_shape($lhs.shape(), $rhs.shape());
+        let lhs = as_contiguous($lhs);
+        let rhs = as_contiguous($rhs);
+        $op(lhs.as_ref(), rhs.as_ref())
+    }};
+}
+
+macro_rules! vertical_op {
+    ($lhs:expr, $rhs:expr, $op:path) => {{
+        assert_same_shape($lhs.shape(), $rhs.shape());
+        let (nrows, ncols) = $lhs.shape_generic();
+        let mut result = OMatrix::from_element_generic(nrows, ncols, T::default());
+
+        let lhs = as_contiguous($lhs);
+        let rhs = as_contiguous($rhs);
+        $op(lhs.as_ref(), rhs.as_ref(), result.as_mut_slice());
+        result
+    }};
+}
+
+impl<T, R, C, S> FastVectorOps<T> for Matrix<T, R, C, S>
+where
+    T: Scalar + Copy + Default + DistanceOps + ArithmeticOps,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+    DefaultAllocator: Allocator<R, C>,
+    for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+{
+    type Output = OMatrix<T, R, C>;
+
+    fn fast_dot<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> T
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>,
+    {
+        distance_op!(self, rhs, cfavml::dot)
+    }
+
+    fn fast_cosine<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> T
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>,
+    {
+        distance_op!(self, rhs, cfavml::cosine)
+    }
+
+    fn fast_squared_euclidean<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> T
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>,
+    {
+        distance_op!(self, rhs, cfavml::squared_euclidean)
+    }
+
+    fn fast_add<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> Self::Output
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>,
+    {
+        vertical_op!(self, rhs, cfavml::add_vertical)
+    }
+
+    fn fast_sub<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> Self::Output
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>,
+    {
+        vertical_op!(self, rhs, cfavml::sub_vertical)
+    }
+
+    fn fast_component_mul<R2, C2, S2>(&self, rhs: &Matrix<T, R2, C2, S2>) -> Self::Output
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: RawStorage<T, R2, C2>,
+    {
+        vertical_op!(self, rhs, cfavml::mul_vertical)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{DMatrix, DVector};
+
+    use super::*;
+
+    const SIZES: [usize; 6] = [0, 1, 7, 32, 129, 1043];
+
+    fn sample_vector(len: usize, offset: f64) -> DVector<f64> {
+        DVector::from_fn(len, |i, _| ((i as f64 + offset) * 0.37).sin())
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        let scale = actual.abs().max(expected.abs()).max(1.0);
+        assert!(
+            (actual - expected).abs() <= 1e-9 * scale,
+            "value mismatch {actual} vs {expected}",
+        );
+    }
+
+    #[test]
+    fn test_vector_distance() {
+        for len in SIZES {
+            let a = sample_vector(len, 1.0);
+            let b = sample_vector(len, 4.0);
+
+            assert_close(a.fast_dot(&b), a.dot(&b));
+            assert_close(a.fast_squared_euclidean(&b), (&a - &b).norm_squared());
+
+            let expected_cosine = if len == 0 {
+                0.0
+            } else {
+                1.0 - a.dot(&b) / (a.norm() * b.norm())
+            };
+            assert_close(a.fast_cosine(&b), expected_cosine);
+        }
+    }
+
+    #[test]
+    fn test_vector_arithmetic() {
+        for len in SIZES {
+            let a = sample_vector(len, 1.0);
+            let b = sample_vector(len, 4.0);
+
+            assert_eq!(a.fast_add(&b), &a + &b);
+            assert_eq!(a.fast_sub(&b), &a - &b);
+            assert_eq!(a.fast_component_mul(&b), a.component_mul(&b));
+        }
+    }
+
+    #[test]
+    fn test_vector_views() {
+        let a = sample_vector(64, 0.0);
+        let b = sample_vector(96, 3.0);
+
+        let b_view = b.rows(16, 64);
+        assert_close(a.fast_dot(&b_view), a.dot(&b_view));
+        assert_eq!(a.fast_add(&b_view), &a + b_view);
+
+        let a_stepped = a.rows_with_step(1, 32, 1);
+        let b_stepped = b.rows_with_step(2, 32, 2);
+        assert_close(a_stepped.fast_dot(&b_stepped), a_stepped.dot(&b_stepped));
+        assert_eq!(a_stepped.fast_sub(&b_stepped), a_stepped - b_stepped);
+    }
+
+    #[test]
+    fn test_matrix_elementwise() {
+        for rows in [1, 5, 33] {
+            for cols in [1, 4, 17] {
+                let a = DMatrix::from_fn(rows, cols, |i, j| (i * cols + j) as f64 * 0.5);
+                let b = DMatrix::from_fn(rows, cols, |i, j| (j * rows + i) as f64 - 3.0);
+
+                assert_eq!(a.fast_add(&b), &a + &b);
+                assert_eq!(a.fast_sub(&b), &a - &b);
+                assert_eq!(a.fast_component_mul(&b), a.component_mul(&b));
+                assert_eq!(a.fast_dot(&b), a.dot(&b));
+
+                let a_t = a.transpose();
+                let b_t = b.transpose();
+                assert_eq!(a_t.fast_add(&b_t), a.fast_add(&b).transpose());
+            }
+        }
+
+        let a = DMatrix::from_fn(8, 8, |i, j| (i + j) as i32);
+        let b = DMatrix::from_fn(8, 8, |i, j| (i * j) as i32);
+        let a_block = a.view((2, 1), (4, 5));
+        let b_block = b.view((1, 3), (4, 5));
+        assert_eq!(a_block.fast_add(&b_block), a_block + b_block);
+        assert_eq!(a_block.fast_dot(&b_block), a_block.dot(&b_block));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot combine matrix")]
+    fn test_shape_mismatch_panics() {
+        let a = DMatrix::<f32>::zeros(3, 4);
+        let b = DMatrix::<f32>::zeros(4, 3);
+        a.fast_add(&b);
+    }
+}
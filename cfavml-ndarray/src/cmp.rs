@@ -0,0 +1,232 @@
+//! Element wise comparison routines over 1-D and 2-D arrays.
+//!
+//! Available as both functions and methods via the `Cmp*Fast` traits, i.e. `gt(&a, 0.5)`
+//! or `a.cmp_gt(0.5)`.
+//!
+//! Each routine produces a mask where `1` is `true` and `0` is `false`, following the
+//! same semantics, including `NaN` handling, as the `cfavml` vertical comparison
+//! routines.
+//!
+//! Operands follow the same `ndarray` broadcasting rules as the [ops](crate::ops)
+//! routines, the right hand side can be an array with the same or fewer dimensions
+//! as the left hand side, or a single scalar value.
+//!
+//! When both operands are contiguous and in standard layout the whole array is
+//! processed in a single call to the `cfavml` vertical routine, otherwise the op
+//! is applied row by row.
+
+use cfavml::buffer::WriteOnlyBuffer;
+use cfavml::mem_loader::{IntoMemLoader, MemLoader};
+use cfavml::safe_trait_cmp_ops::CmpOps;
+use ndarray::{Array, Array1, Array2, ArrayBase, ArrayView1, Data, Dimension, Ix1, Ix2};
+
+use crate::ops::{broadcast_op, define_fast_op, VerticalOp};
+
+define_fast_op!(
+    trait = CmpEqFast,
+    method = cmp_eq,
+    func = eq,
+    op = EqOp,
+    vertical = eq_vertical,
+    bound = CmpOps,
+    desc = "equal to comparison",
+    note = "The result is a mask where `1` is `true` and `0` is `false`.",
+);
+define_fast_op!(
+    trait = CmpNeqFast,
+    method = cmp_neq,
+    func = neq,
+    op = NeqOp,
+    vertical = neq_vertical,
+    bound = CmpOps,
+    desc = "not equal to comparison",
+    note = "The result is a mask where `1` is `true` and `0` is `false`.",
+);
+define_fast_op!(
+    trait = CmpLtFast,
+    method = cmp_lt,
+    func = lt,
+    op = LtOp,
+    vertical = lt_vertical,
+    bound = CmpOps,
+    desc = "less than comparison",
+    note = "The result is a mask where `1` is `true` and `0` is `false`.",
+);
+define_fast_op!(
+    trait = CmpLteFast,
+    method = cmp_lte,
+    func = lte,
+    op = LteOp,
+    vertical = lte_vertical,
+    bound = CmpOps,
+    desc = "less than or equal to comparison",
+    note = "The result is a mask where `1` is `true` and `0` is `false`.",
+);
+define_fast_op!(
+    trait = CmpGtFast,
+    method = cmp_gt,
+    func = gt,
+    op = GtOp,
+    vertical = gt_vertical,
+    bound = CmpOps,
+    desc = "greater than comparison",
+    note = "The result is a mask where `1` is `true` and `0` is `false`.",
+);
+define_fast_op!(
+    trait = CmpGteFast,
+    method = cmp_gte,
+    func = gte,
+    op = GteOp,
+    vertical = gte_vertical,
+    bound = CmpOps,
+    desc = "greater than or equal to comparison",
+    note = "The result is a mask where `1` is `true` and `0` is `false`.",
+);
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{array, s, ArrayView, ShapeBuilder, Zip};
+
+    use super::*;
+
+    fn sample_matrix(shape: (usize, usize)) -> Array2<f32> {
+        Array2::from_shape_fn(shape, |(i, j)| (i * shape.1 + j) as f32 + 1.0)
+    }
+
+    fn sample_f_matrix(shape: (usize, usize)) -> Array2<f32> {
+        Array2::from_shape_fn(shape.f(), |(i, j)| (i * shape.1 + j) as f32 * 0.5 + 1.0)
+    }
+
+    fn sample_vector(len: usize) -> Array1<f32> {
+        Array1::from_shape_fn(len, |i| i as f32 * 0.25 + 1.0)
+    }
+
+    /// Computes the expected mask of `op` over `lhs` and `rhs` broadcast together.
+    fn expected_mask<D1, D2>(
+        lhs: &ArrayView<f32, D1>,
+        rhs: &ArrayView<f32, D2>,
+        op: fn(&f32, &f32) -> bool,
+    ) -> Array<f32, D1>
+    where
+        D1: Dimension,
+        D2: Dimension,
+    {
+        Zip::from(lhs)
+            .and_broadcast(rhs)
+            .map_collect(|a, b| if op(a, b) { 1.0 } else { 0.0 })
+    }
+
+    /// Checks each cmp op against the scalar `ndarray` comparison.
+    macro_rules! check_cmp_ops {
+        ($lhs:expr, scalar = $rhs:expr) => {{
+            let lhs = &$lhs;
+            let rhs = array![$rhs];
+            let (l, r) = (lhs.view(), rhs.view());
+
+            assert_eq!(eq(lhs, $rhs), expected_mask(&l, &r, f32::eq));
+            assert_eq!(neq(lhs, $rhs), expected_mask(&l, &r, f32::ne));
+            assert_eq!(lhs.cmp_lt($rhs), expected_mask(&l, &r, f32::lt));
+            assert_eq!(lhs.cmp_lte($rhs), expected_mask(&l, &r, f32::le));
+            assert_eq!(lhs.cmp_gt($rhs), expected_mask(&l, &r, f32::gt));
+            assert_eq!(lhs.cmp_gte($rhs), expected_mask(&l, &r, f32::ge));
+        }};
+        ($lhs:expr, $rhs:expr) => {{
+            let lhs = &$lhs;
+            let rhs = &$rhs;
+            let (l, r) = (lhs.view(), rhs.view());
+
+            assert_eq!(eq(lhs, rhs), expected_mask(&l, &r, f32::eq));
+            assert_eq!(neq(lhs, rhs), expected_mask(&l, &r, f32::ne));
+            assert_eq!(lhs.cmp_lt(rhs), expected_mask(&l, &r, f32::lt));
+            assert_eq!(lhs.cmp_lte(rhs), expected_mask(&l, &r, f32::le));
+            assert_eq!(lhs.cmp_gt(rhs), expected_mask(&l, &r, f32::gt));
+            assert_eq!(lhs.cmp_gte(rhs), expected_mask(&l, &r, f32::ge));
+        }};
+    }
+
+    #[test]
+    fn test_cmp_standard_layout() {
+        let a = array![1.0f32, 2.0, 3.0, f32::NAN];
+        let b = array![1.0f32, 3.0, 2.0, f32::NAN];
+
+        assert_eq!(eq(&a, &b), array![1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(neq(&a, &b), array![0.0, 1.0, 1.0, 1.0]);
+        assert_eq!(lt(&a, &b), array![0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(lte(&a, &b), array![1.0, 1.0, 0.0, 0.0]);
+        assert_eq!(gt(&a, &b), array![0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(gte(&a, &b), array![1.0, 0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_cmp_scalar_broadcast_1d() {
+        let a = Array1::from_shape_fn(37, |i| (i % 5) as f32 * 0.25);
+
+        check_cmp_ops!(a, scalar = 0.5);
+        check_cmp_ops!(a.view(), scalar = 0.0);
+        check_cmp_ops!(a.slice(s![..;-2]), scalar = 0.75);
+        check_cmp_ops!(Array1::<f32>::zeros(0), scalar = 1.0);
+
+        let mask = array![0.1f32, 0.5, 0.9, f32::NAN].cmp_gt(0.5);
+        assert_eq!(mask, array![0.0, 0.0, 1.0, 0.0]);
+        let mask = neq(&array![f32::NAN, 1.0], f32::NAN);
+        assert_eq!(mask, array![1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_cmp_array_broadcast() {
+        let a = sample_vector(37);
+        let b = sample_vector(37).mapv(|v| 10.0 - v);
+
+        check_cmp_ops!(a, b);
+        check_cmp_ops!(a, Array1::from_elem(1, 4.0f32));
+        check_cmp_ops!(sample_matrix((5, 37)), sample_vector(37).mapv(|v| v * 6.0));
+        check_cmp_ops!(sample_f_matrix((5, 37)), sample_matrix((5, 37)));
+        check_cmp_ops!(sample_matrix((5, 37)), scalar = 40.0);
+    }
+
+    #[test]
+    fn test_cmp_length_one_broadcast() {
+        let a = array![1i32, 2, 3, 4, 5];
+        let value = array![3i32];
+
+        assert_eq!(eq(&a, &value), array![0, 0, 1, 0, 0]);
+        assert_eq!(lt(&a, &value), array![1, 1, 0, 0, 0]);
+        assert_eq!(lt(&value, &a), array![0, 0, 0, 1, 1]);
+        assert_eq!(gte(&value, &value), array![1]);
+    }
+
+    #[test]
+    fn test_cmp_non_standard_layout() {
+        let a = array![1u8, 9, 2, 9, 3, 9, 4, 9];
+        let b = array![4u8, 3, 2, 1];
+
+        let stepped = a.slice(s![..;2]);
+        let reversed = b.slice(s![..;-1]);
+        assert!(!stepped.is_standard_layout());
+        assert!(!reversed.is_standard_layout());
+
+        assert_eq!(eq(&stepped, &reversed), array![1, 1, 1, 1]);
+        assert_eq!(gt(&stepped, &b), array![0, 0, 1, 1]);
+        assert_eq!(lt(&b.slice(s![..;-2]), &array![2u8]), array![1, 0]);
+    }
+
+    #[test]
+    fn test_cmp_integer() {
+        let a = Array::from_shape_fn((4, 9), |(i, j)| (i * 9 + j) as i32 - 10);
+
+        let expected = a.mapv(|v| (v > 0) as i32);
+        assert_eq!(a.cmp_gt(0), expected);
+        assert_eq!(gt(&a.t(), 0), expected.t());
+
+        let row = Array1::from_shape_fn(9, |i| i as u8);
+        assert_eq!(row.cmp_lte(4u8), row.mapv(|v| (v <= 4) as u8));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot broadcast")]
+    fn test_cmp_broadcast_mismatch_panics() {
+        let a = array![1.0f64, 2.0, 3.0];
+        let b = array![1.0f64, 2.0];
+        eq(&a, &b);
+    }
+}
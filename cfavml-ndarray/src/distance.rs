@@ -0,0 +1,303 @@
+//! Distance routines over 1-D and 2-D arrays.
+//!
+//! Available as both functions and methods via the `*Fast` traits, i.e. `dot(&a, &b)`
+//! or `a.dot_fast(&b)`. Like `ndarray`'s own `dot`, the dot product of a matrix and
+//! a vector produces the dot product of each row of the matrix against the vector.
+//!
+//! Arrays which are not in standard layout are copied into a contiguous buffer
+//! before being passed to the `cfavml` routines.
+
+use cfavml::buffer::WriteOnlyBuffer;
+use cfavml::math::{AutoMath, Math};
+use cfavml::safe_trait_distance_ops::DistanceOps;
+use ndarray::{Array1, ArrayBase, Data, Ix1, Ix2};
+
+use crate::layout::{as_slice, to_contiguous};
+
+/// Calculates the Euclidean distance of `a` and `b` from the squared distance.
+fn euclidean_slice<T>(a: &[T], b: &[T]) -> T
+where
+    T: DistanceOps,
+    AutoMath: Math<T>,
+{
+    AutoMath::sqrt(cfavml::squared_euclidean(a, b))
+}
+
+macro_rules! define_fast_distance {
+    (
+        trait = $trait_name:ident,
+        method = $method:ident,
+        func = $func:ident,
+        routine = $routine:path,
+        see = $see:ident,
+        desc = $desc:literal,
+        $(bound = $bound:ty,)?
+    ) => {
+        #[doc = concat!("Calculates the ", $desc, " of the array and `rhs`.")]
+        #[doc = ""]
+        #[doc = concat!("See [cfavml::", stringify!($see), "] for more details.")]
+        pub trait $trait_name<Rhs> {
+            /// The resulting distance type.
+            type Output;
+
+            #[doc = concat!("Calculates the ", $desc, " of `self` and `rhs`.")]
+            #[doc = ""]
+            #[doc = "### Panics"]
+            #[doc = ""]
+            #[doc = "If `self` and `rhs` do not match in length."]
+            fn $method(&self, rhs: Rhs) -> Self::Output;
+        }
+
+        impl<T, S1, S2> $trait_name<&ArrayBase<S2, Ix1>> for ArrayBase<S1, Ix1>
+        where
+            T: DistanceOps,
+            S1: Data<Elem = T>,
+            S2: Data<Elem = T>,
+            $($bound: Math<T>,)?
+        {
+            type Output = T;
+
+            fn $method(&self, rhs: &ArrayBase<S2, Ix1>) -> Self::Output {
+                let a = to_contiguous(self);
+                let b = to_contiguous(rhs);
+                $routine(as_slice(&a), as_slice(&b))
+            }
+        }
+
+        #[inline]
+        #[doc = concat!("Calculates the ", $desc, " of `a` and `b`.")]
+        #[doc = ""]
+        #[doc = concat!("See [", stringify!($trait_name), "] for the supported operands.")]
+        #[doc = ""]
+        #[doc = "### Panics"]
+        #[doc = ""]
+        #[doc = "If `a` and `b` do not match in length."]
+        pub fn $func<L, Rhs>(a: &L, b: Rhs) -> L::Output
+        where
+            L: $trait_name<Rhs>,
+        {
+            a.$method(b)
+        }
+    };
+}
+
+define_fast_distance!(
+    trait = DotFast,
+    method = dot_fast,
+    func = dot,
+    routine = cfavml::dot,
+    see = dot,
+    desc = "dot product",
+);
+define_fast_distance!(
+    trait = CosineFast,
+    method = cosine_fast,
+    func = cosine,
+    routine = cfavml::cosine,
+    see = cosine,
+    desc = "cosine distance",
+);
+define_fast_distance!(
+    trait = SquaredEuclideanFast,
+    method = squared_euclidean_fast,
+    func = squared_euclidean,
+    routine = cfavml::squared_euclidean,
+    see = squared_euclidean,
+    desc = "squared Euclidean distance",
+);
+define_fast_distance!(
+    trait = EuclideanFast,
+    method = euclidean_fast,
+    func = euclidean,
+    routine = euclidean_slice,
+    see = squared_euclidean,
+    desc = "Euclidean distance",
+    bound = AutoMath,
+);
+
+impl<T, S1, S2> DotFast<&ArrayBase<S2, Ix1>> for ArrayBase<S1, Ix2>
+where
+    T: DistanceOps + Default,
+    S1: Data<Elem = T>,
+    S2: Data<Elem = T>,
+    for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+{
+    type Output = Array1<T>;
+
+    /// Calculates the matrix-vector product of `self` and `rhs`, as [dot_rows].
+    fn dot_fast(&self, rhs: &ArrayBase<S2, Ix1>) -> Self::Output {
+        dot_rows(self, rhs)
+    }
+}
+
+#[inline]
+/// Calculates the dot product between the `query` vector and each row of `matrix`,
+/// returning a vector with one value per row.
+///
+/// See [cfavml::dot_batch] for more details.
+///
+/// ### Panics
+///
+/// If the number of columns in `matrix` does not match the length of `query`.
+pub fn dot_rows<T, S1, S2>(
+    matrix: &ArrayBase<S1, Ix2>,
+    query: &ArrayBase<S2, Ix1>,
+) -> Array1<T>
+where
+    T: DistanceOps + Default,
+    S1: Data<Elem = T>,
+    S2: Data<Elem = T>,
+    for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+{
+    assert_eq!(
+        matrix.ncols(),
+        query.len(),
+        "Number of matrix columns does not match the length of the query"
+    );
+
+    let matrix = to_contiguous(matrix);
+    let query = to_contiguous(query);
+
+    let mut result = Array1::default(matrix.nrows());
+    cfavml::dot_batch(
+        as_slice(&query),
+        as_slice(&matrix),
+        result.as_slice_mut().unwrap(),
+    );
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{array, s, Array2, Axis, ShapeBuilder};
+
+    use super::*;
+
+    fn basic_dot_rows<S1, S2>(
+        matrix: &ArrayBase<S1, Ix2>,
+        query: &ArrayBase<S2, Ix1>,
+    ) -> Array1<f32>
+    where
+        S1: Data<Elem = f32>,
+        S2: Data<Elem = f32>,
+    {
+        matrix
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().zip(query).map(|(a, b)| a * b).sum())
+            .collect()
+    }
+
+    #[test]
+    fn test_distance_standard_layout() {
+        let a = array![1.0f32, 2.0, 3.0, 4.0];
+        let b = array![4.0f32, 3.0, 2.0, 1.0];
+
+        assert_eq!(dot(&a, &b), 20.0);
+        assert_eq!(dot(&a.view(), &b.view()), 20.0);
+        assert_eq!(squared_euclidean(&a, &b), 20.0);
+        assert!((cosine(&a, &a) - 0.0f32).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_distance_non_standard_layout() {
+        let a = array![1.0f64, -1.0, 2.0, -1.0, 3.0, -1.0, 4.0, -1.0];
+        let b = array![4.0f64, 3.0, 2.0, 1.0];
+
+        let stepped = a.slice(s![..;2]);
+        assert!(!stepped.is_standard_layout());
+        assert_eq!(dot(&stepped, &b), 20.0);
+        assert_eq!(squared_euclidean(&stepped, &b), 20.0);
+
+        let reversed = b.slice(s![..;-1]);
+        assert!(!reversed.is_standard_layout());
+        assert_eq!(dot(&stepped, &reversed), 30.0);
+        assert_eq!(squared_euclidean(&stepped, &reversed), 0.0);
+        assert!(cosine(&stepped, &reversed).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_distance_matches_ndarray_arithmetic() {
+        let a = Array1::from_shape_fn(131, |i| i as f64 * 0.25 - 7.0);
+        let b = Array1::from_shape_fn(131, |i| (i % 7) as f64 - 3.0);
+
+        let expected_dot = (&a * &b).sum();
+        assert_eq!(dot(&a, &b), expected_dot);
+        assert_eq!(a.dot_fast(&b.view()), expected_dot);
+
+        let diff = &a - &b;
+        let expected_squared = (&diff * &diff).sum();
+        assert_eq!(squared_euclidean(&a, &b), expected_squared);
+        assert_eq!(a.squared_euclidean_fast(&b), expected_squared);
+        assert!((euclidean(&a, &b) - expected_squared.sqrt()).abs() < 1e-9);
+        assert!((a.euclidean_fast(&b) - expected_squared.sqrt()).abs() < 1e-9);
+
+        let norms = (&a * &a).sum().sqrt() * (&b * &b).sum().sqrt();
+        let expected_cosine = 1.0 - expected_dot / norms;
+        assert!((cosine(&a, &b) - expected_cosine).abs() < 1e-9);
+        assert!((a.cosine_fast(&b) - expected_cosine).abs() < 1e-9);
+
+        let stepped = a.slice(s![..;3]);
+        let reversed = b.slice(s![..;-3]);
+        let expected_dot = (&stepped * &reversed).sum();
+        assert_eq!(dot(&stepped, &reversed), expected_dot);
+        assert_eq!(stepped.dot_fast(&reversed), expected_dot);
+
+        let a = Array1::from_shape_fn(67, |i| i as i32 - 30);
+        let b = Array1::from_shape_fn(67, |i| (i % 5) as i32);
+        assert_eq!(dot(&a, &b), (&a * &b).sum());
+        assert_eq!(euclidean(&array![0i32, 0], &array![3i32, 4]), 5);
+    }
+
+    #[test]
+    fn test_dot_matrix_vector_matches_ndarray_arithmetic() {
+        let matrix = Array2::from_shape_fn((7, 33), |(i, j)| (i * 33 + j) as f32 * 0.5);
+        let query = Array1::from_shape_fn(33, |i| i as f32 - 16.0);
+
+        assert_eq!(dot(&matrix, &query), (&matrix * &query).sum_axis(Axis(1)));
+        assert_eq!(
+            matrix.dot_fast(&query),
+            (&matrix * &query).sum_axis(Axis(1))
+        );
+
+        let f_order =
+            Array2::from_shape_fn((7, 33).f(), |(i, j)| (i * 33 + j) as f32 * 0.5);
+        assert_eq!(dot(&f_order, &query), (&f_order * &query).sum_axis(Axis(1)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dot_length_mismatch_panics() {
+        let a = array![1.0f32, 2.0, 3.0];
+        let b = array![1.0f32, 2.0];
+        dot(&a, &b);
+    }
+
+    #[test]
+    fn test_dot_rows() {
+        let matrix = Array2::from_shape_fn((5, 3), |(i, j)| (i * 3 + j) as f32);
+        let query = array![1.0f32, 0.5, -1.0];
+        let expected = basic_dot_rows(&matrix, &query);
+
+        assert_eq!(dot_rows(&matrix, &query), expected);
+
+        let f_order = Array2::from_shape_fn((5, 3).f(), |(i, j)| (i * 3 + j) as f32);
+        assert!(!f_order.is_standard_layout());
+        assert_eq!(dot_rows(&f_order, &query), expected);
+
+        let transposed = matrix.t().to_owned();
+        assert_eq!(dot_rows(&transposed.t(), &query), expected);
+
+        let reversed = matrix.slice(s![..;-1, ..]);
+        let expected_reversed = basic_dot_rows(&reversed, &query);
+        assert_eq!(dot_rows(&reversed, &query.slice(s![..])), expected_reversed);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of matrix columns")]
+    fn test_dot_rows_shape_mismatch_panics() {
+        let matrix = Array2::<f32>::zeros((4, 3));
+        let query = array![1.0f32, 2.0];
+        dot_rows(&matrix, &query);
+    }
+}
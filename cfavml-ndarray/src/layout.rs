@@ -0,0 +1,58 @@
+use ndarray::{ArrayBase, CowArray, Data, Dimension};
+
+/// Returns a view of the array in standard layout, copying the elements into a new
+/// contiguous buffer if the array is not already in standard layout.
+///
+/// This is what makes stepped slices, reversed axes and f-order arrays safe to pass
+/// to the `cfavml` routines, which only operate on contiguous buffers.
+pub(crate) fn to_contiguous<S, D>(array: &ArrayBase<S, D>) -> CowArray<'_, S::Elem, D>
+where
+    S: Data,
+    S::Elem: Clone,
+    D: Dimension,
+{
+    array.as_standard_layout()
+}
+
+/// Returns the contiguous buffer of an array produced by [to_contiguous].
+pub(crate) fn as_slice<'a, A, D>(array: &'a CowArray<'_, A, D>) -> &'a [A]
+where
+    D: Dimension,
+{
+    array
+        .as_slice()
+        .expect("Array should be in standard layout")
+}
+
+/// Returns the shape produced by broadcasting an array of shape `lhs` against an array
+/// of shape `rhs` following the `ndarray` broadcasting rules.
+///
+/// `rhs` must have the same or fewer dimensions as `lhs`, the trailing axes
+/// of each shape are compared and missing axes are treated as having a length of `1`.
+///
+/// # Panics
+///
+/// If the shapes cannot be broadcast together.
+pub(crate) fn broadcast_shape<D>(lhs: &[usize], rhs: &[usize]) -> D
+where
+    D: Dimension,
+{
+    assert!(
+        rhs.len() <= lhs.len(),
+        "Right hand side array cannot have more dimensions than the left hand side"
+    );
+
+    let offset = lhs.len() - rhs.len();
+    let mut shape = D::zeros(lhs.len());
+    for (axis, &len) in lhs.iter().enumerate() {
+        let other = if axis < offset { 1 } else { rhs[axis - offset] };
+        shape[axis] = if len == other || other == 1 {
+            len
+        } else if len == 1 {
+            other
+        } else {
+            panic!("Cannot broadcast array of shape {lhs:?} with array of shape {rhs:?}")
+        };
+    }
+    shape
+}
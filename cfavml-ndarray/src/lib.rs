@@ -0,0 +1,49 @@
+#![doc = include_str!("../README.md")]
+
+pub mod cmp;
+pub mod distance;
+mod layout;
+pub mod ops;
+
+pub use self::cmp::{
+    eq,
+    gt,
+    gte,
+    lt,
+    lte,
+    neq,
+    CmpEqFast,
+    CmpGtFast,
+    CmpGteFast,
+    CmpLtFast,
+    CmpLteFast,
+    CmpNeqFast,
+};
+pub use self::distance::{
+    cosine,
+    dot,
+    dot_rows,
+    euclidean,
+    squared_euclidean,
+    CosineFast,
+    DotFast,
+    EuclideanFast,
+    SquaredEuclideanFast,
+};
+pub use self::ops::{
+    add,
+    div,
+    max,
+    mean_axis,
+    min,
+    mul,
+    norm_axis,
+    sub,
+    sum_axis,
+    AddFast,
+    DivFast,
+    MaxFast,
+    MinFast,
+    MulFast,
+    SubFast,
+};
@@ -0,0 +1,631 @@
+//! Element wise arithmetic routines over 1-D and 2-D arrays, along with the horizontal
+//! reductions of 2-D arrays along an axis.
+//!
+//! Operands follow the `ndarray` broadcasting rules, the right hand side can be an array
+//! with the same or fewer dimensions as the left hand side, or a single scalar value.
+//! For example, a `(3, 4)` matrix can be combined with another `(3, 4)` matrix,
+//! a `(1, 4)` or `(3, 1)` matrix, a row vector of length `4` or a scalar.
+//!
+//! When both operands are contiguous and in standard layout the whole array is
+//! processed in a single call to the `cfavml` vertical routine, otherwise the op
+//! is applied row by row. Rows which are neither contiguous nor broadcast are copied
+//! into a temporary buffer first.
+//!
+//! The axis reductions, [sum_axis], [mean_axis] and [norm_axis], reduce each lane along
+//! the axis with a single call to the `cfavml` horizontal routine. Lanes which are not
+//! contiguous, i.e. the columns of a c-order matrix, are copied into a temporary buffer first.
+
+use std::borrow::Cow;
+
+use cfavml::buffer::WriteOnlyBuffer;
+use cfavml::math::{AutoMath, CastAs, Math};
+use cfavml::mem_loader::{BroadcastLoader, IntoMemLoader, MemLoader};
+use cfavml::safe_trait_agg_ops::AggOps;
+use cfavml::safe_trait_arithmetic_ops::ArithmeticOps;
+use cfavml::safe_trait_cmp_ops::CmpOps;
+use cfavml::safe_trait_distance_ops::DistanceOps;
+use ndarray::{
+    Array,
+    Array1,
+    Array2,
+    ArrayBase,
+    ArrayView,
+    ArrayView1,
+    Axis,
+    Data,
+    Dimension,
+    Ix1,
+    Ix2,
+};
+
+use crate::layout::broadcast_shape;
+
+/// A vertical routine which can be applied to any pair of memory loaders.
+pub(crate) trait VerticalOp<T> {
+    fn apply<B1, B2>(lhs: B1, rhs: B2, result: &mut [T])
+    where
+        B1: IntoMemLoader<T>,
+        B1::Loader: MemLoader<Value = T>,
+        B2: IntoMemLoader<T>,
+        B2::Loader: MemLoader<Value = T>;
+}
+
+/// A single operand of a vertical routine.
+enum Operand<'a, T: Clone> {
+    /// A contiguous buffer of values, borrowed where possible.
+    Buffer(Cow<'a, [T]>),
+    /// A single value broadcast across the whole output.
+    Broadcast(T),
+}
+
+impl<'a, T: Clone> Operand<'a, T> {
+    /// Attempts to operate on the whole view at once, returning `None` if
+    /// the view must be processed row by row.
+    fn whole<D: Dimension>(view: &ArrayView<'a, T, D>) -> Option<Self> {
+        if !view.is_empty() && view.strides().iter().all(|&stride| stride == 0) {
+            return view.first().cloned().map(Self::Broadcast);
+        }
+
+        view.to_slice()
+            .map(|slice| Self::Buffer(Cow::Borrowed(slice)))
+    }
+
+    /// Prepares a single row of a view, copying the row if it is not contiguous.
+    fn row(view: ArrayView1<'a, T>) -> Self {
+        if view.len() > 1 && view.strides()[0] == 0 {
+            return Self::Broadcast(view[0].clone());
+        }
+
+        match view.to_slice() {
+            Some(slice) => Self::Buffer(Cow::Borrowed(slice)),
+            None => Self::Buffer(Cow::Owned(view.to_vec())),
+        }
+    }
+}
+
+/// Applies the op to the operands, writing the result to `result`.
+fn apply_operands<T, O>(lhs: Operand<T>, rhs: Operand<T>, result: &mut [T])
+where
+    T: Copy,
+    O: VerticalOp<T>,
+{
+    let len = result.len();
+    match (lhs, rhs) {
+        (Operand::Buffer(lhs), Operand::Buffer(rhs)) => O::apply(&*lhs, &*rhs, result),
+        (Operand::Buffer(lhs), Operand::Broadcast(rhs)) => {
+            O::apply(&*lhs, BroadcastLoader::new(rhs, len), result)
+        },
+        (Operand::Broadcast(lhs), Operand::Buffer(rhs)) => {
+            O::apply(BroadcastLoader::new(lhs, len), &*rhs, result)
+        },
+        (Operand::Broadcast(lhs), Operand::Broadcast(rhs)) => O::apply(
+            BroadcastLoader::new(lhs, len),
+            BroadcastLoader::new(rhs, len),
+            result,
+        ),
+    }
+}
+
+/// Broadcasts `lhs` and `rhs` to the same shape and applies the op.
+pub(crate) fn broadcast_op<T, O, S1, S2, D1, D2>(
+    lhs: &ArrayBase<S1, D1>,
+    rhs: &ArrayBase<S2, D2>,
+) -> Array<T, D1>
+where
+    T: Copy + Default,
+    O: VerticalOp<T>,
+    S1: Data<Elem = T>,
+    S2: Data<Elem = T>,
+    D1: Dimension,
+    D2: Dimension,
+{
+    let shape: D1 = broadcast_shape(lhs.shape(), rhs.shape());
+    let lhs = lhs.broadcast(shape.clone()).unwrap();
+    let rhs = rhs.broadcast(shape.clone()).unwrap();
+
+    let mut result = Array::default(shape);
+    if let (Some(l), Some(r)) = (Operand::whole(&lhs), Operand::whole(&rhs)) {
+        apply_operands::<T, O>(l, r, result.as_slice_mut().unwrap());
+        return result;
+    }
+
+    let rows = lhs.rows().into_iter().zip(rhs.rows());
+    for ((l, r), mut out) in rows.zip(result.rows_mut()) {
+        let out = out.as_slice_mut().unwrap();
+        apply_operands::<T, O>(Operand::row(l), Operand::row(r), out);
+    }
+
+    result
+}
+
+macro_rules! define_fast_op {
+    (
+        trait = $trait_name:ident,
+        method = $method:ident,
+        func = $func:ident,
+        op = $op:ident,
+        vertical = $vertical:ident,
+        bound = $bound:ident,
+        desc = $desc:literal,
+        $(note = $note:literal,)?
+    ) => {
+        struct $op;
+
+        impl<T> VerticalOp<T> for $op
+        where
+            T: $bound,
+            for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+        {
+            #[inline]
+            fn apply<B1, B2>(lhs: B1, rhs: B2, result: &mut [T])
+            where
+                B1: IntoMemLoader<T>,
+                B1::Loader: MemLoader<Value = T>,
+                B2: IntoMemLoader<T>,
+                B2::Loader: MemLoader<Value = T>,
+            {
+                cfavml::$vertical(lhs, rhs, result)
+            }
+        }
+
+        #[doc = concat!("Performs an element wise ", $desc, " of the array and `rhs`.")]
+        $(
+            #[doc = ""]
+            #[doc = $note]
+        )?
+        #[doc = ""]
+        #[doc = concat!("See [cfavml::", stringify!($vertical), "] for more details.")]
+        pub trait $trait_name<Rhs> {
+            /// The resulting array type.
+            type Output;
+
+            #[doc = concat!("Performs an element wise ", $desc, " of `self` and `rhs`.")]
+            #[doc = ""]
+            #[doc = "### Panics"]
+            #[doc = ""]
+            #[doc = "If `self` and `rhs` cannot be broadcast to the same shape."]
+            fn $method(&self, rhs: Rhs) -> Self::Output;
+        }
+
+        impl<T, S1, S2> $trait_name<&ArrayBase<S2, Ix1>> for ArrayBase<S1, Ix1>
+        where
+            T: $bound + Default,
+            S1: Data<Elem = T>,
+            S2: Data<Elem = T>,
+            for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+        {
+            type Output = Array1<T>;
+
+            fn $method(&self, rhs: &ArrayBase<S2, Ix1>) -> Self::Output {
+                broadcast_op::<T, $op, _, _, _, _>(self, rhs)
+            }
+        }
+
+        impl<T, S1, S2> $trait_name<&ArrayBase<S2, Ix1>> for ArrayBase<S1, Ix2>
+        where
+            T: $bound + Default,
+            S1: Data<Elem = T>,
+            S2: Data<Elem = T>,
+            for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+        {
+            type Output = Array2<T>;
+
+            fn $method(&self, rhs: &ArrayBase<S2, Ix1>) -> Self::Output {
+                broadcast_op::<T, $op, _, _, _, _>(self, rhs)
+            }
+        }
+
+        impl<T, S1, S2> $trait_name<&ArrayBase<S2, Ix2>> for ArrayBase<S1, Ix2>
+        where
+            T: $bound + Default,
+            S1: Data<Elem = T>,
+            S2: Data<Elem = T>,
+            for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+        {
+            type Output = Array2<T>;
+
+            fn $method(&self, rhs: &ArrayBase<S2, Ix2>) -> Self::Output {
+                broadcast_op::<T, $op, _, _, _, _>(self, rhs)
+            }
+        }
+
+        define_fast_op!(
+            @scalar $trait_name, $method, $op,
+            f32, f64, i8, i16, i32, i64, u8, u16, u32, u64
+        );
+
+        #[inline]
+        #[doc = concat!("Performs an element wise ", $desc, " of `lhs` and `rhs`.")]
+        #[doc = ""]
+        #[doc = concat!("See [", stringify!($trait_name), "] for the supported operands.")]
+        #[doc = ""]
+        #[doc = "### Panics"]
+        #[doc = ""]
+        #[doc = "If `lhs` and `rhs` cannot be broadcast to the same shape."]
+        pub fn $func<L, Rhs>(lhs: &L, rhs: Rhs) -> L::Output
+        where
+            L: $trait_name<Rhs>,
+        {
+            lhs.$method(rhs)
+        }
+    };
+    (@scalar $trait_name:ident, $method:ident, $op:ident, $($t:ident),+) => {
+        $(
+            impl<S, D> $trait_name<$t> for ArrayBase<S, D>
+            where
+                S: Data<Elem = $t>,
+                D: Dimension,
+            {
+                type Output = Array<$t, D>;
+
+                fn $method(&self, rhs: $t) -> Self::Output {
+                    let rhs = [rhs];
+                    broadcast_op::<$t, $op, _, _, _, _>(self, &ArrayView1::from(&rhs))
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use define_fast_op;
+
+define_fast_op!(
+    trait = AddFast,
+    method = add_fast,
+    func = add,
+    op = AddOp,
+    vertical = add_vertical,
+    bound = ArithmeticOps,
+    desc = "addition",
+);
+define_fast_op!(
+    trait = SubFast,
+    method = sub_fast,
+    func = sub,
+    op = SubOp,
+    vertical = sub_vertical,
+    bound = ArithmeticOps,
+    desc = "subtraction",
+);
+define_fast_op!(
+    trait = MulFast,
+    method = mul_fast,
+    func = mul,
+    op = MulOp,
+    vertical = mul_vertical,
+    bound = ArithmeticOps,
+    desc = "multiplication",
+);
+define_fast_op!(
+    trait = DivFast,
+    method = div_fast,
+    func = div,
+    op = DivOp,
+    vertical = div_vertical,
+    bound = ArithmeticOps,
+    desc = "division",
+);
+define_fast_op!(
+    trait = MaxFast,
+    method = max_fast,
+    func = max,
+    op = MaxOp,
+    vertical = max_vertical,
+    bound = CmpOps,
+    desc = "max",
+);
+define_fast_op!(
+    trait = MinFast,
+    method = min_fast,
+    func = min,
+    op = MinOp,
+    vertical = min_vertical,
+    bound = CmpOps,
+    desc = "min",
+);
+
+/// Applies `reduce` to each lane of `array` along `axis`, returning one value per lane.
+///
+/// Lanes which are not contiguous are copied into a temporary buffer first.
+fn reduce_axis<T, S, F>(array: &ArrayBase<S, Ix2>, axis: Axis, reduce: F) -> Array1<T>
+where
+    T: Copy,
+    S: Data<Elem = T>,
+    F: Fn(&[T]) -> T,
+{
+    array
+        .lanes(axis)
+        .into_iter()
+        .map(|lane| match lane.to_slice() {
+            Some(slice) => reduce(slice),
+            None => reduce(&lane.to_vec()),
+        })
+        .collect()
+}
+
+#[inline]
+/// Sums the elements of `array` along `axis`, i.e. `Axis(1)` produces the sum of each row
+/// and `Axis(0)` the sum of each column.
+///
+/// See [cfavml::sum] for more details.
+///
+/// ### Panics
+///
+/// If `axis` is out of bounds for a 2-D array.
+pub fn sum_axis<T, S>(array: &ArrayBase<S, Ix2>, axis: Axis) -> Array1<T>
+where
+    T: AggOps,
+    S: Data<Elem = T>,
+{
+    reduce_axis(array, axis, |lane| cfavml::sum(lane))
+}
+
+#[inline]
+/// Calculates the mean of the elements of `array` along `axis`, i.e. `Axis(1)` produces
+/// the mean of each row and `Axis(0)` the mean of each column.
+///
+/// Like `ndarray`'s own `mean_axis`, this returns `None` if the length of `axis` is zero.
+///
+/// ### Panics
+///
+/// If `axis` is out of bounds for a 2-D array.
+pub fn mean_axis<T, S>(array: &ArrayBase<S, Ix2>, axis: Axis) -> Option<Array1<T>>
+where
+    T: AggOps,
+    S: Data<Elem = T>,
+    usize: CastAs<T>,
+    AutoMath: Math<T>,
+{
+    let len = array.len_of(axis);
+    if len == 0 {
+        return None;
+    }
+
+    let len = len.cast_as();
+    Some(reduce_axis(array, axis, |lane| {
+        AutoMath::div(cfavml::sum(lane), len)
+    }))
+}
+
+#[inline]
+/// Calculates the L2 norm of the elements of `array` along `axis`, i.e. `Axis(1)` produces
+/// the norm of each row and `Axis(0)` the norm of each column.
+///
+/// See [cfavml::squared_norm] for more details.
+///
+/// ### Panics
+///
+/// If `axis` is out of bounds for a 2-D array.
+pub fn norm_axis<T, S>(array: &ArrayBase<S, Ix2>, axis: Axis) -> Array1<T>
+where
+    T: DistanceOps,
+    S: Data<Elem = T>,
+    AutoMath: Math<T>,
+{
+    reduce_axis(array, axis, |lane| {
+        AutoMath::sqrt(cfavml::squared_norm(lane))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{s, Array, ShapeBuilder, Zip};
+
+    use super::*;
+
+    fn sample_matrix(shape: (usize, usize)) -> Array2<f32> {
+        Array2::from_shape_fn(shape, |(i, j)| (i * shape.1 + j) as f32 + 1.0)
+    }
+
+    fn sample_f_matrix(shape: (usize, usize)) -> Array2<f32> {
+        Array2::from_shape_fn(shape.f(), |(i, j)| (i * shape.1 + j) as f32 * 0.5 + 1.0)
+    }
+
+    fn sample_vector(len: usize) -> Array1<f32> {
+        Array1::from_shape_fn(len, |i| i as f32 * 0.25 + 1.0)
+    }
+
+    /// Checks each op against the `ndarray` implementation.
+    macro_rules! check_ops {
+        ($lhs:expr, scalar = $rhs:expr) => {{
+            let lhs = &$lhs;
+            let rhs: f32 = $rhs;
+
+            assert_eq!(add(lhs, rhs), lhs + rhs);
+            assert_eq!(sub(lhs, rhs), lhs - rhs);
+            assert_eq!(mul(lhs, rhs), lhs * rhs);
+            assert_eq!(div(lhs, rhs), lhs / rhs);
+            assert_eq!(max(lhs, rhs), lhs.mapv(|v| v.max(rhs)));
+            assert_eq!(min(lhs, rhs), lhs.mapv(|v| v.min(rhs)));
+        }};
+        ($lhs:expr, $rhs:expr) => {{
+            let lhs = &$lhs;
+            let rhs = &$rhs;
+
+            assert_eq!(add(lhs, rhs), lhs + rhs);
+            assert_eq!(sub(lhs, rhs), lhs - rhs);
+            assert_eq!(mul(lhs, rhs), lhs * rhs);
+            assert_eq!(div(lhs, rhs), lhs / rhs);
+
+            let shape = (lhs + rhs).raw_dim();
+            let l = lhs.broadcast(shape.clone()).unwrap();
+            let r = rhs.broadcast(shape).unwrap();
+            let expected_max = Zip::from(&l).and(&r).map_collect(|a, b| a.max(*b));
+            let expected_min = Zip::from(&l).and(&r).map_collect(|a, b| a.min(*b));
+            assert_eq!(max(lhs, rhs), expected_max);
+            assert_eq!(min(lhs, rhs), expected_min);
+        }};
+    }
+
+    #[test]
+    fn test_ops_1d() {
+        let a = sample_vector(37);
+        let b = sample_vector(37).mapv(|v| v * 3.0 - 20.0);
+
+        check_ops!(a, b);
+        check_ops!(a.view(), b.view());
+        check_ops!(a, Array1::from_elem(1, 4.0f32));
+        check_ops!(Array1::from_elem(1, 4.0f32), b);
+        check_ops!(a, scalar = 2.5);
+        check_ops!(Array1::<f32>::zeros(0), Array1::<f32>::zeros(0));
+    }
+
+    #[test]
+    fn test_ops_1d_non_standard_layout() {
+        let a = sample_vector(74);
+        let b = sample_vector(37).mapv(|v| v * 3.0 - 20.0);
+
+        let stepped = a.slice(s![..;2]);
+        let reversed = b.slice(s![..;-1]);
+        assert!(!stepped.is_standard_layout());
+        assert!(!reversed.is_standard_layout());
+
+        check_ops!(stepped, b);
+        check_ops!(b, stepped);
+        check_ops!(stepped, reversed);
+        check_ops!(reversed, scalar = -1.5);
+    }
+
+    #[test]
+    fn test_ops_2d_c_order() {
+        let a = sample_matrix((5, 37));
+
+        check_ops!(a, sample_matrix((5, 37)).mapv(|v| v - 90.0));
+        check_ops!(a, sample_vector(37));
+        check_ops!(a, Array1::from_elem(1, 3.0f32));
+        check_ops!(a, sample_matrix((1, 37)));
+        check_ops!(a, sample_matrix((5, 1)));
+        check_ops!(sample_matrix((5, 1)), sample_matrix((1, 37)));
+        check_ops!(a, scalar = 4.0);
+    }
+
+    #[test]
+    fn test_ops_2d_f_order() {
+        let a = sample_f_matrix((5, 37));
+        let b = sample_f_matrix((5, 37)).mapv(|v| v - 40.0);
+        assert!(!a.is_standard_layout());
+
+        check_ops!(a, b);
+        check_ops!(a, sample_matrix((5, 37)));
+        check_ops!(sample_matrix((5, 37)), b);
+        check_ops!(a, sample_vector(37));
+        check_ops!(a, sample_f_matrix((5, 1)));
+        check_ops!(a, sample_f_matrix((1, 37)));
+        check_ops!(a, scalar = 0.5);
+    }
+
+    #[test]
+    fn test_ops_2d_reversed_axis() {
+        let a = sample_matrix((6, 33));
+        let b = sample_f_matrix((6, 33));
+        let row = sample_vector(66);
+
+        let rows_reversed = a.slice(s![..;-1, ..]);
+        let cols_reversed = b.slice(s![.., ..;-1]);
+        let transposed = sample_matrix((33, 6));
+        let transposed = transposed.t();
+        let row_stepped = row.slice(s![..;-2]);
+
+        check_ops!(rows_reversed, a);
+        check_ops!(rows_reversed, cols_reversed);
+        check_ops!(cols_reversed, transposed);
+        check_ops!(transposed, row_stepped);
+        check_ops!(rows_reversed, row_stepped);
+        check_ops!(cols_reversed, scalar = 3.0);
+    }
+
+    #[test]
+    fn test_ops_integer() {
+        let a = Array::from_shape_fn((4, 9), |(i, j)| (i * 9 + j) as i32 - 10);
+        let row = Array1::from_shape_fn(9, |i| i as i32 * 2);
+
+        assert_eq!(add(&a, &row), &a + &row);
+        assert_eq!(mul(&a.t(), 3), &a.t() * 3);
+        let expected = Zip::from(&a)
+            .and_broadcast(&row)
+            .map_collect(|a, b| *a.max(b));
+        assert_eq!(max(&a, &row), expected);
+    }
+
+    #[test]
+    fn test_reduce_axis_rows() {
+        let batch = sample_matrix((7, 37)).mapv(|v| v * 0.5 - 60.0);
+
+        let expected_sum: Array1<f32> = batch
+            .rows()
+            .into_iter()
+            .map(|row| cfavml::sum(row.as_slice().unwrap()))
+            .collect();
+        assert_eq!(sum_axis(&batch, Axis(1)), expected_sum);
+        assert_eq!(sum_axis(&batch.view(), Axis(1)), expected_sum);
+        assert_eq!(
+            mean_axis(&batch, Axis(1)).unwrap(),
+            expected_sum.mapv(|v| v / 37.0)
+        );
+
+        let expected_norm: Array1<f32> = batch
+            .rows()
+            .into_iter()
+            .map(|row| cfavml::squared_norm(row.as_slice().unwrap()).sqrt())
+            .collect();
+        assert_eq!(norm_axis(&batch, Axis(1)), expected_norm);
+
+        let f_order = Array2::from_shape_fn((7, 37).f(), |(i, j)| batch[[i, j]]);
+        assert!(!f_order.is_standard_layout());
+        assert_eq!(sum_axis(&f_order, Axis(1)), expected_sum);
+        assert_eq!(norm_axis(&f_order, Axis(1)), expected_norm);
+    }
+
+    #[test]
+    fn test_reduce_axis_columns() {
+        let batch = sample_matrix((37, 7)).mapv(|v| v * 0.25 - 20.0);
+        let transposed = batch.t().to_owned();
+
+        assert_eq!(sum_axis(&batch, Axis(0)), sum_axis(&transposed, Axis(1)));
+        assert_eq!(mean_axis(&batch, Axis(0)), mean_axis(&transposed, Axis(1)));
+        assert_eq!(norm_axis(&batch, Axis(0)), norm_axis(&transposed, Axis(1)));
+
+        let expected = batch.sum_axis(Axis(0));
+        let result = sum_axis(&batch, Axis(0));
+        assert!(result
+            .iter()
+            .zip(&expected)
+            .all(|(a, b)| (a - b).abs() < 1e-3));
+    }
+
+    #[test]
+    fn test_reduce_axis_non_standard_layout() {
+        let a = Array2::from_shape_fn((6, 66), |(i, j)| (i * 66 + j) as i32 - 100);
+
+        let stepped = a.slice(s![..;-1, ..;2]);
+        assert!(!stepped.is_standard_layout());
+        assert_eq!(sum_axis(&stepped, Axis(1)), stepped.sum_axis(Axis(1)));
+        assert_eq!(sum_axis(&stepped, Axis(0)), stepped.sum_axis(Axis(0)));
+        assert_eq!(
+            mean_axis(&stepped, Axis(1)),
+            stepped.sum_axis(Axis(1)).mapv(|v| v / 33).into()
+        );
+
+        let sliced = a.slice(s![.., 3..40]);
+        assert_eq!(sum_axis(&sliced, Axis(1)), sliced.sum_axis(Axis(1)));
+    }
+
+    #[test]
+    fn test_reduce_axis_empty() {
+        let empty = Array2::<f64>::zeros((4, 0));
+
+        assert_eq!(sum_axis(&empty, Axis(1)), Array1::zeros(4));
+        assert_eq!(norm_axis(&empty, Axis(1)), Array1::zeros(4));
+        assert_eq!(mean_axis(&empty, Axis(1)), None);
+        assert_eq!(sum_axis(&empty, Axis(0)), Array1::zeros(0));
+        assert_eq!(mean_axis(&empty, Axis(0)), Some(Array1::zeros(0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot broadcast")]
+    fn test_ops_broadcast_mismatch_panics() {
+        let a = sample_matrix((3, 4));
+        let b = sample_vector(3);
+        add(&a, &b);
+    }
+}
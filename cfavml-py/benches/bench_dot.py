@@ -0,0 +1,29 @@
+"""Compares `cfavml.dot` against `numpy.dot` on 1024 dimension float32 vectors.
+
+Run with `python benches/bench_dot.py` after installing the module with maturin.
+"""
+import timeit
+
+import numpy as np
+
+import cfavml
+
+DIMS = 1024
+NUMBER = 200_000
+
+
+def main():
+    rng = np.random.default_rng(2837564324875)
+    a = rng.standard_normal(DIMS).astype(np.float32)
+    b = rng.standard_normal(DIMS).astype(np.float32)
+
+    numpy_time = timeit.timeit(lambda: np.dot(a, b), number=NUMBER)
+    cfavml_time = timeit.timeit(lambda: cfavml.dot(a, b), number=NUMBER)
+
+    print(f"numpy.dot:  {numpy_time / NUMBER * 1e9:8.1f} ns/iter")
+    print(f"cfavml.dot: {cfavml_time / NUMBER * 1e9:8.1f} ns/iter")
+    print(f"speedup:    {numpy_time / cfavml_time:8.2f}x")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,329 @@
+//! Python bindings over the safe, runtime dispatched routines of CFAVML.
+//!
+//! Each routine operates directly on the buffers of contiguous 1-D numpy arrays without
+//! copying them, releasing the GIL while the computation runs so multiple Python threads
+//! can make progress at once.
+//!
+//! Invalid inputs, i.e. mismatched lengths, dtypes or non-contiguous arrays, raise a
+//! `ValueError` rather than panicking.
+
+use numpy::{
+    Element,
+    PyArray1,
+    PyArrayMethods,
+    PyReadonlyArray1,
+    PyUntypedArray,
+    PyUntypedArrayMethods,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+
+/// The dtypes supported by every routine.
+const ALL_DTYPES: &str = "float32, float64, int8 or uint8";
+
+/// A borrowed 1-D numpy array of one of the supported dtypes.
+enum Vector<'py> {
+    F32(PyReadonlyArray1<'py, f32>),
+    F64(PyReadonlyArray1<'py, f64>),
+    I8(PyReadonlyArray1<'py, i8>),
+    U8(PyReadonlyArray1<'py, u8>),
+}
+
+impl<'py> Vector<'py> {
+    /// Borrows `obj` as a 1-D numpy array of one of the supported dtypes.
+    fn extract(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let array = obj
+            .cast::<PyUntypedArray>()
+            .map_err(|_| PyValueError::new_err("Expected a numpy array"))?;
+        if array.ndim() != 1 {
+            return Err(PyValueError::new_err(format!(
+                "Expected a 1-D array, got an array with {} dimensions",
+                array.ndim(),
+            )));
+        }
+
+        if let Ok(array) = obj.cast::<PyArray1<f32>>() {
+            Ok(Self::F32(borrow(array)?))
+        } else if let Ok(array) = obj.cast::<PyArray1<f64>>() {
+            Ok(Self::F64(borrow(array)?))
+        } else if let Ok(array) = obj.cast::<PyArray1<i8>>() {
+            Ok(Self::I8(borrow(array)?))
+        } else if let Ok(array) = obj.cast::<PyArray1<u8>>() {
+            Ok(Self::U8(borrow(array)?))
+        } else {
+            Err(PyValueError::new_err(format!(
+                "Unsupported dtype {}, expected one of {ALL_DTYPES}",
+                array.dtype(),
+            )))
+        }
+    }
+}
+
+/// Acquires a shared borrow over the array.
+fn borrow<'py, T: Element>(
+    array: &Bound<'py, PyArray1<T>>,
+) -> PyResult<PyReadonlyArray1<'py, T>> {
+    array
+        .try_readonly()
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Returns the contiguous buffer of the array.
+fn as_slice<'a, T: Element>(array: &'a PyReadonlyArray1<T>) -> PyResult<&'a [T]> {
+    array
+        .as_slice()
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Checks the two vectors are the same length.
+fn check_len(a: usize, b: usize) -> PyResult<()> {
+    if a != b {
+        return Err(PyValueError::new_err(format!(
+            "Vectors must be the same length, got {a} and {b}"
+        )));
+    }
+    Ok(())
+}
+
+/// Runs a routine taking two vectors and producing a single value.
+fn distance_op<'py, T, V>(
+    py: Python<'py>,
+    a: &PyReadonlyArray1<'py, T>,
+    b: &PyReadonlyArray1<'py, T>,
+    op: impl FnOnce(&[T], &[T]) -> V + Send,
+) -> PyResult<Py<PyAny>>
+where
+    T: Element + Copy + Sync,
+    V: Send + IntoPyObject<'py>,
+{
+    let a = as_slice(a)?;
+    let b = as_slice(b)?;
+    check_len(a.len(), b.len())?;
+
+    let value = py.detach(|| op(a, b));
+    Ok(value.into_bound_py_any(py)?.unbind())
+}
+
+/// Runs a routine taking two vectors and producing a vector, writing into `out`
+/// if provided or a newly allocated array otherwise.
+fn vertical_op<'py, T>(
+    py: Python<'py>,
+    a: &PyReadonlyArray1<'py, T>,
+    b: &PyReadonlyArray1<'py, T>,
+    out: Option<&Bound<'py, PyAny>>,
+    op: impl FnOnce(&[T], &[T], &mut [T]) + Send,
+) -> PyResult<Py<PyAny>>
+where
+    T: Element + Copy + Default + Send + Sync,
+{
+    let a = as_slice(a)?;
+    let b = as_slice(b)?;
+    check_len(a.len(), b.len())?;
+
+    let Some(out) = out else {
+        let mut result = vec![T::default(); a.len()];
+        py.detach(|| op(a, b, &mut result));
+        return Ok(PyArray1::from_vec(py, result).into_any().unbind());
+    };
+
+    let array = out.cast::<PyArray1<T>>().map_err(|_| {
+        PyValueError::new_err(
+            "Output array must be 1-D and match the dtype of the inputs",
+        )
+    })?;
+    // The borrow fails if `out` is also one of the inputs.
+    let mut result = array
+        .try_readwrite()
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let result = result
+        .as_slice_mut()
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    check_len(a.len(), result.len())?;
+
+    py.detach(|| op(a, b, result));
+    Ok(out.clone().unbind())
+}
+
+/// Runs a routine taking a single vector and producing a single value.
+fn agg_op<'py, T>(
+    py: Python<'py>,
+    a: &PyReadonlyArray1<'py, T>,
+    op: impl FnOnce(&[T]) -> T + Send,
+) -> PyResult<Py<PyAny>>
+where
+    T: Element + Copy + Send + Sync + IntoPyObject<'py>,
+{
+    let a = as_slice(a)?;
+
+    let value = py.detach(|| op(a));
+    Ok(value.into_bound_py_any(py)?.unbind())
+}
+
+/// Dispatches a routine over two vectors to the implementation matching their dtype.
+macro_rules! dispatch_pair {
+    (
+        $a:expr,
+        $b:expr,
+        dtypes = $dtypes:expr,
+        [$($variant:ident),+ $(,)?],
+        |$l1:ident, $l2:ident| $body:expr
+    ) => {
+        match (Vector::extract($a)?, Vector::extract($b)?) {
+            $(
+                (Vector::$variant($l1), Vector::$variant($l2)) => $body,
+            )+
+            _ => Err(PyValueError::new_err(format!(
+                "Vectors must have the same dtype, one of {}",
+                $dtypes,
+            ))),
+        }
+    };
+}
+
+/// Dispatches a routine over a single vector to the implementation matching its dtype.
+macro_rules! dispatch_single {
+    ($a:expr, |$l1:ident| $body:expr) => {
+        match Vector::extract($a)? {
+            Vector::F32($l1) => $body,
+            Vector::F64($l1) => $body,
+            Vector::I8($l1) => $body,
+            Vector::U8($l1) => $body,
+        }
+    };
+}
+
+#[pyfunction]
+/// Calculates the dot product of vectors `a` and `b`.
+fn dot<'py>(
+    py: Python<'py>,
+    a: &Bound<'py, PyAny>,
+    b: &Bound<'py, PyAny>,
+) -> PyResult<Py<PyAny>> {
+    dispatch_pair!(a, b, dtypes = ALL_DTYPES, [F32, F64, I8, U8], |a, b| {
+        distance_op(py, &a, &b, |a, b| cfavml::dot(a, b))
+    })
+}
+
+#[pyfunction]
+/// Calculates the cosine distance of vectors `a` and `b`.
+///
+/// The integer dtypes are accumulated in a wider type and return a `float32` distance,
+/// as the norms would otherwise overflow almost immediately.
+fn cosine<'py>(
+    py: Python<'py>,
+    a: &Bound<'py, PyAny>,
+    b: &Bound<'py, PyAny>,
+) -> PyResult<Py<PyAny>> {
+    match (Vector::extract(a)?, Vector::extract(b)?) {
+        (Vector::F32(a), Vector::F32(b)) => {
+            distance_op(py, &a, &b, |a, b| cfavml::cosine(a, b))
+        },
+        (Vector::F64(a), Vector::F64(b)) => {
+            distance_op(py, &a, &b, |a, b| cfavml::cosine(a, b))
+        },
+        (Vector::I8(a), Vector::I8(b)) => distance_op(py, &a, &b, |a, b| {
+            // There is no wide accumulator routine for `i8`, so the elements are
+            // widened to `f32` which holds every `i8` value exactly.
+            let a = a.iter().copied().map(f32::from).collect::<Vec<_>>();
+            let b = b.iter().copied().map(f32::from).collect::<Vec<_>>();
+            cfavml::cosine(&a, &b)
+        }),
+        (Vector::U8(a), Vector::U8(b)) => {
+            distance_op(py, &a, &b, cfavml::cosine_wide_acc)
+        },
+        _ => Err(PyValueError::new_err(format!(
+            "Vectors must have the same dtype, one of {ALL_DTYPES}",
+        ))),
+    }
+}
+
+#[pyfunction]
+/// Calculates the squared Euclidean distance of vectors `a` and `b`.
+fn squared_euclidean<'py>(
+    py: Python<'py>,
+    a: &Bound<'py, PyAny>,
+    b: &Bound<'py, PyAny>,
+) -> PyResult<Py<PyAny>> {
+    dispatch_pair!(a, b, dtypes = ALL_DTYPES, [F32, F64, I8, U8], |a, b| {
+        distance_op(py, &a, &b, |a, b| cfavml::squared_euclidean(a, b))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (a, b, out=None))]
+/// Performs an element wise addition of vectors `a` and `b`, writing the result to
+/// `out` if provided.
+fn add<'py>(
+    py: Python<'py>,
+    a: &Bound<'py, PyAny>,
+    b: &Bound<'py, PyAny>,
+    out: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    dispatch_pair!(a, b, dtypes = ALL_DTYPES, [F32, F64, I8, U8], |a, b| {
+        vertical_op(py, &a, &b, out, |a, b, out| cfavml::add_vertical(a, b, out))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (a, b, out=None))]
+/// Performs an element wise subtraction of vectors `a` and `b`, writing the result to
+/// `out` if provided.
+fn sub<'py>(
+    py: Python<'py>,
+    a: &Bound<'py, PyAny>,
+    b: &Bound<'py, PyAny>,
+    out: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    dispatch_pair!(a, b, dtypes = ALL_DTYPES, [F32, F64, I8, U8], |a, b| {
+        vertical_op(py, &a, &b, out, |a, b, out| cfavml::sub_vertical(a, b, out))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (a, b, out=None))]
+/// Performs an element wise multiplication of vectors `a` and `b`, writing the result to
+/// `out` if provided.
+fn mul<'py>(
+    py: Python<'py>,
+    a: &Bound<'py, PyAny>,
+    b: &Bound<'py, PyAny>,
+    out: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    dispatch_pair!(a, b, dtypes = ALL_DTYPES, [F32, F64, I8, U8], |a, b| {
+        vertical_op(py, &a, &b, out, |a, b, out| cfavml::mul_vertical(a, b, out))
+    })
+}
+
+#[pyfunction]
+/// Calculates the sum of vector `a`.
+fn sum<'py>(py: Python<'py>, a: &Bound<'py, PyAny>) -> PyResult<Py<PyAny>> {
+    dispatch_single!(a, |a| agg_op(py, &a, |a| cfavml::sum(a)))
+}
+
+#[pyfunction]
+/// Calculates the minimum value of vector `a`.
+fn min<'py>(py: Python<'py>, a: &Bound<'py, PyAny>) -> PyResult<Py<PyAny>> {
+    dispatch_single!(a, |a| agg_op(py, &a, |a| cfavml::min(a)))
+}
+
+#[pyfunction]
+/// Calculates the maximum value of vector `a`.
+fn max<'py>(py: Python<'py>, a: &Bound<'py, PyAny>) -> PyResult<Py<PyAny>> {
+    dispatch_single!(a, |a| agg_op(py, &a, |a| cfavml::max(a)))
+}
+
+#[pymodule(name = "cfavml")]
+/// SIMD accelerated vector operations over numpy arrays.
+fn cfavml_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(dot, m)?)?;
+    m.add_function(wrap_pyfunction!(cosine, m)?)?;
+    m.add_function(wrap_pyfunction!(squared_euclidean, m)?)?;
+    m.add_function(wrap_pyfunction!(add, m)?)?;
+    m.add_function(wrap_pyfunction!(sub, m)?)?;
+    m.add_function(wrap_pyfunction!(mul, m)?)?;
+    m.add_function(wrap_pyfunction!(sum, m)?)?;
+    m.add_function(wrap_pyfunction!(min, m)?)?;
+    m.add_function(wrap_pyfunction!(max, m)?)?;
+    Ok(())
+}
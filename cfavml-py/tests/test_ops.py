@@ -0,0 +1,162 @@
+import threading
+
+import numpy as np
+import pytest
+
+import cfavml
+
+DIMS = 1043
+ALL_DTYPES = [np.float32, np.float64, np.int8, np.uint8]
+FLOAT_DTYPES = [np.float32, np.float64]
+
+
+def sample_vectors(dtype, dims=DIMS):
+    rng = np.random.default_rng(9823749823)
+    if np.issubdtype(dtype, np.floating):
+        a = rng.standard_normal(dims)
+        b = rng.standard_normal(dims)
+    else:
+        info = np.iinfo(dtype)
+        a = rng.integers(info.min, info.max, dims, endpoint=True)
+        b = rng.integers(info.min, info.max, dims, endpoint=True)
+    return a.astype(dtype), b.astype(dtype)
+
+
+def assert_close(actual, expected, dtype):
+    if np.issubdtype(dtype, np.floating):
+        np.testing.assert_allclose(actual, expected, rtol=1e-4, atol=1e-4)
+    else:
+        np.testing.assert_array_equal(actual, expected)
+
+
+# Integer routines wrap on overflow, so the references are computed in the
+# same dtype with numpy's (wrapping) arithmetic.
+def reference_dot(a, b):
+    return np.sum(a * b, dtype=a.dtype)
+
+
+def reference_squared_euclidean(a, b):
+    diff = a - b
+    return np.sum(diff * diff, dtype=a.dtype)
+
+
+def reference_cosine(a, b):
+    a = a.astype(np.float64)
+    b = b.astype(np.float64)
+    norm = np.linalg.norm(a) * np.linalg.norm(b)
+    return 1.0 - np.dot(a, b) / norm
+
+
+@pytest.mark.parametrize("dtype", ALL_DTYPES)
+def test_dot(dtype):
+    a, b = sample_vectors(dtype)
+    with np.errstate(over="ignore"):
+        assert_close(cfavml.dot(a, b), reference_dot(a, b), dtype)
+
+
+@pytest.mark.parametrize("dtype", ALL_DTYPES)
+def test_squared_euclidean(dtype):
+    a, b = sample_vectors(dtype)
+    with np.errstate(over="ignore"):
+        expected = reference_squared_euclidean(a, b)
+    assert_close(cfavml.squared_euclidean(a, b), expected, dtype)
+
+
+@pytest.mark.parametrize("dtype", FLOAT_DTYPES)
+def test_cosine(dtype):
+    a, b = sample_vectors(dtype)
+    assert_close(cfavml.cosine(a, b), reference_cosine(a, b), dtype)
+
+
+# The integer dtypes are accumulated in a wider type and return a float32 distance,
+# so are compared against the float64 reference rather than wrapping arithmetic.
+@pytest.mark.parametrize("dtype", [np.int8, np.uint8])
+def test_cosine_integers(dtype):
+    a, b = sample_vectors(dtype)
+    result = cfavml.cosine(a, b)
+    np.testing.assert_allclose(result, reference_cosine(a, b), rtol=1e-4, atol=1e-4)
+
+
+@pytest.mark.parametrize("dtype", ALL_DTYPES)
+@pytest.mark.parametrize(
+    "op, reference",
+    [
+        (cfavml.add, np.add),
+        (cfavml.sub, np.subtract),
+        (cfavml.mul, np.multiply),
+    ],
+)
+def test_vertical(dtype, op, reference):
+    a, b = sample_vectors(dtype)
+    with np.errstate(over="ignore"):
+        expected = reference(a, b)
+
+    result = op(a, b)
+    assert result.dtype == dtype
+    assert_close(result, expected, dtype)
+
+    out = np.zeros_like(a)
+    returned = op(a, b, out=out)
+    assert returned is out
+    assert_close(out, expected, dtype)
+
+
+@pytest.mark.parametrize("dtype", ALL_DTYPES)
+def test_aggregates(dtype):
+    a, _ = sample_vectors(dtype)
+    assert_close(cfavml.sum(a), np.sum(a, dtype=dtype), dtype)
+    assert_close(cfavml.min(a), np.min(a), dtype)
+    assert_close(cfavml.max(a), np.max(a), dtype)
+
+
+def test_readonly_inputs():
+    a, b = sample_vectors(np.float32)
+    a.setflags(write=False)
+    b.setflags(write=False)
+    assert_close(cfavml.dot(a, b), reference_dot(a, b), np.float32)
+
+
+@pytest.mark.parametrize(
+    "a, b",
+    [
+        (np.zeros(4, np.float32), np.zeros(5, np.float32)),
+        (np.zeros(4, np.float32), np.zeros(4, np.float64)),
+        (np.zeros(4, np.int16), np.zeros(4, np.int16)),
+        (np.zeros((2, 2), np.float32), np.zeros((2, 2), np.float32)),
+        (np.zeros(8, np.float32)[::2], np.zeros(4, np.float32)),
+        ([1.0, 2.0], [3.0, 4.0]),
+    ],
+)
+def test_invalid_inputs_raise_value_error(a, b):
+    with pytest.raises(ValueError):
+        cfavml.dot(a, b)
+    with pytest.raises(ValueError):
+        cfavml.add(a, b)
+
+
+def test_invalid_out_raises_value_error():
+    a, b = sample_vectors(np.float32)
+    with pytest.raises(ValueError):
+        cfavml.add(a, b, out=np.zeros(DIMS, np.float64))
+    with pytest.raises(ValueError):
+        cfavml.add(a, b, out=np.zeros(DIMS + 1, np.float32))
+    with pytest.raises(ValueError):
+        cfavml.add(a, b, out=a)
+
+
+def test_threads():
+    a, b = sample_vectors(np.float32, dims=1 << 16)
+    expected = reference_dot(a, b)
+    results = [None] * 8
+
+    def run(i):
+        results[i] = cfavml.dot(a, b)
+
+    threads = [threading.Thread(target=run, args=(i,)) for i in range(len(results))]
+    for thread in threads:
+        thread.start()
+    for thread in threads:
+        thread.join()
+
+    for result in results:
+        assert_close(result, expected, np.float32)
@@ -39,7 +39,7 @@ impl<T: Copy> AlignedBuffer<T> {
         let num_chunks = (len / num_per_chunk) + 1;
 
         let mut buffer = Vec::with_capacity(num_chunks);
-        buffer.extend(std::iter::repeat(AlignedBytes::default()).take(num_chunks));
+        buffer.resize(num_chunks, AlignedBytes::default());
 
         let buffer = buffer.into_boxed_slice();
 
@@ -69,20 +69,26 @@ impl<T: Copy> AlignedBuffer<T> {
 
     #[inline]
     /// Returns the buffer as a borrowed slice of `T`.
+    ///
+    /// The slice is always aligned to 64 bytes, which satisfies the register alignment
+    /// of both AVX2 and AVX512, allowing CFAVML's vertical routines to use their aligned
+    /// loads and stores.
     pub fn as_slice(&self) -> &[T] {
         let ptr = self.buffer.as_ptr();
         unsafe { std::slice::from_raw_parts(ptr.cast(), self.len) }
     }
 
     #[inline]
-    /// Returns the buffer as a borrowed slice of `T`.
+    /// Returns a mutable pointer to the start of the buffer.
     pub fn as_mut_ptr(&mut self) -> *mut T {
         let ptr = self.buffer.as_mut_ptr();
         ptr.cast()
     }
 
     #[inline]
-    /// Returns the buffer as a borrowed slice of `T`.
+    /// Returns the buffer as a mutably borrowed slice of `T`.
+    ///
+    /// Like [AlignedBuffer::as_slice], the slice is always aligned to 64 bytes.
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         let ptr = self.buffer.as_mut_ptr();
         unsafe { std::slice::from_raw_parts_mut(ptr.cast(), self.len) }
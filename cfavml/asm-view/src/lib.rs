@@ -94,6 +94,529 @@ macro_rules! export_vector_x_value_op {
     };
 }
 
+macro_rules! export_cmp_op {
+    ($t:ident, $im:ident, $cmp:ident $(, features = $($feat:expr),+)?) => {
+        paste::paste!{
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_cmp_ $cmp _vertical>](a: &[$t], b: &[$t], res: &mut [$t]) {
+                let res = [<generic_cmp_ $cmp _vertical>]::<_, $im, cfavml::math::AutoMath, _, _, _>(a, b, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_cmp_ $cmp _value>](value: $t, a: &[$t], res: &mut [$t]) {
+                let res = [<generic_cmp_ $cmp _value>]::<_, $im, cfavml::math::AutoMath, _, _>(a, value, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_cmp_ $cmp _bitmask>](a: &[$t], b: &[$t], res: &mut [u8]) {
+                let res = [<generic_cmp_ $cmp _bitmask>]::<_, $im, cfavml::math::AutoMath>(a.len(), a, b, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_count_ $cmp _value>](value: $t, a: &[$t]) {
+                let res = [<generic_count_ $cmp _value>]::<_, $im, cfavml::math::AutoMath, _>(a, value);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_any_ $cmp _value>](value: $t, a: &[$t]) {
+                let res = [<generic_any_ $cmp _value>]::<_, $im, cfavml::math::AutoMath, _>(a, value);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_all_ $cmp _value>](value: $t, a: &[$t]) {
+                let res = [<generic_all_ $cmp _value>]::<_, $im, cfavml::math::AutoMath, _>(a, value);
+                std::hint::black_box(res);
+            }
+        }
+    };
+}
+
+macro_rules! export_horizontal_op {
+    ($t:ident, $im:ident, $op:ident $(, features = $($feat:expr),+)?) => {
+        paste::paste!{
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _ $op>](a: &[$t]) {
+                let res = $op::<_, $im, cfavml::math::AutoMath, _>(a);
+                std::hint::black_box(res);
+            }
+        }
+    };
+}
+
+macro_rules! export_hypot_op {
+    ($t:ident, $im:ident $(, features = $($feat:expr),+)?) => {
+        paste::paste!{
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_hypot_vertical>](a: &[$t], b: &[$t], res: &mut [$t]) {
+                let res = generic_hypot_vertical::<_, $im, cfavml::math::AutoMath, _, _, _>(a, b, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_hypot_vertical_with_broadcast_value>](value: $t, a: &[$t], res: &mut [$t]) {
+                let res = generic_hypot_vertical::<_, $im, cfavml::math::AutoMath, _, _, _>(value, a, res);
+                std::hint::black_box(res);
+            }
+        }
+    };
+}
+
+macro_rules! export_unary_op {
+    ($t:ident, $im:ident, $op:ident $(, features = $($feat:expr),+)?) => {
+        paste::paste!{
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _ $op>](a: &[$t], res: &mut [$t]) {
+                let res = $op::<_, $im, cfavml::math::AutoMath, _, _>(a, res);
+                std::hint::black_box(res);
+            }
+        }
+    };
+}
+
+macro_rules! export_inplace_op {
+    ($t:ident, $im:ident, $op:ident $(, features = $($feat:expr),+)?) => {
+        paste::paste!{
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _ $op>](a: &mut [$t], b: &[$t]) {
+                $op::<_, $im, cfavml::math::AutoMath, _>(a, b);
+                std::hint::black_box(a);
+            }
+        }
+    };
+}
+
+macro_rules! export_batch_op {
+    ($t:ident, $im:ident, $op:ident $(, features = $($feat:expr),+)?) => {
+        paste::paste!{
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _ $op>](query: &[$t], batch: &[$t], res: &mut [$t]) {
+                let res = $op::<_, $im, cfavml::math::AutoMath, _>(query, batch, res);
+                std::hint::black_box(res);
+            }
+        }
+    };
+}
+
+/// Exports every comparison family routine for the given type.
+macro_rules! export_cmp_ops {
+    ($t:ident, $im:ident $(, features = $($feat:expr),+)?) => {
+        export_cmp_op!($t, $im, eq $(, features = $($feat),+)?);
+        export_cmp_op!($t, $im, neq $(, features = $($feat),+)?);
+        export_cmp_op!($t, $im, lt $(, features = $($feat),+)?);
+        export_cmp_op!($t, $im, lte $(, features = $($feat),+)?);
+        export_cmp_op!($t, $im, gt $(, features = $($feat),+)?);
+        export_cmp_op!($t, $im, gte $(, features = $($feat),+)?);
+    };
+}
+
+/// Exports the sum, max, min and norm horizontal reductions for the given type.
+macro_rules! export_horizontal_ops {
+    ($t:ident, $im:ident $(, features = $($feat:expr),+)?) => {
+        export_horizontal_op!($t, $im, generic_sum $(, features = $($feat),+)?);
+        export_horizontal_op!($t, $im, generic_cmp_max $(, features = $($feat),+)?);
+        export_horizontal_op!($t, $im, generic_cmp_min $(, features = $($feat),+)?);
+        export_horizontal_op!($t, $im, generic_squared_norm $(, features = $($feat),+)?);
+    };
+}
+
+/// Exports the bitwise and saturating routines for the given integer type.
+macro_rules! export_int_ops {
+    ($t:ident, $im:ident $(, features = $($feat:expr),+)?) => {
+        export_vector_x_vector_op!($t, $im, generic_bitand_vertical $(, features = $($feat),+)?);
+        export_vector_x_vector_op!($t, $im, generic_bitor_vertical $(, features = $($feat),+)?);
+        export_vector_x_vector_op!($t, $im, generic_bitxor_vertical $(, features = $($feat),+)?);
+        export_vector_x_vector_op!($t, $im, generic_shl_vertical $(, features = $($feat),+)?);
+        export_vector_x_vector_op!($t, $im, generic_shr_vertical $(, features = $($feat),+)?);
+        export_vector_x_vector_op!($t, $im, generic_saturating_add_vertical $(, features = $($feat),+)?);
+        export_vector_x_vector_op!($t, $im, generic_saturating_sub_vertical $(, features = $($feat),+)?);
+        export_unary_op!($t, $im, generic_bitnot_vertical $(, features = $($feat),+)?);
+    };
+}
+
+/// Exports the routines which do not belong to one of the families above.
+///
+/// Some of these require [SimdFloatRegister] so they are only exported for floats.
+macro_rules! export_misc_ops {
+    ($t:ident, $im:ident $(, features = $($feat:expr),+)?) => {
+        export_vector_x_vector_op!($t, $im, generic_add_vertical_nt $(, features = $($feat),+)?);
+        export_vector_x_vector_op!($t, $im, generic_cmp_max_vertical $(, features = $($feat),+)?);
+        export_vector_x_vector_op!($t, $im, generic_cmp_min_vertical $(, features = $($feat),+)?);
+        export_inplace_op!($t, $im, generic_add_inplace $(, features = $($feat),+)?);
+        export_inplace_op!($t, $im, generic_sub_inplace $(, features = $($feat),+)?);
+        export_inplace_op!($t, $im, generic_mul_inplace $(, features = $($feat),+)?);
+        export_inplace_op!($t, $im, generic_div_inplace $(, features = $($feat),+)?);
+        export_unary_op!($t, $im, generic_alternating_sign_flip $(, features = $($feat),+)?);
+        export_unary_op!($t, $im, generic_neg_vertical $(, features = $($feat),+)?);
+        export_unary_op!($t, $im, generic_is_nan_vector $(, features = $($feat),+)?);
+        export_unary_op!($t, $im, generic_is_finite_vector $(, features = $($feat),+)?);
+        export_unary_op!($t, $im, generic_is_infinite_vector $(, features = $($feat),+)?);
+        export_horizontal_op!($t, $im, generic_kahan_sum $(, features = $($feat),+)?);
+        export_horizontal_op!($t, $im, generic_count_nonzero $(, features = $($feat),+)?);
+        export_horizontal_op!($t, $im, generic_any_nan $(, features = $($feat),+)?);
+        export_horizontal_op!($t, $im, generic_any_infinite $(, features = $($feat),+)?);
+        export_horizontal_op!($t, $im, generic_all_finite $(, features = $($feat),+)?);
+        export_batch_op!($t, $im, generic_dot_batch $(, features = $($feat),+)?);
+        export_batch_op!($t, $im, generic_squared_euclidean_batch $(, features = $($feat),+)?);
+        export_batch_op!($t, $im, generic_cosine_batch $(, features = $($feat),+)?);
+
+        paste::paste!{
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_dot_indexed>](dims: usize, query: &[$t], data: &[$t], indices: &[usize], res: &mut [$t]) {
+                let res = generic_dot_indexed::<_, $im, cfavml::math::AutoMath, _>(dims, query, data, indices, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_batch_dot>](n_rows: usize, dims: usize, matrix: &[$t], query: &[$t], scores: &mut [$t]) {
+                let res = generic_batch_dot::<_, $im, cfavml::math::AutoMath>(n_rows, dims, matrix, query, scores);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_pairwise_distances>](n: usize, dims: usize, matrix: &[$t], res: &mut [$t]) {
+                let res = generic_pairwise_distances::<_, $im, cfavml::math::AutoMath>(n, dims, matrix, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_dot_matrix>](dims: usize, a_rows: usize, b_rows: usize, a: &[$t], b: &[$t], res: &mut [$t]) {
+                let res = generic_dot_matrix::<_, $im, cfavml::math::AutoMath>(dims, a_rows, b_rows, a, b, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_squared_euclidean_matrix>](dims: usize, a_rows: usize, b_rows: usize, a: &[$t], b: &[$t], res: &mut [$t]) {
+                let res = generic_squared_euclidean_matrix::<_, $im, cfavml::math::AutoMath>(dims, a_rows, b_rows, a, b, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_gram_matrix>](rows: usize, dims: usize, matrix: &[$t], res: &mut [$t]) {
+                let res = generic_gram_matrix::<_, $im, cfavml::math::AutoMath>(rows, dims, matrix, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_gemv>](rows: usize, cols: usize, matrix: &[$t], x: &[$t], out: &mut [$t]) {
+                let res = generic_gemv::<_, $im, cfavml::math::AutoMath>(rows, cols, matrix, x, out);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_outer_product>](a: &[$t], b: &[$t], res: &mut [$t]) {
+                let res = generic_outer_product::<_, $im, cfavml::math::AutoMath>(a.len(), b.len(), a, b, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_weighted_dot>](a: &[$t], b: &[$t], w: &[$t]) {
+                let res = generic_weighted_dot::<_, $im, cfavml::math::AutoMath>(a.len(), a, b, w);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_sum_compensated>](a: &[$t]) {
+                let res = generic_sum_compensated::<_, $im, cfavml::math::AutoMath>(a);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_dot_compensated>](a: &[$t], b: &[$t]) {
+                let res = generic_dot_compensated::<_, $im, cfavml::math::AutoMath>(a, b);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_sum_ordered>](a: &[$t]) {
+                let res = generic_sum_ordered::<_, $im, cfavml::math::AutoMath>(a);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_dot_ordered>](a: &[$t], b: &[$t]) {
+                let res = generic_dot_ordered::<_, $im, cfavml::math::AutoMath>(a, b);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_squared_norm_ordered>](a: &[$t]) {
+                let res = generic_squared_norm_ordered::<_, $im, cfavml::math::AutoMath>(a);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_l2_normalize_vertical>](a: &[$t], res: &mut [$t]) {
+                let res = generic_l2_normalize_vertical::<_, $im, cfavml::math::AutoMath>(a.len(), a, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_l2_normalize_inplace>](a: &mut [$t]) {
+                generic_l2_normalize_inplace::<_, $im, cfavml::math::AutoMath>(a);
+                std::hint::black_box(a);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_cumulative_max>](a: &[$t], res: &mut [$t]) {
+                let res = generic_cumulative_max::<_, $im, cfavml::math::AutoMath, _>(a.len(), a, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_cumulative_min>](a: &[$t], res: &mut [$t]) {
+                let res = generic_cumulative_min::<_, $im, cfavml::math::AutoMath, _>(a.len(), a, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_moving_average>](window: usize, a: &[$t], res: &mut [$t]) {
+                let res = generic_moving_average::<_, $im, cfavml::math::AutoMath, _>(a.len(), window, a, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_select_vertical>](mask: &[$t], a: &[$t], b: &[$t], res: &mut [$t]) {
+                let res = generic_select_vertical::<_, $im, cfavml::math::AutoMath, _, _, _, _>(mask, a, b, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_compact>](mask: &[$t], values: &[$t], res: &mut [$t]) {
+                let res = generic_compact::<_, $im, cfavml::math::AutoMath, _>(mask.len(), mask, values, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_mask_to_indices>](mask: &[$t], res: &mut [u32]) {
+                let res = generic_mask_to_indices::<_, $im, cfavml::math::AutoMath, _>(mask.len(), mask, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_gather>](a: &[$t], indices: &[u32], res: &mut [$t]) {
+                let res = generic_gather::<_, $im, _>(indices.len(), a, indices, res);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_scatter>](a: &[$t], indices: &[u32], res: &mut [$t]) {
+                let res = generic_scatter::<_, $im>(indices.len(), a, indices, res);
+                std::hint::black_box(res);
+            }
+
+            // `generic_dot` and `generic_squared_euclidean` built on `generic_fold` and
+            // `generic_fold_reduce`, these should produce the same code as the respective
+            // `generic_dot` export and friends, the only difference being the addition
+            // of `init` to the horizontal sum.
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_fold_dot>](a: &[$t], b: &[$t]) {
+                let res = generic_fold::<$t, $im, cfavml::math::AutoMath, _, _, _, _>(
+                    a,
+                    b,
+                    0.0,
+                    |l1, l2, acc| <$im as SimdRegister<$t>>::fmadd(l1, l2, acc),
+                    |a, b, acc| acc + a * b,
+                );
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_fold_squared_euclidean>](a: &[$t], b: &[$t]) {
+                let res = generic_fold::<$t, $im, cfavml::math::AutoMath, _, _, _, _>(
+                    a,
+                    b,
+                    0.0,
+                    |l1, l2, acc| {
+                        let diff = <$im as SimdRegister<$t>>::sub(l1, l2);
+                        <$im as SimdRegister<$t>>::fmadd(diff, diff, acc)
+                    },
+                    |a, b, acc| acc + (a - b) * (a - b),
+                );
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _generic_fold_reduce_dot>](a: &[$t], b: &[$t]) {
+                let res = generic_fold_reduce::<$t, $im, cfavml::math::AutoMath, _, _, _, _, _, _>(
+                    a,
+                    b,
+                    0.0,
+                    |l1, l2, acc| <$im as SimdRegister<$t>>::fmadd(l1, l2, acc),
+                    |l1, l2| <$im as SimdRegister<$t>>::add(l1, l2),
+                    |acc| <$im as SimdRegister<$t>>::sum_to_value(acc),
+                    |a, b, acc| acc + a * b,
+                );
+                std::hint::black_box(res);
+            }
+        }
+    };
+}
+
+/// Exports the routines which widen their inputs and are therefore tied to the
+/// register's specific widening implementations rather than `SimdRegister<T>`.
+macro_rules! export_widen_ops {
+    ($im:ident $(, features = $($feat:expr),+)?) => {
+        paste::paste!{
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _u8_generic_dot_wide_acc>](a: &[u8], b: &[u8]) {
+                let res = generic_dot_wide_acc::<u8, u32, $im, cfavml::math::AutoMath>(a, b);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _u8_generic_squared_euclidean_wide_acc>](a: &[u8], b: &[u8]) {
+                let res = generic_squared_euclidean_wide_acc::<u8, u32, $im, cfavml::math::AutoMath>(a, b);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _u8_generic_cosine_wide_acc>](a: &[u8], b: &[u8]) {
+                let res = generic_cosine_wide_acc::<u8, u32, $im, cfavml::math::AutoMath>(a, b);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _i32_generic_dot_wide_acc>](a: &[i32], b: &[i32]) {
+                let res = generic_dot_wide_acc::<i32, i64, $im, cfavml::math::AutoMath>(a, b);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _f32_generic_dot_f32_f64acc>](a: &[f32], b: &[f32]) {
+                let res = generic_dot_f32_f64acc::<$im>(a, b);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _f32_generic_squared_euclidean_f32_f64acc>](a: &[f32], b: &[f32]) {
+                let res = generic_squared_euclidean_f32_f64acc::<$im>(a, b);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _f32_generic_squared_norm_f32_f64acc>](a: &[f32]) {
+                let res = generic_squared_norm_f32_f64acc::<$im>(a);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _f32_generic_sum_f32_f64acc>](a: &[f32]) {
+                let res = generic_sum_f32_f64acc::<$im>(a);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _f32_i32_generic_cast_vector>](a: &[f32], res: &mut [i32]) {
+                let res = generic_cast_vector::<f32, i32, $im, _>(a, res);
+                std::hint::black_box(res);
+            }
+        }
+    };
+}
+
+/// Exports the bit-packed distance routines, which on x86 also require `popcnt`.
+macro_rules! export_hamming_ops {
+    ($im:ident $(, features = $($feat:expr),+)?) => {
+        paste::paste!{
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _u64_generic_binary_hamming_distance>](a: &[u64], b: &[u64]) {
+                let res = generic_binary_hamming_distance::<$im>(a.len(), a, b);
+                std::hint::black_box(res);
+            }
+
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _u64_generic_binary_jaccard_similarity>](a: &[u64], b: &[u64]) {
+                let res = generic_binary_jaccard_similarity::<$im>(a.len(), a, b);
+                std::hint::black_box(res);
+            }
+        }
+    };
+}
+
+macro_rules! export_widening_dot_op {
+    ($t:ident, $im:ident, $op:ident $(, features = $($feat:expr),+)?) => {
+        paste::paste!{
+            #[inline(never)]
+            $(#[target_feature($(enable = $feat ,)*)])?
+            pub unsafe fn [<impl_ $im:lower _ $t _ $op>](a: &[$t], b: &[$t]) {
+                let res = $op::<$t, $im>(a, b);
+                std::hint::black_box(res);
+            }
+        }
+    };
+}
+
+/// Calls the given export macro for every primitive type.
+macro_rules! for_each_dtype {
+    ($mac:ident, $im:ident $(, features = $($feat:expr),+)?) => {
+        $mac!(i8, $im $(, features = $($feat),+)?);
+        $mac!(i16, $im $(, features = $($feat),+)?);
+        $mac!(i32, $im $(, features = $($feat),+)?);
+        $mac!(i64, $im $(, features = $($feat),+)?);
+        $mac!(u8, $im $(, features = $($feat),+)?);
+        $mac!(u16, $im $(, features = $($feat),+)?);
+        $mac!(u32, $im $(, features = $($feat),+)?);
+        $mac!(u64, $im $(, features = $($feat),+)?);
+        $mac!(f32, $im $(, features = $($feat),+)?);
+        $mac!(f64, $im $(, features = $($feat),+)?);
+    };
+}
+
 export_dense_op!(i8, Fallback, mul_dense);
 export_dense_op!(i8, Fallback, div_dense);
 export_dense_op!(i8, Fallback, add_dense);
@@ -172,6 +695,17 @@ export_vector_x_value_op!(f64, Fallback, generic_sub_vertical);
 export_vector_x_value_op!(f64, Fallback, generic_mul_vertical);
 export_vector_x_value_op!(f64, Fallback, generic_div_vertical);
 
+for_each_dtype!(export_cmp_ops, Fallback);
+for_each_dtype!(export_horizontal_ops, Fallback);
+export_hypot_op!(f32, Fallback);
+export_hypot_op!(f64, Fallback);
+export_int_ops!(i32, Fallback);
+export_misc_ops!(f32, Fallback);
+export_widen_ops!(Fallback);
+export_hamming_ops!(Fallback);
+export_widening_dot_op!(i8, Fallback, generic_dot_widen);
+export_widening_dot_op!(i8, Fallback, generic_squared_euclidean_widen);
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub mod avx2_ops {
     use super::*;
@@ -253,6 +787,23 @@ pub mod avx2_ops {
     export_vector_x_value_op!(f64, Avx2, generic_sub_vertical, features = "avx2");
     export_vector_x_value_op!(f64, Avx2, generic_mul_vertical, features = "avx2");
     export_vector_x_value_op!(f64, Avx2, generic_div_vertical, features = "avx2");
+
+    for_each_dtype!(export_cmp_ops, Avx2, features = "avx2");
+    for_each_dtype!(export_horizontal_ops, Avx2, features = "avx2");
+    export_hypot_op!(f32, Avx2, features = "avx2");
+    export_hypot_op!(f64, Avx2, features = "avx2");
+    export_int_ops!(i32, Avx2, features = "avx2");
+    export_misc_ops!(f32, Avx2, features = "avx2");
+    export_widen_ops!(Avx2, features = "avx2");
+    export_hamming_ops!(Avx2, features = "avx2", "popcnt");
+    export_widening_dot_op!(i8, Avx2, generic_dot_widen, features = "avx2");
+    export_widening_dot_op!(i16, Avx2, generic_dot_widen, features = "avx2");
+    export_widening_dot_op!(
+        i16,
+        Avx2,
+        generic_squared_euclidean_widen,
+        features = "avx2"
+    );
 }
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -275,6 +826,7 @@ pub mod avx2fma_ops {
         features = "avx2",
         "fma"
     );
+    export_hypot_op!(f32, Avx2Fma, features = "avx2", "fma");
 
     export_distance_op!(f64, Avx2Fma, generic_cosine, features = "avx2", "fma");
     export_distance_op!(f64, Avx2Fma, generic_dot, features = "avx2", "fma");
@@ -285,12 +837,142 @@ pub mod avx2fma_ops {
         features = "avx2",
         "fma"
     );
+    export_hypot_op!(f64, Avx2Fma, features = "avx2", "fma");
+
+    export_cmp_ops!(f32, Avx2Fma, features = "avx2", "fma");
+    export_cmp_ops!(f64, Avx2Fma, features = "avx2", "fma");
+    export_horizontal_ops!(f32, Avx2Fma, features = "avx2", "fma");
+    export_horizontal_ops!(f64, Avx2Fma, features = "avx2", "fma");
+    export_misc_ops!(f32, Avx2Fma, features = "avx2", "fma");
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+pub mod avx512_ops {
+    use super::*;
+
+    for_each_dtype!(export_cmp_ops, Avx512, features = "avx512f", "avx512bw");
+    for_each_dtype!(
+        export_horizontal_ops,
+        Avx512,
+        features = "avx512f",
+        "avx512bw"
+    );
+    export_hypot_op!(f32, Avx512, features = "avx512f", "avx512bw");
+    export_hypot_op!(f64, Avx512, features = "avx512f", "avx512bw");
+    export_int_ops!(i32, Avx512, features = "avx512f", "avx512bw");
+    export_misc_ops!(f32, Avx512, features = "avx512f", "avx512bw");
+    export_widen_ops!(Avx512, features = "avx512f", "avx512bw");
+    export_hamming_ops!(Avx512, features = "avx512f", "avx512bw", "popcnt");
+
+    export_widening_dot_op!(
+        i8,
+        Avx512Vnni,
+        generic_dot_widen,
+        features = "avx512f",
+        "avx512bw",
+        "avx512vnni"
+    );
+
+    export_hamming_ops!(
+        Avx512Vpopcntdq,
+        features = "avx512f",
+        "avx512bw",
+        "avx512vpopcntdq",
+        "popcnt"
+    );
 }
 
 #[cfg(target_arch = "aarch64")]
 pub mod neon_ops {
     use super::*;
 
+    export_dense_op!(i8, Neon, mul_dense, features = "neon");
+    export_dense_op!(i8, Neon, div_dense, features = "neon");
+    export_dense_op!(i8, Neon, add_dense, features = "neon");
+    export_dense_op!(i8, Neon, sub_dense, features = "neon");
+    export_dense_op!(i8, Neon, max_dense, features = "neon");
+    export_dense_op!(i8, Neon, min_dense, features = "neon");
+    export_dense_op!(i8, Neon, eq_dense, features = "neon");
+    export_dense_op!(i8, Neon, lt_dense, features = "neon");
+
+    export_dense_op!(i16, Neon, mul_dense, features = "neon");
+    export_dense_op!(i16, Neon, div_dense, features = "neon");
+    export_dense_op!(i16, Neon, add_dense, features = "neon");
+    export_dense_op!(i16, Neon, sub_dense, features = "neon");
+    export_dense_op!(i16, Neon, max_dense, features = "neon");
+    export_dense_op!(i16, Neon, min_dense, features = "neon");
+    export_dense_op!(i16, Neon, eq_dense, features = "neon");
+    export_dense_op!(i16, Neon, lt_dense, features = "neon");
+
+    export_dense_op!(i32, Neon, mul_dense, features = "neon");
+    export_dense_op!(i32, Neon, div_dense, features = "neon");
+    export_dense_op!(i32, Neon, add_dense, features = "neon");
+    export_dense_op!(i32, Neon, sub_dense, features = "neon");
+    export_dense_op!(i32, Neon, max_dense, features = "neon");
+    export_dense_op!(i32, Neon, min_dense, features = "neon");
+    export_dense_op!(i32, Neon, eq_dense, features = "neon");
+    export_dense_op!(i32, Neon, lt_dense, features = "neon");
+
+    export_dense_op!(i64, Neon, mul_dense, features = "neon");
+    export_dense_op!(i64, Neon, div_dense, features = "neon");
+    export_dense_op!(i64, Neon, add_dense, features = "neon");
+    export_dense_op!(i64, Neon, sub_dense, features = "neon");
+    export_dense_op!(i64, Neon, max_dense, features = "neon");
+    export_dense_op!(i64, Neon, min_dense, features = "neon");
+    export_dense_op!(i64, Neon, eq_dense, features = "neon");
+    export_dense_op!(i64, Neon, lt_dense, features = "neon");
+
+    export_dense_op!(u8, Neon, mul_dense, features = "neon");
+    export_dense_op!(u8, Neon, div_dense, features = "neon");
+    export_dense_op!(u8, Neon, add_dense, features = "neon");
+    export_dense_op!(u8, Neon, sub_dense, features = "neon");
+    export_dense_op!(u8, Neon, max_dense, features = "neon");
+    export_dense_op!(u8, Neon, min_dense, features = "neon");
+    export_dense_op!(u8, Neon, eq_dense, features = "neon");
+    export_dense_op!(u8, Neon, lt_dense, features = "neon");
+
+    export_dense_op!(u16, Neon, mul_dense, features = "neon");
+    export_dense_op!(u16, Neon, div_dense, features = "neon");
+    export_dense_op!(u16, Neon, add_dense, features = "neon");
+    export_dense_op!(u16, Neon, sub_dense, features = "neon");
+    export_dense_op!(u16, Neon, max_dense, features = "neon");
+    export_dense_op!(u16, Neon, min_dense, features = "neon");
+    export_dense_op!(u16, Neon, eq_dense, features = "neon");
+    export_dense_op!(u16, Neon, lt_dense, features = "neon");
+
+    export_dense_op!(u32, Neon, mul_dense, features = "neon");
+    export_dense_op!(u32, Neon, div_dense, features = "neon");
+    export_dense_op!(u32, Neon, add_dense, features = "neon");
+    export_dense_op!(u32, Neon, sub_dense, features = "neon");
+    export_dense_op!(u32, Neon, max_dense, features = "neon");
+    export_dense_op!(u32, Neon, min_dense, features = "neon");
+    export_dense_op!(u32, Neon, eq_dense, features = "neon");
+    export_dense_op!(u32, Neon, lt_dense, features = "neon");
+
+    export_dense_op!(u64, Neon, mul_dense, features = "neon");
+    export_dense_op!(u64, Neon, div_dense, features = "neon");
+    export_dense_op!(u64, Neon, add_dense, features = "neon");
+    export_dense_op!(u64, Neon, sub_dense, features = "neon");
+    export_dense_op!(u64, Neon, max_dense, features = "neon");
+    export_dense_op!(u64, Neon, min_dense, features = "neon");
+    export_dense_op!(u64, Neon, eq_dense, features = "neon");
+    export_dense_op!(u64, Neon, lt_dense, features = "neon");
+
+    export_distance_op!(i8, Neon, generic_cosine, features = "neon");
+    export_distance_op!(i8, Neon, generic_dot, features = "neon");
+    export_distance_op!(i8, Neon, generic_squared_euclidean, features = "neon");
+    export_vector_x_vector_op!(i8, Neon, generic_add_vertical, features = "neon");
+    export_vector_x_vector_op!(i8, Neon, generic_sub_vertical, features = "neon");
+    export_vector_x_vector_op!(i8, Neon, generic_mul_vertical, features = "neon");
+    export_vector_x_vector_op!(i8, Neon, generic_div_vertical, features = "neon");
+    export_vector_x_value_op!(i8, Neon, generic_add_vertical, features = "neon");
+    export_vector_x_value_op!(i8, Neon, generic_sub_vertical, features = "neon");
+    export_vector_x_value_op!(i8, Neon, generic_mul_vertical, features = "neon");
+    export_vector_x_value_op!(i8, Neon, generic_div_vertical, features = "neon");
+
     export_dense_op!(f32, Neon, mul_dense, features = "neon");
     export_dense_op!(f32, Neon, div_dense, features = "neon");
     export_dense_op!(f32, Neon, add_dense, features = "neon");
@@ -328,9 +1010,66 @@ pub mod neon_ops {
     export_vector_x_value_op!(f64, Neon, generic_sub_vertical, features = "neon");
     export_vector_x_value_op!(f64, Neon, generic_mul_vertical, features = "neon");
     export_vector_x_value_op!(f64, Neon, generic_div_vertical, features = "neon");
+
+    for_each_dtype!(export_cmp_ops, Neon, features = "neon");
+    for_each_dtype!(export_horizontal_ops, Neon, features = "neon");
+    export_hypot_op!(f32, Neon, features = "neon");
+    export_hypot_op!(f64, Neon, features = "neon");
+    export_int_ops!(i32, Neon, features = "neon");
+    export_misc_ops!(f32, Neon, features = "neon");
+    export_widen_ops!(Neon, features = "neon");
+    export_hamming_ops!(Neon, features = "neon");
+    export_widening_dot_op!(i8, Neon, generic_dot_widen, features = "neon");
+    export_widening_dot_op!(i16, Neon, generic_dot_widen, features = "neon");
+    export_widening_dot_op!(
+        i16,
+        Neon,
+        generic_squared_euclidean_widen,
+        features = "neon"
+    );
+}
+
+// The scalable routines are only implemented by `Sve`, which is nightly only, so they
+// have no exports on other architectures.
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+pub mod sve_ops {
+    use super::*;
+
+    #[inline(never)]
+    #[target_feature(enable = "sve")]
+    pub unsafe fn impl_sve_f32_generic_scalable_dot(a: &[f32], b: &[f32]) {
+        let res = generic_scalable_dot::<f32, Sve>(a, b);
+        std::hint::black_box(res);
+    }
+
+    #[inline(never)]
+    #[target_feature(enable = "sve")]
+    pub unsafe fn impl_sve_f32_generic_scalable_add_vertical(
+        a: &[f32],
+        b: &[f32],
+        res: &mut [f32],
+    ) {
+        let res = generic_scalable_add_vertical::<f32, Sve, _>(a, b, res);
+        std::hint::black_box(res);
+    }
 }
 
 #[inline(never)]
 pub fn ndarray_dot(a: &ndarray::Array1<f32>, b: &ndarray::Array1<f32>) -> f32 {
     a.dot(b)
 }
+
+#[inline(never)]
+pub fn dispatch_cosine_per_call(query: &[f32], vectors: &[&[f32]], scores: &mut [f32]) {
+    for (vector, score) in vectors.iter().zip(scores.iter_mut()) {
+        *score = cfavml::cosine(query, vector);
+    }
+}
+
+#[inline(never)]
+pub fn dispatch_cosine_resolved(query: &[f32], vectors: &[&[f32]], scores: &mut [f32]) {
+    let cosine = cfavml::dispatch::resolve_cosine_f32();
+    for (vector, score) in vectors.iter().zip(scores.iter_mut()) {
+        *score = cosine(query, vector);
+    }
+}
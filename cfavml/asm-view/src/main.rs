@@ -0,0 +1,145 @@
+//! Generates a report of the number of instructions in each exported routine.
+//!
+//! The library is compiled to assembly in release mode with a single codegen unit and
+//! the instructions of every `asm_view` function are counted, the report is then written
+//! to `reports/<arch>.txt` so changes in the codegen of a routine, i.e. an accidental
+//! spill storm in a dense lane op, show up as a diff in review.
+//!
+//! ```shell
+//! cargo run -p asm-view
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::process::Command;
+use std::{env, fs};
+
+fn main() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = manifest_dir.join("../../target/asm-view");
+    let asm_path = target_dir.join("asm_view.s");
+
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let status = Command::new(cargo)
+        .arg("rustc")
+        .arg("--manifest-path")
+        .arg(manifest_dir.join("Cargo.toml"))
+        .args(["--lib", "--release", "--features", "stable-avx512"])
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .args(["--", "-C", "codegen-units=1", "--emit"])
+        .arg(format!("asm={}", asm_path.display()))
+        .status()
+        .expect("Run cargo");
+    assert!(status.success(), "Failed to compile asm-view to assembly");
+
+    let asm = fs::read_to_string(&asm_path).expect("Read generated assembly");
+    let counts = count_instructions(&asm);
+
+    let arch = env::consts::ARCH;
+    let name_width = counts.keys().map(|name| name.len()).max().unwrap_or(0);
+    let mut report = String::new();
+    writeln!(
+        report,
+        "# Generated by `cargo run -p asm-view`, do not edit."
+    )
+    .unwrap();
+    writeln!(
+        report,
+        "# The number of instructions in each {arch} routine."
+    )
+    .unwrap();
+    for (name, count) in counts.iter() {
+        writeln!(report, "{name:<name_width$} {count:>6}").unwrap();
+    }
+
+    let reports_dir = manifest_dir.join("reports");
+    fs::create_dir_all(&reports_dir).expect("Create reports directory");
+    let report_path = reports_dir.join(format!("{arch}.txt"));
+    fs::write(&report_path, report).expect("Write report");
+
+    let total: usize = counts.values().sum();
+    println!(
+        "Wrote {} routines with {total} instructions to {}",
+        counts.len(),
+        report_path.display(),
+    );
+}
+
+/// Counts the instructions of each `asm_view` function in the given assembly.
+///
+/// Functions start at their (mangled) symbol label and end at the `.cfi_endproc`
+/// directive or the `.Lfunc_end` label, every line in between which is not a label,
+/// directive or comment is counted as an instruction.
+fn count_instructions(asm: &str) -> BTreeMap<String, usize> {
+    let mut counts = BTreeMap::new();
+    let mut current: Option<(String, usize)> = None;
+
+    for line in asm.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            if line.trim_start_matches('.').starts_with("Lfunc_end") {
+                if let Some((name, count)) = current.take() {
+                    counts.insert(name, count);
+                }
+            } else if let Some(name) = line.strip_suffix(':').and_then(demangle) {
+                if name.starts_with("asm_view::") {
+                    current = Some((name, 0));
+                }
+            }
+            continue;
+        }
+
+        let Some((_, count)) = current.as_mut() else {
+            continue;
+        };
+
+        let line = line.trim();
+        if line == ".cfi_endproc" {
+            let (name, count) = current.take().unwrap();
+            counts.insert(name, count);
+        } else if !line.is_empty()
+            && !line.starts_with('.')
+            && !line.starts_with('#')
+            && !line.starts_with("//")
+        {
+            *count += 1;
+        }
+    }
+
+    counts
+}
+
+/// Demangles a legacy Rust symbol into its path, without the trailing hash.
+///
+/// Returns `None` if the symbol is not a mangled Rust path.
+fn demangle(symbol: &str) -> Option<String> {
+    let mut rest = symbol.trim_start_matches('_').strip_prefix("ZN")?;
+
+    let mut segments = Vec::new();
+    while !rest.starts_with('E') {
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let len: usize = rest[..digits].parse().ok()?;
+        let segment = rest.get(digits..digits + len)?;
+        segments.push(segment);
+        rest = &rest[digits + len..];
+    }
+
+    if segments
+        .last()
+        .is_some_and(|s| s.len() == 17 && s.starts_with('h'))
+    {
+        segments.pop();
+    }
+
+    let path = segments.join("::");
+    let path = path
+        .replace("$LT$", "<")
+        .replace("$GT$", ">")
+        .replace("$C$", ",")
+        .replace("$u20$", " ")
+        .replace("$u7b$", "{")
+        .replace("$u7d$", "}")
+        .replace("..", "::");
+    Some(path)
+}
@@ -0,0 +1,66 @@
+// The code generated by `divan` is not bound by the crate MSRV.
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+
+use cfavml::buffer::WriteOnlyBuffer;
+use cfavml::safe_trait_arithmetic_ops::ArithmeticOps;
+use cfavml_utils::aligned_buffer::AlignedBuffer;
+use divan::counter::ItemsCount;
+use divan::Bencher;
+use rand::distributions::{Distribution, Standard};
+
+mod utils;
+
+const DIMS: usize = 1536;
+
+fn main() {
+    divan::main();
+}
+
+/// Creates the inputs and output buffers, the vectors start `offset` elements into
+/// each buffer, so an offset of `0` is always aligned to 64 bytes.
+fn offset_buffers<T>(offset: usize) -> [AlignedBuffer<T>; 3]
+where
+    T: Copy,
+    Standard: Distribution<T>,
+{
+    let (l1, l2) = utils::get_sample_vectors::<T>(DIMS + offset);
+
+    let mut a = unsafe { AlignedBuffer::zeroed(DIMS + offset) };
+    a.copy_from_slice(&l1);
+    let mut b = unsafe { AlignedBuffer::zeroed(DIMS + offset) };
+    b.copy_from_slice(&l2);
+    let result = unsafe { AlignedBuffer::zeroed(DIMS + offset) };
+
+    [a, b, result]
+}
+
+#[divan::bench_group(
+    sample_count = 500,
+    sample_size = 5000,
+    threads = false,
+    counters = [ItemsCount::new(DIMS)],
+)]
+mod add {
+    use super::*;
+
+    #[divan::bench(types = [f32, f64, i8, i32], args = [0, 1])]
+    fn cfavml_vector<T>(bencher: Bencher, offset: usize)
+    where
+        Standard: Distribution<T>,
+        T: ArithmeticOps + Copy,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let [a, b, mut result] = offset_buffers::<T>(offset);
+
+        bencher.bench_local(|| {
+            let result = black_box(&mut result.as_mut_slice()[offset..]);
+            cfavml::add_vertical(
+                black_box(&a[offset..]),
+                black_box(&b[offset..]),
+                result,
+            )
+        });
+    }
+}
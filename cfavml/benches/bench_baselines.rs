@@ -0,0 +1,608 @@
+//! Compares each register implementation of the core routines against the baselines.
+//!
+//! Every routine is benchmarked over a range of dimensions with:
+//!
+//! - `iter`: plain iterator code, measuring what LLVM auto-vectorizes by itself.
+//! - `fallback`: the `Fallback` register implementation.
+//! - `avx2`, `avx2fma`, `avx512` and `neon`: the register implementations, these are
+//!   only compiled in when the target features are enabled at compile time, i.e. with
+//!   `RUSTFLAGS="-C target-cpu=native"`.
+//! - `cfavml`: the safe runtime dispatched routine.
+//! - `simsimd`: the equivalent SimSIMD routine where one exists.
+//!
+//! The benchmark thread is pinned to the first core to reduce noise, and the
+//! implementation selected by the runtime dispatch is printed before the run so
+//! results can be attributed to the register which produced them.
+//!
+//! A single implementation can be selected with the Divan filter:
+//!
+//! ```shell
+//! RUSTFLAGS="-C target-cpu=native" cargo bench --bench bench_baselines -- avx2
+//! ```
+
+// The code generated by `divan` is not bound by the crate MSRV.
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+
+use cfavml::buffer::WriteOnlyBuffer;
+use cfavml::danger::{
+    export_agg_ops,
+    export_arithmetic_ops,
+    export_cmp_ops,
+    export_distance_ops,
+    SimdRegister,
+};
+use cfavml::math::{AutoMath, Math, StdMath};
+use divan::counter::ItemsCount;
+use divan::Bencher;
+use rand::distributions::{Distribution, Standard};
+
+mod utils;
+
+const DIMS: &[usize] = &[32, 128, 1024, 100_000];
+
+fn main() {
+    if !cfavml_utils::pinning::pin_current(0) {
+        eprintln!("Failed to pin the benchmark thread, results may be noisy");
+    }
+    println!(
+        "Runtime dispatch selected the {} implementation",
+        selected_impl()
+    );
+
+    divan::main();
+}
+
+/// Returns the register implementation the safe routines dispatch to on this CPU.
+fn selected_impl() -> &'static str {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512"
+    ))]
+    if cfavml::dispatch::is_avx512_available() {
+        return "avx512";
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if cfavml::dispatch::is_avx2_available() && cfavml::dispatch::is_fma_available() {
+        return "avx2fma";
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if cfavml::dispatch::is_avx2_available() {
+        return "avx2";
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    if cfavml::dispatch::is_neon_available() {
+        return "neon";
+    }
+
+    "fallback"
+}
+
+/// Defines a benchmark of the `danger` export of `$op` for each given register.
+///
+/// The benchmarks of a register are only compiled in when its target features are
+/// enabled, calling them on a CPU without those features would be UB.
+macro_rules! register_benches {
+    (
+        kind = $kind:ident,
+        export = $export:ident::$op:ident,
+        types = $types:tt,
+        $($name:ident => $reg:ident $(if $cfg:meta)?),+ $(,)?
+    ) => {
+        paste::paste! {
+            $(
+                $(#[cfg($cfg)])?
+                #[divan::bench(types = $types, args = DIMS)]
+                fn $name<T>(bencher: Bencher, dims: usize)
+                where
+                    T: Copy,
+                    Standard: Distribution<T>,
+                    AutoMath: Math<T>,
+                    cfavml::danger::$reg: SimdRegister<T>,
+                    for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+                {
+                    register_benches!(
+                        @run $kind,
+                        bencher,
+                        dims,
+                        $export::[<generic_ $name _ $op>]
+                    );
+                }
+            )+
+        }
+    };
+    (@run reduce, $bencher:ident, $dims:ident, $func:path) => {{
+        let (a, _) = utils::get_sample_vectors::<T>($dims);
+
+        $bencher
+            .counter(ItemsCount::new($dims))
+            .bench_local(|| unsafe { $func(black_box(&a)) });
+    }};
+    (@run distance, $bencher:ident, $dims:ident, $func:path) => {{
+        let (a, b) = utils::get_sample_vectors::<T>($dims);
+
+        $bencher
+            .counter(ItemsCount::new($dims))
+            .bench_local(|| unsafe { $func(black_box(&a), black_box(&b)) });
+    }};
+    (@run vertical, $bencher:ident, $dims:ident, $func:path) => {{
+        let (a, b) = utils::get_sample_vectors::<T>($dims);
+        let mut result = vec![AutoMath::zero(); $dims];
+
+        $bencher.counter(ItemsCount::new($dims)).bench_local(|| unsafe {
+            $func(black_box(&a), black_box(&b), black_box(result.as_mut_slice()))
+        });
+    }};
+}
+
+/// Defines the register benchmarks of `$op` for every register supporting all types.
+macro_rules! all_register_benches {
+    (kind = $kind:ident, export = $export:ident::$op:ident, types = $types:tt $(,)?) => {
+        register_benches!(
+            kind = $kind,
+            export = $export::$op,
+            types = $types,
+            fallback => Fallback,
+            avx2 => Avx2 if all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "avx2"
+            ),
+            avx512 => Avx512 if all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "stable-avx512",
+                target_feature = "avx512f",
+                target_feature = "avx512bw"
+            ),
+            neon => Neon if all(target_arch = "aarch64", target_feature = "neon"),
+        );
+    };
+}
+
+/// Defines the `avx2fma` register benchmark of `$op`, which only supports floats.
+macro_rules! avx2fma_bench {
+    (kind = $kind:ident, export = $export:ident::$op:ident $(,)?) => {
+        register_benches!(
+            kind = $kind,
+            export = $export::$op,
+            types = [f32, f64],
+            avx2fma => Avx2Fma if all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "avx2",
+                target_feature = "fma"
+            ),
+        );
+    };
+}
+
+#[divan::bench_group(sample_count = 500, threads = false)]
+mod dot_product {
+    use cfavml::safe_trait_distance_ops::DistanceOps;
+
+    use super::*;
+
+    all_register_benches!(
+        kind = distance,
+        export = export_distance_ops::dot,
+        types = [f32, f64, i8, u8],
+    );
+    avx2fma_bench!(kind = distance, export = export_distance_ops::dot);
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn iter<T>(bencher: Bencher, dims: usize)
+    where
+        T: Copy,
+        Standard: Distribution<T>,
+        StdMath: Math<T>,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            black_box(&a)
+                .iter()
+                .zip(black_box(&b))
+                .fold(StdMath::zero(), |acc, (x, y)| {
+                    StdMath::add(acc, StdMath::mul(*x, *y))
+                })
+        });
+    }
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn cfavml<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+
+        bencher
+            .counter(ItemsCount::new(dims))
+            .bench_local(|| cfavml::dot(black_box(&a), black_box(&b)));
+    }
+
+    #[divan::bench(types = [f32, f64, i8], args = DIMS)]
+    fn simsimd<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: simsimd::SpatialSimilarity,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+
+        bencher
+            .counter(ItemsCount::new(dims))
+            .bench_local(|| T::dot(black_box(&a), black_box(&b)));
+    }
+}
+
+#[divan::bench_group(sample_count = 500, threads = false)]
+mod cosine {
+    use cfavml::safe_trait_distance_ops::DistanceOps;
+
+    use super::*;
+
+    // The integer norms wrap with random inputs, which makes the cosine divide by zero.
+    all_register_benches!(
+        kind = distance,
+        export = export_distance_ops::cosine,
+        types = [f32, f64],
+    );
+    avx2fma_bench!(kind = distance, export = export_distance_ops::cosine);
+
+    #[divan::bench(types = [f32, f64], args = DIMS)]
+    fn iter<T>(bencher: Bencher, dims: usize)
+    where
+        T: Copy,
+        Standard: Distribution<T>,
+        StdMath: Math<T>,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            let zero = StdMath::zero();
+            let (dot, norm_a, norm_b) = black_box(&a).iter().zip(black_box(&b)).fold(
+                (zero, zero, zero),
+                |(dot, norm_a, norm_b), (x, y)| {
+                    (
+                        StdMath::add(dot, StdMath::mul(*x, *y)),
+                        StdMath::add(norm_a, StdMath::mul(*x, *x)),
+                        StdMath::add(norm_b, StdMath::mul(*y, *y)),
+                    )
+                },
+            );
+            utils::cosine::<_, StdMath>(dot, norm_a, norm_b)
+        });
+    }
+
+    #[divan::bench(types = [f32, f64], args = DIMS)]
+    fn cfavml<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+
+        bencher
+            .counter(ItemsCount::new(dims))
+            .bench_local(|| cfavml::cosine(black_box(&a), black_box(&b)));
+    }
+
+    #[divan::bench(types = [f32, f64], args = DIMS)]
+    fn simsimd<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: simsimd::SpatialSimilarity,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+
+        bencher
+            .counter(ItemsCount::new(dims))
+            .bench_local(|| T::cosine(black_box(&a), black_box(&b)));
+    }
+}
+
+#[divan::bench_group(sample_count = 500, threads = false)]
+mod squared_euclidean {
+    use cfavml::safe_trait_distance_ops::DistanceOps;
+
+    use super::*;
+
+    all_register_benches!(
+        kind = distance,
+        export = export_distance_ops::squared_euclidean,
+        types = [f32, f64, i8, u8],
+    );
+    avx2fma_bench!(
+        kind = distance,
+        export = export_distance_ops::squared_euclidean
+    );
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn iter<T>(bencher: Bencher, dims: usize)
+    where
+        T: Copy,
+        Standard: Distribution<T>,
+        StdMath: Math<T>,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            black_box(&a).iter().zip(black_box(&b)).fold(
+                StdMath::zero(),
+                |acc, (x, y)| {
+                    let diff = StdMath::sub(*x, *y);
+                    StdMath::add(acc, StdMath::mul(diff, diff))
+                },
+            )
+        });
+    }
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn cfavml<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+
+        bencher
+            .counter(ItemsCount::new(dims))
+            .bench_local(|| cfavml::squared_euclidean(black_box(&a), black_box(&b)));
+    }
+
+    #[divan::bench(types = [f32, f64, i8], args = DIMS)]
+    fn simsimd<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: simsimd::SpatialSimilarity,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+
+        bencher
+            .counter(ItemsCount::new(dims))
+            .bench_local(|| T::sqeuclidean(black_box(&a), black_box(&b)));
+    }
+}
+
+#[divan::bench_group(sample_count = 500, threads = false)]
+mod add_vertical {
+    use cfavml::safe_trait_arithmetic_ops::ArithmeticOps;
+
+    use super::*;
+
+    all_register_benches!(
+        kind = vertical,
+        export = export_arithmetic_ops::add_vertical,
+        types = [f32, f64, i8, u8],
+    );
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn iter<T>(bencher: Bencher, dims: usize)
+    where
+        T: Copy,
+        Standard: Distribution<T>,
+        StdMath: Math<T>,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+        let mut result = vec![StdMath::zero(); dims];
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            let result = black_box(result.as_mut_slice());
+            for ((r, x), y) in result.iter_mut().zip(black_box(&a)).zip(black_box(&b)) {
+                *r = StdMath::add(*x, *y);
+            }
+        });
+    }
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn cfavml<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: ArithmeticOps,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+        let mut result = vec![AutoMath::zero(); dims];
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            cfavml::add_vertical(
+                black_box(&a),
+                black_box(&b),
+                black_box(result.as_mut_slice()),
+            )
+        });
+    }
+}
+
+#[divan::bench_group(sample_count = 500, threads = false)]
+mod mul_vertical {
+    use cfavml::safe_trait_arithmetic_ops::ArithmeticOps;
+
+    use super::*;
+
+    all_register_benches!(
+        kind = vertical,
+        export = export_arithmetic_ops::mul_vertical,
+        types = [f32, f64, i8, u8],
+    );
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn iter<T>(bencher: Bencher, dims: usize)
+    where
+        T: Copy,
+        Standard: Distribution<T>,
+        StdMath: Math<T>,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+        let mut result = vec![StdMath::zero(); dims];
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            let result = black_box(result.as_mut_slice());
+            for ((r, x), y) in result.iter_mut().zip(black_box(&a)).zip(black_box(&b)) {
+                *r = StdMath::mul(*x, *y);
+            }
+        });
+    }
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn cfavml<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: ArithmeticOps,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+        let mut result = vec![AutoMath::zero(); dims];
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            cfavml::mul_vertical(
+                black_box(&a),
+                black_box(&b),
+                black_box(result.as_mut_slice()),
+            )
+        });
+    }
+}
+
+#[divan::bench_group(sample_count = 500, threads = false)]
+mod sum {
+    use cfavml::safe_trait_agg_ops::AggOps;
+
+    use super::*;
+
+    all_register_benches!(
+        kind = reduce,
+        export = export_agg_ops::sum,
+        types = [f32, f64, i8, u8],
+    );
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn iter<T>(bencher: Bencher, dims: usize)
+    where
+        T: Copy,
+        Standard: Distribution<T>,
+        StdMath: Math<T>,
+    {
+        let (a, _) = utils::get_sample_vectors::<T>(dims);
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            black_box(&a)
+                .iter()
+                .fold(StdMath::zero(), |acc, x| StdMath::add(acc, *x))
+        });
+    }
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn cfavml<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: AggOps,
+    {
+        let (a, _) = utils::get_sample_vectors::<T>(dims);
+
+        bencher
+            .counter(ItemsCount::new(dims))
+            .bench_local(|| cfavml::sum(black_box(&a)));
+    }
+}
+
+#[divan::bench_group(sample_count = 500, threads = false)]
+mod max {
+    use cfavml::safe_trait_cmp_ops::CmpOps;
+
+    use super::*;
+
+    all_register_benches!(
+        kind = reduce,
+        export = export_cmp_ops::cmp_max,
+        types = [f32, f64, i8, u8],
+    );
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn iter<T>(bencher: Bencher, dims: usize)
+    where
+        T: Copy,
+        Standard: Distribution<T>,
+        StdMath: Math<T>,
+    {
+        let (a, _) = utils::get_sample_vectors::<T>(dims);
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            black_box(&a)
+                .iter()
+                .fold(StdMath::min(), |acc, x| StdMath::cmp_max(acc, *x))
+        });
+    }
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn cfavml<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: CmpOps,
+    {
+        let (a, _) = utils::get_sample_vectors::<T>(dims);
+
+        bencher
+            .counter(ItemsCount::new(dims))
+            .bench_local(|| cfavml::max(black_box(&a)));
+    }
+}
+
+#[divan::bench_group(sample_count = 500, threads = false)]
+mod eq_vertical {
+    use cfavml::safe_trait_cmp_ops::CmpOps;
+
+    use super::*;
+
+    all_register_benches!(
+        kind = vertical,
+        export = export_cmp_ops::cmp_eq_vertical,
+        types = [f32, f64, i8, u8],
+    );
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn iter<T>(bencher: Bencher, dims: usize)
+    where
+        T: Copy,
+        Standard: Distribution<T>,
+        StdMath: Math<T>,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+        let mut result = vec![StdMath::zero(); dims];
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            let result = black_box(result.as_mut_slice());
+            for ((r, x), y) in result.iter_mut().zip(black_box(&a)).zip(black_box(&b)) {
+                *r = if StdMath::cmp_eq(*x, *y) {
+                    StdMath::one()
+                } else {
+                    StdMath::zero()
+                };
+            }
+        });
+    }
+
+    #[divan::bench(types = [f32, f64, i8, u8], args = DIMS)]
+    fn cfavml<T>(bencher: Bencher, dims: usize)
+    where
+        Standard: Distribution<T>,
+        T: CmpOps,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let (a, b) = utils::get_sample_vectors::<T>(dims);
+        let mut result = vec![AutoMath::zero(); dims];
+
+        bencher.counter(ItemsCount::new(dims)).bench_local(|| {
+            cfavml::eq_vertical(
+                black_box(&a),
+                black_box(&b),
+                black_box(result.as_mut_slice()),
+            )
+        });
+    }
+}
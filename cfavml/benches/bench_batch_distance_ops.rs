@@ -0,0 +1,142 @@
+// The code generated by `divan` is not bound by the crate MSRV.
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+
+use divan::Bencher;
+
+mod utils;
+
+const DIMS: usize = 768;
+const NUM_VECTORS: usize = 10_000;
+
+fn main() {
+    divan::main();
+}
+
+#[divan::bench_group(sample_count = 100, sample_size = 5, threads = false)]
+mod dot_product {
+    use cfavml::safe_trait_distance_ops::DistanceOps;
+    use rand::distributions::{Distribution, Standard};
+
+    use super::*;
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml_single<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps + Default,
+    {
+        let (query, _) = utils::get_sample_vectors::<T>(DIMS);
+        let (batch, _) = utils::get_sample_vectors::<T>(DIMS * NUM_VECTORS);
+        let mut result = vec![T::default(); NUM_VECTORS];
+
+        bencher.bench_local(|| {
+            for (candidate, result) in black_box(&batch).chunks(DIMS).zip(&mut result) {
+                *result = cfavml::dot(black_box(&query), candidate);
+            }
+        });
+    }
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml_batch<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps + Default,
+        for<'a> &'a mut [T]: cfavml::buffer::WriteOnlyBuffer<Item = T>,
+    {
+        let (query, _) = utils::get_sample_vectors::<T>(DIMS);
+        let (batch, _) = utils::get_sample_vectors::<T>(DIMS * NUM_VECTORS);
+        let mut result = vec![T::default(); NUM_VECTORS];
+
+        bencher.bench_local(|| {
+            cfavml::dot_batch(black_box(&query), black_box(&batch), &mut result)
+        });
+    }
+}
+
+#[divan::bench_group(sample_count = 100, sample_size = 5, threads = false)]
+mod cosine {
+    use cfavml::safe_trait_distance_ops::DistanceOps;
+    use rand::distributions::{Distribution, Standard};
+
+    use super::*;
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml_single<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps + Default,
+    {
+        let (query, _) = utils::get_sample_vectors::<T>(DIMS);
+        let (batch, _) = utils::get_sample_vectors::<T>(DIMS * NUM_VECTORS);
+        let mut result = vec![T::default(); NUM_VECTORS];
+
+        bencher.bench_local(|| {
+            for (candidate, result) in black_box(&batch).chunks(DIMS).zip(&mut result) {
+                *result = cfavml::cosine(black_box(&query), candidate);
+            }
+        });
+    }
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml_batch<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps + Default,
+        for<'a> &'a mut [T]: cfavml::buffer::WriteOnlyBuffer<Item = T>,
+    {
+        let (query, _) = utils::get_sample_vectors::<T>(DIMS);
+        let (batch, _) = utils::get_sample_vectors::<T>(DIMS * NUM_VECTORS);
+        let mut result = vec![T::default(); NUM_VECTORS];
+
+        bencher.bench_local(|| {
+            cfavml::cosine_batch(black_box(&query), black_box(&batch), &mut result)
+        });
+    }
+}
+
+#[divan::bench_group(sample_count = 100, sample_size = 5, threads = false)]
+mod euclidean {
+    use cfavml::safe_trait_distance_ops::DistanceOps;
+    use rand::distributions::{Distribution, Standard};
+
+    use super::*;
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml_single<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps + Default,
+    {
+        let (query, _) = utils::get_sample_vectors::<T>(DIMS);
+        let (batch, _) = utils::get_sample_vectors::<T>(DIMS * NUM_VECTORS);
+        let mut result = vec![T::default(); NUM_VECTORS];
+
+        bencher.bench_local(|| {
+            for (candidate, result) in black_box(&batch).chunks(DIMS).zip(&mut result) {
+                *result = cfavml::squared_euclidean(black_box(&query), candidate);
+            }
+        });
+    }
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml_batch<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps + Default,
+        for<'a> &'a mut [T]: cfavml::buffer::WriteOnlyBuffer<Item = T>,
+    {
+        let (query, _) = utils::get_sample_vectors::<T>(DIMS);
+        let (batch, _) = utils::get_sample_vectors::<T>(DIMS * NUM_VECTORS);
+        let mut result = vec![T::default(); NUM_VECTORS];
+
+        bencher.bench_local(|| {
+            cfavml::squared_euclidean_batch(
+                black_box(&query),
+                black_box(&batch),
+                &mut result,
+            )
+        });
+    }
+}
@@ -1,3 +1,6 @@
+// The code generated by `divan` is not bound by the crate MSRV.
+#![allow(clippy::incompatible_msrv)]
+
 #[cfg(unix)]
 extern crate blas_src;
 
@@ -0,0 +1,112 @@
+// The code generated by `divan` is not bound by the crate MSRV.
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+
+use divan::Bencher;
+
+mod utils;
+
+const DIMS: usize = 1_000_000;
+
+fn main() {
+    divan::main();
+}
+
+/// Creates a mask selecting roughly one in every `every` elements.
+fn sample_mask(every: usize) -> Vec<f32> {
+    (0..DIMS)
+        .map(|i| if (i * 7919) % every == 0 { 1.0 } else { 0.0 })
+        .collect()
+}
+
+#[divan::bench_group(sample_count = 50, sample_size = 5, threads = false)]
+mod compact {
+    use super::*;
+
+    #[divan::bench(args = [1, 2, 16, 256])]
+    fn iter_filter(bencher: Bencher, every: usize) {
+        let (values, _) = utils::get_sample_vectors::<f32>(DIMS);
+        let mask = sample_mask(every);
+
+        bencher.bench_local(|| {
+            black_box(&mask)
+                .iter()
+                .zip(black_box(&values).iter())
+                .filter(|(&m, _)| m != 0.0)
+                .map(|(_, &v)| v)
+                .collect::<Vec<f32>>()
+        });
+    }
+
+    #[divan::bench(args = [1, 2, 16, 256])]
+    fn cfavml(bencher: Bencher, every: usize) {
+        let (values, _) = utils::get_sample_vectors::<f32>(DIMS);
+        let mask = sample_mask(every);
+
+        let mut result = Vec::with_capacity(DIMS);
+        bencher.bench_local(|| {
+            result.clear();
+            cfavml::compact(black_box(&mask), black_box(&values), &mut result)
+        });
+    }
+
+    #[divan::bench(args = [1, 2, 16, 256])]
+    fn cfavml_u8(bencher: Bencher, every: usize) {
+        let (values, _) = utils::get_sample_vectors::<u8>(DIMS);
+        let mask = sample_mask(every)
+            .iter()
+            .map(|&m| m as u8)
+            .collect::<Vec<u8>>();
+
+        let mut result = Vec::with_capacity(DIMS);
+        bencher.bench_local(|| {
+            result.clear();
+            cfavml::compact(black_box(&mask), black_box(&values), &mut result)
+        });
+    }
+}
+
+#[divan::bench_group(sample_count = 50, sample_size = 5, threads = false)]
+mod mask_to_indices {
+    use super::*;
+
+    #[divan::bench(args = [1, 2, 16, 256])]
+    fn iter_filter(bencher: Bencher, every: usize) {
+        let mask = sample_mask(every);
+
+        bencher.bench_local(|| {
+            black_box(&mask)
+                .iter()
+                .enumerate()
+                .filter(|(_, &m)| m != 0.0)
+                .map(|(i, _)| i as u32)
+                .collect::<Vec<u32>>()
+        });
+    }
+
+    #[divan::bench(args = [1, 2, 16, 256])]
+    fn cfavml(bencher: Bencher, every: usize) {
+        let mask = sample_mask(every);
+
+        let mut result = Vec::with_capacity(DIMS);
+        bencher.bench_local(|| {
+            result.clear();
+            cfavml::mask_to_indices(black_box(&mask), &mut result)
+        });
+    }
+
+    #[divan::bench(args = [1, 2, 16, 256])]
+    fn cfavml_u8(bencher: Bencher, every: usize) {
+        let mask = sample_mask(every)
+            .iter()
+            .map(|&m| m as u8)
+            .collect::<Vec<u8>>();
+
+        let mut result = Vec::with_capacity(DIMS);
+        bencher.bench_local(|| {
+            result.clear();
+            cfavml::mask_to_indices(black_box(&mask), &mut result)
+        });
+    }
+}
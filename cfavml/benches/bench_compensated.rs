@@ -0,0 +1,83 @@
+// The code generated by `divan` is not bound by the crate MSRV.
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+
+use cfavml::safe_trait_agg_ops::AggOps;
+use cfavml::safe_trait_distance_ops::{CompensatedReductionOps, DistanceOps};
+use divan::counter::ItemsCount;
+use divan::Bencher;
+use rand::distributions::{Distribution, Standard};
+
+mod utils;
+
+/// Large enough for the rounding error of the plain routines to matter.
+const DIMS: usize = 10_000_000;
+
+fn main() {
+    divan::main();
+}
+
+#[divan::bench_group(
+    sample_count = 50,
+    sample_size = 1,
+    threads = false,
+    counters = [ItemsCount::new(DIMS)],
+)]
+mod sum {
+    use super::*;
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml_plain<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: AggOps,
+    {
+        let (l1, _) = utils::get_sample_vectors::<T>(DIMS);
+
+        bencher.bench_local(|| cfavml::sum(black_box(&l1)));
+    }
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml_compensated<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: CompensatedReductionOps,
+    {
+        let (l1, _) = utils::get_sample_vectors::<T>(DIMS);
+
+        bencher.bench_local(|| cfavml::sum_compensated(black_box(&l1)));
+    }
+}
+
+#[divan::bench_group(
+    sample_count = 50,
+    sample_size = 1,
+    threads = false,
+    counters = [ItemsCount::new(DIMS)],
+)]
+mod dot {
+    use super::*;
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml_plain<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: DistanceOps,
+    {
+        let (l1, l2) = utils::get_sample_vectors::<T>(DIMS);
+
+        bencher.bench_local(|| cfavml::dot(black_box(&l1), black_box(&l2)));
+    }
+
+    #[divan::bench(types = [f32, f64])]
+    fn cfavml_compensated<T>(bencher: Bencher)
+    where
+        Standard: Distribution<T>,
+        T: CompensatedReductionOps,
+    {
+        let (l1, l2) = utils::get_sample_vectors::<T>(DIMS);
+
+        bencher.bench_local(|| cfavml::dot_compensated(black_box(&l1), black_box(&l2)));
+    }
+}
@@ -0,0 +1,76 @@
+// The code generated by `divan` is not bound by the crate MSRV.
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+
+use divan::counter::ItemsCount;
+use divan::Bencher;
+
+mod utils;
+
+/// Small enough for the cost of selecting the implementation to be visible.
+const DIMS: &[usize] = &[8, 64, 512];
+/// The number of vectors compared against the query per iteration.
+const N_VECTORS: usize = 1024;
+
+fn main() {
+    divan::main();
+}
+
+fn get_vectors(dims: usize) -> (Vec<f32>, Vec<Vec<f32>>) {
+    let (query, _) = utils::get_sample_vectors::<f32>(dims);
+    let vectors = (0..N_VECTORS)
+        .map(|_| utils::get_sample_vectors::<f32>(dims).1)
+        .collect();
+    (query, vectors)
+}
+
+#[divan::bench_group(
+    sample_count = 500,
+    sample_size = 10,
+    threads = false,
+    counters = [ItemsCount::new(N_VECTORS)],
+)]
+mod cosine {
+    use super::*;
+
+    #[divan::bench(args = DIMS)]
+    /// Checks the available CPU features on every call.
+    fn cfavml_per_call(bencher: Bencher, dims: usize) {
+        let (query, vectors) = get_vectors(dims);
+
+        bencher.bench_local(|| {
+            for vector in vectors.iter() {
+                black_box(cfavml::cosine(black_box(&query), black_box(vector)));
+            }
+        });
+    }
+
+    #[divan::bench(args = DIMS)]
+    /// Looks up the globally cached dispatcher on every call.
+    fn cfavml_global_dispatcher(bencher: Bencher, dims: usize) {
+        let (query, vectors) = get_vectors(dims);
+
+        bencher.bench_local(|| {
+            for vector in vectors.iter() {
+                black_box(cfavml::dispatch::cosine_f32(
+                    black_box(&query),
+                    black_box(vector),
+                ));
+            }
+        });
+    }
+
+    #[divan::bench(args = DIMS)]
+    /// Resolves the implementation once outside the loop.
+    fn cfavml_resolved(bencher: Bencher, dims: usize) {
+        let (query, vectors) = get_vectors(dims);
+
+        bencher.bench_local(|| {
+            let cosine = cfavml::dispatch::resolve_cosine_f32();
+            for vector in vectors.iter() {
+                black_box(cosine(black_box(&query), black_box(vector)));
+            }
+        });
+    }
+}
@@ -0,0 +1,66 @@
+// The code generated by `divan` is not bound by the crate MSRV.
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+
+use divan::counter::ItemsCount;
+use divan::Bencher;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+const DIMS: usize = 768;
+/// Large enough that the dataset is well beyond the size of the LLC, 1GB of `f32`s.
+const NUM_VECTORS: usize = (1 << 30) / (DIMS * 4);
+
+fn main() {
+    divan::main();
+}
+
+/// Returns the dataset and a random permutation of the indices of its vectors, so each
+/// pass gathers every vector in an order the hardware prefetcher can not follow.
+fn get_dataset() -> (Vec<f32>, Vec<usize>) {
+    let data = vec![0.5f32; NUM_VECTORS * DIMS];
+
+    let mut rng = ChaCha8Rng::seed_from_u64(2837564324875);
+    let mut indices = (0..NUM_VECTORS).collect::<Vec<_>>();
+    indices.shuffle(&mut rng);
+
+    (data, indices)
+}
+
+#[divan::bench_group(sample_count = 10, sample_size = 1, threads = false)]
+mod scattered_dot {
+    use super::*;
+
+    #[divan::bench(counters = [ItemsCount::new(NUM_VECTORS)])]
+    fn cfavml_per_call(bencher: Bencher) {
+        let (data, indices) = get_dataset();
+        let query = vec![1.0f32; DIMS];
+        let mut result = vec![0.0f32; NUM_VECTORS];
+
+        bencher.bench_local(|| {
+            for (value, &index) in result.iter_mut().zip(black_box(&indices)) {
+                let candidate = &data[index * DIMS..][..DIMS];
+                *value = cfavml::dot(black_box(&query), candidate);
+            }
+        });
+    }
+
+    #[divan::bench(counters = [ItemsCount::new(NUM_VECTORS)])]
+    fn cfavml_indexed(bencher: Bencher) {
+        let (data, indices) = get_dataset();
+        let query = vec![1.0f32; DIMS];
+        let mut result = vec![0.0f32; NUM_VECTORS];
+
+        bencher.bench_local(|| {
+            cfavml::dot_indexed(
+                DIMS,
+                black_box(&query),
+                black_box(&data),
+                black_box(&indices),
+                black_box(&mut result),
+            )
+        });
+    }
+}
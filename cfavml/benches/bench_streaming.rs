@@ -0,0 +1,62 @@
+// The code generated by `divan` is not bound by the crate MSRV.
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+
+use divan::counter::ItemsCount;
+use divan::Bencher;
+
+/// Large enough that the inputs and output are well beyond the size of the LLC.
+const LARGE_DIMS: usize = 64 << 20;
+/// Small enough to remain cached between passes.
+const HOT_DIMS: usize = 1 << 20;
+
+fn main() {
+    divan::main();
+}
+
+fn add(streaming: bool, a: &[f32], b: &[f32], result: &mut [f32]) {
+    if streaming {
+        cfavml::add_streaming(a, b, result)
+    } else {
+        cfavml::add_vertical(a, b, result)
+    }
+}
+
+#[divan::bench_group(sample_count = 10, sample_size = 1, threads = false)]
+mod large_add {
+    use super::*;
+
+    #[divan::bench(args = [false, true], counters = [ItemsCount::new(LARGE_DIMS)])]
+    fn cfavml_add(bencher: Bencher, streaming: bool) {
+        let a = vec![1.0f32; LARGE_DIMS];
+        let b = vec![2.0f32; LARGE_DIMS];
+        let mut result = vec![0.0f32; LARGE_DIMS];
+
+        bencher.bench_local(|| {
+            add(
+                streaming,
+                black_box(&a),
+                black_box(&b),
+                black_box(&mut result),
+            )
+        });
+    }
+
+    #[divan::bench(args = [false, true], counters = [ItemsCount::new(HOT_DIMS)])]
+    /// Times a pass over a cached array after each large addition, the regular
+    /// stores evict the array from the cache while the streamed stores do not.
+    fn cached_sum_after_add(bencher: Bencher, streaming: bool) {
+        let a = vec![1.0f32; LARGE_DIMS];
+        let b = vec![2.0f32; LARGE_DIMS];
+        let mut result = vec![0.0f32; LARGE_DIMS];
+        let hot = vec![0.5f32; HOT_DIMS];
+
+        bencher
+            .with_inputs(|| {
+                black_box(cfavml::sum(&hot));
+                add(streaming, &a, &b, &mut result);
+            })
+            .bench_local_values(|_| cfavml::sum(black_box(&hot)));
+    }
+}
@@ -0,0 +1,43 @@
+// The code generated by `divan` is not bound by the crate MSRV.
+#![allow(clippy::incompatible_msrv)]
+
+use std::hint::black_box;
+
+use divan::Bencher;
+
+mod utils;
+
+const DIMS: usize = 1_000_000;
+const K: usize = 10;
+
+fn main() {
+    divan::main();
+}
+
+#[divan::bench_group(sample_count = 50, sample_size = 5, threads = false)]
+mod top_k {
+    use super::*;
+
+    #[divan::bench]
+    fn naive_sort(bencher: Bencher) {
+        let (scores, _) = utils::get_sample_vectors::<f32>(DIMS);
+
+        bencher.bench_local(|| {
+            let mut pairs = black_box(&scores)
+                .iter()
+                .copied()
+                .enumerate()
+                .collect::<Vec<_>>();
+            pairs.sort_unstable_by(|a, b| b.1.total_cmp(&a.1));
+            pairs.truncate(K);
+            pairs
+        });
+    }
+
+    #[divan::bench]
+    fn cfavml(bencher: Bencher) {
+        let (scores, _) = utils::get_sample_vectors::<f32>(DIMS);
+
+        bencher.bench_local(|| cfavml::top_k(K, black_box(&scores)));
+    }
+}
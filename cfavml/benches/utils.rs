@@ -68,7 +68,7 @@ pub fn cosine<T: Copy, M: Math<T>>(dot_product: T, norm_x: T, norm_y: T) -> T {
     if M::cmp_eq(norm_x, M::zero()) && M::cmp_eq(norm_y, M::zero()) {
         M::zero()
     } else if M::cmp_eq(norm_x, M::zero()) || M::cmp_eq(norm_y, M::zero()) {
-        M::zero()
+        M::one()
     } else {
         M::sub(
             M::one(),
@@ -0,0 +1,300 @@
+//! An example of implementing the CFAVML register traits for a custom type defined
+//! outside of this crate, allowing the `generic_*` routines to operate on it.
+//!
+//! Here we use a simple Q16.16 fixed point number and an emulated 4 element wide
+//! register, a real implementation would wrap the relevant SIMD intrinsics instead.
+use cfavml::buffer::BufferElement;
+use cfavml::danger::{self, DenseLane, SimdRegister};
+use cfavml::math::Math;
+
+const FRAC_BITS: u32 = 16;
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+/// A fixed point number with 16 integer bits and 16 fractional bits.
+struct Fixed(i32);
+
+impl Fixed {
+    fn from_f32(value: f32) -> Self {
+        Self((value * (1 << FRAC_BITS) as f32) as i32)
+    }
+
+    fn to_f32(self) -> f32 {
+        self.0 as f32 / (1 << FRAC_BITS) as f32
+    }
+}
+
+// Allows `&mut [Fixed]` to be used as the output of the vertical routines.
+impl BufferElement for Fixed {}
+
+/// The scalar math operations used by the routines to process the tail of the
+/// vectors that do not fill a full register.
+struct FixedMath;
+
+impl Math<Fixed> for FixedMath {
+    fn zero() -> Fixed {
+        Fixed(0)
+    }
+
+    fn one() -> Fixed {
+        Fixed(1 << FRAC_BITS)
+    }
+
+    fn max() -> Fixed {
+        Fixed(i32::MAX)
+    }
+
+    fn min() -> Fixed {
+        Fixed(i32::MIN)
+    }
+
+    fn sqrt(a: Fixed) -> Fixed {
+        Fixed::from_f32(a.to_f32().sqrt())
+    }
+
+    fn abs(a: Fixed) -> Fixed {
+        Fixed(a.0.wrapping_abs())
+    }
+
+    fn cmp_eq(a: Fixed, b: Fixed) -> bool {
+        a == b
+    }
+
+    fn cmp_lt(a: Fixed, b: Fixed) -> bool {
+        a < b
+    }
+
+    fn cmp_lte(a: Fixed, b: Fixed) -> bool {
+        a <= b
+    }
+
+    fn cmp_gt(a: Fixed, b: Fixed) -> bool {
+        a > b
+    }
+
+    fn cmp_gte(a: Fixed, b: Fixed) -> bool {
+        a >= b
+    }
+
+    fn cmp_min(a: Fixed, b: Fixed) -> Fixed {
+        if a < b {
+            a
+        } else {
+            b
+        }
+    }
+
+    fn cmp_max(a: Fixed, b: Fixed) -> Fixed {
+        if a > b {
+            a
+        } else {
+            b
+        }
+    }
+
+    fn add(a: Fixed, b: Fixed) -> Fixed {
+        Fixed(a.0.wrapping_add(b.0))
+    }
+
+    fn sub(a: Fixed, b: Fixed) -> Fixed {
+        Fixed(a.0.wrapping_sub(b.0))
+    }
+
+    fn mul(a: Fixed, b: Fixed) -> Fixed {
+        Fixed(((a.0 as i64 * b.0 as i64) >> FRAC_BITS) as i32)
+    }
+
+    fn div(a: Fixed, b: Fixed) -> Fixed {
+        Fixed((((a.0 as i64) << FRAC_BITS) / b.0 as i64) as i32)
+    }
+}
+
+/// An emulated register holding 4 [Fixed] values.
+struct FixedX4;
+
+impl FixedX4 {
+    #[inline(always)]
+    fn map2(
+        l1: [Fixed; 4],
+        l2: [Fixed; 4],
+        op: impl Fn(Fixed, Fixed) -> Fixed,
+    ) -> [Fixed; 4] {
+        [
+            op(l1[0], l2[0]),
+            op(l1[1], l2[1]),
+            op(l1[2], l2[2]),
+            op(l1[3], l2[3]),
+        ]
+    }
+
+    #[inline(always)]
+    fn cmp(
+        l1: [Fixed; 4],
+        l2: [Fixed; 4],
+        op: impl Fn(Fixed, Fixed) -> bool,
+    ) -> [Fixed; 4] {
+        // The comparison routines expect each element to be `1` or `0`, not a bitmask.
+        Self::map2(l1, l2, |a, b| FixedMath::cast_bool(op(a, b)))
+    }
+}
+
+impl SimdRegister<Fixed> for FixedX4 {
+    // `elements_per_lane` is derived from the size of the register, which is
+    // correct here as the register is a packed array of `Fixed` values.
+    type Register = [Fixed; 4];
+
+    unsafe fn load(mem: *const Fixed) -> Self::Register {
+        mem.cast::<Self::Register>().read_unaligned()
+    }
+
+    unsafe fn filled(value: Fixed) -> Self::Register {
+        [value; 4]
+    }
+
+    unsafe fn zeroed() -> Self::Register {
+        [FixedMath::zero(); 4]
+    }
+
+    unsafe fn add(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Self::map2(l1, l2, FixedMath::add)
+    }
+
+    unsafe fn sub(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Self::map2(l1, l2, FixedMath::sub)
+    }
+
+    unsafe fn mul(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Self::map2(l1, l2, FixedMath::mul)
+    }
+
+    unsafe fn div(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Self::map2(l1, l2, FixedMath::div)
+    }
+
+    unsafe fn fmadd(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        Self::add(Self::mul(l1, l2), acc)
+    }
+
+    unsafe fn max(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Self::map2(l1, l2, FixedMath::cmp_max)
+    }
+
+    unsafe fn min(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Self::map2(l1, l2, FixedMath::cmp_min)
+    }
+
+    unsafe fn eq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Self::cmp(l1, l2, FixedMath::cmp_eq)
+    }
+
+    unsafe fn neq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Self::cmp(l1, l2, |a, b| !FixedMath::cmp_eq(a, b))
+    }
+
+    unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Self::cmp(l1, l2, FixedMath::cmp_lt)
+    }
+
+    unsafe fn lte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Self::cmp(l1, l2, FixedMath::cmp_lte)
+    }
+
+    unsafe fn gt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Self::cmp(l1, l2, FixedMath::cmp_gt)
+    }
+
+    unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Self::cmp(l1, l2, FixedMath::cmp_gte)
+    }
+
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let mut result = l2;
+        for i in 0..4 {
+            if mask[i] != FixedMath::zero() {
+                result[i] = l1[i];
+            }
+        }
+        result
+    }
+
+    // The dense lane helpers can be used when overriding the `*_dense` methods,
+    // in this case, mirroring the default implementation.
+    unsafe fn add_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        l1.zip_map(l2, |a, b| Self::add(a, b))
+    }
+
+    unsafe fn sum_to_value(reg: Self::Register) -> Fixed {
+        reg.into_iter().fold(FixedMath::zero(), FixedMath::add)
+    }
+
+    unsafe fn max_to_value(reg: Self::Register) -> Fixed {
+        reg.into_iter().fold(FixedMath::min(), FixedMath::cmp_max)
+    }
+
+    unsafe fn min_to_value(reg: Self::Register) -> Fixed {
+        reg.into_iter().fold(FixedMath::max(), FixedMath::cmp_min)
+    }
+
+    unsafe fn write(mem: *mut Fixed, reg: Self::Register) {
+        mem.cast::<Self::Register>().write_unaligned(reg)
+    }
+}
+
+fn main() {
+    // An odd length ensures both the register and scalar tail paths are used.
+    let a = (0..77)
+        .map(|i| Fixed::from_f32(i as f32 * 0.25))
+        .collect::<Vec<_>>();
+    let b = (0..77)
+        .map(|i| Fixed::from_f32(8.0 - i as f32 * 0.125))
+        .collect::<Vec<_>>();
+
+    let expected_dot = a
+        .iter()
+        .zip(b.iter())
+        .fold(FixedMath::zero(), |acc, (a, b)| {
+            FixedMath::add(acc, FixedMath::mul(*a, *b))
+        });
+    let dot = unsafe { danger::generic_dot::<Fixed, FixedX4, FixedMath, _, _>(&a, &b) };
+    assert_eq!(dot, expected_dot);
+    println!("dot: {}", dot.to_f32());
+
+    let max = unsafe { danger::generic_cmp_max::<Fixed, FixedX4, FixedMath, _>(&a) };
+    assert_eq!(max, Fixed::from_f32(19.0));
+    println!("max: {}", max.to_f32());
+
+    let mut added = vec![Fixed::default(); a.len()];
+    unsafe {
+        danger::generic_add_vertical::<Fixed, FixedX4, FixedMath, _, _, _>(
+            &a, &b, &mut added,
+        )
+    };
+    for (i, value) in added.iter().enumerate() {
+        assert_eq!(*value, FixedMath::add(a[i], b[i]));
+    }
+
+    let mut greater = vec![Fixed::default(); a.len()];
+    unsafe {
+        danger::generic_cmp_gt_vertical::<Fixed, FixedX4, FixedMath, _, _, _>(
+            &a,
+            &b,
+            &mut greater,
+        )
+    };
+    let num_greater = greater.iter().filter(|v| **v == FixedMath::one()).count();
+    assert_eq!(
+        num_greater,
+        a.iter().zip(b.iter()).filter(|(a, b)| a > b).count()
+    );
+    println!("a > b: {num_greater} elements");
+}
@@ -9,6 +9,17 @@ use core::mem::MaybeUninit;
 ///
 /// When accessing the buffer pointer via this trait it is assumed that
 /// the buffer will _never_ be read from and only every written to.
+///
+/// ### Initialization Guarantee
+///
+/// The vertical routines provided by CFAVML which take a `WriteOnlyBuffer` as their output
+/// write every element of the buffer, from `0` to [raw_buffer_len](Self::raw_buffer_len),
+/// exactly once before returning, including the elements in the tail which do not fill
+/// a complete register.
+///
+/// This means once a routine returns normally, a `&mut [MaybeUninit<T>]` output
+/// can be assumed to be fully initialized. If the routine panics, i.e. because the
+/// inputs cannot be projected to the output length, no such assumption can be made.
 pub trait WriteOnlyBuffer: sealed::Sealed {
     type Item;
 
@@ -34,80 +45,95 @@ pub trait WriteOnlyBuffer: sealed::Sealed {
     }
 }
 
+/// A value type which can be written to via a `&mut [T]` or `&mut [MaybeUninit<T>]`
+/// [WriteOnlyBuffer].
+///
+/// This is implemented for all primitive types supported by CFAVML, custom types
+/// used alongside a custom [SimdRegister](crate::danger::SimdRegister) implementation
+/// can implement this trait in order to be used as the output of the vertical routines.
+pub trait BufferElement: Copy {}
+
+impl BufferElement for f32 {}
+impl BufferElement for f64 {}
+
+impl BufferElement for i8 {}
+impl BufferElement for i16 {}
+impl BufferElement for i32 {}
+impl BufferElement for i64 {}
+
+impl BufferElement for u8 {}
+impl BufferElement for u16 {}
+impl BufferElement for u32 {}
+impl BufferElement for u64 {}
+
+#[cfg(feature = "half")]
+impl BufferElement for half::f16 {}
+#[cfg(feature = "half")]
+impl BufferElement for half::bf16 {}
+
+/// A value type whose safe routines are implemented by CFAVML itself, and therefore
+/// uphold the [initialization guarantee](WriteOnlyBuffer#initialization-guarantee)
+/// of the output buffer.
+///
+/// The safe routine traits can be implemented outside of this crate, so this trait is
+/// sealed in order for safe code to rely on the guarantee when handing out uninitialized
+/// memory, i.e. [add_into_spare](crate::add_into_spare).
+pub trait TrustedBufferElement: BufferElement + sealed::SealedElement {}
+
+impl<T: BufferElement + sealed::SealedElement> TrustedBufferElement for T {}
+
 mod sealed {
     use core::mem::MaybeUninit;
 
+    use super::BufferElement;
+
     pub trait Sealed {}
 
-    impl Sealed for &mut [f32] {}
-    impl Sealed for &mut [f64] {}
+    impl<T: BufferElement> Sealed for &mut [T] {}
+    impl<T: BufferElement> Sealed for &mut [MaybeUninit<T>] {}
+
+    pub trait SealedElement {}
+
+    impl SealedElement for f32 {}
+    impl SealedElement for f64 {}
+    impl SealedElement for i8 {}
+    impl SealedElement for i16 {}
+    impl SealedElement for i32 {}
+    impl SealedElement for i64 {}
+    impl SealedElement for u8 {}
+    impl SealedElement for u16 {}
+    impl SealedElement for u32 {}
+    impl SealedElement for u64 {}
+    #[cfg(feature = "half")]
+    impl SealedElement for half::f16 {}
+    #[cfg(feature = "half")]
+    impl SealedElement for half::bf16 {}
+}
 
-    impl Sealed for &mut [i8] {}
-    impl Sealed for &mut [i16] {}
-    impl Sealed for &mut [i32] {}
-    impl Sealed for &mut [i64] {}
+impl<T: BufferElement> WriteOnlyBuffer for &mut [T] {
+    type Item = T;
 
-    impl Sealed for &mut [u8] {}
-    impl Sealed for &mut [u16] {}
-    impl Sealed for &mut [u32] {}
-    impl Sealed for &mut [u64] {}
+    #[inline(always)]
+    fn raw_buffer_len(&self) -> usize {
+        self.len()
+    }
 
-    impl Sealed for &mut [MaybeUninit<f32>] {}
-    impl Sealed for &mut [MaybeUninit<f64>] {}
+    #[inline(always)]
+    unsafe fn as_write_only_ptr(&mut self) -> *mut Self::Item {
+        self.as_mut_ptr()
+    }
+}
 
-    impl Sealed for &mut [MaybeUninit<i8>] {}
-    impl Sealed for &mut [MaybeUninit<i16>] {}
-    impl Sealed for &mut [MaybeUninit<i32>] {}
-    impl Sealed for &mut [MaybeUninit<i64>] {}
+impl<T: BufferElement> WriteOnlyBuffer for &mut [MaybeUninit<T>] {
+    type Item = T;
 
-    impl Sealed for &mut [MaybeUninit<u8>] {}
-    impl Sealed for &mut [MaybeUninit<u16>] {}
-    impl Sealed for &mut [MaybeUninit<u32>] {}
-    impl Sealed for &mut [MaybeUninit<u64>] {}
-}
+    #[inline(always)]
+    fn raw_buffer_len(&self) -> usize {
+        self.len()
+    }
 
-macro_rules! add_slice_impl {
-    ($t:ty, inner = $inner:ty) => {
-        impl WriteOnlyBuffer for &mut [$t] {
-            type Item = $inner;
-
-            #[inline(always)]
-            fn raw_buffer_len(&self) -> usize {
-                self.len()
-            }
-
-            #[inline(always)]
-            unsafe fn as_write_only_ptr(&mut self) -> *mut Self::Item {
-                self.as_mut_ptr().cast()
-            }
-        }
-    };
+    #[inline(always)]
+    unsafe fn as_write_only_ptr(&mut self) -> *mut Self::Item {
+        self.as_mut_ptr().cast()
+    }
 }
-
-// Initialised impls
-add_slice_impl!(f32, inner = f32);
-add_slice_impl!(f64, inner = f64);
-
-add_slice_impl!(i8, inner = i8);
-add_slice_impl!(i16, inner = i16);
-add_slice_impl!(i32, inner = i32);
-add_slice_impl!(i64, inner = i64);
-
-add_slice_impl!(u8, inner = u8);
-add_slice_impl!(u16, inner = u16);
-add_slice_impl!(u32, inner = u32);
-add_slice_impl!(u64, inner = u64);
-
-// Uninit impls
-add_slice_impl!(MaybeUninit<f32>, inner = f32);
-add_slice_impl!(MaybeUninit<f64>, inner = f64);
-
-add_slice_impl!(MaybeUninit<i8>, inner = i8);
-add_slice_impl!(MaybeUninit<i16>, inner = i16);
-add_slice_impl!(MaybeUninit<i32>, inner = i32);
-add_slice_impl!(MaybeUninit<i64>, inner = i64);
-
-add_slice_impl!(MaybeUninit<u8>, inner = u8);
-add_slice_impl!(MaybeUninit<u16>, inner = u16);
-add_slice_impl!(MaybeUninit<u32>, inner = u32);
-add_slice_impl!(MaybeUninit<u64>, inner = u64);
@@ -1,9 +1,17 @@
+use core::mem;
+
 use crate::buffer::WriteOnlyBuffer;
 use crate::danger::{DenseLane, SimdRegister};
 use crate::math::Math;
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 
 #[inline(always)]
+#[allow(clippy::extra_unused_type_parameters, clippy::type_complexity)]
+/// Applies the kernels element wise over `a` and `b`, writing the outputs to `result`.
+///
+/// The register loops only ever run over whole registers within the buffers, the
+/// remaining tail is processed one element at a time with `single_kernel`, so no
+/// load or store touches memory beyond the end of the provided slices.
 pub(crate) unsafe fn apply_vertical_kernel<T, R, M, B1, B2, B3>(
     a: B1,
     b: B2,
@@ -30,6 +38,388 @@ pub(crate) unsafe fn apply_vertical_kernel<T, R, M, B1, B2, B3>(
     let mut a = a.into_projected_mem_loader(project_to_len);
     let mut b = b.into_projected_mem_loader(project_to_len);
 
+    // Checking the alignment once up front allows the register loops to use aligned
+    // loads and stores, avoiding cache line splits on the memory bound routines.
+    let align = mem::size_of::<R::Register>();
+    let is_aligned = (result_ptr as usize) % align == 0
+        && a.is_aligned_to(align)
+        && b.is_aligned_to(align);
+
+    let mut i = if is_aligned {
+        apply_vertical_register_loops::<true, T, R, _, _>(
+            &mut a,
+            &mut b,
+            result_ptr,
+            project_to_len,
+            dense_lane_kernel,
+            reg_kernel,
+        )
+    } else {
+        apply_vertical_register_loops::<false, T, R, _, _>(
+            &mut a,
+            &mut b,
+            result_ptr,
+            project_to_len,
+            dense_lane_kernel,
+            reg_kernel,
+        )
+    };
+
+    while i < project_to_len {
+        result.write_at(i, single_kernel(a.read(), b.read()));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+#[allow(clippy::type_complexity)]
+/// Applies the dense lane and register kernels of [apply_vertical_kernel], returning
+/// the position of the first element which is yet to be processed.
+///
+/// When `ALIGNED` is `true` the aligned loads and stores are used, which requires
+/// `result_ptr`, `a` and `b` to all be aligned to the size of `R::Register`.
+unsafe fn apply_vertical_register_loops<const ALIGNED: bool, T, R, L1, L2>(
+    a: &mut L1,
+    b: &mut L2,
+    result_ptr: *mut T,
+    len: usize,
+    dense_lane_kernel: unsafe fn(
+        DenseLane<R::Register>,
+        DenseLane<R::Register>,
+    ) -> DenseLane<R::Register>,
+    reg_kernel: unsafe fn(R::Register, R::Register) -> R::Register,
+) -> usize
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    L1: MemLoader<Value = T>,
+    L2: MemLoader<Value = T>,
+{
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        if ALIGNED {
+            let l1 = a.load_aligned_dense::<R>();
+            let l2 = b.load_aligned_dense::<R>();
+            R::write_aligned_dense(result_ptr.add(i), dense_lane_kernel(l1, l2));
+        } else {
+            let l1 = a.load_dense::<R>();
+            let l2 = b.load_dense::<R>();
+            R::write_dense(result_ptr.add(i), dense_lane_kernel(l1, l2));
+        }
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        if ALIGNED {
+            let l1 = a.load_aligned::<R>();
+            let l2 = b.load_aligned::<R>();
+            R::write_aligned(result_ptr.add(i), reg_kernel(l1, l2));
+        } else {
+            let l1 = a.load::<R>();
+            let l2 = b.load::<R>();
+            R::write(result_ptr.add(i), reg_kernel(l1, l2));
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    i
+}
+
+#[inline(always)]
+#[allow(clippy::extra_unused_type_parameters, clippy::type_complexity)]
+/// The streaming variant of [apply_vertical_kernel], writing the register outputs
+/// to `result` with [SimdRegister::write_stream].
+///
+/// Non-temporal stores require the destination to be aligned to the size of
+/// `R::Register`, so the elements before the first aligned position of `result`
+/// are processed one at a time with regular stores.
+pub(crate) unsafe fn apply_vertical_stream_kernel<T, R, M, B1, B2, B3>(
+    a: B1,
+    b: B2,
+    mut result: &mut [B3],
+    dense_lane_kernel: unsafe fn(
+        DenseLane<R::Register>,
+        DenseLane<R::Register>,
+    ) -> DenseLane<R::Register>,
+    reg_kernel: unsafe fn(R::Register, R::Register) -> R::Register,
+    single_kernel: unsafe fn(T, T) -> T,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    let project_to_len = result.raw_buffer_len();
+    let result_ptr = result.as_write_only_ptr();
+
+    let mut a = a.into_projected_mem_loader(project_to_len);
+    let mut b = b.into_projected_mem_loader(project_to_len);
+
+    // If the pointer can never be aligned `align_offset` returns `usize::MAX`, in which
+    // case every element is written with regular stores.
+    let prologue_len = result_ptr
+        .align_offset(mem::size_of::<R::Register>())
+        .min(project_to_len);
+
+    let mut i = 0;
+    while i < prologue_len {
+        result.write_at(i, single_kernel(a.read(), b.read()));
+
+        i += 1;
+    }
+
+    let stream_ptr = result_ptr.add(prologue_len);
+    let stream_len = project_to_len - prologue_len;
+    let offset_from = stream_len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let mut j = 0;
+    while j < (stream_len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        let l2 = b.load_dense::<R>();
+        R::write_stream_dense(stream_ptr.add(j), dense_lane_kernel(l1, l2));
+
+        j += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while j < (stream_len - offset_from) {
+        let l1 = a.load::<R>();
+        let l2 = b.load::<R>();
+        R::write_stream(stream_ptr.add(j), reg_kernel(l1, l2));
+
+        j += R::elements_per_lane();
+    }
+
+    // The streamed writes are weakly ordered, so must be fenced before returning.
+    R::stream_fence();
+
+    i += j;
+    while i < project_to_len {
+        result.write_at(i, single_kernel(a.read(), b.read()));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+#[allow(clippy::extra_unused_type_parameters, clippy::type_complexity)]
+/// The in-place variant of [apply_vertical_kernel], applying the kernels to each
+/// element of `a` and `b` and writing the output back to `a`.
+///
+/// Each register of `a` is always loaded before the result is written back to the
+/// same position, so reading and writing through the one pointer is sound. Passing
+/// `a` as both the input and the output of [apply_vertical_kernel] would instead
+/// alias a shared and mutable reference.
+pub(crate) unsafe fn apply_inplace_vertical_kernel<T, R, M, B1>(
+    a: &mut [T],
+    b: B1,
+    dense_lane_kernel: unsafe fn(
+        DenseLane<R::Register>,
+        DenseLane<R::Register>,
+    ) -> DenseLane<R::Register>,
+    reg_kernel: unsafe fn(R::Register, R::Register) -> R::Register,
+    single_kernel: unsafe fn(T, T) -> T,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let project_to_len = a.len();
+    let a_ptr = a.as_mut_ptr();
+
+    let mut b = b.into_projected_mem_loader(project_to_len);
+
+    let align = mem::size_of::<R::Register>();
+    let is_aligned = (a_ptr as usize) % align == 0 && b.is_aligned_to(align);
+
+    let mut i = if is_aligned {
+        apply_inplace_register_loops::<true, T, R, _>(
+            a_ptr,
+            &mut b,
+            project_to_len,
+            dense_lane_kernel,
+            reg_kernel,
+        )
+    } else {
+        apply_inplace_register_loops::<false, T, R, _>(
+            a_ptr,
+            &mut b,
+            project_to_len,
+            dense_lane_kernel,
+            reg_kernel,
+        )
+    };
+
+    while i < project_to_len {
+        let ptr = a_ptr.add(i);
+        ptr.write(single_kernel(ptr.read(), b.read()));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+#[allow(clippy::type_complexity)]
+/// Applies the dense lane and register kernels of [apply_inplace_vertical_kernel],
+/// returning the position of the first element which is yet to be processed.
+///
+/// When `ALIGNED` is `true` the aligned loads and stores are used, which requires
+/// `a_ptr` and `b` to both be aligned to the size of `R::Register`.
+unsafe fn apply_inplace_register_loops<const ALIGNED: bool, T, R, L1>(
+    a_ptr: *mut T,
+    b: &mut L1,
+    len: usize,
+    dense_lane_kernel: unsafe fn(
+        DenseLane<R::Register>,
+        DenseLane<R::Register>,
+    ) -> DenseLane<R::Register>,
+    reg_kernel: unsafe fn(R::Register, R::Register) -> R::Register,
+) -> usize
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    L1: MemLoader<Value = T>,
+{
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        if ALIGNED {
+            let l1 = R::load_aligned_dense(a_ptr.add(i));
+            let l2 = b.load_aligned_dense::<R>();
+            R::write_aligned_dense(a_ptr.add(i), dense_lane_kernel(l1, l2));
+        } else {
+            let l1 = R::load_dense(a_ptr.add(i));
+            let l2 = b.load_dense::<R>();
+            R::write_dense(a_ptr.add(i), dense_lane_kernel(l1, l2));
+        }
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        if ALIGNED {
+            let l1 = R::load_aligned(a_ptr.add(i));
+            let l2 = b.load_aligned::<R>();
+            R::write_aligned(a_ptr.add(i), reg_kernel(l1, l2));
+        } else {
+            let l1 = R::load(a_ptr.add(i));
+            let l2 = b.load::<R>();
+            R::write(a_ptr.add(i), reg_kernel(l1, l2));
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    i
+}
+
+#[inline(always)]
+#[allow(clippy::extra_unused_type_parameters, clippy::type_complexity)]
+/// The single input variant of [apply_vertical_kernel], applying the kernels
+/// to each element of `a` and writing the output to `result`.
+pub(crate) unsafe fn apply_unary_vertical_kernel<T, R, M, B1, B2>(
+    a: B1,
+    mut result: &mut [B2],
+    dense_lane_kernel: unsafe fn(DenseLane<R::Register>) -> DenseLane<R::Register>,
+    reg_kernel: unsafe fn(R::Register) -> R::Register,
+    single_kernel: unsafe fn(T) -> T,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    let project_to_len = result.raw_buffer_len();
+    let result_ptr = result.as_write_only_ptr();
+
+    let mut a = a.into_projected_mem_loader(project_to_len);
+
+    let offset_from = project_to_len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (project_to_len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        let res = dense_lane_kernel(l1);
+        R::write_dense(result_ptr.add(i), res);
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (project_to_len - offset_from) {
+        let l1 = a.load::<R>();
+        let res = reg_kernel(l1);
+        R::write(result_ptr.add(i), res);
+
+        i += R::elements_per_lane();
+    }
+
+    while i < project_to_len {
+        result.write_at(i, single_kernel(a.read()));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+#[allow(clippy::extra_unused_type_parameters, clippy::type_complexity)]
+/// The three input variant of [apply_vertical_kernel], applying the kernels
+/// to each element of `a`, `b` and `c` and writing the output to `result`.
+pub(crate) unsafe fn apply_ternary_vertical_kernel<T, R, M, B1, B2, B3, B4>(
+    a: B1,
+    b: B2,
+    c: B3,
+    mut result: &mut [B4],
+    dense_lane_kernel: unsafe fn(
+        DenseLane<R::Register>,
+        DenseLane<R::Register>,
+        DenseLane<R::Register>,
+    ) -> DenseLane<R::Register>,
+    reg_kernel: unsafe fn(R::Register, R::Register, R::Register) -> R::Register,
+    single_kernel: unsafe fn(T, T, T) -> T,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    B3: IntoMemLoader<T>,
+    B3::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B4]: WriteOnlyBuffer<Item = T>,
+{
+    let project_to_len = result.raw_buffer_len();
+    let result_ptr = result.as_write_only_ptr();
+
+    let mut a = a.into_projected_mem_loader(project_to_len);
+    let mut b = b.into_projected_mem_loader(project_to_len);
+    let mut c = c.into_projected_mem_loader(project_to_len);
+
     let offset_from = project_to_len % R::elements_per_dense();
 
     // Operate over dense lanes first.
@@ -37,8 +427,9 @@ pub(crate) unsafe fn apply_vertical_kernel<T, R, M, B1, B2, B3>(
     while i < (project_to_len - offset_from) {
         let l1 = a.load_dense::<R>();
         let l2 = b.load_dense::<R>();
-        let max = dense_lane_kernel(l1, l2);
-        R::write_dense(result_ptr.add(i), max);
+        let l3 = c.load_dense::<R>();
+        let res = dense_lane_kernel(l1, l2, l3);
+        R::write_dense(result_ptr.add(i), res);
 
         i += R::elements_per_dense();
     }
@@ -48,14 +439,15 @@ pub(crate) unsafe fn apply_vertical_kernel<T, R, M, B1, B2, B3>(
     while i < (project_to_len - offset_from) {
         let l1 = a.load::<R>();
         let l2 = b.load::<R>();
-        let max = reg_kernel(l1, l2);
-        R::write(result_ptr.add(i), max);
+        let l3 = c.load::<R>();
+        let res = reg_kernel(l1, l2, l3);
+        R::write(result_ptr.add(i), res);
 
         i += R::elements_per_lane();
     }
 
     while i < project_to_len {
-        result.write_at(i, single_kernel(a.read(), b.read()));
+        result.write_at(i, single_kernel(a.read(), b.read(), c.read()));
 
         i += 1;
     }
@@ -11,14 +11,39 @@
 
 #![allow(clippy::missing_safety_doc)]
 
-use core::mem;
+use core::mem::{self, MaybeUninit};
+
+/// The largest number of elements a register can hold for the default
+/// [SimdRegister::gather] and [SimdRegister::scatter] implementations,
+/// i.e. a 512-bit register of `u8`.
+const MAX_ELEMENTS_PER_LANE: usize = 64;
 
-#[doc(hidden)]
 #[macro_export]
 /// Applies a given operation expression over the dense lane for upto 3 args.
+///
+/// This is primarily intended for implementing the `*_dense` methods of the register
+/// traits for custom register types, see [DenseLane::map] and friends for a function
+/// based equivalent.
+///
+/// ```rust
+/// use cfavml::apply_dense;
+/// use cfavml::danger::DenseLane;
+///
+/// let l1 = DenseLane::copy(2.0f32);
+/// let l2 = DenseLane::copy(3.0f32);
+///
+/// let doubled = apply_dense!(|v: f32| v * 2.0, l1);
+/// assert_eq!(doubled.a, 4.0);
+///
+/// let scaled = apply_dense!(|v: f32, by: f32| v * by, l1, value = 4.0);
+/// assert_eq!(scaled.b, 8.0);
+///
+/// let added = apply_dense!(|a: f32, b: f32| a + b, l1, l2);
+/// assert_eq!(added.h, 5.0);
+/// ```
 macro_rules! apply_dense {
     ($op:expr, $l1:ident) => {{
-        DenseLane {
+        $crate::danger::DenseLane {
             a: $op($l1.a),
             b: $op($l1.b),
             c: $op($l1.c),
@@ -30,7 +55,7 @@ macro_rules! apply_dense {
         }
     }};
     ($op:expr, $l1:ident, value = $l2:expr) => {{
-        DenseLane {
+        $crate::danger::DenseLane {
             a: $op($l1.a, $l2),
             b: $op($l1.b, $l2),
             c: $op($l1.c, $l2),
@@ -42,7 +67,7 @@ macro_rules! apply_dense {
         }
     }};
     ($op:expr, $l1:ident, $l2:ident) => {{
-        DenseLane {
+        $crate::danger::DenseLane {
             a: $op($l1.a, $l2.a),
             b: $op($l1.b, $l2.b),
             c: $op($l1.c, $l2.c),
@@ -54,7 +79,7 @@ macro_rules! apply_dense {
         }
     }};
     ($op:expr, $l1:ident, $l2:ident, $l3:ident) => {{
-        DenseLane {
+        $crate::danger::DenseLane {
             a: $op($l1.a, $l2.a, $l3.a),
             b: $op($l1.b, $l2.b, $l3.b),
             c: $op($l1.c, $l2.c, $l3.c),
@@ -101,9 +126,97 @@ impl<T: Copy> DenseLane<T> {
             h: value,
         }
     }
+
+    #[inline(always)]
+    /// Applies `op` to each register in the lane, returning a new dense lane of the results.
+    pub fn map<U>(self, mut op: impl FnMut(T) -> U) -> DenseLane<U> {
+        DenseLane {
+            a: op(self.a),
+            b: op(self.b),
+            c: op(self.c),
+            d: op(self.d),
+            e: op(self.e),
+            f: op(self.f),
+            g: op(self.g),
+            h: op(self.h),
+        }
+    }
+
+    #[inline(always)]
+    /// Applies `op` to each pair of registers in `self` and `other`, returning a new
+    /// dense lane of the results.
+    pub fn zip_map<U>(
+        self,
+        other: DenseLane<T>,
+        mut op: impl FnMut(T, T) -> U,
+    ) -> DenseLane<U> {
+        DenseLane {
+            a: op(self.a, other.a),
+            b: op(self.b, other.b),
+            c: op(self.c, other.c),
+            d: op(self.d, other.d),
+            e: op(self.e, other.e),
+            f: op(self.f, other.f),
+            g: op(self.g, other.g),
+            h: op(self.h, other.h),
+        }
+    }
+
+    #[inline(always)]
+    /// Applies `op` to each triple of registers in `self`, `l2` and `l3`, returning a new
+    /// dense lane of the results.
+    pub fn zip3_map<U>(
+        self,
+        l2: DenseLane<T>,
+        l3: DenseLane<T>,
+        mut op: impl FnMut(T, T, T) -> U,
+    ) -> DenseLane<U> {
+        DenseLane {
+            a: op(self.a, l2.a, l3.a),
+            b: op(self.b, l2.b, l3.b),
+            c: op(self.c, l2.c, l3.c),
+            d: op(self.d, l2.d, l3.d),
+            e: op(self.e, l2.e, l3.e),
+            f: op(self.f, l2.f, l3.f),
+            g: op(self.g, l2.g, l3.g),
+            h: op(self.h, l2.h, l3.h),
+        }
+    }
 }
 
 /// A set of core SIMD operations over the given type.
+///
+/// This trait can be implemented for custom register types outside of this crate,
+/// allowing them to be used with the `generic_*` routines exported by the [danger](crate::danger)
+/// module, see `examples/custom_register.rs` for a complete example.
+///
+/// ## Implementation Contract
+///
+/// The generic routines rely on the following invariants being upheld by each implementation:
+///
+/// - `Register` holds exactly [elements_per_lane](Self::elements_per_lane) values of `T`,
+///   the default implementation assumes the register is a packed set of `T` values, so
+///   it must be overridden if that is not the case.
+/// - [load](Self::load) and [write](Self::write) read or write exactly `elements_per_lane`
+///   contiguous elements and must not assume the pointer is aligned.
+/// - [load_dense](Self::load_dense) and [write_dense](Self::write_dense) read or write
+///   [elements_per_dense](Self::elements_per_dense) contiguous elements, with lane `a`
+///   holding the first `elements_per_lane` elements through to lane `h` holding the last.
+/// - [zeroed](Self::zeroed) must return a register of the additive identity and
+///   [fmadd](Self::fmadd) must compute `l1 * l2 + acc`, the operation does not need
+///   to be fused.
+/// - The comparison operations (`eq`, `neq`, `lt`, `lte`, `gt` and `gte`) must return
+///   a register where each element is the `1` value of `T` (as returned by
+///   [Math::one](crate::math::Math::one)) if the comparison is true and `0` otherwise,
+///   _not_ a mask with all bits set. These values are summed directly by the
+///   counting routines and written directly to the output by the vertical routines.
+/// - [select](Self::select) must treat any non-zero element of the mask as true.
+/// - The horizontal reductions (`sum_to_value`, `max_to_value` and `min_to_value`) may
+///   combine the elements in any order.
+///
+/// Elements that do not fill a full register are handled by the routines using the
+/// scalar [Math](crate::math::Math) implementation provided alongside the register,
+/// so the results of the register operations should match the scalar operations.
 pub trait SimdRegister<T: Copy> {
     /// The single register for the given arch.
     ///
@@ -126,6 +239,20 @@ pub trait SimdRegister<T: Copy> {
     /// Loads `Self::elements_per_lane` elements of `T` into a `Self::Register`.
     unsafe fn load(mem: *const T) -> Self::Register;
 
+    #[inline(always)]
+    /// Loads `Self::elements_per_lane` elements of `T` into a `Self::Register` from
+    /// memory aligned to the size of `Self::Register`.
+    ///
+    /// By default this is identical to [SimdRegister::load], implementations with a
+    /// dedicated aligned load instruction should override this.
+    ///
+    /// # Safety
+    ///
+    /// `mem` must be aligned to `mem::size_of::<Self::Register>()` bytes.
+    unsafe fn load_aligned(mem: *const T) -> Self::Register {
+        Self::load(mem)
+    }
+
     /// Loads `Self::elements_per_lane` elements of `value` into a `Self::Register`.
     unsafe fn filled(value: T) -> Self::Register;
 
@@ -149,6 +276,28 @@ pub trait SimdRegister<T: Copy> {
         }
     }
 
+    #[allow(clippy::identity_op)]
+    #[allow(clippy::erasing_op)]
+    #[inline(always)]
+    /// Loads `Self::element_per_dense` elements of `T` into a `DenseLane<Self::Register>`
+    /// from memory aligned to the size of `Self::Register`.
+    ///
+    /// # Safety
+    ///
+    /// `mem` must be aligned to `mem::size_of::<Self::Register>()` bytes.
+    unsafe fn load_aligned_dense(mem: *const T) -> DenseLane<Self::Register> {
+        DenseLane {
+            a: Self::load_aligned(mem.add(Self::elements_per_lane() * 0)),
+            b: Self::load_aligned(mem.add(Self::elements_per_lane() * 1)),
+            c: Self::load_aligned(mem.add(Self::elements_per_lane() * 2)),
+            d: Self::load_aligned(mem.add(Self::elements_per_lane() * 3)),
+            e: Self::load_aligned(mem.add(Self::elements_per_lane() * 4)),
+            f: Self::load_aligned(mem.add(Self::elements_per_lane() * 5)),
+            g: Self::load_aligned(mem.add(Self::elements_per_lane() * 6)),
+            h: Self::load_aligned(mem.add(Self::elements_per_lane() * 7)),
+        }
+    }
+
     #[inline(always)]
     /// Loads `Self::element_per_dense` elements of `T` into a `DenseLane<Self::Register>`.
     unsafe fn filled_dense(value: T) -> DenseLane<Self::Register> {
@@ -208,6 +357,26 @@ pub trait SimdRegister<T: Copy> {
     /// in `l1` are _greater than or equal to_ elements in `l2`.
     unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register;
 
+    /// Selects elements from `l1` where the matching element in `mask` is non-zero,
+    /// otherwise selecting the element from `l2`.
+    ///
+    /// This is designed to work with the `0`/`1` masks produced by the comparison ops.
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register;
+
+    #[inline(always)]
+    /// Negates each element of the register, `-l1`.
+    ///
+    /// By default this subtracts `l1` from zero, wrapping integers in the same way
+    /// as `wrapping_neg`. Float implementations flip the sign bit instead so `-0.0`
+    /// and `NaN` elements match scalar negation.
+    unsafe fn neg(l1: Self::Register) -> Self::Register {
+        Self::sub(Self::zeroed(), l1)
+    }
+
     #[inline(always)]
     /// Perform a element wise add on two dense lanes.
     unsafe fn add_dense(
@@ -332,6 +501,23 @@ pub trait SimdRegister<T: Copy> {
         apply_dense!(Self::gte, l1, l2)
     }
 
+    #[inline(always)]
+    /// Selects elements from dense lane `l1` where the matching element in `mask`
+    /// is non-zero, otherwise selecting the element from `l2`.
+    unsafe fn select_dense(
+        mask: DenseLane<Self::Register>,
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::select, mask, l1, l2)
+    }
+
+    #[inline(always)]
+    /// Negates each element of the dense lane, `-l1`.
+    unsafe fn neg_dense(l1: DenseLane<Self::Register>) -> DenseLane<Self::Register> {
+        apply_dense!(Self::neg, l1)
+    }
+
     /// Performs a horizontal sum of the register returning the resulting value `T`.
     unsafe fn sum_to_value(reg: Self::Register) -> T;
 
@@ -404,4 +590,859 @@ pub trait SimdRegister<T: Copy> {
         Self::write(mem.add(Self::elements_per_lane() * 6), lane.g);
         Self::write(mem.add(Self::elements_per_lane() * 7), lane.h);
     }
+
+    #[inline(always)]
+    /// Writes a single register to the given memory aligned to the size of
+    /// `Self::Register`.
+    ///
+    /// By default this is identical to [SimdRegister::write], implementations with a
+    /// dedicated aligned store instruction should override this.
+    ///
+    /// # Safety
+    ///
+    /// `mem` must be aligned to `mem::size_of::<Self::Register>()` bytes.
+    unsafe fn write_aligned(mem: *mut T, reg: Self::Register) {
+        Self::write(mem, reg)
+    }
+
+    #[allow(clippy::identity_op)]
+    #[allow(clippy::erasing_op)]
+    #[inline(always)]
+    /// Write a dense lane to the given memory aligned to the size of `Self::Register`.
+    ///
+    /// # Safety
+    ///
+    /// `mem` must be aligned to `mem::size_of::<Self::Register>()` bytes.
+    unsafe fn write_aligned_dense(mem: *mut T, lane: DenseLane<Self::Register>) {
+        Self::write_aligned(mem.add(Self::elements_per_lane() * 0), lane.a);
+        Self::write_aligned(mem.add(Self::elements_per_lane() * 1), lane.b);
+        Self::write_aligned(mem.add(Self::elements_per_lane() * 2), lane.c);
+        Self::write_aligned(mem.add(Self::elements_per_lane() * 3), lane.d);
+        Self::write_aligned(mem.add(Self::elements_per_lane() * 4), lane.e);
+        Self::write_aligned(mem.add(Self::elements_per_lane() * 5), lane.f);
+        Self::write_aligned(mem.add(Self::elements_per_lane() * 6), lane.g);
+        Self::write_aligned(mem.add(Self::elements_per_lane() * 7), lane.h);
+    }
+
+    #[inline(always)]
+    /// Write a register to the given memory with a non-temporal store where available,
+    /// bypassing the cache for large outputs which will not be read again soon.
+    ///
+    /// By default this is a regular write, routines using this method must call
+    /// [SimdRegister::stream_fence] once all the streamed writes are complete.
+    ///
+    /// # Safety
+    ///
+    /// `mem` must be aligned to `mem::size_of::<Self::Register>()` bytes.
+    unsafe fn write_stream(mem: *mut T, reg: Self::Register) {
+        Self::write(mem, reg)
+    }
+
+    #[allow(clippy::identity_op)]
+    #[allow(clippy::erasing_op)]
+    #[inline(always)]
+    /// Write a dense lane to the given memory with non-temporal stores where available.
+    ///
+    /// # Safety
+    ///
+    /// `mem` must be aligned to `mem::size_of::<Self::Register>()` bytes.
+    unsafe fn write_stream_dense(mem: *mut T, lane: DenseLane<Self::Register>) {
+        Self::write_stream(mem.add(Self::elements_per_lane() * 0), lane.a);
+        Self::write_stream(mem.add(Self::elements_per_lane() * 1), lane.b);
+        Self::write_stream(mem.add(Self::elements_per_lane() * 2), lane.c);
+        Self::write_stream(mem.add(Self::elements_per_lane() * 3), lane.d);
+        Self::write_stream(mem.add(Self::elements_per_lane() * 4), lane.e);
+        Self::write_stream(mem.add(Self::elements_per_lane() * 5), lane.f);
+        Self::write_stream(mem.add(Self::elements_per_lane() * 6), lane.g);
+        Self::write_stream(mem.add(Self::elements_per_lane() * 7), lane.h);
+    }
+
+    #[inline(always)]
+    /// Orders the non-temporal stores made by [SimdRegister::write_stream] before any
+    /// stores which follow, making them visible to other threads.
+    ///
+    /// By default this does nothing, as the default streamed writes are regular writes.
+    unsafe fn stream_fence() {}
+
+    #[inline(always)]
+    /// Hints to the CPU that the cache line containing `mem` will be read soon,
+    /// fetching it into the cache ahead of the load.
+    ///
+    /// By default this does nothing, `mem` is never dereferenced so it does not need
+    /// to point to valid memory.
+    unsafe fn prefetch(_mem: *const T) {}
+
+    #[inline(always)]
+    /// Gathers `elements_per_lane` elements into a register, where the `i`th element
+    /// of the register is loaded from `base.add(indices[i])`.
+    ///
+    /// By default each element is read individually into a temporary buffer
+    /// which is then loaded.
+    ///
+    /// # Safety
+    ///
+    /// `indices` must point to at least `elements_per_lane` indices, each of which must be
+    /// within bounds of `base` and no larger than `i32::MAX` as the hardware gather
+    /// instructions treat the indices as signed.
+    unsafe fn gather(base: *const T, indices: *const u32) -> Self::Register {
+        let mut buffer = MaybeUninit::<[T; MAX_ELEMENTS_PER_LANE]>::uninit();
+        let buffer_ptr = buffer.as_mut_ptr().cast::<T>();
+        assert!(Self::elements_per_lane() <= MAX_ELEMENTS_PER_LANE);
+
+        for i in 0..Self::elements_per_lane() {
+            let index = indices.add(i).read() as usize;
+            buffer_ptr.add(i).write(base.add(index).read());
+        }
+
+        Self::load(buffer_ptr)
+    }
+
+    #[inline(always)]
+    /// Scatters the `elements_per_lane` elements of a register, where the `i`th element
+    /// of the register is written to `base.add(indices[i])`.
+    ///
+    /// The elements are written in order, so if multiple `indices` are the same, the
+    /// last of the elements written to that index wins.
+    ///
+    /// By default the register is written to a temporary buffer which is then
+    /// written out one element at a time.
+    ///
+    /// # Safety
+    ///
+    /// `indices` must point to at least `elements_per_lane` indices, each of which must be
+    /// within bounds of `base`.
+    unsafe fn scatter(reg: Self::Register, base: *mut T, indices: *const u32) {
+        let mut buffer = MaybeUninit::<[T; MAX_ELEMENTS_PER_LANE]>::uninit();
+        let buffer_ptr = buffer.as_mut_ptr().cast::<T>();
+        assert!(Self::elements_per_lane() <= MAX_ELEMENTS_PER_LANE);
+
+        Self::write(buffer_ptr, reg);
+        for i in 0..Self::elements_per_lane() {
+            let index = indices.add(i).read() as usize;
+            base.add(index).write(buffer_ptr.add(i).read());
+        }
+    }
+
+    #[inline(always)]
+    /// Computes the inclusive running max of the register, where the `i`th element
+    /// of the output is the max of elements `0..=i` of `reg`.
+    ///
+    /// By default this runs a cascade of `log2(elements_per_lane)` steps, each taking the
+    /// max of the register and a copy shifted `2^step` elements towards the end, the
+    /// shifted in elements are duplicated from the start of the register which leaves
+    /// them unchanged as max is idempotent.
+    unsafe fn prefix_max(reg: Self::Register) -> Self::Register {
+        prefix_cascade::<T, Self>(reg, Self::max, false)
+    }
+
+    #[inline(always)]
+    /// Computes the inclusive running min of the register, where the `i`th element
+    /// of the output is the min of elements `0..=i` of `reg`.
+    ///
+    /// By default this uses the same cascade as [SimdRegister::prefix_max].
+    unsafe fn prefix_min(reg: Self::Register) -> Self::Register {
+        prefix_cascade::<T, Self>(reg, Self::min, false)
+    }
+
+    #[inline(always)]
+    /// Computes the inclusive running sum of the register, where the `i`th element
+    /// of the output is the sum of elements `0..=i` of `reg`.
+    ///
+    /// By default this uses the same cascade as [SimdRegister::prefix_max], except the
+    /// shifted in elements are zero.
+    unsafe fn prefix_sum(reg: Self::Register) -> Self::Register {
+        prefix_cascade::<T, Self>(reg, Self::add, true)
+    }
+
+    #[inline(always)]
+    /// Returns a bitmask of the non-zero elements of `mask`, where bit `i` is set if the
+    /// `i`th element of the register is non-zero, i.e. the `movemask` of the register.
+    ///
+    /// This is designed to work with the `0`/`1` masks produced by the comparison ops.
+    ///
+    /// By default `mask` is compared with [SimdRegister::neq] against zero and written to a
+    /// temporary buffer, the elements of which are then checked one at a time, this assumes
+    /// the zero value of `T` is represented by all zero bits.
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        let mut buffer = MaybeUninit::<[T; MAX_ELEMENTS_PER_LANE]>::uninit();
+        let buffer_ptr = buffer.as_mut_ptr().cast::<T>();
+        assert!(Self::elements_per_lane() <= MAX_ELEMENTS_PER_LANE);
+
+        Self::write(buffer_ptr, Self::neq(mask, Self::zeroed()));
+
+        let mut bitmask = 0;
+        for i in 0..Self::elements_per_lane() {
+            let element = buffer_ptr.add(i).cast::<u8>();
+            let is_nonzero =
+                (0..mem::size_of::<T>()).any(|b| element.add(b).read() != 0);
+            bitmask |= (is_nonzero as u64) << i;
+        }
+
+        bitmask
+    }
+
+    #[inline(always)]
+    /// Writes each element of `reg` where bit `i` of `bitmask` is set to `mem`, keeping
+    /// their order within the register, and returns the number of elements written.
+    ///
+    /// Implementations may write up to [SimdRegister::elements_per_lane] elements, the
+    /// elements past the returned count are left in an unspecified state.
+    ///
+    /// By default `reg` is written to a temporary buffer and each selected element copied
+    /// one at a time.
+    ///
+    /// # Safety
+    ///
+    /// `mem` must be valid for writes of [SimdRegister::elements_per_lane] elements and
+    /// `bitmask` must not have any bits set past [SimdRegister::elements_per_lane].
+    unsafe fn compress_store(mem: *mut T, reg: Self::Register, bitmask: u64) -> usize {
+        let mut buffer = MaybeUninit::<[T; MAX_ELEMENTS_PER_LANE]>::uninit();
+        let buffer_ptr = buffer.as_mut_ptr().cast::<T>();
+        assert!(Self::elements_per_lane() <= MAX_ELEMENTS_PER_LANE);
+
+        Self::write(buffer_ptr, reg);
+
+        let mut bits = bitmask;
+        let mut count = 0;
+        while bits != 0 {
+            let offset = bits.trailing_zeros() as usize;
+            mem.add(count).write(buffer_ptr.add(offset).read());
+            count += 1;
+            bits &= bits - 1;
+        }
+
+        count
+    }
+
+    #[inline(always)]
+    /// Writes the index of each bit set in `bitmask` offset by `base` to `mem` in
+    /// ascending order, and returns the number of indices written.
+    ///
+    /// This is the index equivalent of [SimdRegister::compress_store], where `bitmask`
+    /// is the bitmask of a register starting at element `base`.
+    ///
+    /// Implementations may write up to [SimdRegister::elements_per_lane] indices, the
+    /// elements past the returned count are left in an unspecified state. By default
+    /// each index is written one at a time.
+    ///
+    /// # Safety
+    ///
+    /// `mem` must be valid for writes of [SimdRegister::elements_per_lane] elements and
+    /// `bitmask` must not have any bits set past [SimdRegister::elements_per_lane].
+    /// Every index written must fit within a `u32`.
+    unsafe fn compress_indices(mem: *mut u32, base: usize, bitmask: u64) -> usize {
+        let mut bits = bitmask;
+        let mut count = 0;
+        while bits != 0 {
+            let offset = bits.trailing_zeros() as usize;
+            mem.add(count).write((base + offset) as u32);
+            count += 1;
+            bits &= bits - 1;
+        }
+
+        count
+    }
+}
+
+#[inline(always)]
+/// Runs the shift and combine cascade of the default [SimdRegister::prefix_max],
+/// [SimdRegister::prefix_min] and [SimdRegister::prefix_sum] implementations through
+/// a temporary buffer.
+///
+/// If `zero_fill` is `false` the shifted in elements are duplicated from the start
+/// of the register, in which case `op` must be idempotent, i.e. `op(x, x) == x`.
+unsafe fn prefix_cascade<T, R>(
+    mut reg: R::Register,
+    op: unsafe fn(R::Register, R::Register) -> R::Register,
+    zero_fill: bool,
+) -> R::Register
+where
+    T: Copy,
+    R: SimdRegister<T> + ?Sized,
+{
+    let mut buffer = MaybeUninit::<[T; MAX_ELEMENTS_PER_LANE]>::uninit();
+    let mut shifted = MaybeUninit::<[T; MAX_ELEMENTS_PER_LANE]>::uninit();
+    let buffer_ptr = buffer.as_mut_ptr().cast::<T>();
+    let shifted_ptr = shifted.as_mut_ptr().cast::<T>();
+    assert!(R::elements_per_lane() <= MAX_ELEMENTS_PER_LANE);
+
+    let mut step = 1;
+    while step < R::elements_per_lane() {
+        R::write(buffer_ptr, reg);
+        if zero_fill {
+            R::write(shifted_ptr, R::zeroed());
+        }
+        for i in 0..R::elements_per_lane() {
+            if i >= step {
+                shifted_ptr.add(i).write(buffer_ptr.add(i - step).read());
+            } else if !zero_fill {
+                shifted_ptr.add(i).write(buffer_ptr.add(i).read());
+            }
+        }
+
+        reg = op(reg, R::load(shifted_ptr));
+        step *= 2;
+    }
+
+    reg
+}
+
+/// A set of bitwise SIMD operations over the given type.
+///
+/// These are kept separate from [SimdRegister] as they are only implemented
+/// for the integer types, bitwise operations over floats are rarely what you want.
+pub trait SimdBitwiseRegister<T: Copy>: SimdRegister<T> {
+    /// Performs a bitwise _and_ of registers `l1` and `l2`.
+    unsafe fn bitand(l1: Self::Register, l2: Self::Register) -> Self::Register;
+
+    /// Performs a bitwise _or_ of registers `l1` and `l2`.
+    unsafe fn bitor(l1: Self::Register, l2: Self::Register) -> Self::Register;
+
+    /// Performs a bitwise _xor_ of registers `l1` and `l2`.
+    unsafe fn bitxor(l1: Self::Register, l2: Self::Register) -> Self::Register;
+
+    /// Performs a bitwise _not_ of register `l1`, flipping every bit.
+    unsafe fn bitnot(l1: Self::Register) -> Self::Register;
+
+    /// Shifts each element in `l1` left by the matching element in `l2`.
+    ///
+    /// The shift amount is masked to the bit width of `T`, matching `wrapping_shl`.
+    unsafe fn shl(l1: Self::Register, l2: Self::Register) -> Self::Register;
+
+    /// Shifts each element in `l1` right by the matching element in `l2`.
+    ///
+    /// This is an _arithmetic_ shift for signed types and a _logical_ shift for
+    /// unsigned types, the shift amount is masked to the bit width of `T`,
+    /// matching `wrapping_shr`.
+    unsafe fn shr(l1: Self::Register, l2: Self::Register) -> Self::Register;
+
+    #[inline(always)]
+    /// Perform a bitwise _and_ on two dense lanes.
+    unsafe fn bitand_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::bitand, l1, l2)
+    }
+
+    #[inline(always)]
+    /// Perform a bitwise _or_ on two dense lanes.
+    unsafe fn bitor_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::bitor, l1, l2)
+    }
+
+    #[inline(always)]
+    /// Perform a bitwise _xor_ on two dense lanes.
+    unsafe fn bitxor_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::bitxor, l1, l2)
+    }
+
+    #[inline(always)]
+    /// Perform a bitwise _not_ on a dense lane.
+    unsafe fn bitnot_dense(l1: DenseLane<Self::Register>) -> DenseLane<Self::Register> {
+        apply_dense!(Self::bitnot, l1)
+    }
+
+    #[inline(always)]
+    /// Perform a left shift on two dense lanes.
+    unsafe fn shl_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::shl, l1, l2)
+    }
+
+    #[inline(always)]
+    /// Perform a right shift on two dense lanes.
+    unsafe fn shr_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::shr, l1, l2)
+    }
+}
+
+/// A set of saturating arithmetic SIMD operations over the given type.
+///
+/// These are kept separate from [SimdRegister] as they are only implemented
+/// for the integer types, floats already saturate to infinity on overflow.
+pub trait SimdSaturatingRegister<T: Copy>: SimdRegister<T> {
+    /// Adds each element in `l1` and `l2`, clamping the result to the bounds of `T`
+    /// rather than wrapping on overflow, matching `saturating_add`.
+    unsafe fn saturating_add(l1: Self::Register, l2: Self::Register) -> Self::Register;
+
+    /// Subtracts each element in `l2` from `l1`, clamping the result to the bounds of `T`
+    /// rather than wrapping on overflow, matching `saturating_sub`.
+    unsafe fn saturating_sub(l1: Self::Register, l2: Self::Register) -> Self::Register;
+
+    #[inline(always)]
+    /// Perform a saturating add on two dense lanes.
+    unsafe fn saturating_add_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::saturating_add, l1, l2)
+    }
+
+    #[inline(always)]
+    /// Perform a saturating sub on two dense lanes.
+    unsafe fn saturating_sub_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::saturating_sub, l1, l2)
+    }
+}
+
+/// A set of SIMD operations for converting elements of `F` into elements of `T`.
+///
+/// The conversion may change the width of each element, so rather than operating on
+/// registers, each call loads, converts and writes a fixed number of elements.
+///
+/// The conversions follow the semantics of an `as` cast, see [CastAs](crate::math::CastAs).
+pub trait SimdCastRegister<F: Copy, T: Copy> {
+    /// The number of elements converted by a single call to [SimdCastRegister::cast].
+    fn cast_elements_per_lane() -> usize;
+
+    #[inline(always)]
+    /// The number of elements converted by a single call to [SimdCastRegister::cast_dense].
+    fn cast_elements_per_dense() -> usize {
+        Self::cast_elements_per_lane() * DenseLane::<T>::NUM_LANES
+    }
+
+    /// Converts `Self::cast_elements_per_lane` elements of `F` read from `mem`, writing
+    /// the converted elements to `result`.
+    unsafe fn cast(mem: *const F, result: *mut T);
+
+    #[allow(clippy::identity_op)]
+    #[allow(clippy::erasing_op)]
+    #[inline(always)]
+    /// Converts `Self::cast_elements_per_dense` elements of `F` read from `mem`, writing
+    /// the converted elements to `result`.
+    unsafe fn cast_dense(mem: *const F, result: *mut T) {
+        let n = Self::cast_elements_per_lane();
+        Self::cast(mem.add(n * 0), result.add(n * 0));
+        Self::cast(mem.add(n * 1), result.add(n * 1));
+        Self::cast(mem.add(n * 2), result.add(n * 2));
+        Self::cast(mem.add(n * 3), result.add(n * 3));
+        Self::cast(mem.add(n * 4), result.add(n * 4));
+        Self::cast(mem.add(n * 5), result.add(n * 5));
+        Self::cast(mem.add(n * 6), result.add(n * 6));
+        Self::cast(mem.add(n * 7), result.add(n * 7));
+    }
+}
+
+/// A set of SIMD operations for loading elements of `F` widened into a register of `T`.
+///
+/// Unlike [SimdCastRegister] the widened values stay within registers, allowing routines
+/// to accumulate in a wider type than their inputs, i.e. `f32` vectors accumulated
+/// as `f64` values.
+pub trait SimdWidenRegister<F: Copy, T: Copy>: SimdRegister<T> {
+    /// Loads `Self::elements_per_lane` elements of `F`, widening each element into `T`.
+    unsafe fn load_widened(mem: *const F) -> Self::Register;
+
+    #[allow(clippy::identity_op)]
+    #[allow(clippy::erasing_op)]
+    #[inline(always)]
+    /// Loads `Self::elements_per_dense` elements of `F`, widening each element into `T`.
+    unsafe fn load_widened_dense(mem: *const F) -> DenseLane<Self::Register> {
+        DenseLane {
+            a: Self::load_widened(mem.add(Self::elements_per_lane() * 0)),
+            b: Self::load_widened(mem.add(Self::elements_per_lane() * 1)),
+            c: Self::load_widened(mem.add(Self::elements_per_lane() * 2)),
+            d: Self::load_widened(mem.add(Self::elements_per_lane() * 3)),
+            e: Self::load_widened(mem.add(Self::elements_per_lane() * 4)),
+            f: Self::load_widened(mem.add(Self::elements_per_lane() * 5)),
+            g: Self::load_widened(mem.add(Self::elements_per_lane() * 6)),
+            h: Self::load_widened(mem.add(Self::elements_per_lane() * 7)),
+        }
+    }
+}
+
+/// A set of SIMD operations that only make sense for the float types.
+///
+/// These are kept separate from [SimdRegister] as they are not implemented for the
+/// integer types.
+pub trait SimdFloatRegister<T: Copy>: SimdRegister<T> {
+    /// Computes `sqrt(l1² + l2²)` for each element in `l1` and `l2` without undue
+    /// overflow or underflow of the intermediate squares.
+    ///
+    /// If either element is infinite the result is infinite, otherwise if either element
+    /// is `NaN` the result is `NaN`.
+    unsafe fn hypot(l1: Self::Register, l2: Self::Register) -> Self::Register;
+
+    #[inline(always)]
+    /// Computes the hypotenuse of two dense lanes.
+    unsafe fn hypot_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::hypot, l1, l2)
+    }
+
+    #[inline(always)]
+    /// Returns a `0` (false) or `1` (true) mask of which elements of `l1` are `NaN`.
+    ///
+    /// `NaN` is the only value which is not equal to itself.
+    unsafe fn is_nan(l1: Self::Register) -> Self::Register {
+        Self::neq(l1, l1)
+    }
+
+    #[inline(always)]
+    /// Returns a `0` (false) or `1` (true) mask of which elements of `l1` are neither
+    /// infinite nor `NaN`.
+    ///
+    /// `x - x` is `0` for every finite value and `NaN` for infinite and `NaN` values.
+    unsafe fn is_finite(l1: Self::Register) -> Self::Register {
+        let delta = Self::sub(l1, l1);
+        Self::eq(delta, delta)
+    }
+
+    #[inline(always)]
+    /// Returns a `0` (false) or `1` (true) mask of which elements of `l1` are
+    /// positive or negative infinity.
+    ///
+    /// `x - x` is `NaN` for infinite and `NaN` values, removing the elements which
+    /// were already `NaN` leaves only the infinite values.
+    unsafe fn is_infinite(l1: Self::Register) -> Self::Register {
+        Self::sub(Self::is_nan(Self::sub(l1, l1)), Self::is_nan(l1))
+    }
+
+    #[inline(always)]
+    /// Checks which elements of a dense lane are `NaN`.
+    unsafe fn is_nan_dense(l1: DenseLane<Self::Register>) -> DenseLane<Self::Register> {
+        apply_dense!(Self::is_nan, l1)
+    }
+
+    #[inline(always)]
+    /// Checks which elements of a dense lane are finite.
+    unsafe fn is_finite_dense(
+        l1: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::is_finite, l1)
+    }
+
+    #[inline(always)]
+    /// Checks which elements of a dense lane are infinite.
+    unsafe fn is_infinite_dense(
+        l1: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::is_infinite, l1)
+    }
+}
+
+/// A set of SIMD operations for computing dot products over `T` with the products widened
+/// and accumulated as `i32` values.
+///
+/// This is primarily for quantized `i8`, `u8` and `i16` vectors where accumulating in
+/// `T` would overflow almost immediately, as such the `Accumulator` register may be a
+/// different width and layout to the input `Register`.
+pub trait SimdWideningDotRegister<T: Copy> {
+    /// The single input register for the given arch.
+    type Register: Copy;
+    /// The register holding the widened `i32` partial sums.
+    type Accumulator: Copy;
+
+    #[inline(always)]
+    /// The number of elements `T` in a dense lane.
+    fn widen_elements_per_dense() -> usize {
+        Self::widen_elements_per_lane() * DenseLane::<Self::Register>::NUM_LANES
+    }
+
+    #[inline(always)]
+    /// The number of elements `T` in a single input register.
+    fn widen_elements_per_lane() -> usize {
+        mem::size_of::<Self::Register>() / mem::size_of::<T>()
+    }
+
+    /// Loads `Self::widen_elements_per_lane` elements of `T` into a `Self::Register`.
+    unsafe fn load_widen(mem: *const T) -> Self::Register;
+
+    #[allow(clippy::identity_op)]
+    #[allow(clippy::erasing_op)]
+    #[inline(always)]
+    /// Loads `Self::widen_elements_per_dense` elements of `T` into a `DenseLane<Self::Register>`.
+    unsafe fn load_widen_dense(mem: *const T) -> DenseLane<Self::Register> {
+        DenseLane {
+            a: Self::load_widen(mem.add(Self::widen_elements_per_lane() * 0)),
+            b: Self::load_widen(mem.add(Self::widen_elements_per_lane() * 1)),
+            c: Self::load_widen(mem.add(Self::widen_elements_per_lane() * 2)),
+            d: Self::load_widen(mem.add(Self::widen_elements_per_lane() * 3)),
+            e: Self::load_widen(mem.add(Self::widen_elements_per_lane() * 4)),
+            f: Self::load_widen(mem.add(Self::widen_elements_per_lane() * 5)),
+            g: Self::load_widen(mem.add(Self::widen_elements_per_lane() * 6)),
+            h: Self::load_widen(mem.add(Self::widen_elements_per_lane() * 7)),
+        }
+    }
+
+    /// Creates a new zeroed accumulator.
+    unsafe fn zeroed_acc() -> Self::Accumulator;
+
+    #[inline(always)]
+    /// Creates a zeroed dense lane of accumulators.
+    unsafe fn zeroed_acc_dense() -> DenseLane<Self::Accumulator> {
+        DenseLane::copy(Self::zeroed_acc())
+    }
+
+    /// Multiplies the elements of `l1` and `l2`, widening the products and
+    /// adding them to `acc`.
+    unsafe fn dot_acc(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Accumulator,
+    ) -> Self::Accumulator;
+
+    #[inline(always)]
+    /// Multiplies the elements of dense lanes `l1` and `l2`, widening the products and
+    /// adding them to `acc`.
+    unsafe fn dot_acc_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+        acc: DenseLane<Self::Accumulator>,
+    ) -> DenseLane<Self::Accumulator> {
+        apply_dense!(Self::dot_acc, l1, l2, acc)
+    }
+
+    /// Performs an element wise add of two accumulators.
+    unsafe fn add_acc(l1: Self::Accumulator, l2: Self::Accumulator)
+        -> Self::Accumulator;
+
+    #[inline(always)]
+    /// Rolls up a dense lane of accumulators into a single accumulator.
+    unsafe fn sum_acc_to_register(
+        lane: DenseLane<Self::Accumulator>,
+    ) -> Self::Accumulator {
+        let mut acc1 = Self::add_acc(lane.a, lane.b);
+        let acc2 = Self::add_acc(lane.c, lane.d);
+        let mut acc3 = Self::add_acc(lane.e, lane.f);
+        let acc4 = Self::add_acc(lane.g, lane.h);
+
+        acc1 = Self::add_acc(acc1, acc2);
+        acc3 = Self::add_acc(acc3, acc4);
+
+        Self::add_acc(acc1, acc3)
+    }
+
+    /// Performs a horizontal sum of the accumulator returning the resulting `i32` value.
+    unsafe fn sum_acc_to_value(acc: Self::Accumulator) -> i32;
+}
+
+/// A set of SIMD operations for counting the bits of bit-packed vectors of `u64` words,
+/// i.e. for the Hamming distance and Jaccard similarity.
+///
+/// The bits of each word are counted into the `u64` lanes of an accumulator,
+/// so unlike the narrower per-byte counts of the popcount algorithms the counts can
+/// never overflow.
+pub trait SimdHammingRegister {
+    /// The register holding the input words and the `u64` bit counts.
+    type Register: Copy;
+
+    #[inline(always)]
+    /// The number of `u64` words in a dense lane.
+    fn words_per_dense() -> usize {
+        Self::words_per_lane() * DenseLane::<Self::Register>::NUM_LANES
+    }
+
+    #[inline(always)]
+    /// The number of `u64` words in a single register.
+    fn words_per_lane() -> usize {
+        mem::size_of::<Self::Register>() / mem::size_of::<u64>()
+    }
+
+    /// Loads `Self::words_per_lane` words into a `Self::Register`.
+    unsafe fn load_words(mem: *const u64) -> Self::Register;
+
+    #[allow(clippy::identity_op)]
+    #[allow(clippy::erasing_op)]
+    #[inline(always)]
+    /// Loads `Self::words_per_dense` words into a `DenseLane<Self::Register>`.
+    unsafe fn load_words_dense(mem: *const u64) -> DenseLane<Self::Register> {
+        DenseLane {
+            a: Self::load_words(mem.add(Self::words_per_lane() * 0)),
+            b: Self::load_words(mem.add(Self::words_per_lane() * 1)),
+            c: Self::load_words(mem.add(Self::words_per_lane() * 2)),
+            d: Self::load_words(mem.add(Self::words_per_lane() * 3)),
+            e: Self::load_words(mem.add(Self::words_per_lane() * 4)),
+            f: Self::load_words(mem.add(Self::words_per_lane() * 5)),
+            g: Self::load_words(mem.add(Self::words_per_lane() * 6)),
+            h: Self::load_words(mem.add(Self::words_per_lane() * 7)),
+        }
+    }
+
+    /// Creates a new zeroed accumulator.
+    unsafe fn zeroed_acc() -> Self::Register;
+
+    #[inline(always)]
+    /// Creates a zeroed dense lane of accumulators.
+    unsafe fn zeroed_acc_dense() -> DenseLane<Self::Register> {
+        DenseLane::copy(Self::zeroed_acc())
+    }
+
+    /// Performs a bitwise XOR of the words of `l1` and `l2`.
+    unsafe fn xor_words(l1: Self::Register, l2: Self::Register) -> Self::Register;
+
+    /// Performs a bitwise AND of the words of `l1` and `l2`.
+    unsafe fn and_words(l1: Self::Register, l2: Self::Register) -> Self::Register;
+
+    /// Performs a bitwise OR of the words of `l1` and `l2`.
+    unsafe fn or_words(l1: Self::Register, l2: Self::Register) -> Self::Register;
+
+    /// Counts the set bits of each word of `words`, adding the counts to `acc`.
+    unsafe fn popcount_acc(words: Self::Register, acc: Self::Register)
+        -> Self::Register;
+
+    #[inline(always)]
+    /// Counts the bits which differ between each word of `l1` and `l2`, adding
+    /// the counts to `acc`.
+    unsafe fn xor_popcount_acc(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        Self::popcount_acc(Self::xor_words(l1, l2), acc)
+    }
+
+    #[inline(always)]
+    /// Counts the bits which differ between each word of dense lanes `l1` and `l2`,
+    /// adding the counts to `acc`.
+    unsafe fn xor_popcount_acc_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+        acc: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::xor_popcount_acc, l1, l2, acc)
+    }
+
+    #[inline(always)]
+    /// Counts the bits set in both of each word of `l1` and `l2`, adding
+    /// the counts to `acc`.
+    unsafe fn and_popcount_acc(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        Self::popcount_acc(Self::and_words(l1, l2), acc)
+    }
+
+    #[inline(always)]
+    /// Counts the bits set in both of each word of dense lanes `l1` and `l2`,
+    /// adding the counts to `acc`.
+    unsafe fn and_popcount_acc_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+        acc: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::and_popcount_acc, l1, l2, acc)
+    }
+
+    #[inline(always)]
+    /// Counts the bits set in either of each word of `l1` and `l2`, adding
+    /// the counts to `acc`.
+    unsafe fn or_popcount_acc(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        Self::popcount_acc(Self::or_words(l1, l2), acc)
+    }
+
+    #[inline(always)]
+    /// Counts the bits set in either of each word of dense lanes `l1` and `l2`,
+    /// adding the counts to `acc`.
+    unsafe fn or_popcount_acc_dense(
+        l1: DenseLane<Self::Register>,
+        l2: DenseLane<Self::Register>,
+        acc: DenseLane<Self::Register>,
+    ) -> DenseLane<Self::Register> {
+        apply_dense!(Self::or_popcount_acc, l1, l2, acc)
+    }
+
+    /// Performs an element wise add of two accumulators.
+    unsafe fn add_acc(l1: Self::Register, l2: Self::Register) -> Self::Register;
+
+    #[inline(always)]
+    /// Rolls up a dense lane of accumulators into a single accumulator.
+    unsafe fn sum_acc_to_register(lane: DenseLane<Self::Register>) -> Self::Register {
+        let mut acc1 = Self::add_acc(lane.a, lane.b);
+        let acc2 = Self::add_acc(lane.c, lane.d);
+        let mut acc3 = Self::add_acc(lane.e, lane.f);
+        let acc4 = Self::add_acc(lane.g, lane.h);
+
+        acc1 = Self::add_acc(acc1, acc2);
+        acc3 = Self::add_acc(acc3, acc4);
+
+        Self::add_acc(acc1, acc3)
+    }
+
+    /// Performs a horizontal sum of the accumulator returning the resulting `u64` value.
+    unsafe fn sum_acc_to_value(acc: Self::Register) -> u64;
+}
+
+/// A set of SIMD operations over scalable vector registers, i.e. SVE, where the number
+/// of elements held by each register is only known at runtime.
+///
+/// ### Why this is not a [SimdRegister]
+///
+/// Scalable vectors are _sizeless_ in the sense that their width is a property of the
+/// CPU rather than the type, so they cannot be stored within a struct or array. This
+/// rules out the [DenseLane] based unrolling used by all the fixed width registers,
+/// the routines built on this trait instead keep their independent accumulators as
+/// separate locals.
+///
+/// In place of the fixed `elements_per_lane`, [SimdScalableRegister::elements_per_lane]
+/// is a runtime query of the vector length, and every operation takes a `Predicate`
+/// selecting the active elements. The routines use a partial predicate for the tail
+/// of each buffer, which removes the scalar remainder loop entirely.
+pub trait SimdScalableRegister<T: Copy> {
+    /// The scalable register for the given arch.
+    type Register: Copy;
+    /// The predicate selecting the active elements of a register.
+    type Predicate: Copy;
+
+    /// The number of elements `T` held by a single register on the current CPU.
+    unsafe fn elements_per_lane() -> usize;
+
+    /// Creates a predicate with every element active.
+    unsafe fn predicate_all() -> Self::Predicate;
+
+    /// Creates a predicate where only the elements at positions `i..len` are active,
+    /// relative to the start of the register at position `i`.
+    unsafe fn predicate_while_lt(i: usize, len: usize) -> Self::Predicate;
+
+    /// Loads the active elements of `T` into a `Self::Register`, inactive elements
+    /// are set to zero and their memory is never read.
+    unsafe fn load(pg: Self::Predicate, mem: *const T) -> Self::Register;
+
+    /// Writes the active elements of the register to the `mem` pointer, the memory
+    /// of inactive elements is never written.
+    unsafe fn write(pg: Self::Predicate, mem: *mut T, reg: Self::Register);
+
+    /// Creates a new zeroed register.
+    unsafe fn zeroed() -> Self::Register;
+
+    /// Performs an element wise add of the active elements of `l1` and `l2`,
+    /// inactive elements take the value of `l1`.
+    unsafe fn add(
+        pg: Self::Predicate,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register;
+
+    /// Multiplies the active elements of `l1` and `l2` and adds the result to `acc`,
+    /// inactive elements take the value of `acc`.
+    unsafe fn fmadd(
+        pg: Self::Predicate,
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register;
+
+    /// Performs a horizontal sum of all elements in the register.
+    unsafe fn sum_to_value(reg: Self::Register) -> T;
 }
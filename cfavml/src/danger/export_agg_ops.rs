@@ -3,7 +3,13 @@
 //! These include routines that don't have a more suitable grouping (i.e. horizontal sum)
 //! but still provide useful value having SIMD variants.
 
-use crate::danger::{generic_sum, SimdRegister};
+use crate::danger::{
+    generic_sum,
+    generic_sum_compensated,
+    generic_sum_f32_f64acc,
+    generic_sum_ordered,
+    SimdRegister,
+};
 use crate::math::{AutoMath, Math};
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 
@@ -37,7 +43,10 @@ macro_rules! define_sum_impl {
 define_sum_impl!(generic_fallback_sum, Fallback);
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 define_sum_impl!(generic_avx2_sum, Avx2, target_features = "avx2");
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 define_sum_impl!(
     generic_avx512_sum,
     Avx512,
@@ -47,6 +56,139 @@ define_sum_impl!(
 #[cfg(target_arch = "aarch64")]
 define_sum_impl!(generic_neon_sum, Neon, target_features = "neon");
 
+macro_rules! define_slice_sum_impl {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &[T]) -> T
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+define_slice_sum_impl!(
+    name = generic_fallback_sum_ordered,
+    op = generic_sum_ordered,
+    doc = "../export_docs/agg_horizontal_sum_ordered.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_slice_sum_impl!(
+    name = generic_avx2_sum_ordered,
+    op = generic_sum_ordered,
+    doc = "../export_docs/agg_horizontal_sum_ordered.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_slice_sum_impl!(
+    name = generic_avx512_sum_ordered,
+    op = generic_sum_ordered,
+    doc = "../export_docs/agg_horizontal_sum_ordered.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_slice_sum_impl!(
+    name = generic_neon_sum_ordered,
+    op = generic_sum_ordered,
+    doc = "../export_docs/agg_horizontal_sum_ordered.md",
+    Neon,
+    target_features = "neon"
+);
+
+define_slice_sum_impl!(
+    name = generic_fallback_sum_compensated,
+    op = generic_sum_compensated,
+    doc = "../export_docs/agg_horizontal_sum_compensated.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_slice_sum_impl!(
+    name = generic_avx2_sum_compensated,
+    op = generic_sum_compensated,
+    doc = "../export_docs/agg_horizontal_sum_compensated.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_slice_sum_impl!(
+    name = generic_avx512_sum_compensated,
+    op = generic_sum_compensated,
+    doc = "../export_docs/agg_horizontal_sum_compensated.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_slice_sum_impl!(
+    name = generic_neon_sum_compensated,
+    op = generic_sum_compensated,
+    doc = "../export_docs/agg_horizontal_sum_compensated.md",
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_sum_f64acc_impl {
+    (
+        name = $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/agg_horizontal_sum_f64acc.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[f32]) -> f64 {
+            generic_sum_f32_f64acc::<crate::danger::$imp>(a)
+        }
+    };
+}
+
+define_sum_f64acc_impl!(name = f32_fallback_sum_f64acc, Fallback,);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_sum_f64acc_impl!(name = f32_avx2_sum_f64acc, Avx2, target_features = "avx2");
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_sum_f64acc_impl!(
+    name = f32_avx512_sum_f64acc,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_sum_f64acc_impl!(name = f32_neon_sum_f64acc, Neon, target_features = "neon");
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,7 +245,7 @@ mod tests {
     );
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly",
+        feature = "stable-avx512",
         target_feature = "avx512f"
     ))]
     define_agg_test!(
@@ -4,13 +4,23 @@
 
 use crate::buffer::WriteOnlyBuffer;
 use crate::danger::{
+    generic_add_inplace,
     generic_add_vertical,
+    generic_add_vertical_nt,
+    generic_div_inplace,
     generic_div_vertical,
+    generic_mul_inplace,
     generic_mul_vertical,
+    generic_neg_vertical,
+    generic_outer_product,
+    generic_saturating_add_vertical,
+    generic_saturating_sub_vertical,
+    generic_sub_inplace,
     generic_sub_vertical,
     SimdRegister,
+    SimdSaturatingRegister,
 };
-use crate::math::{AutoMath, Math};
+use crate::math::{AutoMath, Math, SaturatingArithmetic};
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 
 macro_rules! define_arithmetic_impls {
@@ -160,7 +170,20 @@ define_arithmetic_impls!(
     Avx2,
     target_features = "avx2"
 );
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_arithmetic_impls!(
+    add = generic_avx2f16c_add_vertical,
+    sub = generic_avx2f16c_sub_vertical,
+    mul = generic_avx2f16c_mul_vertical,
+    div = generic_avx2f16c_div_vertical,
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 define_arithmetic_impls!(
     add = generic_avx512_add_vertical,
     sub = generic_avx512_sub_vertical,
@@ -180,6 +203,397 @@ define_arithmetic_impls!(
     target_features = "neon"
 );
 
+macro_rules! define_streaming_impl {
+    (
+        $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_add_vertical_nt.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2, B3>(
+            a: B1,
+            b: B2,
+            result: &mut [B3],
+        )
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_add_vertical_nt::<T, crate::danger::$imp, AutoMath, B1, B2, B3>(
+                a,
+                b,
+                result,
+            )
+        }
+    };
+}
+
+define_streaming_impl!(generic_fallback_add_vertical_nt, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_streaming_impl!(generic_avx2_add_vertical_nt, Avx2, target_features = "avx2");
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_streaming_impl!(
+    generic_avx512_add_vertical_nt,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_streaming_impl!(generic_neon_add_vertical_nt, Neon, target_features = "neon");
+
+macro_rules! define_inplace_impl {
+    (
+        $name:ident,
+        op = $op:ident,
+        doc = $doc:literal,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1>(a: &mut [T], b: B1)
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B1>(a, b)
+        }
+    };
+}
+
+macro_rules! define_inplace_impls {
+    (
+        add = $add_name:ident,
+        sub = $sub_name:ident,
+        mul = $mul_name:ident,
+        div = $div_name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        define_inplace_impl!(
+            $add_name,
+            op = generic_add_inplace,
+            doc = "../export_docs/arithmetic_add_inplace.md",
+            $imp,
+            $(target_features = $($feat,)*)*
+        );
+        define_inplace_impl!(
+            $sub_name,
+            op = generic_sub_inplace,
+            doc = "../export_docs/arithmetic_sub_inplace.md",
+            $imp,
+            $(target_features = $($feat,)*)*
+        );
+        define_inplace_impl!(
+            $mul_name,
+            op = generic_mul_inplace,
+            doc = "../export_docs/arithmetic_mul_inplace.md",
+            $imp,
+            $(target_features = $($feat,)*)*
+        );
+        define_inplace_impl!(
+            $div_name,
+            op = generic_div_inplace,
+            doc = "../export_docs/arithmetic_div_inplace.md",
+            $imp,
+            $(target_features = $($feat,)*)*
+        );
+    };
+}
+
+define_inplace_impls!(
+    add = generic_fallback_add_inplace,
+    sub = generic_fallback_sub_inplace,
+    mul = generic_fallback_mul_inplace,
+    div = generic_fallback_div_inplace,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_inplace_impls!(
+    add = generic_avx2_add_inplace,
+    sub = generic_avx2_sub_inplace,
+    mul = generic_avx2_mul_inplace,
+    div = generic_avx2_div_inplace,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_inplace_impls!(
+    add = generic_avx2f16c_add_inplace,
+    sub = generic_avx2f16c_sub_inplace,
+    mul = generic_avx2f16c_mul_inplace,
+    div = generic_avx2f16c_div_inplace,
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_inplace_impls!(
+    add = generic_avx512_add_inplace,
+    sub = generic_avx512_sub_inplace,
+    mul = generic_avx512_mul_inplace,
+    div = generic_avx512_div_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_inplace_impls!(
+    add = generic_neon_add_inplace,
+    sub = generic_neon_sub_inplace,
+    mul = generic_neon_mul_inplace,
+    div = generic_neon_div_inplace,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_outer_product_impl {
+    (
+        name = $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_outer_product.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(
+            n: usize,
+            m: usize,
+            a: &[T],
+            b: &[T],
+            result: &mut [T],
+        )
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_outer_product::<T, crate::danger::$imp, AutoMath>(n, m, a, b, result)
+        }
+    };
+}
+
+define_outer_product_impl!(name = generic_fallback_outer_product, Fallback,);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_outer_product_impl!(
+    name = generic_avx2_outer_product,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_outer_product_impl!(
+    name = generic_avx2f16c_outer_product,
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_outer_product_impl!(
+    name = generic_avx512_outer_product,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_outer_product_impl!(
+    name = generic_neon_outer_product,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_neg_impl {
+    (
+        name = $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/arithmetic_neg_vertical.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(
+            a: B1,
+            result: &mut [B2],
+        )
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_neg_vertical::<T, crate::danger::$imp, AutoMath, B1, B2>(a, result)
+        }
+    };
+}
+
+define_neg_impl!(name = generic_fallback_neg_vertical, Fallback,);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_neg_impl!(
+    name = generic_avx2_neg_vertical,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_neg_impl!(
+    name = generic_avx2f16c_neg_vertical,
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_neg_impl!(
+    name = generic_avx512_neg_vertical,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_neg_impl!(
+    name = generic_neon_neg_vertical,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_saturating_impl {
+    (
+        $name:ident,
+        op = $op:ident,
+        doc = $doc:literal,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2, B3>(
+            a: B1,
+            b: B2,
+            result: &mut [B3],
+        )
+        where
+            T: SaturatingArithmetic,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdSaturatingRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B1, B2, B3>(
+                a,
+                b,
+                result,
+            )
+        }
+    };
+}
+
+macro_rules! define_saturating_impls {
+    (
+        add = $add_name:ident,
+        sub = $sub_name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        define_saturating_impl!(
+            $add_name,
+            op = generic_saturating_add_vertical,
+            doc = "../export_docs/arithmetic_saturating_add_vertical.md",
+            $imp,
+            $(target_features = $($feat,)*)*
+        );
+        define_saturating_impl!(
+            $sub_name,
+            op = generic_saturating_sub_vertical,
+            doc = "../export_docs/arithmetic_saturating_sub_vertical.md",
+            $imp,
+            $(target_features = $($feat,)*)*
+        );
+    };
+}
+
+define_saturating_impls!(
+    add = generic_fallback_saturating_add_vertical,
+    sub = generic_fallback_saturating_sub_vertical,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_saturating_impls!(
+    add = generic_avx2_saturating_add_vertical,
+    sub = generic_avx2_saturating_sub_vertical,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_saturating_impls!(
+    add = generic_avx512_saturating_add_vertical,
+    sub = generic_avx512_saturating_sub_vertical,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_saturating_impls!(
+    add = generic_neon_saturating_add_vertical,
+    sub = generic_neon_saturating_sub_vertical,
+    Neon,
+    target_features = "neon"
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -205,6 +619,54 @@ mod tests {
                     );
                 }
 
+                #[test]
+                fn [< $variant _ $op _value_lhs_ $t >]() {
+                    let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let mut result = vec![$t::default(); 533];
+                    unsafe { [< $variant _ $op _vertical >](2 as $t, &l1, &mut result) };
+
+                    let expected = l1.iter()
+                        .copied()
+                        .map(|v| AutoMath::$op(2 as $t, v))
+                        .collect::<Vec<_>>();
+                    assert_eq!(
+                        result,
+                        expected,
+                        "Routine result does not match expected",
+                    );
+                }
+
+                #[test]
+                fn [< $variant _ $op _inplace_ $t >]() {
+                    let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let mut result = l1.clone();
+                    unsafe { [< $variant _ $op _inplace >](&mut result, &l2) };
+                    let expected = l1.iter()
+                        .copied()
+                        .zip(l2.iter().copied())
+                        .map(|(a, b)| AutoMath::$op(a, b))
+                        .collect::<Vec<_>>();
+                    assert_eq!(
+                        result,
+                        expected,
+                        "Routine result does not match expected",
+                    );
+
+                    let mut result = l1.clone();
+                    unsafe { [< $variant _ $op _inplace >](&mut result, 2 as $t) };
+                    let expected = l1.iter()
+                        .copied()
+                        .map(|v| AutoMath::$op(v, 2 as $t))
+                        .collect::<Vec<_>>();
+                    assert_eq!(
+                        result,
+                        expected,
+                        "Routine result does not match expected",
+                    );
+                }
+
                 #[test]
                 fn [< $variant _ $op _vector_ $t >]() {
                     let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(533);
@@ -227,6 +689,127 @@ mod tests {
         };
     }
 
+    macro_rules! define_streaming_test {
+        ($variant:ident, ty = $t:ident) => {
+            paste::paste! {
+                #[test]
+                fn [< $variant _add_nt_ $t >]() {
+                    let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    // Offsetting the output covers both the regular store prologue
+                    // and destinations which are already aligned.
+                    for offset in 0..9 {
+                        for len in [0, 1, 7, 64, 533 - offset] {
+                            let mut buffer = vec![$t::default(); 533];
+                            let result = &mut buffer[offset..][..len];
+                            unsafe {
+                                [< $variant _add_vertical_nt >](
+                                    &l1[..len],
+                                    &l2[..len],
+                                    result,
+                                )
+                            };
+
+                            let expected = l1[..len].iter()
+                                .copied()
+                                .zip(l2.iter().copied())
+                                .map(|(a, b)| AutoMath::add(a, b))
+                                .collect::<Vec<_>>();
+                            assert_eq!(
+                                &buffer[offset..][..len],
+                                expected,
+                                "Routine result does not match expected",
+                            );
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! define_outer_product_test {
+        ($variant:ident, ty = $t:ident) => {
+            paste::paste! {
+                #[test]
+                fn [< $variant _outer_product_ $t >]() {
+                    let (a, _) = crate::test_utils::get_sample_vectors::<$t>(37);
+                    let (_, b) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let mut result = vec![$t::default(); 37 * 533];
+                    unsafe { [< $variant _outer_product >](37, 533, &a, &b, &mut result) };
+
+                    let expected = a.iter()
+                        .flat_map(|x| b.iter().map(|y| AutoMath::mul(*x, *y)))
+                        .collect::<Vec<_>>();
+                    assert_eq!(
+                        result,
+                        expected,
+                        "Routine result does not match expected",
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! define_neg_test {
+        ($variant:ident, ty = $t:ident) => {
+            paste::paste! {
+                #[test]
+                fn [< $variant _neg_vector_ $t >]() {
+                    let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let mut result = vec![$t::default(); 533];
+                    unsafe { [< $variant _neg_vertical >](&l1, &mut result) };
+
+                    let expected = l1.iter()
+                        .copied()
+                        .map(AutoMath::neg)
+                        .collect::<Vec<_>>();
+                    assert_eq!(
+                        result,
+                        expected,
+                        "Routine result does not match expected",
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! define_saturating_test {
+        ($variant:ident, types = $($t:ident $(,)?)+) => {
+            $(
+                paste::paste! {
+                    #[test]
+                    fn [< $variant _saturating_ $t >]() {
+                        let extremes = [$t::MIN, $t::MAX, 0 as $t, 1 as $t, $t::MAX / 2];
+                        let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+                        let l2 = l1
+                            .iter()
+                            .enumerate()
+                            .map(|(i, _)| extremes[i % extremes.len()])
+                            .collect::<Vec<_>>();
+
+                        let mut result = vec![$t::default(); 533];
+                        unsafe { [< $variant _saturating_add_vertical >](&l1, &l2, &mut result) };
+                        let expected = l1.iter()
+                            .zip(&l2)
+                            .map(|(a, b)| a.saturating_add(*b))
+                            .collect::<Vec<_>>();
+                        assert_eq!(result, expected, "Routine result does not match expected");
+
+                        let mut result = vec![$t::default(); 533];
+                        unsafe { [< $variant _saturating_sub_vertical >](&l1, &l2, &mut result) };
+                        let expected = l1.iter()
+                            .zip(&l2)
+                            .map(|(a, b)| a.saturating_sub(*b))
+                            .collect::<Vec<_>>();
+                        assert_eq!(result, expected, "Routine result does not match expected");
+                    }
+                }
+            )*
+        };
+    }
+
     macro_rules! define_arithmetic_test {
         ($variant:ident, types = $($t:ident $(,)?)+) => {
             $(
@@ -234,6 +817,9 @@ mod tests {
                 define_inner_test!($variant, op = sub, ty = $t);
                 define_inner_test!($variant, op = mul, ty = $t);
                 define_inner_test!($variant, op = div, ty = $t);
+                define_outer_product_test!($variant, ty = $t);
+                define_streaming_test!($variant, ty = $t);
+                define_neg_test!($variant, ty = $t);
             )*
         };
     }
@@ -270,7 +856,7 @@ mod tests {
     );
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly",
+        feature = "stable-avx512",
         target_feature = "avx512f"
     ))]
     define_arithmetic_test!(
@@ -300,4 +886,38 @@ mod tests {
         u32,
         u64
     );
+    define_saturating_test!(
+        generic_fallback,
+        types = i8,
+        i16,
+        i32,
+        i64,
+        u8,
+        u16,
+        u32,
+        u64
+    );
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    define_saturating_test!(generic_avx2, types = i8, i16, i32, i64, u8, u16, u32, u64);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512f"
+    ))]
+    define_saturating_test!(
+        generic_avx512,
+        types = i8,
+        i16,
+        i32,
+        i64,
+        u8,
+        u16,
+        u32,
+        u64
+    );
+    #[cfg(target_arch = "aarch64")]
+    define_saturating_test!(generic_neon, types = i8, i16, i32, i64, u8, u16, u32, u64);
 }
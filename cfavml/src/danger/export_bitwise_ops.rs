@@ -0,0 +1,332 @@
+//! Common bitwise operations
+//!
+//! I.e. And, Or, Xor, Not, Shifts...
+
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::{
+    generic_bitand_vertical,
+    generic_bitnot_vertical,
+    generic_bitor_vertical,
+    generic_bitxor_vertical,
+    generic_shl_vertical,
+    generic_shr_vertical,
+    SimdBitwiseRegister,
+};
+use crate::math::{AutoMath, Math, WrappingShift};
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+macro_rules! define_bitwise_binary_impl {
+    (
+        $name:ident,
+        op = $op:ident,
+        bound = [$($bound:tt)+],
+        doc = $doc:literal,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2, B3>(
+            a: B1,
+            b: B2,
+            result: &mut [B3],
+        )
+        where
+            T: $($bound)+,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdBitwiseRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B1, B2, B3>(
+                a,
+                b,
+                result,
+            )
+        }
+    };
+}
+
+macro_rules! define_bitwise_impls {
+    (
+        and = $and_name:ident,
+        or = $or_name:ident,
+        xor = $xor_name:ident,
+        not = $not_name:ident,
+        shl = $shl_name:ident,
+        shr = $shr_name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        define_bitwise_binary_impl!(
+            $and_name,
+            op = generic_bitand_vertical,
+            bound = [Copy + BitAnd<Output = T>],
+            doc = "../export_docs/bitwise_and_vertical.md",
+            $imp,
+            $(target_features = $($feat,)*)*
+        );
+        define_bitwise_binary_impl!(
+            $or_name,
+            op = generic_bitor_vertical,
+            bound = [Copy + BitOr<Output = T>],
+            doc = "../export_docs/bitwise_or_vertical.md",
+            $imp,
+            $(target_features = $($feat,)*)*
+        );
+        define_bitwise_binary_impl!(
+            $xor_name,
+            op = generic_bitxor_vertical,
+            bound = [Copy + BitXor<Output = T>],
+            doc = "../export_docs/bitwise_xor_vertical.md",
+            $imp,
+            $(target_features = $($feat,)*)*
+        );
+        define_bitwise_binary_impl!(
+            $shl_name,
+            op = generic_shl_vertical,
+            bound = [WrappingShift],
+            doc = "../export_docs/bitwise_shl_vertical.md",
+            $imp,
+            $(target_features = $($feat,)*)*
+        );
+        define_bitwise_binary_impl!(
+            $shr_name,
+            op = generic_shr_vertical,
+            bound = [WrappingShift],
+            doc = "../export_docs/bitwise_shr_vertical.md",
+            $imp,
+            $(target_features = $($feat,)*)*
+        );
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/bitwise_not_vertical.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $not_name<T, B1, B2>(
+            a: B1,
+            result: &mut [B2],
+        )
+        where
+            T: Copy + Not<Output = T>,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdBitwiseRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_bitnot_vertical::<T, crate::danger::$imp, AutoMath, B1, B2>(
+                a,
+                result,
+            )
+        }
+    };
+}
+
+define_bitwise_impls!(
+    and = generic_fallback_bitand_vertical,
+    or = generic_fallback_bitor_vertical,
+    xor = generic_fallback_bitxor_vertical,
+    not = generic_fallback_bitnot_vertical,
+    shl = generic_fallback_shl_vertical,
+    shr = generic_fallback_shr_vertical,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_bitwise_impls!(
+    and = generic_avx2_bitand_vertical,
+    or = generic_avx2_bitor_vertical,
+    xor = generic_avx2_bitxor_vertical,
+    not = generic_avx2_bitnot_vertical,
+    shl = generic_avx2_shl_vertical,
+    shr = generic_avx2_shr_vertical,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_bitwise_impls!(
+    and = generic_avx512_bitand_vertical,
+    or = generic_avx512_bitor_vertical,
+    xor = generic_avx512_bitxor_vertical,
+    not = generic_avx512_bitnot_vertical,
+    shl = generic_avx512_shl_vertical,
+    shr = generic_avx512_shr_vertical,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_bitwise_impls!(
+    and = generic_neon_bitand_vertical,
+    or = generic_neon_bitor_vertical,
+    xor = generic_neon_bitxor_vertical,
+    not = generic_neon_bitnot_vertical,
+    shl = generic_neon_shl_vertical,
+    shr = generic_neon_shr_vertical,
+    Neon,
+    target_features = "neon"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! define_inner_test {
+        ($variant:ident, op = $op:ident, sym = $sym:tt, ty = $t:ident) => {
+            paste::paste! {
+                #[test]
+                fn [< $variant _ $op _value_ $t >]() {
+                    let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let mut result = vec![$t::default(); 533];
+                    unsafe { [< $variant _ $op _vertical >](&l1, 0b1010 as $t, &mut result) };
+
+                    let expected = l1.iter()
+                        .copied()
+                        .map(|v| v $sym (0b1010 as $t))
+                        .collect::<Vec<_>>();
+                    assert_eq!(
+                        result,
+                        expected,
+                        "Routine result does not match expected",
+                    );
+                }
+
+                #[test]
+                fn [< $variant _ $op _vector_ $t >]() {
+                    let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let mut result = vec![$t::default(); 533];
+                    unsafe { [< $variant _ $op _vertical >](&l1, &l2, &mut result) };
+
+                    let expected = l1.iter()
+                        .copied()
+                        .zip(l2.iter().copied())
+                        .map(|(a, b)| a $sym b)
+                        .collect::<Vec<_>>();
+                    assert_eq!(
+                        result,
+                        expected,
+                        "Routine result does not match expected",
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! define_bitwise_test {
+        ($variant:ident, types = $($t:ident $(,)?)+) => {
+            $(
+                define_inner_test!($variant, op = bitand, sym = &, ty = $t);
+                define_inner_test!($variant, op = bitor, sym = |, ty = $t);
+                define_inner_test!($variant, op = bitxor, sym = ^, ty = $t);
+
+                paste::paste! {
+                    #[test]
+                    fn [< $variant _shift_value_ $t >]() {
+                        let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                        for amount in [3 as $t, $t::BITS as $t, ($t::BITS + 3) as $t] {
+                            let mut result = vec![$t::default(); 533];
+                            unsafe { [< $variant _shl_vertical >](&l1, amount, &mut result) };
+                            let expected = l1.iter()
+                                .map(|v| v.wrapping_shl(amount as u32))
+                                .collect::<Vec<_>>();
+                            assert_eq!(result, expected, "Routine result does not match expected");
+
+                            let mut result = vec![$t::default(); 533];
+                            unsafe { [< $variant _shr_vertical >](&l1, amount, &mut result) };
+                            let expected = l1.iter()
+                                .map(|v| v.wrapping_shr(amount as u32))
+                                .collect::<Vec<_>>();
+                            assert_eq!(result, expected, "Routine result does not match expected");
+                        }
+                    }
+
+                    #[test]
+                    fn [< $variant _shift_vector_ $t >]() {
+                        let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                        let mut result = vec![$t::default(); 533];
+                        unsafe { [< $variant _shl_vertical >](&l1, &l2, &mut result) };
+                        let expected = l1.iter()
+                            .zip(l2.iter())
+                            .map(|(a, b)| a.wrapping_shl(*b as u32))
+                            .collect::<Vec<_>>();
+                        assert_eq!(result, expected, "Routine result does not match expected");
+
+                        let mut result = vec![$t::default(); 533];
+                        unsafe { [< $variant _shr_vertical >](&l1, &l2, &mut result) };
+                        let expected = l1.iter()
+                            .zip(l2.iter())
+                            .map(|(a, b)| a.wrapping_shr(*b as u32))
+                            .collect::<Vec<_>>();
+                        assert_eq!(result, expected, "Routine result does not match expected");
+                    }
+                }
+
+                paste::paste! {
+                    #[test]
+                    fn [< $variant _bitnot_vector_ $t >]() {
+                        let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                        let mut result = vec![$t::default(); 533];
+                        unsafe { [< $variant _bitnot_vertical >](&l1, &mut result) };
+
+                        let expected = l1.iter()
+                            .map(|v| !v)
+                            .collect::<Vec<_>>();
+                        assert_eq!(
+                            result,
+                            expected,
+                            "Routine result does not match expected",
+                        );
+                    }
+                }
+            )*
+        };
+    }
+
+    define_bitwise_test!(
+        generic_fallback,
+        types = i8,
+        i16,
+        i32,
+        i64,
+        u8,
+        u16,
+        u32,
+        u64
+    );
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    define_bitwise_test!(generic_avx2, types = i8, i16, i32, i64, u8, u16, u32, u64);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512f"
+    ))]
+    define_bitwise_test!(generic_avx512, types = i8, i16, i32, i64, u8, u16, u32, u64);
+    #[cfg(target_arch = "aarch64")]
+    define_bitwise_test!(generic_neon, types = i8, i16, i32, i64, u8, u16, u32, u64);
+}
@@ -0,0 +1,239 @@
+//! Type conversion operations
+//!
+//! These operations convert vectors between the primitive types with the same
+//! semantics as a Rust `as` cast.
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::{generic_cast_vector, SimdCastRegister};
+use crate::math::CastAs;
+
+macro_rules! define_cast_impl {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<F, T, B>(a: &[F], result: &mut [B])
+        where
+            F: CastAs<T>,
+            T: Copy,
+            crate::danger::$imp: SimdCastRegister<F, T>,
+            for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+        {
+            $op::<F, T, crate::danger::$imp, B>(a, result)
+        }
+    };
+}
+
+define_cast_impl!(
+    name = generic_fallback_cast_vector,
+    op = generic_cast_vector,
+    doc = "../export_docs/cast_vector.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_cast_impl!(
+    name = generic_avx2_cast_vector,
+    op = generic_cast_vector,
+    doc = "../export_docs/cast_vector.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_cast_impl!(
+    name = generic_avx512_cast_vector,
+    op = generic_cast_vector,
+    doc = "../export_docs/cast_vector.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_cast_impl!(
+    name = generic_neon_cast_vector,
+    op = generic_cast_vector,
+    doc = "../export_docs/cast_vector.md",
+    Neon,
+    target_features = "neon"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! define_cast_test {
+        ($variant:ident, pairs = $(($from:ident => $to:ident) $(,)?)+) => {
+            $(
+                paste::paste! {
+                    #[test]
+                    fn [< $variant _cast_vector_ $from _to_ $to >]() {
+                        let (l1, _) = crate::test_utils::get_sample_vectors::<$from>(533);
+
+                        let mut result = vec![$to::default(); 533];
+                        unsafe { [< $variant _cast_vector >](&l1, &mut result) };
+
+                        let expected = l1.iter().map(|v| *v as $to).collect::<Vec<$to>>();
+                        assert_eq!(result, expected, "Routine result does not match expected");
+                    }
+                }
+            )*
+
+            paste::paste! {
+                #[test]
+                fn [< $variant _cast_vector_f32_to_i32_special_values >]() {
+                    let mut l1 = vec![
+                        f32::NAN,
+                        -f32::NAN,
+                        f32::INFINITY,
+                        f32::NEG_INFINITY,
+                        f32::MAX,
+                        f32::MIN,
+                        2147483648.0,
+                        -2147483648.0,
+                        2147483520.0,
+                        -2147483904.0,
+                        -0.0,
+                        0.0,
+                        1.9,
+                        -1.9,
+                        0.5,
+                        -0.5,
+                    ];
+                    l1.extend((0..517).map(|i| (i as f32 - 258.0) * 12345.678));
+
+                    let mut result = vec![0i32; l1.len()];
+                    unsafe { [< $variant _cast_vector >](&l1, &mut result) };
+
+                    let expected = l1.iter().map(|v| *v as i32).collect::<Vec<i32>>();
+                    assert_eq!(result, expected, "Routine result does not match expected");
+                }
+
+                #[test]
+                fn [< $variant _cast_vector_f64_to_f32_special_values >]() {
+                    let mut l1 = vec![
+                        f64::INFINITY,
+                        f64::NEG_INFINITY,
+                        f64::MAX,
+                        f64::MIN,
+                        f64::MIN_POSITIVE,
+                        1e-300,
+                        -0.0,
+                        0.1,
+                    ];
+                    l1.extend((0..525).map(|i| (i as f64 - 262.0) * 1.000000123456789));
+
+                    let mut result = vec![0f32; l1.len()];
+                    unsafe { [< $variant _cast_vector >](&l1, &mut result) };
+
+                    let expected = l1.iter().map(|v| *v as f32).collect::<Vec<f32>>();
+                    assert_eq!(result, expected, "Routine result does not match expected");
+
+                    let mut result = vec![0f32; 3];
+                    unsafe { [< $variant _cast_vector >](&[f64::NAN; 3], &mut result) };
+                    assert!(result.iter().all(|v| v.is_nan()), "NaN values must be preserved");
+                }
+
+                #[test]
+                fn [< $variant _cast_vector_int_to_f32_bounds >]() {
+                    let l1 = [i32::MIN, i32::MAX, i32::MAX - 63, 16777217, -16777217, 0, -1];
+                    let mut result = vec![0f32; l1.len()];
+                    unsafe { [< $variant _cast_vector >](&l1, &mut result) };
+                    let expected = l1.iter().map(|v| *v as f32).collect::<Vec<f32>>();
+                    assert_eq!(result, expected, "Routine result does not match expected");
+
+                    let l1 = (0..=255u8).collect::<Vec<u8>>();
+                    let mut result = vec![0f32; l1.len()];
+                    unsafe { [< $variant _cast_vector >](&l1, &mut result) };
+                    let expected = l1.iter().map(|v| *v as f32).collect::<Vec<f32>>();
+                    assert_eq!(result, expected, "Routine result does not match expected");
+
+                    let l1 = (-128..=127i8).collect::<Vec<i8>>();
+                    let mut result = vec![0f32; l1.len()];
+                    unsafe { [< $variant _cast_vector >](&l1, &mut result) };
+                    let expected = l1.iter().map(|v| *v as f32).collect::<Vec<f32>>();
+                    assert_eq!(result, expected, "Routine result does not match expected");
+
+                    let l1 = [i16::MIN, i16::MAX, -1, 0, 1, 1234, -4321, 99, -99];
+                    let mut result = vec![0f32; l1.len()];
+                    unsafe { [< $variant _cast_vector >](&l1, &mut result) };
+                    let expected = l1.iter().map(|v| *v as f32).collect::<Vec<f32>>();
+                    assert_eq!(result, expected, "Routine result does not match expected");
+
+                    let l1 = [u16::MIN, u16::MAX, 1, 32768, 32767, 4321, 99, 7, 8];
+                    let mut result = vec![0f32; l1.len()];
+                    unsafe { [< $variant _cast_vector >](&l1, &mut result) };
+                    let expected = l1.iter().map(|v| *v as f32).collect::<Vec<f32>>();
+                    assert_eq!(result, expected, "Routine result does not match expected");
+                }
+            }
+        };
+    }
+
+    define_cast_test!(
+        generic_fallback,
+        pairs = (f32 => f64),
+        (f64 => f32),
+        (f32 => i32),
+        (i32 => f32),
+        (i8 => f32),
+        (u8 => f32),
+        (i16 => f32),
+        (u16 => f32),
+    );
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    define_cast_test!(
+        generic_avx2,
+        pairs = (f32 => f64),
+        (f64 => f32),
+        (f32 => i32),
+        (i32 => f32),
+        (i8 => f32),
+        (u8 => f32),
+        (i16 => f32),
+        (u16 => f32),
+    );
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512f"
+    ))]
+    define_cast_test!(
+        generic_avx512,
+        pairs = (f32 => f64),
+        (f64 => f32),
+        (f32 => i32),
+        (i32 => f32),
+        (i8 => f32),
+        (u8 => f32),
+        (i16 => f32),
+        (u16 => f32),
+    );
+    #[cfg(target_arch = "aarch64")]
+    define_cast_test!(
+        generic_neon,
+        pairs = (f32 => f64),
+        (f64 => f32),
+        (f32 => i32),
+        (i32 => f32),
+        (i8 => f32),
+        (u8 => f32),
+        (i16 => f32),
+        (u16 => f32),
+    );
+}
@@ -5,19 +5,53 @@
 
 use crate::buffer::WriteOnlyBuffer;
 use crate::danger::{
+    generic_all_eq_value,
+    generic_all_gt_value,
+    generic_all_gte_value,
+    generic_all_lt_value,
+    generic_all_lte_value,
+    generic_all_neq_value,
+    generic_any_eq_value,
+    generic_any_gt_value,
+    generic_any_gte_value,
+    generic_any_lt_value,
+    generic_any_lte_value,
+    generic_any_neq_value,
+    generic_cmp_eq_bitmask,
+    generic_cmp_eq_value,
     generic_cmp_eq_vertical,
+    generic_cmp_gt_bitmask,
+    generic_cmp_gt_value,
     generic_cmp_gt_vertical,
+    generic_cmp_gte_bitmask,
+    generic_cmp_gte_value,
     generic_cmp_gte_vertical,
+    generic_cmp_lt_bitmask,
+    generic_cmp_lt_value,
     generic_cmp_lt_vertical,
+    generic_cmp_lte_bitmask,
+    generic_cmp_lte_value,
     generic_cmp_lte_vertical,
     generic_cmp_max,
     generic_cmp_max_vertical,
     generic_cmp_min,
     generic_cmp_min_vertical,
+    generic_cmp_neq_bitmask,
+    generic_cmp_neq_value,
     generic_cmp_neq_vertical,
+    generic_count_eq_value,
+    generic_count_gt_value,
+    generic_count_gte_value,
+    generic_count_lt_value,
+    generic_count_lte_value,
+    generic_count_neq_value,
+    generic_count_nonzero,
+    generic_cumulative_max,
+    generic_cumulative_min,
+    generic_select_vertical,
     SimdRegister,
 };
-use crate::math::{AutoMath, Math};
+use crate::math::{AutoMath, MaskCount, Math};
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 
 macro_rules! define_op {
@@ -60,6 +94,201 @@ macro_rules! define_op {
     };
 }
 
+macro_rules! define_value_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(
+            a: B1,
+            value: T,
+            result: &mut [B2],
+        )
+        where
+            T: Copy + IntoMemLoader<T>,
+            T::Loader: MemLoader<Value = T>,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B1, B2>(
+                a,
+                value,
+                result,
+            )
+        }
+    };
+}
+
+macro_rules! define_count_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1>(a: B1, value: T) -> usize
+        where
+            T: MaskCount,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B1>(a, value)
+        }
+    };
+}
+
+macro_rules! define_count_nonzero_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1>(a: B1) -> usize
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B1>(a)
+        }
+    };
+}
+
+macro_rules! define_any_all_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1>(a: B1, value: T) -> bool
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B1>(a, value)
+        }
+    };
+}
+
+macro_rules! define_bitmask_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(dims: usize, a: &[T], b: &[T], result: &mut [u8])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath>(dims, a, b, result)
+        }
+    };
+}
+
+macro_rules! define_select_op {
+    (
+        name = $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/cmp_select_vertical.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2, B3, B4>(
+            mask: B1,
+            a: B2,
+            b: B3,
+            result: &mut [B4],
+        )
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            B3: IntoMemLoader<T>,
+            B3::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B4]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_select_vertical::<T, crate::danger::$imp, AutoMath, B1, B2, B3, B4>(
+                mask,
+                a,
+                b,
+                result,
+            )
+        }
+    };
+}
+
 macro_rules! define_extra_horizontal_op {
     (
         horizontal_name = $horizontal_name:ident,
@@ -91,6 +320,38 @@ macro_rules! define_extra_horizontal_op {
     };
 }
 
+macro_rules! define_cumulative_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B>(
+            dims: usize,
+            a: &[T],
+            result: &mut [B],
+        )
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B>(dims, a, result)
+        }
+    };
+}
+
 // OP-max
 define_op!(
     name = generic_fallback_cmp_max_vertical,
@@ -106,7 +367,10 @@ define_op!(
     Avx2,
     target_features = "avx2"
 );
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 define_op!(
     name = generic_avx512_cmp_max_vertical,
     op = generic_cmp_max_vertical,
@@ -139,7 +403,10 @@ define_extra_horizontal_op!(
     Avx2,
     target_features = "avx2"
 );
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 define_extra_horizontal_op!(
     horizontal_name = generic_avx512_cmp_max,
     horizontal_op = generic_cmp_max,
@@ -172,7 +439,10 @@ define_op!(
     Avx2,
     target_features = "avx2"
 );
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 define_op!(
     name = generic_avx512_cmp_min_vertical,
     op = generic_cmp_min_vertical,
@@ -205,7 +475,10 @@ define_extra_horizontal_op!(
     Avx2,
     target_features = "avx2"
 );
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 define_extra_horizontal_op!(
     horizontal_name = generic_avx512_cmp_min,
     horizontal_op = generic_cmp_min,
@@ -223,6 +496,78 @@ define_extra_horizontal_op!(
     target_features = "neon"
 );
 
+// OP-cumulative-max
+define_cumulative_op!(
+    name = generic_fallback_cumulative_max,
+    op = generic_cumulative_max,
+    doc = "../export_docs/cmp_cumulative_max.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_cumulative_op!(
+    name = generic_avx2_cumulative_max,
+    op = generic_cumulative_max,
+    doc = "../export_docs/cmp_cumulative_max.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_cumulative_op!(
+    name = generic_avx512_cumulative_max,
+    op = generic_cumulative_max,
+    doc = "../export_docs/cmp_cumulative_max.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_cumulative_op!(
+    name = generic_neon_cumulative_max,
+    op = generic_cumulative_max,
+    doc = "../export_docs/cmp_cumulative_max.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-cumulative-min
+define_cumulative_op!(
+    name = generic_fallback_cumulative_min,
+    op = generic_cumulative_min,
+    doc = "../export_docs/cmp_cumulative_min.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_cumulative_op!(
+    name = generic_avx2_cumulative_min,
+    op = generic_cumulative_min,
+    doc = "../export_docs/cmp_cumulative_min.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_cumulative_op!(
+    name = generic_avx512_cumulative_min,
+    op = generic_cumulative_min,
+    doc = "../export_docs/cmp_cumulative_min.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_cumulative_op!(
+    name = generic_neon_cumulative_min,
+    op = generic_cumulative_min,
+    doc = "../export_docs/cmp_cumulative_min.md",
+    Neon,
+    target_features = "neon"
+);
+
 // OP-eq
 define_op!(
     name = generic_fallback_cmp_eq_vertical,
@@ -238,7 +583,10 @@ define_op!(
     Avx2,
     target_features = "avx2"
 );
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 define_op!(
     name = generic_avx512_cmp_eq_vertical,
     op = generic_cmp_eq_vertical,
@@ -271,7 +619,10 @@ define_op!(
     Avx2,
     target_features = "avx2"
 );
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 define_op!(
     name = generic_avx512_cmp_neq_vertical,
     op = generic_cmp_neq_vertical,
@@ -304,7 +655,10 @@ define_op!(
     Avx2,
     target_features = "avx2"
 );
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 define_op!(
     name = generic_avx512_cmp_lt_vertical,
     op = generic_cmp_lt_vertical,
@@ -337,7 +691,10 @@ define_op!(
     Avx2,
     target_features = "avx2"
 );
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 define_op!(
     name = generic_avx512_cmp_lte_vertical,
     op = generic_cmp_lte_vertical,
@@ -370,7 +727,10 @@ define_op!(
     Avx2,
     target_features = "avx2"
 );
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 define_op!(
     name = generic_avx512_cmp_gt_vertical,
     op = generic_cmp_gt_vertical,
@@ -403,7 +763,10 @@ define_op!(
     Avx2,
     target_features = "avx2"
 );
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 define_op!(
     name = generic_avx512_cmp_gte_vertical,
     op = generic_cmp_gte_vertical,
@@ -421,20 +784,1161 @@ define_op!(
     target_features = "neon"
 );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    macro_rules! define_inner_test {
-        ($variant:ident, op = $op:ident, ty = $t:ident, fold_on = $fold_cb:ident) => {
-            paste::paste! {
-                #[test]
-                fn [< $variant _ $op _horizontal_ $t >]() {
-                    let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+// OP-eq value
+define_value_op!(
+    name = generic_fallback_cmp_eq_value,
+    op = generic_cmp_eq_value,
+    doc = "../export_docs/cmp_eq_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_value_op!(
+    name = generic_avx2_cmp_eq_value,
+    op = generic_cmp_eq_value,
+    doc = "../export_docs/cmp_eq_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_value_op!(
+    name = generic_avx512_cmp_eq_value,
+    op = generic_cmp_eq_value,
+    doc = "../export_docs/cmp_eq_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_value_op!(
+    name = generic_neon_cmp_eq_value,
+    op = generic_cmp_eq_value,
+    doc = "../export_docs/cmp_eq_value.md",
+    Neon,
+    target_features = "neon"
+);
 
-                    let result = unsafe { [< $variant _cmp_ $op >](&l1) };
+// OP-neq value
+define_value_op!(
+    name = generic_fallback_cmp_neq_value,
+    op = generic_cmp_neq_value,
+    doc = "../export_docs/cmp_neq_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_value_op!(
+    name = generic_avx2_cmp_neq_value,
+    op = generic_cmp_neq_value,
+    doc = "../export_docs/cmp_neq_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_value_op!(
+    name = generic_avx512_cmp_neq_value,
+    op = generic_cmp_neq_value,
+    doc = "../export_docs/cmp_neq_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_value_op!(
+    name = generic_neon_cmp_neq_value,
+    op = generic_cmp_neq_value,
+    doc = "../export_docs/cmp_neq_value.md",
+    Neon,
+    target_features = "neon"
+);
 
-                    let expected = l1.iter()
+// OP-lt value
+define_value_op!(
+    name = generic_fallback_cmp_lt_value,
+    op = generic_cmp_lt_value,
+    doc = "../export_docs/cmp_lt_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_value_op!(
+    name = generic_avx2_cmp_lt_value,
+    op = generic_cmp_lt_value,
+    doc = "../export_docs/cmp_lt_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_value_op!(
+    name = generic_avx512_cmp_lt_value,
+    op = generic_cmp_lt_value,
+    doc = "../export_docs/cmp_lt_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_value_op!(
+    name = generic_neon_cmp_lt_value,
+    op = generic_cmp_lt_value,
+    doc = "../export_docs/cmp_lt_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-lte value
+define_value_op!(
+    name = generic_fallback_cmp_lte_value,
+    op = generic_cmp_lte_value,
+    doc = "../export_docs/cmp_lte_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_value_op!(
+    name = generic_avx2_cmp_lte_value,
+    op = generic_cmp_lte_value,
+    doc = "../export_docs/cmp_lte_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_value_op!(
+    name = generic_avx512_cmp_lte_value,
+    op = generic_cmp_lte_value,
+    doc = "../export_docs/cmp_lte_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_value_op!(
+    name = generic_neon_cmp_lte_value,
+    op = generic_cmp_lte_value,
+    doc = "../export_docs/cmp_lte_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-gt value
+define_value_op!(
+    name = generic_fallback_cmp_gt_value,
+    op = generic_cmp_gt_value,
+    doc = "../export_docs/cmp_gt_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_value_op!(
+    name = generic_avx2_cmp_gt_value,
+    op = generic_cmp_gt_value,
+    doc = "../export_docs/cmp_gt_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_value_op!(
+    name = generic_avx512_cmp_gt_value,
+    op = generic_cmp_gt_value,
+    doc = "../export_docs/cmp_gt_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_value_op!(
+    name = generic_neon_cmp_gt_value,
+    op = generic_cmp_gt_value,
+    doc = "../export_docs/cmp_gt_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-gte value
+define_value_op!(
+    name = generic_fallback_cmp_gte_value,
+    op = generic_cmp_gte_value,
+    doc = "../export_docs/cmp_gte_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_value_op!(
+    name = generic_avx2_cmp_gte_value,
+    op = generic_cmp_gte_value,
+    doc = "../export_docs/cmp_gte_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_value_op!(
+    name = generic_avx512_cmp_gte_value,
+    op = generic_cmp_gte_value,
+    doc = "../export_docs/cmp_gte_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_value_op!(
+    name = generic_neon_cmp_gte_value,
+    op = generic_cmp_gte_value,
+    doc = "../export_docs/cmp_gte_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-eq bitmask
+define_bitmask_op!(
+    name = generic_fallback_cmp_eq_bitmask,
+    op = generic_cmp_eq_bitmask,
+    doc = "../export_docs/cmp_eq_bitmask.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_bitmask_op!(
+    name = generic_avx2_cmp_eq_bitmask,
+    op = generic_cmp_eq_bitmask,
+    doc = "../export_docs/cmp_eq_bitmask.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_bitmask_op!(
+    name = generic_avx512_cmp_eq_bitmask,
+    op = generic_cmp_eq_bitmask,
+    doc = "../export_docs/cmp_eq_bitmask.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_bitmask_op!(
+    name = generic_neon_cmp_eq_bitmask,
+    op = generic_cmp_eq_bitmask,
+    doc = "../export_docs/cmp_eq_bitmask.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-neq bitmask
+define_bitmask_op!(
+    name = generic_fallback_cmp_neq_bitmask,
+    op = generic_cmp_neq_bitmask,
+    doc = "../export_docs/cmp_neq_bitmask.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_bitmask_op!(
+    name = generic_avx2_cmp_neq_bitmask,
+    op = generic_cmp_neq_bitmask,
+    doc = "../export_docs/cmp_neq_bitmask.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_bitmask_op!(
+    name = generic_avx512_cmp_neq_bitmask,
+    op = generic_cmp_neq_bitmask,
+    doc = "../export_docs/cmp_neq_bitmask.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_bitmask_op!(
+    name = generic_neon_cmp_neq_bitmask,
+    op = generic_cmp_neq_bitmask,
+    doc = "../export_docs/cmp_neq_bitmask.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-lt bitmask
+define_bitmask_op!(
+    name = generic_fallback_cmp_lt_bitmask,
+    op = generic_cmp_lt_bitmask,
+    doc = "../export_docs/cmp_lt_bitmask.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_bitmask_op!(
+    name = generic_avx2_cmp_lt_bitmask,
+    op = generic_cmp_lt_bitmask,
+    doc = "../export_docs/cmp_lt_bitmask.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_bitmask_op!(
+    name = generic_avx512_cmp_lt_bitmask,
+    op = generic_cmp_lt_bitmask,
+    doc = "../export_docs/cmp_lt_bitmask.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_bitmask_op!(
+    name = generic_neon_cmp_lt_bitmask,
+    op = generic_cmp_lt_bitmask,
+    doc = "../export_docs/cmp_lt_bitmask.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-lte bitmask
+define_bitmask_op!(
+    name = generic_fallback_cmp_lte_bitmask,
+    op = generic_cmp_lte_bitmask,
+    doc = "../export_docs/cmp_lte_bitmask.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_bitmask_op!(
+    name = generic_avx2_cmp_lte_bitmask,
+    op = generic_cmp_lte_bitmask,
+    doc = "../export_docs/cmp_lte_bitmask.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_bitmask_op!(
+    name = generic_avx512_cmp_lte_bitmask,
+    op = generic_cmp_lte_bitmask,
+    doc = "../export_docs/cmp_lte_bitmask.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_bitmask_op!(
+    name = generic_neon_cmp_lte_bitmask,
+    op = generic_cmp_lte_bitmask,
+    doc = "../export_docs/cmp_lte_bitmask.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-gt bitmask
+define_bitmask_op!(
+    name = generic_fallback_cmp_gt_bitmask,
+    op = generic_cmp_gt_bitmask,
+    doc = "../export_docs/cmp_gt_bitmask.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_bitmask_op!(
+    name = generic_avx2_cmp_gt_bitmask,
+    op = generic_cmp_gt_bitmask,
+    doc = "../export_docs/cmp_gt_bitmask.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_bitmask_op!(
+    name = generic_avx512_cmp_gt_bitmask,
+    op = generic_cmp_gt_bitmask,
+    doc = "../export_docs/cmp_gt_bitmask.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_bitmask_op!(
+    name = generic_neon_cmp_gt_bitmask,
+    op = generic_cmp_gt_bitmask,
+    doc = "../export_docs/cmp_gt_bitmask.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-gte bitmask
+define_bitmask_op!(
+    name = generic_fallback_cmp_gte_bitmask,
+    op = generic_cmp_gte_bitmask,
+    doc = "../export_docs/cmp_gte_bitmask.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_bitmask_op!(
+    name = generic_avx2_cmp_gte_bitmask,
+    op = generic_cmp_gte_bitmask,
+    doc = "../export_docs/cmp_gte_bitmask.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_bitmask_op!(
+    name = generic_avx512_cmp_gte_bitmask,
+    op = generic_cmp_gte_bitmask,
+    doc = "../export_docs/cmp_gte_bitmask.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_bitmask_op!(
+    name = generic_neon_cmp_gte_bitmask,
+    op = generic_cmp_gte_bitmask,
+    doc = "../export_docs/cmp_gte_bitmask.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-select
+define_select_op!(name = generic_fallback_select_vertical, Fallback,);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_select_op!(
+    name = generic_avx2_select_vertical,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_select_op!(
+    name = generic_avx512_select_vertical,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_select_op!(
+    name = generic_neon_select_vertical,
+    Neon,
+    target_features = "neon"
+);
+
+// OP-eq count
+define_count_op!(
+    name = generic_fallback_count_eq_value,
+    op = generic_count_eq_value,
+    doc = "../export_docs/cmp_count_eq_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_count_op!(
+    name = generic_avx2_count_eq_value,
+    op = generic_count_eq_value,
+    doc = "../export_docs/cmp_count_eq_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_count_op!(
+    name = generic_avx512_count_eq_value,
+    op = generic_count_eq_value,
+    doc = "../export_docs/cmp_count_eq_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_count_op!(
+    name = generic_neon_count_eq_value,
+    op = generic_count_eq_value,
+    doc = "../export_docs/cmp_count_eq_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-neq count
+define_count_op!(
+    name = generic_fallback_count_neq_value,
+    op = generic_count_neq_value,
+    doc = "../export_docs/cmp_count_neq_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_count_op!(
+    name = generic_avx2_count_neq_value,
+    op = generic_count_neq_value,
+    doc = "../export_docs/cmp_count_neq_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_count_op!(
+    name = generic_avx512_count_neq_value,
+    op = generic_count_neq_value,
+    doc = "../export_docs/cmp_count_neq_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_count_op!(
+    name = generic_neon_count_neq_value,
+    op = generic_count_neq_value,
+    doc = "../export_docs/cmp_count_neq_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-lt count
+define_count_op!(
+    name = generic_fallback_count_lt_value,
+    op = generic_count_lt_value,
+    doc = "../export_docs/cmp_count_lt_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_count_op!(
+    name = generic_avx2_count_lt_value,
+    op = generic_count_lt_value,
+    doc = "../export_docs/cmp_count_lt_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_count_op!(
+    name = generic_avx512_count_lt_value,
+    op = generic_count_lt_value,
+    doc = "../export_docs/cmp_count_lt_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_count_op!(
+    name = generic_neon_count_lt_value,
+    op = generic_count_lt_value,
+    doc = "../export_docs/cmp_count_lt_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-lte count
+define_count_op!(
+    name = generic_fallback_count_lte_value,
+    op = generic_count_lte_value,
+    doc = "../export_docs/cmp_count_lte_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_count_op!(
+    name = generic_avx2_count_lte_value,
+    op = generic_count_lte_value,
+    doc = "../export_docs/cmp_count_lte_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_count_op!(
+    name = generic_avx512_count_lte_value,
+    op = generic_count_lte_value,
+    doc = "../export_docs/cmp_count_lte_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_count_op!(
+    name = generic_neon_count_lte_value,
+    op = generic_count_lte_value,
+    doc = "../export_docs/cmp_count_lte_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-gt count
+define_count_op!(
+    name = generic_fallback_count_gt_value,
+    op = generic_count_gt_value,
+    doc = "../export_docs/cmp_count_gt_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_count_op!(
+    name = generic_avx2_count_gt_value,
+    op = generic_count_gt_value,
+    doc = "../export_docs/cmp_count_gt_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_count_op!(
+    name = generic_avx512_count_gt_value,
+    op = generic_count_gt_value,
+    doc = "../export_docs/cmp_count_gt_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_count_op!(
+    name = generic_neon_count_gt_value,
+    op = generic_count_gt_value,
+    doc = "../export_docs/cmp_count_gt_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-gte count
+define_count_op!(
+    name = generic_fallback_count_gte_value,
+    op = generic_count_gte_value,
+    doc = "../export_docs/cmp_count_gte_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_count_op!(
+    name = generic_avx2_count_gte_value,
+    op = generic_count_gte_value,
+    doc = "../export_docs/cmp_count_gte_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_count_op!(
+    name = generic_avx512_count_gte_value,
+    op = generic_count_gte_value,
+    doc = "../export_docs/cmp_count_gte_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_count_op!(
+    name = generic_neon_count_gte_value,
+    op = generic_count_gte_value,
+    doc = "../export_docs/cmp_count_gte_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-nonzero count
+define_count_nonzero_op!(
+    name = generic_fallback_count_nonzero,
+    op = generic_count_nonzero,
+    doc = "../export_docs/cmp_count_nonzero.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_count_nonzero_op!(
+    name = generic_avx2_count_nonzero,
+    op = generic_count_nonzero,
+    doc = "../export_docs/cmp_count_nonzero.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_count_nonzero_op!(
+    name = generic_avx512_count_nonzero,
+    op = generic_count_nonzero,
+    doc = "../export_docs/cmp_count_nonzero.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_count_nonzero_op!(
+    name = generic_neon_count_nonzero,
+    op = generic_count_nonzero,
+    doc = "../export_docs/cmp_count_nonzero.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-eq any
+define_any_all_op!(
+    name = generic_fallback_any_eq_value,
+    op = generic_any_eq_value,
+    doc = "../export_docs/cmp_any_eq_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_any_all_op!(
+    name = generic_avx2_any_eq_value,
+    op = generic_any_eq_value,
+    doc = "../export_docs/cmp_any_eq_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_any_all_op!(
+    name = generic_avx512_any_eq_value,
+    op = generic_any_eq_value,
+    doc = "../export_docs/cmp_any_eq_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_any_all_op!(
+    name = generic_neon_any_eq_value,
+    op = generic_any_eq_value,
+    doc = "../export_docs/cmp_any_eq_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-neq any
+define_any_all_op!(
+    name = generic_fallback_any_neq_value,
+    op = generic_any_neq_value,
+    doc = "../export_docs/cmp_any_neq_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_any_all_op!(
+    name = generic_avx2_any_neq_value,
+    op = generic_any_neq_value,
+    doc = "../export_docs/cmp_any_neq_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_any_all_op!(
+    name = generic_avx512_any_neq_value,
+    op = generic_any_neq_value,
+    doc = "../export_docs/cmp_any_neq_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_any_all_op!(
+    name = generic_neon_any_neq_value,
+    op = generic_any_neq_value,
+    doc = "../export_docs/cmp_any_neq_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-lt any
+define_any_all_op!(
+    name = generic_fallback_any_lt_value,
+    op = generic_any_lt_value,
+    doc = "../export_docs/cmp_any_lt_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_any_all_op!(
+    name = generic_avx2_any_lt_value,
+    op = generic_any_lt_value,
+    doc = "../export_docs/cmp_any_lt_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_any_all_op!(
+    name = generic_avx512_any_lt_value,
+    op = generic_any_lt_value,
+    doc = "../export_docs/cmp_any_lt_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_any_all_op!(
+    name = generic_neon_any_lt_value,
+    op = generic_any_lt_value,
+    doc = "../export_docs/cmp_any_lt_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-lte any
+define_any_all_op!(
+    name = generic_fallback_any_lte_value,
+    op = generic_any_lte_value,
+    doc = "../export_docs/cmp_any_lte_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_any_all_op!(
+    name = generic_avx2_any_lte_value,
+    op = generic_any_lte_value,
+    doc = "../export_docs/cmp_any_lte_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_any_all_op!(
+    name = generic_avx512_any_lte_value,
+    op = generic_any_lte_value,
+    doc = "../export_docs/cmp_any_lte_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_any_all_op!(
+    name = generic_neon_any_lte_value,
+    op = generic_any_lte_value,
+    doc = "../export_docs/cmp_any_lte_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-gt any
+define_any_all_op!(
+    name = generic_fallback_any_gt_value,
+    op = generic_any_gt_value,
+    doc = "../export_docs/cmp_any_gt_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_any_all_op!(
+    name = generic_avx2_any_gt_value,
+    op = generic_any_gt_value,
+    doc = "../export_docs/cmp_any_gt_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_any_all_op!(
+    name = generic_avx512_any_gt_value,
+    op = generic_any_gt_value,
+    doc = "../export_docs/cmp_any_gt_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_any_all_op!(
+    name = generic_neon_any_gt_value,
+    op = generic_any_gt_value,
+    doc = "../export_docs/cmp_any_gt_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-gte any
+define_any_all_op!(
+    name = generic_fallback_any_gte_value,
+    op = generic_any_gte_value,
+    doc = "../export_docs/cmp_any_gte_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_any_all_op!(
+    name = generic_avx2_any_gte_value,
+    op = generic_any_gte_value,
+    doc = "../export_docs/cmp_any_gte_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_any_all_op!(
+    name = generic_avx512_any_gte_value,
+    op = generic_any_gte_value,
+    doc = "../export_docs/cmp_any_gte_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_any_all_op!(
+    name = generic_neon_any_gte_value,
+    op = generic_any_gte_value,
+    doc = "../export_docs/cmp_any_gte_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-eq all
+define_any_all_op!(
+    name = generic_fallback_all_eq_value,
+    op = generic_all_eq_value,
+    doc = "../export_docs/cmp_all_eq_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_any_all_op!(
+    name = generic_avx2_all_eq_value,
+    op = generic_all_eq_value,
+    doc = "../export_docs/cmp_all_eq_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_any_all_op!(
+    name = generic_avx512_all_eq_value,
+    op = generic_all_eq_value,
+    doc = "../export_docs/cmp_all_eq_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_any_all_op!(
+    name = generic_neon_all_eq_value,
+    op = generic_all_eq_value,
+    doc = "../export_docs/cmp_all_eq_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-neq all
+define_any_all_op!(
+    name = generic_fallback_all_neq_value,
+    op = generic_all_neq_value,
+    doc = "../export_docs/cmp_all_neq_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_any_all_op!(
+    name = generic_avx2_all_neq_value,
+    op = generic_all_neq_value,
+    doc = "../export_docs/cmp_all_neq_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_any_all_op!(
+    name = generic_avx512_all_neq_value,
+    op = generic_all_neq_value,
+    doc = "../export_docs/cmp_all_neq_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_any_all_op!(
+    name = generic_neon_all_neq_value,
+    op = generic_all_neq_value,
+    doc = "../export_docs/cmp_all_neq_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-lt all
+define_any_all_op!(
+    name = generic_fallback_all_lt_value,
+    op = generic_all_lt_value,
+    doc = "../export_docs/cmp_all_lt_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_any_all_op!(
+    name = generic_avx2_all_lt_value,
+    op = generic_all_lt_value,
+    doc = "../export_docs/cmp_all_lt_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_any_all_op!(
+    name = generic_avx512_all_lt_value,
+    op = generic_all_lt_value,
+    doc = "../export_docs/cmp_all_lt_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_any_all_op!(
+    name = generic_neon_all_lt_value,
+    op = generic_all_lt_value,
+    doc = "../export_docs/cmp_all_lt_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-lte all
+define_any_all_op!(
+    name = generic_fallback_all_lte_value,
+    op = generic_all_lte_value,
+    doc = "../export_docs/cmp_all_lte_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_any_all_op!(
+    name = generic_avx2_all_lte_value,
+    op = generic_all_lte_value,
+    doc = "../export_docs/cmp_all_lte_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_any_all_op!(
+    name = generic_avx512_all_lte_value,
+    op = generic_all_lte_value,
+    doc = "../export_docs/cmp_all_lte_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_any_all_op!(
+    name = generic_neon_all_lte_value,
+    op = generic_all_lte_value,
+    doc = "../export_docs/cmp_all_lte_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-gt all
+define_any_all_op!(
+    name = generic_fallback_all_gt_value,
+    op = generic_all_gt_value,
+    doc = "../export_docs/cmp_all_gt_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_any_all_op!(
+    name = generic_avx2_all_gt_value,
+    op = generic_all_gt_value,
+    doc = "../export_docs/cmp_all_gt_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_any_all_op!(
+    name = generic_avx512_all_gt_value,
+    op = generic_all_gt_value,
+    doc = "../export_docs/cmp_all_gt_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_any_all_op!(
+    name = generic_neon_all_gt_value,
+    op = generic_all_gt_value,
+    doc = "../export_docs/cmp_all_gt_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+// OP-gte all
+define_any_all_op!(
+    name = generic_fallback_all_gte_value,
+    op = generic_all_gte_value,
+    doc = "../export_docs/cmp_all_gte_value.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_any_all_op!(
+    name = generic_avx2_all_gte_value,
+    op = generic_all_gte_value,
+    doc = "../export_docs/cmp_all_gte_value.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_any_all_op!(
+    name = generic_avx512_all_gte_value,
+    op = generic_all_gte_value,
+    doc = "../export_docs/cmp_all_gte_value.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_any_all_op!(
+    name = generic_neon_all_gte_value,
+    op = generic_all_gte_value,
+    doc = "../export_docs/cmp_all_gte_value.md",
+    Neon,
+    target_features = "neon"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! define_inner_test {
+        ($variant:ident, op = $op:ident, ty = $t:ident, fold_on = $fold_cb:ident) => {
+            paste::paste! {
+                #[test]
+                fn [< $variant _ $op _horizontal_ $t >]() {
+                    let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let result = unsafe { [< $variant _cmp_ $op >](&l1) };
+
+                    let expected = l1.iter()
                         .copied()
                         .fold(AutoMath::$fold_cb(), |a, b| AutoMath::[< cmp_ $op >](a, b));
                     assert_eq!(
@@ -484,11 +1988,246 @@ mod tests {
         };
     }
 
+    macro_rules! define_value_test {
+        ($variant:ident, op = $op:ident, ty = $t:ident) => {
+            paste::paste! {
+                #[test]
+                fn [< $variant _ $op _scalar_ $t >]() {
+                    let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+                    let value = l1[7];
+
+                    let mut result = vec![$t::default(); 533];
+                    unsafe { [< $variant _cmp_ $op _value >](&l1, value, &mut result) };
+
+                    let mut expected = vec![$t::default(); 533];
+                    unsafe { [< $variant _cmp_ $op _vertical >](&l1, value, &mut expected) };
+                    assert_eq!(
+                        result,
+                        expected,
+                        "Routine result does not match expected",
+                    );
+                    assert!(
+                        result.iter().all(|v| *v == (0 as $t) || *v == (1 as $t)),
+                        "Routine result is not a mask",
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! define_count_test {
+        ($variant:ident, op = $op:ident, ty = $t:ident, cmp = $cmp:expr) => {
+            paste::paste! {
+                #[test]
+                fn [< $variant _count_ $op _ $t >]() {
+                    let cmp: fn($t, $t) -> bool = $cmp;
+                    let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+                    let value = l1[7];
+
+                    let count = unsafe { [< $variant _count_ $op _value >](&l1, value) };
+
+                    let expected = l1.iter()
+                        .filter(|v| cmp(**v, value))
+                        .count();
+                    assert_eq!(count, expected, "Routine result does not match expected");
+
+                    let count = unsafe { [< $variant _count_ $op _value >](&[] as &[$t], value) };
+                    assert_eq!(count, 0, "Empty input should have a count of 0");
+                }
+            }
+        };
+    }
+
+    macro_rules! define_any_all_test {
+        ($variant:ident, op = $op:ident, ty = $t:ident, cmp = $cmp:expr) => {
+            paste::paste! {
+                #[test]
+                fn [< $variant _any_all_ $op _ $t >]() {
+                    let cmp: fn($t, $t) -> bool = $cmp;
+                    let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    for value in [l1[7], l1[532], 0 as $t, $t::MAX] {
+                        let any = unsafe { [< $variant _any_ $op _value >](&l1, value) };
+                        let expected = l1.iter().any(|v| cmp(*v, value));
+                        assert_eq!(any, expected, "Routine result does not match expected");
+
+                        let all = unsafe { [< $variant _all_ $op _value >](&l1, value) };
+                        let expected = l1.iter().all(|v| cmp(*v, value));
+                        assert_eq!(all, expected, "Routine result does not match expected");
+                    }
+
+                    let any = unsafe { [< $variant _any_ $op _value >](&[] as &[$t], l1[0]) };
+                    assert!(!any, "Empty input should be `false`");
+                    let all = unsafe { [< $variant _all_ $op _value >](&[] as &[$t], l1[0]) };
+                    assert!(all, "Empty input should be `true`");
+                }
+            }
+        };
+    }
+
+    macro_rules! define_bitmask_test {
+        ($variant:ident, op = $op:ident, ty = $t:ident, cmp = $cmp:expr) => {
+            paste::paste! {
+                #[test]
+                fn [< $variant _bitmask_ $op _ $t >]() {
+                    let cmp: fn($t, $t) -> bool = $cmp;
+                    let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let mut result = vec![0; 533usize.div_ceil(8)];
+                    unsafe { [< $variant _cmp_ $op _bitmask >](533, &l1, &l2, &mut result) };
+
+                    let mut expected = vec![0u8; 533usize.div_ceil(8)];
+                    for (i, (a, b)) in l1.iter().zip(l2.iter()).enumerate() {
+                        expected[i / 8] |= (cmp(*a, *b) as u8) << (i % 8);
+                    }
+                    assert_eq!(result, expected, "Routine result does not match expected");
+                }
+            }
+        };
+    }
+
+    macro_rules! define_select_test {
+        ($variant:ident, ty = $t:ident) => {
+            paste::paste! {
+                #[test]
+                fn [< $variant _select_vector_ $t >]() {
+                    let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let mut mask = vec![$t::default(); 533];
+                    unsafe { [< $variant _cmp_gt_vertical >](&l1, &l2, &mut mask) };
+
+                    let mut result = vec![$t::default(); 533];
+                    unsafe { [< $variant _select_vertical >](&mask, &l1, &l2, &mut result) };
+
+                    let expected = l1.iter()
+                        .copied()
+                        .zip(l2.iter().copied())
+                        .map(|(a, b)| if a > b { a } else { b })
+                        .collect::<Vec<_>>();
+                    assert_eq!(
+                        result,
+                        expected,
+                        "Routine result does not match expected",
+                    );
+                }
+
+                #[test]
+                fn [< $variant _select_value_ $t >]() {
+                    let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let mut mask = vec![$t::default(); 533];
+                    unsafe { [< $variant _cmp_gt_vertical >](&l1, 2 as $t, &mut mask) };
+
+                    let mut result = vec![$t::default(); 533];
+                    unsafe { [< $variant _select_vertical >](&mask, &l1, 0 as $t, &mut result) };
+
+                    let expected = l1.iter()
+                        .copied()
+                        .map(|v| if v > 2 as $t { v } else { 0 as $t })
+                        .collect::<Vec<_>>();
+                    assert_eq!(
+                        result,
+                        expected,
+                        "Routine result does not match expected",
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! define_cumulative_test {
+        ($variant:ident, op = $op:ident, ty = $t:ident, init = $init:ident) => {
+            paste::paste! {
+                #[test]
+                fn [< $variant _cumulative_ $op _ $t >]() {
+                    let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let mut result = vec![$t::default(); 533];
+                    unsafe { [< $variant _cumulative_ $op >](533, &l1, &mut result) };
+
+                    let expected = l1.iter()
+                        .scan(AutoMath::$init(), |acc, &v| {
+                            *acc = AutoMath::[< cmp_ $op >](*acc, v);
+                            Some(*acc)
+                        })
+                        .collect::<Vec<_>>();
+                    assert_eq!(
+                        result,
+                        expected,
+                        "Routine result does not match expected",
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! define_min_max_order_test {
+        ($variant:ident, ty = $t:ident) => {
+            paste::paste! {
+                #[test]
+                /// Catches the min and max exports being wired to the wrong routine.
+                fn [< $variant _min_max_order_ $t >]() {
+                    for len in [0, 1, 31, 533] {
+                        let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(len);
+
+                        let mut min = vec![$t::default(); len];
+                        unsafe { [< $variant _cmp_min_vertical >](&l1, &l2, &mut min) };
+                        let mut max = vec![$t::default(); len];
+                        unsafe { [< $variant _cmp_max_vertical >](&l1, &l2, &mut max) };
+
+                        for (i, (a, b)) in l1.iter().zip(l2.iter()).enumerate() {
+                            assert!(
+                                AutoMath::cmp_lte(min[i], max[i]),
+                                "Min {:?} is above max {:?} at {i}",
+                                min[i],
+                                max[i],
+                            );
+                            assert!(
+                                (min[i] == *a && max[i] == *b) || (min[i] == *b && max[i] == *a),
+                                "Min {:?} and max {:?} are not the inputs {a:?} and {b:?} at {i}",
+                                min[i],
+                                max[i],
+                            );
+                        }
+                    }
+                }
+            }
+        };
+    }
+
     macro_rules! define_cmp_test {
         ($variant:ident, types = $($t:ident $(,)?)+) => {
             $(
                 define_inner_test!($variant, op = min, ty = $t, fold_on = max);
                 define_inner_test!($variant, op = max, ty = $t, fold_on = min);
+                define_cumulative_test!($variant, op = max, ty = $t, init = min);
+                define_cumulative_test!($variant, op = min, ty = $t, init = max);
+                define_min_max_order_test!($variant, ty = $t);
+                define_value_test!($variant, op = eq, ty = $t);
+                define_value_test!($variant, op = neq, ty = $t);
+                define_value_test!($variant, op = lt, ty = $t);
+                define_value_test!($variant, op = lte, ty = $t);
+                define_value_test!($variant, op = gt, ty = $t);
+                define_value_test!($variant, op = gte, ty = $t);
+                define_select_test!($variant, ty = $t);
+                define_bitmask_test!($variant, op = eq, ty = $t, cmp = AutoMath::cmp_eq);
+                define_bitmask_test!($variant, op = neq, ty = $t, cmp = |a, b| !AutoMath::cmp_eq(a, b));
+                define_bitmask_test!($variant, op = lt, ty = $t, cmp = AutoMath::cmp_lt);
+                define_bitmask_test!($variant, op = lte, ty = $t, cmp = AutoMath::cmp_lte);
+                define_bitmask_test!($variant, op = gt, ty = $t, cmp = AutoMath::cmp_gt);
+                define_bitmask_test!($variant, op = gte, ty = $t, cmp = AutoMath::cmp_gte);
+                define_count_test!($variant, op = eq, ty = $t, cmp = AutoMath::cmp_eq);
+                define_count_test!($variant, op = neq, ty = $t, cmp = |a, b| !AutoMath::cmp_eq(a, b));
+                define_count_test!($variant, op = lt, ty = $t, cmp = AutoMath::cmp_lt);
+                define_count_test!($variant, op = lte, ty = $t, cmp = AutoMath::cmp_lte);
+                define_count_test!($variant, op = gt, ty = $t, cmp = AutoMath::cmp_gt);
+                define_count_test!($variant, op = gte, ty = $t, cmp = AutoMath::cmp_gte);
+                define_any_all_test!($variant, op = eq, ty = $t, cmp = AutoMath::cmp_eq);
+                define_any_all_test!($variant, op = neq, ty = $t, cmp = |a, b| !AutoMath::cmp_eq(a, b));
+                define_any_all_test!($variant, op = lt, ty = $t, cmp = AutoMath::cmp_lt);
+                define_any_all_test!($variant, op = lte, ty = $t, cmp = AutoMath::cmp_lte);
+                define_any_all_test!($variant, op = gt, ty = $t, cmp = AutoMath::cmp_gt);
+                define_any_all_test!($variant, op = gte, ty = $t, cmp = AutoMath::cmp_gte);
             )*
         };
     }
@@ -525,7 +2264,7 @@ mod tests {
     );
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly",
+        feature = "stable-avx512",
         target_feature = "avx512f"
     ))]
     define_cmp_test!(
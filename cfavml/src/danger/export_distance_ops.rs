@@ -3,215 +3,1992 @@
 //! These operations are well suited for vector search situations, although things like
 //! dot product are more generic than simply vector search.
 
+#[cfg(feature = "half")]
+use half::bf16;
+
+use crate::buffer::WriteOnlyBuffer;
 use crate::danger::{
+    generic_batch_dot,
+    generic_binary_hamming_distance,
+    generic_binary_jaccard_similarity,
     generic_cosine,
+    generic_cosine_batch,
+    generic_cosine_wide_acc,
     generic_dot,
+    generic_dot_batch,
+    generic_dot_compensated,
+    generic_dot_f32_f64acc,
+    generic_dot_indexed,
+    generic_dot_matrix,
+    generic_dot_ordered,
+    generic_dot_wide_acc,
+    generic_dot_widen,
+    generic_gemv,
+    generic_gram_matrix,
+    generic_pairwise_distances,
     generic_squared_euclidean,
+    generic_squared_euclidean_batch,
+    generic_squared_euclidean_f32_f64acc,
+    generic_squared_euclidean_matrix,
+    generic_squared_euclidean_wide_acc,
+    generic_squared_euclidean_widen,
     generic_squared_norm,
+    generic_squared_norm_f32_f64acc,
+    generic_squared_norm_ordered,
+    generic_weighted_dot,
     SimdRegister,
+    SimdWideningDotRegister,
 };
 use crate::math::{AutoMath, Math};
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 
-macro_rules! define_dist_impl {
+macro_rules! define_dist_impl {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(a: B1, b: B2) -> T
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, _, _>(
+                a,
+                b,
+            )
+        }
+    };
+}
+
+define_dist_impl!(
+    name = generic_fallback_cosine,
+    op = generic_cosine,
+    doc = "../export_docs/dist_cosine.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_impl!(
+    name = generic_avx2_cosine,
+    op = generic_cosine,
+    doc = "../export_docs/dist_cosine.md",
+    Avx2,
+    target_features = "avx2",
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_impl!(
+    name = generic_avx2fma_cosine,
+    op = generic_cosine,
+    doc = "../export_docs/dist_cosine.md",
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_dist_impl!(
+    name = generic_avx2f16c_cosine,
+    op = generic_cosine,
+    doc = "../export_docs/dist_cosine.md",
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_dist_impl!(
+    name = generic_avx512_cosine,
+    op = generic_cosine,
+    doc = "../export_docs/dist_cosine.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dist_impl!(
+    name = generic_neon_cosine,
+    op = generic_cosine,
+    doc = "../export_docs/dist_cosine.md",
+    Neon,
+    target_features = "neon",
+);
+
+define_dist_impl!(
+    name = generic_fallback_dot,
+    op = generic_dot,
+    doc = "../export_docs/dist_dot.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_impl!(
+    name = generic_avx2_dot,
+    op = generic_dot,
+    doc = "../export_docs/dist_dot.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_impl!(
+    name = generic_avx2fma_dot,
+    op = generic_dot,
+    doc = "../export_docs/dist_dot.md",
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_dist_impl!(
+    name = generic_avx2f16c_dot,
+    op = generic_dot,
+    doc = "../export_docs/dist_dot.md",
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_dist_impl!(
+    name = generic_avx512_dot,
+    op = generic_dot,
+    doc = "../export_docs/dist_dot.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dist_impl!(
+    name = generic_neon_dot,
+    op = generic_dot,
+    doc = "../export_docs/dist_dot.md",
+    Neon,
+    target_features = "neon"
+);
+
+define_dist_impl!(
+    name = generic_fallback_squared_euclidean,
+    op = generic_squared_euclidean,
+    doc = "../export_docs/dist_euclidean.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_impl!(
+    name = generic_avx2_squared_euclidean,
+    op = generic_squared_euclidean,
+    doc = "../export_docs/dist_euclidean.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_impl!(
+    name = generic_avx2fma_squared_euclidean,
+    op = generic_squared_euclidean,
+    doc = "../export_docs/dist_euclidean.md",
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_dist_impl!(
+    name = generic_avx2f16c_squared_euclidean,
+    op = generic_squared_euclidean,
+    doc = "../export_docs/dist_euclidean.md",
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_dist_impl!(
+    name = generic_avx512_squared_euclidean,
+    op = generic_squared_euclidean,
+    doc = "../export_docs/dist_euclidean.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dist_impl!(
+    name = generic_neon_squared_euclidean,
+    op = generic_squared_euclidean,
+    doc = "../export_docs/dist_euclidean.md",
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_dist_batch_impl {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B>(query: &[T], batch: &[T], result: &mut [B])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B>(
+                query,
+                batch,
+                result,
+            )
+        }
+    };
+}
+
+define_dist_batch_impl!(
+    name = generic_fallback_cosine_batch,
+    op = generic_cosine_batch,
+    doc = "../export_docs/dist_cosine_batch.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_batch_impl!(
+    name = generic_avx2_cosine_batch,
+    op = generic_cosine_batch,
+    doc = "../export_docs/dist_cosine_batch.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_batch_impl!(
+    name = generic_avx2fma_cosine_batch,
+    op = generic_cosine_batch,
+    doc = "../export_docs/dist_cosine_batch.md",
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_dist_batch_impl!(
+    name = generic_avx2f16c_cosine_batch,
+    op = generic_cosine_batch,
+    doc = "../export_docs/dist_cosine_batch.md",
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_dist_batch_impl!(
+    name = generic_avx512_cosine_batch,
+    op = generic_cosine_batch,
+    doc = "../export_docs/dist_cosine_batch.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dist_batch_impl!(
+    name = generic_neon_cosine_batch,
+    op = generic_cosine_batch,
+    doc = "../export_docs/dist_cosine_batch.md",
+    Neon,
+    target_features = "neon"
+);
+define_dist_batch_impl!(
+    name = generic_fallback_dot_batch,
+    op = generic_dot_batch,
+    doc = "../export_docs/dist_dot_batch.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_batch_impl!(
+    name = generic_avx2_dot_batch,
+    op = generic_dot_batch,
+    doc = "../export_docs/dist_dot_batch.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_batch_impl!(
+    name = generic_avx2fma_dot_batch,
+    op = generic_dot_batch,
+    doc = "../export_docs/dist_dot_batch.md",
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_dist_batch_impl!(
+    name = generic_avx2f16c_dot_batch,
+    op = generic_dot_batch,
+    doc = "../export_docs/dist_dot_batch.md",
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_dist_batch_impl!(
+    name = generic_avx512_dot_batch,
+    op = generic_dot_batch,
+    doc = "../export_docs/dist_dot_batch.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dist_batch_impl!(
+    name = generic_neon_dot_batch,
+    op = generic_dot_batch,
+    doc = "../export_docs/dist_dot_batch.md",
+    Neon,
+    target_features = "neon"
+);
+define_dist_batch_impl!(
+    name = generic_fallback_squared_euclidean_batch,
+    op = generic_squared_euclidean_batch,
+    doc = "../export_docs/dist_euclidean_batch.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_batch_impl!(
+    name = generic_avx2_squared_euclidean_batch,
+    op = generic_squared_euclidean_batch,
+    doc = "../export_docs/dist_euclidean_batch.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_batch_impl!(
+    name = generic_avx2fma_squared_euclidean_batch,
+    op = generic_squared_euclidean_batch,
+    doc = "../export_docs/dist_euclidean_batch.md",
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_dist_batch_impl!(
+    name = generic_avx2f16c_squared_euclidean_batch,
+    op = generic_squared_euclidean_batch,
+    doc = "../export_docs/dist_euclidean_batch.md",
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_dist_batch_impl!(
+    name = generic_avx512_squared_euclidean_batch,
+    op = generic_squared_euclidean_batch,
+    doc = "../export_docs/dist_euclidean_batch.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dist_batch_impl!(
+    name = generic_neon_squared_euclidean_batch,
+    op = generic_squared_euclidean_batch,
+    doc = "../export_docs/dist_euclidean_batch.md",
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_dist_indexed_impl {
+    (
+        name = $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_dot_indexed.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B>(
+            dims: usize,
+            query: &[T],
+            data: &[T],
+            indices: &[usize],
+            result: &mut [B],
+        )
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_dot_indexed::<T, crate::danger::$imp, AutoMath, B>(
+                dims,
+                query,
+                data,
+                indices,
+                result,
+            )
+        }
+    };
+}
+
+define_dist_indexed_impl!(name = generic_fallback_dot_indexed, Fallback,);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_indexed_impl!(
+    name = generic_avx2_dot_indexed,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_indexed_impl!(
+    name = generic_avx2fma_dot_indexed,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_dist_indexed_impl!(
+    name = generic_avx2f16c_dot_indexed,
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_dist_indexed_impl!(
+    name = generic_avx512_dot_indexed,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dist_indexed_impl!(
+    name = generic_neon_dot_indexed,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_gemv_impl {
+    (
+        name = $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_gemv.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(
+            rows: usize,
+            cols: usize,
+            matrix: &[T],
+            x: &[T],
+            out: &mut [T],
+        )
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_gemv::<T, crate::danger::$imp, AutoMath>(rows, cols, matrix, x, out)
+        }
+    };
+}
+
+define_gemv_impl!(name = generic_fallback_gemv, Fallback,);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_gemv_impl!(name = generic_avx2_gemv, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_gemv_impl!(
+    name = generic_avx2fma_gemv,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_gemv_impl!(
+    name = generic_avx2f16c_gemv,
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_gemv_impl!(
+    name = generic_avx512_gemv,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_gemv_impl!(name = generic_neon_gemv, Neon, target_features = "neon");
+
+macro_rules! define_batch_dot_impl {
+    (
+        name = $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_batch_dot.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(
+            n_rows: usize,
+            dims: usize,
+            matrix: &[T],
+            query: &[T],
+            scores: &mut [T],
+        )
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_batch_dot::<T, crate::danger::$imp, AutoMath>(
+                n_rows,
+                dims,
+                matrix,
+                query,
+                scores,
+            )
+        }
+    };
+}
+
+define_batch_dot_impl!(name = generic_fallback_batch_dot, Fallback,);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_batch_dot_impl!(
+    name = generic_avx2_batch_dot,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_batch_dot_impl!(
+    name = generic_avx2fma_batch_dot,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_batch_dot_impl!(
+    name = generic_avx2f16c_batch_dot,
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_batch_dot_impl!(
+    name = generic_avx512_batch_dot,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_batch_dot_impl!(
+    name = generic_neon_batch_dot,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_weighted_dot_impl {
+    (
+        name = $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_weighted_dot.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(dims: usize, a: &[T], b: &[T], w: &[T]) -> T
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_weighted_dot::<T, crate::danger::$imp, AutoMath>(dims, a, b, w)
+        }
+    };
+}
+
+define_weighted_dot_impl!(name = generic_fallback_weighted_dot, Fallback,);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_dot_impl!(
+    name = generic_avx2_weighted_dot,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_weighted_dot_impl!(
+    name = generic_avx2fma_weighted_dot,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_weighted_dot_impl!(
+    name = generic_avx2f16c_weighted_dot,
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_weighted_dot_impl!(
+    name = generic_avx512_weighted_dot,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_weighted_dot_impl!(
+    name = generic_neon_weighted_dot,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_dist_matrix_impl {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(
+            dims: usize,
+            a_rows: usize,
+            b_rows: usize,
+            a: &[T],
+            b: &[T],
+            result: &mut [T],
+        )
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath>(
+                dims,
+                a_rows,
+                b_rows,
+                a,
+                b,
+                result,
+            )
+        }
+    };
+}
+
+define_dist_matrix_impl!(
+    name = generic_fallback_dot_matrix,
+    op = generic_dot_matrix,
+    doc = "../export_docs/dist_dot_matrix.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_matrix_impl!(
+    name = generic_avx2_dot_matrix,
+    op = generic_dot_matrix,
+    doc = "../export_docs/dist_dot_matrix.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_matrix_impl!(
+    name = generic_avx2fma_dot_matrix,
+    op = generic_dot_matrix,
+    doc = "../export_docs/dist_dot_matrix.md",
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_dist_matrix_impl!(
+    name = generic_avx2f16c_dot_matrix,
+    op = generic_dot_matrix,
+    doc = "../export_docs/dist_dot_matrix.md",
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_dist_matrix_impl!(
+    name = generic_avx512_dot_matrix,
+    op = generic_dot_matrix,
+    doc = "../export_docs/dist_dot_matrix.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dist_matrix_impl!(
+    name = generic_neon_dot_matrix,
+    op = generic_dot_matrix,
+    doc = "../export_docs/dist_dot_matrix.md",
+    Neon,
+    target_features = "neon"
+);
+define_dist_matrix_impl!(
+    name = generic_fallback_squared_euclidean_matrix,
+    op = generic_squared_euclidean_matrix,
+    doc = "../export_docs/dist_euclidean_matrix.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_matrix_impl!(
+    name = generic_avx2_squared_euclidean_matrix,
+    op = generic_squared_euclidean_matrix,
+    doc = "../export_docs/dist_euclidean_matrix.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_dist_matrix_impl!(
+    name = generic_avx2fma_squared_euclidean_matrix,
+    op = generic_squared_euclidean_matrix,
+    doc = "../export_docs/dist_euclidean_matrix.md",
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_dist_matrix_impl!(
+    name = generic_avx2f16c_squared_euclidean_matrix,
+    op = generic_squared_euclidean_matrix,
+    doc = "../export_docs/dist_euclidean_matrix.md",
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_dist_matrix_impl!(
+    name = generic_avx512_squared_euclidean_matrix,
+    op = generic_squared_euclidean_matrix,
+    doc = "../export_docs/dist_euclidean_matrix.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_dist_matrix_impl!(
+    name = generic_neon_squared_euclidean_matrix,
+    op = generic_squared_euclidean_matrix,
+    doc = "../export_docs/dist_euclidean_matrix.md",
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_gram_impl {
+    (
+        name = $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_gram_matrix.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(rows: usize, dims: usize, matrix: &[T], result: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_gram_matrix::<T, crate::danger::$imp, AutoMath>(rows, dims, matrix, result)
+        }
+    };
+}
+
+define_gram_impl!(name = generic_fallback_gram_matrix, Fallback,);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_gram_impl!(
+    name = generic_avx2_gram_matrix,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_gram_impl!(
+    name = generic_avx2fma_gram_matrix,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_gram_impl!(
+    name = generic_avx2f16c_gram_matrix,
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_gram_impl!(
+    name = generic_avx512_gram_matrix,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_gram_impl!(
+    name = generic_neon_gram_matrix,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_pairwise_impl {
+    (
+        name = $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_pairwise_distances.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(n: usize, dims: usize, matrix: &[T], result: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_pairwise_distances::<T, crate::danger::$imp, AutoMath>(
+                n,
+                dims,
+                matrix,
+                result,
+            )
+        }
+    };
+}
+
+define_pairwise_impl!(name = generic_fallback_pairwise_distances, Fallback,);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_pairwise_impl!(
+    name = generic_avx2_pairwise_distances,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_pairwise_impl!(
+    name = generic_avx2fma_pairwise_distances,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_pairwise_impl!(
+    name = generic_avx2f16c_pairwise_distances,
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_pairwise_impl!(
+    name = generic_avx512_pairwise_distances,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_pairwise_impl!(
+    name = generic_neon_pairwise_distances,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_norm_impl {
+    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_norm.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1>(a: B1) -> T
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_squared_norm::<T, crate::danger::$imp, AutoMath, _>(a)
+        }
+    };
+}
+
+define_norm_impl!(generic_fallback_squared_norm, Fallback);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_norm_impl!(generic_avx2_squared_norm, Avx2, target_features = "avx2");
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_norm_impl!(
+    generic_avx2fma_squared_norm,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma",
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_norm_impl!(
+    generic_avx2f16c_squared_norm,
+    Avx2F16C,
+    target_features = "avx2",
+    "f16c",
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_norm_impl!(
+    generic_avx512_squared_norm,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_norm_impl!(generic_neon_squared_norm, Neon, target_features = "neon");
+
+macro_rules! define_reduce_impl {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        args = ($($arg:ident $(,)?)+),
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>($($arg: &[T], )+) -> T
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath>($($arg, )+)
+        }
+    };
+}
+
+define_reduce_impl!(
+    name = generic_fallback_dot_ordered,
+    op = generic_dot_ordered,
+    args = (a, b),
+    doc = "../export_docs/dist_dot_ordered.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_reduce_impl!(
+    name = generic_avx2_dot_ordered,
+    op = generic_dot_ordered,
+    args = (a, b),
+    doc = "../export_docs/dist_dot_ordered.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_reduce_impl!(
+    name = generic_avx512_dot_ordered,
+    op = generic_dot_ordered,
+    args = (a, b),
+    doc = "../export_docs/dist_dot_ordered.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_reduce_impl!(
+    name = generic_neon_dot_ordered,
+    op = generic_dot_ordered,
+    args = (a, b),
+    doc = "../export_docs/dist_dot_ordered.md",
+    Neon,
+    target_features = "neon"
+);
+
+define_reduce_impl!(
+    name = generic_fallback_squared_norm_ordered,
+    op = generic_squared_norm_ordered,
+    args = (a),
+    doc = "../export_docs/dist_norm_ordered.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_reduce_impl!(
+    name = generic_avx2_squared_norm_ordered,
+    op = generic_squared_norm_ordered,
+    args = (a),
+    doc = "../export_docs/dist_norm_ordered.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_reduce_impl!(
+    name = generic_avx512_squared_norm_ordered,
+    op = generic_squared_norm_ordered,
+    args = (a),
+    doc = "../export_docs/dist_norm_ordered.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_reduce_impl!(
+    name = generic_neon_squared_norm_ordered,
+    op = generic_squared_norm_ordered,
+    args = (a),
+    doc = "../export_docs/dist_norm_ordered.md",
+    Neon,
+    target_features = "neon"
+);
+
+define_reduce_impl!(
+    name = generic_fallback_dot_compensated,
+    op = generic_dot_compensated,
+    args = (a, b),
+    doc = "../export_docs/dist_dot_compensated.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_reduce_impl!(
+    name = generic_avx2_dot_compensated,
+    op = generic_dot_compensated,
+    args = (a, b),
+    doc = "../export_docs/dist_dot_compensated.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_reduce_impl!(
+    name = generic_avx512_dot_compensated,
+    op = generic_dot_compensated,
+    args = (a, b),
+    doc = "../export_docs/dist_dot_compensated.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_reduce_impl!(
+    name = generic_neon_dot_compensated,
+    op = generic_dot_compensated,
+    args = (a, b),
+    doc = "../export_docs/dist_dot_compensated.md",
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_widen_impl {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T>(a: &[T], b: &[T]) -> i32
+        where
+            T: Copy + Into<i32>,
+            crate::danger::$imp: SimdWideningDotRegister<T>,
+        {
+            $op::<T, crate::danger::$imp>(a, b)
+        }
+    };
+}
+
+define_widen_impl!(
+    name = generic_fallback_dot_widen,
+    op = generic_dot_widen,
+    doc = "../export_docs/dist_dot_widen.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_widen_impl!(
+    name = generic_avx2_dot_widen,
+    op = generic_dot_widen,
+    doc = "../export_docs/dist_dot_widen.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_widen_impl!(
+    name = generic_avx512vnni_dot_widen,
+    op = generic_dot_widen,
+    doc = "../export_docs/dist_dot_widen.md",
+    Avx512Vnni,
+    target_features = "avx512f",
+    "avx512bw",
+    "avx512vnni"
+);
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+#[inline]
+#[target_feature(enable = "avx512f", enable = "avx512bw", enable = "avx512vnni")]
+#[doc = include_str!("../export_docs/dist_dot_widen.md")]
+/// - **`+avx512f`** **`+avx512bw`** **`+avx512vnni`** CPU features are available at runtime.
+///   Running on hardware _without_ this feature available will cause immediate UB.
+///
+/// This is [generic_avx512vnni_dot_widen] specialised to `u8`, the products are
+/// accumulated with the native `vpdpbusd` instruction.
+pub unsafe fn u8_xany_avx512_vnni_dot(a: &[u8], b: &[u8]) -> i32 {
+    generic_avx512vnni_dot_widen(a, b)
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+#[inline]
+#[target_feature(enable = "avx512f", enable = "avx512bw", enable = "avx512vnni")]
+#[doc = include_str!("../export_docs/dist_dot_widen.md")]
+/// - **`+avx512f`** **`+avx512bw`** **`+avx512vnni`** CPU features are available at runtime.
+///   Running on hardware _without_ this feature available will cause immediate UB.
+///
+/// This is [generic_avx512vnni_dot_widen] specialised to `i8`, the products are
+/// accumulated with the native `vpdpbusd` instruction.
+pub unsafe fn i8_xany_avx512_vnni_dot(a: &[i8], b: &[i8]) -> i32 {
+    generic_avx512vnni_dot_widen(a, b)
+}
+#[cfg(target_arch = "aarch64")]
+define_widen_impl!(
+    name = generic_neon_dot_widen,
+    op = generic_dot_widen,
+    doc = "../export_docs/dist_dot_widen.md",
+    Neon,
+    target_features = "neon"
+);
+define_widen_impl!(
+    name = generic_fallback_squared_euclidean_widen,
+    op = generic_squared_euclidean_widen,
+    doc = "../export_docs/dist_euclidean_widen.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_widen_impl!(
+    name = generic_avx2_squared_euclidean_widen,
+    op = generic_squared_euclidean_widen,
+    doc = "../export_docs/dist_euclidean_widen.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_widen_impl!(
+    name = generic_avx512vnni_squared_euclidean_widen,
+    op = generic_squared_euclidean_widen,
+    doc = "../export_docs/dist_euclidean_widen.md",
+    Avx512Vnni,
+    target_features = "avx512f",
+    "avx512bw",
+    "avx512vnni"
+);
+#[cfg(target_arch = "aarch64")]
+define_widen_impl!(
+    name = generic_neon_squared_euclidean_widen,
+    op = generic_squared_euclidean_widen,
+    doc = "../export_docs/dist_euclidean_widen.md",
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_f64acc_impl {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[f32], b: &[f32]) -> f64 {
+            $op::<crate::danger::$imp>(a, b)
+        }
+    };
+}
+
+define_f64acc_impl!(
+    name = f32_fallback_dot_f64acc,
+    op = generic_dot_f32_f64acc,
+    doc = "../export_docs/dist_dot_f64acc.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_f64acc_impl!(
+    name = f32_avx2_dot_f64acc,
+    op = generic_dot_f32_f64acc,
+    doc = "../export_docs/dist_dot_f64acc.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_f64acc_impl!(
+    name = f32_avx2fma_dot_f64acc,
+    op = generic_dot_f32_f64acc,
+    doc = "../export_docs/dist_dot_f64acc.md",
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_f64acc_impl!(
+    name = f32_avx512_dot_f64acc,
+    op = generic_dot_f32_f64acc,
+    doc = "../export_docs/dist_dot_f64acc.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_f64acc_impl!(
+    name = f32_neon_dot_f64acc,
+    op = generic_dot_f32_f64acc,
+    doc = "../export_docs/dist_dot_f64acc.md",
+    Neon,
+    target_features = "neon"
+);
+define_f64acc_impl!(
+    name = f32_fallback_squared_euclidean_f64acc,
+    op = generic_squared_euclidean_f32_f64acc,
+    doc = "../export_docs/dist_euclidean_f64acc.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_f64acc_impl!(
+    name = f32_avx2_squared_euclidean_f64acc,
+    op = generic_squared_euclidean_f32_f64acc,
+    doc = "../export_docs/dist_euclidean_f64acc.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_f64acc_impl!(
+    name = f32_avx2fma_squared_euclidean_f64acc,
+    op = generic_squared_euclidean_f32_f64acc,
+    doc = "../export_docs/dist_euclidean_f64acc.md",
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_f64acc_impl!(
+    name = f32_avx512_squared_euclidean_f64acc,
+    op = generic_squared_euclidean_f32_f64acc,
+    doc = "../export_docs/dist_euclidean_f64acc.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_f64acc_impl!(
+    name = f32_neon_squared_euclidean_f64acc,
+    op = generic_squared_euclidean_f32_f64acc,
+    doc = "../export_docs/dist_euclidean_f64acc.md",
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_norm_f64acc_impl {
+    (
+        name = $name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/dist_norm_f64acc.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[f32]) -> f64 {
+            generic_squared_norm_f32_f64acc::<crate::danger::$imp>(a)
+        }
+    };
+}
+
+define_norm_f64acc_impl!(name = f32_fallback_squared_norm_f64acc, Fallback,);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_norm_f64acc_impl!(
+    name = f32_avx2_squared_norm_f64acc,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_norm_f64acc_impl!(
+    name = f32_avx2fma_squared_norm_f64acc,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_norm_f64acc_impl!(
+    name = f32_avx512_squared_norm_f64acc,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_norm_f64acc_impl!(
+    name = f32_neon_squared_norm_f64acc,
+    Neon,
+    target_features = "neon"
+);
+
+macro_rules! define_wide_acc_impl {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $from:ident => $to:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name(a: &[$from], b: &[$from]) -> $to {
+            $op::<$from, $to, crate::danger::$imp, AutoMath>(a, b)
+        }
+    };
+}
+
+define_wide_acc_impl!(
+    name = i32_fallback_squared_euclidean_i64acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_wide_acc.md",
+    i32 => i64,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_wide_acc_impl!(
+    name = i32_avx2_squared_euclidean_i64acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_wide_acc.md",
+    i32 => i64,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_wide_acc_impl!(
+    name = i32_avx512_squared_euclidean_i64acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_wide_acc.md",
+    i32 => i64,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_wide_acc_impl!(
+    name = i32_neon_squared_euclidean_i64acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_wide_acc.md",
+    i32 => i64,
+    Neon,
+    target_features = "neon"
+);
+define_wide_acc_impl!(
+    name = u32_fallback_squared_euclidean_u64acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_wide_acc.md",
+    u32 => u64,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_wide_acc_impl!(
+    name = u32_avx2_squared_euclidean_u64acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_wide_acc.md",
+    u32 => u64,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_wide_acc_impl!(
+    name = u32_avx512_squared_euclidean_u64acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_wide_acc.md",
+    u32 => u64,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_wide_acc_impl!(
+    name = u32_neon_squared_euclidean_u64acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_wide_acc.md",
+    u32 => u64,
+    Neon,
+    target_features = "neon"
+);
+define_wide_acc_impl!(
+    name = u16_fallback_squared_euclidean_u32acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_wide_acc.md",
+    u16 => u32,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_wide_acc_impl!(
+    name = u16_avx2_squared_euclidean_u32acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_wide_acc.md",
+    u16 => u32,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_wide_acc_impl!(
+    name = u16_avx512_squared_euclidean_u32acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_wide_acc.md",
+    u16 => u32,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_wide_acc_impl!(
+    name = u16_neon_squared_euclidean_u32acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_wide_acc.md",
+    u16 => u32,
+    Neon,
+    target_features = "neon"
+);
+define_wide_acc_impl!(
+    name = i32_fallback_dot_i64acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_wide_acc.md",
+    i32 => i64,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_wide_acc_impl!(
+    name = i32_avx2_dot_i64acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_wide_acc.md",
+    i32 => i64,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_wide_acc_impl!(
+    name = i32_avx512_dot_i64acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_wide_acc.md",
+    i32 => i64,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_wide_acc_impl!(
+    name = i32_neon_dot_i64acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_wide_acc.md",
+    i32 => i64,
+    Neon,
+    target_features = "neon"
+);
+define_wide_acc_impl!(
+    name = u32_fallback_dot_u64acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_wide_acc.md",
+    u32 => u64,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_wide_acc_impl!(
+    name = u32_avx2_dot_u64acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_wide_acc.md",
+    u32 => u64,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_wide_acc_impl!(
+    name = u32_avx512_dot_u64acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_wide_acc.md",
+    u32 => u64,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_wide_acc_impl!(
+    name = u32_neon_dot_u64acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_wide_acc.md",
+    u32 => u64,
+    Neon,
+    target_features = "neon"
+);
+define_wide_acc_impl!(
+    name = u16_fallback_dot_u32acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_wide_acc.md",
+    u16 => u32,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_wide_acc_impl!(
+    name = u16_avx2_dot_u32acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_wide_acc.md",
+    u16 => u32,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_wide_acc_impl!(
+    name = u16_avx512_dot_u32acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_wide_acc.md",
+    u16 => u32,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_wide_acc_impl!(
+    name = u16_neon_dot_u32acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_wide_acc.md",
+    u16 => u32,
+    Neon,
+    target_features = "neon"
+);
+#[cfg(feature = "half")]
+define_wide_acc_impl!(
+    name = bf16_fallback_dot_f32acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_bf16.md",
+    bf16 => f32,
+    Fallback,
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_wide_acc_impl!(
+    name = bf16_avx2_dot_f32acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_bf16.md",
+    bf16 => f32,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_wide_acc_impl!(
+    name = bf16_avx2fma_dot_f32acc,
+    op = generic_dot_wide_acc,
+    doc = "../export_docs/dist_dot_bf16.md",
+    bf16 => f32,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512",
+    feature = "half"
+))]
+#[inline]
+#[target_feature(enable = "avx512f", enable = "avx512bw", enable = "avx512bf16")]
+#[doc = include_str!("../export_docs/dist_dot_bf16.md")]
+/// - **`+avx512f`** **`+avx512bw`** **`+avx512bf16`** CPU features are available at runtime.
+///   Running on hardware _without_ this feature available will cause immediate UB.
+///
+/// The products are accumulated with the native `vdpbf16ps` instruction, which flushes
+/// denormal values to zero.
+pub unsafe fn bf16_avx512bf16_dot_f32acc(a: &[bf16], b: &[bf16]) -> f32 {
+    crate::danger::Avx512Bf16::dot_f32acc(a, b)
+}
+#[cfg(feature = "half")]
+define_wide_acc_impl!(
+    name = bf16_fallback_squared_euclidean_f32acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_bf16.md",
+    bf16 => f32,
+    Fallback,
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_wide_acc_impl!(
+    name = bf16_avx2_squared_euclidean_f32acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_bf16.md",
+    bf16 => f32,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+define_wide_acc_impl!(
+    name = bf16_avx2fma_squared_euclidean_f32acc,
+    op = generic_squared_euclidean_wide_acc,
+    doc = "../export_docs/dist_euclidean_bf16.md",
+    bf16 => f32,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+
+macro_rules! define_cosine_wide_acc_impl {
     (
         name = $name:ident,
-        op = $op:ident,
-        doc = $doc:expr,
+        $from:ident => $to:ident,
         $imp:ident $(,)?
         $(target_features = $($feat:expr $(,)?)+)?
     ) => {
         #[inline]
         $(#[target_feature($(enable = $feat, )*)])*
-        #[doc = include_str!($doc)]
+        #[doc = include_str!("../export_docs/dist_cosine_wide_acc.md")]
         $(
 
             #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
             #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
         )*
-        pub unsafe fn $name<T, B1, B2>(a: B1, b: B2) -> T
-        where
-            T: Copy,
-            B1: IntoMemLoader<T>,
-            B1::Loader: MemLoader<Value = T>,
-            B2: IntoMemLoader<T>,
-            B2::Loader: MemLoader<Value = T>,
-            crate::danger::$imp: SimdRegister<T>,
-            AutoMath: Math<T>,
-        {
-            $op::<T, crate::danger::$imp, AutoMath, _, _>(
-                a,
-                b,
-            )
+        pub unsafe fn $name(a: &[$from], b: &[$from]) -> f32 {
+            generic_cosine_wide_acc::<$from, $to, crate::danger::$imp, AutoMath>(a, b)
         }
     };
 }
 
-define_dist_impl!(
-    name = generic_fallback_cosine,
-    op = generic_cosine,
-    doc = "../export_docs/dist_cosine.md",
-    Fallback,
-);
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-define_dist_impl!(
-    name = generic_avx2_cosine,
-    op = generic_cosine,
-    doc = "../export_docs/dist_cosine.md",
-    Avx2,
-    target_features = "avx2",
-);
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-define_dist_impl!(
-    name = generic_avx2fma_cosine,
-    op = generic_cosine,
-    doc = "../export_docs/dist_cosine.md",
-    Avx2Fma,
-    target_features = "avx2",
-    "fma"
-);
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
-define_dist_impl!(
-    name = generic_avx512_cosine,
-    op = generic_cosine,
-    doc = "../export_docs/dist_cosine.md",
-    Avx512,
-    target_features = "avx512f",
-    "avx512bw"
-);
-#[cfg(target_arch = "aarch64")]
-define_dist_impl!(
-    name = generic_neon_cosine,
-    op = generic_cosine,
-    doc = "../export_docs/dist_cosine.md",
-    Neon,
-    target_features = "neon",
-);
-
-define_dist_impl!(
-    name = generic_fallback_dot,
-    op = generic_dot,
-    doc = "../export_docs/dist_dot.md",
+define_cosine_wide_acc_impl!(
+    name = u8_fallback_cosine_u32acc,
+    u8 => u32,
     Fallback,
 );
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-define_dist_impl!(
-    name = generic_avx2_dot,
-    op = generic_dot,
-    doc = "../export_docs/dist_dot.md",
+define_cosine_wide_acc_impl!(
+    name = u8_avx2_cosine_u32acc,
+    u8 => u32,
     Avx2,
     target_features = "avx2"
 );
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-define_dist_impl!(
-    name = generic_avx2fma_dot,
-    op = generic_dot,
-    doc = "../export_docs/dist_dot.md",
-    Avx2Fma,
-    target_features = "avx2",
-    "fma"
-);
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
-define_dist_impl!(
-    name = generic_avx512_dot,
-    op = generic_dot,
-    doc = "../export_docs/dist_dot.md",
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_cosine_wide_acc_impl!(
+    name = u8_avx512_cosine_u32acc,
+    u8 => u32,
     Avx512,
     target_features = "avx512f",
     "avx512bw"
 );
 #[cfg(target_arch = "aarch64")]
-define_dist_impl!(
-    name = generic_neon_dot,
-    op = generic_dot,
-    doc = "../export_docs/dist_dot.md",
+define_cosine_wide_acc_impl!(
+    name = u8_neon_cosine_u32acc,
+    u8 => u32,
     Neon,
     target_features = "neon"
 );
-
-define_dist_impl!(
-    name = generic_fallback_squared_euclidean,
-    op = generic_squared_euclidean,
-    doc = "../export_docs/dist_euclidean.md",
+define_cosine_wide_acc_impl!(
+    name = u16_fallback_cosine_u64acc,
+    u16 => u64,
     Fallback,
 );
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-define_dist_impl!(
-    name = generic_avx2_squared_euclidean,
-    op = generic_squared_euclidean,
-    doc = "../export_docs/dist_euclidean.md",
+define_cosine_wide_acc_impl!(
+    name = u16_avx2_cosine_u64acc,
+    u16 => u64,
     Avx2,
     target_features = "avx2"
 );
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-define_dist_impl!(
-    name = generic_avx2fma_squared_euclidean,
-    op = generic_squared_euclidean,
-    doc = "../export_docs/dist_euclidean.md",
-    Avx2Fma,
-    target_features = "avx2",
-    "fma"
-);
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
-define_dist_impl!(
-    name = generic_avx512_squared_euclidean,
-    op = generic_squared_euclidean,
-    doc = "../export_docs/dist_euclidean.md",
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_cosine_wide_acc_impl!(
+    name = u16_avx512_cosine_u64acc,
+    u16 => u64,
     Avx512,
     target_features = "avx512f",
     "avx512bw"
 );
 #[cfg(target_arch = "aarch64")]
-define_dist_impl!(
-    name = generic_neon_squared_euclidean,
-    op = generic_squared_euclidean,
-    doc = "../export_docs/dist_euclidean.md",
+define_cosine_wide_acc_impl!(
+    name = u16_neon_cosine_u64acc,
+    u16 => u64,
     Neon,
     target_features = "neon"
 );
 
-macro_rules! define_norm_impl {
-    ($name:ident, $imp:ident $(,)? $(target_features = $($feat:expr $(,)?)+)?) => {
+macro_rules! define_bit_distance_impl {
+    (
+        name = $name:ident,
+        op = $op:ident -> $ret:ty,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
         #[inline]
         $(#[target_feature($(enable = $feat, )*)])*
-        #[doc = include_str!("../export_docs/dist_norm.md")]
+        #[doc = include_str!($doc)]
         $(
 
             #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
             #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
         )*
-        pub unsafe fn $name<T, B1>(a: B1) -> T
-        where
-            T: Copy,
-            B1: IntoMemLoader<T>,
-            B1::Loader: MemLoader<Value = T>,
-            crate::danger::$imp: SimdRegister<T>,
-            AutoMath: Math<T>,
-        {
-            generic_squared_norm::<T, crate::danger::$imp, AutoMath, _>(a)
+        pub unsafe fn $name(words: usize, a: &[u64], b: &[u64]) -> $ret {
+            $op::<crate::danger::$imp>(words, a, b)
         }
     };
 }
 
-define_norm_impl!(generic_fallback_squared_norm, Fallback);
+define_bit_distance_impl!(
+    name = u64_fallback_binary_hamming,
+    op = generic_binary_hamming_distance -> u32,
+    doc = "../export_docs/dist_binary_hamming.md",
+    Fallback,
+);
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-define_norm_impl!(generic_avx2_squared_norm, Avx2, target_features = "avx2");
+define_bit_distance_impl!(
+    name = u64_avx2_binary_hamming,
+    op = generic_binary_hamming_distance -> u32,
+    doc = "../export_docs/dist_binary_hamming.md",
+    Avx2,
+    target_features = "avx2",
+    "popcnt"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_bit_distance_impl!(
+    name = u64_avx512_binary_hamming,
+    op = generic_binary_hamming_distance -> u32,
+    doc = "../export_docs/dist_binary_hamming.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw",
+    "popcnt"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_bit_distance_impl!(
+    name = u64_avx512vpopcntdq_binary_hamming,
+    op = generic_binary_hamming_distance -> u32,
+    doc = "../export_docs/dist_binary_hamming.md",
+    Avx512Vpopcntdq,
+    target_features = "avx512f",
+    "avx512bw",
+    "avx512vpopcntdq",
+    "popcnt"
+);
+#[cfg(target_arch = "aarch64")]
+define_bit_distance_impl!(
+    name = u64_neon_binary_hamming,
+    op = generic_binary_hamming_distance -> u32,
+    doc = "../export_docs/dist_binary_hamming.md",
+    Neon,
+    target_features = "neon"
+);
+define_bit_distance_impl!(
+    name = u64_fallback_binary_jaccard,
+    op = generic_binary_jaccard_similarity -> f32,
+    doc = "../export_docs/dist_binary_jaccard.md",
+    Fallback,
+);
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-define_norm_impl!(
-    generic_avx2fma_squared_norm,
-    Avx2Fma,
+define_bit_distance_impl!(
+    name = u64_avx2_binary_jaccard,
+    op = generic_binary_jaccard_similarity -> f32,
+    doc = "../export_docs/dist_binary_jaccard.md",
+    Avx2,
     target_features = "avx2",
-    "fma",
+    "popcnt"
 );
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
-define_norm_impl!(
-    generic_avx512_squared_norm,
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_bit_distance_impl!(
+    name = u64_avx512_binary_jaccard,
+    op = generic_binary_jaccard_similarity -> f32,
+    doc = "../export_docs/dist_binary_jaccard.md",
     Avx512,
     target_features = "avx512f",
-    "avx512bw"
+    "avx512bw",
+    "popcnt"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_bit_distance_impl!(
+    name = u64_avx512vpopcntdq_binary_jaccard,
+    op = generic_binary_jaccard_similarity -> f32,
+    doc = "../export_docs/dist_binary_jaccard.md",
+    Avx512Vpopcntdq,
+    target_features = "avx512f",
+    "avx512bw",
+    "avx512vpopcntdq",
+    "popcnt"
 );
 #[cfg(target_arch = "aarch64")]
-define_norm_impl!(generic_neon_squared_norm, Neon, target_features = "neon");
+define_bit_distance_impl!(
+    name = u64_neon_binary_jaccard,
+    op = generic_binary_jaccard_similarity -> f32,
+    doc = "../export_docs/dist_binary_jaccard.md",
+    Neon,
+    target_features = "neon"
+);
 
 #[cfg(test)]
 mod tests {
@@ -232,7 +2009,33 @@ mod tests {
                             "Routine result does not match expected, {actual:?} vs {expected:?}",
                         );
                     }
+                }
+            )*
+        };
+    }
 
+    // Integer types like `i8` do not behave well with the rng generated vectors and the
+    // cosine logic, so the batch variant is only tested over the float types.
+    macro_rules! define_cosine_batch_test {
+        ($variant:ident, types = $($t:ident $(,)?)+) => {
+            $(
+                paste::paste! {
+                    #[test]
+                    fn [< $variant _cosine_batch_ $t >]() {
+                        let (query, _) = crate::test_utils::get_sample_vectors::<$t>(53);
+                        let (batch, _) = crate::test_utils::get_sample_vectors::<$t>(53 * 5);
+
+                        let mut result = vec![$t::default(); 5];
+                        unsafe { [< $variant _cosine_batch >](&query, &batch, &mut result) };
+
+                        for (candidate, actual) in batch.chunks(53).zip(result) {
+                            let expected: $t = crate::test_utils::simple_cosine(&query, candidate);
+                            assert!(
+                                AutoMath::is_close(actual, expected),
+                                "Routine result does not match expected, {actual:?} vs {expected:?}",
+                            );
+                        }
+                    }
                 }
             )*
         };
@@ -266,6 +2069,133 @@ mod tests {
                         );
                     }
 
+                    #[test]
+                    fn [< $variant _dot_batch_ $t >]() {
+                        let (query, _) = crate::test_utils::get_sample_vectors::<$t>(53);
+                        let (batch, _) = crate::test_utils::get_sample_vectors::<$t>(53 * 5);
+
+                        let mut result = vec![$t::default(); 5];
+                        unsafe { [< $variant _dot_batch >](&query, &batch, &mut result) };
+
+                        for (candidate, actual) in batch.chunks(53).zip(result) {
+                            let expected: $t = crate::test_utils::simple_dot(&query, candidate);
+                            assert!(
+                                AutoMath::is_close(actual, expected),
+                                "Routine result does not match expected, {actual:?} vs {expected:?}",
+                            );
+                        }
+                    }
+
+                    #[test]
+                    fn [< $variant _euclidean_batch_ $t >]() {
+                        let (query, _) = crate::test_utils::get_sample_vectors::<$t>(53);
+                        let (batch, _) = crate::test_utils::get_sample_vectors::<$t>(53 * 5);
+
+                        let mut result = vec![$t::default(); 5];
+                        unsafe { [< $variant _squared_euclidean_batch >](&query, &batch, &mut result) };
+
+                        for (candidate, actual) in batch.chunks(53).zip(result) {
+                            let expected: $t = crate::test_utils::simple_euclidean(&query, candidate);
+                            assert!(
+                                AutoMath::is_close(actual, expected),
+                                "Routine result does not match expected, {actual:?} vs {expected:?}",
+                            );
+                        }
+                    }
+
+                    #[test]
+                    fn [< $variant _dot_matrix_ $t >]() {
+                        let (a, b) = crate::test_utils::get_sample_vectors::<$t>(53 * 15);
+
+                        let mut result = vec![$t::default(); 15 * 15];
+                        unsafe { [< $variant _dot_matrix >](53, 15, 15, &a, &b, &mut result) };
+
+                        for (i, x) in a.chunks(53).enumerate() {
+                            for (j, y) in b.chunks(53).enumerate() {
+                                let actual = result[i * 15 + j];
+                                let expected: $t = crate::test_utils::simple_dot(x, y);
+                                assert!(
+                                    AutoMath::is_close(actual, expected),
+                                    "Routine result does not match expected, {actual:?} vs {expected:?}",
+                                );
+                            }
+                        }
+                    }
+
+                    #[test]
+                    fn [< $variant _euclidean_matrix_ $t >]() {
+                        let (a, b) = crate::test_utils::get_sample_vectors::<$t>(53 * 15);
+
+                        let mut result = vec![$t::default(); 15 * 15];
+                        unsafe { [< $variant _squared_euclidean_matrix >](53, 15, 15, &a, &b, &mut result) };
+
+                        for (i, x) in a.chunks(53).enumerate() {
+                            for (j, y) in b.chunks(53).enumerate() {
+                                let actual = result[i * 15 + j];
+                                let expected: $t = crate::test_utils::simple_euclidean(x, y);
+                                assert!(
+                                    AutoMath::is_close(actual, expected),
+                                    "Routine result does not match expected, {actual:?} vs {expected:?}",
+                                );
+                            }
+                        }
+                    }
+
+                    #[test]
+                    fn [< $variant _gram_matrix_ $t >]() {
+                        let (matrix, _) = crate::test_utils::get_sample_vectors::<$t>(53 * 15);
+
+                        let mut result = vec![$t::default(); 15 * 15];
+                        unsafe { [< $variant _gram_matrix >](15, 53, &matrix, &mut result) };
+
+                        for (i, x) in matrix.chunks(53).enumerate() {
+                            for (j, y) in matrix.chunks(53).enumerate() {
+                                let actual = result[i * 15 + j];
+                                let expected: $t = crate::test_utils::simple_dot(x, y);
+                                assert!(
+                                    AutoMath::is_close(actual, expected),
+                                    "Routine result does not match expected, {actual:?} vs {expected:?}",
+                                );
+                            }
+                        }
+                    }
+
+                    #[test]
+                    fn [< $variant _batch_dot_ $t >]() {
+                        let (query, _) = crate::test_utils::get_sample_vectors::<$t>(53);
+                        let (matrix, _) = crate::test_utils::get_sample_vectors::<$t>(53 * 15);
+
+                        let mut scores = vec![$t::default(); 15];
+                        unsafe { [< $variant _batch_dot >](15, 53, &matrix, &query, &mut scores) };
+
+                        for (row, actual) in matrix.chunks(53).zip(scores) {
+                            let expected: $t = crate::test_utils::simple_dot(row, &query);
+                            assert!(
+                                AutoMath::is_close(actual, expected),
+                                "Routine result does not match expected, {actual:?} vs {expected:?}",
+                            );
+                        }
+                    }
+
+                    #[test]
+                    fn [< $variant _pairwise_distances_ $t >]() {
+                        let (matrix, _) = crate::test_utils::get_sample_vectors::<$t>(53 * 15);
+
+                        let mut result = vec![$t::default(); 15 * 15];
+                        unsafe { [< $variant _pairwise_distances >](15, 53, &matrix, &mut result) };
+
+                        for (i, x) in matrix.chunks(53).enumerate() {
+                            for (j, y) in matrix.chunks(53).enumerate().skip(i + 1) {
+                                let actual = result[i * 15 + j];
+                                let expected: $t = crate::test_utils::simple_euclidean(x, y);
+                                assert!(
+                                    AutoMath::is_close(actual, expected),
+                                    "Routine result does not match expected, {actual:?} vs {expected:?}",
+                                );
+                            }
+                        }
+                    }
+
                     #[test]
                     fn [< $variant _norm_ $t >]() {
                         let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
@@ -296,6 +2226,7 @@ mod tests {
         u64
     );
     define_cosine_extra_test!(generic_fallback, types = f32, f64, i8, u8);
+    define_cosine_batch_test!(generic_fallback, types = f32, f64);
 
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
@@ -319,6 +2250,11 @@ mod tests {
         target_feature = "avx2"
     ))]
     define_cosine_extra_test!(generic_avx2, types = f32, f64, i8, u8);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    define_cosine_batch_test!(generic_avx2, types = f32, f64);
 
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
@@ -332,10 +2268,16 @@ mod tests {
         target_feature = "fma"
     ))]
     define_cosine_extra_test!(generic_avx2fma, types = f32, f64);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2",
+        target_feature = "fma"
+    ))]
+    define_cosine_batch_test!(generic_avx2fma, types = f32, f64);
 
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly",
+        feature = "stable-avx512",
         target_feature = "avx512f"
     ))]
     define_distance_test!(
@@ -353,10 +2295,16 @@ mod tests {
     );
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly",
+        feature = "stable-avx512",
         target_feature = "avx512f"
     ))]
     define_cosine_extra_test!(generic_avx512, types = f32, f64, i8, u8);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512f"
+    ))]
+    define_cosine_batch_test!(generic_avx512, types = f32, f64);
 
     #[cfg(target_arch = "aarch64")]
     define_distance_test!(
@@ -374,4 +2322,69 @@ mod tests {
     );
     #[cfg(target_arch = "aarch64")]
     define_cosine_extra_test!(generic_neon, types = f32, f64, i8, u8);
+    #[cfg(target_arch = "aarch64")]
+    define_cosine_batch_test!(generic_neon, types = f32, f64);
+
+    macro_rules! define_widen_test {
+        ($variant:ident, types = $($t:ident $(,)?)+) => {
+            $(
+                paste::paste! {
+                    #[test]
+                    fn [< $variant _dot_widen_ $t >]() {
+                        let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                        let actual = unsafe { [< $variant _dot_widen >](&l1, &l2) };
+                        let expected = l1.iter()
+                            .zip(l2.iter())
+                            .map(|(a, b)| *a as i32 * *b as i32)
+                            .sum::<i32>();
+                        assert_eq!(actual, expected, "Routine result does not match expected");
+                    }
+
+                    #[test]
+                    fn [< $variant _squared_euclidean_widen_ $t >]() {
+                        let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                        let actual = unsafe { [< $variant _squared_euclidean_widen >](&l1, &l2) };
+                        let expected = l1.iter()
+                            .zip(l2.iter())
+                            .map(|(a, b)| (*a as i32 - *b as i32).pow(2))
+                            .sum::<i32>();
+                        assert_eq!(actual, expected, "Routine result does not match expected");
+                    }
+                }
+            )*
+        };
+    }
+
+    define_widen_test!(generic_fallback, types = i8, u8);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    define_widen_test!(generic_avx2, types = i8, u8);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512vnni"
+    ))]
+    define_widen_test!(generic_avx512vnni, types = i8, u8);
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512vnni"
+    ))]
+    #[test]
+    fn test_xany_avx512_vnni_dot() {
+        let (l1, l2) = crate::test_utils::get_sample_vectors::<u8>(533);
+        let expected = unsafe { generic_avx512vnni_dot_widen(&l1, &l2) };
+        assert_eq!(unsafe { u8_xany_avx512_vnni_dot(&l1, &l2) }, expected);
+
+        let (l1, l2) = crate::test_utils::get_sample_vectors::<i8>(533);
+        let expected = unsafe { generic_avx512vnni_dot_widen(&l1, &l2) };
+        assert_eq!(unsafe { i8_xany_avx512_vnni_dot(&l1, &l2) }, expected);
+    }
+    #[cfg(target_arch = "aarch64")]
+    define_widen_test!(generic_neon, types = i8, u8);
 }
@@ -0,0 +1,583 @@
+//! Float only operations
+//!
+//! I.e. Hypot, NaN checks...
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::{
+    generic_all_finite,
+    generic_any_infinite,
+    generic_any_nan,
+    generic_hypot_vertical,
+    generic_is_finite_vector,
+    generic_is_infinite_vector,
+    generic_is_nan_vector,
+    generic_l2_normalize_inplace,
+    generic_l2_normalize_vertical,
+    SimdFloatRegister,
+};
+use crate::math::{AutoMath, Math};
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+macro_rules! define_float_impls {
+    (
+        hypot = $hypot_name:ident,
+        l2_normalize = $l2_normalize_name:ident,
+        l2_normalize_inplace = $l2_normalize_inplace_name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/float_hypot_vertical.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $hypot_name<T, B1, B2, B3>(
+            a: B1,
+            b: B2,
+            result: &mut [B3],
+        )
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            B2: IntoMemLoader<T>,
+            B2::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdFloatRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_hypot_vertical::<T, crate::danger::$imp, AutoMath, B1, B2, B3>(
+                a,
+                b,
+                result,
+            )
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/float_l2_normalize_vertical.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $l2_normalize_name<T>(
+            dims: usize,
+            a: &[T],
+            result: &mut [T],
+        )
+        where
+            T: Copy,
+            crate::danger::$imp: SimdFloatRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_l2_normalize_vertical::<T, crate::danger::$imp, AutoMath>(
+                dims,
+                a,
+                result,
+            )
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/float_l2_normalize_inplace.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $l2_normalize_inplace_name<T>(a: &mut [T])
+        where
+            T: Copy,
+            crate::danger::$imp: SimdFloatRegister<T>,
+            AutoMath: Math<T>,
+        {
+            generic_l2_normalize_inplace::<T, crate::danger::$imp, AutoMath>(a)
+        }
+    };
+}
+
+macro_rules! define_float_class_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1, B2>(a: B1, result: &mut [B2])
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdFloatRegister<T>,
+            AutoMath: Math<T>,
+            for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B1, B2>(a, result)
+        }
+    };
+}
+
+macro_rules! define_float_class_reduce_op {
+    (
+        name = $name:ident,
+        op = $op:ident,
+        doc = $doc:expr,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!($doc)]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $name<T, B1>(a: B1) -> bool
+        where
+            T: Copy,
+            B1: IntoMemLoader<T>,
+            B1::Loader: MemLoader<Value = T>,
+            crate::danger::$imp: SimdFloatRegister<T>,
+            AutoMath: Math<T>,
+        {
+            $op::<T, crate::danger::$imp, AutoMath, B1>(a)
+        }
+    };
+}
+
+define_float_impls!(
+    hypot = generic_fallback_hypot_vertical,
+    l2_normalize = generic_fallback_l2_normalize_vertical,
+    l2_normalize_inplace = generic_fallback_l2_normalize_inplace,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_float_impls!(
+    hypot = generic_avx2_hypot_vertical,
+    l2_normalize = generic_avx2_l2_normalize_vertical,
+    l2_normalize_inplace = generic_avx2_l2_normalize_inplace,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_float_impls!(
+    hypot = generic_avx2fma_hypot_vertical,
+    l2_normalize = generic_avx2fma_l2_normalize_vertical,
+    l2_normalize_inplace = generic_avx2fma_l2_normalize_inplace,
+    Avx2Fma,
+    target_features = "avx2",
+    "fma"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_float_impls!(
+    hypot = generic_avx512_hypot_vertical,
+    l2_normalize = generic_avx512_l2_normalize_vertical,
+    l2_normalize_inplace = generic_avx512_l2_normalize_inplace,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_float_impls!(
+    hypot = generic_neon_hypot_vertical,
+    l2_normalize = generic_neon_l2_normalize_vertical,
+    l2_normalize_inplace = generic_neon_l2_normalize_inplace,
+    Neon,
+    target_features = "neon"
+);
+
+define_float_class_op!(
+    name = generic_fallback_is_nan_vector,
+    op = generic_is_nan_vector,
+    doc = "../export_docs/float_is_nan_vector.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_float_class_op!(
+    name = generic_avx2_is_nan_vector,
+    op = generic_is_nan_vector,
+    doc = "../export_docs/float_is_nan_vector.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_float_class_op!(
+    name = generic_avx512_is_nan_vector,
+    op = generic_is_nan_vector,
+    doc = "../export_docs/float_is_nan_vector.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_float_class_op!(
+    name = generic_neon_is_nan_vector,
+    op = generic_is_nan_vector,
+    doc = "../export_docs/float_is_nan_vector.md",
+    Neon,
+    target_features = "neon"
+);
+define_float_class_op!(
+    name = generic_fallback_is_finite_vector,
+    op = generic_is_finite_vector,
+    doc = "../export_docs/float_is_finite_vector.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_float_class_op!(
+    name = generic_avx2_is_finite_vector,
+    op = generic_is_finite_vector,
+    doc = "../export_docs/float_is_finite_vector.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_float_class_op!(
+    name = generic_avx512_is_finite_vector,
+    op = generic_is_finite_vector,
+    doc = "../export_docs/float_is_finite_vector.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_float_class_op!(
+    name = generic_neon_is_finite_vector,
+    op = generic_is_finite_vector,
+    doc = "../export_docs/float_is_finite_vector.md",
+    Neon,
+    target_features = "neon"
+);
+define_float_class_op!(
+    name = generic_fallback_is_infinite_vector,
+    op = generic_is_infinite_vector,
+    doc = "../export_docs/float_is_infinite_vector.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_float_class_op!(
+    name = generic_avx2_is_infinite_vector,
+    op = generic_is_infinite_vector,
+    doc = "../export_docs/float_is_infinite_vector.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_float_class_op!(
+    name = generic_avx512_is_infinite_vector,
+    op = generic_is_infinite_vector,
+    doc = "../export_docs/float_is_infinite_vector.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_float_class_op!(
+    name = generic_neon_is_infinite_vector,
+    op = generic_is_infinite_vector,
+    doc = "../export_docs/float_is_infinite_vector.md",
+    Neon,
+    target_features = "neon"
+);
+define_float_class_reduce_op!(
+    name = generic_fallback_any_nan,
+    op = generic_any_nan,
+    doc = "../export_docs/float_any_nan.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_float_class_reduce_op!(
+    name = generic_avx2_any_nan,
+    op = generic_any_nan,
+    doc = "../export_docs/float_any_nan.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_float_class_reduce_op!(
+    name = generic_avx512_any_nan,
+    op = generic_any_nan,
+    doc = "../export_docs/float_any_nan.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_float_class_reduce_op!(
+    name = generic_neon_any_nan,
+    op = generic_any_nan,
+    doc = "../export_docs/float_any_nan.md",
+    Neon,
+    target_features = "neon"
+);
+define_float_class_reduce_op!(
+    name = generic_fallback_any_infinite,
+    op = generic_any_infinite,
+    doc = "../export_docs/float_any_infinite.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_float_class_reduce_op!(
+    name = generic_avx2_any_infinite,
+    op = generic_any_infinite,
+    doc = "../export_docs/float_any_infinite.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_float_class_reduce_op!(
+    name = generic_avx512_any_infinite,
+    op = generic_any_infinite,
+    doc = "../export_docs/float_any_infinite.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_float_class_reduce_op!(
+    name = generic_neon_any_infinite,
+    op = generic_any_infinite,
+    doc = "../export_docs/float_any_infinite.md",
+    Neon,
+    target_features = "neon"
+);
+define_float_class_reduce_op!(
+    name = generic_fallback_all_finite,
+    op = generic_all_finite,
+    doc = "../export_docs/float_all_finite.md",
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_float_class_reduce_op!(
+    name = generic_avx2_all_finite,
+    op = generic_all_finite,
+    doc = "../export_docs/float_all_finite.md",
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_float_class_reduce_op!(
+    name = generic_avx512_all_finite,
+    op = generic_all_finite,
+    doc = "../export_docs/float_all_finite.md",
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_float_class_reduce_op!(
+    name = generic_neon_all_finite,
+    op = generic_all_finite,
+    doc = "../export_docs/float_all_finite.md",
+    Neon,
+    target_features = "neon"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! define_inner_test {
+        ($variant:ident, op = $op:ident, ty = $t:ident) => {
+            paste::paste! {
+                #[test]
+                fn [< $variant _ $op _value_ $t >]() {
+                    let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let mut result = vec![$t::default(); 533];
+                    unsafe { [< $variant _ $op _vertical >](&l1, 2 as $t, &mut result) };
+
+                    let expected = l1.iter()
+                        .copied()
+                        .map(|v| v.$op(2 as $t))
+                        .collect::<Vec<_>>();
+                    for (value, expected) in result.into_iter().zip(expected) {
+                        assert!(
+                            AutoMath::is_close(value, expected),
+                            "Routine result does not match expected {value} vs {expected}",
+                        );
+                    }
+                }
+
+                #[test]
+                fn [< $variant _ $op _vector_ $t >]() {
+                    let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                    let mut result = vec![$t::default(); 533];
+                    unsafe { [< $variant _ $op _vertical >](&l1, &l2, &mut result) };
+
+                    let expected = l1.iter()
+                        .copied()
+                        .zip(l2.iter().copied())
+                        .map(|(a, b)| a.$op(b))
+                        .collect::<Vec<_>>();
+                    for (value, expected) in result.into_iter().zip(expected) {
+                        assert!(
+                            AutoMath::is_close(value, expected),
+                            "Routine result does not match expected {value} vs {expected}",
+                        );
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! define_float_test {
+        ($variant:ident, types = $($t:ident $(,)?)+) => {
+            $(
+                define_inner_test!($variant, op = hypot, ty = $t);
+
+                paste::paste! {
+                    #[test]
+                    fn [< $variant _l2_normalize_ $t >]() {
+                        let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                        let norm = l1.iter().map(|v| v * v).sum::<$t>().sqrt();
+                        let expected = l1.iter().map(|v| v / norm).collect::<Vec<_>>();
+
+                        let mut result = vec![$t::default(); 533];
+                        unsafe { [< $variant _l2_normalize_vertical >](533, &l1, &mut result) };
+                        for (value, expected) in result.iter().copied().zip(expected.iter().copied()) {
+                            assert!(
+                                AutoMath::is_close(value, expected),
+                                "Routine result does not match expected {value} vs {expected}",
+                            );
+                        }
+
+                        let mut inplace = l1.clone();
+                        unsafe { [< $variant _l2_normalize_inplace >](&mut inplace) };
+                        assert_eq!(inplace, result, "In-place result does not match out-of-place result");
+
+                        let mut zeroes = vec![$t::default(); 533];
+                        unsafe { [< $variant _l2_normalize_inplace >](&mut zeroes) };
+                        assert!(zeroes.iter().all(|v| *v == 0.0), "Zero vector should remain zero");
+                    }
+                }
+            )*
+        };
+    }
+
+    macro_rules! define_class_test {
+        ($variant:ident, types = $($t:ident $(,)?)+) => {
+            $(
+                paste::paste! {
+                    #[test]
+                    fn [< $variant _float_class_ $t >]() {
+                        let (mut l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+                        l1[3] = $t::NAN;
+                        l1[100] = $t::INFINITY;
+                        l1[250] = $t::NEG_INFINITY;
+                        l1[400] = $t::MIN_POSITIVE / 2.0;
+                        l1[532] = -$t::NAN;
+
+                        let mut result = vec![$t::default(); 533];
+                        unsafe { [< $variant _is_nan_vector >](&l1, &mut result) };
+                        let expected = l1.iter()
+                            .map(|v| AutoMath::cast_bool(v.is_nan()))
+                            .collect::<Vec<_>>();
+                        assert_eq!(result, expected, "is_nan mismatch");
+
+                        unsafe { [< $variant _is_finite_vector >](&l1, &mut result) };
+                        let expected = l1.iter()
+                            .map(|v| AutoMath::cast_bool(v.is_finite()))
+                            .collect::<Vec<_>>();
+                        assert_eq!(result, expected, "is_finite mismatch");
+
+                        unsafe { [< $variant _is_infinite_vector >](&l1, &mut result) };
+                        let expected = l1.iter()
+                            .map(|v| AutoMath::cast_bool(v.is_infinite()))
+                            .collect::<Vec<_>>();
+                        assert_eq!(result, expected, "is_infinite mismatch");
+
+                        unsafe {
+                            assert!([< $variant _any_nan >](&l1));
+                            assert!([< $variant _any_infinite >](&l1));
+                            assert!(![< $variant _all_finite >](&l1));
+
+                            let finite = &l1[4..100];
+                            assert!(![< $variant _any_nan >](finite));
+                            assert!(![< $variant _any_infinite >](finite));
+                            assert!([< $variant _all_finite >](finite));
+
+                            let empty: &[$t] = &[];
+                            assert!(![< $variant _any_nan >](empty));
+                            assert!(![< $variant _any_infinite >](empty));
+                            assert!([< $variant _all_finite >](empty));
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    define_float_test!(generic_fallback, types = f32, f64);
+    define_class_test!(generic_fallback, types = f32, f64);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    define_class_test!(generic_avx2, types = f32, f64);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512f"
+    ))]
+    define_class_test!(generic_avx512, types = f32, f64);
+    #[cfg(target_arch = "aarch64")]
+    define_class_test!(generic_neon, types = f32, f64);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    define_float_test!(generic_avx2, types = f32, f64);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2",
+        target_feature = "fma"
+    ))]
+    define_float_test!(generic_avx2fma, types = f32, f64);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512f"
+    ))]
+    define_float_test!(generic_avx512, types = f32, f64);
+    #[cfg(target_arch = "aarch64")]
+    define_float_test!(generic_neon, types = f32, f64);
+}
@@ -0,0 +1,246 @@
+//! Gather, scatter and compaction operations
+//!
+//! Only AVX2 provides hardware gather instructions for the 32-bit types and a `movemask`
+//! for the compaction bitmasks, so the other architectures use the fallback implementation.
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::{
+    generic_compact,
+    generic_gather,
+    generic_mask_to_indices,
+    generic_scatter,
+    SimdRegister,
+};
+use crate::math::{AutoMath, Math};
+
+macro_rules! define_gather_impls {
+    (
+        gather = $gather_name:ident,
+        scatter = $scatter_name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/gather.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $gather_name<T, B>(
+            dims: usize,
+            a: &[T],
+            indices: &[u32],
+            result: &mut [B],
+        )
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_gather::<T, crate::danger::$imp, B>(dims, a, indices, result)
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/scatter.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $scatter_name<T>(
+            dims: usize,
+            a: &[T],
+            indices: &[u32],
+            result: &mut [T],
+        )
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+        {
+            generic_scatter::<T, crate::danger::$imp>(dims, a, indices, result)
+        }
+    };
+}
+
+macro_rules! define_compact_impls {
+    (
+        compact = $compact_name:ident,
+        mask_to_indices = $mask_to_indices_name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/compact.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $compact_name<T, B>(
+            dims: usize,
+            mask: &[T],
+            values: &[T],
+            result: &mut [B],
+        ) -> usize
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+            for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_compact::<T, crate::danger::$imp, AutoMath, B>(dims, mask, values, result)
+        }
+
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/mask_to_indices.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $mask_to_indices_name<T, B>(
+            dims: usize,
+            mask: &[T],
+            result: &mut [B],
+        ) -> usize
+        where
+            T: Copy,
+            AutoMath: Math<T>,
+            crate::danger::$imp: SimdRegister<T>,
+            for<'a> &'a mut [B]: WriteOnlyBuffer<Item = u32>,
+        {
+            generic_mask_to_indices::<T, crate::danger::$imp, AutoMath, B>(dims, mask, result)
+        }
+    };
+}
+
+define_gather_impls!(
+    gather = generic_fallback_gather,
+    scatter = generic_fallback_scatter,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_gather_impls!(
+    gather = generic_avx2_gather,
+    scatter = generic_avx2_scatter,
+    Avx2,
+    target_features = "avx2"
+);
+
+define_compact_impls!(
+    compact = generic_fallback_compact,
+    mask_to_indices = generic_fallback_mask_to_indices,
+    Fallback,
+);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_compact_impls!(
+    compact = generic_avx2_compact,
+    mask_to_indices = generic_avx2_mask_to_indices,
+    Avx2,
+    target_features = "avx2"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! define_gather_test {
+        ($variant:ident, types = $($t:ident $(,)?)+) => {
+            $(
+                paste::paste! {
+                    #[test]
+                    fn [< $variant _gather_ $t >]() {
+                        let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+                        let indices = (0..1043u32).map(|i| (i * 31) % 533).collect::<Vec<_>>();
+
+                        let mut result = vec![$t::default(); indices.len()];
+                        unsafe { [< $variant _gather >](indices.len(), &l1, &indices, &mut result) };
+
+                        let expected = indices.iter().map(|&i| l1[i as usize]).collect::<Vec<_>>();
+                        assert_eq!(result, expected, "Routine result does not match expected");
+                    }
+
+                    #[test]
+                    fn [< $variant _scatter_ $t >]() {
+                        let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+                        let indices = (0..533u32).map(|i| (i * 31) % 67).collect::<Vec<_>>();
+
+                        let mut result = vec![$t::default(); 67];
+                        unsafe { [< $variant _scatter >](l1.len(), &l1, &indices, &mut result) };
+
+                        let mut expected = vec![$t::default(); 67];
+                        for (&value, &i) in l1.iter().zip(indices.iter()) {
+                            expected[i as usize] = value;
+                        }
+                        assert_eq!(result, expected, "Routine result does not match expected");
+                    }
+
+                    #[test]
+                    fn [< $variant _compact_ $t >]() {
+                        let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(533);
+                        let mask = l2
+                            .iter()
+                            .enumerate()
+                            .map(|(i, &v)| if i % 5 < 2 { v } else { $t::default() })
+                            .collect::<Vec<_>>();
+
+                        let mut result = vec![$t::default(); l1.len()];
+                        let count = unsafe { [< $variant _compact >](l1.len(), &mask, &l1, &mut result) };
+
+                        let expected = mask
+                            .iter()
+                            .zip(l1.iter())
+                            .filter(|(&m, _)| m != $t::default())
+                            .map(|(_, &v)| v)
+                            .collect::<Vec<_>>();
+                        assert_eq!(result[..count], expected, "Routine result does not match expected");
+
+                        let mut indices = vec![0; mask.len()];
+                        let count = unsafe { [< $variant _mask_to_indices >](mask.len(), &mask, &mut indices) };
+
+                        let expected = (0..mask.len() as u32)
+                            .filter(|&i| mask[i as usize] != $t::default())
+                            .collect::<Vec<_>>();
+                        assert_eq!(indices[..count], expected, "Routine result does not match expected");
+                    }
+                }
+            )*
+        };
+    }
+
+    define_gather_test!(
+        generic_fallback,
+        types = f32,
+        f64,
+        i8,
+        i16,
+        i32,
+        i64,
+        u8,
+        u16,
+        u32,
+        u64
+    );
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    define_gather_test!(
+        generic_avx2,
+        types = f32,
+        f64,
+        i8,
+        i16,
+        i32,
+        i64,
+        u8,
+        u16,
+        u32,
+        u64
+    );
+}
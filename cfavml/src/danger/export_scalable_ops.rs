@@ -0,0 +1,82 @@
+//! Operations over scalable vector registers
+//!
+//! These routines are specialised for SVE, where the vector length is only known at
+//! runtime and the tail of each buffer is handled with a partial predicate.
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::{
+    generic_scalable_add_vertical,
+    generic_scalable_dot,
+    SimdScalableRegister,
+};
+
+#[inline]
+#[target_feature(enable = "sve")]
+#[doc = include_str!("../export_docs/scalable_dot.md")]
+/// - **`+sve`** CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB.
+pub unsafe fn generic_sve_dot<T>(a: &[T], b: &[T]) -> T
+where
+    T: Copy,
+    crate::danger::Sve: SimdScalableRegister<T>,
+{
+    generic_scalable_dot::<T, crate::danger::Sve>(a, b)
+}
+
+#[inline]
+#[target_feature(enable = "sve")]
+#[doc = include_str!("../export_docs/scalable_add_vertical.md")]
+/// - **`+sve`** CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB.
+pub unsafe fn generic_sve_add_vertical<T, B>(a: &[T], b: &[T], result: &mut [B])
+where
+    T: Copy,
+    crate::danger::Sve: SimdScalableRegister<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    generic_scalable_add_vertical::<T, crate::danger::Sve, B>(a, b, result)
+}
+
+// The SVE results are validated against the Neon routines on the same hardware.
+#[cfg(all(test, target_feature = "sve"))]
+mod tests {
+    use super::*;
+    use crate::danger::export_arithmetic_ops::generic_neon_add_vertical;
+    use crate::danger::export_distance_ops::generic_neon_dot;
+    use crate::math::{AutoMath, Math};
+
+    macro_rules! define_sve_test {
+        ($($t:ident $(,)?)+) => {
+            $(
+                paste::paste! {
+                    #[test]
+                    fn [< generic_sve_dot_ $t >]() {
+                        for size in [0, 1, 3, 17, 64, 533, 1043] {
+                            let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(size);
+
+                            let actual = unsafe { generic_sve_dot(&l1, &l2) };
+                            let expected = unsafe { generic_neon_dot(&l1, &l2) };
+                            assert!(
+                                AutoMath::is_close(actual, expected),
+                                "Routine result does not match expected {actual} vs {expected} for size {size}",
+                            );
+                        }
+                    }
+
+                    #[test]
+                    fn [< generic_sve_add_vertical_ $t >]() {
+                        for size in [0, 1, 3, 17, 64, 533, 1043] {
+                            let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(size);
+
+                            let mut actual = vec![$t::default(); size];
+                            unsafe { generic_sve_add_vertical(&l1, &l2, &mut actual) };
+                            let mut expected = vec![$t::default(); size];
+                            unsafe { generic_neon_add_vertical(&l1, &l2, &mut expected) };
+                            assert_eq!(actual, expected, "Routine result does not match expected");
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    define_sve_test!(f32, f64);
+}
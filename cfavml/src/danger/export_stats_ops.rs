@@ -0,0 +1,136 @@
+//! Statistical operations
+//!
+//! These are only implemented for the types a window size can be cast to,
+//! i.e. `f32`, `f64`, `i32` and `i64`.
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::{generic_moving_average, SimdRegister};
+use crate::math::{AutoMath, CastAs, Math, WindowSum};
+
+macro_rules! define_stats_impls {
+    (
+        moving_average = $moving_average_name:ident,
+        $imp:ident $(,)?
+        $(target_features = $($feat:expr $(,)?)+)?
+    ) => {
+        #[inline]
+        $(#[target_feature($(enable = $feat, )*)])*
+        #[doc = include_str!("../export_docs/stats_moving_average.md")]
+        $(
+
+            #[doc = concat!("- ", $("**`+", $feat, "`** ", )*)]
+            #[doc = "CPU features are available at runtime. Running on hardware _without_ this feature available will cause immediate UB."]
+        )*
+        pub unsafe fn $moving_average_name<T, B>(
+            dims: usize,
+            window: usize,
+            a: &[T],
+            result: &mut [B],
+        )
+        where
+            T: Copy,
+            crate::danger::$imp: SimdRegister<T>,
+            AutoMath: Math<T>,
+            T: WindowSum,
+            usize: CastAs<T>,
+            for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+        {
+            generic_moving_average::<T, crate::danger::$imp, AutoMath, B>(
+                dims,
+                window,
+                a,
+                result,
+            )
+        }
+    };
+}
+
+define_stats_impls!(moving_average = generic_fallback_moving_average, Fallback,);
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+define_stats_impls!(
+    moving_average = generic_avx2_moving_average,
+    Avx2,
+    target_features = "avx2"
+);
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+define_stats_impls!(
+    moving_average = generic_avx512_moving_average,
+    Avx512,
+    target_features = "avx512f",
+    "avx512bw"
+);
+#[cfg(target_arch = "aarch64")]
+define_stats_impls!(
+    moving_average = generic_neon_moving_average,
+    Neon,
+    target_features = "neon"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! define_stats_test {
+        ($variant:ident, types = $($t:ident $(,)?)+) => {
+            $(
+                paste::paste! {
+                    #[test]
+                    fn [< $variant _moving_average_ $t >]() {
+                        let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(533);
+
+                        let mut result = vec![$t::default(); 533 - 17 + 1];
+                        unsafe { [< $variant _moving_average >](533, 17, &l1, &mut result) };
+
+                        let expected = l1
+                            .windows(17)
+                            .map(|values| {
+                                let total = values
+                                    .iter()
+                                    .fold($t::empty_sum(), |acc, &v| v.add_to_sum(acc));
+                                $t::window_mean(total, 17)
+                            })
+                            .collect::<Vec<$t>>();
+                        for (actual, expected) in result.into_iter().zip(expected) {
+                            assert!(
+                                AutoMath::is_close(actual, expected),
+                                "Routine result does not match expected {actual} vs {expected}",
+                            );
+                        }
+                    }
+                }
+            )*
+
+            paste::paste! {
+                #[test]
+                fn [< $variant _moving_average_window_sum_overflow >]() {
+                    let mut result = [0; 1];
+                    unsafe { [< $variant _moving_average >](2, 2, &[i32::MAX; 2], &mut result) };
+                    assert_eq!(result, [i32::MAX]);
+
+                    let a = [i64::MIN, i64::MIN, i64::MAX, i64::MAX];
+                    let mut result = [0; 3];
+                    unsafe { [< $variant _moving_average >](4, 2, &a, &mut result) };
+                    assert_eq!(result, [i64::MIN, 0, i64::MAX]);
+                }
+            }
+        };
+    }
+
+    define_stats_test!(generic_fallback, types = f32, f64, i32, i64);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    define_stats_test!(generic_avx2, types = f32, f64, i32, i64);
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512f"
+    ))]
+    define_stats_test!(generic_avx512, types = f32, f64, i32, i64);
+    #[cfg(target_arch = "aarch64")]
+    define_stats_test!(generic_neon, types = f32, f64, i32, i64);
+}
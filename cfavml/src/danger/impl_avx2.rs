@@ -5,8 +5,19 @@ use core::arch::x86_64::*;
 use core::iter::zip;
 use core::mem;
 
-use super::core_simd_api::{DenseLane, SimdRegister};
+use super::core_simd_api::{
+    DenseLane,
+    SimdBitwiseRegister,
+    SimdCastRegister,
+    SimdFloatRegister,
+    SimdHammingRegister,
+    SimdRegister,
+    SimdSaturatingRegister,
+    SimdWidenRegister,
+    SimdWideningDotRegister,
+};
 use crate::apply_dense;
+use crate::math::{SaturatingArithmetic, WrappingShift};
 
 /// AVX2 enabled SIMD operations.
 ///
@@ -21,6 +32,11 @@ impl SimdRegister<f32> for Avx2 {
         _mm256_loadu_ps(mem)
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f32) -> Self::Register {
+        _mm256_load_ps(mem)
+    }
+
     #[inline(always)]
     unsafe fn filled(value: f32) -> Self::Register {
         _mm256_set1_ps(value)
@@ -83,6 +99,21 @@ impl SimdRegister<f32> for Avx2 {
         _mm256_and_ps(mask, _mm256_set1_ps(1.0))
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = _mm256_cmp_ps::<_CMP_EQ_OQ>(mask, _mm256_setzero_ps());
+        _mm256_blendv_ps(l1, l2, is_zero)
+    }
+
+    #[inline(always)]
+    unsafe fn neg(l1: Self::Register) -> Self::Register {
+        _mm256_xor_ps(l1, _mm256_set1_ps(-0.0))
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         let mask = _mm256_cmp_ps::<_CMP_LT_OQ>(l1, l2);
@@ -167,6 +198,62 @@ impl SimdRegister<f32> for Avx2 {
     unsafe fn write(mem: *mut f32, reg: Self::Register) {
         _mm256_storeu_ps(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut f32, reg: Self::Register) {
+        _mm256_store_ps(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut f32, reg: Self::Register) {
+        _mm256_stream_ps(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const f32) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn gather(base: *const f32, indices: *const u32) -> Self::Register {
+        _mm256_i32gather_ps::<4>(base, _mm256_loadu_si256(indices.cast()))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_max(reg: Self::Register) -> Self::Register {
+        prefix_cascade_ps(reg, |l1, l2| <Self as SimdRegister<f32>>::max(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_min(reg: Self::Register) -> Self::Register {
+        prefix_cascade_ps(reg, |l1, l2| <Self as SimdRegister<f32>>::min(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(reg: Self::Register) -> Self::Register {
+        prefix_sum_ps(reg, |l1, l2| <Self as SimdRegister<f32>>::add(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        _mm256_movemask_ps(_mm256_cmp_ps::<_CMP_NEQ_UQ>(mask, _mm256_setzero_ps()))
+            as u64
+    }
+
+    #[inline(always)]
+    unsafe fn compress_store(mem: *mut f32, reg: Self::Register, bitmask: u64) -> usize {
+        compress_store_epi32(mem.cast(), _mm256_castps_si256(reg), bitmask)
+    }
+
+    #[inline(always)]
+    unsafe fn compress_indices(mem: *mut u32, base: usize, bitmask: u64) -> usize {
+        compress_indices_epi32(mem, base, bitmask)
+    }
 }
 
 impl SimdRegister<f64> for Avx2 {
@@ -177,6 +264,11 @@ impl SimdRegister<f64> for Avx2 {
         _mm256_loadu_pd(mem)
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f64) -> Self::Register {
+        _mm256_load_pd(mem)
+    }
+
     #[inline(always)]
     unsafe fn filled(value: f64) -> Self::Register {
         _mm256_set1_pd(value)
@@ -240,6 +332,21 @@ impl SimdRegister<f64> for Avx2 {
         _mm256_and_pd(mask, _mm256_set1_pd(1.0))
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = _mm256_cmp_pd::<_CMP_EQ_OQ>(mask, _mm256_setzero_pd());
+        _mm256_blendv_pd(l1, l2, is_zero)
+    }
+
+    #[inline(always)]
+    unsafe fn neg(l1: Self::Register) -> Self::Register {
+        _mm256_xor_pd(l1, _mm256_set1_pd(-0.0))
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         let mask = _mm256_cmp_pd::<_CMP_LT_OQ>(l1, l2);
@@ -313,6 +420,47 @@ impl SimdRegister<f64> for Avx2 {
     unsafe fn write(mem: *mut f64, reg: Self::Register) {
         _mm256_storeu_pd(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut f64, reg: Self::Register) {
+        _mm256_store_pd(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut f64, reg: Self::Register) {
+        _mm256_stream_pd(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const f64) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_max(reg: Self::Register) -> Self::Register {
+        prefix_cascade_pd(reg, |l1, l2| <Self as SimdRegister<f64>>::max(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_min(reg: Self::Register) -> Self::Register {
+        prefix_cascade_pd(reg, |l1, l2| <Self as SimdRegister<f64>>::min(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(reg: Self::Register) -> Self::Register {
+        prefix_sum_pd(reg, |l1, l2| <Self as SimdRegister<f64>>::add(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        _mm256_movemask_pd(_mm256_cmp_pd::<_CMP_NEQ_UQ>(mask, _mm256_setzero_pd()))
+            as u64
+    }
 }
 
 impl SimdRegister<i8> for Avx2 {
@@ -323,6 +471,11 @@ impl SimdRegister<i8> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i8) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i8) -> Self::Register {
         _mm256_set1_epi8(value)
@@ -408,6 +561,17 @@ impl SimdRegister<i8> for Avx2 {
         _mm256_andnot_si256(eq_mask, _mm256_set1_epi8(1))
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        // The compare sets every byte of the element so the byte blend is exact.
+        let is_zero = _mm256_cmpeq_epi8(mask, _mm256_setzero_si256());
+        _mm256_blendv_epi8(l1, l2, is_zero)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         <Self as SimdRegister<i8>>::gt(l2, l1)
@@ -611,6 +775,42 @@ impl SimdRegister<i8> for Avx2 {
     unsafe fn write(mem: *mut i8, reg: Self::Register) {
         _mm256_storeu_si256(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut i8, reg: Self::Register) {
+        _mm256_store_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut i8, reg: Self::Register) {
+        _mm256_stream_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const i8) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        !(_mm256_movemask_epi8(_mm256_cmpeq_epi8(mask, _mm256_setzero_si256())) as u32)
+            as u64
+    }
+
+    #[inline(always)]
+    unsafe fn compress_store(mem: *mut i8, reg: Self::Register, bitmask: u64) -> usize {
+        compress_store_epi8(mem.cast(), reg, bitmask)
+    }
+
+    #[inline(always)]
+    unsafe fn compress_indices(mem: *mut u32, base: usize, bitmask: u64) -> usize {
+        compress_indices_epi32(mem, base, bitmask)
+    }
 }
 
 impl SimdRegister<i16> for Avx2 {
@@ -621,6 +821,11 @@ impl SimdRegister<i16> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i16) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i16) -> Self::Register {
         _mm256_set1_epi16(value)
@@ -698,6 +903,17 @@ impl SimdRegister<i16> for Avx2 {
         _mm256_andnot_si256(eq_mask, _mm256_set1_epi16(1))
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        // The compare sets every byte of the element so the byte blend is exact.
+        let is_zero = _mm256_cmpeq_epi16(mask, _mm256_setzero_si256());
+        _mm256_blendv_epi8(l1, l2, is_zero)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         <Self as SimdRegister<i16>>::gt(l2, l1)
@@ -896,6 +1112,31 @@ impl SimdRegister<i16> for Avx2 {
     unsafe fn write(mem: *mut i16, reg: Self::Register) {
         _mm256_storeu_si256(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut i16, reg: Self::Register) {
+        _mm256_store_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut i16, reg: Self::Register) {
+        _mm256_stream_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const i16) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        nonzero_bitmask_epi16(mask)
+    }
 }
 
 impl SimdRegister<i32> for Avx2 {
@@ -906,6 +1147,11 @@ impl SimdRegister<i32> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i32) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i32) -> Self::Register {
         _mm256_set1_epi32(value)
@@ -983,6 +1229,17 @@ impl SimdRegister<i32> for Avx2 {
         _mm256_andnot_si256(eq_mask, _mm256_set1_epi32(1))
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        // The compare sets every byte of the element so the byte blend is exact.
+        let is_zero = _mm256_cmpeq_epi32(mask, _mm256_setzero_si256());
+        _mm256_blendv_epi8(l1, l2, is_zero)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         <Self as SimdRegister<i32>>::gt(l2, l1)
@@ -1151,6 +1408,61 @@ impl SimdRegister<i32> for Avx2 {
     unsafe fn write(mem: *mut i32, reg: Self::Register) {
         _mm256_storeu_si256(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut i32, reg: Self::Register) {
+        _mm256_store_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut i32, reg: Self::Register) {
+        _mm256_stream_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const i32) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn gather(base: *const i32, indices: *const u32) -> Self::Register {
+        _mm256_i32gather_epi32::<4>(base, _mm256_loadu_si256(indices.cast()))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_max(reg: Self::Register) -> Self::Register {
+        prefix_cascade_epi32(reg, |l1, l2| <Self as SimdRegister<i32>>::max(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_min(reg: Self::Register) -> Self::Register {
+        prefix_cascade_epi32(reg, |l1, l2| <Self as SimdRegister<i32>>::min(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(reg: Self::Register) -> Self::Register {
+        prefix_sum_epi32(reg, |l1, l2| <Self as SimdRegister<i32>>::add(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        nonzero_bitmask_epi32(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn compress_store(mem: *mut i32, reg: Self::Register, bitmask: u64) -> usize {
+        compress_store_epi32(mem.cast(), reg, bitmask)
+    }
+
+    #[inline(always)]
+    unsafe fn compress_indices(mem: *mut u32, base: usize, bitmask: u64) -> usize {
+        compress_indices_epi32(mem, base, bitmask)
+    }
 }
 
 impl SimdRegister<i64> for Avx2 {
@@ -1161,6 +1473,11 @@ impl SimdRegister<i64> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i64) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i64) -> Self::Register {
         _mm256_set1_epi64x(value)
@@ -1250,6 +1567,17 @@ impl SimdRegister<i64> for Avx2 {
         _mm256_andnot_si256(eq_mask, _mm256_set1_epi64x(1))
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        // The compare sets every byte of the element so the byte blend is exact.
+        let is_zero = _mm256_cmpeq_epi64(mask, _mm256_setzero_si256());
+        _mm256_blendv_epi8(l1, l2, is_zero)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         <Self as SimdRegister<i64>>::gt(l2, l1)
@@ -1414,6 +1742,46 @@ impl SimdRegister<i64> for Avx2 {
     unsafe fn write(mem: *mut i64, reg: Self::Register) {
         _mm256_storeu_si256(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut i64, reg: Self::Register) {
+        _mm256_store_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut i64, reg: Self::Register) {
+        _mm256_stream_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const i64) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_max(reg: Self::Register) -> Self::Register {
+        prefix_cascade_epi64(reg, |l1, l2| <Self as SimdRegister<i64>>::max(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_min(reg: Self::Register) -> Self::Register {
+        prefix_cascade_epi64(reg, |l1, l2| <Self as SimdRegister<i64>>::min(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(reg: Self::Register) -> Self::Register {
+        prefix_sum_epi64(reg, |l1, l2| <Self as SimdRegister<i64>>::add(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        nonzero_bitmask_epi64(mask)
+    }
 }
 
 impl SimdRegister<u8> for Avx2 {
@@ -1424,6 +1792,11 @@ impl SimdRegister<u8> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u8) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u8) -> Self::Register {
         _mm256_set1_epi8(value as i8)
@@ -1498,6 +1871,15 @@ impl SimdRegister<u8> for Avx2 {
         <Self as SimdRegister<i8>>::neq(l1, l2) // Operation is identical
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        <Self as SimdRegister<i8>>::select(mask, l1, l2) // Operation is identical
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         <Self as SimdRegister<u8>>::gt(l2, l1)
@@ -1737,6 +2119,42 @@ impl SimdRegister<u8> for Avx2 {
     unsafe fn write(mem: *mut u8, reg: Self::Register) {
         _mm256_storeu_si256(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut u8, reg: Self::Register) {
+        _mm256_store_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut u8, reg: Self::Register) {
+        _mm256_stream_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const u8) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        !(_mm256_movemask_epi8(_mm256_cmpeq_epi8(mask, _mm256_setzero_si256())) as u32)
+            as u64
+    }
+
+    #[inline(always)]
+    unsafe fn compress_store(mem: *mut u8, reg: Self::Register, bitmask: u64) -> usize {
+        compress_store_epi8(mem.cast(), reg, bitmask)
+    }
+
+    #[inline(always)]
+    unsafe fn compress_indices(mem: *mut u32, base: usize, bitmask: u64) -> usize {
+        compress_indices_epi32(mem, base, bitmask)
+    }
 }
 
 impl SimdRegister<u16> for Avx2 {
@@ -1747,6 +2165,11 @@ impl SimdRegister<u16> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u16) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u16) -> Self::Register {
         _mm256_set1_epi16(value as i16)
@@ -1822,6 +2245,15 @@ impl SimdRegister<u16> for Avx2 {
         <Self as SimdRegister<i16>>::neq(l1, l2) // Operation is identical
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        <Self as SimdRegister<i16>>::select(mask, l1, l2) // Operation is identical
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         <Self as SimdRegister<u16>>::gt(l2, l1)
@@ -2065,14 +2497,44 @@ impl SimdRegister<u16> for Avx2 {
     unsafe fn write(mem: *mut u16, reg: Self::Register) {
         _mm256_storeu_si256(mem.cast(), reg)
     }
-}
 
-impl SimdRegister<u32> for Avx2 {
-    type Register = __m256i;
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut u16, reg: Self::Register) {
+        _mm256_store_si256(mem.cast(), reg)
+    }
 
     #[inline(always)]
-    unsafe fn load(mem: *const u32) -> Self::Register {
-        _mm256_loadu_si256(mem.cast())
+    unsafe fn write_stream(mem: *mut u16, reg: Self::Register) {
+        _mm256_stream_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const u16) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        nonzero_bitmask_epi16(mask)
+    }
+}
+
+impl SimdRegister<u32> for Avx2 {
+    type Register = __m256i;
+
+    #[inline(always)]
+    unsafe fn load(mem: *const u32) -> Self::Register {
+        _mm256_loadu_si256(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u32) -> Self::Register {
+        _mm256_load_si256(mem.cast())
     }
 
     #[inline(always)]
@@ -2150,6 +2612,15 @@ impl SimdRegister<u32> for Avx2 {
         <Self as SimdRegister<i32>>::neq(l1, l2) // Operation is identical
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        <Self as SimdRegister<i32>>::select(mask, l1, l2) // Operation is identical
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         <Self as SimdRegister<u32>>::gt(l2, l1)
@@ -2363,6 +2834,61 @@ impl SimdRegister<u32> for Avx2 {
     unsafe fn write(mem: *mut u32, reg: Self::Register) {
         _mm256_storeu_si256(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut u32, reg: Self::Register) {
+        _mm256_store_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut u32, reg: Self::Register) {
+        _mm256_stream_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const u32) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn gather(base: *const u32, indices: *const u32) -> Self::Register {
+        _mm256_i32gather_epi32::<4>(base.cast(), _mm256_loadu_si256(indices.cast()))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_max(reg: Self::Register) -> Self::Register {
+        prefix_cascade_epi32(reg, |l1, l2| <Self as SimdRegister<u32>>::max(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_min(reg: Self::Register) -> Self::Register {
+        prefix_cascade_epi32(reg, |l1, l2| <Self as SimdRegister<u32>>::min(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(reg: Self::Register) -> Self::Register {
+        prefix_sum_epi32(reg, |l1, l2| <Self as SimdRegister<u32>>::add(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        nonzero_bitmask_epi32(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn compress_store(mem: *mut u32, reg: Self::Register, bitmask: u64) -> usize {
+        compress_store_epi32(mem.cast(), reg, bitmask)
+    }
+
+    #[inline(always)]
+    unsafe fn compress_indices(mem: *mut u32, base: usize, bitmask: u64) -> usize {
+        compress_indices_epi32(mem, base, bitmask)
+    }
 }
 
 impl SimdRegister<u64> for Avx2 {
@@ -2373,6 +2899,11 @@ impl SimdRegister<u64> for Avx2 {
         _mm256_loadu_si256(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u64) -> Self::Register {
+        _mm256_load_si256(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u64) -> Self::Register {
         _mm256_set1_epi64x(value as i64)
@@ -2466,6 +2997,15 @@ impl SimdRegister<u64> for Avx2 {
         <Self as SimdRegister<i64>>::neq(l1, l2) // Operation is identical
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        <Self as SimdRegister<i64>>::select(mask, l1, l2) // Operation is identical
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         <Self as SimdRegister<u64>>::gt(l2, l1)
@@ -2723,4 +3263,935 @@ impl SimdRegister<u64> for Avx2 {
     unsafe fn write(mem: *mut u64, reg: Self::Register) {
         _mm256_storeu_si256(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut u64, reg: Self::Register) {
+        _mm256_store_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut u64, reg: Self::Register) {
+        _mm256_stream_si256(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const u64) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_max(reg: Self::Register) -> Self::Register {
+        prefix_cascade_epi64(reg, |l1, l2| <Self as SimdRegister<u64>>::max(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_min(reg: Self::Register) -> Self::Register {
+        prefix_cascade_epi64(reg, |l1, l2| <Self as SimdRegister<u64>>::min(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(reg: Self::Register) -> Self::Register {
+        prefix_sum_epi64(reg, |l1, l2| <Self as SimdRegister<u64>>::add(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        nonzero_bitmask_epi64(mask)
+    }
+}
+
+#[inline(always)]
+/// Runs the prefix cascade over the 8 `f32` elements of `reg`, where each step
+/// combines the register with a copy shifted towards the end, duplicating the
+/// leading elements.
+///
+/// `op` must be idempotent, i.e. `op(x, x) == x`, so the duplicated elements do
+/// not change the result.
+unsafe fn prefix_cascade_ps(
+    reg: __m256,
+    op: impl Fn(__m256, __m256) -> __m256,
+) -> __m256 {
+    // Within each 128-bit half: [x0, x0, x1, x2] then [x0, x1, x0, x1].
+    let reg = op(
+        reg,
+        _mm256_permute_ps::<{ super::_MM_SHUFFLE(2, 1, 0, 0) }>(reg),
+    );
+    let reg = op(
+        reg,
+        _mm256_permute_ps::<{ super::_MM_SHUFFLE(1, 0, 1, 0) }>(reg),
+    );
+
+    // Carry the last element of the low half into each element of the high half.
+    let low_last = _mm256_permute_ps::<{ super::_MM_SHUFFLE(3, 3, 3, 3) }>(reg);
+    let carried = _mm256_blend_ps::<0b1111_0000>(
+        reg,
+        _mm256_permute2f128_ps::<0x00>(low_last, low_last),
+    );
+    op(reg, carried)
+}
+
+#[inline(always)]
+/// Runs the prefix cascade over the 4 `f64` elements of `reg`, see [prefix_cascade_ps].
+unsafe fn prefix_cascade_pd(
+    reg: __m256d,
+    op: impl Fn(__m256d, __m256d) -> __m256d,
+) -> __m256d {
+    // [x0, x0, x1, x2] then [x0, x1, x0, x1].
+    let reg = op(
+        reg,
+        _mm256_permute4x64_pd::<{ super::_MM_SHUFFLE(2, 1, 0, 0) }>(reg),
+    );
+    op(
+        reg,
+        _mm256_permute4x64_pd::<{ super::_MM_SHUFFLE(1, 0, 1, 0) }>(reg),
+    )
+}
+
+#[inline(always)]
+/// Runs the prefix cascade over the 8 32-bit integer elements of `reg`, see [prefix_cascade_ps].
+unsafe fn prefix_cascade_epi32(
+    reg: __m256i,
+    op: impl Fn(__m256i, __m256i) -> __m256i,
+) -> __m256i {
+    let reg = prefix_cascade_ps(_mm256_castsi256_ps(reg), |l1, l2| {
+        _mm256_castsi256_ps(op(_mm256_castps_si256(l1), _mm256_castps_si256(l2)))
+    });
+    _mm256_castps_si256(reg)
+}
+
+#[inline(always)]
+/// Runs the prefix cascade over the 4 64-bit integer elements of `reg`, see [prefix_cascade_ps].
+unsafe fn prefix_cascade_epi64(
+    reg: __m256i,
+    op: impl Fn(__m256i, __m256i) -> __m256i,
+) -> __m256i {
+    let reg = prefix_cascade_pd(_mm256_castsi256_pd(reg), |l1, l2| {
+        _mm256_castsi256_pd(op(_mm256_castpd_si256(l1), _mm256_castpd_si256(l2)))
+    });
+    _mm256_castpd_si256(reg)
+}
+
+#[inline(always)]
+/// Runs the prefix sum cascade over the 8 `f32` elements of `reg`, where each step
+/// adds a copy of the register shifted towards the end, shifting in zeroes.
+unsafe fn prefix_sum_ps(reg: __m256, add: impl Fn(__m256, __m256) -> __m256) -> __m256 {
+    // Within each 128-bit half: [0, x0, x1, x2] then [0, 0, x0, x1].
+    let reg = add(
+        reg,
+        _mm256_castsi256_ps(_mm256_slli_si256::<4>(_mm256_castps_si256(reg))),
+    );
+    let reg = add(
+        reg,
+        _mm256_castsi256_ps(_mm256_slli_si256::<8>(_mm256_castps_si256(reg))),
+    );
+
+    // Carry the last element of the low half into each element of the high half.
+    let low_last = _mm256_permute_ps::<{ super::_MM_SHUFFLE(3, 3, 3, 3) }>(reg);
+    add(reg, _mm256_permute2f128_ps::<0x08>(low_last, low_last))
+}
+
+#[inline(always)]
+/// Runs the prefix sum cascade over the 4 `f64` elements of `reg`, see [prefix_sum_ps].
+unsafe fn prefix_sum_pd(
+    reg: __m256d,
+    add: impl Fn(__m256d, __m256d) -> __m256d,
+) -> __m256d {
+    let zero = _mm256_setzero_pd();
+
+    // [0, x0, x1, x2] then [0, 0, x0, x1].
+    let shifted = _mm256_permute4x64_pd::<{ super::_MM_SHUFFLE(2, 1, 0, 0) }>(reg);
+    let reg = add(reg, _mm256_blend_pd::<0b0001>(shifted, zero));
+    let shifted = _mm256_permute4x64_pd::<{ super::_MM_SHUFFLE(1, 0, 1, 0) }>(reg);
+    add(reg, _mm256_blend_pd::<0b0011>(shifted, zero))
+}
+
+#[inline(always)]
+/// Runs the prefix sum cascade over the 8 32-bit integer elements of `reg`, see [prefix_sum_ps].
+unsafe fn prefix_sum_epi32(
+    reg: __m256i,
+    add: impl Fn(__m256i, __m256i) -> __m256i,
+) -> __m256i {
+    let reg = prefix_sum_ps(_mm256_castsi256_ps(reg), |l1, l2| {
+        _mm256_castsi256_ps(add(_mm256_castps_si256(l1), _mm256_castps_si256(l2)))
+    });
+    _mm256_castps_si256(reg)
+}
+
+#[inline(always)]
+/// Runs the prefix sum cascade over the 4 64-bit integer elements of `reg`, see [prefix_sum_ps].
+unsafe fn prefix_sum_epi64(
+    reg: __m256i,
+    add: impl Fn(__m256i, __m256i) -> __m256i,
+) -> __m256i {
+    let reg = prefix_sum_pd(_mm256_castsi256_pd(reg), |l1, l2| {
+        _mm256_castsi256_pd(add(_mm256_castpd_si256(l1), _mm256_castpd_si256(l2)))
+    });
+    _mm256_castpd_si256(reg)
+}
+
+#[inline(always)]
+/// Returns the bitmask of the non-zero 16-bit elements of `mask`.
+unsafe fn nonzero_bitmask_epi16(mask: __m256i) -> u64 {
+    let is_zero = _mm256_cmpeq_epi16(mask, _mm256_setzero_si256());
+    // Narrow each element to a byte, packing operates within each 128-bit half
+    // so the halves are moved back into order before the movemask.
+    let packed = _mm256_packs_epi16(is_zero, is_zero);
+    let packed = _mm256_permute4x64_epi64::<{ super::_MM_SHUFFLE(3, 1, 2, 0) }>(packed);
+    !(_mm256_movemask_epi8(packed) as u32 as u16) as u64
+}
+
+#[inline(always)]
+/// Returns the bitmask of the non-zero 32-bit elements of `mask`.
+unsafe fn nonzero_bitmask_epi32(mask: __m256i) -> u64 {
+    let is_zero = _mm256_cmpeq_epi32(mask, _mm256_setzero_si256());
+    !(_mm256_movemask_ps(_mm256_castsi256_ps(is_zero)) as u8) as u64
+}
+
+#[inline(always)]
+/// Returns the bitmask of the non-zero 64-bit elements of `mask`.
+unsafe fn nonzero_bitmask_epi64(mask: __m256i) -> u64 {
+    let is_zero = _mm256_cmpeq_epi64(mask, _mm256_setzero_si256());
+    (!_mm256_movemask_pd(_mm256_castsi256_pd(is_zero)) & 0b1111) as u64
+}
+
+#[inline(always)]
+/// Returns if `bitmask` has at most 2 bits set, in which case compressing one element at
+/// a time is cheaper than the permute or shuffle.
+///
+/// The bits are cleared directly as `popcnt` is not part of the AVX2 target features.
+fn is_sparse(bitmask: u64) -> bool {
+    let rest = bitmask & bitmask.wrapping_sub(1);
+    rest & rest.wrapping_sub(1) == 0
+}
+
+#[inline(always)]
+/// Writes the 32-bit elements of `reg` selected by `bitmask` to `mem` with a single
+/// permute, using the indices looked up in [COMPRESS_EPI32_LUT].
+///
+/// The whole register may be written, only the first elements up to the returned count
+/// are the selected elements.
+unsafe fn compress_store_epi32(mem: *mut i32, reg: __m256i, bitmask: u64) -> usize {
+    if is_sparse(bitmask) {
+        let mut buffer = [0i32; 8];
+        _mm256_storeu_si256(buffer.as_mut_ptr().cast(), reg);
+        return compress_sparse(bitmask, |count, offset| {
+            mem.add(count).write(buffer[offset])
+        });
+    }
+
+    let packed = _mm256_set1_epi32(COMPRESS_EPI32_LUT[bitmask as usize & 0xFF] as i32);
+    let shifts = _mm256_setr_epi32(0, 4, 8, 12, 16, 20, 24, 28);
+    let indices =
+        _mm256_and_si256(_mm256_srlv_epi32(packed, shifts), _mm256_set1_epi32(0b1111));
+    _mm256_storeu_si256(mem.cast(), _mm256_permutevar8x32_epi32(reg, indices));
+
+    COMPRESS_COUNT_LUT[bitmask as usize & 0xFF] as usize
+}
+
+#[inline(always)]
+/// Writes the index of each bit set in `bitmask` offset by `base` to `mem`, building
+/// the indices of each non-zero group of 8 bits in a register compressed with
+/// [compress_store_epi32].
+unsafe fn compress_indices_epi32(mem: *mut u32, base: usize, bitmask: u64) -> usize {
+    if is_sparse(bitmask) {
+        return compress_sparse(bitmask, |count, offset| {
+            mem.add(count).write((base + offset) as u32)
+        });
+    }
+
+    let offsets = _mm256_setr_epi32(0, 1, 2, 3, 4, 5, 6, 7);
+
+    let mut count = 0;
+    let mut bits = bitmask;
+    let mut base = base;
+    while bits != 0 {
+        let group = bits & 0xFF;
+        if group != 0 {
+            let indices = _mm256_add_epi32(_mm256_set1_epi32(base as i32), offsets);
+            count += compress_store_epi32(mem.add(count).cast(), indices, group);
+        }
+
+        bits >>= 8;
+        base += 8;
+    }
+
+    count
+}
+
+#[inline(always)]
+/// Writes the 8-bit elements of `reg` selected by `bitmask` to `mem`, each non-zero
+/// group of 8 elements is compressed with a single shuffle using the indices looked
+/// up in [COMPRESS_EPI8_LUT].
+///
+/// All 8 bytes of each compressed group may be written, only the first elements up to
+/// the returned count are the selected elements.
+unsafe fn compress_store_epi8(mem: *mut u8, reg: __m256i, bitmask: u64) -> usize {
+    let mut buffer = [0u8; 32];
+    _mm256_storeu_si256(buffer.as_mut_ptr().cast(), reg);
+
+    if is_sparse(bitmask) {
+        return compress_sparse(bitmask, |count, offset| {
+            mem.add(count).write(buffer[offset])
+        });
+    }
+
+    let mut count = 0;
+    let mut bits = bitmask;
+    let mut offset = 0;
+    while bits != 0 {
+        let group = (bits & 0xFF) as usize;
+        if group != 0 {
+            let values = _mm_loadl_epi64(buffer.as_ptr().add(offset).cast());
+            let shuffle = _mm_loadl_epi64(COMPRESS_EPI8_LUT.as_ptr().add(group).cast());
+            _mm_storel_epi64(mem.add(count).cast(), _mm_shuffle_epi8(values, shuffle));
+            count += COMPRESS_COUNT_LUT[group] as usize;
+        }
+
+        bits >>= 8;
+        offset += 8;
+    }
+
+    count
+}
+
+#[inline(always)]
+/// Calls `write` with the output position and register offset of each bit set in
+/// `bitmask`, returning the number of bits set.
+unsafe fn compress_sparse(bitmask: u64, mut write: impl FnMut(usize, usize)) -> usize {
+    let mut bits = bitmask;
+    let mut count = 0;
+    while bits != 0 {
+        write(count, bits.trailing_zeros() as usize);
+        count += 1;
+        bits &= bits - 1;
+    }
+
+    count
+}
+
+/// The permute indices moving the selected elements of each 8 element bitmask to the
+/// start of the register, packed as one nibble per element.
+static COMPRESS_EPI32_LUT: [u64; 256] = compress_lut(4);
+
+/// The shuffle indices moving the selected elements of each 8 element bitmask to the
+/// start of the group, packed as one byte per element.
+static COMPRESS_EPI8_LUT: [u64; 256] = compress_lut(8);
+
+/// The number of elements selected by each 8 element bitmask.
+static COMPRESS_COUNT_LUT: [u8; 256] = {
+    let mut lut = [0; 256];
+    let mut bitmask = 0;
+    while bitmask < lut.len() {
+        lut[bitmask] = (bitmask as u8).count_ones() as u8;
+        bitmask += 1;
+    }
+    lut
+};
+
+/// Builds the compression indices of every 8 element bitmask, packing the index of
+/// each selected element into `bits_per_index` bits of the entry in order.
+const fn compress_lut(bits_per_index: u32) -> [u64; 256] {
+    let mut lut = [0; 256];
+
+    let mut bitmask = 0;
+    while bitmask < lut.len() {
+        let mut count = 0;
+        let mut i = 0;
+        while i < 8 {
+            if bitmask & (1 << i) != 0 {
+                lut[bitmask] |= (i as u64) << (count * bits_per_index);
+                count += 1;
+            }
+            i += 1;
+        }
+        bitmask += 1;
+    }
+
+    lut
+}
+
+/// Implements the bitwise operations for the integer types.
+///
+/// The and/or/xor/not operations are type agnostic and simply operate on the
+/// 256 bits of the register, the shift operations are provided per type.
+macro_rules! impl_avx2_bitwise {
+    ($t:ty, shl = $shl:path, shr = $shr:path) => {
+        impl SimdBitwiseRegister<$t> for Avx2 {
+            #[inline(always)]
+            unsafe fn bitand(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                _mm256_and_si256(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn bitor(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                _mm256_or_si256(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn bitxor(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                _mm256_xor_si256(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn bitnot(l1: Self::Register) -> Self::Register {
+                _mm256_xor_si256(l1, _mm256_set1_epi32(-1))
+            }
+
+            #[inline(always)]
+            unsafe fn shl(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                $shl(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn shr(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                $shr(l1, l2)
+            }
+        }
+    };
+}
+
+// AVX2 has no variable shift instructions for 8 and 16 bit elements, so these
+// fall back to scalar shifts over each element.
+impl_avx2_bitwise!(i8, shl = scalar_shl::<i8, 32>, shr = scalar_shr::<i8, 32>);
+impl_avx2_bitwise!(
+    i16,
+    shl = scalar_shl::<i16, 16>,
+    shr = scalar_shr::<i16, 16>
+);
+impl_avx2_bitwise!(i32, shl = shl_epi32, shr = sra_epi32);
+impl_avx2_bitwise!(i64, shl = shl_epi64, shr = sra_epi64);
+impl_avx2_bitwise!(u8, shl = scalar_shl::<u8, 32>, shr = scalar_shr::<u8, 32>);
+impl_avx2_bitwise!(
+    u16,
+    shl = scalar_shl::<u16, 16>,
+    shr = scalar_shr::<u16, 16>
+);
+impl_avx2_bitwise!(u32, shl = shl_epi32, shr = srl_epi32);
+impl_avx2_bitwise!(u64, shl = shl_epi64, shr = srl_epi64);
+
+/// Implements the saturating arithmetic for the integer types.
+macro_rules! impl_avx2_saturating {
+    ($t:ty, add = $add:path, sub = $sub:path) => {
+        impl SimdSaturatingRegister<$t> for Avx2 {
+            #[inline(always)]
+            unsafe fn saturating_add(
+                l1: Self::Register,
+                l2: Self::Register,
+            ) -> Self::Register {
+                $add(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn saturating_sub(
+                l1: Self::Register,
+                l2: Self::Register,
+            ) -> Self::Register {
+                $sub(l1, l2)
+            }
+        }
+    };
+}
+
+impl_avx2_saturating!(i8, add = _mm256_adds_epi8, sub = _mm256_subs_epi8);
+impl_avx2_saturating!(i16, add = _mm256_adds_epi16, sub = _mm256_subs_epi16);
+impl_avx2_saturating!(u8, add = _mm256_adds_epu8, sub = _mm256_subs_epu8);
+impl_avx2_saturating!(u16, add = _mm256_adds_epu16, sub = _mm256_subs_epu16);
+// AVX2 has no saturating instructions for 32 and 64 bit elements, so these
+// fall back to scalar saturating arithmetic over each element.
+impl_avx2_saturating!(
+    i32,
+    add = scalar_saturating_add::<i32, 8>,
+    sub = scalar_saturating_sub::<i32, 8>
+);
+impl_avx2_saturating!(
+    i64,
+    add = scalar_saturating_add::<i64, 4>,
+    sub = scalar_saturating_sub::<i64, 4>
+);
+impl_avx2_saturating!(
+    u32,
+    add = scalar_saturating_add::<u32, 8>,
+    sub = scalar_saturating_sub::<u32, 8>
+);
+impl_avx2_saturating!(
+    u64,
+    add = scalar_saturating_add::<u64, 4>,
+    sub = scalar_saturating_sub::<u64, 4>
+);
+
+/// Implements the widening dot product for the byte types.
+///
+/// `_mm256_maddubs_epi16` is avoided as it saturates its `i16` pair sums and requires
+/// one side to be unsigned, instead each half of the register is extended to `i16`
+/// and multiplied via `_mm256_madd_epi16` which produces exact `i32` pair sums.
+macro_rules! impl_avx2_widening_dot {
+    ($t:ty, extend = $extend:ident) => {
+        impl SimdWideningDotRegister<$t> for Avx2 {
+            type Register = __m256i;
+            type Accumulator = __m256i;
+
+            #[inline(always)]
+            unsafe fn load_widen(mem: *const $t) -> Self::Register {
+                _mm256_loadu_si256(mem.cast())
+            }
+
+            #[inline(always)]
+            unsafe fn zeroed_acc() -> Self::Accumulator {
+                _mm256_setzero_si256()
+            }
+
+            #[inline(always)]
+            unsafe fn dot_acc(
+                l1: Self::Register,
+                l2: Self::Register,
+                acc: Self::Accumulator,
+            ) -> Self::Accumulator {
+                let l1_lo = $extend(_mm256_castsi256_si128(l1));
+                let l1_hi = $extend(_mm256_extracti128_si256::<1>(l1));
+                let l2_lo = $extend(_mm256_castsi256_si128(l2));
+                let l2_hi = $extend(_mm256_extracti128_si256::<1>(l2));
+
+                let acc = _mm256_add_epi32(acc, _mm256_madd_epi16(l1_lo, l2_lo));
+                _mm256_add_epi32(acc, _mm256_madd_epi16(l1_hi, l2_hi))
+            }
+
+            #[inline(always)]
+            unsafe fn add_acc(
+                l1: Self::Accumulator,
+                l2: Self::Accumulator,
+            ) -> Self::Accumulator {
+                _mm256_add_epi32(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn sum_acc_to_value(acc: Self::Accumulator) -> i32 {
+                <Avx2 as SimdRegister<i32>>::sum_to_value(acc)
+            }
+        }
+    };
+}
+
+impl_avx2_widening_dot!(i8, extend = _mm256_cvtepi8_epi16);
+impl_avx2_widening_dot!(u8, extend = _mm256_cvtepu8_epi16);
+
+impl SimdWideningDotRegister<i16> for Avx2 {
+    type Register = __m256i;
+    type Accumulator = __m256i;
+
+    #[inline(always)]
+    unsafe fn load_widen(mem: *const i16) -> Self::Register {
+        _mm256_loadu_si256(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed_acc() -> Self::Accumulator {
+        _mm256_setzero_si256()
+    }
+
+    #[inline(always)]
+    /// `_mm256_madd_epi16` multiplies the `i16` elements and sums adjacent pairs of the
+    /// `i32` products, the only pair which can overflow is `i16::MIN` squared twice,
+    /// which wraps exactly as the scalar accumulation does.
+    unsafe fn dot_acc(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Accumulator,
+    ) -> Self::Accumulator {
+        _mm256_add_epi32(acc, _mm256_madd_epi16(l1, l2))
+    }
+
+    #[inline(always)]
+    unsafe fn add_acc(
+        l1: Self::Accumulator,
+        l2: Self::Accumulator,
+    ) -> Self::Accumulator {
+        _mm256_add_epi32(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_acc_to_value(acc: Self::Accumulator) -> i32 {
+        <Avx2 as SimdRegister<i32>>::sum_to_value(acc)
+    }
+}
+
+impl SimdCastRegister<f32, f64> for Avx2 {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        4
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const f32, result: *mut f64) {
+        _mm256_storeu_pd(result, _mm256_cvtps_pd(_mm_loadu_ps(mem)))
+    }
+}
+
+impl SimdCastRegister<f64, f32> for Avx2 {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        4
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const f64, result: *mut f32) {
+        _mm_storeu_ps(result, _mm256_cvtpd_ps(_mm256_loadu_pd(mem)))
+    }
+}
+
+impl SimdCastRegister<f32, i32> for Avx2 {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        8
+    }
+
+    #[inline(always)]
+    /// `_mm256_cvttps_epi32` produces `i32::MIN` for `NaN` and any out of range value,
+    /// so positive overflow and `NaN` are corrected to match the saturating `as` cast.
+    unsafe fn cast(mem: *const f32, result: *mut i32) {
+        let value = _mm256_loadu_ps(mem);
+        let truncated = _mm256_cvttps_epi32(value);
+
+        // Flipping the bits of `i32::MIN` produces `i32::MAX`.
+        let overflow = _mm256_cmp_ps::<_CMP_GE_OQ>(value, _mm256_set1_ps(2147483648.0));
+        let truncated = _mm256_xor_si256(truncated, _mm256_castps_si256(overflow));
+
+        let not_nan = _mm256_cmp_ps::<_CMP_ORD_Q>(value, value);
+        let truncated = _mm256_and_si256(truncated, _mm256_castps_si256(not_nan));
+
+        _mm256_storeu_si256(result.cast(), truncated)
+    }
+}
+
+impl SimdCastRegister<i32, f32> for Avx2 {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        8
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const i32, result: *mut f32) {
+        let value = _mm256_loadu_si256(mem.cast());
+        _mm256_storeu_ps(result, _mm256_cvtepi32_ps(value))
+    }
+}
+
+/// Implements the conversion of the smaller integer types to `f32` by extending
+/// each element to an `i32` first, which is always exact.
+macro_rules! impl_avx2_int_to_f32_cast {
+    ($t:ty, load = $load:ident, extend = $extend:ident) => {
+        impl SimdCastRegister<$t, f32> for Avx2 {
+            #[inline(always)]
+            fn cast_elements_per_lane() -> usize {
+                8
+            }
+
+            #[inline(always)]
+            unsafe fn cast(mem: *const $t, result: *mut f32) {
+                let extended = $extend($load(mem.cast()));
+                _mm256_storeu_ps(result, _mm256_cvtepi32_ps(extended))
+            }
+        }
+    };
+}
+
+impl_avx2_int_to_f32_cast!(i8, load = _mm_loadl_epi64, extend = _mm256_cvtepi8_epi32);
+impl_avx2_int_to_f32_cast!(u8, load = _mm_loadl_epi64, extend = _mm256_cvtepu8_epi32);
+impl_avx2_int_to_f32_cast!(i16, load = _mm_loadu_si128, extend = _mm256_cvtepi16_epi32);
+impl_avx2_int_to_f32_cast!(u16, load = _mm_loadu_si128, extend = _mm256_cvtepu16_epi32);
+
+impl SimdWidenRegister<f32, f64> for Avx2 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const f32) -> Self::Register {
+        _mm256_cvtps_pd(_mm_loadu_ps(mem))
+    }
+}
+
+impl SimdWidenRegister<i32, i64> for Avx2 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const i32) -> Self::Register {
+        _mm256_cvtepi32_epi64(_mm_loadu_si128(mem.cast()))
+    }
+}
+
+impl SimdWidenRegister<u32, u64> for Avx2 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const u32) -> Self::Register {
+        _mm256_cvtepu32_epi64(_mm_loadu_si128(mem.cast()))
+    }
+}
+
+impl SimdWidenRegister<u16, u32> for Avx2 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const u16) -> Self::Register {
+        _mm256_cvtepu16_epi32(_mm_loadu_si128(mem.cast()))
+    }
+}
+
+impl SimdWidenRegister<u8, u32> for Avx2 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const u8) -> Self::Register {
+        _mm256_cvtepu8_epi32(_mm_loadl_epi64(mem.cast()))
+    }
+}
+
+impl SimdWidenRegister<u16, u64> for Avx2 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const u16) -> Self::Register {
+        _mm256_cvtepu16_epi64(_mm_loadl_epi64(mem.cast()))
+    }
+}
+
+impl SimdHammingRegister for Avx2 {
+    type Register = __m256i;
+
+    #[inline(always)]
+    unsafe fn load_words(mem: *const u64) -> Self::Register {
+        _mm256_loadu_si256(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed_acc() -> Self::Register {
+        _mm256_setzero_si256()
+    }
+
+    #[inline(always)]
+    unsafe fn xor_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm256_xor_si256(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn and_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm256_and_si256(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn or_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm256_or_si256(l1, l2)
+    }
+
+    #[inline(always)]
+    /// AVX2 has no popcount instruction, so this uses the Wilkes-Wheeler-Gill
+    /// algorithm, looking up the bit count of each nibble with `vpshufb` before
+    /// summing the byte counts of each word with `vpsadbw`.
+    unsafe fn popcount_acc(
+        words: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        #[rustfmt::skip]
+        let lookup = _mm256_setr_epi8(
+            0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+            0, 1, 1, 2, 1, 2, 2, 3, 1, 2, 2, 3, 2, 3, 3, 4,
+        );
+        let low_mask = _mm256_set1_epi8(0x0F);
+
+        let lo = _mm256_and_si256(words, low_mask);
+        let hi = _mm256_and_si256(_mm256_srli_epi16::<4>(words), low_mask);
+        let counts = _mm256_add_epi8(
+            _mm256_shuffle_epi8(lookup, lo),
+            _mm256_shuffle_epi8(lookup, hi),
+        );
+
+        _mm256_add_epi64(acc, _mm256_sad_epu8(counts, _mm256_setzero_si256()))
+    }
+
+    #[inline(always)]
+    unsafe fn add_acc(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm256_add_epi64(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_acc_to_value(acc: Self::Register) -> u64 {
+        let counts = mem::transmute::<Self::Register, [u64; 4]>(acc);
+        counts.iter().sum()
+    }
+}
+
+impl SimdFloatRegister<f32> for Avx2 {
+    #[inline(always)]
+    unsafe fn hypot(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        hypot_ps::<Self>(l1, l2)
+    }
+}
+
+impl SimdFloatRegister<f64> for Avx2 {
+    #[inline(always)]
+    unsafe fn hypot(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        hypot_pd::<Self>(l1, l2)
+    }
+}
+
+#[inline(always)]
+/// Computes the hypotenuse of `l1` and `l2` as `hi * sqrt(1 + (lo / hi)²)` where
+/// `hi` and `lo` are the larger and smaller of the absolute values.
+///
+/// The `1 + ratio²` step is computed with `R::fmadd` so the FMA register
+/// can share this implementation while using the fused instruction.
+pub(super) unsafe fn hypot_ps<R>(l1: __m256, l2: __m256) -> __m256
+where
+    R: SimdRegister<f32, Register = __m256>,
+{
+    let sign_mask = _mm256_set1_ps(-0.0);
+    let a = _mm256_andnot_ps(sign_mask, l1);
+    let b = _mm256_andnot_ps(sign_mask, l2);
+
+    let hi = _mm256_max_ps(a, b);
+    let lo = _mm256_min_ps(a, b);
+    let ratio = _mm256_div_ps(lo, hi);
+    let scale = _mm256_sqrt_ps(R::fmadd(ratio, ratio, _mm256_set1_ps(1.0)));
+    let result = _mm256_mul_ps(hi, scale);
+
+    // `0 / 0` produces a NaN ratio when both inputs are zero.
+    let is_zero = _mm256_cmp_ps::<_CMP_EQ_OQ>(hi, _mm256_setzero_ps());
+    let result = _mm256_andnot_ps(is_zero, result);
+
+    let is_nan = _mm256_cmp_ps::<_CMP_UNORD_Q>(a, b);
+    let result = _mm256_blendv_ps(result, _mm256_add_ps(a, b), is_nan);
+
+    let inf = _mm256_set1_ps(f32::INFINITY);
+    let is_inf = _mm256_or_ps(
+        _mm256_cmp_ps::<_CMP_EQ_OQ>(a, inf),
+        _mm256_cmp_ps::<_CMP_EQ_OQ>(b, inf),
+    );
+    _mm256_blendv_ps(result, inf, is_inf)
+}
+
+#[inline(always)]
+/// Computes the hypotenuse of `l1` and `l2` as `hi * sqrt(1 + (lo / hi)²)` where
+/// `hi` and `lo` are the larger and smaller of the absolute values.
+///
+/// The `1 + ratio²` step is computed with `R::fmadd` so the FMA register
+/// can share this implementation while using the fused instruction.
+pub(super) unsafe fn hypot_pd<R>(l1: __m256d, l2: __m256d) -> __m256d
+where
+    R: SimdRegister<f64, Register = __m256d>,
+{
+    let sign_mask = _mm256_set1_pd(-0.0);
+    let a = _mm256_andnot_pd(sign_mask, l1);
+    let b = _mm256_andnot_pd(sign_mask, l2);
+
+    let hi = _mm256_max_pd(a, b);
+    let lo = _mm256_min_pd(a, b);
+    let ratio = _mm256_div_pd(lo, hi);
+    let scale = _mm256_sqrt_pd(R::fmadd(ratio, ratio, _mm256_set1_pd(1.0)));
+    let result = _mm256_mul_pd(hi, scale);
+
+    // `0 / 0` produces a NaN ratio when both inputs are zero.
+    let is_zero = _mm256_cmp_pd::<_CMP_EQ_OQ>(hi, _mm256_setzero_pd());
+    let result = _mm256_andnot_pd(is_zero, result);
+
+    let is_nan = _mm256_cmp_pd::<_CMP_UNORD_Q>(a, b);
+    let result = _mm256_blendv_pd(result, _mm256_add_pd(a, b), is_nan);
+
+    let inf = _mm256_set1_pd(f64::INFINITY);
+    let is_inf = _mm256_or_pd(
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(a, inf),
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(b, inf),
+    );
+    _mm256_blendv_pd(result, inf, is_inf)
+}
+
+#[inline(always)]
+unsafe fn shl_epi32(l1: __m256i, l2: __m256i) -> __m256i {
+    let count = _mm256_and_si256(l2, _mm256_set1_epi32(31));
+    _mm256_sllv_epi32(l1, count)
+}
+
+#[inline(always)]
+unsafe fn sra_epi32(l1: __m256i, l2: __m256i) -> __m256i {
+    let count = _mm256_and_si256(l2, _mm256_set1_epi32(31));
+    _mm256_srav_epi32(l1, count)
+}
+
+#[inline(always)]
+unsafe fn srl_epi32(l1: __m256i, l2: __m256i) -> __m256i {
+    let count = _mm256_and_si256(l2, _mm256_set1_epi32(31));
+    _mm256_srlv_epi32(l1, count)
+}
+
+#[inline(always)]
+unsafe fn shl_epi64(l1: __m256i, l2: __m256i) -> __m256i {
+    let count = _mm256_and_si256(l2, _mm256_set1_epi64x(63));
+    _mm256_sllv_epi64(l1, count)
+}
+
+#[inline(always)]
+/// Arithmetic right shift of `i64` elements.
+///
+/// AVX2 has no `srav_epi64` so the sign bits are shifted back in manually,
+/// when the count is zero the sign shift is by 64 which `sllv` turns into zero.
+unsafe fn sra_epi64(l1: __m256i, l2: __m256i) -> __m256i {
+    let count = _mm256_and_si256(l2, _mm256_set1_epi64x(63));
+    let sign = _mm256_cmpgt_epi64(_mm256_setzero_si256(), l1);
+    let sign_count = _mm256_sub_epi64(_mm256_set1_epi64x(64), count);
+    _mm256_or_si256(
+        _mm256_srlv_epi64(l1, count),
+        _mm256_sllv_epi64(sign, sign_count),
+    )
+}
+
+#[inline(always)]
+unsafe fn srl_epi64(l1: __m256i, l2: __m256i) -> __m256i {
+    let count = _mm256_and_si256(l2, _mm256_set1_epi64x(63));
+    _mm256_srlv_epi64(l1, count)
+}
+
+#[inline(always)]
+unsafe fn scalar_shl<T: WrappingShift, const N: usize>(
+    l1: __m256i,
+    l2: __m256i,
+) -> __m256i {
+    apply_scalar_op::<_, T, N>(l1, l2, T::shl_wrapping)
+}
+
+#[inline(always)]
+unsafe fn scalar_shr<T: WrappingShift, const N: usize>(
+    l1: __m256i,
+    l2: __m256i,
+) -> __m256i {
+    apply_scalar_op::<_, T, N>(l1, l2, T::shr_wrapping)
+}
+
+#[inline(always)]
+unsafe fn scalar_saturating_add<T: SaturatingArithmetic, const N: usize>(
+    l1: __m256i,
+    l2: __m256i,
+) -> __m256i {
+    apply_scalar_op::<_, T, N>(l1, l2, T::add_saturating)
+}
+
+#[inline(always)]
+unsafe fn scalar_saturating_sub<T: SaturatingArithmetic, const N: usize>(
+    l1: __m256i,
+    l2: __m256i,
+) -> __m256i {
+    apply_scalar_op::<_, T, N>(l1, l2, T::sub_saturating)
+}
+
+#[inline(always)]
+/// Applies the scalar `op` to each of the `N` elements `T` within the register `R`.
+///
+/// This is used for element sizes which have no matching SIMD instruction, i.e.
+/// variable shifts of 8 and 16 bit elements or saturating arithmetic of 32 and 64 bit elements.
+pub(super) unsafe fn apply_scalar_op<R, T, const N: usize>(
+    l1: R,
+    l2: R,
+    op: fn(T, T) -> T,
+) -> R
+where
+    R: Copy,
+    T: Copy,
+{
+    let mut l1 = mem::transmute_copy::<R, [T; N]>(&l1);
+    let l2 = mem::transmute_copy::<R, [T; N]>(&l2);
+
+    for (a, b) in zip(l1.iter_mut(), l2) {
+        *a = op(*a, b);
+    }
+
+    mem::transmute_copy::<[T; N], R>(&l1)
 }
@@ -0,0 +1,193 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use half::bf16;
+
+use super::core_simd_api::{SimdRegister, SimdWidenRegister};
+use super::impl_avx2::Avx2;
+use super::impl_avx2fma::Avx2Fma;
+
+// `bf16` is the top half of an `f32`, so unlike `f16` the conversions only need
+// integer shifts and do not require any CPU features beyond AVX2.
+//
+// Values are converted to `f32` on load and back to `bf16` on write, all computation
+// in between is done on `f32` registers via the [Avx2] operations, so intermediate
+// values like sums are kept at single precision.
+
+#[inline(always)]
+/// Loads 8 `bf16` values, widening them to `f32`.
+unsafe fn load_bf16_as_f32(mem: *const bf16) -> __m256 {
+    let raw = _mm256_cvtepu16_epi32(_mm_loadu_si128(mem.cast()));
+    _mm256_castsi256_ps(_mm256_slli_epi32::<16>(raw))
+}
+
+#[inline(always)]
+/// Narrows 8 `f32` values to `bf16` rounding to the nearest even value, matching
+/// [bf16::from_f32].
+unsafe fn write_f32_as_bf16(mem: *mut bf16, reg: __m256) {
+    let bits = _mm256_castps_si256(reg);
+
+    let lsb = _mm256_and_si256(_mm256_srli_epi32::<16>(bits), _mm256_set1_epi32(1));
+    let bias = _mm256_add_epi32(lsb, _mm256_set1_epi32(0x7FFF));
+    let rounded = _mm256_srli_epi32::<16>(_mm256_add_epi32(bits, bias));
+
+    // NaNs are kept quiet rather than being rounded into infinities.
+    let quiet_nan =
+        _mm256_or_si256(_mm256_srli_epi32::<16>(bits), _mm256_set1_epi32(0x40));
+    let is_nan = _mm256_castps_si256(_mm256_cmp_ps::<_CMP_UNORD_Q>(reg, reg));
+    let narrowed = _mm256_blendv_epi8(rounded, quiet_nan, is_nan);
+
+    // Every value fits within 16 bits so the saturating pack is lossless, it works on
+    // each 128 bit half independently so the halves must be moved together after.
+    let packed = _mm256_packus_epi32(narrowed, narrowed);
+    let packed = _mm256_permute4x64_epi64::<0b1000>(packed);
+    _mm_storeu_si128(mem.cast(), _mm256_castsi256_si128(packed))
+}
+
+impl SimdRegister<bf16> for Avx2 {
+    type Register = __m256;
+
+    #[inline(always)]
+    fn elements_per_lane() -> usize {
+        8
+    }
+
+    #[inline(always)]
+    unsafe fn load(mem: *const bf16) -> Self::Register {
+        load_bf16_as_f32(mem)
+    }
+
+    #[inline(always)]
+    unsafe fn filled(value: bf16) -> Self::Register {
+        Avx2::filled(value.to_f32())
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed() -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::zeroed()
+    }
+
+    #[inline(always)]
+    unsafe fn add(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::add(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::sub(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn mul(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::mul(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn div(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::div(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn fmadd(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::fmadd(l1, l2, acc)
+    }
+
+    #[inline(always)]
+    unsafe fn max(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::max(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn min(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::min(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn eq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::eq(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn neq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::neq(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::select(mask, l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn neg(l1: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::neg(l1)
+    }
+
+    #[inline(always)]
+    unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::lt(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn lte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::lte(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn gt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::gt(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::gte(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_to_value(reg: Self::Register) -> bf16 {
+        bf16::from_f32(<Avx2 as SimdRegister<f32>>::sum_to_value(reg))
+    }
+
+    #[inline(always)]
+    unsafe fn max_to_value(reg: Self::Register) -> bf16 {
+        bf16::from_f32(<Avx2 as SimdRegister<f32>>::max_to_value(reg))
+    }
+
+    #[inline(always)]
+    unsafe fn min_to_value(reg: Self::Register) -> bf16 {
+        bf16::from_f32(<Avx2 as SimdRegister<f32>>::min_to_value(reg))
+    }
+
+    #[inline(always)]
+    unsafe fn write(mem: *mut bf16, reg: Self::Register) {
+        write_f32_as_bf16(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const bf16) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+}
+
+impl SimdWidenRegister<bf16, f32> for Avx2 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const bf16) -> Self::Register {
+        load_bf16_as_f32(mem)
+    }
+}
+
+impl SimdWidenRegister<bf16, f32> for Avx2Fma {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const bf16) -> Self::Register {
+        load_bf16_as_f32(mem)
+    }
+}
@@ -0,0 +1,151 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use half::f16;
+
+use super::core_simd_api::SimdRegister;
+use super::impl_avx2::Avx2;
+
+/// AVX2 & F16C enabled SIMD operations over `f16` values.
+///
+/// Values are converted to `f32` on load and back to `f16` on write, all
+/// computation in between is done on `f32` registers via the [Avx2] operations,
+/// so intermediate values like sums are kept at single precision.
+///
+/// This requires the `avx2` & `f16c` CPU features be enabled.
+pub struct Avx2F16C;
+
+impl SimdRegister<f16> for Avx2F16C {
+    type Register = __m256;
+
+    #[inline(always)]
+    fn elements_per_lane() -> usize {
+        8
+    }
+
+    #[inline(always)]
+    unsafe fn load(mem: *const f16) -> Self::Register {
+        _mm256_cvtph_ps(_mm_loadu_si128(mem.cast()))
+    }
+
+    #[inline(always)]
+    unsafe fn filled(value: f16) -> Self::Register {
+        Avx2::filled(value.to_f32())
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed() -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::zeroed()
+    }
+
+    #[inline(always)]
+    unsafe fn add(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::add(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sub(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::sub(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn mul(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::mul(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn div(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::div(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn fmadd(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::fmadd(l1, l2, acc)
+    }
+
+    #[inline(always)]
+    unsafe fn max(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::max(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn min(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::min(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn eq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::eq(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn neq(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::neq(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::select(mask, l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn neg(l1: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::neg(l1)
+    }
+
+    #[inline(always)]
+    unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::lt(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn lte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::lte(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn gt(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::gt(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn gte(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::gte(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_to_value(reg: Self::Register) -> f16 {
+        f16::from_f32(Avx2::sum_to_value(reg))
+    }
+
+    #[inline(always)]
+    unsafe fn max_to_value(reg: Self::Register) -> f16 {
+        f16::from_f32(Avx2::max_to_value(reg))
+    }
+
+    #[inline(always)]
+    unsafe fn min_to_value(reg: Self::Register) -> f16 {
+        f16::from_f32(Avx2::min_to_value(reg))
+    }
+
+    #[inline(always)]
+    unsafe fn write(mem: *mut f16, reg: Self::Register) {
+        let converted = _mm256_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(reg);
+        _mm_storeu_si128(mem.cast(), converted)
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const f16) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+}
@@ -3,8 +3,8 @@ use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
-use super::core_simd_api::SimdRegister;
-use super::impl_avx2::Avx2;
+use super::core_simd_api::{SimdFloatRegister, SimdRegister, SimdWidenRegister};
+use super::impl_avx2::{hypot_pd, hypot_ps, Avx2};
 
 /// AVX2 & FMA enabled SIMD operations.
 ///
@@ -19,6 +19,11 @@ impl SimdRegister<f32> for Avx2Fma {
         Avx2::load(mem)
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f32) -> Self::Register {
+        Avx2::load_aligned(mem)
+    }
+
     #[inline(always)]
     unsafe fn filled(value: f32) -> Self::Register {
         Avx2::filled(value)
@@ -78,6 +83,20 @@ impl SimdRegister<f32> for Avx2Fma {
         <Avx2 as SimdRegister<f32>>::neq(l1, l2)
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::select(mask, l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn neg(l1: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::neg(l1)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         <Avx2 as SimdRegister<f32>>::lt(l1, l2)
@@ -117,6 +136,61 @@ impl SimdRegister<f32> for Avx2Fma {
     unsafe fn write(mem: *mut f32, reg: Self::Register) {
         Avx2::write(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut f32, reg: Self::Register) {
+        Avx2::write_aligned(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut f32, reg: Self::Register) {
+        Avx2::write_stream(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        <Avx2 as SimdRegister<f32>>::stream_fence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const f32) {
+        <Avx2 as SimdRegister<f32>>::prefetch(mem)
+    }
+
+    #[inline(always)]
+    unsafe fn gather(base: *const f32, indices: *const u32) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::gather(base, indices)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_max(reg: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::prefix_max(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_min(reg: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::prefix_min(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(reg: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f32>>::prefix_sum(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        <Avx2 as SimdRegister<f32>>::nonzero_bitmask(mask)
+    }
+
+    #[inline(always)]
+    unsafe fn compress_store(mem: *mut f32, reg: Self::Register, bitmask: u64) -> usize {
+        <Avx2 as SimdRegister<f32>>::compress_store(mem, reg, bitmask)
+    }
+
+    #[inline(always)]
+    unsafe fn compress_indices(mem: *mut u32, base: usize, bitmask: u64) -> usize {
+        <Avx2 as SimdRegister<f32>>::compress_indices(mem, base, bitmask)
+    }
 }
 
 impl SimdRegister<f64> for Avx2Fma {
@@ -127,6 +201,11 @@ impl SimdRegister<f64> for Avx2Fma {
         Avx2::load(mem)
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f64) -> Self::Register {
+        Avx2::load_aligned(mem)
+    }
+
     #[inline(always)]
     unsafe fn filled(value: f64) -> Self::Register {
         Avx2::filled(value)
@@ -186,6 +265,20 @@ impl SimdRegister<f64> for Avx2Fma {
         <Avx2 as SimdRegister<f64>>::neq(l1, l2)
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        <Avx2 as SimdRegister<f64>>::select(mask, l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn neg(l1: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f64>>::neg(l1)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         <Avx2 as SimdRegister<f64>>::lt(l1, l2)
@@ -224,4 +317,65 @@ impl SimdRegister<f64> for Avx2Fma {
     unsafe fn write(mem: *mut f64, reg: Self::Register) {
         Avx2::write(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut f64, reg: Self::Register) {
+        Avx2::write_aligned(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut f64, reg: Self::Register) {
+        Avx2::write_stream(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        <Avx2 as SimdRegister<f64>>::stream_fence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const f64) {
+        <Avx2 as SimdRegister<f64>>::prefetch(mem)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_max(reg: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f64>>::prefix_max(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_min(reg: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f64>>::prefix_min(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn prefix_sum(reg: Self::Register) -> Self::Register {
+        <Avx2 as SimdRegister<f64>>::prefix_sum(reg)
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        <Avx2 as SimdRegister<f64>>::nonzero_bitmask(mask)
+    }
+}
+
+impl SimdWidenRegister<f32, f64> for Avx2Fma {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const f32) -> Self::Register {
+        <Avx2 as SimdWidenRegister<f32, f64>>::load_widened(mem)
+    }
+}
+
+impl SimdFloatRegister<f32> for Avx2Fma {
+    #[inline(always)]
+    unsafe fn hypot(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        hypot_ps::<Self>(l1, l2)
+    }
+}
+
+impl SimdFloatRegister<f64> for Avx2Fma {
+    #[inline(always)]
+    unsafe fn hypot(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        hypot_pd::<Self>(l1, l2)
+    }
 }
@@ -5,9 +5,19 @@ use core::arch::x86_64::*;
 use core::iter::zip;
 use core::mem;
 
-use super::core_simd_api::{DenseLane, SimdRegister};
-use super::impl_avx2::Avx2;
+use super::core_simd_api::{
+    DenseLane,
+    SimdBitwiseRegister,
+    SimdCastRegister,
+    SimdFloatRegister,
+    SimdHammingRegister,
+    SimdRegister,
+    SimdSaturatingRegister,
+    SimdWidenRegister,
+};
+use super::impl_avx2::{apply_scalar_op, Avx2};
 use crate::apply_dense;
+use crate::math::{SaturatingArithmetic, WrappingShift};
 
 /// AVX512 enabled SIMD operations.
 ///
@@ -27,6 +37,11 @@ impl SimdRegister<f32> for Avx512 {
         _mm512_loadu_ps(mem)
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f32) -> Self::Register {
+        _mm512_load_ps(mem)
+    }
+
     #[inline(always)]
     unsafe fn filled(value: f32) -> Self::Register {
         _mm512_set1_ps(value)
@@ -88,6 +103,24 @@ impl SimdRegister<f32> for Avx512 {
         fast_cvt_mask16_to_m512(mask)
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(mask, _mm512_setzero_ps());
+        _mm512_mask_blend_ps(is_zero, l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn neg(l1: Self::Register) -> Self::Register {
+        // `_mm512_xor_ps` requires `avx512dq`, so the sign bit is flipped as an integer.
+        let flipped =
+            _mm512_xor_si512(_mm512_castps_si512(l1), _mm512_set1_epi32(i32::MIN));
+        _mm512_castsi512_ps(flipped)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         let mask = _mm512_cmp_ps_mask::<_CMP_LT_OQ>(l1, l2);
@@ -131,6 +164,31 @@ impl SimdRegister<f32> for Avx512 {
     unsafe fn write(mem: *mut f32, reg: Self::Register) {
         _mm512_storeu_ps(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut f32, reg: Self::Register) {
+        _mm512_store_ps(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut f32, reg: Self::Register) {
+        _mm512_stream_ps(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const f32) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        _mm512_cmp_ps_mask::<_CMP_NEQ_UQ>(mask, _mm512_setzero_ps()) as u64
+    }
 }
 
 impl SimdRegister<f64> for Avx512 {
@@ -141,6 +199,11 @@ impl SimdRegister<f64> for Avx512 {
         _mm512_loadu_pd(mem)
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const f64) -> Self::Register {
+        _mm512_load_pd(mem)
+    }
+
     #[inline(always)]
     unsafe fn filled(value: f64) -> Self::Register {
         _mm512_set1_pd(value)
@@ -202,6 +265,23 @@ impl SimdRegister<f64> for Avx512 {
         fast_cvt_mask8_to_m512d(mask)
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(mask, _mm512_setzero_pd());
+        _mm512_mask_blend_pd(is_zero, l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn neg(l1: Self::Register) -> Self::Register {
+        let flipped =
+            _mm512_xor_si512(_mm512_castpd_si512(l1), _mm512_set1_epi64(i64::MIN));
+        _mm512_castsi512_pd(flipped)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         let mask = _mm512_cmp_pd_mask::<_CMP_LT_OQ>(l1, l2);
@@ -245,6 +325,31 @@ impl SimdRegister<f64> for Avx512 {
     unsafe fn write(mem: *mut f64, reg: Self::Register) {
         _mm512_storeu_pd(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut f64, reg: Self::Register) {
+        _mm512_store_pd(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut f64, reg: Self::Register) {
+        _mm512_stream_pd(mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const f64) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        _mm512_cmp_pd_mask::<_CMP_NEQ_UQ>(mask, _mm512_setzero_pd()) as u64
+    }
 }
 
 impl SimdRegister<i8> for Avx512 {
@@ -255,6 +360,11 @@ impl SimdRegister<i8> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i8) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i8) -> Self::Register {
         _mm512_set1_epi8(value)
@@ -337,6 +447,16 @@ impl SimdRegister<i8> for Avx512 {
         fast_cvt_mask64_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = _mm512_cmpeq_epi8_mask(mask, _mm512_setzero_si512());
+        _mm512_mask_blend_epi8(is_zero, l1, l2)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         let mask = _mm512_cmplt_epi8_mask(l1, l2);
@@ -423,6 +543,31 @@ impl SimdRegister<i8> for Avx512 {
     unsafe fn write(mem: *mut i8, reg: Self::Register) {
         _mm512_storeu_si512(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut i8, reg: Self::Register) {
+        _mm512_store_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut i8, reg: Self::Register) {
+        _mm512_stream_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const i8) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        _mm512_test_epi8_mask(mask, mask)
+    }
 }
 
 impl SimdRegister<i16> for Avx512 {
@@ -433,6 +578,11 @@ impl SimdRegister<i16> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i16) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i16) -> Self::Register {
         _mm512_set1_epi16(value)
@@ -510,6 +660,16 @@ impl SimdRegister<i16> for Avx512 {
         fast_cvt_mask32_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = _mm512_cmpeq_epi16_mask(mask, _mm512_setzero_si512());
+        _mm512_mask_blend_epi16(is_zero, l1, l2)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         let mask = _mm512_cmplt_epi16_mask(l1, l2);
@@ -586,6 +746,31 @@ impl SimdRegister<i16> for Avx512 {
     unsafe fn write(mem: *mut i16, reg: Self::Register) {
         _mm512_storeu_si512(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut i16, reg: Self::Register) {
+        _mm512_store_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut i16, reg: Self::Register) {
+        _mm512_stream_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const i16) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        _mm512_test_epi16_mask(mask, mask) as u64
+    }
 }
 
 impl SimdRegister<i32> for Avx512 {
@@ -596,6 +781,11 @@ impl SimdRegister<i32> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i32) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i32) -> Self::Register {
         _mm512_set1_epi32(value)
@@ -673,6 +863,16 @@ impl SimdRegister<i32> for Avx512 {
         fast_cvt_mask16_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = _mm512_cmpeq_epi32_mask(mask, _mm512_setzero_si512());
+        _mm512_mask_blend_epi32(is_zero, l1, l2)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         let mask = _mm512_cmplt_epi32_mask(l1, l2);
@@ -734,6 +934,31 @@ impl SimdRegister<i32> for Avx512 {
     unsafe fn write(mem: *mut i32, reg: Self::Register) {
         _mm512_storeu_si512(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut i32, reg: Self::Register) {
+        _mm512_store_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut i32, reg: Self::Register) {
+        _mm512_stream_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const i32) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        _mm512_test_epi32_mask(mask, mask) as u64
+    }
 }
 
 impl SimdRegister<i64> for Avx512 {
@@ -744,6 +969,11 @@ impl SimdRegister<i64> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const i64) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: i64) -> Self::Register {
         _mm512_set1_epi64(value)
@@ -822,6 +1052,16 @@ impl SimdRegister<i64> for Avx512 {
         fast_cvt_mask8_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = _mm512_cmpeq_epi64_mask(mask, _mm512_setzero_si512());
+        _mm512_mask_blend_epi64(is_zero, l1, l2)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         let mask = _mm512_cmplt_epi64_mask(l1, l2);
@@ -875,6 +1115,31 @@ impl SimdRegister<i64> for Avx512 {
     unsafe fn write(mem: *mut i64, reg: Self::Register) {
         _mm512_storeu_si512(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut i64, reg: Self::Register) {
+        _mm512_store_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut i64, reg: Self::Register) {
+        _mm512_stream_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const i64) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        _mm512_test_epi64_mask(mask, mask) as u64
+    }
 }
 
 impl SimdRegister<u8> for Avx512 {
@@ -885,6 +1150,11 @@ impl SimdRegister<u8> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u8) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u8) -> Self::Register {
         _mm512_set1_epi8(value as i8)
@@ -961,6 +1231,15 @@ impl SimdRegister<u8> for Avx512 {
         fast_cvt_mask64_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        <Self as SimdRegister<i8>>::select(mask, l1, l2) // Operation is identical
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         let mask = _mm512_cmplt_epu8_mask(l1, l2);
@@ -1032,6 +1311,31 @@ impl SimdRegister<u8> for Avx512 {
     unsafe fn write(mem: *mut u8, reg: Self::Register) {
         _mm512_storeu_si512(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut u8, reg: Self::Register) {
+        _mm512_store_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut u8, reg: Self::Register) {
+        _mm512_stream_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const u8) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        _mm512_test_epi8_mask(mask, mask)
+    }
 }
 
 impl SimdRegister<u16> for Avx512 {
@@ -1042,6 +1346,11 @@ impl SimdRegister<u16> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u16) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u16) -> Self::Register {
         _mm512_set1_epi16(value as i16)
@@ -1118,6 +1427,15 @@ impl SimdRegister<u16> for Avx512 {
         fast_cvt_mask32_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        <Self as SimdRegister<i16>>::select(mask, l1, l2) // Operation is identical
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         let mask = _mm512_cmplt_epu16_mask(l1, l2);
@@ -1189,6 +1507,31 @@ impl SimdRegister<u16> for Avx512 {
     unsafe fn write(mem: *mut u16, reg: Self::Register) {
         _mm512_storeu_si512(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut u16, reg: Self::Register) {
+        _mm512_store_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut u16, reg: Self::Register) {
+        _mm512_stream_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const u16) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        _mm512_test_epi16_mask(mask, mask) as u64
+    }
 }
 
 impl SimdRegister<u32> for Avx512 {
@@ -1199,6 +1542,11 @@ impl SimdRegister<u32> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u32) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u32) -> Self::Register {
         _mm512_set1_epi32(value as i32)
@@ -1275,6 +1623,15 @@ impl SimdRegister<u32> for Avx512 {
         fast_cvt_mask16_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        <Self as SimdRegister<i32>>::select(mask, l1, l2) // Operation is identical
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         let mask = _mm512_cmplt_epu32_mask(l1, l2);
@@ -1336,6 +1693,31 @@ impl SimdRegister<u32> for Avx512 {
     unsafe fn write(mem: *mut u32, reg: Self::Register) {
         _mm512_storeu_si512(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut u32, reg: Self::Register) {
+        _mm512_store_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut u32, reg: Self::Register) {
+        _mm512_stream_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const u32) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        _mm512_test_epi32_mask(mask, mask) as u64
+    }
 }
 
 impl SimdRegister<u64> for Avx512 {
@@ -1346,6 +1728,11 @@ impl SimdRegister<u64> for Avx512 {
         _mm512_loadu_si512(mem.cast())
     }
 
+    #[inline(always)]
+    unsafe fn load_aligned(mem: *const u64) -> Self::Register {
+        _mm512_load_si512(mem.cast())
+    }
+
     #[inline(always)]
     unsafe fn filled(value: u64) -> Self::Register {
         _mm512_set1_epi64(value as i64)
@@ -1431,6 +1818,15 @@ impl SimdRegister<u64> for Avx512 {
         fast_cvt_mask8_to_m512i(mask)
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        <Self as SimdRegister<i64>>::select(mask, l1, l2) // Operation is identical
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         let mask = _mm512_cmplt_epu64_mask(l1, l2);
@@ -1484,6 +1880,31 @@ impl SimdRegister<u64> for Avx512 {
     unsafe fn write(mem: *mut u64, reg: Self::Register) {
         _mm512_storeu_si512(mem.cast(), reg)
     }
+
+    #[inline(always)]
+    unsafe fn write_aligned(mem: *mut u64, reg: Self::Register) {
+        _mm512_store_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn write_stream(mem: *mut u64, reg: Self::Register) {
+        _mm512_stream_si512(mem.cast(), reg)
+    }
+
+    #[inline(always)]
+    unsafe fn stream_fence() {
+        _mm_sfence()
+    }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const u64) {
+        _mm_prefetch::<_MM_HINT_T0>(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        _mm512_test_epi64_mask(mask, mask) as u64
+    }
 }
 
 #[inline(always)]
@@ -1531,3 +1952,443 @@ unsafe fn fast_cvt_mask8_to_m512d(mask: __mmask8) -> __m512d {
         _mm512_mask_sub_epi64(zeroes, mask, _mm512_castpd_si512(ones), zeroes);
     _mm512_castsi512_pd(expanded_mask)
 }
+
+/// Implements the bitwise operations for the integer types.
+///
+/// The and/or/xor/not operations are type agnostic and simply operate on the
+/// 512 bits of the register, the shift operations are provided per type.
+macro_rules! impl_avx512_bitwise {
+    ($t:ty, shl = $shl:path, shr = $shr:path) => {
+        impl SimdBitwiseRegister<$t> for Avx512 {
+            #[inline(always)]
+            unsafe fn bitand(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                _mm512_and_si512(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn bitor(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                _mm512_or_si512(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn bitxor(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                _mm512_xor_si512(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn bitnot(l1: Self::Register) -> Self::Register {
+                _mm512_xor_si512(l1, _mm512_set1_epi32(-1))
+            }
+
+            #[inline(always)]
+            unsafe fn shl(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                $shl(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn shr(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                $shr(l1, l2)
+            }
+        }
+    };
+}
+
+// AVX512 has no variable shift instructions for 8 bit elements, so these
+// fall back to scalar shifts over each element.
+impl_avx512_bitwise!(i8, shl = scalar_shl::<i8, 64>, shr = scalar_shr::<i8, 64>);
+impl_avx512_bitwise!(i16, shl = shl_epi16, shr = sra_epi16);
+impl_avx512_bitwise!(i32, shl = shl_epi32, shr = sra_epi32);
+impl_avx512_bitwise!(i64, shl = shl_epi64, shr = sra_epi64);
+impl_avx512_bitwise!(u8, shl = scalar_shl::<u8, 64>, shr = scalar_shr::<u8, 64>);
+impl_avx512_bitwise!(u16, shl = shl_epi16, shr = srl_epi16);
+impl_avx512_bitwise!(u32, shl = shl_epi32, shr = srl_epi32);
+impl_avx512_bitwise!(u64, shl = shl_epi64, shr = srl_epi64);
+
+/// Implements the saturating arithmetic for the integer types.
+macro_rules! impl_avx512_saturating {
+    ($t:ty, add = $add:path, sub = $sub:path) => {
+        impl SimdSaturatingRegister<$t> for Avx512 {
+            #[inline(always)]
+            unsafe fn saturating_add(
+                l1: Self::Register,
+                l2: Self::Register,
+            ) -> Self::Register {
+                $add(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn saturating_sub(
+                l1: Self::Register,
+                l2: Self::Register,
+            ) -> Self::Register {
+                $sub(l1, l2)
+            }
+        }
+    };
+}
+
+impl_avx512_saturating!(i8, add = _mm512_adds_epi8, sub = _mm512_subs_epi8);
+impl_avx512_saturating!(i16, add = _mm512_adds_epi16, sub = _mm512_subs_epi16);
+impl_avx512_saturating!(u8, add = _mm512_adds_epu8, sub = _mm512_subs_epu8);
+impl_avx512_saturating!(u16, add = _mm512_adds_epu16, sub = _mm512_subs_epu16);
+// AVX512 has no saturating instructions for 32 and 64 bit elements, so these
+// fall back to scalar saturating arithmetic over each element.
+impl_avx512_saturating!(
+    i32,
+    add = scalar_saturating_add::<i32, 16>,
+    sub = scalar_saturating_sub::<i32, 16>
+);
+impl_avx512_saturating!(
+    i64,
+    add = scalar_saturating_add::<i64, 8>,
+    sub = scalar_saturating_sub::<i64, 8>
+);
+impl_avx512_saturating!(
+    u32,
+    add = scalar_saturating_add::<u32, 16>,
+    sub = scalar_saturating_sub::<u32, 16>
+);
+impl_avx512_saturating!(
+    u64,
+    add = scalar_saturating_add::<u64, 8>,
+    sub = scalar_saturating_sub::<u64, 8>
+);
+
+impl SimdCastRegister<f32, f64> for Avx512 {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        8
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const f32, result: *mut f64) {
+        _mm512_storeu_pd(result, _mm512_cvtps_pd(_mm256_loadu_ps(mem)))
+    }
+}
+
+impl SimdCastRegister<f64, f32> for Avx512 {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        8
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const f64, result: *mut f32) {
+        _mm256_storeu_ps(result, _mm512_cvtpd_ps(_mm512_loadu_pd(mem)))
+    }
+}
+
+impl SimdCastRegister<f32, i32> for Avx512 {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        16
+    }
+
+    #[inline(always)]
+    /// `_mm512_cvttps_epi32` produces `i32::MIN` for `NaN` and any out of range value,
+    /// so positive overflow and `NaN` are corrected to match the saturating `as` cast.
+    unsafe fn cast(mem: *const f32, result: *mut i32) {
+        let value = _mm512_loadu_ps(mem);
+        let truncated = _mm512_cvttps_epi32(value);
+
+        let overflow =
+            _mm512_cmp_ps_mask::<_CMP_GE_OQ>(value, _mm512_set1_ps(2147483648.0));
+        let truncated =
+            _mm512_mask_mov_epi32(truncated, overflow, _mm512_set1_epi32(i32::MAX));
+
+        let is_nan = _mm512_cmp_ps_mask::<_CMP_UNORD_Q>(value, value);
+        let truncated = _mm512_mask_mov_epi32(truncated, is_nan, _mm512_setzero_si512());
+
+        _mm512_storeu_si512(result.cast(), truncated)
+    }
+}
+
+impl SimdCastRegister<i32, f32> for Avx512 {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        16
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const i32, result: *mut f32) {
+        let value = _mm512_loadu_si512(mem.cast());
+        _mm512_storeu_ps(result, _mm512_cvtepi32_ps(value))
+    }
+}
+
+/// Implements the conversion of the smaller integer types to `f32` by extending
+/// each element to an `i32` first, which is always exact.
+macro_rules! impl_avx512_int_to_f32_cast {
+    ($t:ty, load = $load:ident, extend = $extend:ident) => {
+        impl SimdCastRegister<$t, f32> for Avx512 {
+            #[inline(always)]
+            fn cast_elements_per_lane() -> usize {
+                16
+            }
+
+            #[inline(always)]
+            unsafe fn cast(mem: *const $t, result: *mut f32) {
+                let extended = $extend($load(mem.cast()));
+                _mm512_storeu_ps(result, _mm512_cvtepi32_ps(extended))
+            }
+        }
+    };
+}
+
+impl_avx512_int_to_f32_cast!(i8, load = _mm_loadu_si128, extend = _mm512_cvtepi8_epi32);
+impl_avx512_int_to_f32_cast!(u8, load = _mm_loadu_si128, extend = _mm512_cvtepu8_epi32);
+impl_avx512_int_to_f32_cast!(
+    i16,
+    load = _mm256_loadu_si256,
+    extend = _mm512_cvtepi16_epi32
+);
+impl_avx512_int_to_f32_cast!(
+    u16,
+    load = _mm256_loadu_si256,
+    extend = _mm512_cvtepu16_epi32
+);
+
+impl SimdWidenRegister<f32, f64> for Avx512 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const f32) -> Self::Register {
+        _mm512_cvtps_pd(_mm256_loadu_ps(mem))
+    }
+}
+
+impl SimdWidenRegister<i32, i64> for Avx512 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const i32) -> Self::Register {
+        _mm512_cvtepi32_epi64(_mm256_loadu_si256(mem.cast()))
+    }
+}
+
+impl SimdWidenRegister<u32, u64> for Avx512 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const u32) -> Self::Register {
+        _mm512_cvtepu32_epi64(_mm256_loadu_si256(mem.cast()))
+    }
+}
+
+impl SimdWidenRegister<u16, u32> for Avx512 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const u16) -> Self::Register {
+        _mm512_cvtepu16_epi32(_mm256_loadu_si256(mem.cast()))
+    }
+}
+
+impl SimdWidenRegister<u8, u32> for Avx512 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const u8) -> Self::Register {
+        _mm512_cvtepu8_epi32(_mm_loadu_si128(mem.cast()))
+    }
+}
+
+impl SimdWidenRegister<u16, u64> for Avx512 {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const u16) -> Self::Register {
+        _mm512_cvtepu16_epi64(_mm_loadu_si128(mem.cast()))
+    }
+}
+
+impl SimdHammingRegister for Avx512 {
+    type Register = __m512i;
+
+    #[inline(always)]
+    unsafe fn load_words(mem: *const u64) -> Self::Register {
+        _mm512_loadu_si512(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed_acc() -> Self::Register {
+        _mm512_setzero_si512()
+    }
+
+    #[inline(always)]
+    unsafe fn xor_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm512_xor_si512(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn and_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm512_and_si512(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn or_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm512_or_si512(l1, l2)
+    }
+
+    #[inline(always)]
+    /// The `vpopcntq` instruction requires `avx512vpopcntdq`, which is provided by
+    /// `Avx512Vpopcntdq`, so like AVX2 this uses the Wilkes-Wheeler-Gill algorithm.
+    unsafe fn popcount_acc(
+        words: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        let lookup = _mm512_set4_epi32(0x04030302, 0x03020201, 0x03020201, 0x02010100);
+        let low_mask = _mm512_set1_epi8(0x0F);
+
+        let lo = _mm512_and_si512(words, low_mask);
+        let hi = _mm512_and_si512(_mm512_srli_epi16::<4>(words), low_mask);
+        let counts = _mm512_add_epi8(
+            _mm512_shuffle_epi8(lookup, lo),
+            _mm512_shuffle_epi8(lookup, hi),
+        );
+
+        _mm512_add_epi64(acc, _mm512_sad_epu8(counts, _mm512_setzero_si512()))
+    }
+
+    #[inline(always)]
+    unsafe fn add_acc(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        _mm512_add_epi64(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_acc_to_value(acc: Self::Register) -> u64 {
+        _mm512_reduce_add_epi64(acc) as u64
+    }
+}
+
+impl SimdFloatRegister<f32> for Avx512 {
+    #[inline(always)]
+    /// Computes the hypotenuse as `hi * sqrt(1 + (lo / hi)²)` where `hi` and `lo` are
+    /// the larger and smaller of the absolute values.
+    unsafe fn hypot(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let a = _mm512_abs_ps(l1);
+        let b = _mm512_abs_ps(l2);
+
+        let hi = _mm512_max_ps(a, b);
+        let lo = _mm512_min_ps(a, b);
+        let ratio = _mm512_div_ps(lo, hi);
+        let scale = _mm512_sqrt_ps(_mm512_fmadd_ps(ratio, ratio, _mm512_set1_ps(1.0)));
+        let result = _mm512_mul_ps(hi, scale);
+
+        // `0 / 0` produces a NaN ratio when both inputs are zero.
+        let zero = _mm512_setzero_ps();
+        let is_zero = _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(hi, zero);
+        let result = _mm512_mask_blend_ps(is_zero, result, zero);
+
+        let is_nan = _mm512_cmp_ps_mask::<_CMP_UNORD_Q>(a, b);
+        let result = _mm512_mask_blend_ps(is_nan, result, _mm512_add_ps(a, b));
+
+        let inf = _mm512_set1_ps(f32::INFINITY);
+        let is_inf = _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(a, inf)
+            | _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(b, inf);
+        _mm512_mask_blend_ps(is_inf, result, inf)
+    }
+}
+
+impl SimdFloatRegister<f64> for Avx512 {
+    #[inline(always)]
+    /// Computes the hypotenuse as `hi * sqrt(1 + (lo / hi)²)` where `hi` and `lo` are
+    /// the larger and smaller of the absolute values.
+    unsafe fn hypot(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let a = _mm512_abs_pd(l1);
+        let b = _mm512_abs_pd(l2);
+
+        let hi = _mm512_max_pd(a, b);
+        let lo = _mm512_min_pd(a, b);
+        let ratio = _mm512_div_pd(lo, hi);
+        let scale = _mm512_sqrt_pd(_mm512_fmadd_pd(ratio, ratio, _mm512_set1_pd(1.0)));
+        let result = _mm512_mul_pd(hi, scale);
+
+        // `0 / 0` produces a NaN ratio when both inputs are zero.
+        let zero = _mm512_setzero_pd();
+        let is_zero = _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(hi, zero);
+        let result = _mm512_mask_blend_pd(is_zero, result, zero);
+
+        let is_nan = _mm512_cmp_pd_mask::<_CMP_UNORD_Q>(a, b);
+        let result = _mm512_mask_blend_pd(is_nan, result, _mm512_add_pd(a, b));
+
+        let inf = _mm512_set1_pd(f64::INFINITY);
+        let is_inf = _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(a, inf)
+            | _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(b, inf);
+        _mm512_mask_blend_pd(is_inf, result, inf)
+    }
+}
+
+#[inline(always)]
+unsafe fn shl_epi16(l1: __m512i, l2: __m512i) -> __m512i {
+    let count = _mm512_and_si512(l2, _mm512_set1_epi16(15));
+    _mm512_sllv_epi16(l1, count)
+}
+
+#[inline(always)]
+unsafe fn sra_epi16(l1: __m512i, l2: __m512i) -> __m512i {
+    let count = _mm512_and_si512(l2, _mm512_set1_epi16(15));
+    _mm512_srav_epi16(l1, count)
+}
+
+#[inline(always)]
+unsafe fn srl_epi16(l1: __m512i, l2: __m512i) -> __m512i {
+    let count = _mm512_and_si512(l2, _mm512_set1_epi16(15));
+    _mm512_srlv_epi16(l1, count)
+}
+
+#[inline(always)]
+unsafe fn shl_epi32(l1: __m512i, l2: __m512i) -> __m512i {
+    let count = _mm512_and_si512(l2, _mm512_set1_epi32(31));
+    _mm512_sllv_epi32(l1, count)
+}
+
+#[inline(always)]
+unsafe fn sra_epi32(l1: __m512i, l2: __m512i) -> __m512i {
+    let count = _mm512_and_si512(l2, _mm512_set1_epi32(31));
+    _mm512_srav_epi32(l1, count)
+}
+
+#[inline(always)]
+unsafe fn srl_epi32(l1: __m512i, l2: __m512i) -> __m512i {
+    let count = _mm512_and_si512(l2, _mm512_set1_epi32(31));
+    _mm512_srlv_epi32(l1, count)
+}
+
+#[inline(always)]
+unsafe fn shl_epi64(l1: __m512i, l2: __m512i) -> __m512i {
+    let count = _mm512_and_si512(l2, _mm512_set1_epi64(63));
+    _mm512_sllv_epi64(l1, count)
+}
+
+#[inline(always)]
+unsafe fn sra_epi64(l1: __m512i, l2: __m512i) -> __m512i {
+    let count = _mm512_and_si512(l2, _mm512_set1_epi64(63));
+    _mm512_srav_epi64(l1, count)
+}
+
+#[inline(always)]
+unsafe fn srl_epi64(l1: __m512i, l2: __m512i) -> __m512i {
+    let count = _mm512_and_si512(l2, _mm512_set1_epi64(63));
+    _mm512_srlv_epi64(l1, count)
+}
+
+#[inline(always)]
+unsafe fn scalar_shl<T: WrappingShift, const N: usize>(
+    l1: __m512i,
+    l2: __m512i,
+) -> __m512i {
+    apply_scalar_op::<_, T, N>(l1, l2, T::shl_wrapping)
+}
+
+#[inline(always)]
+unsafe fn scalar_shr<T: WrappingShift, const N: usize>(
+    l1: __m512i,
+    l2: __m512i,
+) -> __m512i {
+    apply_scalar_op::<_, T, N>(l1, l2, T::shr_wrapping)
+}
+
+#[inline(always)]
+unsafe fn scalar_saturating_add<T: SaturatingArithmetic, const N: usize>(
+    l1: __m512i,
+    l2: __m512i,
+) -> __m512i {
+    apply_scalar_op::<_, T, N>(l1, l2, T::add_saturating)
+}
+
+#[inline(always)]
+unsafe fn scalar_saturating_sub<T: SaturatingArithmetic, const N: usize>(
+    l1: __m512i,
+    l2: __m512i,
+) -> __m512i {
+    apply_scalar_op::<_, T, N>(l1, l2, T::sub_saturating)
+}
@@ -0,0 +1,126 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+use core::mem;
+
+use half::bf16;
+
+use super::core_simd_api::DenseLane;
+
+/// AVX512 BF16 enabled SIMD operations.
+///
+/// This requires the `avx512f`, `avx512bw` and `avx512bf16` CPU features be enabled.
+///
+/// BF16 provides the `vdpbf16ps` instruction which multiplies pairs of `bf16` values,
+/// accumulating the products directly into `f32` lanes without converting the inputs
+/// first. Unlike the AVX2 `bf16` registers, which widen every value to `f32`, this only
+/// accelerates the dot product.
+pub struct Avx512Bf16;
+
+impl Avx512Bf16 {
+    /// The number of `bf16` elements in a single input register.
+    const ELEMENTS_PER_LANE: usize = 32;
+    /// The number of `bf16` elements in a dense lane.
+    const ELEMENTS_PER_DENSE: usize =
+        Self::ELEMENTS_PER_LANE * DenseLane::<__m512>::NUM_LANES;
+
+    #[inline(always)]
+    /// Computes the dot product of two `bf16` vectors accumulating in `f32`.
+    ///
+    /// Each `vdpbf16ps` multiplies the values exactly and accumulates the pair of
+    /// products into the `f32` lanes with round to nearest even, denormal inputs and
+    /// outputs are flushed to zero.
+    ///
+    /// # Safety
+    ///
+    /// The `avx512f`, `avx512bw` and `avx512bf16` CPU features must be available.
+    ///
+    /// # Panics
+    ///
+    /// If the lengths of `a` and `b` do not match.
+    pub unsafe fn dot_f32acc(a: &[bf16], b: &[bf16]) -> f32 {
+        assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+        let len = a.len();
+        let a = a.as_ptr();
+        let b = b.as_ptr();
+
+        let offset_from = len % Self::ELEMENTS_PER_DENSE;
+
+        let mut total = DenseLane::copy(_mm512_setzero_ps());
+
+        // Operate over dense lanes first.
+        let mut i = 0;
+        while i < (len - offset_from) {
+            let l1 = Self::load_dense(a.add(i));
+            let l2 = Self::load_dense(b.add(i));
+            total = DenseLane {
+                a: _mm512_dpbf16_ps(total.a, l1.a, l2.a),
+                b: _mm512_dpbf16_ps(total.b, l1.b, l2.b),
+                c: _mm512_dpbf16_ps(total.c, l1.c, l2.c),
+                d: _mm512_dpbf16_ps(total.d, l1.d, l2.d),
+                e: _mm512_dpbf16_ps(total.e, l1.e, l2.e),
+                f: _mm512_dpbf16_ps(total.f, l1.f, l2.f),
+                g: _mm512_dpbf16_ps(total.g, l1.g, l2.g),
+                h: _mm512_dpbf16_ps(total.h, l1.h, l2.h),
+            };
+
+            i += Self::ELEMENTS_PER_DENSE;
+        }
+
+        let acc1 = _mm512_add_ps(
+            _mm512_add_ps(total.a, total.b),
+            _mm512_add_ps(total.c, total.d),
+        );
+        let acc2 = _mm512_add_ps(
+            _mm512_add_ps(total.e, total.f),
+            _mm512_add_ps(total.g, total.h),
+        );
+        let mut total = _mm512_add_ps(acc1, acc2);
+
+        // Operate over single registers next.
+        let offset_from = offset_from % Self::ELEMENTS_PER_LANE;
+        while i < (len - offset_from) {
+            let l1 = Self::load(a.add(i));
+            let l2 = Self::load(b.add(i));
+            total = _mm512_dpbf16_ps(total, l1, l2);
+
+            i += Self::ELEMENTS_PER_LANE;
+        }
+
+        // Handle the remainder.
+        let mut total = _mm512_reduce_add_ps(total);
+
+        while i < len {
+            let a = a.add(i).read().to_f32();
+            let b = b.add(i).read().to_f32();
+            total += a * b;
+
+            i += 1;
+        }
+
+        total
+    }
+
+    #[inline(always)]
+    unsafe fn load(mem: *const bf16) -> __m512bh {
+        mem::transmute(_mm512_loadu_si512(mem.cast()))
+    }
+
+    #[allow(clippy::identity_op)]
+    #[allow(clippy::erasing_op)]
+    #[inline(always)]
+    unsafe fn load_dense(mem: *const bf16) -> DenseLane<__m512bh> {
+        DenseLane {
+            a: Self::load(mem.add(Self::ELEMENTS_PER_LANE * 0)),
+            b: Self::load(mem.add(Self::ELEMENTS_PER_LANE * 1)),
+            c: Self::load(mem.add(Self::ELEMENTS_PER_LANE * 2)),
+            d: Self::load(mem.add(Self::ELEMENTS_PER_LANE * 3)),
+            e: Self::load(mem.add(Self::ELEMENTS_PER_LANE * 4)),
+            f: Self::load(mem.add(Self::ELEMENTS_PER_LANE * 5)),
+            g: Self::load(mem.add(Self::ELEMENTS_PER_LANE * 6)),
+            h: Self::load(mem.add(Self::ELEMENTS_PER_LANE * 7)),
+        }
+    }
+}
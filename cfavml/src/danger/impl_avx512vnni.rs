@@ -0,0 +1,149 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use super::core_simd_api::SimdWideningDotRegister;
+
+/// AVX512 VNNI enabled SIMD operations.
+///
+/// This requires the `avx512f`, `avx512bw` and `avx512vnni` CPU features be enabled.
+///
+/// VNNI provides the `vpdpbusd` instruction which multiplies groups of four unsigned
+/// and signed bytes, accumulating the products directly into `i32` lanes. This makes it
+/// ideal for the widened dot products used with quantized `i8` and `u8` vectors, the
+/// `vpdpwssd` word variant is likewise used for `i16` vectors.
+pub struct Avx512Vnni;
+
+impl SimdWideningDotRegister<i8> for Avx512Vnni {
+    type Register = __m512i;
+    type Accumulator = __m512i;
+
+    #[inline(always)]
+    unsafe fn load_widen(mem: *const i8) -> Self::Register {
+        _mm512_loadu_si512(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed_acc() -> Self::Accumulator {
+        _mm512_setzero_si512()
+    }
+
+    #[inline(always)]
+    /// Computes the widened dot product of two signed byte registers.
+    ///
+    /// `vpdpbusd` requires the first operand to be unsigned, so `l1` is flipped into the
+    /// unsigned domain by adding `128` and the resulting `128 * l2` bias is removed
+    /// afterwards, the wrapping arithmetic means the final result is exact.
+    unsafe fn dot_acc(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Accumulator,
+    ) -> Self::Accumulator {
+        let bias = _mm512_set1_epi8(i8::MIN);
+        let l1_unsigned = _mm512_xor_si512(l1, bias);
+
+        let acc = _mm512_dpbusd_epi32(acc, l1_unsigned, l2);
+        let correction = _mm512_dpbusd_epi32(_mm512_setzero_si512(), bias, l2);
+        _mm512_sub_epi32(acc, correction)
+    }
+
+    #[inline(always)]
+    unsafe fn add_acc(
+        l1: Self::Accumulator,
+        l2: Self::Accumulator,
+    ) -> Self::Accumulator {
+        _mm512_add_epi32(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_acc_to_value(acc: Self::Accumulator) -> i32 {
+        _mm512_reduce_add_epi32(acc)
+    }
+}
+
+impl SimdWideningDotRegister<u8> for Avx512Vnni {
+    type Register = __m512i;
+    type Accumulator = __m512i;
+
+    #[inline(always)]
+    unsafe fn load_widen(mem: *const u8) -> Self::Register {
+        _mm512_loadu_si512(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed_acc() -> Self::Accumulator {
+        _mm512_setzero_si512()
+    }
+
+    #[inline(always)]
+    /// Computes the widened dot product of two unsigned byte registers.
+    ///
+    /// `vpdpbusd` requires the second operand to be signed, so `l2` is flipped into the
+    /// signed domain by subtracting `128` and the resulting `-128 * l1` bias is removed
+    /// afterwards, the wrapping arithmetic means the final result is exact.
+    unsafe fn dot_acc(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Accumulator,
+    ) -> Self::Accumulator {
+        let bias = _mm512_set1_epi8(i8::MIN);
+        let l2_signed = _mm512_xor_si512(l2, bias);
+
+        let acc = _mm512_dpbusd_epi32(acc, l1, l2_signed);
+        let correction = _mm512_dpbusd_epi32(_mm512_setzero_si512(), l1, bias);
+        _mm512_sub_epi32(acc, correction)
+    }
+
+    #[inline(always)]
+    unsafe fn add_acc(
+        l1: Self::Accumulator,
+        l2: Self::Accumulator,
+    ) -> Self::Accumulator {
+        _mm512_add_epi32(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_acc_to_value(acc: Self::Accumulator) -> i32 {
+        _mm512_reduce_add_epi32(acc)
+    }
+}
+
+impl SimdWideningDotRegister<i16> for Avx512Vnni {
+    type Register = __m512i;
+    type Accumulator = __m512i;
+
+    #[inline(always)]
+    unsafe fn load_widen(mem: *const i16) -> Self::Register {
+        _mm512_loadu_si512(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed_acc() -> Self::Accumulator {
+        _mm512_setzero_si512()
+    }
+
+    #[inline(always)]
+    /// `vpdpwssd` multiplies pairs of signed words and adds both products directly into
+    /// the `i32` lanes, so unlike the byte variants no bias correction is required.
+    unsafe fn dot_acc(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Accumulator,
+    ) -> Self::Accumulator {
+        _mm512_dpwssd_epi32(acc, l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn add_acc(
+        l1: Self::Accumulator,
+        l2: Self::Accumulator,
+    ) -> Self::Accumulator {
+        _mm512_add_epi32(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_acc_to_value(acc: Self::Accumulator) -> i32 {
+        _mm512_reduce_add_epi32(acc)
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use super::core_simd_api::SimdHammingRegister;
+use super::impl_avx512::Avx512;
+
+/// AVX512 VPOPCNTDQ enabled SIMD operations.
+///
+/// This requires the `avx512f`, `avx512bw` and `avx512vpopcntdq` CPU features be enabled.
+///
+/// VPOPCNTDQ provides the `vpopcntq` instruction which counts the set bits of each
+/// `u64` lane directly, replacing the nibble lookup tables otherwise needed when
+/// computing the Hamming distance of bit-packed vectors.
+pub struct Avx512Vpopcntdq;
+
+impl SimdHammingRegister for Avx512Vpopcntdq {
+    type Register = __m512i;
+
+    #[inline(always)]
+    unsafe fn load_words(mem: *const u64) -> Self::Register {
+        Avx512::load_words(mem)
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed_acc() -> Self::Register {
+        Avx512::zeroed_acc()
+    }
+
+    #[inline(always)]
+    unsafe fn xor_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Avx512::xor_words(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn and_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Avx512::and_words(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn or_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Avx512::or_words(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn popcount_acc(
+        words: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        _mm512_add_epi64(acc, _mm512_popcnt_epi64(words))
+    }
+
+    #[inline(always)]
+    unsafe fn add_acc(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        Avx512::add_acc(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_acc_to_value(acc: Self::Register) -> u64 {
+        Avx512::sum_acc_to_value(acc)
+    }
+}
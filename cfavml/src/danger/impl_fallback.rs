@@ -1,5 +1,17 @@
-use crate::danger::{DenseLane, SimdRegister};
-use crate::math::{AutoMath, Math};
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+use crate::danger::{
+    DenseLane,
+    SimdBitwiseRegister,
+    SimdCastRegister,
+    SimdFloatRegister,
+    SimdHammingRegister,
+    SimdRegister,
+    SimdSaturatingRegister,
+    SimdWidenRegister,
+    SimdWideningDotRegister,
+};
+use crate::math::{AutoMath, CastAs, Math, SaturatingArithmetic, WrappingShift};
 
 /// Fallback SIMD-like operations.
 ///
@@ -129,4 +141,213 @@ where
     unsafe fn neq(l1: Self::Register, l2: Self::Register) -> Self::Register {
         AutoMath::cast_bool(!AutoMath::cmp_eq(l1, l2))
     }
+
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        if AutoMath::cmp_eq(mask, AutoMath::zero()) {
+            l2
+        } else {
+            l1
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn neg(l1: Self::Register) -> Self::Register {
+        AutoMath::neg(l1)
+    }
+}
+
+impl<T> SimdBitwiseRegister<T> for Fallback
+where
+    T: Copy
+        + BitAnd<Output = T>
+        + BitOr<Output = T>
+        + BitXor<Output = T>
+        + Not<Output = T>
+        + WrappingShift,
+    AutoMath: Math<T>,
+{
+    #[inline(always)]
+    unsafe fn bitand(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        l1 & l2
+    }
+
+    #[inline(always)]
+    unsafe fn bitor(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        l1 | l2
+    }
+
+    #[inline(always)]
+    unsafe fn bitxor(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        l1 ^ l2
+    }
+
+    #[inline(always)]
+    unsafe fn bitnot(l1: Self::Register) -> Self::Register {
+        !l1
+    }
+
+    #[inline(always)]
+    unsafe fn shl(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        l1.shl_wrapping(l2)
+    }
+
+    #[inline(always)]
+    unsafe fn shr(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        l1.shr_wrapping(l2)
+    }
+}
+
+impl<T> SimdSaturatingRegister<T> for Fallback
+where
+    T: SaturatingArithmetic,
+    AutoMath: Math<T>,
+{
+    #[inline(always)]
+    unsafe fn saturating_add(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        l1.add_saturating(l2)
+    }
+
+    #[inline(always)]
+    unsafe fn saturating_sub(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        l1.sub_saturating(l2)
+    }
+}
+
+impl<F, T> SimdCastRegister<F, T> for Fallback
+where
+    F: CastAs<T>,
+    T: Copy,
+{
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        1
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const F, result: *mut T) {
+        result.write(mem.read().cast_as())
+    }
+}
+
+impl<F, T> SimdWidenRegister<F, T> for Fallback
+where
+    F: CastAs<T>,
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const F) -> Self::Register {
+        mem.read().cast_as()
+    }
+}
+
+impl SimdFloatRegister<f32> for Fallback {
+    #[inline(always)]
+    unsafe fn hypot(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        AutoMath::hypot(l1, l2)
+    }
+}
+
+impl SimdFloatRegister<f64> for Fallback {
+    #[inline(always)]
+    unsafe fn hypot(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        AutoMath::hypot(l1, l2)
+    }
+}
+
+macro_rules! impl_fallback_widening_dot {
+    ($t:ty) => {
+        impl SimdWideningDotRegister<$t> for Fallback {
+            type Register = $t;
+            type Accumulator = i32;
+
+            #[inline(always)]
+            unsafe fn load_widen(mem: *const $t) -> Self::Register {
+                mem.read()
+            }
+
+            #[inline(always)]
+            unsafe fn zeroed_acc() -> Self::Accumulator {
+                0
+            }
+
+            #[inline(always)]
+            unsafe fn dot_acc(
+                l1: Self::Register,
+                l2: Self::Register,
+                acc: Self::Accumulator,
+            ) -> Self::Accumulator {
+                acc.wrapping_add((l1 as i32).wrapping_mul(l2 as i32))
+            }
+
+            #[inline(always)]
+            unsafe fn add_acc(
+                l1: Self::Accumulator,
+                l2: Self::Accumulator,
+            ) -> Self::Accumulator {
+                l1.wrapping_add(l2)
+            }
+
+            #[inline(always)]
+            unsafe fn sum_acc_to_value(acc: Self::Accumulator) -> i32 {
+                acc
+            }
+        }
+    };
+}
+
+impl_fallback_widening_dot!(i8);
+impl_fallback_widening_dot!(u8);
+impl_fallback_widening_dot!(i16);
+
+impl SimdHammingRegister for Fallback {
+    type Register = u64;
+
+    #[inline(always)]
+    unsafe fn load_words(mem: *const u64) -> Self::Register {
+        mem.read()
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed_acc() -> Self::Register {
+        0
+    }
+
+    #[inline(always)]
+    unsafe fn xor_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        l1 ^ l2
+    }
+
+    #[inline(always)]
+    unsafe fn and_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        l1 & l2
+    }
+
+    #[inline(always)]
+    unsafe fn or_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        l1 | l2
+    }
+
+    #[inline(always)]
+    unsafe fn popcount_acc(
+        words: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        acc + words.count_ones() as u64
+    }
+
+    #[inline(always)]
+    unsafe fn add_acc(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        l1 + l2
+    }
+
+    #[inline(always)]
+    unsafe fn sum_acc_to_value(acc: Self::Register) -> u64 {
+        acc
+    }
 }
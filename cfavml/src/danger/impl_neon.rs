@@ -2,7 +2,17 @@ use core::arch::aarch64::*;
 use core::iter::zip;
 use core::mem;
 
-use crate::danger::{DenseLane, SimdRegister};
+use crate::danger::{
+    DenseLane,
+    SimdBitwiseRegister,
+    SimdCastRegister,
+    SimdFloatRegister,
+    SimdHammingRegister,
+    SimdRegister,
+    SimdSaturatingRegister,
+    SimdWidenRegister,
+    SimdWideningDotRegister,
+};
 use crate::math::{AutoMath, Math};
 
 const BITS_8_CAPACITY: usize = 16;
@@ -13,6 +23,13 @@ const BITS_64_CAPACITY: usize = 2;
 /// NEON enabled SIMD operations.
 ///
 /// This requires the `neon` CPU features be enabled.
+///
+/// Unlike x86, fused multiply-add is always available on aarch64, so the `f32` and `f64`
+/// `fmadd` operations always use the fused `vfmaq` instructions and there is no need
+/// for a separate FMA register type like `Avx2Fma`.
+///
+/// NEON has no dedicated aligned load or store instructions, so the aligned register
+/// methods fall back to their unaligned counterparts.
 pub struct Neon;
 
 impl SimdRegister<f32> for Neon {
@@ -90,6 +107,21 @@ impl SimdRegister<f32> for Neon {
         )
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = vceqq_f32(mask, vdupq_n_f32(0.0));
+        vbslq_f32(is_zero, l2, l1)
+    }
+
+    #[inline(always)]
+    unsafe fn neg(l1: Self::Register) -> Self::Register {
+        vnegq_f32(l1)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         apply_fallback_math::<f32, Self::Register, _, BITS_32_CAPACITY>(
@@ -145,6 +177,16 @@ impl SimdRegister<f32> for Neon {
     unsafe fn write(mem: *mut f32, reg: Self::Register) {
         vst1q_f32(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const f32) {
+        prefetch_l1(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        !movemask_u32x4(vceqzq_f32(mask)) & 0xF
+    }
 }
 
 impl SimdRegister<f64> for Neon {
@@ -222,6 +264,21 @@ impl SimdRegister<f64> for Neon {
         )
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_zero = vceqq_f64(mask, vdupq_n_f64(0.0));
+        vbslq_f64(is_zero, l2, l1)
+    }
+
+    #[inline(always)]
+    unsafe fn neg(l1: Self::Register) -> Self::Register {
+        vnegq_f64(l1)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         apply_fallback_math::<f64, Self::Register, _, BITS_64_CAPACITY>(
@@ -277,6 +334,16 @@ impl SimdRegister<f64> for Neon {
     unsafe fn write(mem: *mut f64, reg: Self::Register) {
         vst1q_f64(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const f64) {
+        prefetch_l1(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        !movemask_u64x2(vceqzq_f64(mask)) & 0b11
+    }
 }
 
 impl SimdRegister<i8> for Neon {
@@ -363,6 +430,16 @@ impl SimdRegister<i8> for Neon {
         })
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_non_zero = vtstq_s8(mask, mask);
+        vbslq_s8(is_non_zero, l1, l2)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         apply_fallback_math::<i8, Self::Register, _, BITS_8_CAPACITY>(l1, l2, |a, b| {
@@ -410,6 +487,16 @@ impl SimdRegister<i8> for Neon {
     unsafe fn write(mem: *mut i8, reg: Self::Register) {
         vst1q_s8(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const i8) {
+        prefetch_l1(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        movemask_u8x16(vtstq_s8(mask, mask))
+    }
 }
 
 impl SimdRegister<i16> for Neon {
@@ -492,6 +579,16 @@ impl SimdRegister<i16> for Neon {
         )
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_non_zero = vtstq_s16(mask, mask);
+        vbslq_s16(is_non_zero, l1, l2)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         apply_fallback_math::<i16, Self::Register, _, BITS_16_CAPACITY>(
@@ -557,6 +654,16 @@ impl SimdRegister<i16> for Neon {
     unsafe fn write(mem: *mut i16, reg: Self::Register) {
         vst1q_s16(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const i16) {
+        prefetch_l1(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        movemask_u16x8(vtstq_s16(mask, mask))
+    }
 }
 
 impl SimdRegister<i32> for Neon {
@@ -639,6 +746,16 @@ impl SimdRegister<i32> for Neon {
         )
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_non_zero = vtstq_s32(mask, mask);
+        vbslq_s32(is_non_zero, l1, l2)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         apply_fallback_math::<i32, Self::Register, _, BITS_32_CAPACITY>(
@@ -704,6 +821,16 @@ impl SimdRegister<i32> for Neon {
     unsafe fn write(mem: *mut i32, reg: Self::Register) {
         vst1q_s32(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const i32) {
+        prefetch_l1(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        movemask_u32x4(vtstq_s32(mask, mask))
+    }
 }
 
 impl SimdRegister<i64> for Neon {
@@ -798,6 +925,16 @@ impl SimdRegister<i64> for Neon {
         )
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_non_zero = vtstq_s64(mask, mask);
+        vbslq_s64(is_non_zero, l1, l2)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         apply_fallback_math::<i64, Self::Register, _, BITS_64_CAPACITY>(
@@ -865,6 +1002,16 @@ impl SimdRegister<i64> for Neon {
     unsafe fn write(mem: *mut i64, reg: Self::Register) {
         vst1q_s64(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const i64) {
+        prefetch_l1(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        movemask_u64x2(vtstq_s64(mask, mask))
+    }
 }
 
 impl SimdRegister<u8> for Neon {
@@ -941,6 +1088,16 @@ impl SimdRegister<u8> for Neon {
         })
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_non_zero = vtstq_u8(mask, mask);
+        vbslq_u8(is_non_zero, l1, l2)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         apply_fallback_math::<u8, Self::Register, _, BITS_8_CAPACITY>(l1, l2, |a, b| {
@@ -998,6 +1155,16 @@ impl SimdRegister<u8> for Neon {
     unsafe fn write(mem: *mut u8, reg: Self::Register) {
         vst1q_u8(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const u8) {
+        prefetch_l1(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        movemask_u8x16(vtstq_u8(mask, mask))
+    }
 }
 
 impl SimdRegister<u16> for Neon {
@@ -1080,6 +1247,16 @@ impl SimdRegister<u16> for Neon {
         )
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_non_zero = vtstq_u16(mask, mask);
+        vbslq_u16(is_non_zero, l1, l2)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         apply_fallback_math::<u16, Self::Register, _, BITS_16_CAPACITY>(
@@ -1145,6 +1322,16 @@ impl SimdRegister<u16> for Neon {
     unsafe fn write(mem: *mut u16, reg: Self::Register) {
         vst1q_u16(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const u16) {
+        prefetch_l1(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        movemask_u16x8(vtstq_u16(mask, mask))
+    }
 }
 
 impl SimdRegister<u32> for Neon {
@@ -1227,6 +1414,16 @@ impl SimdRegister<u32> for Neon {
         )
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_non_zero = vtstq_u32(mask, mask);
+        vbslq_u32(is_non_zero, l1, l2)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         apply_fallback_math::<u32, Self::Register, _, BITS_32_CAPACITY>(
@@ -1292,6 +1489,16 @@ impl SimdRegister<u32> for Neon {
     unsafe fn write(mem: *mut u32, reg: Self::Register) {
         vst1q_u32(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const u32) {
+        prefetch_l1(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        movemask_u32x4(vtstq_u32(mask, mask))
+    }
 }
 
 impl SimdRegister<u64> for Neon {
@@ -1386,6 +1593,16 @@ impl SimdRegister<u64> for Neon {
         )
     }
 
+    #[inline(always)]
+    unsafe fn select(
+        mask: Self::Register,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        let is_non_zero = vtstq_u64(mask, mask);
+        vbslq_u64(is_non_zero, l1, l2)
+    }
+
     #[inline(always)]
     unsafe fn lt(l1: Self::Register, l2: Self::Register) -> Self::Register {
         apply_fallback_math::<u64, Self::Register, _, BITS_64_CAPACITY>(
@@ -1453,6 +1670,76 @@ impl SimdRegister<u64> for Neon {
     unsafe fn write(mem: *mut u64, reg: Self::Register) {
         vst1q_u64(mem, reg)
     }
+
+    #[inline(always)]
+    unsafe fn prefetch(mem: *const u64) {
+        prefetch_l1(mem.cast())
+    }
+
+    #[inline(always)]
+    unsafe fn nonzero_bitmask(mask: Self::Register) -> u64 {
+        movemask_u64x2(vtstq_u64(mask, mask))
+    }
+}
+
+#[inline(always)]
+/// Prefetches the cache line containing `mem` into the L1 cache with `prfm`.
+///
+/// There is no stable intrinsic for `prfm`, so the instruction is emitted directly,
+/// it never faults even if `mem` does not point to valid memory.
+unsafe fn prefetch_l1(mem: *const u8) {
+    core::arch::asm!(
+        "prfm pldl1keep, [{mem}]",
+        mem = in(reg) mem,
+        options(nostack, readonly, preserves_flags),
+    );
+}
+
+#[inline(always)]
+/// Returns the bitmask of a `uint8x16_t` where each element is either all bits set or
+/// zero.
+///
+/// NEON has no `movemask`, instead each element is narrowed to a nibble with `vshrn`
+/// and the lowest bit of each nibble is then folded down into a contiguous mask.
+unsafe fn movemask_u8x16(mask: uint8x16_t) -> u64 {
+    let nibbles = vshrn_n_u16::<4>(vreinterpretq_u16_u8(mask));
+    let mut bits =
+        vget_lane_u64::<0>(vreinterpret_u64_u8(nibbles)) & 0x1111_1111_1111_1111;
+    bits = (bits | (bits >> 3)) & 0x0303_0303_0303_0303;
+    bits = (bits | (bits >> 6)) & 0x000F_000F_000F_000F;
+    bits = (bits | (bits >> 12)) & 0x0000_00FF_0000_00FF;
+    (bits | (bits >> 24)) & 0xFFFF
+}
+
+#[inline(always)]
+/// Returns the bitmask of a `uint16x8_t` where each element is either all bits set or
+/// zero.
+///
+/// Each element is narrowed to a byte with `vshrn`, the multiply then gathers the
+/// lowest bit of each byte into the top byte.
+unsafe fn movemask_u16x8(mask: uint16x8_t) -> u64 {
+    let bytes = vget_lane_u64::<0>(vreinterpret_u64_u8(vshrn_n_u16::<8>(mask)));
+    (bytes & 0x0101_0101_0101_0101).wrapping_mul(0x0102_0408_1020_4080) >> 56
+}
+
+#[inline(always)]
+/// Returns the bitmask of a `uint32x4_t` where each element is either all bits set or
+/// zero.
+///
+/// Each element is narrowed to 16 bits with `vshrn`, the multiply then gathers the
+/// lowest bit of each element into bits `48..52`.
+unsafe fn movemask_u32x4(mask: uint32x4_t) -> u64 {
+    let halves = vget_lane_u64::<0>(vreinterpret_u64_u16(vshrn_n_u32::<16>(mask)));
+    let gather = (1 << 48) | (1 << 33) | (1 << 18) | (1 << 3);
+    ((halves & 0x0001_0001_0001_0001).wrapping_mul(gather) >> 48) & 0xF
+}
+
+#[inline(always)]
+/// Returns the bitmask of a `uint64x2_t` where each element is either all bits set or
+/// zero.
+unsafe fn movemask_u64x2(mask: uint64x2_t) -> u64 {
+    let words = vget_lane_u64::<0>(vreinterpret_u64_u32(vshrn_n_u64::<32>(mask)));
+    (words & 1) | ((words >> 31) & 2)
 }
 
 #[inline]
@@ -1477,6 +1764,565 @@ where
     R::from_array(result)
 }
 
+/// Implements the bitwise operations for the integer types.
+///
+/// NEON has no `vmvnq` variant for 64 bit lanes, so _not_ is expressed as
+/// a xor with all bits set for every type to keep things uniform.
+///
+/// Shifts are performed with `vshlq` which shifts right when given a negative count,
+/// this is an arithmetic shift for signed types and a logical shift for unsigned types.
+/// The counts are always signed, so unsigned counts are reinterpreted after masking.
+macro_rules! impl_neon_bitwise {
+    (
+        $t:ident,
+        and = $and:ident,
+        or = $or:ident,
+        xor = $xor:ident,
+        dup = $dup:ident,
+        shl = $shl:ident,
+        neg = $neg:ident,
+        $(reinterpret = $reinterpret:ident,)?
+    ) => {
+        impl SimdBitwiseRegister<$t> for Neon {
+            #[inline(always)]
+            unsafe fn bitand(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                $and(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn bitor(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                $or(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn bitxor(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                $xor(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn bitnot(l1: Self::Register) -> Self::Register {
+                $xor(l1, $dup(!0))
+            }
+
+            #[inline(always)]
+            unsafe fn shl(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                let count = $and(l2, $dup(($t::BITS - 1) as $t));
+                $shl(l1, $($reinterpret)?(count))
+            }
+
+            #[inline(always)]
+            unsafe fn shr(l1: Self::Register, l2: Self::Register) -> Self::Register {
+                let count = $and(l2, $dup(($t::BITS - 1) as $t));
+                $shl(l1, $neg($($reinterpret)?(count)))
+            }
+        }
+    };
+}
+
+impl_neon_bitwise!(
+    i8,
+    and = vandq_s8,
+    or = vorrq_s8,
+    xor = veorq_s8,
+    dup = vdupq_n_s8,
+    shl = vshlq_s8,
+    neg = vnegq_s8,
+);
+impl_neon_bitwise!(
+    i16,
+    and = vandq_s16,
+    or = vorrq_s16,
+    xor = veorq_s16,
+    dup = vdupq_n_s16,
+    shl = vshlq_s16,
+    neg = vnegq_s16,
+);
+impl_neon_bitwise!(
+    i32,
+    and = vandq_s32,
+    or = vorrq_s32,
+    xor = veorq_s32,
+    dup = vdupq_n_s32,
+    shl = vshlq_s32,
+    neg = vnegq_s32,
+);
+impl_neon_bitwise!(
+    i64,
+    and = vandq_s64,
+    or = vorrq_s64,
+    xor = veorq_s64,
+    dup = vdupq_n_s64,
+    shl = vshlq_s64,
+    neg = vnegq_s64,
+);
+impl_neon_bitwise!(
+    u8,
+    and = vandq_u8,
+    or = vorrq_u8,
+    xor = veorq_u8,
+    dup = vdupq_n_u8,
+    shl = vshlq_u8,
+    neg = vnegq_s8,
+    reinterpret = vreinterpretq_s8_u8,
+);
+impl_neon_bitwise!(
+    u16,
+    and = vandq_u16,
+    or = vorrq_u16,
+    xor = veorq_u16,
+    dup = vdupq_n_u16,
+    shl = vshlq_u16,
+    neg = vnegq_s16,
+    reinterpret = vreinterpretq_s16_u16,
+);
+impl_neon_bitwise!(
+    u32,
+    and = vandq_u32,
+    or = vorrq_u32,
+    xor = veorq_u32,
+    dup = vdupq_n_u32,
+    shl = vshlq_u32,
+    neg = vnegq_s32,
+    reinterpret = vreinterpretq_s32_u32,
+);
+impl_neon_bitwise!(
+    u64,
+    and = vandq_u64,
+    or = vorrq_u64,
+    xor = veorq_u64,
+    dup = vdupq_n_u64,
+    shl = vshlq_u64,
+    neg = vnegq_s64,
+    reinterpret = vreinterpretq_s64_u64,
+);
+
+/// NEON provides saturating add and sub instructions for every integer width.
+macro_rules! impl_neon_saturating {
+    ($t:ty, add = $add:ident, sub = $sub:ident) => {
+        impl SimdSaturatingRegister<$t> for Neon {
+            #[inline(always)]
+            unsafe fn saturating_add(
+                l1: Self::Register,
+                l2: Self::Register,
+            ) -> Self::Register {
+                $add(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn saturating_sub(
+                l1: Self::Register,
+                l2: Self::Register,
+            ) -> Self::Register {
+                $sub(l1, l2)
+            }
+        }
+    };
+}
+
+impl_neon_saturating!(i8, add = vqaddq_s8, sub = vqsubq_s8);
+impl_neon_saturating!(i16, add = vqaddq_s16, sub = vqsubq_s16);
+impl_neon_saturating!(i32, add = vqaddq_s32, sub = vqsubq_s32);
+impl_neon_saturating!(i64, add = vqaddq_s64, sub = vqsubq_s64);
+impl_neon_saturating!(u8, add = vqaddq_u8, sub = vqsubq_u8);
+impl_neon_saturating!(u16, add = vqaddq_u16, sub = vqsubq_u16);
+impl_neon_saturating!(u32, add = vqaddq_u32, sub = vqsubq_u32);
+impl_neon_saturating!(u64, add = vqaddq_u64, sub = vqsubq_u64);
+
+/// Implements the widening dot product for the byte types.
+///
+/// `vdotq_s32` requires the separate `dotprod` feature, so the products are instead
+/// widened to 16 bits via `vmull` (which cannot overflow for byte inputs) and then
+/// pairwise accumulated into the 32 bit lanes via `vpadalq`.
+macro_rules! impl_neon_widening_dot {
+    (
+        $t:ident,
+        load = $load:ident,
+        acc = $acc:ty,
+        zeroed = $zeroed:ident,
+        mul_lo = $mul_lo:ident,
+        mul_hi = $mul_hi:ident,
+        get_lo = $get_lo:ident,
+        pairwise_add = $pairwise_add:ident,
+        add = $add:ident,
+        sum = $sum:ident,
+        $(reinterpret = $reinterpret:ident,)?
+    ) => {
+        impl SimdWideningDotRegister<$t> for Neon {
+            type Register = <Neon as SimdRegister<$t>>::Register;
+            type Accumulator = $acc;
+
+            #[inline(always)]
+            unsafe fn load_widen(mem: *const $t) -> Self::Register {
+                $load(mem)
+            }
+
+            #[inline(always)]
+            unsafe fn zeroed_acc() -> Self::Accumulator {
+                $zeroed(0)
+            }
+
+            #[inline(always)]
+            unsafe fn dot_acc(
+                l1: Self::Register,
+                l2: Self::Register,
+                acc: Self::Accumulator,
+            ) -> Self::Accumulator {
+                let lo = $mul_lo($get_lo(l1), $get_lo(l2));
+                let hi = $mul_hi(l1, l2);
+                let acc = $pairwise_add(acc, lo);
+                $pairwise_add(acc, hi)
+            }
+
+            #[inline(always)]
+            unsafe fn add_acc(
+                l1: Self::Accumulator,
+                l2: Self::Accumulator,
+            ) -> Self::Accumulator {
+                $add(l1, l2)
+            }
+
+            #[inline(always)]
+            unsafe fn sum_acc_to_value(acc: Self::Accumulator) -> i32 {
+                $sum($($reinterpret)?(acc))
+            }
+        }
+    };
+}
+
+impl_neon_widening_dot!(
+    i8,
+    load = vld1q_s8,
+    acc = int32x4_t,
+    zeroed = vdupq_n_s32,
+    mul_lo = vmull_s8,
+    mul_hi = vmull_high_s8,
+    get_lo = vget_low_s8,
+    pairwise_add = vpadalq_s16,
+    add = vaddq_s32,
+    sum = vaddvq_s32,
+);
+impl_neon_widening_dot!(
+    u8,
+    load = vld1q_u8,
+    acc = uint32x4_t,
+    zeroed = vdupq_n_u32,
+    mul_lo = vmull_u8,
+    mul_hi = vmull_high_u8,
+    get_lo = vget_low_u8,
+    pairwise_add = vpadalq_u16,
+    add = vaddq_u32,
+    sum = vaddvq_s32,
+    reinterpret = vreinterpretq_s32_u32,
+);
+
+impl SimdWideningDotRegister<i16> for Neon {
+    type Register = int16x8_t;
+    type Accumulator = int32x4_t;
+
+    #[inline(always)]
+    unsafe fn load_widen(mem: *const i16) -> Self::Register {
+        vld1q_s16(mem)
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed_acc() -> Self::Accumulator {
+        vdupq_n_s32(0)
+    }
+
+    #[inline(always)]
+    /// The products of `i16` values always fit within `i32`, so each half of the
+    /// register is multiplied and accumulated directly via `vmlal`.
+    unsafe fn dot_acc(
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Accumulator,
+    ) -> Self::Accumulator {
+        let acc = vmlal_s16(acc, vget_low_s16(l1), vget_low_s16(l2));
+        vmlal_high_s16(acc, l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn add_acc(
+        l1: Self::Accumulator,
+        l2: Self::Accumulator,
+    ) -> Self::Accumulator {
+        vaddq_s32(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_acc_to_value(acc: Self::Accumulator) -> i32 {
+        vaddvq_s32(acc)
+    }
+}
+
+impl SimdCastRegister<f32, f64> for Neon {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        4
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const f32, result: *mut f64) {
+        let value = vld1q_f32(mem);
+        vst1q_f64(result, vcvt_f64_f32(vget_low_f32(value)));
+        vst1q_f64(result.add(2), vcvt_high_f64_f32(value));
+    }
+}
+
+impl SimdCastRegister<f64, f32> for Neon {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        4
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const f64, result: *mut f32) {
+        let low = vcvt_f32_f64(vld1q_f64(mem));
+        let value = vcvt_high_f32_f64(low, vld1q_f64(mem.add(2)));
+        vst1q_f32(result, value);
+    }
+}
+
+impl SimdCastRegister<f32, i32> for Neon {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        4
+    }
+
+    #[inline(always)]
+    /// `vcvtq_s32_f32` already truncates, saturates and converts `NaN` to `0`,
+    /// matching the `as` cast.
+    unsafe fn cast(mem: *const f32, result: *mut i32) {
+        vst1q_s32(result, vcvtq_s32_f32(vld1q_f32(mem)))
+    }
+}
+
+impl SimdCastRegister<i32, f32> for Neon {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        4
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const i32, result: *mut f32) {
+        vst1q_f32(result, vcvtq_f32_s32(vld1q_s32(mem)))
+    }
+}
+
+impl SimdCastRegister<i16, f32> for Neon {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        8
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const i16, result: *mut f32) {
+        let value = vld1q_s16(mem);
+        let low = vmovl_s16(vget_low_s16(value));
+        let high = vmovl_high_s16(value);
+        vst1q_f32(result, vcvtq_f32_s32(low));
+        vst1q_f32(result.add(4), vcvtq_f32_s32(high));
+    }
+}
+
+impl SimdCastRegister<u16, f32> for Neon {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        8
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const u16, result: *mut f32) {
+        let value = vld1q_u16(mem);
+        let low = vmovl_u16(vget_low_u16(value));
+        let high = vmovl_high_u16(value);
+        vst1q_f32(result, vcvtq_f32_u32(low));
+        vst1q_f32(result.add(4), vcvtq_f32_u32(high));
+    }
+}
+
+impl SimdCastRegister<i8, f32> for Neon {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        8
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const i8, result: *mut f32) {
+        let value = vmovl_s8(vld1_s8(mem));
+        let low = vmovl_s16(vget_low_s16(value));
+        let high = vmovl_high_s16(value);
+        vst1q_f32(result, vcvtq_f32_s32(low));
+        vst1q_f32(result.add(4), vcvtq_f32_s32(high));
+    }
+}
+
+impl SimdCastRegister<u8, f32> for Neon {
+    #[inline(always)]
+    fn cast_elements_per_lane() -> usize {
+        8
+    }
+
+    #[inline(always)]
+    unsafe fn cast(mem: *const u8, result: *mut f32) {
+        let value = vmovl_u8(vld1_u8(mem));
+        let low = vmovl_u16(vget_low_u16(value));
+        let high = vmovl_high_u16(value);
+        vst1q_f32(result, vcvtq_f32_u32(low));
+        vst1q_f32(result.add(4), vcvtq_f32_u32(high));
+    }
+}
+
+impl SimdWidenRegister<f32, f64> for Neon {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const f32) -> Self::Register {
+        vcvt_f64_f32(vld1_f32(mem))
+    }
+}
+
+impl SimdWidenRegister<i32, i64> for Neon {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const i32) -> Self::Register {
+        vmovl_s32(vld1_s32(mem))
+    }
+}
+
+impl SimdWidenRegister<u32, u64> for Neon {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const u32) -> Self::Register {
+        vmovl_u32(vld1_u32(mem))
+    }
+}
+
+impl SimdWidenRegister<u16, u32> for Neon {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const u16) -> Self::Register {
+        vmovl_u16(vld1_u16(mem))
+    }
+}
+
+impl SimdWidenRegister<u8, u32> for Neon {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const u8) -> Self::Register {
+        // Only 4 bytes fill the register, so avoid `vld1_u8` reading past them.
+        let packed = vdup_n_u32(mem.cast::<u32>().read_unaligned());
+        vmovl_u16(vget_low_u16(vmovl_u8(vreinterpret_u8_u32(packed))))
+    }
+}
+
+impl SimdWidenRegister<u16, u64> for Neon {
+    #[inline(always)]
+    unsafe fn load_widened(mem: *const u16) -> Self::Register {
+        // Only 2 elements fill the register, so avoid `vld1_u16` reading past them.
+        let packed = vdup_n_u32(mem.cast::<u32>().read_unaligned());
+        vmovl_u32(vget_low_u32(vmovl_u16(vreinterpret_u16_u32(packed))))
+    }
+}
+
+impl SimdHammingRegister for Neon {
+    type Register = uint64x2_t;
+
+    #[inline(always)]
+    unsafe fn load_words(mem: *const u64) -> Self::Register {
+        vld1q_u64(mem)
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed_acc() -> Self::Register {
+        vdupq_n_u64(0)
+    }
+
+    #[inline(always)]
+    unsafe fn xor_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        veorq_u64(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn and_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        vandq_u64(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn or_words(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        vorrq_u64(l1, l2)
+    }
+
+    #[inline(always)]
+    /// Counts the bits of each byte with `vcnt` before pairwise widening the byte
+    /// counts into the `u64` lanes.
+    unsafe fn popcount_acc(
+        words: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        let counts = vcntq_u8(vreinterpretq_u8_u64(words));
+        vaddq_u64(acc, vpaddlq_u32(vpaddlq_u16(vpaddlq_u8(counts))))
+    }
+
+    #[inline(always)]
+    unsafe fn add_acc(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        vaddq_u64(l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_acc_to_value(acc: Self::Register) -> u64 {
+        vaddvq_u64(acc)
+    }
+}
+
+impl SimdFloatRegister<f32> for Neon {
+    #[inline(always)]
+    /// Computes the hypotenuse as `hi * sqrt(1 + (lo / hi)²)` where `hi` and `lo` are
+    /// the larger and smaller of the absolute values.
+    unsafe fn hypot(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let a = vabsq_f32(l1);
+        let b = vabsq_f32(l2);
+
+        let hi = vmaxq_f32(a, b);
+        let lo = vminq_f32(a, b);
+        let ratio = vdivq_f32(lo, hi);
+        let scale = vsqrtq_f32(vfmaq_f32(vdupq_n_f32(1.0), ratio, ratio));
+        let result = vmulq_f32(hi, scale);
+
+        // `0 / 0` produces a NaN ratio when both inputs are zero.
+        let zero = vdupq_n_f32(0.0);
+        let result = vbslq_f32(vceqq_f32(hi, zero), zero, result);
+
+        let is_ordered = vandq_u32(vceqq_f32(a, a), vceqq_f32(b, b));
+        let result = vbslq_f32(is_ordered, result, vaddq_f32(a, b));
+
+        let inf = vdupq_n_f32(f32::INFINITY);
+        let is_inf = vorrq_u32(vceqq_f32(a, inf), vceqq_f32(b, inf));
+        vbslq_f32(is_inf, inf, result)
+    }
+}
+
+impl SimdFloatRegister<f64> for Neon {
+    #[inline(always)]
+    /// Computes the hypotenuse as `hi * sqrt(1 + (lo / hi)²)` where `hi` and `lo` are
+    /// the larger and smaller of the absolute values.
+    unsafe fn hypot(l1: Self::Register, l2: Self::Register) -> Self::Register {
+        let a = vabsq_f64(l1);
+        let b = vabsq_f64(l2);
+
+        let hi = vmaxq_f64(a, b);
+        let lo = vminq_f64(a, b);
+        let ratio = vdivq_f64(lo, hi);
+        let scale = vsqrtq_f64(vfmaq_f64(vdupq_n_f64(1.0), ratio, ratio));
+        let result = vmulq_f64(hi, scale);
+
+        // `0 / 0` produces a NaN ratio when both inputs are zero.
+        let zero = vdupq_n_f64(0.0);
+        let result = vbslq_f64(vceqq_f64(hi, zero), zero, result);
+
+        let is_ordered = vandq_u64(vceqq_f64(a, a), vceqq_f64(b, b));
+        let result = vbslq_f64(is_ordered, result, vaddq_f64(a, b));
+
+        let inf = vdupq_n_f64(f64::INFINITY);
+        let is_inf = vorrq_u64(vceqq_f64(a, inf), vceqq_f64(b, inf));
+        vbslq_f64(is_inf, inf, result)
+    }
+}
+
 /// A helper trait to work around transmute limitations.
 ///
 /// TODO: We should use this for all of the transmute ops in this file
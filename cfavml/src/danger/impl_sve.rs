@@ -0,0 +1,131 @@
+use core::arch::aarch64::*;
+
+use crate::danger::SimdScalableRegister;
+
+/// SVE enabled SIMD operations.
+///
+/// This requires the `sve` CPU features be enabled and is only available with the
+/// `nightly` feature, as the SVE intrinsics are not yet stable.
+///
+/// The vector length is a property of the CPU, anywhere from 128 to 2048 bits, so
+/// this is a [SimdScalableRegister] rather than a `SimdRegister`. Only the `f32` and
+/// `f64` types are supported.
+pub struct Sve;
+
+impl SimdScalableRegister<f32> for Sve {
+    type Register = svfloat32_t;
+    type Predicate = svbool_t;
+
+    #[inline(always)]
+    unsafe fn elements_per_lane() -> usize {
+        svcntw() as usize
+    }
+
+    #[inline(always)]
+    unsafe fn predicate_all() -> Self::Predicate {
+        svptrue_b32()
+    }
+
+    #[inline(always)]
+    unsafe fn predicate_while_lt(i: usize, len: usize) -> Self::Predicate {
+        svwhilelt_b32_u64(i as u64, len as u64)
+    }
+
+    #[inline(always)]
+    unsafe fn load(pg: Self::Predicate, mem: *const f32) -> Self::Register {
+        svld1_f32(pg, mem)
+    }
+
+    #[inline(always)]
+    unsafe fn write(pg: Self::Predicate, mem: *mut f32, reg: Self::Register) {
+        svst1_f32(pg, mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed() -> Self::Register {
+        svdup_n_f32(0.0)
+    }
+
+    #[inline(always)]
+    unsafe fn add(
+        pg: Self::Predicate,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        svadd_f32_m(pg, l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn fmadd(
+        pg: Self::Predicate,
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        svmla_f32_m(pg, acc, l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_to_value(reg: Self::Register) -> f32 {
+        svaddv_f32(svptrue_b32(), reg)
+    }
+}
+
+impl SimdScalableRegister<f64> for Sve {
+    type Register = svfloat64_t;
+    type Predicate = svbool_t;
+
+    #[inline(always)]
+    unsafe fn elements_per_lane() -> usize {
+        svcntd() as usize
+    }
+
+    #[inline(always)]
+    unsafe fn predicate_all() -> Self::Predicate {
+        svptrue_b64()
+    }
+
+    #[inline(always)]
+    unsafe fn predicate_while_lt(i: usize, len: usize) -> Self::Predicate {
+        svwhilelt_b64_u64(i as u64, len as u64)
+    }
+
+    #[inline(always)]
+    unsafe fn load(pg: Self::Predicate, mem: *const f64) -> Self::Register {
+        svld1_f64(pg, mem)
+    }
+
+    #[inline(always)]
+    unsafe fn write(pg: Self::Predicate, mem: *mut f64, reg: Self::Register) {
+        svst1_f64(pg, mem, reg)
+    }
+
+    #[inline(always)]
+    unsafe fn zeroed() -> Self::Register {
+        svdup_n_f64(0.0)
+    }
+
+    #[inline(always)]
+    unsafe fn add(
+        pg: Self::Predicate,
+        l1: Self::Register,
+        l2: Self::Register,
+    ) -> Self::Register {
+        svadd_f64_m(pg, l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn fmadd(
+        pg: Self::Predicate,
+        l1: Self::Register,
+        l2: Self::Register,
+        acc: Self::Register,
+    ) -> Self::Register {
+        svmla_f64_m(pg, acc, l1, l2)
+    }
+
+    #[inline(always)]
+    unsafe fn sum_to_value(reg: Self::Register) -> f64 {
+        svaddv_f64(svptrue_b64(), reg)
+    }
+}
@@ -3,66 +3,276 @@
 mod core_simd_api;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod impl_avx2;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+mod impl_avx2bf16;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+mod impl_avx2f16c;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod impl_avx2fma;
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+// The AVX512 intrinsics were stabilized in Rust `1.89`, these modules are only compiled with
+// the `stable-avx512` feature which documents that requirement.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+#[clippy::msrv = "1.89"]
 mod impl_avx512;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512",
+    feature = "half"
+))]
+#[clippy::msrv = "1.89"]
+mod impl_avx512bf16;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+#[clippy::msrv = "1.89"]
+mod impl_avx512vnni;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+#[clippy::msrv = "1.89"]
+mod impl_avx512vpopcntdq;
 mod impl_fallback;
 #[cfg(target_arch = "aarch64")]
 mod impl_neon;
+// The SVE intrinsics are currently nightly only.
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+mod impl_sve;
 mod op_arithmetic_vertical;
+mod op_batch_distance;
+mod op_bitwise_vertical;
+mod op_cast;
+mod op_cmp_any_all;
+mod op_cmp_bitmask;
+mod op_cmp_count;
 mod op_cmp_max;
 mod op_cmp_min;
+mod op_compact;
+mod op_compensated;
 mod op_cosine;
+mod op_distance_matrix;
 mod op_dot;
+mod op_dot_widen;
 mod op_euclidean;
+mod op_float_class;
+mod op_fold;
+mod op_gather;
+mod op_gemv;
+mod op_gram;
+mod op_hamming;
+mod op_hypot;
 mod op_norm;
+mod op_ordered;
+mod op_outer_product;
+mod op_prefix_sum;
+mod op_scalable;
+mod op_select;
+mod op_stats;
 mod op_sum;
 
 mod core_routine_boilerplate;
 pub mod export_agg_ops;
 pub mod export_arithmetic_ops;
+pub mod export_bitwise_ops;
+pub mod export_cast_ops;
 pub mod export_cmp_ops;
 pub mod export_distance_ops;
+pub mod export_float_ops;
+pub mod export_gather_ops;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+pub mod export_scalable_ops;
+pub mod export_stats_ops;
 #[cfg(test)]
 mod impl_test;
 mod op_cmp_vertical;
 #[cfg(test)]
+mod test_differential;
+#[cfg(test)]
 mod test_suite;
 
-pub use self::core_simd_api::{DenseLane, SimdRegister};
+pub use self::core_simd_api::{
+    DenseLane,
+    SimdBitwiseRegister,
+    SimdCastRegister,
+    SimdFloatRegister,
+    SimdHammingRegister,
+    SimdRegister,
+    SimdSaturatingRegister,
+    SimdScalableRegister,
+    SimdWidenRegister,
+    SimdWideningDotRegister,
+};
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::impl_avx2::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+pub use self::impl_avx2f16c::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::impl_avx2fma::*;
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 pub use self::impl_avx512::*;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512",
+    feature = "half"
+))]
+pub use self::impl_avx512bf16::*;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+pub use self::impl_avx512vnni::*;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+pub use self::impl_avx512vpopcntdq::*;
 pub use self::impl_fallback::*;
 #[cfg(target_arch = "aarch64")]
 pub use self::impl_neon::*;
+#[cfg(all(target_arch = "aarch64", feature = "nightly"))]
+pub use self::impl_sve::*;
 pub use self::op_arithmetic_vertical::{
+    generic_add_inplace,
     generic_add_vertical,
+    generic_add_vertical_nt,
+    generic_alternating_sign_flip,
+    generic_div_inplace,
     generic_div_vertical,
+    generic_mul_inplace,
     generic_mul_vertical,
+    generic_neg_vertical,
+    generic_saturating_add_vertical,
+    generic_saturating_sub_vertical,
+    generic_sub_inplace,
     generic_sub_vertical,
 };
+pub use self::op_batch_distance::{
+    generic_batch_dot,
+    generic_cosine_batch,
+    generic_dot_batch,
+    generic_dot_indexed,
+    generic_pairwise_distances,
+    generic_squared_euclidean_batch,
+};
+pub use self::op_bitwise_vertical::{
+    generic_bitand_vertical,
+    generic_bitnot_vertical,
+    generic_bitor_vertical,
+    generic_bitxor_vertical,
+    generic_shl_vertical,
+    generic_shr_vertical,
+};
+pub use self::op_cast::generic_cast_vector;
+pub use self::op_cmp_any_all::{
+    generic_all_eq_value,
+    generic_all_gt_value,
+    generic_all_gte_value,
+    generic_all_lt_value,
+    generic_all_lte_value,
+    generic_all_neq_value,
+    generic_any_eq_value,
+    generic_any_gt_value,
+    generic_any_gte_value,
+    generic_any_lt_value,
+    generic_any_lte_value,
+    generic_any_neq_value,
+};
+pub use self::op_cmp_bitmask::{
+    generic_cmp_eq_bitmask,
+    generic_cmp_gt_bitmask,
+    generic_cmp_gte_bitmask,
+    generic_cmp_lt_bitmask,
+    generic_cmp_lte_bitmask,
+    generic_cmp_neq_bitmask,
+};
+pub use self::op_cmp_count::{
+    generic_count_eq_value,
+    generic_count_gt_value,
+    generic_count_gte_value,
+    generic_count_lt_value,
+    generic_count_lte_value,
+    generic_count_neq_value,
+    generic_count_nonzero,
+};
 pub use self::op_cmp_max::{generic_cmp_max, generic_cmp_max_vertical};
 pub use self::op_cmp_min::{generic_cmp_min, generic_cmp_min_vertical};
 pub use self::op_cmp_vertical::{
+    generic_cmp_eq_value,
     generic_cmp_eq_vertical,
+    generic_cmp_gt_value,
     generic_cmp_gt_vertical,
+    generic_cmp_gte_value,
     generic_cmp_gte_vertical,
+    generic_cmp_lt_value,
     generic_cmp_lt_vertical,
+    generic_cmp_lte_value,
     generic_cmp_lte_vertical,
+    generic_cmp_neq_value,
     generic_cmp_neq_vertical,
 };
+pub use self::op_compact::{generic_compact, generic_mask_to_indices};
+pub use self::op_compensated::{generic_dot_compensated, generic_sum_compensated};
 #[cfg(test)]
 pub(crate) use self::op_cosine::cosine;
-pub use self::op_cosine::generic_cosine;
-pub use self::op_dot::generic_dot;
-pub use self::op_euclidean::generic_squared_euclidean;
-pub use self::op_norm::generic_squared_norm;
-pub use self::op_sum::generic_sum;
+pub use self::op_cosine::{generic_cosine, generic_cosine_wide_acc};
+pub use self::op_distance_matrix::{
+    generic_dot_matrix,
+    generic_squared_euclidean_matrix,
+};
+pub use self::op_dot::{
+    generic_dot,
+    generic_dot_f32_f64acc,
+    generic_dot_wide_acc,
+    generic_weighted_dot,
+};
+pub use self::op_dot_widen::{generic_dot_widen, generic_squared_euclidean_widen};
+pub use self::op_euclidean::{
+    generic_squared_euclidean,
+    generic_squared_euclidean_f32_f64acc,
+    generic_squared_euclidean_wide_acc,
+};
+pub use self::op_float_class::{
+    generic_all_finite,
+    generic_any_infinite,
+    generic_any_nan,
+    generic_is_finite_vector,
+    generic_is_infinite_vector,
+    generic_is_nan_vector,
+};
+pub use self::op_fold::{generic_fold, generic_fold_reduce};
+pub use self::op_gather::{generic_gather, generic_scatter};
+pub use self::op_gemv::generic_gemv;
+pub use self::op_gram::generic_gram_matrix;
+pub use self::op_hamming::{
+    generic_binary_hamming_distance,
+    generic_binary_jaccard_similarity,
+};
+pub use self::op_hypot::generic_hypot_vertical;
+pub use self::op_norm::{
+    generic_l2_normalize_inplace,
+    generic_l2_normalize_vertical,
+    generic_squared_norm,
+    generic_squared_norm_f32_f64acc,
+};
+pub use self::op_ordered::{
+    generic_dot_ordered,
+    generic_squared_norm_ordered,
+    generic_sum_ordered,
+    ORDERED_LANES,
+};
+pub use self::op_outer_product::generic_outer_product;
+pub use self::op_prefix_sum::{generic_cumulative_max, generic_cumulative_min};
+pub use self::op_scalable::{generic_scalable_add_vertical, generic_scalable_dot};
+pub use self::op_select::generic_select_vertical;
+pub use self::op_stats::generic_moving_average;
+pub use self::op_sum::{generic_kahan_sum, generic_sum, generic_sum_f32_f64acc};
 
 #[allow(non_snake_case)]
 pub(crate) const fn _MM_SHUFFLE(z: u32, y: u32, x: u32, w: u32) -> i32 {
@@ -1,8 +1,13 @@
-use super::core_routine_boilerplate::apply_vertical_kernel;
-use super::core_simd_api::SimdRegister;
+use super::core_routine_boilerplate::{
+    apply_inplace_vertical_kernel,
+    apply_unary_vertical_kernel,
+    apply_vertical_kernel,
+    apply_vertical_stream_kernel,
+};
+use super::core_simd_api::{SimdRegister, SimdSaturatingRegister};
 use crate::buffer::WriteOnlyBuffer;
-use crate::math::Math;
-use crate::mem_loader::{IntoMemLoader, MemLoader};
+use crate::math::{Math, SaturatingArithmetic};
+use crate::mem_loader::{ChunkedLoader, IntoMemLoader, MemLoader};
 
 #[inline(always)]
 /// A generic vector addition implementation over one vector and single value.
@@ -33,6 +38,42 @@ where
     )
 }
 
+#[inline(always)]
+/// A generic vector addition implementation over one vector and single value, writing
+/// the result with non-temporal stores where the register supports them.
+///
+/// This avoids the writes to `result` evicting the rest of the cache when the output
+/// is too large to be read again while it is still cached.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_add_vertical_nt<T, R, M, B1, B2, B3>(
+    a: B1,
+    b: B2,
+    result: &mut [B3],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    apply_vertical_stream_kernel::<T, R, M, B1, B2, B3>(
+        a,
+        b,
+        result,
+        R::add_dense,
+        R::add,
+        M::add,
+    )
+}
+
 #[inline(always)]
 /// A generic vector subtraction implementation over one vector and single value.
 ///
@@ -120,6 +161,216 @@ where
     )
 }
 
+#[inline(always)]
+/// A generic in-place vector addition implementation over a vector and either
+/// another vector or single value, writing the result back to `a`.
+///
+/// # Safety
+///
+/// The size of `b` must be equal to the length of `a`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_add_inplace<T, R, M, B1>(a: &mut [T], b: B1)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    apply_inplace_vertical_kernel::<T, R, M, B1>(a, b, R::add_dense, R::add, M::add)
+}
+
+#[inline(always)]
+/// A generic in-place vector subtraction implementation over a vector and either
+/// another vector or single value, writing the result back to `a`.
+///
+/// # Safety
+///
+/// The size of `b` must be equal to the length of `a`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_sub_inplace<T, R, M, B1>(a: &mut [T], b: B1)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    apply_inplace_vertical_kernel::<T, R, M, B1>(a, b, R::sub_dense, R::sub, M::sub)
+}
+
+#[inline(always)]
+/// A generic in-place vector multiplication implementation over a vector and either
+/// another vector or single value, writing the result back to `a`.
+///
+/// # Safety
+///
+/// The size of `b` must be equal to the length of `a`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_mul_inplace<T, R, M, B1>(a: &mut [T], b: B1)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    apply_inplace_vertical_kernel::<T, R, M, B1>(a, b, R::mul_dense, R::mul, M::mul)
+}
+
+#[inline(always)]
+/// A generic in-place vector division implementation over a vector and either
+/// another vector or single value, writing the result back to `a`.
+///
+/// # Safety
+///
+/// The size of `b` must be equal to the length of `a`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_div_inplace<T, R, M, B1>(a: &mut [T], b: B1)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    apply_inplace_vertical_kernel::<T, R, M, B1>(a, b, R::div_dense, R::div, M::div)
+}
+
+#[inline(always)]
+/// A generic vector saturating addition implementation over two vectors or broadcast values.
+///
+/// Rather than wrapping on overflow, the result is clamped to the bounds of `T`,
+/// matching the behaviour of [`saturating_add`](i16::saturating_add).
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_saturating_add_vertical<T, R, M, B1, B2, B3>(
+    a: B1,
+    b: B2,
+    result: &mut [B3],
+) where
+    T: SaturatingArithmetic,
+    R: SimdSaturatingRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    apply_vertical_kernel::<T, R, M, B1, B2, B3>(
+        a,
+        b,
+        result,
+        R::saturating_add_dense,
+        R::saturating_add,
+        |a, b| a.add_saturating(b),
+    )
+}
+
+#[inline(always)]
+/// A generic vector saturating subtraction implementation over two vectors or broadcast values.
+///
+/// Rather than wrapping on overflow, the result is clamped to the bounds of `T`,
+/// matching the behaviour of [`saturating_sub`](i16::saturating_sub).
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_saturating_sub_vertical<T, R, M, B1, B2, B3>(
+    a: B1,
+    b: B2,
+    result: &mut [B3],
+) where
+    T: SaturatingArithmetic,
+    R: SimdSaturatingRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    apply_vertical_kernel::<T, R, M, B1, B2, B3>(
+        a,
+        b,
+        result,
+        R::saturating_sub_dense,
+        R::saturating_sub,
+        |a, b| a.sub_saturating(b),
+    )
+}
+
+#[inline(always)]
+/// A generic alternating sign flip, negating every odd element of vector `a`,
+/// i.e. `[a, b, c, d] -> [a, -b, c, -d]`.
+///
+/// This is implemented as a [generic_mul_vertical] against the `[1, -1]` pattern
+/// tiled via a [ChunkedLoader], integer types follow the wrapping behaviour of `M`.
+///
+/// # Safety
+///
+/// The sizes of `a` and `result` must be equal, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_alternating_sign_flip<T, R, M, B1, B2>(a: B1, result: &mut [B2])
+where
+    T: Copy + Default,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    let pattern = [M::one(), M::sub(M::zero(), M::one())];
+    let len = result.raw_buffer_len();
+
+    generic_mul_vertical::<T, R, M, B1, ChunkedLoader<T>, B2>(
+        a,
+        ChunkedLoader::new(&pattern, len),
+        result,
+    )
+}
+
+#[inline(always)]
+/// A generic vector negation implementation over a single vector, `-a`.
+///
+/// Integer types follow the wrapping behaviour of `M`, so `MIN` negates to itself,
+/// float types flip the sign bit, matching scalar negation for `-0.0` and `NaN` values.
+///
+/// # Safety
+///
+/// The sizes of `a` and `result` must be equal, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_neg_vertical<T, R, M, B1, B2>(a: B1, result: &mut [B2])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    apply_unary_vertical_kernel::<T, R, M, B1, B2>(
+        a,
+        result,
+        R::neg_dense,
+        R::neg,
+        M::neg,
+    )
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
@@ -285,4 +536,370 @@ pub(crate) mod tests {
         }
         assert_eq!(result, expected_result, "value mismatch");
     }
+
+    pub(crate) unsafe fn test_broadcast_value_lhs_sub<T, R>(value: T, l1: Vec<T>)
+    where
+        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        let dims = l1.len();
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_sub_vertical::<T, R, AutoMath, _, _, _>(value, &l1, &mut result);
+
+        let mut expected_result = Vec::new();
+        for a in l1.iter().copied() {
+            expected_result.push(AutoMath::sub(value, a));
+        }
+        assert_eq!(result, expected_result, "value mismatch");
+    }
+
+    pub(crate) unsafe fn test_broadcast_value_lhs_div<T, R>(value: T, l1: Vec<T>)
+    where
+        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        let dims = l1.len();
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_div_vertical::<T, R, AutoMath, _, _, _>(value, &l1, &mut result);
+
+        let mut expected_result = Vec::new();
+        for a in l1.iter().copied() {
+            expected_result.push(AutoMath::div(value, a));
+        }
+        assert_eq!(result, expected_result, "value mismatch");
+    }
+
+    pub(crate) unsafe fn test_saturating_all<T, R>(
+        l1: Vec<T>,
+        l2: Vec<T>,
+        extremes: &[T],
+    ) where
+        T: SaturatingArithmetic + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        T::Loader: MemLoader<Value = T>,
+        R: SimdSaturatingRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        let dims = l1.len();
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_saturating_add_vertical::<T, R, AutoMath, _, _, _>(
+            &l1,
+            &l2,
+            &mut result,
+        );
+        let expected = l1
+            .iter()
+            .zip(&l2)
+            .map(|(a, b)| a.add_saturating(*b))
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected, "saturating add vector mismatch");
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_saturating_sub_vertical::<T, R, AutoMath, _, _, _>(
+            &l1,
+            &l2,
+            &mut result,
+        );
+        let expected = l1
+            .iter()
+            .zip(&l2)
+            .map(|(a, b)| a.sub_saturating(*b))
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected, "saturating sub vector mismatch");
+
+        // Every pairing of the extremes, repeated so the dense, register and
+        // remainder paths all see values which overflow.
+        let pairs = extremes
+            .iter()
+            .flat_map(|a| extremes.iter().map(move |b| (*a, *b)))
+            .collect::<Vec<_>>();
+        let (lhs, rhs): (Vec<T>, Vec<T>) =
+            pairs.iter().copied().cycle().take(dims).unzip();
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_saturating_add_vertical::<T, R, AutoMath, _, _, _>(
+            &lhs,
+            &rhs,
+            &mut result,
+        );
+        let expected = lhs
+            .iter()
+            .zip(&rhs)
+            .map(|(a, b)| a.add_saturating(*b))
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected, "saturating add extremes mismatch");
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_saturating_sub_vertical::<T, R, AutoMath, _, _, _>(
+            &lhs,
+            &rhs,
+            &mut result,
+        );
+        let expected = lhs
+            .iter()
+            .zip(&rhs)
+            .map(|(a, b)| a.sub_saturating(*b))
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected, "saturating sub extremes mismatch");
+
+        for &value in extremes {
+            let mut result = vec![AutoMath::zero(); dims];
+            generic_saturating_add_vertical::<T, R, AutoMath, _, _, _>(
+                &lhs,
+                value,
+                &mut result,
+            );
+            let expected = lhs
+                .iter()
+                .map(|a| a.add_saturating(value))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                result, expected,
+                "saturating add value mismatch for {value:?}"
+            );
+
+            let mut result = vec![AutoMath::zero(); dims];
+            generic_saturating_sub_vertical::<T, R, AutoMath, _, _, _>(
+                value,
+                &lhs,
+                &mut result,
+            );
+            let expected = lhs
+                .iter()
+                .map(|a| value.sub_saturating(*a))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                result, expected,
+                "saturating sub value mismatch for {value:?}"
+            );
+        }
+    }
+
+    pub(crate) unsafe fn test_alternating_sign_flip<T, R>(l1: Vec<T>)
+    where
+        T: Copy + Default + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        let dims = l1.len();
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_alternating_sign_flip::<T, R, AutoMath, _, _>(&l1, &mut result);
+
+        let mut expected_result = Vec::new();
+        for (i, a) in l1.iter().copied().enumerate() {
+            if i % 2 == 0 {
+                expected_result.push(a);
+            } else {
+                expected_result.push(AutoMath::sub(AutoMath::zero(), a));
+            }
+        }
+        assert_eq!(result, expected_result, "value mismatch");
+    }
+
+    pub(crate) unsafe fn test_neg_vertical<T, R>(l1: Vec<T>)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        let dims = l1.len();
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_neg_vertical::<T, R, AutoMath, _, _>(&l1, &mut result);
+
+        let mut expected_result = Vec::new();
+        for a in l1.iter().copied() {
+            expected_result.push(AutoMath::sub(AutoMath::zero(), a));
+        }
+        assert_eq!(result, expected_result, "value mismatch");
+    }
+
+    pub(crate) unsafe fn test_neg_special<T, R, B>(l1: Vec<T>, to_bits: fn(T) -> B)
+    where
+        T: Copy + core::ops::Neg<Output = T> + std::fmt::Debug,
+        B: PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        let dims = l1.len();
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_neg_vertical::<T, R, AutoMath, _, _>(&l1, &mut result);
+
+        for (value, input) in result.into_iter().zip(l1) {
+            assert_eq!(
+                to_bits(value),
+                to_bits(-input),
+                "value mismatch on input {input:?}, got {value:?}",
+            );
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) unsafe fn test_inplace_vector_all<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+    {
+        use crate::math::AutoMath;
+
+        let cases: [(&str, unsafe fn(&mut [T], &[T]), fn(T, T) -> T); 4] = [
+            (
+                "add",
+                |a, b| generic_add_inplace::<T, R, AutoMath, _>(a, b),
+                AutoMath::add,
+            ),
+            (
+                "sub",
+                |a, b| generic_sub_inplace::<T, R, AutoMath, _>(a, b),
+                AutoMath::sub,
+            ),
+            (
+                "mul",
+                |a, b| generic_mul_inplace::<T, R, AutoMath, _>(a, b),
+                AutoMath::mul,
+            ),
+            (
+                "div",
+                |a, b| generic_div_inplace::<T, R, AutoMath, _>(a, b),
+                AutoMath::div,
+            ),
+        ];
+
+        for (name, op, expected_op) in cases {
+            let mut result = l1.clone();
+            op(&mut result, &l2);
+
+            let mut expected_result = Vec::new();
+            for (a, b) in l1.iter().copied().zip(l2.iter().copied()) {
+                expected_result.push(expected_op(a, b));
+            }
+            assert_eq!(result, expected_result, "{name} value mismatch");
+        }
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) unsafe fn test_inplace_value_all<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+    {
+        use crate::math::AutoMath;
+
+        let cases: [(&str, unsafe fn(&mut [T], T), fn(T, T) -> T); 4] = [
+            (
+                "add",
+                |a, b| generic_add_inplace::<T, R, AutoMath, _>(a, b),
+                AutoMath::add,
+            ),
+            (
+                "sub",
+                |a, b| generic_sub_inplace::<T, R, AutoMath, _>(a, b),
+                AutoMath::sub,
+            ),
+            (
+                "mul",
+                |a, b| generic_mul_inplace::<T, R, AutoMath, _>(a, b),
+                AutoMath::mul,
+            ),
+            (
+                "div",
+                |a, b| generic_div_inplace::<T, R, AutoMath, _>(a, b),
+                AutoMath::div,
+            ),
+        ];
+
+        for (name, op, expected_op) in cases {
+            let mut result = l1.clone();
+            op(&mut result, value);
+
+            let mut expected_result = Vec::new();
+            for a in l1.iter().copied() {
+                expected_result.push(expected_op(a, value));
+            }
+            assert_eq!(result, expected_result, "{name} value mismatch");
+        }
+    }
+
+    /// Writes each vertical op into the spare capacity of a `Vec` at a range of odd
+    /// lengths, only setting the length once the routine returns.
+    ///
+    /// Running this under Miri checks every element, including the tail, is written.
+    pub(crate) unsafe fn test_vector_uninit<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [core::mem::MaybeUninit<T>]: WriteOnlyBuffer<Item = T>,
+    {
+        use core::mem::MaybeUninit;
+
+        use crate::math::AutoMath;
+
+        type VerticalCase<T> = (
+            &'static str,
+            unsafe fn(&[T], &[T], &mut [MaybeUninit<T>]),
+            fn(T, T) -> T,
+        );
+        let cases: [VerticalCase<T>; 4] = [
+            (
+                "add",
+                |a, b, r| generic_add_vertical::<T, R, AutoMath, _, _, _>(a, b, r),
+                AutoMath::add,
+            ),
+            (
+                "sub",
+                |a, b, r| generic_sub_vertical::<T, R, AutoMath, _, _, _>(a, b, r),
+                AutoMath::sub,
+            ),
+            (
+                "mul",
+                |a, b, r| generic_mul_vertical::<T, R, AutoMath, _, _, _>(a, b, r),
+                AutoMath::mul,
+            ),
+            (
+                "div",
+                |a, b, r| generic_div_vertical::<T, R, AutoMath, _, _, _>(a, b, r),
+                AutoMath::div,
+            ),
+        ];
+
+        for len in [0, 1, 3, 7, 15, 31, 33, 67, 131, l1.len()] {
+            let (a, b) = (&l1[..len], &l2[..len]);
+
+            for (name, op, expected_op) in cases {
+                let mut result = Vec::<T>::with_capacity(len);
+                op(a, b, &mut result.spare_capacity_mut()[..len]);
+                result.set_len(len);
+
+                let expected_result = a
+                    .iter()
+                    .copied()
+                    .zip(b.iter().copied())
+                    .map(|(a, b)| expected_op(a, b))
+                    .collect::<Vec<T>>();
+                assert_eq!(result, expected_result, "{name} mismatch with len {len}");
+            }
+        }
+    }
 }
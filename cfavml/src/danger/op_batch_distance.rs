@@ -0,0 +1,989 @@
+//! Batched distance routines computing the distance between a single query vector
+//! and a contiguous batch of candidate vectors.
+//!
+//! Each routine processes [CANDIDATES_PER_ITER] candidates at once, reusing each load
+//! of the query across all candidates in the group. The accumulation order for each
+//! candidate is identical to the respective single-pair routine, meaning the results
+//! are bit-identical to calling the pairwise op for each candidate.
+//!
+//! [generic_dot_indexed] instead gathers candidates scattered throughout a larger buffer
+//! one at a time, prefetching the next candidate while computing the current one.
+//!
+//! [generic_batch_dot] and [generic_gemv](super::generic_gemv) trade the bit-identical
+//! accumulation order for a narrower accumulator per row, allowing [ROWS_PER_ITER] rows
+//! to be processed at once.
+//!
+//! [generic_pairwise_distances] treats every row of a single matrix as the query in
+//! turn, batching it against each of the rows which follow it.
+
+use core::{mem, slice};
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::core_simd_api::{DenseLane, SimdRegister};
+use crate::danger::op_cosine::cosine;
+use crate::danger::op_dot::generic_dot;
+use crate::math::Math;
+
+/// The number of candidates that are processed against the query at once.
+const CANDIDATES_PER_ITER: usize = 2;
+/// The number of matrix rows that are processed against the query at once by
+/// [generic_batch_dot] and [generic_gemv](super::generic_gemv).
+const ROWS_PER_ITER: usize = 4;
+/// The assumed size of a cache line when prefetching candidates.
+const CACHE_LINE_SIZE: usize = 64;
+
+#[inline(always)]
+/// A generic batched dot product between a `query` vector and each vector within `batch`.
+///
+/// `batch` is treated as a contiguous set of vectors each of `query.len()` elements, with
+/// the dot product of the `i`th vector being written to `result[i]`.
+///
+/// # Panics
+///
+/// If `batch` is not exactly `query.len() * result.len()` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_batch<T, R, M, B>(
+    query: &[T],
+    batch: &[T],
+    mut result: &mut [B],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    let dims = query.len();
+    let num_candidates = result.raw_buffer_len();
+    assert_batch_len(dims, batch.len(), num_candidates);
+
+    let query_ptr = query.as_ptr();
+    let batch_ptr = batch.as_ptr();
+
+    let mut i = 0;
+    while i + CANDIDATES_PER_ITER <= num_candidates {
+        let candidates = candidate_ptrs::<T, CANDIDATES_PER_ITER>(batch_ptr, dims, i);
+        let totals =
+            dot_many::<T, R, M, CANDIDATES_PER_ITER>(dims, query_ptr, candidates);
+        write_many(&mut result, i, totals);
+
+        i += CANDIDATES_PER_ITER;
+    }
+
+    while i < num_candidates {
+        let candidates = candidate_ptrs::<T, 1>(batch_ptr, dims, i);
+        let totals = dot_many::<T, R, M, 1>(dims, query_ptr, candidates);
+        write_many(&mut result, i, totals);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic batched squared Euclidean distance between a `query` vector and each
+/// vector within `batch`.
+///
+/// `batch` is treated as a contiguous set of vectors each of `query.len()` elements, with
+/// the distance of the `i`th vector being written to `result[i]`.
+///
+/// # Panics
+///
+/// If `batch` is not exactly `query.len() * result.len()` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_squared_euclidean_batch<T, R, M, B>(
+    query: &[T],
+    batch: &[T],
+    mut result: &mut [B],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    let dims = query.len();
+    let num_candidates = result.raw_buffer_len();
+    assert_batch_len(dims, batch.len(), num_candidates);
+
+    let query_ptr = query.as_ptr();
+    let batch_ptr = batch.as_ptr();
+
+    let mut i = 0;
+    while i + CANDIDATES_PER_ITER <= num_candidates {
+        let candidates = candidate_ptrs::<T, CANDIDATES_PER_ITER>(batch_ptr, dims, i);
+        let totals = squared_euclidean_many::<T, R, M, CANDIDATES_PER_ITER>(
+            dims, query_ptr, candidates,
+        );
+        write_many(&mut result, i, totals);
+
+        i += CANDIDATES_PER_ITER;
+    }
+
+    while i < num_candidates {
+        let candidates = candidate_ptrs::<T, 1>(batch_ptr, dims, i);
+        let totals = squared_euclidean_many::<T, R, M, 1>(dims, query_ptr, candidates);
+        write_many(&mut result, i, totals);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic batched cosine distance between a `query` vector and each vector within `batch`.
+///
+/// `batch` is treated as a contiguous set of vectors each of `query.len()` elements, with
+/// the distance of the `i`th vector being written to `result[i]`.
+///
+/// The norm of the `query` is only computed once for the entire batch.
+///
+/// # Panics
+///
+/// If `batch` is not exactly `query.len() * result.len()` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cosine_batch<T, R, M, B>(
+    query: &[T],
+    batch: &[T],
+    mut result: &mut [B],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    let dims = query.len();
+    let num_candidates = result.raw_buffer_len();
+    assert_batch_len(dims, batch.len(), num_candidates);
+
+    let query_ptr = query.as_ptr();
+    let batch_ptr = batch.as_ptr();
+
+    let norm_query = cosine_query_norm::<T, R, M>(dims, query_ptr);
+
+    let mut i = 0;
+    while i + CANDIDATES_PER_ITER <= num_candidates {
+        let candidates = candidate_ptrs::<T, CANDIDATES_PER_ITER>(batch_ptr, dims, i);
+        let (dots, norms) =
+            cosine_many::<T, R, M, CANDIDATES_PER_ITER>(dims, query_ptr, candidates);
+
+        for n in 0..CANDIDATES_PER_ITER {
+            result.write_at(i + n, cosine::<T, M>(dots[n], norm_query, norms[n]));
+        }
+
+        i += CANDIDATES_PER_ITER;
+    }
+
+    while i < num_candidates {
+        let candidates = candidate_ptrs::<T, 1>(batch_ptr, dims, i);
+        let (dots, norms) = cosine_many::<T, R, M, 1>(dims, query_ptr, candidates);
+        result.write_at(i, cosine::<T, M>(dots[0], norm_query, norms[0]));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic dot product between a `query` vector and each of the `dims` element vectors
+/// within `data` selected by `indices`.
+///
+/// The `i`th vector of `data` starts at `data[i * dims]`, with the dot product of the
+/// vector selected by `indices[n]` being written to `result[n]`.
+///
+/// The cache lines of the next candidate are prefetched while the current candidate is
+/// being computed, hiding the latency of the first loads of each candidate when they
+/// are scattered throughout a large `data` buffer.
+///
+/// # Panics
+///
+/// If `query` is not exactly `dims` elements in length, `result` is not exactly
+/// `indices.len()` elements in length or any of the `indices` are out of bounds
+/// of `data`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_indexed<T, R, M, B>(
+    dims: usize,
+    query: &[T],
+    data: &[T],
+    indices: &[usize],
+    mut result: &mut [B],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    assert_eq!(
+        query.len(),
+        dims,
+        "Buffer `query` must be exactly `dims` elements in length"
+    );
+    assert_eq!(
+        result.raw_buffer_len(),
+        indices.len(),
+        "Buffer `result` must be exactly `indices.len()` elements in length"
+    );
+    let num_vectors = data.len().checked_div(dims).unwrap_or(usize::MAX);
+    assert!(
+        indices.iter().all(|&index| index < num_vectors),
+        "Index out of bounds of the vectors within `data`"
+    );
+
+    let data_ptr = data.as_ptr();
+
+    for (n, &index) in indices.iter().enumerate() {
+        if let Some(&next) = indices.get(n + 1) {
+            prefetch_vector::<T, R>(data_ptr.add(next * dims), dims);
+        }
+
+        let candidate = slice::from_raw_parts(data_ptr.add(index * dims), dims);
+        let total = generic_dot::<T, R, M, _, _>(query, candidate);
+        result.write_at(n, total);
+    }
+}
+
+#[inline(always)]
+/// A generic dot product between each of the `n_rows` rows of `matrix` and the
+/// `query` vector, each being `dims` elements in length.
+///
+/// The dot product of the `i`th row is written to `scores[i]`.
+///
+/// [ROWS_PER_ITER] rows are processed at once, each with its own pair of accumulator
+/// registers, so the loads of every row are interleaved with a single load of the query.
+/// Unlike [generic_dot_batch] the accumulation order differs from
+/// [generic_dot](crate::danger::generic_dot), so float results may differ slightly.
+///
+/// # Panics
+///
+/// If `matrix` is not exactly `n_rows * dims` elements in length, `query` is not exactly
+/// `dims` elements in length or `scores` is not exactly `n_rows` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_batch_dot<T, R, M>(
+    n_rows: usize,
+    dims: usize,
+    matrix: &[T],
+    query: &[T],
+    scores: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        matrix.len(),
+        n_rows * dims,
+        "Buffer `matrix` must be exactly `n_rows * dims` elements in length"
+    );
+    assert_eq!(
+        query.len(),
+        dims,
+        "Buffer `query` must be exactly `dims` elements in length"
+    );
+    assert_eq!(
+        scores.len(),
+        n_rows,
+        "Buffer `scores` must be exactly `n_rows` elements in length"
+    );
+
+    batch_dot_kernel::<T, R, M>(
+        dims,
+        query.as_ptr(),
+        matrix.as_ptr(),
+        n_rows,
+        |i, total| scores[i] = total,
+    );
+}
+
+#[inline(always)]
+/// A generic squared Euclidean distance between every pair of the `n` vectors in
+/// `matrix`, each being `dims` elements in length.
+///
+/// The distance between the `i`th and `j`th vector, where `i < j`, is written to
+/// `result[i * n + j]`, filling the upper triangle of the row-major `n x n` matrix.
+/// The diagonal and lower triangle of `result` are left untouched.
+///
+/// Each row is batched against all of the rows following it, the accumulation order of
+/// each pair being identical to
+/// [generic_squared_euclidean](crate::danger::generic_squared_euclidean).
+///
+/// # Panics
+///
+/// If `matrix` is not exactly `n * dims` elements in length or `result` is not
+/// exactly `n * n` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_pairwise_distances<T, R, M>(
+    n: usize,
+    dims: usize,
+    matrix: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        matrix.len(),
+        n * dims,
+        "Buffer `matrix` must be exactly `n * dims` elements in length"
+    );
+    assert_eq!(
+        result.len(),
+        n * n,
+        "Buffer `result` must be exactly `n * n` elements in length"
+    );
+
+    let matrix_ptr = matrix.as_ptr();
+
+    for i in 0..n {
+        let query_ptr = matrix_ptr.add(i * dims);
+        let row = &mut result[i * n..][..n];
+
+        let mut j = i + 1;
+        while j + CANDIDATES_PER_ITER <= n {
+            let candidates =
+                candidate_ptrs::<T, CANDIDATES_PER_ITER>(matrix_ptr, dims, j);
+            let totals = squared_euclidean_many::<T, R, M, CANDIDATES_PER_ITER>(
+                dims, query_ptr, candidates,
+            );
+            row[j..][..CANDIDATES_PER_ITER].copy_from_slice(&totals);
+
+            j += CANDIDATES_PER_ITER;
+        }
+
+        while j < n {
+            let candidates = candidate_ptrs::<T, 1>(matrix_ptr, dims, j);
+            let [total] =
+                squared_euclidean_many::<T, R, M, 1>(dims, query_ptr, candidates);
+            row[j] = total;
+
+            j += 1;
+        }
+    }
+}
+
+#[inline(always)]
+/// Prefetches every cache line of the `dims` element vector starting at `ptr`.
+unsafe fn prefetch_vector<T, R>(ptr: *const T, dims: usize)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+{
+    let num_bytes = dims * mem::size_of::<T>();
+
+    let mut offset = 0;
+    while offset < num_bytes {
+        R::prefetch(ptr.byte_add(offset));
+        offset += CACHE_LINE_SIZE;
+    }
+}
+
+#[inline(always)]
+fn assert_batch_len(dims: usize, batch_len: usize, num_candidates: usize) {
+    assert_eq!(
+        batch_len,
+        dims * num_candidates,
+        "Buffer `batch` must be exactly `query.len() * result.len()` elements in length"
+    );
+}
+
+#[inline(always)]
+/// Computes the dot product of the query against each of the `num_rows` rows of `dims`
+/// elements starting at `matrix`, passing the total of the `i`th row to `write`.
+///
+/// [ROWS_PER_ITER] rows are processed at once with [dot_rows], with the remaining rows
+/// being processed one at a time.
+pub(super) unsafe fn batch_dot_kernel<T, R, M>(
+    dims: usize,
+    query: *const T,
+    matrix: *const T,
+    num_rows: usize,
+    mut write: impl FnMut(usize, T),
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let mut i = 0;
+    while i + ROWS_PER_ITER <= num_rows {
+        let rows = candidate_ptrs::<T, ROWS_PER_ITER>(matrix, dims, i);
+        let totals = dot_rows::<T, R, M, ROWS_PER_ITER>(dims, query, rows);
+        for (n, total) in totals.into_iter().enumerate() {
+            write(i + n, total);
+        }
+
+        i += ROWS_PER_ITER;
+    }
+
+    while i < num_rows {
+        let rows = candidate_ptrs::<T, 1>(matrix, dims, i);
+        let [total] = dot_rows::<T, R, M, 1>(dims, query, rows);
+        write(i, total);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+unsafe fn candidate_ptrs<T, const N: usize>(
+    batch_ptr: *const T,
+    dims: usize,
+    start: usize,
+) -> [*const T; N] {
+    let mut ptrs = [batch_ptr; N];
+    for (n, ptr) in ptrs.iter_mut().enumerate() {
+        *ptr = batch_ptr.add((start + n) * dims);
+    }
+    ptrs
+}
+
+#[inline(always)]
+unsafe fn write_many<T, B, const N: usize>(
+    result: &mut &mut [B],
+    start: usize,
+    values: [T; N],
+) where
+    T: Copy,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    for (n, value) in values.into_iter().enumerate() {
+        result.write_at(start + n, value);
+    }
+}
+
+#[inline(always)]
+/// Computes the dot product of the query against `N` candidates, following the same
+/// accumulation order as [generic_dot](crate::danger::generic_dot).
+unsafe fn dot_many<T, R, M, const N: usize>(
+    dims: usize,
+    query: *const T,
+    candidates: [*const T; N],
+) -> [T; N]
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let offset_from = dims % R::elements_per_dense();
+
+    let mut dense_totals = [R::zeroed_dense(); N];
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let l1 = R::load_dense(query.add(i));
+        for n in 0..N {
+            let l2 = R::load_dense(candidates[n].add(i));
+            dense_totals[n] = R::fmadd_dense(l1, l2, dense_totals[n]);
+        }
+
+        i += R::elements_per_dense();
+    }
+
+    let mut totals =
+        dense_totals.map(|lane: DenseLane<R::Register>| R::sum_to_register(lane));
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (dims - offset_from) {
+        let l1 = R::load(query.add(i));
+        for n in 0..N {
+            let l2 = R::load(candidates[n].add(i));
+            totals[n] = R::fmadd(l1, l2, totals[n]);
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut totals = totals.map(|reg| R::sum_to_value(reg));
+
+    while i < dims {
+        let a = query.add(i).read();
+        for n in 0..N {
+            let b = candidates[n].add(i).read();
+            totals[n] = M::add(totals[n], M::mul(a, b));
+        }
+
+        i += 1;
+    }
+
+    totals
+}
+
+#[inline(always)]
+/// Computes the dot product of the query against `N` rows, accumulating each row into
+/// a pair of registers rather than a full dense lane to keep every accumulator and
+/// the query resident in registers.
+unsafe fn dot_rows<T, R, M, const N: usize>(
+    dims: usize,
+    query: *const T,
+    rows: [*const T; N],
+) -> [T; N]
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let step = R::elements_per_lane() * 2;
+    let offset_from = dims % step;
+
+    let mut acc_a = [R::zeroed(); N];
+    let mut acc_b = [R::zeroed(); N];
+
+    // Operate over pairs of registers first.
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let q_a = R::load(query.add(i));
+        let q_b = R::load(query.add(i + R::elements_per_lane()));
+        for n in 0..N {
+            let l_a = R::load(rows[n].add(i));
+            let l_b = R::load(rows[n].add(i + R::elements_per_lane()));
+            acc_a[n] = R::fmadd(q_a, l_a, acc_a[n]);
+            acc_b[n] = R::fmadd(q_b, l_b, acc_b[n]);
+        }
+
+        i += step;
+    }
+
+    // Operate over a single register next.
+    let offset_from = offset_from % R::elements_per_lane();
+    if i < (dims - offset_from) {
+        let q = R::load(query.add(i));
+        for n in 0..N {
+            let l = R::load(rows[n].add(i));
+            acc_a[n] = R::fmadd(q, l, acc_a[n]);
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut totals = [M::zero(); N];
+    for n in 0..N {
+        totals[n] = R::sum_to_value(R::add(acc_a[n], acc_b[n]));
+    }
+
+    while i < dims {
+        let a = query.add(i).read();
+        for n in 0..N {
+            let b = rows[n].add(i).read();
+            totals[n] = M::add(totals[n], M::mul(a, b));
+        }
+
+        i += 1;
+    }
+
+    totals
+}
+
+#[inline(always)]
+/// Computes the squared Euclidean distance of the query against `N` candidates, following
+/// the same accumulation order as [generic_squared_euclidean](crate::danger::generic_squared_euclidean).
+unsafe fn squared_euclidean_many<T, R, M, const N: usize>(
+    dims: usize,
+    query: *const T,
+    candidates: [*const T; N],
+) -> [T; N]
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let offset_from = dims % R::elements_per_dense();
+
+    let mut dense_totals = [R::zeroed_dense(); N];
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let l1 = R::load_dense(query.add(i));
+        for n in 0..N {
+            let l2 = R::load_dense(candidates[n].add(i));
+            let diff = R::sub_dense(l1, l2);
+            dense_totals[n] = R::fmadd_dense(diff, diff, dense_totals[n]);
+        }
+
+        i += R::elements_per_dense();
+    }
+
+    let mut totals =
+        dense_totals.map(|lane: DenseLane<R::Register>| R::sum_to_register(lane));
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (dims - offset_from) {
+        let l1 = R::load(query.add(i));
+        for n in 0..N {
+            let l2 = R::load(candidates[n].add(i));
+            let diff = R::sub(l1, l2);
+            totals[n] = R::fmadd(diff, diff, totals[n]);
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut totals = totals.map(|reg| R::sum_to_value(reg));
+
+    while i < dims {
+        let a = query.add(i).read();
+        for n in 0..N {
+            let b = candidates[n].add(i).read();
+            let diff = M::sub(a, b);
+            totals[n] = M::add(totals[n], M::mul(diff, diff));
+        }
+
+        i += 1;
+    }
+
+    totals
+}
+
+#[inline(always)]
+/// Computes the squared norm of the query in the same accumulation order as
+/// [generic_cosine](crate::danger::generic_cosine).
+unsafe fn cosine_query_norm<T, R, M>(dims: usize, query: *const T) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let offset_from = dims % R::elements_per_lane();
+
+    let mut norm = R::zeroed();
+
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let l1 = R::load(query.add(i));
+        norm = R::fmadd(l1, l1, norm);
+
+        i += R::elements_per_lane();
+    }
+
+    let mut norm = R::sum_to_value(norm);
+
+    while i < dims {
+        let a = query.add(i).read();
+        norm = M::add(norm, M::mul(a, a));
+
+        i += 1;
+    }
+
+    norm
+}
+
+#[inline(always)]
+/// Computes the dot product and squared norm of `N` candidates in the same accumulation
+/// order as [generic_cosine](crate::danger::generic_cosine).
+unsafe fn cosine_many<T, R, M, const N: usize>(
+    dims: usize,
+    query: *const T,
+    candidates: [*const T; N],
+) -> ([T; N], [T; N])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let offset_from = dims % R::elements_per_lane();
+
+    let mut dots = [R::zeroed(); N];
+    let mut norms = [R::zeroed(); N];
+
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let l1 = R::load(query.add(i));
+        for n in 0..N {
+            let l2 = R::load(candidates[n].add(i));
+            norms[n] = R::fmadd(l2, l2, norms[n]);
+            dots[n] = R::fmadd(l1, l2, dots[n]);
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    let mut dots = dots.map(|reg| R::sum_to_value(reg));
+    let mut norms = norms.map(|reg| R::sum_to_value(reg));
+
+    while i < dims {
+        let a = query.add(i).read();
+        for n in 0..N {
+            let b = candidates[n].add(i).read();
+            norms[n] = M::add(norms[n], M::mul(b, b));
+            dots[n] = M::add(dots[n], M::mul(a, b));
+        }
+
+        i += 1;
+    }
+
+    (dots, norms)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::danger::{generic_cosine, generic_dot, generic_squared_euclidean};
+
+    fn get_batch<T>(dims: usize, num_candidates: usize) -> (Vec<T>, Vec<T>)
+    where
+        T: Copy,
+        crate::math::AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let (query, _) = crate::test_utils::get_sample_vectors::<T>(dims);
+        let (batch, _) =
+            crate::test_utils::get_sample_vectors::<T>(dims * num_candidates);
+        (query, batch)
+    }
+
+    pub(crate) unsafe fn test_dot_batch<T, R>(dims: usize, num_candidates: usize)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        let (query, batch) = get_batch::<T>(dims, num_candidates);
+
+        let mut result = vec![AutoMath::zero(); num_candidates];
+        generic_dot_batch::<T, R, AutoMath, _>(&query, &batch, &mut result);
+
+        let expected = batch
+            .chunks(dims.max(1))
+            .map(|candidate| generic_dot::<T, R, AutoMath, _, _>(&query, candidate))
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected[..num_candidates], "value mismatch");
+    }
+
+    pub(crate) unsafe fn test_batch_dot<T, R>(dims: usize, n_rows: usize)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        use crate::math::AutoMath;
+
+        let (query, matrix) = get_batch::<T>(dims, n_rows);
+
+        let mut scores = vec![AutoMath::zero(); n_rows];
+        generic_batch_dot::<T, R, AutoMath>(n_rows, dims, &matrix, &query, &mut scores);
+
+        for (i, value) in scores.into_iter().enumerate() {
+            let row = &matrix[i * dims..][..dims];
+            let expected = crate::test_utils::simple_dot(row, &query);
+            assert!(
+                AutoMath::is_close(value, expected),
+                "value mismatch at row {i} {value:?} vs {expected:?}"
+            );
+        }
+    }
+
+    pub(crate) unsafe fn test_dot_indexed<T, R>(dims: usize, num_vectors: usize)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        let (query, data) = get_batch::<T>(dims, num_vectors);
+        let indices = (0..num_vectors)
+            .rev()
+            .chain((0..num_vectors).step_by(3))
+            .collect::<Vec<_>>();
+
+        let mut result = vec![AutoMath::zero(); indices.len()];
+        generic_dot_indexed::<T, R, AutoMath, _>(
+            dims,
+            &query,
+            &data,
+            &indices,
+            &mut result,
+        );
+
+        let expected = indices
+            .iter()
+            .map(|&index| {
+                let candidate = &data[index * dims..][..dims];
+                generic_dot::<T, R, AutoMath, _, _>(&query, candidate)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected, "value mismatch");
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds of the vectors within `data`")]
+    fn test_dot_indexed_out_of_bounds() {
+        use crate::math::AutoMath;
+
+        let mut result = [0.0f32; 2];
+        unsafe {
+            generic_dot_indexed::<f32, crate::danger::Fallback, AutoMath, _>(
+                2,
+                &[0.0; 2],
+                &[0.0; 6],
+                &[0, 3],
+                &mut result,
+            )
+        };
+    }
+
+    pub(crate) unsafe fn test_squared_euclidean_batch<T, R>(
+        dims: usize,
+        num_candidates: usize,
+    ) where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        let (query, batch) = get_batch::<T>(dims, num_candidates);
+
+        let mut result = vec![AutoMath::zero(); num_candidates];
+        generic_squared_euclidean_batch::<T, R, AutoMath, _>(
+            &query,
+            &batch,
+            &mut result,
+        );
+
+        let expected = batch
+            .chunks(dims.max(1))
+            .map(|candidate| {
+                generic_squared_euclidean::<T, R, AutoMath, _, _>(&query, candidate)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected[..num_candidates], "value mismatch");
+    }
+
+    pub(crate) unsafe fn test_pairwise_distances<T, R>(n: usize, dims: usize)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        use crate::math::AutoMath;
+
+        let (matrix, _) = crate::test_utils::get_sample_vectors::<T>(n * dims);
+
+        // The diagonal and lower triangle must be left untouched.
+        let sentinel = AutoMath::max();
+        let mut result = vec![sentinel; n * n];
+        generic_pairwise_distances::<T, R, AutoMath>(n, dims, &matrix, &mut result);
+
+        for i in 0..n {
+            for j in 0..n {
+                let value = result[i * n + j];
+                let expected = if i < j {
+                    let x = &matrix[i * dims..][..dims];
+                    let y = &matrix[j * dims..][..dims];
+                    generic_squared_euclidean::<T, R, AutoMath, _, _>(x, y)
+                } else {
+                    sentinel
+                };
+                assert_eq!(value, expected, "value mismatch at ({i}, {j})");
+            }
+        }
+    }
+
+    /// A batch of `0` and `1` values, keeping the norms of the narrow and signed integer
+    /// types from overflowing where the rng generated values would.
+    #[allow(unused)]
+    fn get_bounded_batch<T>(dims: usize, num_candidates: usize) -> (Vec<T>, Vec<T>)
+    where
+        T: Copy,
+        crate::math::AutoMath: Math<T>,
+    {
+        use crate::math::AutoMath;
+
+        let bounded = |len: usize, step: usize| {
+            (0..len)
+                .map(|i| {
+                    if (i + i / step) % 3 == 0 {
+                        AutoMath::zero()
+                    } else {
+                        AutoMath::one()
+                    }
+                })
+                .collect::<Vec<T>>()
+        };
+        (bounded(dims, 7), bounded(dims * num_candidates, 13))
+    }
+
+    pub(crate) unsafe fn test_cosine_batch<T, R>(dims: usize, num_candidates: usize)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let (query, batch) = get_batch::<T>(dims, num_candidates);
+        check_cosine_batch::<T, R>(&query, &batch, dims, num_candidates);
+    }
+
+    #[allow(unused)]
+    pub(crate) unsafe fn test_cosine_batch_bounded<T, R>(
+        dims: usize,
+        num_candidates: usize,
+    ) where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let (query, batch) = get_bounded_batch::<T>(dims, num_candidates);
+        check_cosine_batch::<T, R>(&query, &batch, dims, num_candidates);
+    }
+
+    unsafe fn check_cosine_batch<T, R>(
+        query: &[T],
+        batch: &[T],
+        dims: usize,
+        num_candidates: usize,
+    ) where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        crate::math::AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        use crate::math::AutoMath;
+
+        let mut result = vec![AutoMath::zero(); num_candidates];
+        generic_cosine_batch::<T, R, AutoMath, _>(query, batch, &mut result);
+
+        let expected = batch
+            .chunks(dims.max(1))
+            .map(|candidate| generic_cosine::<T, R, AutoMath, _, _>(query, candidate))
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected[..num_candidates], "value mismatch");
+    }
+}
@@ -0,0 +1,325 @@
+use core::ops::{BitAnd, BitOr, BitXor, Not};
+
+use super::core_routine_boilerplate::{
+    apply_unary_vertical_kernel,
+    apply_vertical_kernel,
+};
+use super::core_simd_api::SimdBitwiseRegister;
+use crate::buffer::WriteOnlyBuffer;
+use crate::math::{Math, WrappingShift};
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+#[inline(always)]
+/// A generic vector bitwise _and_ implementation over two vectors or broadcast values.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_bitand_vertical<T, R, M, B1, B2, B3>(
+    a: B1,
+    b: B2,
+    result: &mut [B3],
+) where
+    T: Copy + BitAnd<Output = T>,
+    R: SimdBitwiseRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    apply_vertical_kernel::<T, R, M, B1, B2, B3>(
+        a,
+        b,
+        result,
+        R::bitand_dense,
+        R::bitand,
+        |a, b| a & b,
+    )
+}
+
+#[inline(always)]
+/// A generic vector bitwise _or_ implementation over two vectors or broadcast values.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_bitor_vertical<T, R, M, B1, B2, B3>(
+    a: B1,
+    b: B2,
+    result: &mut [B3],
+) where
+    T: Copy + BitOr<Output = T>,
+    R: SimdBitwiseRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    apply_vertical_kernel::<T, R, M, B1, B2, B3>(
+        a,
+        b,
+        result,
+        R::bitor_dense,
+        R::bitor,
+        |a, b| a | b,
+    )
+}
+
+#[inline(always)]
+/// A generic vector bitwise _xor_ implementation over two vectors or broadcast values.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_bitxor_vertical<T, R, M, B1, B2, B3>(
+    a: B1,
+    b: B2,
+    result: &mut [B3],
+) where
+    T: Copy + BitXor<Output = T>,
+    R: SimdBitwiseRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    apply_vertical_kernel::<T, R, M, B1, B2, B3>(
+        a,
+        b,
+        result,
+        R::bitxor_dense,
+        R::bitxor,
+        |a, b| a ^ b,
+    )
+}
+
+#[inline(always)]
+/// A generic vector bitwise _not_ implementation over a single vector.
+///
+/// # Safety
+///
+/// The sizes of `a` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_bitnot_vertical<T, R, M, B1, B2>(a: B1, result: &mut [B2])
+where
+    T: Copy + Not<Output = T>,
+    R: SimdBitwiseRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    apply_unary_vertical_kernel::<T, R, M, B1, B2>(
+        a,
+        result,
+        R::bitnot_dense,
+        R::bitnot,
+        |a| !a,
+    )
+}
+
+#[inline(always)]
+/// A generic vector _shift left_ implementation over two vectors or broadcast values,
+/// shifting each element of `a` by the corresponding element of `b`.
+///
+/// The shift amount is masked to the bit width of `T`, matching the behaviour of
+/// [`wrapping_shl`](i32::wrapping_shl), i.e. shifting an `i32` by `33` is the same
+/// as shifting it by `1`.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_shl_vertical<T, R, M, B1, B2, B3>(a: B1, b: B2, result: &mut [B3])
+where
+    T: WrappingShift,
+    R: SimdBitwiseRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    apply_vertical_kernel::<T, R, M, B1, B2, B3>(
+        a,
+        b,
+        result,
+        R::shl_dense,
+        R::shl,
+        |a, b| a.shl_wrapping(b),
+    )
+}
+
+#[inline(always)]
+/// A generic vector _shift right_ implementation over two vectors or broadcast values,
+/// shifting each element of `a` by the corresponding element of `b`.
+///
+/// Signed types perform an arithmetic shift (sign extending) while unsigned types
+/// perform a logical shift (zero filling).
+///
+/// The shift amount is masked to the bit width of `T`, matching the behaviour of
+/// [`wrapping_shr`](i32::wrapping_shr), i.e. shifting an `i32` by `33` is the same
+/// as shifting it by `1`.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_shr_vertical<T, R, M, B1, B2, B3>(a: B1, b: B2, result: &mut [B3])
+where
+    T: WrappingShift,
+    R: SimdBitwiseRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    apply_vertical_kernel::<T, R, M, B1, B2, B3>(
+        a,
+        b,
+        result,
+        R::shr_dense,
+        R::shr,
+        |a, b| a.shr_wrapping(b),
+    )
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+
+    pub(crate) unsafe fn test_bitwise_vector_all<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy
+            + PartialEq
+            + std::fmt::Debug
+            + BitAnd<Output = T>
+            + BitOr<Output = T>
+            + BitXor<Output = T>
+            + Not<Output = T>,
+        R: SimdBitwiseRegister<T>,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let dims = l1.len();
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_bitand_vertical::<T, R, AutoMath, _, _, _>(&l1, &l2, &mut result);
+        let expected = l1.iter().zip(&l2).map(|(a, b)| *a & *b).collect::<Vec<_>>();
+        assert_eq!(result, expected, "bitand value mismatch");
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_bitor_vertical::<T, R, AutoMath, _, _, _>(&l1, &l2, &mut result);
+        let expected = l1.iter().zip(&l2).map(|(a, b)| *a | *b).collect::<Vec<_>>();
+        assert_eq!(result, expected, "bitor value mismatch");
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_bitxor_vertical::<T, R, AutoMath, _, _, _>(&l1, &l2, &mut result);
+        let expected = l1.iter().zip(&l2).map(|(a, b)| *a ^ *b).collect::<Vec<_>>();
+        assert_eq!(result, expected, "bitxor value mismatch");
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_bitnot_vertical::<T, R, AutoMath, _, _>(&l1, &mut result);
+        let expected = l1.iter().map(|a| !*a).collect::<Vec<_>>();
+        assert_eq!(result, expected, "bitnot value mismatch");
+    }
+
+    pub(crate) unsafe fn test_bitwise_value_all<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy
+            + PartialEq
+            + std::fmt::Debug
+            + IntoMemLoader<T>
+            + BitAnd<Output = T>
+            + BitOr<Output = T>
+            + BitXor<Output = T>,
+        T::Loader: MemLoader<Value = T>,
+        R: SimdBitwiseRegister<T>,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let dims = l1.len();
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_bitand_vertical::<T, R, AutoMath, _, _, _>(&l1, value, &mut result);
+        let expected = l1.iter().map(|a| *a & value).collect::<Vec<_>>();
+        assert_eq!(result, expected, "bitand value mismatch");
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_bitor_vertical::<T, R, AutoMath, _, _, _>(&l1, value, &mut result);
+        let expected = l1.iter().map(|a| *a | value).collect::<Vec<_>>();
+        assert_eq!(result, expected, "bitor value mismatch");
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_bitxor_vertical::<T, R, AutoMath, _, _, _>(&l1, value, &mut result);
+        let expected = l1.iter().map(|a| *a ^ value).collect::<Vec<_>>();
+        assert_eq!(result, expected, "bitxor value mismatch");
+    }
+
+    pub(crate) unsafe fn test_shift_all<T, R>(l1: Vec<T>, l2: Vec<T>, boundary: &[T])
+    where
+        T: WrappingShift + PartialEq + std::fmt::Debug + IntoMemLoader<T>,
+        T::Loader: MemLoader<Value = T>,
+        R: SimdBitwiseRegister<T>,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let dims = l1.len();
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_shl_vertical::<T, R, AutoMath, _, _, _>(&l1, &l2, &mut result);
+        let expected = l1
+            .iter()
+            .zip(&l2)
+            .map(|(a, b)| a.shl_wrapping(*b))
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected, "shl vector mismatch");
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_shr_vertical::<T, R, AutoMath, _, _, _>(&l1, &l2, &mut result);
+        let expected = l1
+            .iter()
+            .zip(&l2)
+            .map(|(a, b)| a.shr_wrapping(*b))
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected, "shr vector mismatch");
+
+        // Amounts at and beyond the bit width must wrap rather than saturate.
+        for &amount in boundary {
+            let mut result = vec![AutoMath::zero(); dims];
+            generic_shl_vertical::<T, R, AutoMath, _, _, _>(&l1, amount, &mut result);
+            let expected = l1
+                .iter()
+                .map(|a| a.shl_wrapping(amount))
+                .collect::<Vec<_>>();
+            assert_eq!(result, expected, "shl value mismatch for {amount:?}");
+
+            let mut result = vec![AutoMath::zero(); dims];
+            generic_shr_vertical::<T, R, AutoMath, _, _, _>(&l1, amount, &mut result);
+            let expected = l1
+                .iter()
+                .map(|a| a.shr_wrapping(amount))
+                .collect::<Vec<_>>();
+            assert_eq!(result, expected, "shr value mismatch for {amount:?}");
+        }
+    }
+}
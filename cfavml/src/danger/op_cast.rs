@@ -0,0 +1,86 @@
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::core_simd_api::SimdCastRegister;
+use crate::math::CastAs;
+
+#[inline(always)]
+/// A generic vector conversion of each element of `a` from `F` into `T`, writing
+/// the converted elements to `result`.
+///
+/// The conversion follows the semantics of an `as` cast:
+///
+/// - Float to int conversions truncate towards zero, saturate to the bounds of `T`
+///   and convert `NaN` to `0`.
+/// - Int to float and `f64` to `f32` conversions round to the nearest representable value.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+///
+/// # Panics
+///
+/// If the lengths of `a` and `result` do not match.
+pub unsafe fn generic_cast_vector<F, T, R, B>(a: &[F], mut result: &mut [B])
+where
+    F: CastAs<T>,
+    T: Copy,
+    R: SimdCastRegister<F, T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    let len = a.len();
+    assert_eq!(
+        len,
+        result.raw_buffer_len(),
+        "Buffers `a` and `result` do not match in size"
+    );
+
+    let a = a.as_ptr();
+    let result_ptr = result.as_write_only_ptr();
+
+    let offset_from = len % R::cast_elements_per_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        R::cast_dense(a.add(i), result_ptr.add(i));
+        i += R::cast_elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::cast_elements_per_lane();
+    while i < (len - offset_from) {
+        R::cast(a.add(i), result_ptr.add(i));
+        i += R::cast_elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        result.write_at(i, a.add(i).read().cast_as());
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+
+    pub(crate) unsafe fn test_cast<F, T, R>(l1: Vec<F>)
+    where
+        F: CastAs<T>,
+        T: Copy + Default + Debug,
+        R: SimdCastRegister<F, T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let mut result = vec![T::default(); l1.len()];
+        generic_cast_vector::<F, T, R, T>(&l1, &mut result);
+
+        let expected = l1.iter().map(|v| v.cast_as()).collect::<Vec<T>>();
+        // Compared via the debug output so `NaN` values match.
+        assert_eq!(
+            format!("{result:?}"),
+            format!("{expected:?}"),
+            "value mismatch"
+        );
+    }
+}
@@ -0,0 +1,551 @@
+//! Boolean comparison reductions, checking if any or all elements of a vector satisfy
+//! a comparison against a broadcast value.
+//!
+//! Each dense lane of comparison masks is reduced to a single value via the register
+//! `max` (any) or `min` (all) horizontals, returning as soon as the result is known.
+
+use crate::danger::{DenseLane, SimdRegister};
+use crate::math::Math;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+#[inline(always)]
+/// A generic check if **_any_** element of vector `a` is **_equal to_** the broadcast `value`.
+///
+/// This returns as soon as a match is found, an empty input returns `false`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_any_eq_value<T, R, M, B1>(a: B1, value: T) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_any_kernel::<T, R, M, B1>(a, value, R::eq_dense, R::eq, M::cmp_eq)
+}
+
+#[inline(always)]
+/// A generic check if **_any_** element of vector `a` is **_not equal to_** the broadcast `value`.
+///
+/// This returns as soon as a match is found, an empty input returns `false`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_any_neq_value<T, R, M, B1>(a: B1, value: T) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_any_kernel::<T, R, M, B1>(a, value, R::neq_dense, R::neq, |a, b| {
+        !M::cmp_eq(a, b)
+    })
+}
+
+#[inline(always)]
+/// A generic check if **_any_** element of vector `a` is **_less than_** the broadcast `value`.
+///
+/// This returns as soon as a match is found, an empty input returns `false`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_any_lt_value<T, R, M, B1>(a: B1, value: T) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_any_kernel::<T, R, M, B1>(a, value, R::lt_dense, R::lt, M::cmp_lt)
+}
+
+#[inline(always)]
+/// A generic check if **_any_** element of vector `a` is **_less than or equal to_** the broadcast `value`.
+///
+/// This returns as soon as a match is found, an empty input returns `false`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_any_lte_value<T, R, M, B1>(a: B1, value: T) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_any_kernel::<T, R, M, B1>(a, value, R::lte_dense, R::lte, M::cmp_lte)
+}
+
+#[inline(always)]
+/// A generic check if **_any_** element of vector `a` is **_greater than_** the broadcast `value`.
+///
+/// This returns as soon as a match is found, an empty input returns `false`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_any_gt_value<T, R, M, B1>(a: B1, value: T) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_any_kernel::<T, R, M, B1>(a, value, R::gt_dense, R::gt, M::cmp_gt)
+}
+
+#[inline(always)]
+/// A generic check if **_any_** element of vector `a` is **_greater than or equal to_** the broadcast `value`.
+///
+/// This returns as soon as a match is found, an empty input returns `false`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_any_gte_value<T, R, M, B1>(a: B1, value: T) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_any_kernel::<T, R, M, B1>(a, value, R::gte_dense, R::gte, M::cmp_gte)
+}
+
+#[inline(always)]
+/// A generic check if **_all_** elements of vector `a` are **_equal to_** the broadcast `value`.
+///
+/// This returns as soon as a mismatch is found, an empty input returns `true`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_all_eq_value<T, R, M, B1>(a: B1, value: T) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_all_kernel::<T, R, M, B1>(a, value, R::eq_dense, R::eq, M::cmp_eq)
+}
+
+#[inline(always)]
+/// A generic check if **_all_** elements of vector `a` are **_not equal to_** the broadcast `value`.
+///
+/// This returns as soon as a mismatch is found, an empty input returns `true`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_all_neq_value<T, R, M, B1>(a: B1, value: T) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_all_kernel::<T, R, M, B1>(a, value, R::neq_dense, R::neq, |a, b| {
+        !M::cmp_eq(a, b)
+    })
+}
+
+#[inline(always)]
+/// A generic check if **_all_** elements of vector `a` are **_less than_** the broadcast `value`.
+///
+/// This returns as soon as a mismatch is found, an empty input returns `true`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_all_lt_value<T, R, M, B1>(a: B1, value: T) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_all_kernel::<T, R, M, B1>(a, value, R::lt_dense, R::lt, M::cmp_lt)
+}
+
+#[inline(always)]
+/// A generic check if **_all_** elements of vector `a` are **_less than or equal to_** the broadcast `value`.
+///
+/// This returns as soon as a mismatch is found, an empty input returns `true`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_all_lte_value<T, R, M, B1>(a: B1, value: T) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_all_kernel::<T, R, M, B1>(a, value, R::lte_dense, R::lte, M::cmp_lte)
+}
+
+#[inline(always)]
+/// A generic check if **_all_** elements of vector `a` are **_greater than_** the broadcast `value`.
+///
+/// This returns as soon as a mismatch is found, an empty input returns `true`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_all_gt_value<T, R, M, B1>(a: B1, value: T) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_all_kernel::<T, R, M, B1>(a, value, R::gt_dense, R::gt, M::cmp_gt)
+}
+
+#[inline(always)]
+/// A generic check if **_all_** elements of vector `a` are **_greater than or equal to_** the broadcast `value`.
+///
+/// This returns as soon as a mismatch is found, an empty input returns `true`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_all_gte_value<T, R, M, B1>(a: B1, value: T) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_all_kernel::<T, R, M, B1>(a, value, R::gte_dense, R::gte, M::cmp_gte)
+}
+
+#[allow(clippy::type_complexity)]
+#[inline(always)]
+unsafe fn generic_any_kernel<T, R, M, B1>(
+    a: B1,
+    value: T,
+    dense_kernel: unsafe fn(
+        DenseLane<R::Register>,
+        DenseLane<R::Register>,
+    ) -> DenseLane<R::Register>,
+    reg_kernel: unsafe fn(R::Register, R::Register) -> R::Register,
+    single_kernel: impl Fn(T, T) -> bool,
+) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let value_dense = R::filled_dense(value);
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        let mask = R::max_to_register(dense_kernel(l1, value_dense));
+        if M::cmp_eq(R::max_to_value(mask), M::one()) {
+            return true;
+        }
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let value_reg = R::filled(value);
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        let mask = reg_kernel(l1, value_reg);
+        if M::cmp_eq(R::max_to_value(mask), M::one()) {
+            return true;
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        if single_kernel(a.read(), value) {
+            return true;
+        }
+
+        i += 1;
+    }
+
+    false
+}
+
+#[allow(clippy::type_complexity)]
+#[inline(always)]
+unsafe fn generic_all_kernel<T, R, M, B1>(
+    a: B1,
+    value: T,
+    dense_kernel: unsafe fn(
+        DenseLane<R::Register>,
+        DenseLane<R::Register>,
+    ) -> DenseLane<R::Register>,
+    reg_kernel: unsafe fn(R::Register, R::Register) -> R::Register,
+    single_kernel: impl Fn(T, T) -> bool,
+) -> bool
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let value_dense = R::filled_dense(value);
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        let mask = R::min_to_register(dense_kernel(l1, value_dense));
+        if M::cmp_eq(R::min_to_value(mask), M::zero()) {
+            return false;
+        }
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let value_reg = R::filled(value);
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        let mask = reg_kernel(l1, value_reg);
+        if M::cmp_eq(R::min_to_value(mask), M::zero()) {
+            return false;
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        if !single_kernel(a.read(), value) {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+
+    pub(crate) unsafe fn test_any_eq<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let result = generic_any_eq_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().any(|v| AutoMath::cmp_eq(*v, value));
+        assert_eq!(result, expected, "value mismatch");
+
+        let result = generic_any_eq_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert!(!result, "empty input should be `false`");
+    }
+
+    pub(crate) unsafe fn test_any_neq<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let result = generic_any_neq_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().any(|v| !AutoMath::cmp_eq(*v, value));
+        assert_eq!(result, expected, "value mismatch");
+
+        let result = generic_any_neq_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert!(!result, "empty input should be `false`");
+    }
+
+    pub(crate) unsafe fn test_any_lt<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let result = generic_any_lt_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().any(|v| AutoMath::cmp_lt(*v, value));
+        assert_eq!(result, expected, "value mismatch");
+
+        let result = generic_any_lt_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert!(!result, "empty input should be `false`");
+    }
+
+    pub(crate) unsafe fn test_any_lte<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let result = generic_any_lte_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().any(|v| AutoMath::cmp_lte(*v, value));
+        assert_eq!(result, expected, "value mismatch");
+
+        let result = generic_any_lte_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert!(!result, "empty input should be `false`");
+    }
+
+    pub(crate) unsafe fn test_any_gt<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let result = generic_any_gt_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().any(|v| AutoMath::cmp_gt(*v, value));
+        assert_eq!(result, expected, "value mismatch");
+
+        let result = generic_any_gt_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert!(!result, "empty input should be `false`");
+    }
+
+    pub(crate) unsafe fn test_any_gte<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let result = generic_any_gte_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().any(|v| AutoMath::cmp_gte(*v, value));
+        assert_eq!(result, expected, "value mismatch");
+
+        let result = generic_any_gte_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert!(!result, "empty input should be `false`");
+    }
+
+    pub(crate) unsafe fn test_all_eq<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let result = generic_all_eq_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().all(|v| AutoMath::cmp_eq(*v, value));
+        assert_eq!(result, expected, "value mismatch");
+
+        let result = generic_all_eq_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert!(result, "empty input should be `true`");
+    }
+
+    pub(crate) unsafe fn test_all_neq<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let result = generic_all_neq_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().all(|v| !AutoMath::cmp_eq(*v, value));
+        assert_eq!(result, expected, "value mismatch");
+
+        let result = generic_all_neq_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert!(result, "empty input should be `true`");
+    }
+
+    pub(crate) unsafe fn test_all_lt<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let result = generic_all_lt_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().all(|v| AutoMath::cmp_lt(*v, value));
+        assert_eq!(result, expected, "value mismatch");
+
+        let result = generic_all_lt_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert!(result, "empty input should be `true`");
+    }
+
+    pub(crate) unsafe fn test_all_lte<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let result = generic_all_lte_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().all(|v| AutoMath::cmp_lte(*v, value));
+        assert_eq!(result, expected, "value mismatch");
+
+        let result = generic_all_lte_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert!(result, "empty input should be `true`");
+    }
+
+    pub(crate) unsafe fn test_all_gt<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let result = generic_all_gt_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().all(|v| AutoMath::cmp_gt(*v, value));
+        assert_eq!(result, expected, "value mismatch");
+
+        let result = generic_all_gt_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert!(result, "empty input should be `true`");
+    }
+
+    pub(crate) unsafe fn test_all_gte<T, R>(l1: Vec<T>, value: T)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let result = generic_all_gte_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().all(|v| AutoMath::cmp_gte(*v, value));
+        assert_eq!(result, expected, "value mismatch");
+
+        let result = generic_all_gte_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert!(result, "empty input should be `true`");
+    }
+}
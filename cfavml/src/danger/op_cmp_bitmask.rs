@@ -0,0 +1,232 @@
+//! Comparison routines producing a packed bitmask rather than a `0`/`1` mask per element.
+//!
+//! Bit `i` of the output is the result of comparing element `i`, packed least
+//! significant bit first within each byte, i.e. element `0` is bit `0` of byte `0` and
+//! element `9` is bit `1` of byte `1`. Any bits of the last byte past `dims` are zeroed.
+//!
+//! Each register of comparison results is reduced with [SimdRegister::nonzero_bitmask],
+//! i.e. a `movemask` on x86.
+
+use super::core_simd_api::SimdRegister;
+use crate::math::Math;
+
+macro_rules! define_bitmask_op {
+    ($name:ident, $desc:literal, $reg_op:ident, $scalar_op:expr) => {
+        #[inline(always)]
+        #[doc = concat!(
+            "A generic vector element-wise check of vectors `a` and `b` checking if element ",
+            "of `a` is **_", $desc, "_** element of `b`, writing the result of each check as ",
+            "a single bit of `result`.",
+        )]
+        ///
+        /// Bits are packed least significant bit first within each byte, the trailing bits
+        /// of the last byte past `dims` are zeroed.
+        ///
+        /// # Panics
+        ///
+        /// If `a` and `b` are not exactly `dims` elements in length or `result` is less
+        /// than `dims.div_ceil(8)` bytes in length.
+        ///
+        /// # Safety
+        ///
+        /// The safety requirements of `M` definition the basic math operations and
+        /// the requirements of `R` SIMD register must also be followed.
+        pub unsafe fn $name<T, R, M>(dims: usize, a: &[T], b: &[T], result: &mut [u8])
+        where
+            T: Copy,
+            R: SimdRegister<T>,
+            M: Math<T>,
+        {
+            generic_cmp_bitmask::<T, R>(dims, a, b, result, R::$reg_op, $scalar_op)
+        }
+    };
+}
+
+define_bitmask_op!(generic_cmp_eq_bitmask, "equal to", eq, M::cmp_eq);
+define_bitmask_op!(generic_cmp_neq_bitmask, "not equal to", neq, |a, b| {
+    !M::cmp_eq(a, b)
+});
+define_bitmask_op!(generic_cmp_lt_bitmask, "less than", lt, M::cmp_lt);
+define_bitmask_op!(
+    generic_cmp_lte_bitmask,
+    "less than or equal to",
+    lte,
+    M::cmp_lte
+);
+define_bitmask_op!(generic_cmp_gt_bitmask, "greater than", gt, M::cmp_gt);
+define_bitmask_op!(
+    generic_cmp_gte_bitmask,
+    "greater than or equal to",
+    gte,
+    M::cmp_gte
+);
+
+#[inline(always)]
+/// Applies the comparison a register at a time, packing the bitmask of each register
+/// into `result` and completing the tail with `scalar_op`.
+unsafe fn generic_cmp_bitmask<T, R>(
+    dims: usize,
+    a: &[T],
+    b: &[T],
+    result: &mut [u8],
+    reg_op: unsafe fn(R::Register, R::Register) -> R::Register,
+    scalar_op: fn(T, T) -> bool,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+{
+    assert_eq!(
+        a.len(),
+        dims,
+        "Buffer `a` must be exactly `dims` elements in length"
+    );
+    assert_eq!(
+        b.len(),
+        dims,
+        "Buffer `b` must be exactly `dims` elements in length"
+    );
+    assert!(
+        result.len() >= dims.div_ceil(8),
+        "Buffer `result` must be at least `dims.div_ceil(8)` bytes in length"
+    );
+
+    let a_ptr = a.as_ptr();
+    let b_ptr = b.as_ptr();
+
+    let lanes = R::elements_per_lane();
+    let offset_from = dims % lanes;
+
+    // Registers of fewer than 8 elements only fill part of a byte, so the bits are
+    // collected until a whole byte is available. The widest register has 64 elements,
+    // which along with at most 7 pending bits always fits within a `u128`.
+    let mut pending = 0u128;
+    let mut pending_bits = 0;
+    let mut byte = 0;
+
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let mask = reg_op(R::load(a_ptr.add(i)), R::load(b_ptr.add(i)));
+        pending |= (R::nonzero_bitmask(mask) as u128) << pending_bits;
+        pending_bits += lanes;
+
+        while pending_bits >= 8 {
+            *result.get_unchecked_mut(byte) = pending as u8;
+            pending >>= 8;
+            pending_bits -= 8;
+            byte += 1;
+        }
+
+        i += lanes;
+    }
+
+    while i < dims {
+        let is_set = scalar_op(*a.get_unchecked(i), *b.get_unchecked(i));
+        pending |= (is_set as u128) << pending_bits;
+        pending_bits += 1;
+
+        if pending_bits == 8 {
+            *result.get_unchecked_mut(byte) = pending as u8;
+            pending = 0;
+            pending_bits = 0;
+            byte += 1;
+        }
+
+        i += 1;
+    }
+
+    if pending_bits > 0 {
+        *result.get_unchecked_mut(byte) = pending as u8;
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+
+    type BitmaskOp<T> = (
+        &'static str,
+        unsafe fn(usize, &[T], &[T], &mut [u8]),
+        fn(T, T) -> bool,
+    );
+
+    pub(crate) unsafe fn test_cmp_bitmask<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        // Every third element of `b` matches `a`, so each predicate sees both outcomes.
+        let l2 = l1
+            .iter()
+            .zip(l2.iter())
+            .enumerate()
+            .map(|(i, (&a, &b))| if i % 3 == 0 { a } else { b })
+            .collect::<Vec<T>>();
+
+        let ops: [BitmaskOp<T>; 6] = [
+            (
+                "eq",
+                generic_cmp_eq_bitmask::<T, R, AutoMath>,
+                AutoMath::cmp_eq,
+            ),
+            ("neq", generic_cmp_neq_bitmask::<T, R, AutoMath>, |a, b| {
+                !AutoMath::cmp_eq(a, b)
+            }),
+            (
+                "lt",
+                generic_cmp_lt_bitmask::<T, R, AutoMath>,
+                AutoMath::cmp_lt,
+            ),
+            (
+                "lte",
+                generic_cmp_lte_bitmask::<T, R, AutoMath>,
+                AutoMath::cmp_lte,
+            ),
+            (
+                "gt",
+                generic_cmp_gt_bitmask::<T, R, AutoMath>,
+                AutoMath::cmp_gt,
+            ),
+            (
+                "gte",
+                generic_cmp_gte_bitmask::<T, R, AutoMath>,
+                AutoMath::cmp_gte,
+            ),
+        ];
+
+        // Lengths which are not a multiple of 8 or the register width.
+        for dims in [0, 1, 3, 7, 9, 15, 33, 67, l1.len()] {
+            let (a, b) = (&l1[..dims], &l2[..dims]);
+
+            for (name, op, scalar_op) in ops {
+                // An extra byte which must be left untouched.
+                let mut result = vec![0xAA; dims.div_ceil(8) + 1];
+                op(dims, a, b, &mut result);
+
+                for i in 0..dims {
+                    let bit = (result[i / 8] >> (i % 8)) & 1;
+                    assert_eq!(
+                        bit == 1,
+                        scalar_op(a[i], b[i]),
+                        "{name} bit mismatch at {i} with dims {dims}",
+                    );
+                }
+
+                if dims % 8 != 0 {
+                    let last = result[dims / 8];
+                    assert_eq!(
+                        last >> (dims % 8),
+                        0,
+                        "{name} trailing bits must be zero with dims {dims}",
+                    );
+                }
+                assert_eq!(
+                    result[dims.div_ceil(8)],
+                    0xAA,
+                    "{name} wrote past the end of the bitmask with dims {dims}",
+                );
+            }
+        }
+    }
+}
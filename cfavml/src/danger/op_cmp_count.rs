@@ -0,0 +1,447 @@
+//! Comparison count routines, counting the elements of a vector that satisfy a
+//! comparison against a broadcast value.
+//!
+//! The `0`/`1` masks produced by the comparison are accumulated in registers rather than
+//! written out, and only flushed into the `usize` count when a lane could overflow.
+//!
+//! [generic_count_nonzero] has no mask to accumulate, instead each register is reduced
+//! with [SimdRegister::nonzero_bitmask] and the set bits counted directly into the `usize`.
+
+use crate::danger::{DenseLane, SimdRegister};
+use crate::math::{MaskCount, Math};
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+/// The number of masks a single lane can accumulate before it may overflow
+/// the smallest supported type (`i8`).
+const MAX_LANE_COUNT: usize = i8::MAX as usize;
+/// The maximum number of elements `T` within a single register of any supported arch.
+const MAX_ELEMENTS_PER_LANE: usize = 64;
+
+#[inline(always)]
+/// A generic count of the elements of vector `a` that are **_equal to_** the broadcast `value`.
+///
+/// The comparison masks are summed in registers without being written out,
+/// an empty input returns `0`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_count_eq_value<T, R, M, B1>(a: B1, value: T) -> usize
+where
+    T: MaskCount,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_count_kernel::<T, R, M, B1>(a, value, R::eq_dense, R::eq, M::cmp_eq)
+}
+
+#[inline(always)]
+/// A generic count of the elements of vector `a` that are **_not equal to_** the broadcast `value`.
+///
+/// The comparison masks are summed in registers without being written out,
+/// an empty input returns `0`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_count_neq_value<T, R, M, B1>(a: B1, value: T) -> usize
+where
+    T: MaskCount,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_count_kernel::<T, R, M, B1>(a, value, R::neq_dense, R::neq, |a, b| {
+        !M::cmp_eq(a, b)
+    })
+}
+
+#[inline(always)]
+/// A generic count of the elements of vector `a` that are **_less than_** the broadcast `value`.
+///
+/// The comparison masks are summed in registers without being written out,
+/// an empty input returns `0`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_count_lt_value<T, R, M, B1>(a: B1, value: T) -> usize
+where
+    T: MaskCount,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_count_kernel::<T, R, M, B1>(a, value, R::lt_dense, R::lt, M::cmp_lt)
+}
+
+#[inline(always)]
+/// A generic count of the elements of vector `a` that are **_less than or equal to_** the broadcast `value`.
+///
+/// The comparison masks are summed in registers without being written out,
+/// an empty input returns `0`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_count_lte_value<T, R, M, B1>(a: B1, value: T) -> usize
+where
+    T: MaskCount,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_count_kernel::<T, R, M, B1>(a, value, R::lte_dense, R::lte, M::cmp_lte)
+}
+
+#[inline(always)]
+/// A generic count of the elements of vector `a` that are **_greater than_** the broadcast `value`.
+///
+/// The comparison masks are summed in registers without being written out,
+/// an empty input returns `0`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_count_gt_value<T, R, M, B1>(a: B1, value: T) -> usize
+where
+    T: MaskCount,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_count_kernel::<T, R, M, B1>(a, value, R::gt_dense, R::gt, M::cmp_gt)
+}
+
+#[inline(always)]
+/// A generic count of the elements of vector `a` that are **_greater than or equal to_** the broadcast `value`.
+///
+/// The comparison masks are summed in registers without being written out,
+/// an empty input returns `0`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_count_gte_value<T, R, M, B1>(a: B1, value: T) -> usize
+where
+    T: MaskCount,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_count_kernel::<T, R, M, B1>(a, value, R::gte_dense, R::gte, M::cmp_gte)
+}
+
+#[inline(always)]
+/// A generic count of the elements of vector `a` that are **_non-zero_**.
+///
+/// Each register is reduced to a bitmask of its non-zero elements, the set bits of which
+/// are counted, an empty input returns `0`.
+///
+/// For `f32` and `f64` types both `0.0` and `-0.0` are zero, `NaN` is non-zero.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_count_nonzero<T, R, M, B1>(a: B1) -> usize
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    let mut count = 0;
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        count += count_nonzero_register::<T, R>(l1.a)
+            + count_nonzero_register::<T, R>(l1.b)
+            + count_nonzero_register::<T, R>(l1.c)
+            + count_nonzero_register::<T, R>(l1.d)
+            + count_nonzero_register::<T, R>(l1.e)
+            + count_nonzero_register::<T, R>(l1.f)
+            + count_nonzero_register::<T, R>(l1.g)
+            + count_nonzero_register::<T, R>(l1.h);
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        count += count_nonzero_register::<T, R>(l1);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        if !M::cmp_eq(a.read(), M::zero()) {
+            count += 1;
+        }
+
+        i += 1;
+    }
+
+    count
+}
+
+#[inline(always)]
+/// Counts the non-zero elements of a single register.
+unsafe fn count_nonzero_register<T, R>(reg: R::Register) -> usize
+where
+    T: Copy,
+    R: SimdRegister<T>,
+{
+    R::nonzero_bitmask(reg).count_ones() as usize
+}
+
+#[allow(clippy::type_complexity)]
+#[inline(always)]
+unsafe fn generic_count_kernel<T, R, M, B1>(
+    a: B1,
+    value: T,
+    dense_kernel: unsafe fn(
+        DenseLane<R::Register>,
+        DenseLane<R::Register>,
+    ) -> DenseLane<R::Register>,
+    reg_kernel: unsafe fn(R::Register, R::Register) -> R::Register,
+    single_kernel: impl Fn(T, T) -> bool,
+) -> usize
+where
+    T: MaskCount,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    let mut count = 0;
+    let mut acc = R::zeroed();
+    let mut acc_lane_count = 0;
+
+    // Operate over dense lanes first.
+    let value_dense = R::filled_dense(value);
+    let mut i = 0;
+    while i < (len - offset_from) {
+        if acc_lane_count + DenseLane::<R::Register>::NUM_LANES > MAX_LANE_COUNT {
+            count += flush_count::<T, R, M>(acc);
+            acc = R::zeroed();
+            acc_lane_count = 0;
+        }
+
+        let l1 = a.load_dense::<R>();
+        let mask = dense_kernel(l1, value_dense);
+        acc = R::add(acc, R::sum_to_register(mask));
+        acc_lane_count += DenseLane::<R::Register>::NUM_LANES;
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let value_reg = R::filled(value);
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        if acc_lane_count + 1 > MAX_LANE_COUNT {
+            count += flush_count::<T, R, M>(acc);
+            acc = R::zeroed();
+            acc_lane_count = 0;
+        }
+
+        let l1 = a.load::<R>();
+        acc = R::add(acc, reg_kernel(l1, value_reg));
+        acc_lane_count += 1;
+
+        i += R::elements_per_lane();
+    }
+
+    count += flush_count::<T, R, M>(acc);
+
+    // Handle the remainder.
+    while i < len {
+        if single_kernel(a.read(), value) {
+            count += 1;
+        }
+
+        i += 1;
+    }
+
+    count
+}
+
+#[inline(always)]
+/// Sums the lanes of a register of accumulated masks into a count.
+///
+/// This is done in `usize` rather than via [SimdRegister::sum_to_value] as the
+/// total across the whole register can overflow the smaller types.
+unsafe fn flush_count<T, R, M>(acc: R::Register) -> usize
+where
+    T: MaskCount,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    debug_assert!(R::elements_per_lane() <= MAX_ELEMENTS_PER_LANE);
+
+    let mut lanes = [M::zero(); MAX_ELEMENTS_PER_LANE];
+    R::write(lanes.as_mut_ptr(), acc);
+
+    lanes[..R::elements_per_lane()]
+        .iter()
+        .map(|v| v.to_count())
+        .sum()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+
+    pub(crate) unsafe fn test_count_eq<T, R>(l1: Vec<T>, value: T)
+    where
+        T: MaskCount + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let count = generic_count_eq_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().filter(|v| AutoMath::cmp_eq(**v, value)).count();
+        assert_eq!(count, expected, "count mismatch");
+
+        let count = generic_count_eq_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert_eq!(count, 0, "empty input should have a count of 0");
+    }
+
+    pub(crate) unsafe fn test_count_neq<T, R>(l1: Vec<T>, value: T)
+    where
+        T: MaskCount + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let count = generic_count_neq_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().filter(|v| !AutoMath::cmp_eq(**v, value)).count();
+        assert_eq!(count, expected, "count mismatch");
+
+        let count = generic_count_neq_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert_eq!(count, 0, "empty input should have a count of 0");
+    }
+
+    pub(crate) unsafe fn test_count_lt<T, R>(l1: Vec<T>, value: T)
+    where
+        T: MaskCount + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let count = generic_count_lt_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().filter(|v| AutoMath::cmp_lt(**v, value)).count();
+        assert_eq!(count, expected, "count mismatch");
+
+        let count = generic_count_lt_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert_eq!(count, 0, "empty input should have a count of 0");
+    }
+
+    pub(crate) unsafe fn test_count_lte<T, R>(l1: Vec<T>, value: T)
+    where
+        T: MaskCount + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let count = generic_count_lte_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().filter(|v| AutoMath::cmp_lte(**v, value)).count();
+        assert_eq!(count, expected, "count mismatch");
+
+        let count = generic_count_lte_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert_eq!(count, 0, "empty input should have a count of 0");
+    }
+
+    pub(crate) unsafe fn test_count_gt<T, R>(l1: Vec<T>, value: T)
+    where
+        T: MaskCount + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let count = generic_count_gt_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().filter(|v| AutoMath::cmp_gt(**v, value)).count();
+        assert_eq!(count, expected, "count mismatch");
+
+        let count = generic_count_gt_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert_eq!(count, 0, "empty input should have a count of 0");
+    }
+
+    pub(crate) unsafe fn test_count_gte<T, R>(l1: Vec<T>, value: T)
+    where
+        T: MaskCount + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let count = generic_count_gte_value::<T, R, AutoMath, _>(&l1, value);
+        let expected = l1.iter().filter(|v| AutoMath::cmp_gte(**v, value)).count();
+        assert_eq!(count, expected, "count mismatch");
+
+        let count = generic_count_gte_value::<T, R, AutoMath, _>(&[] as &[T], value);
+        assert_eq!(count, 0, "empty input should have a count of 0");
+    }
+
+    pub(crate) unsafe fn test_count_nonzero<T, R>(l1: Vec<T>)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let count = generic_count_nonzero::<T, R, AutoMath, _>(&l1);
+        let expected = l1
+            .iter()
+            .filter(|v| !AutoMath::cmp_eq(**v, AutoMath::zero()))
+            .count();
+        assert_eq!(count, expected, "count mismatch");
+
+        let count = generic_count_nonzero::<T, R, AutoMath, _>(&[] as &[T]);
+        assert_eq!(count, 0, "empty input should have a count of 0");
+    }
+
+    /// Checks the counts of an input just over a multiple of the dense lane width
+    /// where the only matching element falls in the scalar remainder.
+    pub(crate) unsafe fn test_count_tail_only<T, R>()
+    where
+        T: MaskCount + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let mut l1 = vec![AutoMath::zero(); R::elements_per_dense() * 3 + 1];
+        *l1.last_mut().unwrap() = AutoMath::one();
+
+        let count = generic_count_nonzero::<T, R, AutoMath, _>(&l1);
+        assert_eq!(count, 1, "nonzero count should only include the tail");
+        let count = generic_count_gt_value::<T, R, AutoMath, _>(&l1, AutoMath::zero());
+        assert_eq!(count, 1, "gt count should only include the tail");
+        let count = generic_count_eq_value::<T, R, AutoMath, _>(&l1, AutoMath::one());
+        assert_eq!(count, 1, "eq count should only include the tail");
+        let count = generic_count_lt_value::<T, R, AutoMath, _>(&l1, AutoMath::zero());
+        assert_eq!(count, 0, "lt count should be 0");
+    }
+}
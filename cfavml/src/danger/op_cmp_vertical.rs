@@ -214,6 +214,162 @@ pub unsafe fn generic_cmp_gte_vertical<T, R, M, B1, B2, B3>(
     )
 }
 
+#[inline(always)]
+/// A generic vector element-wise check of vector `a` against a broadcast `value` checking
+/// if element of `a` is **_equal to_** `value`.
+///
+/// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
+///
+/// This is equivalent to calling [generic_cmp_eq_vertical] with `value` as the broadcast input.
+///
+/// # Safety
+///
+/// The sizes of `a` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_cmp_eq_value<T, R, M, B1, B2>(a: B1, value: T, result: &mut [B2])
+where
+    T: Copy + IntoMemLoader<T>,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    generic_cmp_eq_vertical::<T, R, M, B1, T, B2>(a, value, result)
+}
+
+#[inline(always)]
+/// A generic vector element-wise check of vector `a` against a broadcast `value` checking
+/// if element of `a` is **_not equal to_** `value`.
+///
+/// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
+///
+/// This is equivalent to calling [generic_cmp_neq_vertical] with `value` as the broadcast input.
+///
+/// # Safety
+///
+/// The sizes of `a` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_cmp_neq_value<T, R, M, B1, B2>(a: B1, value: T, result: &mut [B2])
+where
+    T: Copy + IntoMemLoader<T>,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    generic_cmp_neq_vertical::<T, R, M, B1, T, B2>(a, value, result)
+}
+
+#[inline(always)]
+/// A generic vector element-wise check of vector `a` against a broadcast `value` checking
+/// if element of `a` is **_less than_** `value`.
+///
+/// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
+///
+/// This is equivalent to calling [generic_cmp_lt_vertical] with `value` as the broadcast input.
+///
+/// # Safety
+///
+/// The sizes of `a` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_cmp_lt_value<T, R, M, B1, B2>(a: B1, value: T, result: &mut [B2])
+where
+    T: Copy + IntoMemLoader<T>,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    generic_cmp_lt_vertical::<T, R, M, B1, T, B2>(a, value, result)
+}
+
+#[inline(always)]
+/// A generic vector element-wise check of vector `a` against a broadcast `value` checking
+/// if element of `a` is **_less than or equal to_** `value`.
+///
+/// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
+///
+/// This is equivalent to calling [generic_cmp_lte_vertical] with `value` as the broadcast input.
+///
+/// # Safety
+///
+/// The sizes of `a` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_cmp_lte_value<T, R, M, B1, B2>(a: B1, value: T, result: &mut [B2])
+where
+    T: Copy + IntoMemLoader<T>,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    generic_cmp_lte_vertical::<T, R, M, B1, T, B2>(a, value, result)
+}
+
+#[inline(always)]
+/// A generic vector element-wise check of vector `a` against a broadcast `value` checking
+/// if element of `a` is **_greater than_** `value`.
+///
+/// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
+///
+/// This is equivalent to calling [generic_cmp_gt_vertical] with `value` as the broadcast input.
+///
+/// # Safety
+///
+/// The sizes of `a` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_cmp_gt_value<T, R, M, B1, B2>(a: B1, value: T, result: &mut [B2])
+where
+    T: Copy + IntoMemLoader<T>,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    generic_cmp_gt_vertical::<T, R, M, B1, T, B2>(a, value, result)
+}
+
+#[inline(always)]
+/// A generic vector element-wise check of vector `a` against a broadcast `value` checking
+/// if element of `a` is **_greater than or equal to_** `value`.
+///
+/// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
+///
+/// This is equivalent to calling [generic_cmp_gte_vertical] with `value` as the broadcast input.
+///
+/// # Safety
+///
+/// The sizes of `a` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_cmp_gte_value<T, R, M, B1, B2>(a: B1, value: T, result: &mut [B2])
+where
+    T: Copy + IntoMemLoader<T>,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    generic_cmp_gte_vertical::<T, R, M, B1, T, B2>(a, value, result)
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use std::iter::zip;
@@ -356,6 +512,10 @@ pub(crate) mod tests {
         let mut result = vec![AutoMath::zero(); dims];
         generic_cmp_eq_vertical::<T, R, AutoMath, _, _, _>(&l1, value, &mut result);
 
+        let mut value_result = vec![AutoMath::zero(); dims];
+        generic_cmp_eq_value::<T, R, AutoMath, _, _>(&l1, value, &mut value_result);
+        assert_eq!(result, value_result, "value routine mismatch");
+
         let mut expected_result = Vec::new();
         for a in l1 {
             expected_result.push(AutoMath::cast_bool(AutoMath::cmp_eq(a, value)));
@@ -376,6 +536,10 @@ pub(crate) mod tests {
         let mut result = vec![AutoMath::zero(); dims];
         generic_cmp_neq_vertical::<T, R, AutoMath, _, _, _>(&l1, value, &mut result);
 
+        let mut value_result = vec![AutoMath::zero(); dims];
+        generic_cmp_neq_value::<T, R, AutoMath, _, _>(&l1, value, &mut value_result);
+        assert_eq!(result, value_result, "value routine mismatch");
+
         let mut expected_result = Vec::new();
         for a in l1 {
             expected_result.push(AutoMath::cast_bool(!AutoMath::cmp_eq(a, value)));
@@ -396,6 +560,10 @@ pub(crate) mod tests {
         let mut result = vec![AutoMath::zero(); dims];
         generic_cmp_lt_vertical::<T, R, AutoMath, _, _, _>(&l1, value, &mut result);
 
+        let mut value_result = vec![AutoMath::zero(); dims];
+        generic_cmp_lt_value::<T, R, AutoMath, _, _>(&l1, value, &mut value_result);
+        assert_eq!(result, value_result, "value routine mismatch");
+
         let mut expected_result = Vec::new();
         for a in l1 {
             expected_result.push(AutoMath::cast_bool(AutoMath::cmp_lt(a, value)));
@@ -416,6 +584,10 @@ pub(crate) mod tests {
         let mut result = vec![AutoMath::zero(); dims];
         generic_cmp_lte_vertical::<T, R, AutoMath, _, _, _>(&l1, value, &mut result);
 
+        let mut value_result = vec![AutoMath::zero(); dims];
+        generic_cmp_lte_value::<T, R, AutoMath, _, _>(&l1, value, &mut value_result);
+        assert_eq!(result, value_result, "value routine mismatch");
+
         let mut expected_result = Vec::new();
         for a in l1 {
             expected_result.push(AutoMath::cast_bool(AutoMath::cmp_lte(a, value)));
@@ -436,6 +608,10 @@ pub(crate) mod tests {
         let mut result = vec![AutoMath::zero(); dims];
         generic_cmp_gt_vertical::<T, R, AutoMath, _, _, _>(&l1, value, &mut result);
 
+        let mut value_result = vec![AutoMath::zero(); dims];
+        generic_cmp_gt_value::<T, R, AutoMath, _, _>(&l1, value, &mut value_result);
+        assert_eq!(result, value_result, "value routine mismatch");
+
         let mut expected_result = Vec::new();
         for a in l1 {
             expected_result.push(AutoMath::cast_bool(AutoMath::cmp_gt(a, value)));
@@ -456,6 +632,10 @@ pub(crate) mod tests {
         let mut result = vec![AutoMath::zero(); dims];
         generic_cmp_gte_vertical::<T, R, AutoMath, _, _, _>(&l1, value, &mut result);
 
+        let mut value_result = vec![AutoMath::zero(); dims];
+        generic_cmp_gte_value::<T, R, AutoMath, _, _>(&l1, value, &mut value_result);
+        assert_eq!(result, value_result, "value routine mismatch");
+
         let mut expected_result = Vec::new();
         for a in l1 {
             expected_result.push(AutoMath::cast_bool(AutoMath::cmp_gte(a, value)));
@@ -0,0 +1,262 @@
+//! Compaction routines selecting the elements of a vector where a mask is non-zero.
+//!
+//! Each register of the mask is reduced to a bitmask with [SimdRegister::nonzero_bitmask],
+//! i.e. a `movemask` on x86, allowing registers without any selected elements to be
+//! skipped and registers where every element is selected to be written whole.
+//!
+//! The selected elements, or indices, of every other register are written with
+//! [SimdRegister::compress_store] and [SimdRegister::compress_indices], on AVX2 this is
+//! a permute or shuffle using indices looked up from the bitmask rather than one write
+//! per selected element.
+
+use super::core_simd_api::SimdRegister;
+use crate::buffer::WriteOnlyBuffer;
+use crate::math::Math;
+
+#[inline(always)]
+/// A generic compaction implementation, writing each element of `values` where the
+/// corresponding element of `mask` is non-zero to the start of `result`.
+///
+/// The selected elements keep their order within `values` and the number of
+/// elements written is returned, elements of `result` past this count may be
+/// overwritten.
+///
+/// # Panics
+///
+/// If `mask` and `values` are not exactly `dims` elements in length or `result` is
+/// less than `dims` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_compact<T, R, M, B>(
+    dims: usize,
+    mask: &[T],
+    values: &[T],
+    mut result: &mut [B],
+) -> usize
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    assert_eq!(
+        mask.len(),
+        dims,
+        "Buffer `mask` must be exactly `dims` elements in length"
+    );
+    assert_eq!(
+        values.len(),
+        dims,
+        "Buffer `values` must be exactly `dims` elements in length"
+    );
+    assert!(
+        result.raw_buffer_len() >= dims,
+        "Buffer `result` must be at least `dims` elements in length"
+    );
+
+    let mask_ptr = mask.as_ptr();
+    let values_ptr = values.as_ptr();
+    let result_ptr = result.as_write_only_ptr();
+
+    let all_selected = all_bits::<T, R>();
+    let offset_from = dims % R::elements_per_lane();
+
+    let mut count = 0;
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let bits = R::nonzero_bitmask(R::load(mask_ptr.add(i)));
+
+        if bits == all_selected {
+            R::write(result_ptr.add(count), R::load(values_ptr.add(i)));
+            count += R::elements_per_lane();
+        } else if bits != 0 {
+            let values = R::load(values_ptr.add(i));
+            count += R::compress_store(result_ptr.add(count), values, bits);
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    while i < dims {
+        if !M::cmp_eq(*mask.get_unchecked(i), M::zero()) {
+            result.write_at(count, *values.get_unchecked(i));
+            count += 1;
+        }
+
+        i += 1;
+    }
+
+    count
+}
+
+#[inline(always)]
+/// A generic mask to indices implementation, writing the index of each non-zero
+/// element of `mask` to the start of `result` in ascending order.
+///
+/// The number of indices written is returned, elements of `result` past this
+/// count may be overwritten.
+///
+/// # Panics
+///
+/// If `mask` is not exactly `dims` elements in length, `result` is less than `dims`
+/// elements in length or `dims` is larger than `u32::MAX + 1`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_mask_to_indices<T, R, M, B>(
+    dims: usize,
+    mask: &[T],
+    mut result: &mut [B],
+) -> usize
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = u32>,
+{
+    assert_eq!(
+        mask.len(),
+        dims,
+        "Buffer `mask` must be exactly `dims` elements in length"
+    );
+    assert!(
+        result.raw_buffer_len() >= dims,
+        "Buffer `result` must be at least `dims` elements in length"
+    );
+    assert!(
+        dims as u64 <= u32::MAX as u64 + 1,
+        "Buffer `mask` must be indexable by `u32`"
+    );
+
+    let mask_ptr = mask.as_ptr();
+    let result_ptr = result.as_write_only_ptr();
+
+    let offset_from = dims % R::elements_per_lane();
+
+    let mut count = 0;
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let bits = R::nonzero_bitmask(R::load(mask_ptr.add(i)));
+        if bits != 0 {
+            count += R::compress_indices(result_ptr.add(count), i, bits);
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    while i < dims {
+        if !M::cmp_eq(*mask.get_unchecked(i), M::zero()) {
+            result.write_at(count, i as u32);
+            count += 1;
+        }
+
+        i += 1;
+    }
+
+    count
+}
+
+#[inline(always)]
+/// Returns the bitmask of a register where every element is selected.
+fn all_bits<T: Copy, R: SimdRegister<T>>() -> u64 {
+    u64::MAX >> (64 - R::elements_per_lane())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+
+    /// Masks selecting none, all, every third, an irregular pattern and a single element
+    /// of `dims` elements.
+    fn sample_masks<T>(dims: usize) -> Vec<Vec<T>>
+    where
+        T: Copy,
+        AutoMath: Math<T>,
+    {
+        let none = vec![AutoMath::zero(); dims];
+        let all = vec![AutoMath::one(); dims];
+        let sparse = (0..dims)
+            .map(|i| {
+                if i % 3 == 1 {
+                    AutoMath::one()
+                } else {
+                    AutoMath::zero()
+                }
+            })
+            .collect();
+        let irregular = (0..dims)
+            .map(|i| {
+                if (i * i + i / 3) % 5 < 2 {
+                    AutoMath::one()
+                } else {
+                    AutoMath::zero()
+                }
+            })
+            .collect();
+        let mut single = none.clone();
+        if let Some(last) = single.last_mut() {
+            *last = AutoMath::one();
+        }
+        vec![none, all, sparse, irregular, single]
+    }
+
+    pub(crate) unsafe fn test_compact<T, R>(l1: Vec<T>)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        for dims in [0, 1, 7, 67, l1.len()] {
+            let values = &l1[..dims];
+
+            for mask in sample_masks::<T>(dims) {
+                let mut result = vec![AutoMath::zero(); dims];
+                let count = generic_compact::<T, R, AutoMath, _>(
+                    dims,
+                    &mask,
+                    values,
+                    &mut result,
+                );
+
+                let expected = mask
+                    .iter()
+                    .zip(values)
+                    .filter(|(&m, _)| !AutoMath::cmp_eq(m, AutoMath::zero()))
+                    .map(|(_, &v)| v)
+                    .collect::<Vec<T>>();
+                assert_eq!(count, expected.len(), "count mismatch with dims {dims}");
+                assert_eq!(result[..count], expected, "value mismatch with dims {dims}");
+            }
+        }
+    }
+
+    pub(crate) unsafe fn test_mask_to_indices<T, R>(l1: Vec<T>)
+    where
+        T: Copy,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        for dims in [0, 1, 7, 67, l1.len()] {
+            for mask in sample_masks::<T>(dims) {
+                let mut result = vec![0; dims];
+                let count = generic_mask_to_indices::<T, R, AutoMath, _>(
+                    dims,
+                    &mask,
+                    &mut result,
+                );
+
+                let expected = (0..dims as u32)
+                    .filter(|&i| !AutoMath::cmp_eq(mask[i as usize], AutoMath::zero()))
+                    .collect::<Vec<u32>>();
+                assert_eq!(result[..count], expected, "index mismatch with dims {dims}");
+            }
+        }
+    }
+}
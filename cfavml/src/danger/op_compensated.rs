@@ -0,0 +1,418 @@
+//! Compensated summation routines, tracking the rounding error of each addition so it
+//! can be re-applied to the final result.
+//!
+//! Each accumulator lane keeps a running sum alongside a first and second order
+//! compensation, each addition being split into the rounded sum and its exact error
+//! using the branch free `TwoSum` transformation:
+//!
+//! ```ignore
+//! sum = a + b
+//! b_virtual = sum - a
+//! err = (a - (sum - b_virtual)) + (b - b_virtual)
+//! ```
+//!
+//! The error of each addition to the sum is added to the first order compensation, and
+//! the error of _that_ addition is added to the second order compensation. The two
+//! compensation terms are then renormalized so the second order term only holds what
+//! the first cannot represent, otherwise the rounding of the second order term itself
+//! builds up over millions of additions.
+//!
+//! This gives a result which is typically as accurate as accumulating in twice the
+//! precision, even when large values cancel one another out, which is where
+//! [generic_kahan_sum] breaks down.
+//!
+//! NOTE:
+//! The compensation relies on floating point addition _not_ being associative, math
+//! implementations which allow the compiler to re-associate operations, i.e. `FastMath`,
+//! may optimize the compensation away entirely.
+//!
+//! [generic_kahan_sum]: crate::danger::generic_kahan_sum
+
+use crate::danger::core_simd_api::{DenseLane, SimdRegister};
+use crate::math::Math;
+
+#[derive(Copy, Clone)]
+/// A running sum with its first and second order compensation.
+struct Compensated<T> {
+    sum: T,
+    comp: T,
+    comp2: T,
+}
+
+impl<T: Copy> Compensated<T> {
+    #[inline(always)]
+    fn new(zero: T) -> Self {
+        Self {
+            sum: zero,
+            comp: zero,
+            comp2: zero,
+        }
+    }
+}
+
+#[inline(always)]
+/// A generic horizontal sum implementation using second order compensated summation,
+/// this is slower than [generic_sum](crate::danger::generic_sum) but accumulates
+/// significantly less floating point error on large or badly conditioned vectors.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must be followed.
+pub unsafe fn generic_sum_compensated<T, R, M>(a: &[T]) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    compensated_reduce::<T, R, M>(
+        a.len(),
+        a.as_ptr(),
+        a.as_ptr(),
+        |l1, _| l1,
+        |l1, _| l1,
+        |a, _| a,
+    )
+}
+
+#[inline(always)]
+/// A generic dot product implementation using second order compensated summation to
+/// accumulate the products, this is slower than
+/// [generic_dot](crate::danger::generic_dot) but accumulates significantly less floating
+/// point error on large or badly conditioned vectors.
+///
+/// Only the accumulation is compensated, the rounding of each individual product
+/// is not tracked.
+///
+/// # Panics
+///
+/// If vectors `a` and `b` are not equal in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must be followed.
+pub unsafe fn generic_dot_compensated<T, R, M>(a: &[T], b: &[T]) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+    compensated_reduce::<T, R, M>(
+        a.len(),
+        a.as_ptr(),
+        b.as_ptr(),
+        |l1, l2| R::mul_dense(l1, l2),
+        |l1, l2| R::mul(l1, l2),
+        |a, b| M::mul(a, b),
+    )
+}
+
+#[inline(always)]
+/// Accumulates `term(a[i], b[i])` for each element with second order compensation.
+///
+/// `dense_term`, `reg_term` and `value_term` must perform the same operation on a dense
+/// lane, a register and a single value respectively.
+unsafe fn compensated_reduce<T, R, M>(
+    len: usize,
+    a: *const T,
+    b: *const T,
+    dense_term: impl Fn(
+        DenseLane<R::Register>,
+        DenseLane<R::Register>,
+    ) -> DenseLane<R::Register>,
+    reg_term: impl Fn(R::Register, R::Register) -> R::Register,
+    value_term: impl Fn(T, T) -> T,
+) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let offset_from = len % R::elements_per_dense();
+
+    let mut acc = DenseLane::copy(Compensated::new(R::zeroed()));
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_dense(a.add(i));
+        let l2 = R::load_dense(b.add(i));
+        let terms = dense_term(l1, l2);
+        acc.a = compensated_add_register::<T, R>(acc.a, terms.a);
+        acc.b = compensated_add_register::<T, R>(acc.b, terms.b);
+        acc.c = compensated_add_register::<T, R>(acc.c, terms.c);
+        acc.d = compensated_add_register::<T, R>(acc.d, terms.d);
+        acc.e = compensated_add_register::<T, R>(acc.e, terms.e);
+        acc.f = compensated_add_register::<T, R>(acc.f, terms.f);
+        acc.g = compensated_add_register::<T, R>(acc.g, terms.g);
+        acc.h = compensated_add_register::<T, R>(acc.h, terms.h);
+
+        i += R::elements_per_dense();
+    }
+
+    // Merge the dense lanes, carrying over the compensation of each lane.
+    let mut total = acc.a;
+    for lane in [acc.b, acc.c, acc.d, acc.e, acc.f, acc.g, acc.h] {
+        total = compensated_add_register::<T, R>(total, lane.sum);
+        total = compensated_add_register::<T, R>(total, lane.comp);
+        total = compensated_add_register::<T, R>(total, lane.comp2);
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load(a.add(i));
+        let l2 = R::load(b.add(i));
+        total = compensated_add_register::<T, R>(total, reg_term(l1, l2));
+
+        i += R::elements_per_lane();
+    }
+
+    // Fold each element of the registers into a single compensated value.
+    let mut lanes = [M::zero(); 64];
+    let mut value = Compensated::new(M::zero());
+    for register in [total.sum, total.comp, total.comp2] {
+        R::write(lanes.as_mut_ptr(), register);
+        for lane in &lanes[..R::elements_per_lane()] {
+            value = compensated_add_value::<T, M>(value, *lane);
+        }
+    }
+
+    // Handle the remainder.
+    while i < len {
+        let term = value_term(a.add(i).read(), b.add(i).read());
+        value = compensated_add_value::<T, M>(value, term);
+
+        i += 1;
+    }
+
+    M::add(value.sum, M::add(value.comp, value.comp2))
+}
+
+#[inline(always)]
+/// Adds `value` to the compensated register `acc`.
+unsafe fn compensated_add_register<T, R>(
+    acc: Compensated<R::Register>,
+    value: R::Register,
+) -> Compensated<R::Register>
+where
+    T: Copy,
+    R: SimdRegister<T>,
+{
+    let (sum, err) = two_sum_register::<T, R>(acc.sum, value);
+    let (comp, err) = two_sum_register::<T, R>(acc.comp, err);
+    let (comp, comp2) = two_sum_register::<T, R>(comp, R::add(acc.comp2, err));
+    Compensated { sum, comp, comp2 }
+}
+
+#[inline(always)]
+/// Returns the rounded sum of `a` and `b` along with the exact error of the rounding.
+unsafe fn two_sum_register<T, R>(
+    a: R::Register,
+    b: R::Register,
+) -> (R::Register, R::Register)
+where
+    T: Copy,
+    R: SimdRegister<T>,
+{
+    let sum = R::add(a, b);
+    let b_virtual = R::sub(sum, a);
+    let a_err = R::sub(a, R::sub(sum, b_virtual));
+    let b_err = R::sub(b, b_virtual);
+    (sum, R::add(a_err, b_err))
+}
+
+#[inline(always)]
+/// Adds `value` to the compensated value `acc`.
+fn compensated_add_value<T, M>(acc: Compensated<T>, value: T) -> Compensated<T>
+where
+    T: Copy,
+    M: Math<T>,
+{
+    let (sum, err) = two_sum_value::<T, M>(acc.sum, value);
+    let (comp, err) = two_sum_value::<T, M>(acc.comp, err);
+    let (comp, comp2) = two_sum_value::<T, M>(comp, M::add(acc.comp2, err));
+    Compensated { sum, comp, comp2 }
+}
+
+#[inline(always)]
+/// Returns the rounded sum of `a` and `b` along with the exact error of the rounding.
+fn two_sum_value<T, M>(a: T, b: T) -> (T, T)
+where
+    T: Copy,
+    M: Math<T>,
+{
+    let sum = M::add(a, b);
+    let b_virtual = M::sub(sum, a);
+    let a_err = M::sub(a, M::sub(sum, b_virtual));
+    let b_err = M::sub(b, b_virtual);
+    (sum, M::add(a_err, b_err))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+    use crate::danger::generic_sum;
+    use crate::math::AutoMath;
+
+    /// Sequentially accumulates the values with the scalar compensated addition.
+    fn reference_reduce<T>(values: impl Iterator<Item = T>) -> T
+    where
+        T: Copy,
+        AutoMath: Math<T>,
+    {
+        let value = values.fold(Compensated::new(AutoMath::zero()), |acc, v| {
+            compensated_add_value::<T, AutoMath>(acc, v)
+        });
+        AutoMath::add(value.sum, AutoMath::add(value.comp, value.comp2))
+    }
+
+    pub(crate) unsafe fn test_compensated<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy + Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let value = generic_dot_compensated::<T, R, AutoMath>(&l1, &l2);
+        let products = l1.iter().zip(&l2).map(|(a, b)| AutoMath::mul(*a, *b));
+        let expected = reference_reduce(products);
+        assert!(
+            AutoMath::is_close(value, expected),
+            "dot value missmatch {value:?} vs {expected:?}"
+        );
+
+        let value = generic_sum_compensated::<T, R, AutoMath>(&l1);
+        let expected = reference_reduce(l1.iter().copied());
+        assert!(
+            AutoMath::is_close(value, expected),
+            "sum value missmatch {value:?} vs {expected:?}"
+        );
+    }
+
+    /// Sums blocks of `big`, `1`, `-big`, `1` where each block is wide enough for
+    /// every lane of the widest register, so every `1` following a `big` is lost to
+    /// rounding when accumulated without compensation.
+    pub(crate) unsafe fn test_compensated_cancellation<T, R>(big: T)
+    where
+        T: Copy + Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let one = AutoMath::one();
+        let neg_big = AutoMath::sub(AutoMath::zero(), big);
+
+        let mut values = Vec::new();
+        let mut expected = AutoMath::zero();
+        for _ in 0..64 {
+            for value in [big, one, neg_big, one] {
+                values.extend([value; 128]);
+            }
+            for _ in 0..256 {
+                expected = AutoMath::add(expected, one);
+            }
+        }
+        // Exercise the single register and remainder loops too.
+        for _ in 0..43 {
+            values.push(one);
+            expected = AutoMath::add(expected, one);
+        }
+
+        let value = generic_sum_compensated::<T, R, AutoMath>(&values);
+        assert!(
+            AutoMath::cmp_eq(value, expected),
+            "sum value missmatch {value:?} vs {expected:?}"
+        );
+
+        let twos = vec![AutoMath::add(one, one); values.len()];
+        let value = generic_dot_compensated::<T, R, AutoMath>(&values, &twos);
+        let expected = AutoMath::add(expected, expected);
+        assert!(
+            AutoMath::cmp_eq(value, expected),
+            "dot value missmatch {value:?} vs {expected:?}"
+        );
+    }
+
+    /// Small values interleaved with large cancellation pairs, every small value
+    /// is lost entirely when naively added to a lane holding the large value.
+    ///
+    /// Each run is wide enough to cover every lane of the widest dense register.
+    fn get_cancellation_vector() -> (Vec<f32>, f64) {
+        let num_small = if cfg!(miri) { 4_096 } else { 10_000_000 };
+
+        let mut values = Vec::with_capacity(num_small + num_small / 4);
+        let mut count = 0;
+        while count < num_small {
+            values.extend([1.0e7f32; 128]);
+            values.extend([1.0e-7f32; 1024]);
+            values.extend([-1.0e7f32; 128]);
+            count += 1024;
+        }
+
+        // The large pairs cancel exactly, so the reference is the `f64` sum of the
+        // small values, adding them to a running total of `1e7` would lose precision
+        // even in `f64`.
+        let expected = values
+            .iter()
+            .filter(|v| v.abs() < 1.0)
+            .map(|v| *v as f64)
+            .sum::<f64>();
+        (values, expected)
+    }
+
+    unsafe fn check_cancellation_accuracy<R: SimdRegister<f32>>() {
+        let (values, expected) = get_cancellation_vector();
+
+        let naive = generic_sum::<f32, R, AutoMath, _>(&values) as f64;
+        let value = generic_sum_compensated::<f32, R, AutoMath>(&values) as f64;
+
+        // Within a few `f32` ulps of the `f64` ground truth.
+        let ulp = (expected as f32).to_bits() + 1;
+        let ulp = (f32::from_bits(ulp) as f64) - (expected as f32) as f64;
+        let error = (value - expected).abs();
+        assert!(
+            error <= 4.0 * ulp,
+            "compensated error {error} is larger than 4 ulps {ulp} ({value} vs {expected})"
+        );
+
+        let naive_error = (naive - expected).abs();
+        assert!(
+            naive_error > 1000.0 * ulp,
+            "naive error {naive_error} should be orders of magnitude off ({naive} vs {expected})"
+        );
+    }
+
+    #[test]
+    fn test_fallback_compensated_cancellation_accuracy() {
+        unsafe { check_cancellation_accuracy::<crate::danger::Fallback>() }
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    #[test]
+    fn test_avx2_compensated_cancellation_accuracy() {
+        unsafe { check_cancellation_accuracy::<crate::danger::Avx2>() }
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx512f",
+        feature = "stable-avx512"
+    ))]
+    #[test]
+    fn test_avx512_compensated_cancellation_accuracy() {
+        unsafe { check_cancellation_accuracy::<crate::danger::Avx512>() }
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    #[test]
+    fn test_neon_compensated_cancellation_accuracy() {
+        unsafe { check_cancellation_accuracy::<crate::danger::Neon>() }
+    }
+}
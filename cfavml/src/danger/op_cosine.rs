@@ -1,5 +1,5 @@
-use crate::danger::core_simd_api::SimdRegister;
-use crate::math::Math;
+use crate::danger::core_simd_api::{SimdRegister, SimdWidenRegister};
+use crate::math::{CastAs, Math};
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 
 #[inline(always)]
@@ -71,6 +71,75 @@ where
     cosine::<T, M>(dot, norm_a, norm_b)
 }
 
+#[inline(always)]
+/// A generic cosine implementation over two integer vectors with each element widened
+/// and accumulated as the wider integer type `T`, i.e. `u8` vectors accumulated as
+/// `u32` values.
+///
+/// The dot product and norms are converted to `f64` once accumulated and the distance
+/// is returned as an `f32`, as the cosine distance is inherently a float value.
+///
+/// The products are computed after widening so they never overflow, but the
+/// accumulation wraps on overflow of `T`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of the `R` SIMD register must be followed.
+///
+/// # Panics
+///
+/// If the lengths of `a` and `b` do not match.
+pub unsafe fn generic_cosine_wide_acc<F, T, R, M>(a: &[F], b: &[F]) -> f32
+where
+    F: CastAs<T>,
+    T: CastAs<f64>,
+    R: SimdWidenRegister<F, T>,
+    M: Math<T> + Math<f64>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+    let len = a.len();
+    let a = a.as_ptr();
+    let b = b.as_ptr();
+
+    let offset_from = len % R::elements_per_lane();
+
+    let mut norm_a = R::zeroed();
+    let mut norm_b = R::zeroed();
+    let mut dot = R::zeroed();
+
+    // Like `generic_cosine` this operates over single registers only.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_widened(a.add(i));
+        let l2 = R::load_widened(b.add(i));
+
+        norm_a = R::fmadd(l1, l1, norm_a);
+        norm_b = R::fmadd(l2, l2, norm_b);
+        dot = R::fmadd(l1, l2, dot);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut norm_a = R::sum_to_value(norm_a);
+    let mut norm_b = R::sum_to_value(norm_b);
+    let mut dot = R::sum_to_value(dot);
+
+    while i < len {
+        let a: T = a.add(i).read().cast_as();
+        let b: T = b.add(i).read().cast_as();
+        norm_a = M::add(norm_a, M::mul(a, a));
+        norm_b = M::add(norm_b, M::mul(b, b));
+        dot = M::add(dot, M::mul(a, b));
+
+        i += 1;
+    }
+
+    cosine::<f64, M>(dot.cast_as(), norm_a.cast_as(), norm_b.cast_as()) as f32
+}
+
 #[inline(always)]
 pub(crate) fn cosine<T: Copy, M: Math<T>>(dot_product: T, norm_x: T, norm_y: T) -> T {
     if M::cmp_eq(norm_x, M::zero()) && M::cmp_eq(norm_y, M::zero()) {
@@ -101,3 +170,33 @@ where
         "value missmatch {value:?} vs {expected_value:?}"
     );
 }
+
+#[cfg(test)]
+pub(crate) unsafe fn test_cosine_wide_acc<F, T, R>(l1: Vec<F>, l2: Vec<F>)
+where
+    F: CastAs<T> + CastAs<f32> + Default,
+    T: CastAs<f64>,
+    R: SimdWidenRegister<F, T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let value = generic_cosine_wide_acc::<F, T, R, AutoMath>(&l1, &l2);
+    let l1_f32 = l1
+        .iter()
+        .map(|v| CastAs::<f32>::cast_as(*v))
+        .collect::<Vec<f32>>();
+    let l2_f32 = l2
+        .iter()
+        .map(|v| CastAs::<f32>::cast_as(*v))
+        .collect::<Vec<f32>>();
+    let expected_value = crate::test_utils::simple_cosine::<f32>(&l1_f32, &l2_f32);
+    assert!(
+        <AutoMath as Math<f32>>::is_close(value, expected_value),
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+
+    let zeroes = vec![F::default(); l1.len()];
+    let value = generic_cosine_wide_acc::<F, T, R, AutoMath>(&zeroes, &zeroes);
+    assert_eq!(value, 0.0, "zero vectors should have no distance");
+}
@@ -0,0 +1,439 @@
+//! All-pairs distance routines computing the full `M x N` distance matrix between
+//! two contiguous sets of vectors.
+//!
+//! Each routine walks the output in tiles of [A_ROWS_PER_TILE] x [B_ROWS_PER_TILE],
+//! keeping one accumulator register per output element of the tile so each load of
+//! a row of `a` is reused against a block of rows of `b` and vice versa.
+
+use crate::danger::core_simd_api::SimdRegister;
+use crate::math::Math;
+
+/// The number of rows of `a` processed at once.
+pub(super) const A_ROWS_PER_TILE: usize = 2;
+/// The number of rows of `b` processed at once.
+pub(super) const B_ROWS_PER_TILE: usize = 4;
+
+#[inline(always)]
+/// A generic all-pairs dot product between each of the `a_rows` vectors in `a` and each
+/// of the `b_rows` vectors in `b`, each being `dims` elements in length.
+///
+/// The dot product of the `i`th vector of `a` and `j`th vector of `b` is written to
+/// `result[i * b_rows + j]`, producing a row-major `a_rows x b_rows` matrix.
+///
+/// # Panics
+///
+/// If `a` is not exactly `dims * a_rows` elements in length, `b` is not exactly
+/// `dims * b_rows` elements in length or `result` is not exactly `a_rows * b_rows`
+/// elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_matrix<T, R, M>(
+    dims: usize,
+    a_rows: usize,
+    b_rows: usize,
+    a: &[T],
+    b: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_matrix_len(dims, a_rows, b_rows, a.len(), b.len(), result.len());
+
+    let a_ptr = a.as_ptr();
+    let b_ptr = b.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let mut i = 0;
+    while i + A_ROWS_PER_TILE <= a_rows {
+        dot_row_block::<T, R, M, A_ROWS_PER_TILE>(
+            dims, b_rows, i, a_ptr, b_ptr, result_ptr,
+        );
+        i += A_ROWS_PER_TILE;
+    }
+
+    while i < a_rows {
+        dot_row_block::<T, R, M, 1>(dims, b_rows, i, a_ptr, b_ptr, result_ptr);
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic all-pairs squared Euclidean distance between each of the `a_rows` vectors
+/// in `a` and each of the `b_rows` vectors in `b`, each being `dims` elements in length.
+///
+/// The distance between the `i`th vector of `a` and `j`th vector of `b` is written to
+/// `result[i * b_rows + j]`, producing a row-major `a_rows x b_rows` matrix.
+///
+/// The distance is computed via the `‖a‖² + ‖b‖² − 2a·b` decomposition, with the norms
+/// of every vector being computed once up front. The last row of `result` is used
+/// as scratch space for the norms of `b` before being overwritten with its distances.
+///
+/// Because of the decomposition, float results may differ slightly from
+/// [generic_squared_euclidean](crate::danger::generic_squared_euclidean) and can be
+/// marginally negative when two vectors are (almost) identical.
+///
+/// # Panics
+///
+/// If `a` is not exactly `dims * a_rows` elements in length, `b` is not exactly
+/// `dims * b_rows` elements in length or `result` is not exactly `a_rows * b_rows`
+/// elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_squared_euclidean_matrix<T, R, M>(
+    dims: usize,
+    a_rows: usize,
+    b_rows: usize,
+    a: &[T],
+    b: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_matrix_len(dims, a_rows, b_rows, a.len(), b.len(), result.len());
+
+    if a_rows == 0 {
+        return;
+    }
+
+    let a_ptr = a.as_ptr();
+    let b_ptr = b.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    // The last row of the result is only written by the final row block, and each
+    // tile reads the norms it requires before writing its values, so we can stash
+    // the norms of `b` here rather than allocating.
+    let b_norms = result_ptr.add((a_rows - 1) * b_rows);
+    for j in 0..b_rows {
+        let row = b_ptr.add(j * dims);
+        let [[norm]] = dot_tile::<T, R, M, 1, 1>(dims, [row], [row]);
+        b_norms.add(j).write(norm);
+    }
+
+    let mut i = 0;
+    while i + A_ROWS_PER_TILE <= a_rows {
+        squared_euclidean_row_block::<T, R, M, A_ROWS_PER_TILE>(
+            dims, b_rows, i, a_ptr, b_ptr, b_norms, result_ptr,
+        );
+        i += A_ROWS_PER_TILE;
+    }
+
+    while i < a_rows {
+        squared_euclidean_row_block::<T, R, M, 1>(
+            dims, b_rows, i, a_ptr, b_ptr, b_norms, result_ptr,
+        );
+        i += 1;
+    }
+}
+
+#[inline(always)]
+fn assert_matrix_len(
+    dims: usize,
+    a_rows: usize,
+    b_rows: usize,
+    a_len: usize,
+    b_len: usize,
+    result_len: usize,
+) {
+    assert_eq!(
+        a_len,
+        dims * a_rows,
+        "Buffer `a` must be exactly `dims * a_rows` elements in length"
+    );
+    assert_eq!(
+        b_len,
+        dims * b_rows,
+        "Buffer `b` must be exactly `dims * b_rows` elements in length"
+    );
+    assert_eq!(
+        result_len,
+        a_rows * b_rows,
+        "Buffer `result` must be exactly `a_rows * b_rows` elements in length"
+    );
+}
+
+#[inline(always)]
+pub(super) unsafe fn row_ptrs<T, const N: usize>(
+    ptr: *const T,
+    dims: usize,
+    start: usize,
+) -> [*const T; N] {
+    let mut ptrs = [ptr; N];
+    for (n, row) in ptrs.iter_mut().enumerate() {
+        *row = ptr.add((start + n) * dims);
+    }
+    ptrs
+}
+
+#[inline(always)]
+/// Computes the dot products of `MA` rows of `a` starting at `a_start` against every
+/// row of `b`, writing them to the respective rows of the result.
+unsafe fn dot_row_block<T, R, M, const MA: usize>(
+    dims: usize,
+    b_rows: usize,
+    a_start: usize,
+    a: *const T,
+    b: *const T,
+    result: *mut T,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let a_rows = row_ptrs::<T, MA>(a, dims, a_start);
+
+    let mut j = 0;
+    while j + B_ROWS_PER_TILE <= b_rows {
+        let b_rows_tile = row_ptrs::<T, B_ROWS_PER_TILE>(b, dims, j);
+        let tile = dot_tile::<T, R, M, MA, B_ROWS_PER_TILE>(dims, a_rows, b_rows_tile);
+        write_tile(result, b_rows, a_start, j, tile);
+
+        j += B_ROWS_PER_TILE;
+    }
+
+    while j < b_rows {
+        let b_rows_tile = row_ptrs::<T, 1>(b, dims, j);
+        let tile = dot_tile::<T, R, M, MA, 1>(dims, a_rows, b_rows_tile);
+        write_tile(result, b_rows, a_start, j, tile);
+
+        j += 1;
+    }
+}
+
+#[inline(always)]
+/// Computes the squared Euclidean distances of `MA` rows of `a` starting at `a_start`
+/// against every row of `b`, writing them to the respective rows of the result.
+unsafe fn squared_euclidean_row_block<T, R, M, const MA: usize>(
+    dims: usize,
+    b_rows: usize,
+    a_start: usize,
+    a: *const T,
+    b: *const T,
+    b_norms: *const T,
+    result: *mut T,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let a_rows = row_ptrs::<T, MA>(a, dims, a_start);
+    let mut a_norms = [M::zero(); MA];
+    for (norm, row) in a_norms.iter_mut().zip(a_rows) {
+        let [[value]] = dot_tile::<T, R, M, 1, 1>(dims, [row], [row]);
+        *norm = value;
+    }
+
+    let mut j = 0;
+    while j + B_ROWS_PER_TILE <= b_rows {
+        let b_rows_tile = row_ptrs::<T, B_ROWS_PER_TILE>(b, dims, j);
+        let tile = dot_tile::<T, R, M, MA, B_ROWS_PER_TILE>(dims, a_rows, b_rows_tile);
+        let tile = dots_to_squared_euclidean::<T, M, MA, B_ROWS_PER_TILE>(
+            tile,
+            a_norms,
+            b_norms.add(j),
+        );
+        write_tile(result, b_rows, a_start, j, tile);
+
+        j += B_ROWS_PER_TILE;
+    }
+
+    while j < b_rows {
+        let b_rows_tile = row_ptrs::<T, 1>(b, dims, j);
+        let tile = dot_tile::<T, R, M, MA, 1>(dims, a_rows, b_rows_tile);
+        let tile =
+            dots_to_squared_euclidean::<T, M, MA, 1>(tile, a_norms, b_norms.add(j));
+        write_tile(result, b_rows, a_start, j, tile);
+
+        j += 1;
+    }
+}
+
+#[inline(always)]
+/// Converts a tile of dot products into squared Euclidean distances.
+///
+/// All norms are read before the tile is written, which is what allows
+/// the norms of `b` to live within the result buffer.
+unsafe fn dots_to_squared_euclidean<T, M, const MA: usize, const NB: usize>(
+    dots: [[T; NB]; MA],
+    a_norms: [T; MA],
+    b_norms: *const T,
+) -> [[T; NB]; MA]
+where
+    T: Copy,
+    M: Math<T>,
+{
+    let mut norms = [M::zero(); NB];
+    for (n, norm) in norms.iter_mut().enumerate() {
+        *norm = b_norms.add(n).read();
+    }
+
+    let mut tile = dots;
+    for (row, a_norm) in tile.iter_mut().zip(a_norms) {
+        for (value, b_norm) in row.iter_mut().zip(norms) {
+            let dot = *value;
+            *value = M::sub(M::add(a_norm, b_norm), M::add(dot, dot));
+        }
+    }
+    tile
+}
+
+#[inline(always)]
+unsafe fn write_tile<T, const MA: usize, const NB: usize>(
+    result: *mut T,
+    b_rows: usize,
+    a_start: usize,
+    b_start: usize,
+    tile: [[T; NB]; MA],
+) {
+    for (m, row) in tile.into_iter().enumerate() {
+        let out = result.add((a_start + m) * b_rows + b_start);
+        for (n, value) in row.into_iter().enumerate() {
+            out.add(n).write(value);
+        }
+    }
+}
+
+#[inline(always)]
+/// Computes the dot product of every pair within the `MA` rows of `a` and `NB` rows of `b`.
+///
+/// Each step loads one register from each of the `NB` rows of `b` and reuses them against
+/// a register loaded from each of the `MA` rows of `a`, accumulating into `MA * NB`
+/// independent registers.
+pub(super) unsafe fn dot_tile<T, R, M, const MA: usize, const NB: usize>(
+    dims: usize,
+    a: [*const T; MA],
+    b: [*const T; NB],
+) -> [[T; NB]; MA]
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let offset_from = dims % R::elements_per_lane();
+
+    let mut totals = [[R::zeroed(); NB]; MA];
+
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let mut l2 = [R::zeroed(); NB];
+        for (reg, row) in l2.iter_mut().zip(b) {
+            *reg = R::load(row.add(i));
+        }
+
+        for (acc, row) in totals.iter_mut().zip(a) {
+            let l1 = R::load(row.add(i));
+            for (total, l2) in acc.iter_mut().zip(l2) {
+                *total = R::fmadd(l1, l2, *total);
+            }
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    let mut totals = totals.map(|acc| acc.map(|reg| R::sum_to_value(reg)));
+
+    while i < dims {
+        for (acc, row) in totals.iter_mut().zip(a) {
+            let x = row.add(i).read();
+            for (total, other) in acc.iter_mut().zip(b) {
+                let y = other.add(i).read();
+                *total = M::add(*total, M::mul(x, y));
+            }
+        }
+
+        i += 1;
+    }
+
+    totals
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+    use crate::test_utils::{simple_dot, simple_euclidean};
+
+    fn get_matrices<T>(dims: usize, a_rows: usize, b_rows: usize) -> (Vec<T>, Vec<T>)
+    where
+        T: Copy,
+        AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let (a, _) = crate::test_utils::get_sample_vectors::<T>(dims * a_rows);
+        let (_, b) = crate::test_utils::get_sample_vectors::<T>(dims * b_rows);
+        (a, b)
+    }
+
+    fn assert_matrix_close<T>(
+        dims: usize,
+        a_rows: usize,
+        b_rows: usize,
+        a: &[T],
+        b: &[T],
+        result: &[T],
+        reference: fn(&[T], &[T]) -> T,
+    ) where
+        T: Copy + std::fmt::Debug,
+        AutoMath: Math<T>,
+    {
+        for i in 0..a_rows {
+            for j in 0..b_rows {
+                let x = &a[i * dims..][..dims];
+                let y = &b[j * dims..][..dims];
+                let expected = reference(x, y);
+                let value = result[i * b_rows + j];
+                assert!(
+                    AutoMath::is_close(value, expected),
+                    "value mismatch at ({i}, {j}) {value:?} vs {expected:?}"
+                );
+            }
+        }
+    }
+
+    pub(crate) unsafe fn test_dot_matrix<T, R>(dims: usize, a_rows: usize, b_rows: usize)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let (a, b) = get_matrices::<T>(dims, a_rows, b_rows);
+
+        let mut result = vec![AutoMath::zero(); a_rows * b_rows];
+        generic_dot_matrix::<T, R, AutoMath>(dims, a_rows, b_rows, &a, &b, &mut result);
+        assert_matrix_close(dims, a_rows, b_rows, &a, &b, &result, simple_dot);
+    }
+
+    pub(crate) unsafe fn test_squared_euclidean_matrix<T, R>(
+        dims: usize,
+        a_rows: usize,
+        b_rows: usize,
+    ) where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let (a, b) = get_matrices::<T>(dims, a_rows, b_rows);
+
+        let mut result = vec![AutoMath::zero(); a_rows * b_rows];
+        generic_squared_euclidean_matrix::<T, R, AutoMath>(
+            dims,
+            a_rows,
+            b_rows,
+            &a,
+            &b,
+            &mut result,
+        );
+        assert_matrix_close(dims, a_rows, b_rows, &a, &b, &result, simple_euclidean);
+    }
+}
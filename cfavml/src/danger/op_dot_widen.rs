@@ -0,0 +1,157 @@
+use crate::danger::core_simd_api::SimdWideningDotRegister;
+
+#[inline(always)]
+/// A generic dot product implementation over two vectors with the products of each
+/// element widened and accumulated as an `i32`.
+///
+/// The accumulation wraps on overflow, matching the behaviour of `wrapping_add` when
+/// summing the products in scalar code.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+///
+/// # Panics
+///
+/// If the lengths of `a` and `b` do not match.
+pub unsafe fn generic_dot_widen<T, R>(a: &[T], b: &[T]) -> i32
+where
+    T: Copy + Into<i32>,
+    R: SimdWideningDotRegister<T>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+    let len = a.len();
+    let a = a.as_ptr();
+    let b = b.as_ptr();
+
+    let offset_from = len % R::widen_elements_per_dense();
+
+    let mut total = R::zeroed_acc_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_widen_dense(a.add(i));
+        let l2 = R::load_widen_dense(b.add(i));
+        total = R::dot_acc_dense(l1, l2, total);
+
+        i += R::widen_elements_per_dense();
+    }
+
+    let mut total = R::sum_acc_to_register(total);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::widen_elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load_widen(a.add(i));
+        let l2 = R::load_widen(b.add(i));
+        total = R::dot_acc(l1, l2, total);
+
+        i += R::widen_elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_acc_to_value(total);
+
+    while i < len {
+        let a: i32 = a.add(i).read().into();
+        let b: i32 = b.add(i).read().into();
+        total = total.wrapping_add(a.wrapping_mul(b));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// A generic squared Euclidean distance implementation over two vectors with the squared
+/// differences of each element widened and accumulated as an `i32`.
+///
+/// The distance is computed as `‖a‖² + ‖b‖² − 2a·b` using the widened dot products, as
+/// the differences of two byte values do not fit within the original type. Since the
+/// integer arithmetic wraps, the result is identical to summing the squared differences
+/// directly with `wrapping_add`.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+///
+/// # Panics
+///
+/// If the lengths of `a` and `b` do not match.
+pub unsafe fn generic_squared_euclidean_widen<T, R>(a: &[T], b: &[T]) -> i32
+where
+    T: Copy + Into<i32>,
+    R: SimdWideningDotRegister<T>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+    let len = a.len();
+    let a = a.as_ptr();
+    let b = b.as_ptr();
+
+    let offset_from = len % R::widen_elements_per_lane();
+
+    let mut norms = R::zeroed_acc();
+    let mut dots = R::zeroed_acc();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_widen(a.add(i));
+        let l2 = R::load_widen(b.add(i));
+        norms = R::dot_acc(l1, l1, norms);
+        norms = R::dot_acc(l2, l2, norms);
+        dots = R::dot_acc(l1, l2, dots);
+
+        i += R::widen_elements_per_lane();
+    }
+
+    let norms = R::sum_acc_to_value(norms);
+    let dots = R::sum_acc_to_value(dots);
+    let mut total = norms.wrapping_sub(dots.wrapping_mul(2));
+
+    // Handle the remainder.
+    while i < len {
+        let a: i32 = a.add(i).read().into();
+        let b: i32 = b.add(i).read().into();
+        let diff = a.wrapping_sub(b);
+        total = total.wrapping_add(diff.wrapping_mul(diff));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_dot_widen<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + Into<i32>,
+    R: SimdWideningDotRegister<T>,
+{
+    let value = generic_dot_widen::<T, R>(&l1, &l2);
+    let expected_value = l1.iter().zip(l2.iter()).fold(0i32, |acc, (a, b)| {
+        let a: i32 = (*a).into();
+        let b: i32 = (*b).into();
+        acc.wrapping_add(a * b)
+    });
+    assert_eq!(value, expected_value, "value missmatch");
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_squared_euclidean_widen<T, R>(l1: Vec<T>, l2: Vec<T>)
+where
+    T: Copy + Into<i32>,
+    R: SimdWideningDotRegister<T>,
+{
+    let value = generic_squared_euclidean_widen::<T, R>(&l1, &l2);
+    let expected_value = l1.iter().zip(l2.iter()).fold(0i32, |acc, (a, b)| {
+        let a: i32 = (*a).into();
+        let b: i32 = (*b).into();
+        let diff = a - b;
+        acc.wrapping_add(diff.wrapping_mul(diff))
+    });
+    assert_eq!(value, expected_value, "value missmatch");
+}
@@ -1,5 +1,5 @@
-use crate::danger::core_simd_api::SimdRegister;
-use crate::math::Math;
+use crate::danger::core_simd_api::{SimdRegister, SimdWidenRegister};
+use crate::math::{CastAs, Math};
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 
 #[inline(always)]
@@ -72,6 +72,141 @@ where
     total
 }
 
+#[inline(always)]
+/// A generic squared Euclidean distance implementation over two `f32` vectors with
+/// each element widened and accumulated as an `f64`.
+///
+/// This is slower than [generic_squared_euclidean] as each register holds half as many
+/// elements, but avoids the precision loss of accumulating large vectors in `f32`.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+///
+/// # Panics
+///
+/// If the lengths of `a` and `b` do not match.
+pub unsafe fn generic_squared_euclidean_f32_f64acc<R>(a: &[f32], b: &[f32]) -> f64
+where
+    R: SimdWidenRegister<f32, f64>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+    let len = a.len();
+    let a = a.as_ptr();
+    let b = b.as_ptr();
+
+    let offset_from = len % R::elements_per_dense();
+
+    let mut total = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_widened_dense(a.add(i));
+        let l2 = R::load_widened_dense(b.add(i));
+        let diff = R::sub_dense(l1, l2);
+        total = R::fmadd_dense(diff, diff, total);
+
+        i += R::elements_per_dense();
+    }
+
+    let mut total = R::sum_to_register(total);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load_widened(a.add(i));
+        let l2 = R::load_widened(b.add(i));
+        let diff = R::sub(l1, l2);
+        total = R::fmadd(diff, diff, total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+
+    while i < len {
+        let diff = a.add(i).read() as f64 - b.add(i).read() as f64;
+        total += diff * diff;
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// A generic squared Euclidean distance implementation over two integer vectors with
+/// each element widened and accumulated as the wider integer type `T`, i.e. `i32`
+/// vectors accumulated as `i64` values.
+///
+/// The differences are computed after widening so they never overflow, but the
+/// accumulation wraps on overflow of `T`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of the `R` SIMD register must be followed.
+///
+/// # Panics
+///
+/// If the lengths of `a` and `b` do not match.
+pub unsafe fn generic_squared_euclidean_wide_acc<F, T, R, M>(a: &[F], b: &[F]) -> T
+where
+    F: CastAs<T>,
+    T: Copy,
+    R: SimdWidenRegister<F, T>,
+    M: Math<T>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+    let len = a.len();
+    let a = a.as_ptr();
+    let b = b.as_ptr();
+
+    let offset_from = len % R::elements_per_dense();
+
+    let mut total = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_widened_dense(a.add(i));
+        let l2 = R::load_widened_dense(b.add(i));
+        let diff = R::sub_dense(l1, l2);
+        total = R::fmadd_dense(diff, diff, total);
+
+        i += R::elements_per_dense();
+    }
+
+    let mut total = R::sum_to_register(total);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load_widened(a.add(i));
+        let l2 = R::load_widened(b.add(i));
+        let diff = R::sub(l1, l2);
+        total = R::fmadd(diff, diff, total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+
+    while i < len {
+        let diff = M::sub(a.add(i).read().cast_as(), b.add(i).read().cast_as());
+        total = M::add(total, M::mul(diff, diff));
+
+        i += 1;
+    }
+
+    total
+}
+
 #[cfg(test)]
 pub(crate) unsafe fn test_euclidean<T, R>(l1: Vec<T>, l2: Vec<T>)
 where
@@ -88,3 +223,82 @@ where
         "value missmatch {value:?} vs {expected_value:?}"
     );
 }
+
+#[cfg(test)]
+pub(crate) unsafe fn test_squared_euclidean_f32_f64acc<R>(l1: Vec<f32>, l2: Vec<f32>)
+where
+    R: SimdWidenRegister<f32, f64> + SimdRegister<f32>,
+{
+    use crate::math::{AutoMath, StdMath};
+
+    let value = generic_squared_euclidean_f32_f64acc::<R>(&l1, &l2);
+    let expected_value = l1.iter().zip(l2.iter()).fold(0.0, |acc, (a, b)| {
+        let diff = *a as f64 - *b as f64;
+        acc + diff * diff
+    });
+    assert!(
+        StdMath::is_close(value, expected_value),
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+
+    // One large value followed by two small values, the small values are lost
+    // entirely when accumulated in `f32`.
+    let l1 = (0..l1.len())
+        .map(|i| if i % 3 == 0 { 1.0e3 } else { 1.0e-2 })
+        .collect::<Vec<f32>>();
+    let l2 = vec![0.0; l1.len()];
+    let expected_value = l1.iter().map(|v| (*v as f64) * (*v as f64)).sum::<f64>();
+
+    let value = generic_squared_euclidean_f32_f64acc::<R>(&l1, &l2);
+    let f32_value = generic_squared_euclidean::<f32, R, AutoMath, _, _>(&l1, &l2) as f64;
+    assert!(
+        (value - expected_value).abs() < (f32_value - expected_value).abs(),
+        "f64 accumulation should be more precise than f32 {value:?} vs {f32_value:?}"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_squared_euclidean_wide_acc<F, T, R>(l1: Vec<F>, l2: Vec<F>)
+where
+    F: CastAs<T> + TryFrom<i64> + Into<i64>,
+    <F as TryFrom<i64>>::Error: std::fmt::Debug,
+    T: Copy + PartialEq + std::fmt::Debug + TryFrom<i64>,
+    <T as TryFrom<i64>>::Error: std::fmt::Debug,
+    R: SimdWidenRegister<F, T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    // The sample vectors cover the full range of the type, so the sum of squares is
+    // expected to wrap the same way the scalar accumulation does.
+    let value = generic_squared_euclidean_wide_acc::<F, T, R, AutoMath>(&l1, &l2);
+    let expected_value =
+        l1.iter()
+            .zip(l2.iter())
+            .fold(AutoMath::zero(), |acc, (a, b)| {
+                let diff = AutoMath::sub((*a).cast_as(), (*b).cast_as());
+                AutoMath::add(acc, AutoMath::mul(diff, diff))
+            });
+    assert_eq!(value, expected_value, "value missmatch");
+
+    // Each squared difference overflows the element type, but the total still fits
+    // within the accumulator so the result must be exact.
+    let base = 1i64 << (std::mem::size_of::<F>() * 8 - 6);
+    let l1 = (0..l1.len())
+        .map(|i| F::try_from(base + (i % 7) as i64).unwrap())
+        .collect::<Vec<F>>();
+    let l2 = (0..l1.len())
+        .map(|i| F::try_from((i % 5) as i64).unwrap())
+        .collect::<Vec<F>>();
+    let expected_value = l1.iter().zip(l2.iter()).fold(0i64, |acc, (a, b)| {
+        let diff = (*a).into() - (*b).into();
+        acc + diff * diff
+    });
+
+    let value = generic_squared_euclidean_wide_acc::<F, T, R, AutoMath>(&l1, &l2);
+    assert_eq!(
+        value,
+        T::try_from(expected_value).unwrap(),
+        "value missmatch"
+    );
+}
@@ -0,0 +1,364 @@
+//! Float classification routines, checking which elements of a vector are `NaN`,
+//! finite or infinite.
+//!
+//! The vector routines produce `0` (false) / `1` (true) masks in the same way as the
+//! comparison routines, while the `any`/`all` reductions return as soon as the result is known.
+
+use super::core_routine_boilerplate::apply_unary_vertical_kernel;
+use super::core_simd_api::SimdFloatRegister;
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::DenseLane;
+use crate::math::Math;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+#[inline(always)]
+/// A generic vector element-wise check of which elements of vector `a` are `NaN`.
+///
+/// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
+///
+/// # Safety
+///
+/// The sizes of `a` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_is_nan_vector<T, R, M, B1, B2>(a: B1, result: &mut [B2])
+where
+    T: Copy,
+    R: SimdFloatRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    apply_unary_vertical_kernel::<T, R, M, B1, B2>(
+        a,
+        result,
+        R::is_nan_dense,
+        R::is_nan,
+        |a| M::cast_bool(is_nan::<T, M>(a)),
+    )
+}
+
+#[inline(always)]
+/// A generic vector element-wise check of which elements of vector `a` are finite,
+/// i.e. neither infinite nor `NaN`.
+///
+/// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
+///
+/// # Safety
+///
+/// The sizes of `a` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_is_finite_vector<T, R, M, B1, B2>(a: B1, result: &mut [B2])
+where
+    T: Copy,
+    R: SimdFloatRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    apply_unary_vertical_kernel::<T, R, M, B1, B2>(
+        a,
+        result,
+        R::is_finite_dense,
+        R::is_finite,
+        |a| M::cast_bool(is_finite::<T, M>(a)),
+    )
+}
+
+#[inline(always)]
+/// A generic vector element-wise check of which elements of vector `a` are
+/// positive or negative infinity.
+///
+/// The result of each element check is returned as a mask of either `0` (false) or ` 1` (true).
+///
+/// # Safety
+///
+/// The sizes of `a` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_is_infinite_vector<T, R, M, B1, B2>(a: B1, result: &mut [B2])
+where
+    T: Copy,
+    R: SimdFloatRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    apply_unary_vertical_kernel::<T, R, M, B1, B2>(
+        a,
+        result,
+        R::is_infinite_dense,
+        R::is_infinite,
+        |a| M::cast_bool(is_infinite::<T, M>(a)),
+    )
+}
+
+#[inline(always)]
+/// A generic check if **_any_** element of vector `a` is `NaN`.
+///
+/// This returns as soon as a `NaN` is found, an empty input returns `false`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_any_nan<T, R, M, B1>(a: B1) -> bool
+where
+    T: Copy,
+    R: SimdFloatRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_any_mask_kernel::<T, R, M, B1>(a, R::is_nan_dense, R::is_nan, is_nan::<T, M>)
+}
+
+#[inline(always)]
+/// A generic check if **_any_** element of vector `a` is positive or negative infinity.
+///
+/// This returns as soon as an infinite value is found, an empty input returns `false`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_any_infinite<T, R, M, B1>(a: B1) -> bool
+where
+    T: Copy,
+    R: SimdFloatRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_any_mask_kernel::<T, R, M, B1>(
+        a,
+        R::is_infinite_dense,
+        R::is_infinite,
+        is_infinite::<T, M>,
+    )
+}
+
+#[inline(always)]
+/// A generic check if **_all_** elements of vector `a` are finite, i.e. neither
+/// infinite nor `NaN`.
+///
+/// This returns as soon as a non-finite value is found, an empty input returns `true`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_all_finite<T, R, M, B1>(a: B1) -> bool
+where
+    T: Copy,
+    R: SimdFloatRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    generic_all_mask_kernel::<T, R, M, B1>(
+        a,
+        R::is_finite_dense,
+        R::is_finite,
+        is_finite::<T, M>,
+    )
+}
+
+#[inline(always)]
+fn is_nan<T: Copy, M: Math<T>>(a: T) -> bool {
+    !M::cmp_eq(a, a)
+}
+
+#[inline(always)]
+fn is_infinite<T: Copy, M: Math<T>>(a: T) -> bool {
+    M::cmp_eq(M::abs(a), M::max())
+}
+
+#[inline(always)]
+fn is_finite<T: Copy, M: Math<T>>(a: T) -> bool {
+    !is_nan::<T, M>(a) && !is_infinite::<T, M>(a)
+}
+
+#[allow(clippy::type_complexity)]
+#[inline(always)]
+/// Returns `true` if any element of `a` produces a `1` in the mask.
+unsafe fn generic_any_mask_kernel<T, R, M, B1>(
+    a: B1,
+    dense_kernel: unsafe fn(DenseLane<R::Register>) -> DenseLane<R::Register>,
+    reg_kernel: unsafe fn(R::Register) -> R::Register,
+    single_kernel: fn(T) -> bool,
+) -> bool
+where
+    T: Copy,
+    R: SimdFloatRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        let mask = R::max_to_register(dense_kernel(l1));
+        if M::cmp_eq(R::max_to_value(mask), M::one()) {
+            return true;
+        }
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        if M::cmp_eq(R::max_to_value(reg_kernel(l1)), M::one()) {
+            return true;
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        if single_kernel(a.read()) {
+            return true;
+        }
+
+        i += 1;
+    }
+
+    false
+}
+
+#[allow(clippy::type_complexity)]
+#[inline(always)]
+/// Returns `true` if every element of `a` produces a `1` in the mask.
+unsafe fn generic_all_mask_kernel<T, R, M, B1>(
+    a: B1,
+    dense_kernel: unsafe fn(DenseLane<R::Register>) -> DenseLane<R::Register>,
+    reg_kernel: unsafe fn(R::Register) -> R::Register,
+    single_kernel: fn(T) -> bool,
+) -> bool
+where
+    T: Copy,
+    R: SimdFloatRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        let mask = R::min_to_register(dense_kernel(l1));
+        if M::cmp_eq(R::min_to_value(mask), M::zero()) {
+            return false;
+        }
+
+        i += R::elements_per_dense();
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        if M::cmp_eq(R::min_to_value(reg_kernel(l1)), M::zero()) {
+            return false;
+        }
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    while i < len {
+        if !single_kernel(a.read()) {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+    use crate::math::AutoMath;
+
+    pub(crate) unsafe fn test_is_nan<T, R>(l1: Vec<T>, expected: Vec<bool>)
+    where
+        T: Copy + PartialEq + Debug,
+        R: SimdFloatRegister<T>,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let mut result = vec![AutoMath::zero(); l1.len()];
+        generic_is_nan_vector::<T, R, AutoMath, _, _>(&l1, &mut result);
+
+        let expected_mask = expected
+            .iter()
+            .map(|v| AutoMath::cast_bool(*v))
+            .collect::<Vec<T>>();
+        assert_eq!(result, expected_mask, "value mismatch");
+
+        let any = generic_any_nan::<T, R, AutoMath, _>(&l1);
+        assert_eq!(any, expected.iter().any(|v| *v), "any mismatch");
+    }
+
+    pub(crate) unsafe fn test_is_finite<T, R>(l1: Vec<T>, expected: Vec<bool>)
+    where
+        T: Copy + PartialEq + Debug,
+        R: SimdFloatRegister<T>,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let mut result = vec![AutoMath::zero(); l1.len()];
+        generic_is_finite_vector::<T, R, AutoMath, _, _>(&l1, &mut result);
+
+        let expected_mask = expected
+            .iter()
+            .map(|v| AutoMath::cast_bool(*v))
+            .collect::<Vec<T>>();
+        assert_eq!(result, expected_mask, "value mismatch");
+
+        let all = generic_all_finite::<T, R, AutoMath, _>(&l1);
+        assert_eq!(all, expected.iter().all(|v| *v), "all mismatch");
+    }
+
+    pub(crate) unsafe fn test_is_infinite<T, R>(l1: Vec<T>, expected: Vec<bool>)
+    where
+        T: Copy + PartialEq + Debug,
+        R: SimdFloatRegister<T>,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let mut result = vec![AutoMath::zero(); l1.len()];
+        generic_is_infinite_vector::<T, R, AutoMath, _, _>(&l1, &mut result);
+
+        let expected_mask = expected
+            .iter()
+            .map(|v| AutoMath::cast_bool(*v))
+            .collect::<Vec<T>>();
+        assert_eq!(result, expected_mask, "value mismatch");
+
+        let any = generic_any_infinite::<T, R, AutoMath, _>(&l1);
+        assert_eq!(any, expected.iter().any(|v| *v), "any mismatch");
+    }
+}
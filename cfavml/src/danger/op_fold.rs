@@ -0,0 +1,281 @@
+//! Generic fold drivers over two vectors, handling the dense lane unrolling, the
+//! single register loop and the scalar remainder of a reduction so one-off fused
+//! operations only need to provide the per-register and per-element steps.
+//!
+//! The steps are generic closures rather than function pointers, so after
+//! monomorphization the routine compiles down to the same code as the hand-written
+//! reductions, i.e. [generic_dot](crate::danger::generic_dot) is equivalent to:
+//!
+//! ```ignore
+//! generic_fold::<T, R, M, _, _, _, _>(
+//!     a,
+//!     b,
+//!     M::zero(),
+//!     |l1, l2, acc| R::fmadd(l1, l2, acc),
+//!     |a, b, acc| M::add(acc, M::mul(a, b)),
+//! )
+//! ```
+
+use crate::danger::{DenseLane, SimdRegister};
+use crate::math::Math;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+#[inline(always)]
+/// A generic fold over two vectors which sums the values accumulated by `step`.
+///
+/// `step(l1, l2, acc)` accumulates a register of `a` and `b` into the accumulator
+/// `acc`, with the accumulators starting zeroed and being summed together once
+/// all registers are processed. `tail_step(a, b, acc)` then accumulates each of the
+/// remaining elements that do not fill a register into the summed value, which
+/// starts at `init`.
+///
+/// Use [generic_fold_reduce] when the accumulators should be combined with
+/// something other than an addition, i.e. a horizontal max.
+///
+/// # Safety
+///
+/// The sizes of `a` and `b` must be equal, the safety requirements of `M` definition
+/// the basic math operations and the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_fold<T, R, M, B1, B2, F, G>(
+    a: B1,
+    b: B2,
+    init: T,
+    step: F,
+    tail_step: G,
+) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    F: Fn(R::Register, R::Register, R::Register) -> R::Register,
+    G: Fn(T, T, T) -> T,
+{
+    generic_fold_kernel::<T, R, B1, B2, _, _, _, _>(
+        a,
+        b,
+        R::zeroed(),
+        step,
+        |lane| R::sum_to_register(lane),
+        |reg| M::add(init, R::sum_to_value(reg)),
+        tail_step,
+    )
+}
+
+#[inline(always)]
+/// A generic fold over two vectors with a custom reduction of the accumulators.
+///
+/// Every accumulator register starts filled with `identity`, which must be the
+/// identity value of `combine`, i.e. `0` for an addition or the min value of `T`
+/// for a max. `step(l1, l2, acc)` accumulates a register of `a` and `b` into `acc`,
+/// the accumulators are merged with `combine` and reduced to a single value with
+/// `reduce`, `tail_step(a, b, acc)` then accumulates each of the remaining elements
+/// into the reduced value.
+///
+/// # Safety
+///
+/// The sizes of `a` and `b` must be equal, the safety requirements of `M` definition
+/// the basic math operations and the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_fold_reduce<T, R, M, B1, B2, F, C, H, G>(
+    a: B1,
+    b: B2,
+    identity: T,
+    step: F,
+    combine: C,
+    reduce: H,
+    tail_step: G,
+) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    F: Fn(R::Register, R::Register, R::Register) -> R::Register,
+    C: Fn(R::Register, R::Register) -> R::Register,
+    H: Fn(R::Register) -> T,
+    G: Fn(T, T, T) -> T,
+{
+    generic_fold_kernel::<T, R, B1, B2, _, _, _, _>(
+        a,
+        b,
+        R::filled(identity),
+        step,
+        |lane| {
+            let acc1 = combine(combine(lane.a, lane.b), combine(lane.c, lane.d));
+            let acc2 = combine(combine(lane.e, lane.f), combine(lane.g, lane.h));
+            combine(acc1, acc2)
+        },
+        reduce,
+        tail_step,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+unsafe fn generic_fold_kernel<T, R, B1, B2, F, L, H, G>(
+    a: B1,
+    b: B2,
+    acc_init: R::Register,
+    step: F,
+    roll_up: L,
+    reduce: H,
+    tail_step: G,
+) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    F: Fn(R::Register, R::Register, R::Register) -> R::Register,
+    L: Fn(DenseLane<R::Register>) -> R::Register,
+    H: Fn(R::Register) -> T,
+    G: Fn(T, T, T) -> T,
+{
+    let mut a = a.into_mem_loader();
+    let mut b = b.into_mem_loader();
+    assert_eq!(
+        a.projected_len(),
+        b.projected_len(),
+        "Buffers `a` and `b` do not match in size"
+    );
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    let mut total = DenseLane::copy(acc_init);
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        let l2 = b.load_dense::<R>();
+        total = DenseLane {
+            a: step(l1.a, l2.a, total.a),
+            b: step(l1.b, l2.b, total.b),
+            c: step(l1.c, l2.c, total.c),
+            d: step(l1.d, l2.d, total.d),
+            e: step(l1.e, l2.e, total.e),
+            f: step(l1.f, l2.f, total.f),
+            g: step(l1.g, l2.g, total.g),
+            h: step(l1.h, l2.h, total.h),
+        };
+
+        i += R::elements_per_dense();
+    }
+
+    let mut total = roll_up(total);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        let l2 = b.load::<R>();
+        total = step(l1, l2, total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = reduce(total);
+
+    while i < len {
+        total = tail_step(a.read(), b.read(), total);
+
+        i += 1;
+    }
+
+    total
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::danger::{generic_cmp_max, generic_dot, generic_squared_euclidean};
+    use crate::math::AutoMath;
+
+    /// [generic_dot] re-implemented on top of [generic_fold].
+    pub(crate) unsafe fn fold_dot<T, R>(a: &[T], b: &[T]) -> T
+    where
+        T: Copy,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        generic_fold::<T, R, AutoMath, _, _, _, _>(
+            a,
+            b,
+            AutoMath::zero(),
+            |l1, l2, acc| R::fmadd(l1, l2, acc),
+            |a, b, acc| AutoMath::add(acc, AutoMath::mul(a, b)),
+        )
+    }
+
+    /// [generic_squared_euclidean] re-implemented on top of [generic_fold].
+    pub(crate) unsafe fn fold_squared_euclidean<T, R>(a: &[T], b: &[T]) -> T
+    where
+        T: Copy,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        generic_fold::<T, R, AutoMath, _, _, _, _>(
+            a,
+            b,
+            AutoMath::zero(),
+            |l1, l2, acc| {
+                let diff = R::sub(l1, l2);
+                R::fmadd(diff, diff, acc)
+            },
+            |a, b, acc| {
+                let diff = AutoMath::sub(a, b);
+                AutoMath::add(acc, AutoMath::mul(diff, diff))
+            },
+        )
+    }
+
+    pub(crate) unsafe fn test_fold<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        // Accumulated in the same order so the results must match exactly.
+        let expected = generic_dot::<T, R, AutoMath, _, _>(&l1, &l2);
+        assert_eq!(fold_dot::<T, R>(&l1, &l2), expected, "dot missmatch");
+
+        let expected = generic_squared_euclidean::<T, R, AutoMath, _, _>(&l1, &l2);
+        assert_eq!(
+            fold_squared_euclidean::<T, R>(&l1, &l2),
+            expected,
+            "squared euclidean missmatch"
+        );
+
+        let total = generic_fold::<T, R, AutoMath, _, _, _, _>(
+            &[] as &[T],
+            &[] as &[T],
+            AutoMath::one(),
+            |l1, l2, acc| R::fmadd(l1, l2, acc),
+            |a, b, acc| AutoMath::add(acc, AutoMath::mul(a, b)),
+        );
+        assert_eq!(total, AutoMath::one(), "empty input should return `init`");
+
+        // The max of `a` expressed as a fold ignoring `b`.
+        let max = generic_fold_reduce::<T, R, AutoMath, _, _, _, _, _, _>(
+            &l1,
+            &l2,
+            AutoMath::min(),
+            |l1, _, acc| R::max(acc, l1),
+            |a, b| R::max(a, b),
+            |reg| R::max_to_value(reg),
+            |a, _, acc| AutoMath::cmp_max(acc, a),
+        );
+        let expected = generic_cmp_max::<T, R, AutoMath, _>(&l1);
+        assert_eq!(max, expected, "max missmatch");
+    }
+}
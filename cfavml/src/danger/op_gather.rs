@@ -0,0 +1,181 @@
+//! Gather and scatter routines rearranging the elements of a vector by an index vector.
+//!
+//! Both routines use 32-bit indices, matching the hardware gather instructions and
+//! halving the memory traffic of the indices compared to `usize`.
+
+use super::core_simd_api::SimdRegister;
+use crate::buffer::WriteOnlyBuffer;
+
+#[inline(always)]
+/// A generic gather implementation, writing the element of `a` selected by
+/// `indices[i]` to `result[i]`.
+///
+/// Registers are gathered with [SimdRegister::gather], which uses the hardware gather
+/// instructions where available, i.e. for the 32-bit types on AVX2.
+///
+/// # Panics
+///
+/// If `indices` and `result` are not exactly `dims` elements in length.
+///
+/// # Safety
+///
+/// Every index in `indices` must be within bounds of `a`, the requirements
+/// of `R` SIMD register must also be followed.
+pub unsafe fn generic_gather<T, R, B>(
+    dims: usize,
+    a: &[T],
+    indices: &[u32],
+    mut result: &mut [B],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    assert_eq!(
+        indices.len(),
+        dims,
+        "Buffer `indices` must be exactly `dims` elements in length"
+    );
+    assert_eq!(
+        result.raw_buffer_len(),
+        dims,
+        "Buffer `result` must be exactly `dims` elements in length"
+    );
+
+    let a_ptr = a.as_ptr();
+    let indices_ptr = indices.as_ptr();
+    let result_ptr = result.as_write_only_ptr();
+
+    let mut i = 0;
+
+    // The hardware gather instructions treat the indices as signed, which only
+    // holds for the indices of buffers within `i32::MAX` elements.
+    if a.len() <= i32::MAX as usize {
+        let offset_from = dims % R::elements_per_lane();
+        while i < (dims - offset_from) {
+            R::write(result_ptr.add(i), R::gather(a_ptr, indices_ptr.add(i)));
+            i += R::elements_per_lane();
+        }
+    }
+
+    while i < dims {
+        let index = *indices.get_unchecked(i) as usize;
+        result.write_at(i, *a.get_unchecked(index));
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// A generic scatter implementation, writing `a[i]` to the element of `result`
+/// selected by `indices[i]`.
+///
+/// The elements are written in order, so if `indices` contains duplicates the last
+/// element of `a` written to that index wins. Elements of `result` which are not
+/// selected by any index are left untouched.
+///
+/// # Panics
+///
+/// If `a` and `indices` are not exactly `dims` elements in length.
+///
+/// # Safety
+///
+/// Every index in `indices` must be within bounds of `result`, the requirements
+/// of `R` SIMD register must also be followed.
+pub unsafe fn generic_scatter<T, R>(
+    dims: usize,
+    a: &[T],
+    indices: &[u32],
+    result: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+{
+    assert_eq!(
+        a.len(),
+        dims,
+        "Buffer `a` must be exactly `dims` elements in length"
+    );
+    assert_eq!(
+        indices.len(),
+        dims,
+        "Buffer `indices` must be exactly `dims` elements in length"
+    );
+
+    let a_ptr = a.as_ptr();
+    let indices_ptr = indices.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let offset_from = dims % R::elements_per_lane();
+
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        R::scatter(R::load(a_ptr.add(i)), result_ptr, indices_ptr.add(i));
+        i += R::elements_per_lane();
+    }
+
+    while i < dims {
+        let index = *indices.get_unchecked(i) as usize;
+        *result.get_unchecked_mut(index) = *a.get_unchecked(i);
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::math::{AutoMath, Math};
+
+    /// A deterministic permutation of `0..len` mixed with repeated indices.
+    fn sample_indices(len: usize, dims: usize) -> Vec<u32> {
+        (0..dims).map(|i| ((i * 7919 + 13) % len) as u32).collect()
+    }
+
+    pub(crate) unsafe fn test_gather<T, R>(l1: Vec<T>)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        for dims in [0, 1, 7, 67, l1.len(), l1.len() * 2 + 3] {
+            let indices = sample_indices(l1.len(), dims);
+
+            let mut result = vec![AutoMath::zero(); dims];
+            generic_gather::<T, R, _>(dims, &l1, &indices, &mut result);
+
+            let expected = indices
+                .iter()
+                .map(|&index| l1[index as usize])
+                .collect::<Vec<T>>();
+            assert_eq!(result, expected, "value mismatch with dims {dims}");
+        }
+    }
+
+    pub(crate) unsafe fn test_scatter<T, R>(l1: Vec<T>)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        for len in [1, 7, 67, l1.len()] {
+            let dims = l1.len();
+            let indices = sample_indices(len, dims);
+
+            let mut result = vec![AutoMath::one(); len];
+            generic_scatter::<T, R>(dims, &l1, &indices, &mut result);
+
+            let mut expected = vec![AutoMath::one(); len];
+            for (&value, &index) in l1.iter().zip(indices.iter()) {
+                expected[index as usize] = value;
+            }
+            assert_eq!(result, expected, "value mismatch with len {len}");
+        }
+
+        // Every element lands on the same index, so only the last one must remain.
+        let indices = vec![3; l1.len()];
+        let mut result = vec![AutoMath::zero(); 4];
+        generic_scatter::<T, R>(l1.len(), &l1, &indices, &mut result);
+        assert_eq!(result[3], l1[l1.len() - 1], "last write should win");
+        assert_eq!(result[..3], [AutoMath::zero(); 3]);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::danger::core_simd_api::SimdRegister;
+use crate::danger::op_batch_distance::batch_dot_kernel;
+use crate::math::Math;
+
+#[inline(always)]
+/// A generic matrix-vector product of the `rows x cols` row-major `matrix` and the
+/// `cols` element vector `x`, writing the `rows` element result to `out`.
+///
+/// This is [generic_batch_dot](super::generic_batch_dot) with `x` as the query, so the
+/// results are identical.
+///
+/// # Panics
+///
+/// If `matrix` is not exactly `rows * cols` elements in length, `x` is not exactly
+/// `cols` elements in length or `out` is not exactly `rows` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_gemv<T, R, M>(
+    rows: usize,
+    cols: usize,
+    matrix: &[T],
+    x: &[T],
+    out: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        matrix.len(),
+        rows * cols,
+        "Buffer `matrix` must be exactly `rows * cols` elements in length"
+    );
+    assert_eq!(
+        x.len(),
+        cols,
+        "Buffer `x` must be exactly `cols` elements in length"
+    );
+    assert_eq!(
+        out.len(),
+        rows,
+        "Buffer `out` must be exactly `rows` elements in length"
+    );
+
+    batch_dot_kernel::<T, R, M>(cols, x.as_ptr(), matrix.as_ptr(), rows, |i, total| {
+        out[i] = total
+    });
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+
+    pub(crate) unsafe fn test_gemv<T, R>(rows: usize, cols: usize)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let (matrix, _) = crate::test_utils::get_sample_vectors::<T>(rows * cols);
+        let (_, x) = crate::test_utils::get_sample_vectors::<T>(cols);
+
+        let mut out = vec![AutoMath::zero(); rows];
+        generic_gemv::<T, R, AutoMath>(rows, cols, &matrix, &x, &mut out);
+
+        for (i, value) in out.iter().copied().enumerate() {
+            let mut expected = AutoMath::zero();
+            for j in 0..cols {
+                expected =
+                    AutoMath::add(expected, AutoMath::mul(matrix[i * cols + j], x[j]));
+            }
+            assert!(
+                AutoMath::is_close(value, expected),
+                "value mismatch at row {i} {value:?} vs {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Buffer `x` must be exactly `cols` elements in length")]
+    fn test_gemv_shape_missmatch() {
+        let mut out = [0.0f32; 2];
+        unsafe {
+            generic_gemv::<f32, crate::danger::Fallback, AutoMath>(
+                2, 3, &[0.0; 6], &[0.0; 2], &mut out,
+            )
+        };
+    }
+}
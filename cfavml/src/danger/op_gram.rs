@@ -0,0 +1,258 @@
+//! Gram matrix routines computing the dot product between every pair of rows of
+//! a single matrix.
+//!
+//! As `G[i, j] == G[j, i]` only the upper triangle is computed, each value being mirrored
+//! into the lower triangle as it is written. The rows are walked in blocks sized to keep
+//! the pair of blocks being multiplied resident in L2 cache, with each pair of blocks
+//! being computed in the same register tiles as
+//! [generic_dot_matrix](crate::danger::generic_dot_matrix).
+
+use core::mem;
+
+use crate::danger::core_simd_api::SimdRegister;
+use crate::danger::op_distance_matrix::{
+    dot_tile,
+    row_ptrs,
+    A_ROWS_PER_TILE,
+    B_ROWS_PER_TILE,
+};
+use crate::math::Math;
+
+/// The number of bytes of rows in a single block, two blocks are worked
+/// on at once so this is half of a conservative 256KB L2 cache.
+const BLOCK_SIZE_BYTES: usize = 128 << 10;
+
+#[inline(always)]
+/// A generic Gram matrix of the `rows` vectors in `matrix`, each being `dims` elements
+/// in length, computing the dot product between every pair of vectors.
+///
+/// The dot product of the `i`th and `j`th vector is written to both `result[i * rows + j]`
+/// and `result[j * rows + i]`, producing a symmetric row-major `rows x rows` matrix.
+///
+/// # Panics
+///
+/// If `matrix` is not exactly `rows * dims` elements in length or `result` is not
+/// exactly `rows * rows` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_gram_matrix<T, R, M>(
+    rows: usize,
+    dims: usize,
+    matrix: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        matrix.len(),
+        rows * dims,
+        "Buffer `matrix` must be exactly `rows * dims` elements in length"
+    );
+    assert_eq!(
+        result.len(),
+        rows * rows,
+        "Buffer `result` must be exactly `rows * rows` elements in length"
+    );
+
+    let matrix_ptr = matrix.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let block_rows = rows_per_block::<T>(dims);
+
+    let mut a_start = 0;
+    while a_start < rows {
+        let a_end = rows.min(a_start + block_rows);
+
+        let mut b_start = a_start;
+        while b_start < rows {
+            let b_end = rows.min(b_start + block_rows);
+            gram_block::<T, R, M>(
+                dims,
+                rows,
+                (a_start, a_end),
+                (b_start, b_end),
+                matrix_ptr,
+                result_ptr,
+            );
+            b_start = b_end;
+        }
+
+        a_start = a_end;
+    }
+}
+
+#[inline(always)]
+/// The number of rows within a single block, always being a multiple of the tile sizes.
+fn rows_per_block<T>(dims: usize) -> usize {
+    let row_size = dims.max(1) * mem::size_of::<T>().max(1);
+    let rows = (BLOCK_SIZE_BYTES / row_size) / B_ROWS_PER_TILE * B_ROWS_PER_TILE;
+    rows.max(B_ROWS_PER_TILE)
+}
+
+#[inline(always)]
+/// Computes the dot products of the rows within the `a` block against the rows within
+/// the `b` block, skipping any tiles which fall entirely below the diagonal.
+unsafe fn gram_block<T, R, M>(
+    dims: usize,
+    rows: usize,
+    (a_start, a_end): (usize, usize),
+    (b_start, b_end): (usize, usize),
+    matrix: *const T,
+    result: *mut T,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let mut i = a_start;
+    while i + A_ROWS_PER_TILE <= a_end {
+        gram_row_block::<T, R, M, A_ROWS_PER_TILE>(
+            dims,
+            rows,
+            i,
+            (b_start.max(i), b_end),
+            matrix,
+            result,
+        );
+        i += A_ROWS_PER_TILE;
+    }
+
+    while i < a_end {
+        gram_row_block::<T, R, M, 1>(
+            dims,
+            rows,
+            i,
+            (b_start.max(i), b_end),
+            matrix,
+            result,
+        );
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Computes the dot products of `MA` rows starting at `a_start` against the rows
+/// of the given range, writing them to both triangles of the result.
+unsafe fn gram_row_block<T, R, M, const MA: usize>(
+    dims: usize,
+    rows: usize,
+    a_start: usize,
+    (b_start, b_end): (usize, usize),
+    matrix: *const T,
+    result: *mut T,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let a_rows = row_ptrs::<T, MA>(matrix, dims, a_start);
+
+    let mut j = b_start;
+    while j + B_ROWS_PER_TILE <= b_end {
+        let b_rows_tile = row_ptrs::<T, B_ROWS_PER_TILE>(matrix, dims, j);
+        let tile = dot_tile::<T, R, M, MA, B_ROWS_PER_TILE>(dims, a_rows, b_rows_tile);
+        write_symmetric_tile(result, rows, a_start, j, tile);
+
+        j += B_ROWS_PER_TILE;
+    }
+
+    while j < b_end {
+        let b_rows_tile = row_ptrs::<T, 1>(matrix, dims, j);
+        let tile = dot_tile::<T, R, M, MA, 1>(dims, a_rows, b_rows_tile);
+        write_symmetric_tile(result, rows, a_start, j, tile);
+
+        j += 1;
+    }
+}
+
+#[inline(always)]
+/// Writes the tile to its position in the result and its transposed position.
+///
+/// Tiles on the diagonal overlap their own mirror, but both orders of a pair produce
+/// the same value as the accumulation order is the same.
+unsafe fn write_symmetric_tile<T: Copy, const MA: usize, const NB: usize>(
+    result: *mut T,
+    rows: usize,
+    a_start: usize,
+    b_start: usize,
+    tile: [[T; NB]; MA],
+) {
+    for (m, row) in tile.into_iter().enumerate() {
+        let i = a_start + m;
+        for (n, value) in row.into_iter().enumerate() {
+            let j = b_start + n;
+            result.add(i * rows + j).write(value);
+            result.add(j * rows + i).write(value);
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+    use crate::test_utils::simple_dot;
+
+    pub(crate) unsafe fn test_gram_matrix<T, R>(rows: usize, dims: usize)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let (matrix, _) = crate::test_utils::get_sample_vectors::<T>(rows * dims);
+
+        let mut result = vec![AutoMath::zero(); rows * rows];
+        generic_gram_matrix::<T, R, AutoMath>(rows, dims, &matrix, &mut result);
+
+        for i in 0..rows {
+            for j in 0..rows {
+                let x = &matrix[i * dims..][..dims];
+                let y = &matrix[j * dims..][..dims];
+                let expected = simple_dot(x, y);
+                let value = result[i * rows + j];
+                assert!(
+                    AutoMath::is_close(value, expected),
+                    "value mismatch at ({i}, {j}) {value:?} vs {expected:?}"
+                );
+            }
+        }
+    }
+
+    /// Checks a matrix large enough to be split into multiple blocks against the
+    /// all-pairs dot product matrix.
+    ///
+    /// Each pair is accumulated in the same order regardless of the tile it falls in,
+    /// so the results must match exactly, which avoids float error from the long rows.
+    pub(crate) unsafe fn test_gram_matrix_blocked<T, R>(dims: usize)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        // Two full blocks and a partial block to exercise the trailing rows.
+        let rows = rows_per_block::<T>(dims) * 2 + 3;
+
+        let (matrix, _) = crate::test_utils::get_sample_vectors::<T>(rows * dims);
+
+        let mut result = vec![AutoMath::zero(); rows * rows];
+        generic_gram_matrix::<T, R, AutoMath>(rows, dims, &matrix, &mut result);
+
+        let mut expected = vec![AutoMath::zero(); rows * rows];
+        crate::danger::generic_dot_matrix::<T, R, AutoMath>(
+            dims,
+            rows,
+            rows,
+            &matrix,
+            &matrix,
+            &mut expected,
+        );
+        assert_eq!(result, expected, "value mismatch");
+    }
+}
@@ -0,0 +1,214 @@
+use crate::danger::core_simd_api::SimdHammingRegister;
+
+#[inline(always)]
+/// A generic Hamming distance implementation over two bit-packed vectors of `words`
+/// `u64` values, counting the number of bits which differ between `a` and `b`.
+///
+/// The distance is accumulated as `u64` values, then truncated to a `u32` on return,
+/// which only wraps for vectors of more than `2³²` bits.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+///
+/// # Panics
+///
+/// If the lengths of `a` and `b` are not equal to `words`.
+pub unsafe fn generic_binary_hamming_distance<R>(
+    words: usize,
+    a: &[u64],
+    b: &[u64],
+) -> u32
+where
+    R: SimdHammingRegister,
+{
+    assert_eq!(
+        a.len(),
+        words,
+        "Buffer `a` does not match the number of words"
+    );
+    assert_eq!(
+        b.len(),
+        words,
+        "Buffer `b` does not match the number of words"
+    );
+
+    let a = a.as_ptr();
+    let b = b.as_ptr();
+
+    let offset_from = words % R::words_per_dense();
+
+    let mut total = R::zeroed_acc_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (words - offset_from) {
+        let l1 = R::load_words_dense(a.add(i));
+        let l2 = R::load_words_dense(b.add(i));
+        total = R::xor_popcount_acc_dense(l1, l2, total);
+
+        i += R::words_per_dense();
+    }
+
+    let mut total = R::sum_acc_to_register(total);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::words_per_lane();
+    while i < (words - offset_from) {
+        let l1 = R::load_words(a.add(i));
+        let l2 = R::load_words(b.add(i));
+        total = R::xor_popcount_acc(l1, l2, total);
+
+        i += R::words_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_acc_to_value(total);
+
+    while i < words {
+        let diff = a.add(i).read() ^ b.add(i).read();
+        total += diff.count_ones() as u64;
+
+        i += 1;
+    }
+
+    total as u32
+}
+
+#[inline(always)]
+/// A generic Jaccard similarity implementation over two bit-packed vectors of `words`
+/// `u64` values, computing `popcount(a & b) / popcount(a | b)` in a single pass.
+///
+/// If both `a` and `b` have no bits set, i.e. they are identical, the similarity
+/// is `1.0`.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+///
+/// # Panics
+///
+/// If the lengths of `a` and `b` are not equal to `words`.
+pub unsafe fn generic_binary_jaccard_similarity<R>(
+    words: usize,
+    a: &[u64],
+    b: &[u64],
+) -> f32
+where
+    R: SimdHammingRegister,
+{
+    assert_eq!(
+        a.len(),
+        words,
+        "Buffer `a` does not match the number of words"
+    );
+    assert_eq!(
+        b.len(),
+        words,
+        "Buffer `b` does not match the number of words"
+    );
+
+    let a = a.as_ptr();
+    let b = b.as_ptr();
+
+    let offset_from = words % R::words_per_dense();
+
+    let mut intersection = R::zeroed_acc_dense();
+    let mut union = R::zeroed_acc_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (words - offset_from) {
+        let l1 = R::load_words_dense(a.add(i));
+        let l2 = R::load_words_dense(b.add(i));
+        intersection = R::and_popcount_acc_dense(l1, l2, intersection);
+        union = R::or_popcount_acc_dense(l1, l2, union);
+
+        i += R::words_per_dense();
+    }
+
+    let mut intersection = R::sum_acc_to_register(intersection);
+    let mut union = R::sum_acc_to_register(union);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::words_per_lane();
+    while i < (words - offset_from) {
+        let l1 = R::load_words(a.add(i));
+        let l2 = R::load_words(b.add(i));
+        intersection = R::and_popcount_acc(l1, l2, intersection);
+        union = R::or_popcount_acc(l1, l2, union);
+
+        i += R::words_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut intersection = R::sum_acc_to_value(intersection);
+    let mut union = R::sum_acc_to_value(union);
+
+    while i < words {
+        let l1 = a.add(i).read();
+        let l2 = b.add(i).read();
+        intersection += (l1 & l2).count_ones() as u64;
+        union += (l1 | l2).count_ones() as u64;
+
+        i += 1;
+    }
+
+    if union == 0 {
+        return 1.0;
+    }
+
+    (intersection as f64 / union as f64) as f32
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_binary_hamming_distance<R>(l1: Vec<u64>, l2: Vec<u64>)
+where
+    R: SimdHammingRegister,
+{
+    let value = generic_binary_hamming_distance::<R>(l1.len(), &l1, &l2);
+    let expected_value = l1
+        .iter()
+        .zip(&l2)
+        .map(|(a, b)| (a ^ b).count_ones())
+        .sum::<u32>();
+    assert_eq!(value, expected_value, "value missmatch");
+
+    let ones = vec![u64::MAX; l1.len()];
+    let zeroes = vec![0; l1.len()];
+    let value = generic_binary_hamming_distance::<R>(l1.len(), &ones, &zeroes);
+    assert_eq!(value, l1.len() as u32 * 64, "every bit should differ");
+
+    let value = generic_binary_hamming_distance::<R>(l1.len(), &l1, &l1);
+    assert_eq!(value, 0, "identical vectors should have no distance");
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_binary_jaccard_similarity<R>(l1: Vec<u64>, l2: Vec<u64>)
+where
+    R: SimdHammingRegister,
+{
+    let value = generic_binary_jaccard_similarity::<R>(l1.len(), &l1, &l2);
+    let (intersection, union) =
+        l1.iter()
+            .zip(&l2)
+            .fold((0u64, 0u64), |(inter, union), (a, b)| {
+                (
+                    inter + (a & b).count_ones() as u64,
+                    union + (a | b).count_ones() as u64,
+                )
+            });
+    let expected_value = (intersection as f64 / union as f64) as f32;
+    assert_eq!(value, expected_value, "value missmatch");
+
+    let ones = vec![u64::MAX; l1.len()];
+    let zeroes = vec![0; l1.len()];
+    let value = generic_binary_jaccard_similarity::<R>(l1.len(), &ones, &zeroes);
+    assert_eq!(value, 0.0, "disjoint vectors should have no similarity");
+
+    let value = generic_binary_jaccard_similarity::<R>(l1.len(), &l1, &l1);
+    assert_eq!(value, 1.0, "identical vectors should be entirely similar");
+
+    let value = generic_binary_jaccard_similarity::<R>(l1.len(), &zeroes, &zeroes);
+    assert_eq!(value, 1.0, "empty vectors should be entirely similar");
+}
@@ -0,0 +1,98 @@
+use super::core_routine_boilerplate::apply_vertical_kernel;
+use super::core_simd_api::SimdFloatRegister;
+use crate::buffer::WriteOnlyBuffer;
+use crate::math::Math;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+#[inline(always)]
+/// A generic vector hypotenuse implementation computing `sqrt(a² + b²)` for each
+/// element without undue overflow or underflow.
+///
+/// # Safety
+///
+/// The sizes of `a`, `b` and `result` must be equal to `dims`, the safety requirements of
+/// `M` definition the basic math operations and the requirements of `R` SIMD register
+/// must also be followed.
+pub unsafe fn generic_hypot_vertical<T, R, M, B1, B2, B3>(
+    a: B1,
+    b: B2,
+    result: &mut [B3],
+) where
+    T: Copy,
+    R: SimdFloatRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    apply_vertical_kernel::<T, R, M, B1, B2, B3>(
+        a,
+        b,
+        result,
+        R::hypot_dense,
+        R::hypot,
+        M::hypot,
+    )
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+    use crate::math::AutoMath;
+
+    pub(crate) unsafe fn test_hypot<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy + Debug,
+        R: SimdFloatRegister<T>,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let dims = l1.len();
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_hypot_vertical::<T, R, AutoMath, _, _, _>(&l1, &l2, &mut result);
+
+        for ((a, b), value) in l1.iter().zip(l2.iter()).zip(result) {
+            let expected = AutoMath::sqrt(AutoMath::add(
+                AutoMath::mul(*a, *a),
+                AutoMath::mul(*b, *b),
+            ));
+            assert!(
+                AutoMath::is_close(value, expected),
+                "value missmatch {value:?} vs {expected:?}"
+            );
+        }
+    }
+
+    pub(crate) unsafe fn test_hypot_special<T, R>(
+        l1: Vec<T>,
+        l2: Vec<T>,
+        expected: Vec<T>,
+    ) where
+        T: Copy + Debug,
+        R: SimdFloatRegister<T>,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let dims = l1.len();
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_hypot_vertical::<T, R, AutoMath, _, _, _>(&l1, &l2, &mut result);
+
+        for (value, expected) in result.into_iter().zip(expected) {
+            if AutoMath::cmp_eq(expected, expected) {
+                assert!(
+                    AutoMath::cmp_eq(value, expected),
+                    "value missmatch {value:?} vs {expected:?}"
+                );
+            } else {
+                assert!(
+                    !AutoMath::cmp_eq(value, value),
+                    "expected NaN got {value:?}"
+                );
+            }
+        }
+    }
+}
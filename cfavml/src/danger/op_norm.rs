@@ -1,6 +1,8 @@
-use crate::danger::core_simd_api::SimdRegister;
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::core_simd_api::{SimdRegister, SimdWidenRegister};
+use crate::danger::{generic_div_inplace, generic_div_vertical};
 use crate::math::Math;
-use crate::mem_loader::{IntoMemLoader, MemLoader};
+use crate::mem_loader::{BroadcastLoader, IntoMemLoader, MemLoader};
 
 #[inline(always)]
 /// A generic squared norm implementation over a vectors of a given set of dimensions.
@@ -58,6 +60,127 @@ where
     total
 }
 
+#[inline(always)]
+/// A generic squared norm implementation over an `f32` vector with each element
+/// widened and accumulated as an `f64`.
+///
+/// This is slower than [generic_squared_norm] as each register holds half as many
+/// elements, but avoids the precision loss of accumulating large vectors in `f32`.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+pub unsafe fn generic_squared_norm_f32_f64acc<R>(a: &[f32]) -> f64
+where
+    R: SimdWidenRegister<f32, f64>,
+{
+    let len = a.len();
+    let a = a.as_ptr();
+
+    let offset_from = len % R::elements_per_dense();
+
+    let mut total = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_widened_dense(a.add(i));
+        total = R::fmadd_dense(l1, l1, total);
+
+        i += R::elements_per_dense();
+    }
+
+    let mut total = R::sum_to_register(total);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load_widened(a.add(i));
+        total = R::fmadd(l1, l1, total);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+
+    while i < len {
+        let a = a.add(i).read() as f64;
+        total += a * a;
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// A generic L2 normalization implementation, dividing each element of `a` by the
+/// L2 norm of `a` and writing the result to `result`.
+///
+/// This is a two pass approach, first computing the norm via [generic_squared_norm]
+/// then dividing `a` by it. If the norm is zero `result` is filled with zeroes.
+///
+/// # Panics
+///
+/// If vectors `a` and `result` are not exactly `dims` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_l2_normalize_vertical<T, R, M>(
+    dims: usize,
+    a: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+{
+    assert_eq!(a.len(), dims, "Vector `a` does not match size `dims`");
+    assert_eq!(
+        result.len(),
+        dims,
+        "Vector `result` does not match size `dims`"
+    );
+
+    let norm = M::sqrt(generic_squared_norm::<T, R, M, _>(a));
+    if M::cmp_eq(norm, M::zero()) {
+        result.fill(M::zero());
+        return;
+    }
+
+    generic_div_vertical::<T, R, M, _, _, T>(a, BroadcastLoader::new(norm, dims), result)
+}
+
+#[inline(always)]
+/// A generic in-place L2 normalization implementation, dividing each element of `a`
+/// by the L2 norm of `a`.
+///
+/// If the norm is zero `a` is filled with zeroes.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_l2_normalize_inplace<T, R, M>(a: &mut [T])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let norm = M::sqrt(generic_squared_norm::<T, R, M, _>(&*a));
+    if M::cmp_eq(norm, M::zero()) {
+        a.fill(M::zero());
+        return;
+    }
+
+    let dims = a.len();
+    generic_div_inplace::<T, R, M, _>(a, BroadcastLoader::new(norm, dims))
+}
+
 #[cfg(test)]
 pub(crate) unsafe fn test_squared_norm<T, R>(l1: Vec<T>)
 where
@@ -74,3 +197,81 @@ where
         "value missmatch {value:?} vs {expected_value:?}"
     );
 }
+
+#[cfg(test)]
+pub(crate) unsafe fn test_squared_norm_f32_f64acc<R>(l1: Vec<f32>)
+where
+    R: SimdWidenRegister<f32, f64> + SimdRegister<f32>,
+{
+    use crate::math::{AutoMath, StdMath};
+
+    let value = generic_squared_norm_f32_f64acc::<R>(&l1);
+    let expected_value = l1
+        .iter()
+        .fold(0.0, |acc, v| acc + (*v as f64) * (*v as f64));
+    assert!(
+        StdMath::is_close(value, expected_value),
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+
+    // One large value followed by two small values, the small values are lost
+    // entirely when accumulated in `f32`.
+    let l1 = (0..l1.len())
+        .map(|i| if i % 3 == 0 { 1.0e2 } else { 1.0e-2 })
+        .collect::<Vec<f32>>();
+    let expected_value = l1.iter().map(|v| (*v as f64) * (*v as f64)).sum::<f64>();
+
+    let value = generic_squared_norm_f32_f64acc::<R>(&l1);
+    let error = (value - expected_value).abs();
+    assert!(
+        error <= expected_value * 1e-12,
+        "value should match the f64 reference {value:?} vs {expected_value:?}"
+    );
+    let f32_value = generic_squared_norm::<f32, R, AutoMath, _>(&l1) as f64;
+    assert!(
+        error < (f32_value - expected_value).abs(),
+        "f64 accumulation should be more precise than f32 {value:?} vs {f32_value:?}"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_l2_normalize<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + std::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+    for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+{
+    use crate::math::AutoMath;
+
+    let norm = AutoMath::sqrt(crate::test_utils::simple_dot(&l1, &l1));
+    let expected = l1
+        .iter()
+        .map(|v| AutoMath::div(*v, norm))
+        .collect::<Vec<T>>();
+
+    let mut result = vec![AutoMath::one(); l1.len()];
+    generic_l2_normalize_vertical::<T, R, AutoMath>(l1.len(), &l1, &mut result);
+    for (value, expected) in result.iter().zip(expected.iter()) {
+        assert!(
+            AutoMath::is_close(*value, *expected),
+            "value missmatch {value:?} vs {expected:?}"
+        );
+    }
+
+    let mut inplace = l1.clone();
+    generic_l2_normalize_inplace::<T, R, AutoMath>(&mut inplace);
+    assert_eq!(
+        inplace, result,
+        "in-place result should match out-of-place result"
+    );
+
+    let zeroes = vec![AutoMath::zero(); l1.len()];
+    let mut result = vec![AutoMath::one(); l1.len()];
+    generic_l2_normalize_vertical::<T, R, AutoMath>(l1.len(), &zeroes, &mut result);
+    assert_eq!(result, zeroes, "zero norm should produce a zero vector");
+
+    let mut inplace = zeroes.clone();
+    generic_l2_normalize_inplace::<T, R, AutoMath>(&mut inplace);
+    assert_eq!(inplace, zeroes, "zero norm should produce a zero vector");
+}
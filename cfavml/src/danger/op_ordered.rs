@@ -0,0 +1,211 @@
+//! Reductions with a fixed accumulation order, producing bit-identical results
+//! regardless of the SIMD register used.
+//!
+//! The regular reductions accumulate into as many lanes as the register provides before
+//! summing the lanes together, so the order floats are added in, and therefore the
+//! rounding of the result, differs between `Fallback`, `Avx2`, `Avx512` and `Neon`.
+//!
+//! These routines instead always accumulate into [ORDERED_LANES] virtual lanes, element
+//! `i` being added to lane `i % ORDERED_LANES` in order, regardless of how many
+//! registers those lanes are spread across. The products are never fused with the
+//! accumulation, and once accumulated the lanes are combined pairwise in a fixed tree:
+//!
+//! ```ignore
+//! lanes = [l0, l1, l2, l3, ...]
+//! lanes = [l0 + l1, l2 + l3, ...]
+//! ...
+//! return lanes[0]
+//! ```
+//!
+//! Every register whose number of elements divides [ORDERED_LANES] therefore performs
+//! exactly the same sequence of operations, the only exception being `Fallback` with
+//! the `nightly` feature enabled, where the compiler is allowed to reassociate the
+//! fast-math operations.
+
+use crate::danger::core_simd_api::SimdRegister;
+use crate::math::Math;
+
+/// The number of virtual lanes the values are accumulated into.
+///
+/// This is the number of `u8` elements in an AVX512 register, the widest register
+/// supported, so every register evenly divides it.
+pub const ORDERED_LANES: usize = 64;
+
+#[inline(always)]
+/// A generic dot product of `a` and `b` accumulated in a fixed order, producing the
+/// same result for every SIMD register `R`.
+///
+/// # Panics
+///
+/// If vectors `a` and `b` are not equal in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_dot_ordered<T, R, M>(a: &[T], b: &[T]) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+    ordered_reduce::<T, R, M>(
+        a.len(),
+        a.as_ptr(),
+        b.as_ptr(),
+        |l1, l2| R::mul(l1, l2),
+        |a, b| M::mul(a, b),
+    )
+}
+
+#[inline(always)]
+/// A generic squared norm of `a` accumulated in a fixed order, producing the
+/// same result for every SIMD register `R`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_squared_norm_ordered<T, R, M>(a: &[T]) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    ordered_reduce::<T, R, M>(
+        a.len(),
+        a.as_ptr(),
+        a.as_ptr(),
+        |l1, _| R::mul(l1, l1),
+        |a, _| M::mul(a, a),
+    )
+}
+
+#[inline(always)]
+/// A generic horizontal sum of `a` accumulated in a fixed order, producing the
+/// same result for every SIMD register `R`.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_sum_ordered<T, R, M>(a: &[T]) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    ordered_reduce::<T, R, M>(a.len(), a.as_ptr(), a.as_ptr(), |l1, _| l1, |a, _| a)
+}
+
+#[inline(always)]
+/// Accumulates `term(a[i], b[i])` into lane `i % ORDERED_LANES`, then combines the
+/// lanes pairwise.
+///
+/// `reg_term` and `value_term` must perform the same operation on a register
+/// and a single value respectively.
+unsafe fn ordered_reduce<T, R, M>(
+    len: usize,
+    a: *const T,
+    b: *const T,
+    reg_term: impl Fn(R::Register, R::Register) -> R::Register,
+    value_term: impl Fn(T, T) -> T,
+) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    let registers_per_block = ORDERED_LANES / R::elements_per_lane();
+    debug_assert_eq!(
+        registers_per_block * R::elements_per_lane(),
+        ORDERED_LANES,
+        "register must evenly divide the ordered lanes",
+    );
+
+    let mut acc = [R::zeroed(); ORDERED_LANES];
+    let acc = &mut acc[..registers_per_block];
+
+    let offset_from = len % ORDERED_LANES;
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for (n, acc) in acc.iter_mut().enumerate() {
+            let offset = i + (n * R::elements_per_lane());
+            let l1 = R::load(a.add(offset));
+            let l2 = R::load(b.add(offset));
+            *acc = R::add(*acc, reg_term(l1, l2));
+        }
+
+        i += ORDERED_LANES;
+    }
+
+    let mut lanes = [M::zero(); ORDERED_LANES];
+    for (n, acc) in acc.iter().enumerate() {
+        R::write(lanes.as_mut_ptr().add(n * R::elements_per_lane()), *acc);
+    }
+
+    // The remainder lines up with the start of the lanes.
+    for (lane, j) in lanes.iter_mut().zip(i..len) {
+        *lane = M::add(*lane, value_term(a.add(j).read(), b.add(j).read()));
+    }
+
+    let mut width = ORDERED_LANES;
+    while width > 1 {
+        width /= 2;
+        for n in 0..width {
+            lanes[n] = M::add(lanes[n * 2], lanes[(n * 2) + 1]);
+        }
+    }
+
+    lanes[0]
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+
+    /// The plain scalar equivalent of [ordered_reduce].
+    fn reference_reduce<T>(values: impl Iterator<Item = T>) -> T
+    where
+        T: Copy,
+        AutoMath: Math<T>,
+    {
+        let mut lanes = [AutoMath::zero(); ORDERED_LANES];
+        for (i, value) in values.enumerate() {
+            let lane = &mut lanes[i % ORDERED_LANES];
+            *lane = AutoMath::add(*lane, value);
+        }
+
+        let mut lanes = lanes.to_vec();
+        while lanes.len() > 1 {
+            lanes = lanes
+                .chunks(2)
+                .map(|pair| AutoMath::add(pair[0], pair[1]))
+                .collect();
+        }
+        lanes[0]
+    }
+
+    pub(crate) unsafe fn test_ordered<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+    {
+        let value = generic_dot_ordered::<T, R, AutoMath>(&l1, &l2);
+        let products = l1.iter().zip(&l2).map(|(a, b)| AutoMath::mul(*a, *b));
+        let expected = reference_reduce(products);
+        assert_eq!(value, expected, "dot value missmatch");
+
+        let value = generic_squared_norm_ordered::<T, R, AutoMath>(&l1);
+        let expected = reference_reduce(l1.iter().map(|a| AutoMath::mul(*a, *a)));
+        assert_eq!(value, expected, "squared norm value missmatch");
+
+        let value = generic_sum_ordered::<T, R, AutoMath>(&l1);
+        let expected = reference_reduce(l1.iter().copied());
+        assert_eq!(value, expected, "sum value missmatch");
+    }
+}
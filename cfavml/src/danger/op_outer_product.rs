@@ -0,0 +1,110 @@
+use crate::danger::core_simd_api::SimdRegister;
+use crate::math::Math;
+
+#[inline(always)]
+/// A generic outer product of the `n` element vector `a` and the `m` element vector `b`,
+/// writing the row-major `n x m` matrix to `result`.
+///
+/// Each element of `a` is broadcast to a register and multiplied against all of `b`,
+/// filling one row of `result` at a time.
+///
+/// # Panics
+///
+/// If `a` is not exactly `n` elements in length, `b` is not exactly `m` elements
+/// in length or `result` is not exactly `n * m` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_outer_product<T, R, M>(
+    n: usize,
+    m: usize,
+    a: &[T],
+    b: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+{
+    assert_eq!(
+        a.len(),
+        n,
+        "Buffer `a` must be exactly `n` elements in length"
+    );
+    assert_eq!(
+        b.len(),
+        m,
+        "Buffer `b` must be exactly `m` elements in length"
+    );
+    assert_eq!(
+        result.len(),
+        n * m,
+        "Buffer `result` must be exactly `n * m` elements in length"
+    );
+
+    let offset_from_dense = m % R::elements_per_dense();
+    let offset_from_lane = m % R::elements_per_lane();
+
+    let b_ptr = b.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    for (i, value) in a.iter().copied().enumerate() {
+        let row = result_ptr.add(i * m);
+
+        let scale_dense = R::filled_dense(value);
+        let mut j = 0;
+        while j < (m - offset_from_dense) {
+            let l2 = R::load_dense(b_ptr.add(j));
+            R::write_dense(row.add(j), R::mul_dense(scale_dense, l2));
+
+            j += R::elements_per_dense();
+        }
+
+        let scale = R::filled(value);
+        while j < (m - offset_from_lane) {
+            let l2 = R::load(b_ptr.add(j));
+            R::write(row.add(j), R::mul(scale, l2));
+
+            j += R::elements_per_lane();
+        }
+
+        while j < m {
+            row.add(j).write(M::mul(value, b_ptr.add(j).read()));
+
+            j += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+
+    pub(crate) unsafe fn test_outer_product<T, R>(n: usize, m: usize)
+    where
+        T: Copy + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let (a, _) = crate::test_utils::get_sample_vectors::<T>(n);
+        let (_, b) = crate::test_utils::get_sample_vectors::<T>(m);
+
+        let mut result = vec![AutoMath::zero(); n * m];
+        generic_outer_product::<T, R, AutoMath>(n, m, &a, &b, &mut result);
+
+        for i in 0..n {
+            for j in 0..m {
+                let expected = AutoMath::mul(a[i], b[j]);
+                let value = result[i * m + j];
+                assert!(
+                    AutoMath::is_close(value, expected),
+                    "value mismatch at ({i}, {j}) {value:?} vs {expected:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Prefix (running) operations over a vector.
+//!
+//! Each register is first scanned within itself using [SimdRegister::prefix_max] or
+//! [SimdRegister::prefix_min], then combined with the carry of all previous registers,
+//! the last element of the result then becomes the carry for the next register.
+
+use super::core_simd_api::SimdRegister;
+use crate::buffer::WriteOnlyBuffer;
+use crate::math::Math;
+
+#[inline(always)]
+/// A generic cumulative max implementation, writing the running max of `a` to `result`
+/// where `result[i] = max(a[0..=i])`.
+///
+/// # Panics
+///
+/// If `a` and `result` are not exactly `dims` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cumulative_max<T, R, M, B>(dims: usize, a: &[T], result: &mut [B])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    generic_cumulative::<T, R, B>(
+        dims,
+        a,
+        result,
+        M::min(),
+        R::prefix_max,
+        R::max,
+        R::max_to_value,
+        M::cmp_max,
+    )
+}
+
+#[inline(always)]
+/// A generic cumulative min implementation, writing the running min of `a` to `result`
+/// where `result[i] = min(a[0..=i])`.
+///
+/// # Panics
+///
+/// If `a` and `result` are not exactly `dims` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_cumulative_min<T, R, M, B>(dims: usize, a: &[T], result: &mut [B])
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    generic_cumulative::<T, R, B>(
+        dims,
+        a,
+        result,
+        M::max(),
+        R::prefix_min,
+        R::min,
+        R::min_to_value,
+        M::cmp_min,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+unsafe fn generic_cumulative<T, R, B>(
+    dims: usize,
+    a: &[T],
+    mut result: &mut [B],
+    identity: T,
+    prefix_op: unsafe fn(R::Register) -> R::Register,
+    op: unsafe fn(R::Register, R::Register) -> R::Register,
+    reduce_op: unsafe fn(R::Register) -> T,
+    scalar_op: fn(T, T) -> T,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    assert_eq!(
+        a.len(),
+        dims,
+        "Buffer `a` must be exactly `dims` elements in length"
+    );
+    assert_eq!(
+        result.raw_buffer_len(),
+        dims,
+        "Buffer `result` must be exactly `dims` elements in length"
+    );
+
+    let a_ptr = a.as_ptr();
+    let result_ptr = result.as_write_only_ptr();
+
+    let offset_from = dims % R::elements_per_lane();
+
+    let mut carry = identity;
+    let mut i = 0;
+    while i < (dims - offset_from) {
+        let scanned = prefix_op(R::load(a_ptr.add(i)));
+        let scanned = op(scanned, R::filled(carry));
+        R::write(result_ptr.add(i), scanned);
+
+        // The running value is monotonic, so the reduction of the register
+        // is its last element.
+        carry = reduce_op(scanned);
+        i += R::elements_per_lane();
+    }
+
+    while i < dims {
+        carry = scalar_op(carry, *a.get_unchecked(i));
+        result.write_at(i, carry);
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_cumulative<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialOrd + std::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+    for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+{
+    use crate::math::AutoMath;
+
+    for dims in [0, 1, 7, 67, l1.len()] {
+        let a = &l1[..dims];
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_cumulative_max::<T, R, AutoMath, _>(dims, a, &mut result);
+        let expected = a
+            .iter()
+            .scan(AutoMath::min(), |acc, &v| {
+                *acc = AutoMath::cmp_max(*acc, v);
+                Some(*acc)
+            })
+            .collect::<Vec<T>>();
+        assert_eq!(result, expected, "cumulative max mismatch with dims {dims}");
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_cumulative_min::<T, R, AutoMath, _>(dims, a, &mut result);
+        let expected = a
+            .iter()
+            .scan(AutoMath::max(), |acc, &v| {
+                *acc = AutoMath::cmp_min(*acc, v);
+                Some(*acc)
+            })
+            .collect::<Vec<T>>();
+        assert_eq!(result, expected, "cumulative min mismatch with dims {dims}");
+    }
+
+    // Sorted inputs change the running value on every element, exercising every carry.
+    let mut sorted = l1.clone();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut result = vec![AutoMath::zero(); sorted.len()];
+    generic_cumulative_max::<T, R, AutoMath, _>(sorted.len(), &sorted, &mut result);
+    assert_eq!(result, sorted, "cumulative max of sorted input");
+    sorted.reverse();
+    generic_cumulative_min::<T, R, AutoMath, _>(sorted.len(), &sorted, &mut result);
+    assert_eq!(result, sorted, "cumulative min of sorted input");
+}
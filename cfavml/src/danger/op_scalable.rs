@@ -0,0 +1,126 @@
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::core_simd_api::SimdScalableRegister;
+
+#[inline(always)]
+/// A generic dot product implementation over two vectors using a scalable register.
+///
+/// Four independent accumulators are used to hide the latency of each FMA, the tail of
+/// the vectors is handled with a partial predicate rather than a scalar loop.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+///
+/// # Panics
+///
+/// If the lengths of `a` and `b` do not match.
+pub unsafe fn generic_scalable_dot<T, R>(a: &[T], b: &[T]) -> T
+where
+    T: Copy,
+    R: SimdScalableRegister<T>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+    let len = a.len();
+    let a = a.as_ptr();
+    let b = b.as_ptr();
+
+    let step = R::elements_per_lane();
+    let all = R::predicate_all();
+
+    let mut acc1 = R::zeroed();
+    let mut acc2 = R::zeroed();
+    let mut acc3 = R::zeroed();
+    let mut acc4 = R::zeroed();
+
+    // Operate over 4 full registers at a time first.
+    let mut i = 0;
+    while i + (step * 4) <= len {
+        let l1 = R::load(all, a.add(i));
+        let l2 = R::load(all, b.add(i));
+        acc1 = R::fmadd(all, l1, l2, acc1);
+
+        let l1 = R::load(all, a.add(i + step));
+        let l2 = R::load(all, b.add(i + step));
+        acc2 = R::fmadd(all, l1, l2, acc2);
+
+        let l1 = R::load(all, a.add(i + (step * 2)));
+        let l2 = R::load(all, b.add(i + (step * 2)));
+        acc3 = R::fmadd(all, l1, l2, acc3);
+
+        let l1 = R::load(all, a.add(i + (step * 3)));
+        let l2 = R::load(all, b.add(i + (step * 3)));
+        acc4 = R::fmadd(all, l1, l2, acc4);
+
+        i += step * 4;
+    }
+
+    // Handle the remainder, the last register is only partially active.
+    while i < len {
+        let pg = R::predicate_while_lt(i, len);
+        let l1 = R::load(pg, a.add(i));
+        let l2 = R::load(pg, b.add(i));
+        acc1 = R::fmadd(pg, l1, l2, acc1);
+
+        i += step;
+    }
+
+    acc1 = R::add(all, acc1, acc2);
+    acc3 = R::add(all, acc3, acc4);
+    R::sum_to_value(R::add(all, acc1, acc3))
+}
+
+#[inline(always)]
+/// A generic vector addition implementation over two vectors using a scalable register.
+///
+/// The tail of the vectors is handled with a partial predicate rather than a scalar loop.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+///
+/// # Panics
+///
+/// If the lengths of `a`, `b` and `result` do not match.
+pub unsafe fn generic_scalable_add_vertical<T, R, B>(
+    a: &[T],
+    b: &[T],
+    mut result: &mut [B],
+) where
+    T: Copy,
+    R: SimdScalableRegister<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+    assert_eq!(
+        a.len(),
+        result.raw_buffer_len(),
+        "Buffers `a` and `result` do not match in size"
+    );
+
+    let len = a.len();
+    let a = a.as_ptr();
+    let b = b.as_ptr();
+    let result = result.as_write_only_ptr();
+
+    let step = R::elements_per_lane();
+    let all = R::predicate_all();
+
+    // Operate over full registers first.
+    let mut i = 0;
+    while i + step <= len {
+        let l1 = R::load(all, a.add(i));
+        let l2 = R::load(all, b.add(i));
+        R::write(all, result.add(i), R::add(all, l1, l2));
+
+        i += step;
+    }
+
+    // Handle the remainder, the last register is only partially active.
+    if i < len {
+        let pg = R::predicate_while_lt(i, len);
+        let l1 = R::load(pg, a.add(i));
+        let l2 = R::load(pg, b.add(i));
+        R::write(pg, result.add(i), R::add(pg, l1, l2));
+    }
+}
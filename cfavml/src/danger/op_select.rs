@@ -0,0 +1,102 @@
+use super::core_routine_boilerplate::apply_ternary_vertical_kernel;
+use super::core_simd_api::SimdRegister;
+use crate::buffer::WriteOnlyBuffer;
+use crate::math::Math;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+#[inline(always)]
+/// A generic select implementation picking the element from `a` where the matching
+/// element in `mask` is non-zero, otherwise picking the element from `b`.
+///
+/// # Safety
+///
+/// The sizes of `mask`, `a`, `b` and `result` must be equal to `dims`, the safety
+/// requirements of `M` definition the basic math operations and the requirements
+/// of `R` SIMD register must also be followed.
+pub unsafe fn generic_select_vertical<T, R, M, B1, B2, B3, B4>(
+    mask: B1,
+    a: B2,
+    b: B3,
+    result: &mut [B4],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    B3: IntoMemLoader<T>,
+    B3::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B4]: WriteOnlyBuffer<Item = T>,
+{
+    apply_ternary_vertical_kernel::<T, R, M, B1, B2, B3, B4>(
+        mask,
+        a,
+        b,
+        result,
+        R::select_dense,
+        R::select,
+        |mask, a, b| {
+            if M::cmp_eq(mask, M::zero()) {
+                b
+            } else {
+                a
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::iter::zip;
+
+    use super::*;
+    use crate::danger::generic_cmp_gt_vertical;
+    use crate::math::AutoMath;
+
+    pub(crate) unsafe fn test_select<T, R>(l1: Vec<T>, l2: Vec<T>)
+    where
+        T: Copy + PartialEq + std::fmt::Debug,
+        R: SimdRegister<T>,
+        AutoMath: Math<T>,
+        for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+    {
+        let dims = l1.len();
+
+        let mut mask = vec![AutoMath::zero(); dims];
+        generic_cmp_gt_vertical::<T, R, AutoMath, _, _, _>(&l1, &l2, &mut mask);
+
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_select_vertical::<T, R, AutoMath, _, _, _, _>(
+            &mask,
+            &l1,
+            &l2,
+            &mut result,
+        );
+        let expected = zip(l1.iter(), l2.iter())
+            .map(|(a, b)| if AutoMath::cmp_gt(*a, *b) { *a } else { *b })
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected, "value missmatch on cmp mask");
+
+        // Any non-zero value in the mask selects from `a`, not just `1`.
+        let ones = vec![AutoMath::one(); dims];
+        let mut result = vec![AutoMath::zero(); dims];
+        generic_select_vertical::<T, R, AutoMath, _, _, _, _>(
+            &l1,
+            &ones,
+            &l2,
+            &mut result,
+        );
+        let expected = zip(l1.iter(), l2.iter())
+            .map(|(mask, b)| {
+                if AutoMath::cmp_eq(*mask, AutoMath::zero()) {
+                    *b
+                } else {
+                    AutoMath::one()
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(result, expected, "value missmatch on raw mask");
+    }
+}
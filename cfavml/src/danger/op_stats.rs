@@ -0,0 +1,227 @@
+//! Statistical routines over a vector, i.e. moving averages.
+
+use super::core_simd_api::SimdRegister;
+use crate::buffer::WriteOnlyBuffer;
+use crate::math::{CastAs, Math, WindowSum};
+
+/// Windows up to this size are summed directly, as summing the window is cheaper than
+/// the running sum for only a handful of elements.
+const DIRECT_SUM_MAX_WINDOW: usize = 4;
+
+#[inline(always)]
+/// A generic moving average implementation, writing the mean of each `window` sized
+/// window of `a` to `result` where `result[i] = sum(a[i..i + window]) / window`.
+///
+/// `result` holds one element per window, `dims - window + 1` elements or no elements
+/// if `window` is larger than `dims`.
+///
+/// Small windows are summed directly, otherwise the sum of each window is computed from
+/// the sum of the previous window, `sum[i] = sum[i - 1] + a[i + window - 1] - a[i - 1]`.
+/// The differences are computed a register at a time and combined with
+/// [SimdRegister::prefix_sum], avoiding both the `O(dims * window)` cost of summing each
+/// window and materializing the prefix sum of `a`.
+///
+/// For the float types, the running sum accumulates rounding error over long series.
+/// For the integer types, the running sum is held in the wider [WindowSum::Sum] type so
+/// the sum of a window cannot overflow, the results are exact for any window whose mean
+/// fits within `T`. The wider type has no register equivalent, so the running sum is kept
+/// as a scalar for the integer types.
+///
+/// # Panics
+///
+/// If `window` is zero, `a` is not exactly `dims` elements in length or `result` is not
+/// exactly `dims - window + 1` elements in length.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and
+/// the requirements of `R` SIMD register must also be followed.
+pub unsafe fn generic_moving_average<T, R, M, B>(
+    dims: usize,
+    window: usize,
+    a: &[T],
+    mut result: &mut [B],
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    T: WindowSum,
+    usize: CastAs<T>,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    assert_ne!(
+        window, 0,
+        "Moving average `window` must be greater than zero"
+    );
+    assert_eq!(
+        a.len(),
+        dims,
+        "Buffer `a` must be exactly `dims` elements in length"
+    );
+    let num_windows = (dims + 1).saturating_sub(window);
+    assert_eq!(
+        result.raw_buffer_len(),
+        num_windows,
+        "Buffer `result` must be exactly `dims - window + 1` elements in length"
+    );
+
+    if num_windows == 0 {
+        return;
+    }
+
+    if T::WIDENED {
+        let mut total = T::empty_sum();
+        for k in 0..window {
+            total = a.get_unchecked(k).add_to_sum(total);
+        }
+        result.write_at(0, T::window_mean(total, window));
+
+        for i in 1..num_windows {
+            total = a.get_unchecked(i + window - 1).add_to_sum(total);
+            total = a.get_unchecked(i - 1).sub_from_sum(total);
+            result.write_at(i, T::window_mean(total, window));
+        }
+
+        return;
+    }
+
+    let a_ptr = a.as_ptr();
+    let result_ptr = result.as_write_only_ptr();
+
+    let window_value: T = window.cast_as();
+    let window_reg = R::filled(window_value);
+
+    let offset_from = num_windows % R::elements_per_lane();
+
+    if window <= DIRECT_SUM_MAX_WINDOW {
+        let mut i = 0;
+        while i < (num_windows - offset_from) {
+            let mut total = R::load(a_ptr.add(i));
+            for k in 1..window {
+                total = R::add(total, R::load(a_ptr.add(i + k)));
+            }
+            R::write(result_ptr.add(i), R::div(total, window_reg));
+
+            i += R::elements_per_lane();
+        }
+
+        while i < num_windows {
+            let mut total = *a.get_unchecked(i);
+            for k in 1..window {
+                total = M::add(total, *a.get_unchecked(i + k));
+            }
+            result.write_at(i, M::div(total, window_value));
+
+            i += 1;
+        }
+
+        return;
+    }
+
+    let mut total = M::zero();
+    for k in 0..window {
+        total = M::add(total, *a.get_unchecked(k));
+    }
+    result.write_at(0, M::div(total, window_value));
+
+    // The first window is already written, leaving `num_windows - 1` to compute.
+    let remaining = num_windows - 1;
+    let offset_from = remaining % R::elements_per_lane();
+
+    let mut i = 1;
+    while i < (1 + remaining - offset_from) {
+        let added = R::load(a_ptr.add(i + window - 1));
+        let removed = R::load(a_ptr.add(i - 1));
+        let diff = R::sub(added, removed);
+
+        let totals = R::add(R::prefix_sum(diff), R::filled(total));
+        R::write(result_ptr.add(i), R::div(totals, window_reg));
+
+        total = M::add(total, R::sum_to_value(diff));
+        i += R::elements_per_lane();
+    }
+
+    while i < num_windows {
+        let added = *a.get_unchecked(i + window - 1);
+        let removed = *a.get_unchecked(i - 1);
+        total = M::add(total, M::sub(added, removed));
+        result.write_at(i, M::div(total, window_value));
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_moving_average<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + std::fmt::Debug + WindowSum,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+    usize: CastAs<T>,
+    for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+{
+    use crate::math::AutoMath;
+
+    for dims in [0, 1, 7, 67, l1.len()] {
+        let a = &l1[..dims];
+
+        for window in [1, 2, 3, 5, 16, 67, 200] {
+            let num_windows = (dims + 1).saturating_sub(window);
+
+            let mut result = vec![AutoMath::zero(); num_windows];
+            generic_moving_average::<T, R, AutoMath, _>(dims, window, a, &mut result);
+
+            let expected = a
+                .windows(window)
+                .map(|values| {
+                    let total = values
+                        .iter()
+                        .fold(T::empty_sum(), |acc, &v| v.add_to_sum(acc));
+                    T::window_mean(total, window)
+                })
+                .collect::<Vec<T>>();
+            assert_eq!(result.len(), expected.len());
+            for (i, (&actual, &expected)) in result.iter().zip(&expected).enumerate() {
+                assert!(
+                    AutoMath::is_close(actual, expected),
+                    "value mismatch at {i} with dims {dims} and window {window}, \
+                    {actual:?} vs {expected:?}",
+                );
+            }
+        }
+    }
+
+    if T::WIDENED {
+        // The sum of each window overflows `T` while the mean of each window does not.
+        let a = (0..67)
+            .map(|i| {
+                if i % 3 == 0 {
+                    AutoMath::min()
+                } else {
+                    AutoMath::max()
+                }
+            })
+            .collect::<Vec<T>>();
+
+        for window in [1, 2, 3, 5, 16, 67] {
+            let mut result = vec![AutoMath::zero(); a.len() - window + 1];
+            generic_moving_average::<T, R, AutoMath, _>(
+                a.len(),
+                window,
+                &a,
+                &mut result,
+            );
+
+            let expected = a
+                .windows(window)
+                .map(|values| {
+                    let total = values
+                        .iter()
+                        .fold(T::empty_sum(), |acc, &v| v.add_to_sum(acc));
+                    T::window_mean(total, window)
+                })
+                .collect::<Vec<T>>();
+            assert_eq!(result, expected, "value mismatch with window {window}");
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::danger::core_simd_api::SimdRegister;
+use crate::danger::core_simd_api::{SimdRegister, SimdWidenRegister};
 use crate::math::Math;
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 
@@ -57,6 +57,165 @@ where
     sum
 }
 
+#[inline(always)]
+/// A generic horizontal sum implementation using Kahan compensated summation, this is
+/// slower than [generic_sum] but accumulates significantly less floating point error
+/// on large vectors.
+///
+/// Each register keeps a running compensation alongside the sum, tracking the low order
+/// bits lost by each addition which are then re-applied on the next addition.
+///
+/// NOTE:
+/// The compensation relies on floating point addition _not_ being associative, math
+/// implementations which allow the compiler to re-associate operations, i.e. `FastMath`,
+/// may optimize the compensation away entirely.
+///
+/// # Safety
+///
+/// The safety requirements of `M` definition the basic math operations and the
+/// requirements of `R` SIMD register must be followed.
+pub unsafe fn generic_kahan_sum<T, R, M, B1>(a: B1) -> T
+where
+    T: Copy,
+    R: SimdRegister<T>,
+    M: Math<T>,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    let mut a = a.into_mem_loader();
+
+    let len = a.projected_len();
+    let offset_from = len % R::elements_per_dense();
+
+    let mut sum = R::zeroed_dense();
+    let mut comp = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = a.load_dense::<R>();
+        kahan_add_register::<T, R>(&mut sum.a, &mut comp.a, l1.a);
+        kahan_add_register::<T, R>(&mut sum.b, &mut comp.b, l1.b);
+        kahan_add_register::<T, R>(&mut sum.c, &mut comp.c, l1.c);
+        kahan_add_register::<T, R>(&mut sum.d, &mut comp.d, l1.d);
+        kahan_add_register::<T, R>(&mut sum.e, &mut comp.e, l1.e);
+        kahan_add_register::<T, R>(&mut sum.f, &mut comp.f, l1.f);
+        kahan_add_register::<T, R>(&mut sum.g, &mut comp.g, l1.g);
+        kahan_add_register::<T, R>(&mut sum.h, &mut comp.h, l1.h);
+
+        i += R::elements_per_dense();
+    }
+
+    // Merge the dense lanes, carrying over the compensation of each lane.
+    let (mut total, mut total_comp) = (sum.a, comp.a);
+    for (lane_sum, lane_comp) in [
+        (sum.b, comp.b),
+        (sum.c, comp.c),
+        (sum.d, comp.d),
+        (sum.e, comp.e),
+        (sum.f, comp.f),
+        (sum.g, comp.g),
+        (sum.h, comp.h),
+    ] {
+        total_comp = R::add(total_comp, lane_comp);
+        kahan_add_register::<T, R>(&mut total, &mut total_comp, lane_sum);
+    }
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = a.load::<R>();
+        kahan_add_register::<T, R>(&mut total, &mut total_comp, l1);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total_comp = R::sum_to_value(total_comp);
+    let mut total = R::sum_to_value(total);
+
+    while i < len {
+        let y = M::sub(a.read(), total_comp);
+        let t = M::add(total, y);
+        total_comp = M::sub(M::sub(t, total), y);
+        total = t;
+
+        i += 1;
+    }
+
+    M::sub(total, total_comp)
+}
+
+#[inline(always)]
+/// A generic horizontal sum implementation over an `f32` vector with each element
+/// widened and accumulated as an `f64`.
+///
+/// This is slower than [generic_sum] as each register holds half as many elements,
+/// but avoids the precision loss of accumulating large vectors in `f32`.
+///
+/// # Safety
+///
+/// The safety requirements of the `R` SIMD register must be followed.
+pub unsafe fn generic_sum_f32_f64acc<R>(a: &[f32]) -> f64
+where
+    R: SimdWidenRegister<f32, f64>,
+{
+    let len = a.len();
+    let a = a.as_ptr();
+
+    let offset_from = len % R::elements_per_dense();
+
+    let mut total = R::zeroed_dense();
+
+    // Operate over dense lanes first.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let l1 = R::load_widened_dense(a.add(i));
+        total = R::add_dense(total, l1);
+
+        i += R::elements_per_dense();
+    }
+
+    let mut total = R::sum_to_register(total);
+
+    // Operate over single registers next.
+    let offset_from = offset_from % R::elements_per_lane();
+    while i < (len - offset_from) {
+        let l1 = R::load_widened(a.add(i));
+        total = R::add(total, l1);
+
+        i += R::elements_per_lane();
+    }
+
+    // Handle the remainder.
+    let mut total = R::sum_to_value(total);
+
+    while i < len {
+        total += a.add(i).read() as f64;
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// Performs a single Kahan summation step, adding `value` to `sum` and updating
+/// the running compensation `comp`.
+unsafe fn kahan_add_register<T, R>(
+    sum: &mut R::Register,
+    comp: &mut R::Register,
+    value: R::Register,
+) where
+    T: Copy,
+    R: SimdRegister<T>,
+{
+    let y = R::sub(value, *comp);
+    let t = R::add(*sum, y);
+    *comp = R::sub(R::sub(t, *sum), y);
+    *sum = t;
+}
+
 #[cfg(test)]
 pub(crate) unsafe fn test_sum<T, R>(l1: Vec<T>)
 where
@@ -75,3 +234,111 @@ where
         "value missmatch on horizontal {sum:?} vs {expected_sum:?}"
     );
 }
+
+#[cfg(test)]
+pub(crate) unsafe fn test_kahan_sum<T, R>(l1: Vec<T>)
+where
+    T: Copy + PartialEq + std::fmt::Debug,
+    R: SimdRegister<T>,
+    crate::math::AutoMath: Math<T>,
+{
+    use crate::math::AutoMath;
+
+    let sum = generic_kahan_sum::<T, R, AutoMath, _>(&l1);
+    let expected_sum = l1
+        .iter()
+        .fold(AutoMath::zero(), |a, b| AutoMath::add(a, *b));
+    assert!(
+        AutoMath::is_close(sum, expected_sum),
+        "value missmatch on kahan horizontal {sum:?} vs {expected_sum:?}"
+    );
+}
+
+#[cfg(test)]
+pub(crate) unsafe fn test_sum_f32_f64acc<R>(l1: Vec<f32>)
+where
+    R: SimdWidenRegister<f32, f64> + SimdRegister<f32>,
+{
+    use crate::math::{AutoMath, StdMath};
+
+    let value = generic_sum_f32_f64acc::<R>(&l1);
+    let expected_value = l1.iter().fold(0.0, |acc, v| acc + *v as f64);
+    assert!(
+        StdMath::is_close(value, expected_value),
+        "value missmatch {value:?} vs {expected_value:?}"
+    );
+
+    // One large value followed by two small values, the small values are lost
+    // entirely when accumulated in `f32`.
+    let l1 = (0..l1.len())
+        .map(|i| if i % 3 == 0 { 1.0e4 } else { 1.0e-3 })
+        .collect::<Vec<f32>>();
+    let expected_value = l1.iter().map(|v| *v as f64).sum::<f64>();
+
+    let value = generic_sum_f32_f64acc::<R>(&l1);
+    let error = (value - expected_value).abs();
+    assert!(
+        error <= expected_value * 1e-12,
+        "value should match the f64 reference {value:?} vs {expected_value:?}"
+    );
+    let f32_value = generic_sum::<f32, R, AutoMath, _>(&l1) as f64;
+    assert!(
+        error < (f32_value - expected_value).abs(),
+        "f64 accumulation should be more precise than f32 {value:?} vs {f32_value:?}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::Fallback;
+    use crate::math::StdMath;
+
+    /// Alternating large and small values, the small values are entirely lost when
+    /// naively added to the large running total in `f32`.
+    ///
+    /// The pattern repeats every 3 elements so each SIMD lane sees both large and
+    /// small values.
+    fn get_adversarial_vector() -> (Vec<f32>, f64) {
+        let values = (0..1_000_000)
+            .map(|i| if i % 3 == 0 { 1.0e4 } else { 1.0e-3 })
+            .collect::<Vec<f32>>();
+        let expected = values.iter().map(|v| *v as f64).sum::<f64>();
+        (values, expected)
+    }
+
+    unsafe fn check_kahan_accuracy<R: SimdRegister<f32>>() {
+        let (values, expected) = get_adversarial_vector();
+
+        let naive = generic_sum::<f32, R, StdMath, _>(&values) as f64;
+        let kahan = generic_kahan_sum::<f32, R, StdMath, _>(&values) as f64;
+
+        let naive_error = (naive - expected).abs();
+        let kahan_error = (kahan - expected).abs();
+        assert!(
+            kahan_error < naive_error,
+            "kahan error {kahan_error} should be less than naive error {naive_error}"
+        );
+        // Within a single `f32` ulp of the `f64` ground truth.
+        let ulp = (expected as f32).to_bits() + 1;
+        let ulp = (f32::from_bits(ulp) as f64) - (expected as f32) as f64;
+        assert!(
+            kahan_error <= ulp,
+            "kahan error {kahan_error} is larger than a single ulp {ulp}"
+        );
+    }
+
+    #[test]
+    fn test_fallback_kahan_sum_accuracy() {
+        unsafe { check_kahan_accuracy::<Fallback>() }
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    #[test]
+    fn test_avx2_kahan_sum_accuracy() {
+        unsafe { check_kahan_accuracy::<crate::danger::Avx2>() }
+    }
+}
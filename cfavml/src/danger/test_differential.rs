@@ -0,0 +1,1956 @@
+//! A property based differential test harness across the register implementations.
+//!
+//! Every op in the registry is run over randomly generated vectors with the `Fallback`
+//! register as the oracle and each register available to the target as the candidate.
+//! The vector lengths span `0..=257` so every tail and unrolled block boundary of
+//! the widest registers is crossed, and the values are mixed with the edge cases
+//! of each type (`NaN`, infinities, subnormals, `MIN` and `MAX`.)
+//!
+//! Failures are shrunk by proptest to the smallest vectors which still disagree.
+//!
+//! Every op is also run over each length from `0` to two dense lanes of the candidate
+//! register, covering each mix of dense lane, single register and scalar tail
+//! iterations. Those inputs are allocated to exactly their length so any read or write
+//! past the end of a slice is reported when the suite runs under Miri or AddressSanitizer.
+//!
+//! New register implementations only need to be added to the `differential_test!`
+//! invocations at the bottom of this file, along with the groups of ops behind the
+//! extension traits they implement for each type.
+//!
+//! Every routine exported by the `danger` module must be registered here, which is
+//! enforced by `test_every_export_is_registered`.
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::fmt::Debug;
+
+use proptest::prelude::*;
+use proptest::sample::Index;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::{Config, TestCaseError, TestRunner};
+
+use super::*;
+use crate::buffer::BufferElement;
+use crate::math::{AutoMath, CastAs, MaskCount, Math, SaturatingArithmetic, WindowSum};
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+const MAX_LEN: usize = 257;
+
+/// The exported routines which cannot be checked by the harness.
+///
+/// The scalable routines require a [SimdScalableRegister], which only the SVE register
+/// implements, so there is no `Fallback` oracle to check them against.
+const UNCHECKED: &[&str] = &["generic_scalable_add_vertical", "generic_scalable_dot"];
+
+/// The inputs to every op.
+#[derive(Debug, Clone)]
+struct Case<T> {
+    a: Vec<T>,
+    b: Vec<T>,
+    value: T,
+    /// Indices into `a`, used by the gather, scatter and indexed ops.
+    indices: Vec<u32>,
+}
+
+#[derive(Debug)]
+enum Output<O> {
+    Vector(Vec<O>),
+    Value(O),
+    Count(usize),
+    Bool(bool),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Check {
+    /// The results must be the same value, or both be `NaN`.
+    Exact,
+    /// The same as `Exact`, but `NaN` inputs are skipped, `max` and `min` do not define
+    /// which value is returned when one of them is `NaN` so it differs between registers.
+    ExactOrdered,
+    /// The results may differ by the reordering of a sum, the function returns the sum of
+    /// the absolute values of the terms which bounds that error.
+    Reduction(fn(&[f64], &[f64]) -> f64),
+    /// The results may differ by the given multiple of the machine epsilon relative to
+    /// the expected value, i.e. where a register fuses the operations of each element.
+    Relative(f64),
+    /// The results may differ by the tolerance returned by the function, for the ops
+    /// where the rounding error of a reduction is carried through a division.
+    Tolerance(fn(&[f64], &[f64], Precision) -> f64),
+}
+
+/// The precision of an output type.
+#[derive(Debug, Clone, Copy)]
+struct Precision {
+    /// The machine epsilon of the type.
+    epsilon: f64,
+    /// The smallest positive normal value of the type.
+    min_positive: f64,
+    /// The largest finite value of the type.
+    max: f64,
+}
+
+/// Runs an op on both registers, returning the difference between their outputs.
+type Run<T> = Box<dyn Fn(&Case<T>) -> Result<(), String>>;
+
+/// An op run on both the `Fallback` oracle and a candidate register.
+struct Op<T> {
+    name: &'static str,
+    run: Run<T>,
+}
+
+impl<T> Op<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+{
+    fn new<O: Value>(
+        name: &'static str,
+        check: Check,
+        oracle: unsafe fn(&Case<T>) -> Output<O>,
+        candidate: unsafe fn(&Case<T>) -> Output<O>,
+    ) -> Self {
+        let run = move |case: &Case<T>| {
+            let expected = unsafe { oracle(case) };
+            let actual = unsafe { candidate(case) };
+            check_output(check, case, actual, expected)
+        };
+
+        Self {
+            name,
+            run: Box::new(run),
+        }
+    }
+}
+
+/// Registers the op `$run` instantiated for both the `Fallback` oracle and the
+/// candidate register `R`.
+macro_rules! op {
+    ($check:expr, $run:ident::<$($t:ident),+>) => {
+        Op::new(
+            stringify!($run),
+            $check,
+            $run::<$($t,)+ Fallback>,
+            $run::<$($t,)+ R>,
+        )
+    };
+}
+
+/// A value output by an op.
+trait Value: Copy + Debug + Default + PartialEq + 'static {
+    const IS_FLOAT: bool;
+
+    fn is_nan(self) -> bool;
+
+    fn to_f64(self) -> f64;
+
+    fn precision() -> Precision;
+}
+
+/// A type the ops are run over.
+trait Element: Value + BufferElement + MaskCount + IntoMemLoader<Self>
+where
+    Self::Loader: MemLoader<Value = Self>,
+{
+    /// Generates the values of the type, mixing in the edge cases.
+    fn strategy() -> BoxedStrategy<Self>;
+}
+
+macro_rules! impl_float_element {
+    ($t:ident) => {
+        impl Value for $t {
+            const IS_FLOAT: bool = true;
+
+            fn is_nan(self) -> bool {
+                self.is_nan()
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn precision() -> Precision {
+                Precision {
+                    epsilon: $t::EPSILON as f64,
+                    min_positive: $t::MIN_POSITIVE as f64,
+                    max: $t::MAX as f64,
+                }
+            }
+        }
+
+        impl Element for $t {
+            fn strategy() -> BoxedStrategy<Self> {
+                prop_oneof![
+                    8 => -1000.0 as $t..1000.0,
+                    1 => any::<$t>(),
+                    1 => prop::sample::select(vec![
+                        $t::NAN,
+                        $t::INFINITY,
+                        $t::NEG_INFINITY,
+                        0.0,
+                        -0.0,
+                        $t::MIN,
+                        $t::MAX,
+                        $t::MIN_POSITIVE,
+                        $t::MIN_POSITIVE / 2.0,
+                        -$t::MIN_POSITIVE / 4.0,
+                    ]),
+                ]
+                .boxed()
+            }
+        }
+    };
+}
+
+macro_rules! impl_int_element {
+    ($t:ident) => {
+        impl Value for $t {
+            const IS_FLOAT: bool = false;
+
+            fn is_nan(self) -> bool {
+                false
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn precision() -> Precision {
+                Precision {
+                    epsilon: 0.0,
+                    min_positive: 0.0,
+                    max: $t::MAX as f64,
+                }
+            }
+        }
+
+        impl Element for $t {
+            fn strategy() -> BoxedStrategy<Self> {
+                prop_oneof![
+                    4 => any::<$t>(),
+                    4 => (-8i8..8).prop_map(|v| v as $t),
+                    1 => prop::sample::select(vec![$t::MIN, $t::MAX, $t::MIN.wrapping_add(1)]),
+                ]
+                .boxed()
+            }
+        }
+    };
+}
+
+impl_float_element!(f32);
+impl_float_element!(f64);
+impl_int_element!(i8);
+impl_int_element!(i16);
+impl_int_element!(i32);
+impl_int_element!(i64);
+impl_int_element!(u8);
+impl_int_element!(u16);
+impl_int_element!(u32);
+impl_int_element!(u64);
+
+fn case_strategy<T: Element>() -> impl Strategy<Value = Case<T>>
+where
+    T::Loader: MemLoader<Value = T>,
+{
+    (0..=MAX_LEN)
+        .prop_flat_map(|len| {
+            (
+                prop::collection::vec(T::strategy(), len),
+                prop::collection::vec(T::strategy(), len),
+                T::strategy(),
+                any::<Option<Index>>(),
+                prop::collection::vec(any::<Index>(), len),
+            )
+        })
+        .prop_map(|(a, b, value, index, indices)| {
+            // The comparisons against a value are only interesting if the value
+            // actually appears in the vector some of the time.
+            let value = match index {
+                Some(index) if !a.is_empty() => a[index.index(a.len())],
+                _ => value,
+            };
+            let indices = indices
+                .into_iter()
+                .map(|index| index.index(a.len()) as u32)
+                .collect();
+            Case {
+                a,
+                b,
+                value,
+                indices,
+            }
+        })
+}
+
+/// Splits `len` elements into `rows` vectors of `dims` elements for the batch and
+/// matrix ops, the row counts crossing the tiles of the batched kernels.
+fn shape(len: usize) -> (usize, usize) {
+    let rows = (len % 5 + 1).min(len);
+    (rows, len.checked_div(rows).unwrap_or(0))
+}
+
+/// Downcasts the case of an op which is only implemented for a single type.
+fn downcast<T: 'static, U: 'static>(case: &Case<T>) -> &Case<U> {
+    (case as &dyn Any)
+        .downcast_ref()
+        .expect("The op is not registered for this type")
+}
+
+/// Defines ops with the signature `(a, b, result)`, the extra bounds of the routine
+/// being given after the `where`.
+macro_rules! define_vertical_op {
+    ($name:ident, $op:ident $(, where $($bounds:tt)+)?) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+            $($($bounds)+)?
+        {
+            let mut result = vec![AutoMath::zero(); case.a.len()];
+            $op::<T, R, AutoMath, _, _, _>(
+                case.a.as_slice(),
+                case.b.as_slice(),
+                &mut result,
+            );
+            Output::Vector(result)
+        }
+    };
+}
+
+macro_rules! define_value_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            let mut result = vec![AutoMath::zero(); case.a.len()];
+            $op::<T, R, AutoMath, _, _, _>(case.a.as_slice(), case.value, &mut result);
+            Output::Vector(result)
+        }
+    };
+}
+
+macro_rules! define_cmp_value_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            let mut result = vec![AutoMath::zero(); case.a.len()];
+            $op::<T, R, AutoMath, _, _>(case.a.as_slice(), case.value, &mut result);
+            Output::Vector(result)
+        }
+    };
+}
+
+/// Defines ops with the signature `(a, result)`, the extra bounds of the routine
+/// being given after the `where`.
+macro_rules! define_unary_vertical_op {
+    ($name:ident, $op:ident $(, where $($bounds:tt)+)?) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+            $($($bounds)+)?
+        {
+            let mut result = vec![AutoMath::zero(); case.a.len()];
+            $op::<T, R, AutoMath, _, _>(case.a.as_slice(), &mut result);
+            Output::Vector(result)
+        }
+    };
+}
+
+macro_rules! define_inplace_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            let mut result = case.a.clone();
+            $op::<T, R, AutoMath, _>(&mut result, case.b.as_slice());
+            Output::Vector(result)
+        }
+    };
+}
+
+macro_rules! define_unary_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            Output::Value($op::<T, R, AutoMath, _>(case.a.as_slice()))
+        }
+    };
+}
+
+macro_rules! define_binary_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            Output::Value($op::<T, R, AutoMath, _, _>(
+                case.a.as_slice(),
+                case.b.as_slice(),
+            ))
+        }
+    };
+}
+
+/// Defines the ops over slices rather than memory loaders, with the signature `(a)`
+/// or `(a, b)`.
+macro_rules! define_slice_op {
+    ($name:ident, $op:ident($a:ident)) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            Output::Value($op::<T, R, AutoMath>(&case.$a))
+        }
+    };
+    ($name:ident, $op:ident($a:ident, $b:ident)) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            Output::Value($op::<T, R, AutoMath>(&case.$a, &case.$b))
+        }
+    };
+}
+
+macro_rules! define_count_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            Output::Count($op::<T, R, AutoMath, _>(case.a.as_slice(), case.value))
+        }
+    };
+}
+
+macro_rules! define_any_all_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            Output::Bool($op::<T, R, AutoMath, _>(case.a.as_slice(), case.value))
+        }
+    };
+}
+
+macro_rules! define_float_class_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdFloatRegister<T>,
+            AutoMath: Math<T>,
+        {
+            Output::Bool($op::<T, R, AutoMath, _>(case.a.as_slice()))
+        }
+    };
+}
+
+macro_rules! define_bitmask_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<u8>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            let mut result = vec![0; case.a.len().div_ceil(8)];
+            $op::<T, R, AutoMath>(case.a.len(), &case.a, &case.b, &mut result);
+            Output::Vector(result)
+        }
+    };
+}
+
+macro_rules! define_prefix_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            let mut result = vec![AutoMath::zero(); case.a.len()];
+            $op::<T, R, AutoMath, _>(case.a.len(), &case.a, &mut result);
+            Output::Vector(result)
+        }
+    };
+}
+
+/// Defines the ops between the first row of `b` as the query and each row of `a`.
+macro_rules! define_batch_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            let (rows, dims) = shape(case.a.len());
+            let mut result = vec![AutoMath::zero(); rows];
+            $op::<T, R, AutoMath, _>(
+                &case.b[..dims],
+                &case.a[..rows * dims],
+                &mut result,
+            );
+            Output::Vector(result)
+        }
+    };
+}
+
+/// Defines the ops between each row of `a` and each row of `b`.
+macro_rules! define_matrix_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<T>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdRegister<T>,
+            AutoMath: Math<T>,
+        {
+            let (rows, dims) = shape(case.a.len());
+            let mut result = vec![AutoMath::zero(); rows * rows];
+            $op::<T, R, AutoMath>(
+                dims,
+                rows,
+                rows,
+                &case.a[..rows * dims],
+                &case.b[..rows * dims],
+                &mut result,
+            );
+            Output::Vector(result)
+        }
+    };
+}
+
+/// Defines the widened ops over the accumulator type `W`.
+macro_rules! define_wide_acc_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, W, R>(case: &Case<T>) -> Output<W>
+        where
+            T: Element + CastAs<W>,
+            T::Loader: MemLoader<Value = T>,
+            W: Value,
+            R: SimdWidenRegister<T, W>,
+            AutoMath: Math<W>,
+        {
+            Output::Value($op::<T, W, R, AutoMath>(&case.a, &case.b))
+        }
+    };
+}
+
+macro_rules! define_widening_dot_op {
+    ($name:ident, $op:ident) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<i32>
+        where
+            T: Element + Into<i32>,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdWideningDotRegister<T>,
+        {
+            Output::Value($op::<T, R>(&case.a, &case.b))
+        }
+    };
+}
+
+/// Defines the `f32` ops accumulated in `f64`.
+macro_rules! define_f64acc_op {
+    ($name:ident, $op:ident($a:ident)) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<f64>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdWidenRegister<f32, f64>,
+        {
+            let case = downcast::<T, f32>(case);
+            Output::Value($op::<R>(&case.$a))
+        }
+    };
+    ($name:ident, $op:ident($a:ident, $b:ident)) => {
+        unsafe fn $name<T, R>(case: &Case<T>) -> Output<f64>
+        where
+            T: Element,
+            T::Loader: MemLoader<Value = T>,
+            R: SimdWidenRegister<f32, f64>,
+        {
+            let case = downcast::<T, f32>(case);
+            Output::Value($op::<R>(&case.$a, &case.$b))
+        }
+    };
+}
+
+define_vertical_op!(add_vertical, generic_add_vertical);
+define_vertical_op!(add_vertical_nt, generic_add_vertical_nt);
+define_vertical_op!(sub_vertical, generic_sub_vertical);
+define_vertical_op!(mul_vertical, generic_mul_vertical);
+define_vertical_op!(div_vertical, generic_div_vertical);
+define_vertical_op!(max_vertical, generic_cmp_max_vertical);
+define_vertical_op!(min_vertical, generic_cmp_min_vertical);
+define_vertical_op!(eq_vertical, generic_cmp_eq_vertical);
+define_vertical_op!(neq_vertical, generic_cmp_neq_vertical);
+define_vertical_op!(lt_vertical, generic_cmp_lt_vertical);
+define_vertical_op!(lte_vertical, generic_cmp_lte_vertical);
+define_vertical_op!(gt_vertical, generic_cmp_gt_vertical);
+define_vertical_op!(gte_vertical, generic_cmp_gte_vertical);
+define_vertical_op!(
+    bitand_vertical,
+    generic_bitand_vertical,
+    where R: SimdBitwiseRegister<T>, T: core::ops::BitAnd<Output = T>
+);
+define_vertical_op!(
+    bitor_vertical,
+    generic_bitor_vertical,
+    where R: SimdBitwiseRegister<T>, T: core::ops::BitOr<Output = T>
+);
+define_vertical_op!(
+    bitxor_vertical,
+    generic_bitxor_vertical,
+    where R: SimdBitwiseRegister<T>, T: core::ops::BitXor<Output = T>
+);
+define_vertical_op!(
+    shl_vertical,
+    generic_shl_vertical,
+    where R: SimdBitwiseRegister<T>, T: crate::math::WrappingShift
+);
+define_vertical_op!(
+    shr_vertical,
+    generic_shr_vertical,
+    where R: SimdBitwiseRegister<T>, T: crate::math::WrappingShift
+);
+define_vertical_op!(
+    saturating_add_vertical,
+    generic_saturating_add_vertical,
+    where R: SimdSaturatingRegister<T>, T: SaturatingArithmetic
+);
+define_vertical_op!(
+    saturating_sub_vertical,
+    generic_saturating_sub_vertical,
+    where R: SimdSaturatingRegister<T>, T: SaturatingArithmetic
+);
+define_vertical_op!(
+    hypot_vertical,
+    generic_hypot_vertical,
+    where R: SimdFloatRegister<T>
+);
+
+define_value_op!(add_value, generic_add_vertical);
+define_value_op!(sub_value, generic_sub_vertical);
+define_value_op!(mul_value, generic_mul_vertical);
+define_value_op!(div_value, generic_div_vertical);
+
+define_cmp_value_op!(eq_value, generic_cmp_eq_value);
+define_cmp_value_op!(neq_value, generic_cmp_neq_value);
+define_cmp_value_op!(lt_value, generic_cmp_lt_value);
+define_cmp_value_op!(lte_value, generic_cmp_lte_value);
+define_cmp_value_op!(gt_value, generic_cmp_gt_value);
+define_cmp_value_op!(gte_value, generic_cmp_gte_value);
+
+define_unary_vertical_op!(neg_vertical, generic_neg_vertical);
+define_unary_vertical_op!(
+    alternating_sign_flip,
+    generic_alternating_sign_flip,
+    where T: Default
+);
+define_unary_vertical_op!(
+    bitnot_vertical,
+    generic_bitnot_vertical,
+    where R: SimdBitwiseRegister<T>, T: core::ops::Not<Output = T>
+);
+define_unary_vertical_op!(
+    is_nan_vector,
+    generic_is_nan_vector,
+    where R: SimdFloatRegister<T>
+);
+define_unary_vertical_op!(
+    is_finite_vector,
+    generic_is_finite_vector,
+    where R: SimdFloatRegister<T>
+);
+define_unary_vertical_op!(
+    is_infinite_vector,
+    generic_is_infinite_vector,
+    where R: SimdFloatRegister<T>
+);
+
+define_inplace_op!(add_inplace, generic_add_inplace);
+define_inplace_op!(sub_inplace, generic_sub_inplace);
+define_inplace_op!(mul_inplace, generic_mul_inplace);
+define_inplace_op!(div_inplace, generic_div_inplace);
+
+define_unary_op!(sum, generic_sum);
+define_unary_op!(kahan_sum, generic_kahan_sum);
+define_unary_op!(squared_norm, generic_squared_norm);
+define_unary_op!(max, generic_cmp_max);
+define_unary_op!(min, generic_cmp_min);
+define_binary_op!(dot, generic_dot);
+define_binary_op!(squared_euclidean, generic_squared_euclidean);
+define_binary_op!(cosine, generic_cosine);
+
+define_slice_op!(sum_compensated, generic_sum_compensated(a));
+define_slice_op!(sum_ordered, generic_sum_ordered(a));
+define_slice_op!(squared_norm_ordered, generic_squared_norm_ordered(a));
+define_slice_op!(dot_compensated, generic_dot_compensated(a, b));
+define_slice_op!(dot_ordered, generic_dot_ordered(a, b));
+
+define_count_op!(count_eq, generic_count_eq_value);
+define_count_op!(count_neq, generic_count_neq_value);
+define_count_op!(count_lt, generic_count_lt_value);
+define_count_op!(count_lte, generic_count_lte_value);
+define_count_op!(count_gt, generic_count_gt_value);
+define_count_op!(count_gte, generic_count_gte_value);
+
+define_any_all_op!(any_eq, generic_any_eq_value);
+define_any_all_op!(any_neq, generic_any_neq_value);
+define_any_all_op!(any_gt, generic_any_gt_value);
+define_any_all_op!(any_gte, generic_any_gte_value);
+define_any_all_op!(any_lt, generic_any_lt_value);
+define_any_all_op!(any_lte, generic_any_lte_value);
+define_any_all_op!(all_eq, generic_all_eq_value);
+define_any_all_op!(all_neq, generic_all_neq_value);
+define_any_all_op!(all_gt, generic_all_gt_value);
+define_any_all_op!(all_gte, generic_all_gte_value);
+define_any_all_op!(all_lt, generic_all_lt_value);
+define_any_all_op!(all_lte, generic_all_lte_value);
+
+define_float_class_op!(any_nan, generic_any_nan);
+define_float_class_op!(any_infinite, generic_any_infinite);
+define_float_class_op!(all_finite, generic_all_finite);
+
+define_bitmask_op!(eq_bitmask, generic_cmp_eq_bitmask);
+define_bitmask_op!(neq_bitmask, generic_cmp_neq_bitmask);
+define_bitmask_op!(lt_bitmask, generic_cmp_lt_bitmask);
+define_bitmask_op!(lte_bitmask, generic_cmp_lte_bitmask);
+define_bitmask_op!(gt_bitmask, generic_cmp_gt_bitmask);
+define_bitmask_op!(gte_bitmask, generic_cmp_gte_bitmask);
+
+define_prefix_op!(cumulative_max, generic_cumulative_max);
+define_prefix_op!(cumulative_min, generic_cumulative_min);
+
+define_batch_op!(dot_batch, generic_dot_batch);
+define_batch_op!(squared_euclidean_batch, generic_squared_euclidean_batch);
+define_batch_op!(cosine_batch, generic_cosine_batch);
+
+define_matrix_op!(dot_matrix, generic_dot_matrix);
+define_matrix_op!(squared_euclidean_matrix, generic_squared_euclidean_matrix);
+
+define_wide_acc_op!(dot_wide_acc, generic_dot_wide_acc);
+define_wide_acc_op!(
+    squared_euclidean_wide_acc,
+    generic_squared_euclidean_wide_acc
+);
+
+define_widening_dot_op!(dot_widen, generic_dot_widen);
+define_widening_dot_op!(squared_euclidean_widen, generic_squared_euclidean_widen);
+
+define_f64acc_op!(sum_f32_f64acc, generic_sum_f32_f64acc(a));
+define_f64acc_op!(squared_norm_f32_f64acc, generic_squared_norm_f32_f64acc(a));
+define_f64acc_op!(dot_f32_f64acc, generic_dot_f32_f64acc(a, b));
+define_f64acc_op!(
+    squared_euclidean_f32_f64acc,
+    generic_squared_euclidean_f32_f64acc(a, b)
+);
+
+unsafe fn count_nonzero<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    Output::Count(generic_count_nonzero::<T, R, AutoMath, _>(
+        case.a.as_slice(),
+    ))
+}
+
+unsafe fn select_vertical<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let mut result = vec![AutoMath::zero(); case.a.len()];
+    generic_select_vertical::<T, R, AutoMath, _, _, _, _>(
+        case.b.as_slice(),
+        case.a.as_slice(),
+        case.value,
+        &mut result,
+    );
+    Output::Vector(result)
+}
+
+unsafe fn compact<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let mut result = vec![AutoMath::zero(); case.a.len()];
+    let count = generic_compact::<T, R, AutoMath, _>(
+        case.a.len(),
+        &case.b,
+        &case.a,
+        &mut result,
+    );
+    result.truncate(count);
+    Output::Vector(result)
+}
+
+unsafe fn mask_to_indices<T, R>(case: &Case<T>) -> Output<u32>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let mut result = vec![0; case.a.len()];
+    let count =
+        generic_mask_to_indices::<T, R, AutoMath, _>(case.a.len(), &case.b, &mut result);
+    result.truncate(count);
+    Output::Vector(result)
+}
+
+unsafe fn gather<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let mut result = vec![AutoMath::zero(); case.a.len()];
+    generic_gather::<T, R, _>(case.a.len(), &case.a, &case.indices, &mut result);
+    Output::Vector(result)
+}
+
+unsafe fn scatter<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let mut result = case.b.clone();
+    generic_scatter::<T, R>(case.a.len(), &case.a, &case.indices, &mut result);
+    Output::Vector(result)
+}
+
+unsafe fn outer_product<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let (n, m) = shape(case.a.len());
+    let mut result = vec![AutoMath::zero(); n * m];
+    generic_outer_product::<T, R, AutoMath>(
+        n,
+        m,
+        &case.a[..n],
+        &case.b[..m],
+        &mut result,
+    );
+    Output::Vector(result)
+}
+
+unsafe fn weighted_dot<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    Output::Value(generic_weighted_dot::<T, R, AutoMath>(
+        case.a.len(),
+        &case.a,
+        &case.b,
+        &case.a,
+    ))
+}
+
+unsafe fn fold<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    Output::Value(generic_fold::<T, R, AutoMath, _, _, _, _>(
+        case.a.as_slice(),
+        case.b.as_slice(),
+        AutoMath::zero(),
+        |l1, l2, acc| R::fmadd(l1, l2, acc),
+        |a, b, acc| AutoMath::add(AutoMath::mul(a, b), acc),
+    ))
+}
+
+unsafe fn fold_reduce<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    Output::Value(generic_fold_reduce::<T, R, AutoMath, _, _, _, _, _, _>(
+        case.a.as_slice(),
+        case.b.as_slice(),
+        AutoMath::min(),
+        |l1, l2, acc| R::max(R::max(l1, l2), acc),
+        |l1, l2| R::max(l1, l2),
+        |reg| R::max_to_value(reg),
+        |a, b, acc| AutoMath::cmp_max(AutoMath::cmp_max(a, b), acc),
+    ))
+}
+
+unsafe fn moving_average<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+    T: WindowSum,
+    usize: CastAs<T>,
+{
+    let window = case.a.len() % 11 + 1;
+    let mut result = vec![AutoMath::zero(); (case.a.len() + 1).saturating_sub(window)];
+    generic_moving_average::<T, R, AutoMath, _>(
+        case.a.len(),
+        window,
+        &case.a,
+        &mut result,
+    );
+    Output::Vector(result)
+}
+
+unsafe fn gemv<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let (rows, dims) = shape(case.a.len());
+    let mut result = vec![AutoMath::zero(); rows];
+    generic_gemv::<T, R, AutoMath>(
+        rows,
+        dims,
+        &case.a[..rows * dims],
+        &case.b[..dims],
+        &mut result,
+    );
+    Output::Vector(result)
+}
+
+unsafe fn batch_dot<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let (rows, dims) = shape(case.a.len());
+    let mut result = vec![AutoMath::zero(); rows];
+    generic_batch_dot::<T, R, AutoMath>(
+        rows,
+        dims,
+        &case.a[..rows * dims],
+        &case.b[..dims],
+        &mut result,
+    );
+    Output::Vector(result)
+}
+
+unsafe fn dot_indexed<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let (rows, dims) = shape(case.a.len());
+    let indices = case
+        .indices
+        .iter()
+        .map(|&index| index as usize % rows)
+        .collect::<Vec<_>>();
+    let mut result = vec![AutoMath::zero(); indices.len()];
+    generic_dot_indexed::<T, R, AutoMath, _>(
+        dims,
+        &case.b[..dims],
+        &case.a[..rows * dims],
+        &indices,
+        &mut result,
+    );
+    Output::Vector(result)
+}
+
+unsafe fn pairwise_distances<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let (rows, dims) = shape(case.a.len());
+    let mut result = vec![AutoMath::zero(); rows * rows];
+    generic_pairwise_distances::<T, R, AutoMath>(
+        rows,
+        dims,
+        &case.a[..rows * dims],
+        &mut result,
+    );
+    Output::Vector(result)
+}
+
+unsafe fn gram_matrix<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let (rows, dims) = shape(case.a.len());
+    let mut result = vec![AutoMath::zero(); rows * rows];
+    generic_gram_matrix::<T, R, AutoMath>(
+        rows,
+        dims,
+        &case.a[..rows * dims],
+        &mut result,
+    );
+    Output::Vector(result)
+}
+
+unsafe fn l2_normalize_vertical<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let mut result = vec![AutoMath::zero(); case.a.len()];
+    generic_l2_normalize_vertical::<T, R, AutoMath>(case.a.len(), &case.a, &mut result);
+    Output::Vector(result)
+}
+
+unsafe fn l2_normalize_inplace<T, R>(case: &Case<T>) -> Output<T>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let mut result = case.a.clone();
+    generic_l2_normalize_inplace::<T, R, AutoMath>(&mut result);
+    Output::Vector(result)
+}
+
+unsafe fn cast_vector<T, U, R>(case: &Case<T>) -> Output<U>
+where
+    T: Element + CastAs<U>,
+    T::Loader: MemLoader<Value = T>,
+    U: Value + BufferElement,
+    R: SimdCastRegister<T, U>,
+{
+    let mut result = vec![U::default(); case.a.len()];
+    generic_cast_vector::<T, U, R, _>(&case.a, &mut result);
+    Output::Vector(result)
+}
+
+unsafe fn cosine_wide_acc<T, W, R>(case: &Case<T>) -> Output<f32>
+where
+    T: Element + CastAs<W>,
+    T::Loader: MemLoader<Value = T>,
+    W: CastAs<f64>,
+    R: SimdWidenRegister<T, W>,
+    AutoMath: Math<W> + Math<f64>,
+{
+    Output::Value(generic_cosine_wide_acc::<T, W, R, AutoMath>(
+        &case.a, &case.b,
+    ))
+}
+
+unsafe fn binary_hamming_distance<T, R>(case: &Case<T>) -> Output<u32>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdHammingRegister,
+{
+    let case = downcast::<T, u64>(case);
+    Output::Value(generic_binary_hamming_distance::<R>(
+        case.a.len(),
+        &case.a,
+        &case.b,
+    ))
+}
+
+unsafe fn binary_jaccard_similarity<T, R>(case: &Case<T>) -> Output<f32>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdHammingRegister,
+{
+    let case = downcast::<T, u64>(case);
+    Output::Value(generic_binary_jaccard_similarity::<R>(
+        case.a.len(),
+        &case.a,
+        &case.b,
+    ))
+}
+
+fn sum_magnitude(a: &[f64], _: &[f64]) -> f64 {
+    a.iter().map(|v| v.abs()).sum()
+}
+
+fn squared_norm_magnitude(a: &[f64], _: &[f64]) -> f64 {
+    a.iter().map(|v| v * v).sum()
+}
+
+fn dot_magnitude(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| (a * b).abs()).sum()
+}
+
+fn squared_euclidean_magnitude(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| (a - b) * (a - b)).sum()
+}
+
+/// Each window is computed from the running sum, which accumulates the rounding error
+/// of every element before it.
+///
+/// A `NaN` element is ignored, as every window from it onwards is `NaN` for both registers.
+fn moving_average_magnitude(a: &[f64], _: &[f64]) -> f64 {
+    2.0 * a
+        .iter()
+        .filter(|v| !v.is_nan())
+        .map(|v| v.abs())
+        .sum::<f64>()
+}
+
+/// The largest `magnitude` between each `dims` element row of `a` and each of `b`.
+///
+/// A `NaN` row pair is ignored rather than carried to the result, its output being
+/// `NaN` for both registers while the other outputs are checked as normal.
+fn max_over_rows(
+    a: &[f64],
+    b: &[f64],
+    dims: usize,
+    magnitude: impl Fn(&[f64], &[f64]) -> f64,
+) -> f64 {
+    if dims == 0 {
+        return 0.0;
+    }
+
+    a.chunks_exact(dims)
+        .flat_map(|a| b.chunks_exact(dims).map(|b| magnitude(a, b)))
+        .fold(0.0, f64::max)
+}
+
+/// The largest `magnitude` between the first row of `b` and each row of `a`.
+fn batch_magnitude(a: &[f64], b: &[f64], magnitude: fn(&[f64], &[f64]) -> f64) -> f64 {
+    let (rows, dims) = shape(a.len());
+    max_over_rows(&a[..rows * dims], &b[..dims], dims, magnitude)
+}
+
+/// The largest `magnitude` between each row of `a` and each row of `b`.
+fn matrix_magnitude(a: &[f64], b: &[f64], magnitude: fn(&[f64], &[f64]) -> f64) -> f64 {
+    let (rows, dims) = shape(a.len());
+    max_over_rows(&a[..rows * dims], &b[..rows * dims], dims, magnitude)
+}
+
+/// The bound on the error of a sum of `n` terms of the given magnitude.
+fn sum_error(n: f64, magnitude: f64, precision: Precision) -> f64 {
+    2.0 * n * precision.epsilon * magnitude + n * precision.min_positive
+}
+
+fn cosine_tolerance(a: &[f64], b: &[f64], precision: Precision) -> f64 {
+    let n = a.len() as f64 + 1.0;
+    let dot = dot_magnitude(a, b);
+    let norm_a = squared_norm_magnitude(a, b);
+    let norm_b = squared_norm_magnitude(b, a);
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+
+    // The product of the norms loses its relative precision when it overflows or
+    // underflows, which registers may reach on either side of.
+    let norms = norm_a * norm_b;
+    if dot > precision.max || norms > precision.max || norms < precision.min_positive {
+        return f64::INFINITY;
+    }
+
+    let relative = |norm| sum_error(n, norm, precision) / norm;
+    (sum_error(n, dot, precision) + dot * (relative(norm_a) + relative(norm_b)))
+        / norms.sqrt()
+        + 8.0 * precision.epsilon
+}
+
+fn cosine_batch_tolerance(a: &[f64], b: &[f64], precision: Precision) -> f64 {
+    let (rows, dims) = shape(a.len());
+    max_over_rows(&a[..rows * dims], &b[..dims], dims, |a, b| {
+        cosine_tolerance(a, b, precision)
+    })
+}
+
+fn l2_normalize_tolerance(a: &[f64], _: &[f64], precision: Precision) -> f64 {
+    let norm = squared_norm_magnitude(a, a);
+    if norm == 0.0 {
+        return 0.0;
+    }
+
+    // Subnormal norms lose their relative precision.
+    if norm > precision.max || norm < precision.min_positive {
+        return f64::INFINITY;
+    }
+
+    sum_error(a.len() as f64 + 1.0, norm, precision) / norm + 4.0 * precision.epsilon
+}
+
+fn weighted_dot_tolerance(a: &[f64], b: &[f64], precision: Precision) -> f64 {
+    // The products of each term are computed in either order, so one may overflow
+    // or underflow where the other does not.
+    let products = a.iter().zip(b).flat_map(|(a, b)| [a * a, a * b]);
+    if products
+        .map(f64::abs)
+        .any(|v| v > precision.max || (v > 0.0 && v < precision.min_positive))
+    {
+        return f64::INFINITY;
+    }
+
+    let magnitude = a.iter().zip(b).map(|(a, b)| (a * a * b).abs()).sum::<f64>();
+    sum_error(a.len() as f64 + 1.0, 2.0 * magnitude, precision)
+}
+
+/// The registry of every op requiring only [SimdRegister], instantiated for the
+/// register `R`.
+fn ops<T, R>() -> Vec<Op<T>>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    Fallback: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    use Check::*;
+
+    // The ordered reductions only depend on the register width when the `Fallback`
+    // register is allowed to reorder them with the fast math intrinsics.
+    let ordered_reduction = |magnitude| {
+        if cfg!(feature = "nightly") {
+            Reduction(magnitude)
+        } else {
+            Exact
+        }
+    };
+
+    let mut ops = vec![
+        op!(Exact, add_vertical::<T>),
+        op!(Exact, add_vertical_nt::<T>),
+        op!(Exact, sub_vertical::<T>),
+        op!(Exact, mul_vertical::<T>),
+        op!(Exact, neg_vertical::<T>),
+        op!(Exact, alternating_sign_flip::<T>),
+        op!(Exact, add_value::<T>),
+        op!(Exact, sub_value::<T>),
+        op!(Exact, mul_value::<T>),
+        op!(Exact, add_inplace::<T>),
+        op!(Exact, sub_inplace::<T>),
+        op!(Exact, mul_inplace::<T>),
+        op!(ExactOrdered, max_vertical::<T>),
+        op!(ExactOrdered, min_vertical::<T>),
+        op!(Exact, eq_vertical::<T>),
+        op!(Exact, neq_vertical::<T>),
+        op!(Exact, lt_vertical::<T>),
+        op!(Exact, lte_vertical::<T>),
+        op!(Exact, gt_vertical::<T>),
+        op!(Exact, gte_vertical::<T>),
+        op!(Exact, eq_value::<T>),
+        op!(Exact, neq_value::<T>),
+        op!(Exact, lt_value::<T>),
+        op!(Exact, lte_value::<T>),
+        op!(Exact, gt_value::<T>),
+        op!(Exact, gte_value::<T>),
+        op!(Exact, eq_bitmask::<T>),
+        op!(Exact, neq_bitmask::<T>),
+        op!(Exact, lt_bitmask::<T>),
+        op!(Exact, lte_bitmask::<T>),
+        op!(Exact, gt_bitmask::<T>),
+        op!(Exact, gte_bitmask::<T>),
+        op!(Exact, select_vertical::<T>),
+        op!(Exact, compact::<T>),
+        op!(Exact, mask_to_indices::<T>),
+        op!(Exact, gather::<T>),
+        op!(Exact, scatter::<T>),
+        op!(Exact, outer_product::<T>),
+        op!(ExactOrdered, max::<T>),
+        op!(ExactOrdered, min::<T>),
+        op!(ExactOrdered, cumulative_max::<T>),
+        op!(ExactOrdered, cumulative_min::<T>),
+        op!(ExactOrdered, fold_reduce::<T>),
+        op!(Exact, count_eq::<T>),
+        op!(Exact, count_neq::<T>),
+        op!(Exact, count_lt::<T>),
+        op!(Exact, count_lte::<T>),
+        op!(Exact, count_gt::<T>),
+        op!(Exact, count_gte::<T>),
+        op!(Exact, count_nonzero::<T>),
+        op!(Exact, any_eq::<T>),
+        op!(Exact, any_neq::<T>),
+        op!(Exact, any_gt::<T>),
+        op!(Exact, any_gte::<T>),
+        op!(Exact, any_lt::<T>),
+        op!(Exact, any_lte::<T>),
+        op!(Exact, all_eq::<T>),
+        op!(Exact, all_neq::<T>),
+        op!(Exact, all_gt::<T>),
+        op!(Exact, all_gte::<T>),
+        op!(Exact, all_lt::<T>),
+        op!(Exact, all_lte::<T>),
+        op!(Reduction(sum_magnitude), sum::<T>),
+        op!(Reduction(sum_magnitude), kahan_sum::<T>),
+        op!(Reduction(sum_magnitude), sum_compensated::<T>),
+        op!(ordered_reduction(sum_magnitude), sum_ordered::<T>),
+        op!(Reduction(squared_norm_magnitude), squared_norm::<T>),
+        op!(
+            ordered_reduction(squared_norm_magnitude),
+            squared_norm_ordered::<T>
+        ),
+        op!(Reduction(dot_magnitude), dot::<T>),
+        op!(Reduction(dot_magnitude), dot_compensated::<T>),
+        op!(ordered_reduction(dot_magnitude), dot_ordered::<T>),
+        op!(Reduction(dot_magnitude), fold::<T>),
+        op!(Tolerance(weighted_dot_tolerance), weighted_dot::<T>),
+        op!(
+            Reduction(squared_euclidean_magnitude),
+            squared_euclidean::<T>
+        ),
+        op!(
+            Reduction(|a, b| batch_magnitude(a, b, dot_magnitude)),
+            dot_batch::<T>
+        ),
+        op!(
+            Reduction(|a, b| batch_magnitude(a, b, dot_magnitude)),
+            batch_dot::<T>
+        ),
+        op!(
+            Reduction(|a, b| batch_magnitude(a, b, dot_magnitude)),
+            gemv::<T>
+        ),
+        op!(
+            Reduction(|a, b| batch_magnitude(a, b, dot_magnitude)),
+            dot_indexed::<T>
+        ),
+        op!(
+            Reduction(|a, b| matrix_magnitude(a, b, dot_magnitude)),
+            dot_matrix::<T>
+        ),
+        op!(
+            Reduction(|a, _| matrix_magnitude(a, a, dot_magnitude)),
+            gram_matrix::<T>
+        ),
+        op!(
+            Reduction(|a, b| batch_magnitude(a, b, squared_euclidean_magnitude)),
+            squared_euclidean_batch::<T>
+        ),
+        op!(
+            Reduction(|a, b| matrix_magnitude(a, b, squared_euclidean_magnitude)),
+            squared_euclidean_matrix::<T>
+        ),
+        op!(
+            Reduction(|a, _| matrix_magnitude(a, a, squared_euclidean_magnitude)),
+            pairwise_distances::<T>
+        ),
+    ];
+
+    // Integer division by zero is not defined by any of the impls.
+    if T::IS_FLOAT {
+        ops.extend([
+            op!(Exact, div_vertical::<T>),
+            op!(Exact, div_value::<T>),
+            op!(Exact, div_inplace::<T>),
+            op!(Tolerance(cosine_tolerance), cosine::<T>),
+            op!(Tolerance(cosine_batch_tolerance), cosine_batch::<T>),
+            op!(
+                Tolerance(l2_normalize_tolerance),
+                l2_normalize_vertical::<T>
+            ),
+            op!(Tolerance(l2_normalize_tolerance), l2_normalize_inplace::<T>),
+        ]);
+    }
+
+    ops
+}
+
+/// The ops of registers implementing [SimdFloatRegister].
+fn float_ops<T, R>() -> Vec<Op<T>>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdFloatRegister<T>,
+    Fallback: SimdFloatRegister<T>,
+    AutoMath: Math<T>,
+{
+    vec![
+        op!(Check::Exact, is_nan_vector::<T>),
+        op!(Check::Exact, is_finite_vector::<T>),
+        op!(Check::Exact, is_infinite_vector::<T>),
+        op!(Check::Exact, any_nan::<T>),
+        op!(Check::Exact, any_infinite::<T>),
+        op!(Check::Exact, all_finite::<T>),
+        op!(Check::Relative(2.0), hypot_vertical::<T>),
+    ]
+}
+
+/// The ops of registers implementing [SimdBitwiseRegister].
+fn bitwise_ops<T, R>() -> Vec<Op<T>>
+where
+    T: Element
+        + core::ops::BitAnd<Output = T>
+        + core::ops::BitOr<Output = T>
+        + core::ops::BitXor<Output = T>
+        + core::ops::Not<Output = T>
+        + crate::math::WrappingShift,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdBitwiseRegister<T>,
+    Fallback: SimdBitwiseRegister<T>,
+    AutoMath: Math<T>,
+{
+    vec![
+        op!(Check::Exact, bitand_vertical::<T>),
+        op!(Check::Exact, bitor_vertical::<T>),
+        op!(Check::Exact, bitxor_vertical::<T>),
+        op!(Check::Exact, bitnot_vertical::<T>),
+        op!(Check::Exact, shl_vertical::<T>),
+        op!(Check::Exact, shr_vertical::<T>),
+    ]
+}
+
+/// The ops of registers implementing [SimdSaturatingRegister].
+fn saturating_ops<T, R>() -> Vec<Op<T>>
+where
+    T: Element + SaturatingArithmetic,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdSaturatingRegister<T>,
+    Fallback: SimdSaturatingRegister<T>,
+    AutoMath: Math<T>,
+{
+    vec![
+        op!(Check::Exact, saturating_add_vertical::<T>),
+        op!(Check::Exact, saturating_sub_vertical::<T>),
+    ]
+}
+
+/// The ops of the types which a window length can be cast to.
+fn stats_ops<T, R>() -> Vec<Op<T>>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    Fallback: SimdRegister<T>,
+    AutoMath: Math<T>,
+    T: WindowSum,
+    usize: CastAs<T>,
+{
+    vec![op!(
+        Check::Reduction(moving_average_magnitude),
+        moving_average::<T>
+    )]
+}
+
+/// The ops of registers implementing [SimdCastRegister] from `T` to `U`.
+fn cast_ops<T, U, R>() -> Vec<Op<T>>
+where
+    T: Element + CastAs<U>,
+    T::Loader: MemLoader<Value = T>,
+    U: Value + BufferElement,
+    R: SimdCastRegister<T, U>,
+    Fallback: SimdCastRegister<T, U>,
+{
+    vec![op!(Check::Exact, cast_vector::<T, U>)]
+}
+
+/// The ops of registers implementing [SimdWidenRegister] from `T` to `W`.
+fn wide_acc_ops<T, W, R>() -> Vec<Op<T>>
+where
+    T: Element + CastAs<W>,
+    T::Loader: MemLoader<Value = T>,
+    W: Value,
+    R: SimdWidenRegister<T, W>,
+    Fallback: SimdWidenRegister<T, W>,
+    AutoMath: Math<W>,
+{
+    vec![
+        op!(Check::Reduction(dot_magnitude), dot_wide_acc::<T, W>),
+        op!(
+            Check::Reduction(squared_euclidean_magnitude),
+            squared_euclidean_wide_acc::<T, W>
+        ),
+    ]
+}
+
+/// The cosine of registers implementing [SimdWidenRegister] from `T` to `W`.
+fn cosine_wide_acc_ops<T, W, R>() -> Vec<Op<T>>
+where
+    T: Element + CastAs<W>,
+    T::Loader: MemLoader<Value = T>,
+    W: CastAs<f64>,
+    R: SimdWidenRegister<T, W>,
+    Fallback: SimdWidenRegister<T, W>,
+    AutoMath: Math<W> + Math<f64>,
+{
+    vec![op!(
+        Check::Tolerance(cosine_tolerance),
+        cosine_wide_acc::<T, W>
+    )]
+}
+
+/// The ops of registers implementing [SimdWideningDotRegister].
+fn widening_dot_ops<T, R>() -> Vec<Op<T>>
+where
+    T: Element + Into<i32>,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdWideningDotRegister<T>,
+    Fallback: SimdWideningDotRegister<T>,
+{
+    vec![
+        op!(Check::Exact, dot_widen::<T>),
+        op!(Check::Exact, squared_euclidean_widen::<T>),
+    ]
+}
+
+/// The `f32` ops of registers implementing [SimdWidenRegister] from `f32` to `f64`.
+fn f64acc_ops<T, R>() -> Vec<Op<T>>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdWidenRegister<f32, f64>,
+{
+    use Check::Reduction;
+
+    vec![
+        op!(Reduction(sum_magnitude), sum_f32_f64acc::<T>),
+        op!(
+            Reduction(squared_norm_magnitude),
+            squared_norm_f32_f64acc::<T>
+        ),
+        op!(Reduction(dot_magnitude), dot_f32_f64acc::<T>),
+        op!(
+            Reduction(squared_euclidean_magnitude),
+            squared_euclidean_f32_f64acc::<T>
+        ),
+    ]
+}
+
+/// The `u64` ops of registers implementing [SimdHammingRegister].
+fn hamming_ops<T, R>() -> Vec<Op<T>>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdHammingRegister,
+{
+    vec![
+        op!(Check::Exact, binary_hamming_distance::<T>),
+        op!(Check::Exact, binary_jaccard_similarity::<T>),
+    ]
+}
+
+fn check_value<T: Element, O: Value>(
+    check: Check,
+    case: &Case<T>,
+    actual: O,
+    expected: O,
+) -> Result<(), String>
+where
+    T::Loader: MemLoader<Value = T>,
+{
+    if actual == expected || (actual.is_nan() && expected.is_nan()) {
+        return Ok(());
+    }
+
+    let precision = O::precision();
+    let a = || case.a.iter().map(|v| v.to_f64()).collect::<Vec<_>>();
+    let b = || case.b.iter().map(|v| v.to_f64()).collect::<Vec<_>>();
+    let tolerance = match check {
+        Check::Exact => return Err(format!("{actual:?} != {expected:?}")),
+        Check::ExactOrdered if case.a.iter().chain(&case.b).any(|v| v.is_nan()) => {
+            return Ok(())
+        },
+        Check::ExactOrdered => return Err(format!("{actual:?} != {expected:?}")),
+        // Integer results wrap, so the order they are computed in makes no difference.
+        _ if !O::IS_FLOAT => return Err(format!("{actual:?} != {expected:?}")),
+        Check::Relative(epsilons) => {
+            epsilons * precision.epsilon * expected.to_f64().abs()
+                + precision.min_positive
+        },
+        Check::Reduction(magnitude) => {
+            let magnitude = magnitude(&a(), &b());
+            // Whether the partial sums overflow depends on the order they are computed in.
+            if magnitude > precision.max {
+                f64::INFINITY
+            } else {
+                sum_error(case.a.len() as f64 + 1.0, magnitude, precision)
+            }
+        },
+        Check::Tolerance(tolerance) => tolerance(&a(), &b(), precision),
+    };
+
+    // Any `NaN` in the terms is carried through every order they are computed in.
+    if tolerance.is_nan() {
+        return Err(format!("{actual:?} is not NaN with a NaN term"));
+    }
+
+    if tolerance.is_infinite() {
+        return Ok(());
+    }
+
+    let error = (actual.to_f64() - expected.to_f64()).abs();
+    if error <= tolerance {
+        Ok(())
+    } else {
+        Err(format!(
+            "{actual:?} != {expected:?}, error {error:e} is above the tolerance {tolerance:e}",
+        ))
+    }
+}
+
+fn check_output<T: Element, O: Value>(
+    check: Check,
+    case: &Case<T>,
+    actual: Output<O>,
+    expected: Output<O>,
+) -> Result<(), String>
+where
+    T::Loader: MemLoader<Value = T>,
+{
+    match (actual, expected) {
+        (Output::Vector(actual), Output::Vector(expected)) => {
+            if actual.len() != expected.len() {
+                return Err(format!(
+                    "length {} != {}: {actual:?} != {expected:?}",
+                    actual.len(),
+                    expected.len(),
+                ));
+            }
+
+            for (i, (actual, expected)) in actual.into_iter().zip(expected).enumerate() {
+                check_value(check, case, actual, expected)
+                    .map_err(|e| format!("value mismatch at {i}: {e}"))?;
+            }
+            Ok(())
+        },
+        (Output::Value(actual), Output::Value(expected)) => {
+            check_value(check, case, actual, expected)
+        },
+        (Output::Count(actual), Output::Count(expected)) if actual == expected => Ok(()),
+        (Output::Bool(actual), Output::Bool(expected)) if actual == expected => Ok(()),
+        (actual, expected) => Err(format!("{actual:?} != {expected:?}")),
+    }
+}
+
+/// Runs every op on the candidate register and checks it against the `Fallback` oracle.
+fn check_candidate<T>(
+    name: &str,
+    ops: &[Op<T>],
+    case: &Case<T>,
+) -> Result<(), TestCaseError>
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+{
+    for op in ops {
+        (op.run)(case).map_err(|e| {
+            TestCaseError::fail(format!("{name} {} differs from Fallback: {e}", op.name))
+        })?;
+    }
+    Ok(())
+}
+
+/// Runs every op on the candidate register over each length up to two dense lanes.
+fn check_bounds<T, R>(name: &str, ops: &[Op<T>])
+where
+    T: Element,
+    T::Loader: MemLoader<Value = T>,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    let mut runner = TestRunner::deterministic();
+    let mut sample = |len: usize| -> Vec<T> {
+        let values = (0..len)
+            .map(|_| T::strategy().new_tree(&mut runner).unwrap().current())
+            .collect::<Vec<_>>();
+        // The allocation must not have any spare capacity for sanitizers to see over-reads.
+        values.into_boxed_slice().into_vec()
+    };
+
+    for len in 0..=R::elements_per_dense() * 2 {
+        let a = sample(len);
+        let b = sample(len);
+        let value = a.get(len / 2).copied().unwrap_or_else(AutoMath::zero);
+        let indices = (0..len).map(|i| ((i * 7 + 3) % len) as u32).collect();
+        let case = Case {
+            a,
+            b,
+            value,
+            indices,
+        };
+
+        if let Err(e) = check_candidate(name, ops, &case) {
+            panic!("length {len}: {e}");
+        }
+    }
+}
+
+fn config() -> Config {
+    Config {
+        cases: if cfg!(miri) { 4 } else { 256 },
+        failure_persistence: None,
+        ..Config::default()
+    }
+}
+
+#[test]
+fn test_every_export_is_registered() {
+    fn identifiers(source: &str) -> impl Iterator<Item = &str> {
+        source
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|ident| ident.starts_with("generic_"))
+    }
+
+    let registered =
+        identifiers(include_str!("test_differential.rs")).collect::<HashSet<_>>();
+    let exports = include_str!("mod.rs")
+        .split("pub use")
+        .skip(1)
+        .flat_map(|statement| identifiers(statement.split(';').next().unwrap()))
+        .collect::<HashSet<_>>();
+
+    for name in UNCHECKED {
+        assert!(exports.contains(name), "{name} is no longer exported");
+    }
+
+    // Every registered op names the routine it runs within this file, either as the
+    // argument of its `define_*!` macro or within its body.
+    let mut missing = exports
+        .into_iter()
+        .filter(|name| !UNCHECKED.contains(name) && !registered.contains(name))
+        .collect::<Vec<_>>();
+    missing.sort_unstable();
+    assert!(
+        missing.is_empty(),
+        "exported routines missing from the differential harness: {missing:?}",
+    );
+}
+
+/// Builds the ops of the candidate register `$im`, with the `[<$group>_ops]` of each
+/// extension trait it implements for `$t`.
+macro_rules! registry {
+    ($t:ident, $im:ident $(, $group:ident $(<$u:ty>)?)*) => {{
+        #[allow(unused_mut)]
+        let mut ops = ops::<$t, $im>();
+        $(
+            ops.extend(paste::paste!([<$group _ops>]::<$t, $($u,)? $im>()));
+        )*
+        ops
+    }};
+}
+
+macro_rules! differential_test {
+    ($t:ident, $($im:ident $([$($group:ident $(<$u:ty>)?),* $(,)?])? $(,)?)+) => {
+        paste::paste! {
+            proptest! {
+                #![proptest_config(config())]
+
+                #[test]
+                fn [<test_differential_ $t>](case in case_strategy::<$t>()) {
+                    $(
+                        let ops = registry!($t, $im $($(, $group $(<$u>)?)*)?);
+                        check_candidate(stringify!($im), &ops, &case)?;
+                    )*
+                }
+            }
+
+            #[test]
+            fn [<test_bounds_ $t>]() {
+                $(
+                    let ops = registry!($t, $im $($(, $group $(<$u>)?)*)?);
+                    check_bounds::<$t, $im>(stringify!($im), &ops);
+                )*
+            }
+        }
+    };
+}
+
+#[cfg(not(any(
+    all(target_feature = "avx2", target_feature = "fma"),
+    target_feature = "neon"
+)))]
+mod fallback_tests {
+    use super::*;
+
+    // Without any SIMD registers the oracle is only checked against itself, which
+    // still catches panics and out of bounds accesses in the tail handling.
+    differential_test!(f32, Fallback [float, stats, cast<f64>, cast<i32>, f64acc]);
+    differential_test!(f64, Fallback [float, stats, cast<f32>]);
+    differential_test!(i8, Fallback [bitwise, saturating, cast<f32>, widening_dot]);
+    differential_test!(i16, Fallback [bitwise, saturating, cast<f32>, widening_dot]);
+    differential_test!(i32, Fallback [bitwise, saturating, stats, cast<f32>, wide_acc<i64>]);
+    differential_test!(i64, Fallback [bitwise, saturating, stats]);
+    differential_test!(u8, Fallback [bitwise, saturating, cast<f32>, widening_dot, cosine_wide_acc<u32>]);
+    differential_test!(u16, Fallback [bitwise, saturating, cast<f32>, wide_acc<u32>, cosine_wide_acc<u64>]);
+    differential_test!(u32, Fallback [bitwise, saturating, wide_acc<u64>]);
+    differential_test!(u64, Fallback [bitwise, saturating, hamming]);
+}
+
+#[cfg(all(
+    target_feature = "avx2",
+    target_feature = "fma",
+    not(all(target_feature = "avx512f", feature = "stable-avx512"))
+))]
+mod avx2_tests {
+    use super::*;
+
+    differential_test!(
+        f32,
+        Avx2 [float, stats, cast<f64>, cast<i32>, f64acc],
+        Avx2Fma [float, stats, f64acc],
+    );
+    differential_test!(f64, Avx2 [float, stats, cast<f32>], Avx2Fma [float, stats]);
+    differential_test!(i8, Avx2 [bitwise, saturating, cast<f32>, widening_dot]);
+    differential_test!(i16, Avx2 [bitwise, saturating, cast<f32>, widening_dot]);
+    differential_test!(i32, Avx2 [bitwise, saturating, stats, cast<f32>, wide_acc<i64>]);
+    differential_test!(i64, Avx2 [bitwise, saturating, stats]);
+    differential_test!(
+        u8,
+        Avx2 [bitwise, saturating, cast<f32>, widening_dot, cosine_wide_acc<u32>],
+    );
+    differential_test!(
+        u16,
+        Avx2 [bitwise, saturating, cast<f32>, wide_acc<u32>, cosine_wide_acc<u64>],
+    );
+    differential_test!(u32, Avx2 [bitwise, saturating, wide_acc<u64>]);
+    differential_test!(u64, Avx2 [bitwise, saturating, hamming]);
+}
+
+#[cfg(all(
+    target_feature = "avx2",
+    target_feature = "fma",
+    target_feature = "avx512f",
+    feature = "stable-avx512"
+))]
+mod avx512_tests {
+    use super::*;
+
+    differential_test!(
+        f32,
+        Avx2 [float, stats, cast<f64>, cast<i32>, f64acc],
+        Avx2Fma [float, stats, f64acc],
+        Avx512 [float, stats, cast<f64>, cast<i32>, f64acc],
+    );
+    differential_test!(
+        f64,
+        Avx2 [float, stats, cast<f32>],
+        Avx2Fma [float, stats],
+        Avx512 [float, stats, cast<f32>],
+    );
+    differential_test!(
+        i8,
+        Avx2 [bitwise, saturating, cast<f32>, widening_dot],
+        Avx512 [bitwise, saturating, cast<f32>],
+    );
+    differential_test!(
+        i16,
+        Avx2 [bitwise, saturating, cast<f32>, widening_dot],
+        Avx512 [bitwise, saturating, cast<f32>],
+    );
+    differential_test!(
+        i32,
+        Avx2 [bitwise, saturating, stats, cast<f32>, wide_acc<i64>],
+        Avx512 [bitwise, saturating, stats, cast<f32>, wide_acc<i64>],
+    );
+    differential_test!(
+        i64,
+        Avx2 [bitwise, saturating, stats],
+        Avx512 [bitwise, saturating, stats],
+    );
+    differential_test!(
+        u8,
+        Avx2 [bitwise, saturating, cast<f32>, widening_dot, cosine_wide_acc<u32>],
+        Avx512 [bitwise, saturating, cast<f32>, cosine_wide_acc<u32>],
+    );
+    differential_test!(
+        u16,
+        Avx2 [bitwise, saturating, cast<f32>, wide_acc<u32>, cosine_wide_acc<u64>],
+        Avx512 [bitwise, saturating, cast<f32>, wide_acc<u32>, cosine_wide_acc<u64>],
+    );
+    differential_test!(
+        u32,
+        Avx2 [bitwise, saturating, wide_acc<u64>],
+        Avx512 [bitwise, saturating, wide_acc<u64>],
+    );
+    differential_test!(
+        u64,
+        Avx2 [bitwise, saturating, hamming],
+        Avx512 [bitwise, saturating, hamming],
+    );
+}
+
+#[cfg(target_feature = "neon")]
+mod neon_tests {
+    use super::*;
+
+    differential_test!(f32, Neon [float, stats, cast<f64>, cast<i32>, f64acc]);
+    differential_test!(f64, Neon [float, stats, cast<f32>]);
+    differential_test!(i8, Neon [bitwise, saturating, cast<f32>, widening_dot]);
+    differential_test!(i16, Neon [bitwise, saturating, cast<f32>, widening_dot]);
+    differential_test!(i32, Neon [bitwise, saturating, stats, cast<f32>, wide_acc<i64>]);
+    differential_test!(i64, Neon [bitwise, saturating, stats]);
+    differential_test!(
+        u8,
+        Neon [bitwise, saturating, cast<f32>, widening_dot, cosine_wide_acc<u32>],
+    );
+    differential_test!(
+        u16,
+        Neon [bitwise, saturating, cast<f32>, wide_acc<u32>, cosine_wide_acc<u64>],
+    );
+    differential_test!(u32, Neon [bitwise, saturating, wide_acc<u64>]);
+    differential_test!(u64, Neon [bitwise, saturating, hamming]);
+}
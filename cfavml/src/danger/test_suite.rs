@@ -3,7 +3,7 @@ use std::fmt::Debug;
 use super::*;
 use crate::buffer::WriteOnlyBuffer;
 use crate::danger::SimdRegister;
-use crate::math::{AutoMath, Math};
+use crate::math::{AutoMath, MaskCount, Math};
 use crate::mem_loader::IntoMemLoader;
 
 const DATA_SIZE: usize = if cfg!(miri) { 133 } else { 1043 };
@@ -11,14 +11,481 @@ const DATA_SIZE: usize = if cfg!(miri) { 133 } else { 1043 };
 // Some types like `i8` and `i16` do not behave well with the rng generated vectors _and_
 // cosine logic, so we skip them since it is unlikely anyone will actually do cosine distance
 // on i8 or i16 values.
+//
+// The signed integer types can also overflow when computing the batch norms from the rng
+// generated candidates, so they run the batch test over bounded values via `bounded_batch`.
 macro_rules! test_cosine_extra {
     ($t:ident, $im:ident) => {
+        test_cosine_extra!($t, $im, batch = test_cosine_batch);
+    };
+    ($t:ident, $im:ident, bounded_batch) => {
+        test_cosine_extra!($t, $im, batch = test_cosine_batch_bounded);
+    };
+    ($t:ident, $im:ident, batch = $batch:ident) => {
         paste::paste! {
             #[test]
             fn [<test_ $im:lower _ $t _cosine>]() {
                 let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
                 unsafe { crate::danger::op_cosine::test_cosine::<$t, $im>(l1, l2) };
             }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _cosine_batch>]() {
+                unsafe { crate::danger::op_batch_distance::tests::$batch::<$t, $im>(131, 7) };
+            }
+        }
+    };
+}
+
+// Bitwise operations are only supported on the integer types.
+macro_rules! test_bitwise_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _bitwise_value>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_bitwise_vertical::tests::test_bitwise_value_all::<$t, $im>(
+                        l1,
+                        0b1010 as $t,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _bitwise_vector>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_bitwise_vertical::tests::test_bitwise_vector_all::<$t, $im>(
+                        l1, l2,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _shift>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                let bits = $t::BITS as $t;
+                let boundary = [0, 1, bits - 1, bits, bits + 1, bits * 2 + 3, !0];
+                unsafe {
+                    crate::danger::op_bitwise_vertical::tests::test_shift_all::<$t, $im>(
+                        l1, l2, &boundary,
+                    )
+                };
+            }
+        }
+    };
+}
+
+// Saturating arithmetic is only supported on the integer types.
+macro_rules! test_saturating_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _saturating>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                let extremes = [$t::MIN, $t::MIN + 1, 0, 1, $t::MAX - 1, $t::MAX];
+                unsafe {
+                    crate::danger::op_arithmetic_vertical::tests::test_saturating_all::<$t, $im>(
+                        l1, l2, &extremes,
+                    )
+                };
+            }
+        }
+    };
+}
+
+// The conversions are tested against the `as` cast, the float inputs are scaled out
+// of the sample `0..1` range and mixed with special values so the truncation,
+// saturation and `NaN` handling of each register impl is checked.
+macro_rules! test_cast_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _cast>]() {
+                use crate::danger::op_cast::tests::test_cast;
+
+                let (l1, _) = crate::test_utils::get_sample_vectors::<f32>(DATA_SIZE);
+                let mut l1 = l1
+                    .into_iter()
+                    .map(|v| (v - 0.5) * 1e10)
+                    .collect::<Vec<f32>>();
+                l1.extend([f32::NAN, f32::INFINITY, f32::NEG_INFINITY, 2147483648.0, -0.0]);
+                unsafe { test_cast::<f32, f64, $im>(l1.clone()) };
+                unsafe { test_cast::<f32, i32, $im>(l1) };
+
+                let (l1, _) = crate::test_utils::get_sample_vectors::<f64>(DATA_SIZE);
+                let mut l1 = l1
+                    .into_iter()
+                    .map(|v| (v - 0.5) * 1e40)
+                    .collect::<Vec<f64>>();
+                l1.extend([f64::NAN, f64::INFINITY, f64::MIN_POSITIVE, -0.0]);
+                unsafe { test_cast::<f64, f32, $im>(l1) };
+
+                let (l1, _) = crate::test_utils::get_sample_vectors::<i32>(DATA_SIZE);
+                unsafe { test_cast::<i32, f32, $im>(l1) };
+                let (l1, _) = crate::test_utils::get_sample_vectors::<i8>(DATA_SIZE);
+                unsafe { test_cast::<i8, f32, $im>(l1) };
+                let (l1, _) = crate::test_utils::get_sample_vectors::<u8>(DATA_SIZE);
+                unsafe { test_cast::<u8, f32, $im>(l1) };
+                let (l1, _) = crate::test_utils::get_sample_vectors::<i16>(DATA_SIZE);
+                unsafe { test_cast::<i16, f32, $im>(l1) };
+                let (l1, _) = crate::test_utils::get_sample_vectors::<u16>(DATA_SIZE);
+                unsafe { test_cast::<u16, f32, $im>(l1) };
+            }
+        }
+    };
+}
+
+// The widened dot product is only supported by a subset of register and type pairs.
+//
+// The full range tests use values at the extremes of the type in every element so any
+// intermediate overflow or saturation within the register impls shows up immediately.
+macro_rules! test_dot_widen_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_widen>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_dot_widen::test_dot_widen::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_widen_full_range>]() {
+                for (x, y) in [
+                    ($t::MIN, $t::MAX),
+                    ($t::MAX, $t::MAX),
+                    ($t::MIN, $t::MIN),
+                    ($t::MIN + 1, $t::MIN + 1),
+                ] {
+                    let l1 = vec![x; DATA_SIZE];
+                    let l2 = vec![y; DATA_SIZE];
+                    unsafe { crate::danger::op_dot_widen::test_dot_widen::<$t, $im>(l1, l2) };
+                }
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _squared_euclidean_widen>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_dot_widen::test_squared_euclidean_widen::<$t, $im>(l1, l2)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _squared_euclidean_widen_full_range>]() {
+                for (x, y) in [
+                    ($t::MIN, $t::MAX),
+                    ($t::MAX, $t::MIN),
+                    ($t::MIN + 1, $t::MAX),
+                ] {
+                    let l1 = vec![x; DATA_SIZE];
+                    let l2 = vec![y; DATA_SIZE];
+                    unsafe {
+                        crate::danger::op_dot_widen::test_squared_euclidean_widen::<$t, $im>(l1, l2)
+                    };
+                }
+            }
+        }
+    };
+}
+
+// The `f32` routines accumulating in `f64`, these also check the widened accumulation
+// is more precise than the `f32` routines for inputs which lose precision in `f32`.
+macro_rules! test_f64acc_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _dot_f32_f64acc>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<f32>(DATA_SIZE);
+                unsafe { crate::danger::op_dot::test_dot_f32_f64acc::<$im>(l1, l2) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _squared_euclidean_f32_f64acc>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<f32>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_euclidean::test_squared_euclidean_f32_f64acc::<$im>(
+                        l1, l2,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _squared_norm_f32_f64acc>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<f32>(DATA_SIZE);
+                unsafe { crate::danger::op_norm::test_squared_norm_f32_f64acc::<$im>(l1) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _sum_f32_f64acc>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<f32>(DATA_SIZE);
+                unsafe { crate::danger::op_sum::test_sum_f32_f64acc::<$im>(l1) };
+            }
+        }
+    };
+}
+
+// The integer routines accumulating in a wider integer type.
+macro_rules! test_wide_acc_extra {
+    ($t:ident, $acc:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _squared_euclidean_wide_acc>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_euclidean::test_squared_euclidean_wide_acc::<
+                        $t,
+                        $acc,
+                        $im,
+                    >(l1, l2)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_wide_acc>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_dot::test_dot_wide_acc::<$t, $acc, $im>(l1, l2)
+                };
+            }
+        }
+    };
+}
+
+// The integer cosine routines accumulating in a wider integer type, these are checked
+// against the cosine distance of the vectors cast to `f32`.
+macro_rules! test_cosine_wide_acc_extra {
+    ($t:ident, $acc:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _cosine_wide_acc>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_cosine::test_cosine_wide_acc::<
+                        $t,
+                        $acc,
+                        $im,
+                    >(l1, l2)
+                };
+            }
+        }
+    };
+}
+
+// The binary Hamming distance and Jaccard similarity over bit-packed `u64` words.
+macro_rules! test_hamming_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _binary_hamming>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<u64>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_hamming::test_binary_hamming_distance::<$im>(
+                        l1,
+                        l2,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _binary_jaccard>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<u64>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_hamming::test_binary_jaccard_similarity::<$im>(
+                        l1,
+                        l2,
+                    )
+                };
+            }
+        }
+    };
+}
+
+// Moving averages are only exported for the types a window size can be cast to.
+macro_rules! test_stats_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _moving_average>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_stats::test_moving_average::<$t, $im>(l1) };
+            }
+        }
+    };
+}
+
+// Float only operations, the special values are repeated out to `DATA_SIZE` so that
+// every register path is exercised rather than just the scalar tail.
+//
+// `3·2¹⁰⁰` and `4·2¹⁰⁰` overflow and `3·2⁻¹⁰⁰` and `4·2⁻¹⁰⁰` underflow when squared in `f32`,
+// but the hypotenuse of each pair is still exactly representable.
+macro_rules! test_float_extra {
+    ($t:ident, $im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _ $t _hypot>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_hypot::tests::test_hypot::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _l2_normalize>]() {
+                for len in [0, 1, 63, 64, 65, DATA_SIZE] {
+                    let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(len);
+                    unsafe { crate::danger::op_norm::test_l2_normalize::<$t, $im>(l1) };
+                }
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _ordered>]() {
+                use crate::danger::op_ordered::tests::test_ordered;
+                for len in [0, 1, 63, 64, 65, DATA_SIZE] {
+                    let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(len);
+                    unsafe { test_ordered::<$t, $im>(l1, l2) };
+                }
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _compensated>]() {
+                use crate::danger::op_compensated::tests::*;
+                for len in [0, 1, 63, 64, 65, DATA_SIZE] {
+                    let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(len);
+                    unsafe { test_compensated::<$t, $im>(l1, l2) };
+                }
+
+                let big = (2.0 as $t).powi(<$t>::MANTISSA_DIGITS as i32 + 6);
+                unsafe { test_compensated_cancellation::<$t, $im>(big) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _hypot_special>]() {
+                let big = (2.0 as $t).powi(100);
+                let small = (2.0 as $t).powi(-100);
+                let cases = [
+                    (3.0, 4.0, 5.0),
+                    (-3.0, -4.0, 5.0),
+                    (0.0, 0.0, 0.0),
+                    (-0.0, 5.0, 5.0),
+                    (3.0 * big, 4.0 * big, 5.0 * big),
+                    (3.0 * small, 4.0 * small, 5.0 * small),
+                    ($t::INFINITY, $t::NAN, $t::INFINITY),
+                    ($t::NAN, $t::NEG_INFINITY, $t::INFINITY),
+                    ($t::NAN, 1.0, $t::NAN),
+                    (1.0, $t::NAN, $t::NAN),
+                ];
+
+                let cases = cases.iter().cycle().take(DATA_SIZE);
+                let l1 = cases.clone().map(|c| c.0).collect();
+                let l2 = cases.clone().map(|c| c.1).collect();
+                let expected = cases.map(|c| c.2).collect();
+                unsafe {
+                    crate::danger::op_hypot::tests::test_hypot_special::<$t, $im>(
+                        l1, l2, expected,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _neg_special>]() {
+                let cases = [
+                    1.5,
+                    -2.0,
+                    0.0,
+                    -0.0,
+                    $t::MIN_POSITIVE,
+                    $t::MAX,
+                    $t::MIN,
+                    $t::INFINITY,
+                    $t::NEG_INFINITY,
+                    $t::NAN,
+                    -$t::NAN,
+                ];
+
+                let l1 = cases.iter().copied().cycle().take(DATA_SIZE).collect();
+                unsafe {
+                    crate::danger::op_arithmetic_vertical::tests::test_neg_special::<$t, $im, _>(
+                        l1,
+                        $t::to_bits,
+                    )
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _float_class>]() {
+                let subnormal = $t::MIN_POSITIVE / 4.0;
+                let cases = [
+                    1.5,
+                    -2.0,
+                    0.0,
+                    -0.0,
+                    subnormal,
+                    -subnormal,
+                    $t::MIN_POSITIVE,
+                    $t::MAX,
+                    $t::MIN,
+                    $t::INFINITY,
+                    $t::NEG_INFINITY,
+                    $t::NAN,
+                    -$t::NAN,
+                ];
+
+                let l1 = cases.iter().copied().cycle().take(DATA_SIZE).collect::<Vec<_>>();
+                let is_nan = l1.iter().map(|v| v.is_nan()).collect();
+                let is_finite = l1.iter().map(|v| v.is_finite()).collect();
+                let is_infinite = l1.iter().map(|v| v.is_infinite()).collect();
+                unsafe {
+                    crate::danger::op_float_class::tests::test_is_nan::<$t, $im>(
+                        l1.clone(),
+                        is_nan,
+                    );
+                    crate::danger::op_float_class::tests::test_is_finite::<$t, $im>(
+                        l1.clone(),
+                        is_finite,
+                    );
+                    crate::danger::op_float_class::tests::test_is_infinite::<$t, $im>(
+                        l1,
+                        is_infinite,
+                    );
+                }
+
+                // A single special value at each position checks the early exit of the
+                // reductions on the dense lane, single register and remainder paths.
+                let (sample, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                for pos in [0, DATA_SIZE / 2, DATA_SIZE - 1] {
+                    for special in [$t::NAN, $t::INFINITY, $t::NEG_INFINITY, subnormal] {
+                        let mut l1 = sample.clone();
+                        l1[pos] = special;
+
+                        let mut expected = vec![false; DATA_SIZE];
+                        expected[pos] = special.is_nan();
+                        unsafe {
+                            crate::danger::op_float_class::tests::test_is_nan::<$t, $im>(
+                                l1.clone(),
+                                expected,
+                            )
+                        };
+
+                        let mut expected = vec![true; DATA_SIZE];
+                        expected[pos] = special.is_finite();
+                        unsafe {
+                            crate::danger::op_float_class::tests::test_is_finite::<$t, $im>(
+                                l1.clone(),
+                                expected,
+                            )
+                        };
+
+                        let mut expected = vec![false; DATA_SIZE];
+                        expected[pos] = special.is_infinite();
+                        unsafe {
+                            crate::danger::op_float_class::tests::test_is_infinite::<$t, $im>(
+                                l1,
+                                expected,
+                            )
+                        };
+                    }
+                }
+            }
         }
     };
 }
@@ -34,12 +501,265 @@ macro_rules! test_nan_sanity {
                 let l2 = vec![1.0, 1.0, 1.0, 1.0, 1.0];
 
                 test_cmp_value_all::<$t, $im>(l1.clone(), 0.0);
+                test_cmp_count_all::<$t, $im>(l1.clone(), 0.0);
+                test_cmp_any_all::<$t, $im>(l1.clone(), 0.0);
+                test_cmp_any_all::<$t, $im>(l1.clone(), $t::NAN);
                 test_cmp_vector_all::<$t, $im>(l1, l2);
             }
         }
     };
 }
 
+// `f16` values only hold ~3 significant digits, so the reductions are checked against the
+// same computation done in `f32` after converting the inputs up front rather than against
+// an `f16` reference which would round after every step.
+//
+// The vertical ops round once per element in every impl, so those must match exactly.
+#[cfg(feature = "half")]
+macro_rules! test_half_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _f16_distance>]() {
+                use half::f16;
+
+                const COSINE_SIZE: usize = DATA_SIZE / 2;
+
+                let (l1, l2) = crate::test_utils::get_sample_half_vectors(DATA_SIZE);
+                let l1_f32 = l1.iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+                let l2_f32 = l2.iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+
+                let cases = unsafe {
+                    [
+                        (
+                            "dot",
+                            op_dot::generic_dot::<f16, $im, AutoMath, _, _>(&l1, &l2),
+                            crate::test_utils::simple_dot(&l1_f32, &l2_f32),
+                        ),
+                        // The product of the two squared norms must stay within `f16::MAX`.
+                        (
+                            "cosine",
+                            op_cosine::generic_cosine::<f16, $im, AutoMath, _, _>(
+                                &l1[..COSINE_SIZE],
+                                &l2[..COSINE_SIZE],
+                            ),
+                            crate::test_utils::simple_cosine(
+                                &l1_f32[..COSINE_SIZE],
+                                &l2_f32[..COSINE_SIZE],
+                            ),
+                        ),
+                        (
+                            "squared_euclidean",
+                            op_euclidean::generic_squared_euclidean::<f16, $im, AutoMath, _, _>(
+                                &l1, &l2,
+                            ),
+                            crate::test_utils::simple_euclidean(&l1_f32, &l2_f32),
+                        ),
+                        (
+                            "squared_norm",
+                            op_norm::generic_squared_norm::<f16, $im, AutoMath, _>(&l1),
+                            crate::test_utils::simple_dot(&l1_f32, &l1_f32),
+                        ),
+                    ]
+                };
+
+                for (name, value, expected) in cases {
+                    let expected = f16::from_f32(expected);
+                    assert!(
+                        AutoMath::is_close(value, expected),
+                        "{name} value missmatch {value:?} vs {expected:?}"
+                    );
+                }
+            }
+
+            #[test]
+            fn [<test_ $im:lower _f16_arithmetic>]() {
+                let (l1, l2) = crate::test_utils::get_sample_half_vectors(DATA_SIZE);
+                unsafe {
+                    op_arithmetic_vertical::tests::test_simple_vector_add::<_, $im>(
+                        l1.clone(),
+                        l2.clone(),
+                    );
+                    op_arithmetic_vertical::tests::test_simple_vector_sub::<_, $im>(
+                        l1.clone(),
+                        l2.clone(),
+                    );
+                    op_arithmetic_vertical::tests::test_simple_vector_mul::<_, $im>(
+                        l1.clone(),
+                        l2.clone(),
+                    );
+                    op_arithmetic_vertical::tests::test_simple_vector_div::<_, $im>(l1, l2);
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _f16_load_write>]() {
+                use half::f16;
+
+                // Every `f16` is exactly representable as an `f32`, so converting
+                // on load and back on write must preserve the original values.
+                let (l1, _) = crate::test_utils::get_sample_half_vectors(DATA_SIZE);
+                let lane_size = <$im as SimdRegister<f16>>::elements_per_lane();
+
+                let mut result = vec![f16::ZERO; l1.len()];
+                for (src, dst) in l1
+                    .chunks_exact(lane_size)
+                    .zip(result.chunks_exact_mut(lane_size))
+                {
+                    unsafe {
+                        let reg = <$im as SimdRegister<f16>>::load(src.as_ptr());
+                        <$im as SimdRegister<f16>>::write(dst.as_mut_ptr(), reg);
+                    }
+                }
+
+                let written = l1.len() - (l1.len() % lane_size);
+                assert_eq!(result[..written], l1[..written], "round trip missmatch");
+            }
+
+            #[test]
+            fn [<test_ $im:lower _f16_fold>]() {
+                let (l1, l2) = crate::test_utils::get_sample_half_vectors(DATA_SIZE);
+                unsafe { crate::danger::op_fold::tests::test_fold::<_, $im>(l1, l2) };
+            }
+        }
+    };
+}
+
+// The `bf16` registers are tested the same way as `f16`, the `f32` accumulating routines
+// are compared against an `f64` reference where every product of two `bf16` values is exact.
+#[cfg(feature = "half")]
+macro_rules! test_bf16_extra {
+    ($im:ident) => {
+        paste::paste! {
+            #[test]
+            fn [<test_ $im:lower _bf16_distance>]() {
+                use half::bf16;
+
+                let (l1, l2) = crate::test_utils::get_sample_bf16_vectors(DATA_SIZE);
+                let l1_f32 = l1.iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+                let l2_f32 = l2.iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+
+                let cases = unsafe {
+                    [
+                        (
+                            "dot",
+                            op_dot::generic_dot::<bf16, $im, AutoMath, _, _>(&l1, &l2),
+                            crate::test_utils::simple_dot(&l1_f32, &l2_f32),
+                        ),
+                        (
+                            "squared_euclidean",
+                            op_euclidean::generic_squared_euclidean::<bf16, $im, AutoMath, _, _>(
+                                &l1, &l2,
+                            ),
+                            crate::test_utils::simple_euclidean(&l1_f32, &l2_f32),
+                        ),
+                        (
+                            "squared_norm",
+                            op_norm::generic_squared_norm::<bf16, $im, AutoMath, _>(&l1),
+                            crate::test_utils::simple_dot(&l1_f32, &l1_f32),
+                        ),
+                    ]
+                };
+
+                for (name, value, expected) in cases {
+                    let expected = bf16::from_f32(expected);
+                    assert!(
+                        AutoMath::is_close(value, expected),
+                        "{name} value missmatch {value:?} vs {expected:?}"
+                    );
+                }
+            }
+
+            #[test]
+            fn [<test_ $im:lower _bf16_f32acc>]() {
+                let (l1, l2) = crate::test_utils::get_sample_bf16_vectors(DATA_SIZE);
+
+                // Every product of two `bf16` values is exact in `f64`, so this only
+                // differs from the result by the rounding of the `f32` accumulation.
+                let expected_dot = l1
+                    .iter()
+                    .zip(l2.iter())
+                    .fold(0.0, |acc, (a, b)| acc + a.to_f64() * b.to_f64());
+                let expected_dist = l1.iter().zip(l2.iter()).fold(0.0, |acc, (a, b)| {
+                    let diff = a.to_f64() - b.to_f64();
+                    acc + diff * diff
+                });
+
+                let cases = unsafe {
+                    [
+                        (
+                            "dot",
+                            op_dot::generic_dot_wide_acc::<_, f32, $im, AutoMath>(&l1, &l2),
+                            expected_dot,
+                        ),
+                        (
+                            "squared_euclidean",
+                            op_euclidean::generic_squared_euclidean_wide_acc::<
+                                _,
+                                f32,
+                                $im,
+                                AutoMath,
+                            >(&l1, &l2),
+                            expected_dist,
+                        ),
+                    ]
+                };
+
+                for (name, value, expected) in cases {
+                    let diff = (value as f64 - expected).abs();
+                    assert!(
+                        diff <= expected.abs() * 1e-5,
+                        "{name} value missmatch {value:?} vs {expected:?}"
+                    );
+                }
+            }
+
+            #[test]
+            fn [<test_ $im:lower _bf16_write_rounding>]() {
+                use half::bf16;
+
+                // The sums are not representable as `bf16`, so writing them must round
+                // exactly like the scalar conversion, including the special values.
+                let (mut l1, l2) = crate::test_utils::get_sample_bf16_vectors(DATA_SIZE);
+                l1[..4].copy_from_slice(&[
+                    bf16::NAN,
+                    bf16::INFINITY,
+                    bf16::NEG_INFINITY,
+                    bf16::MAX,
+                ]);
+
+                let lane_size = <$im as SimdRegister<bf16>>::elements_per_lane();
+
+                let mut result = vec![bf16::ZERO; l1.len()];
+                for ((a, b), dst) in l1
+                    .chunks_exact(lane_size)
+                    .zip(l2.chunks_exact(lane_size))
+                    .zip(result.chunks_exact_mut(lane_size))
+                {
+                    unsafe {
+                        let reg = <$im as SimdRegister<bf16>>::add(
+                            <$im as SimdRegister<bf16>>::load(a.as_ptr()),
+                            <$im as SimdRegister<bf16>>::load(b.as_ptr()),
+                        );
+                        <$im as SimdRegister<bf16>>::write(dst.as_mut_ptr(), reg);
+                    }
+                }
+
+                let written = l1.len() - (l1.len() % lane_size);
+                for i in 0..written {
+                    let expected = bf16::from_f32(l1[i].to_f32() + l2[i].to_f32());
+                    assert_eq!(
+                        result[i].to_bits(),
+                        expected.to_bits(),
+                        "rounding missmatch at {i} {:?} vs {expected:?}",
+                        result[i],
+                    );
+                }
+            }
+        }
+    };
+}
+
 macro_rules! test_suite {
     ($t:ident, $im:ident) => {
         paste::paste! {
@@ -54,6 +774,145 @@ macro_rules! test_suite {
                 unsafe { crate::danger::op_dot::test_dot::<$t, $im>(l1, l2) };
             }
 
+            #[test]
+            fn [<test_ $im:lower _ $t _fold>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_fold::tests::test_fold::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _weighted_dot>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                let weights = l2.iter().rev().copied().collect();
+                unsafe { crate::danger::op_dot::test_weighted_dot::<$t, $im>(l1, l2, weights) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_batch>]() {
+                unsafe { crate::danger::op_batch_distance::tests::test_dot_batch::<$t, $im>(131, 7) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_indexed>]() {
+                unsafe { crate::danger::op_batch_distance::tests::test_dot_indexed::<$t, $im>(131, 7) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _batch_dot>]() {
+                use crate::danger::op_batch_distance::tests::test_batch_dot;
+                unsafe {
+                    test_batch_dot::<$t, $im>(131, 7);
+                    test_batch_dot::<$t, $im>(533, 8);
+                    test_batch_dot::<$t, $im>(3, 13);
+                    test_batch_dot::<$t, $im>(17, 0);
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _gemv>]() {
+                use crate::danger::op_gemv::tests::test_gemv;
+                unsafe {
+                    test_gemv::<$t, $im>(37, 533);
+                    test_gemv::<$t, $im>(1, 1);
+                    test_gemv::<$t, $im>(533, 3);
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _euclidean_batch>]() {
+                unsafe {
+                    crate::danger::op_batch_distance::tests::test_squared_euclidean_batch::<$t, $im>(131, 7)
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _select>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_select::tests::test_select::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _gather>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_gather::tests::test_gather::<$t, $im>(l1.clone());
+                    crate::danger::op_gather::tests::test_scatter::<$t, $im>(l1);
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _compact>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe {
+                    crate::danger::op_compact::tests::test_compact::<$t, $im>(l1.clone());
+                    crate::danger::op_compact::tests::test_mask_to_indices::<$t, $im>(l1);
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _cmp_bitmask>]() {
+                let (l1, l2) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_cmp_bitmask::tests::test_cmp_bitmask::<$t, $im>(l1, l2) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _cumulative>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                unsafe { crate::danger::op_prefix_sum::test_cumulative::<$t, $im>(l1) };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _dot_matrix>]() {
+                use crate::danger::op_distance_matrix::tests::test_dot_matrix;
+                unsafe {
+                    test_dot_matrix::<$t, $im>(67, 5, 9);
+                    test_dot_matrix::<$t, $im>(35, 3, 1000);
+                    test_dot_matrix::<$t, $im>(13, 1, 1);
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _gram_matrix>]() {
+                use crate::danger::op_gram::tests::{test_gram_matrix, test_gram_matrix_blocked};
+                unsafe {
+                    test_gram_matrix::<$t, $im>(9, 67);
+                    test_gram_matrix::<$t, $im>(1, 13);
+                    test_gram_matrix::<$t, $im>(0, 5);
+                    test_gram_matrix_blocked::<$t, $im>(8191);
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _pairwise_distances>]() {
+                use crate::danger::op_batch_distance::tests::test_pairwise_distances;
+                unsafe {
+                    test_pairwise_distances::<$t, $im>(9, 67);
+                    test_pairwise_distances::<$t, $im>(4, 1043);
+                    test_pairwise_distances::<$t, $im>(1, 13);
+                    test_pairwise_distances::<$t, $im>(0, 5);
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _euclidean_matrix>]() {
+                use crate::danger::op_distance_matrix::tests::test_squared_euclidean_matrix;
+                unsafe {
+                    test_squared_euclidean_matrix::<$t, $im>(67, 5, 9);
+                    test_squared_euclidean_matrix::<$t, $im>(35, 3, 1000);
+                    test_squared_euclidean_matrix::<$t, $im>(13, 1, 1);
+                };
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _outer_product>]() {
+                use crate::danger::op_outer_product::tests::test_outer_product;
+                unsafe {
+                    test_outer_product::<$t, $im>(9, 533);
+                    test_outer_product::<$t, $im>(1, 1);
+                    test_outer_product::<$t, $im>(533, 3);
+                };
+            }
+
             #[test]
             fn [<test_ $im:lower _ $t _norm>]() {
                 let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
@@ -84,6 +943,12 @@ macro_rules! test_suite {
                 unsafe { crate::danger::op_sum::test_sum::<$t, $im>(l1) };
             }
 
+            #[test]
+            fn [<test_ $im:lower _ $t _kahan_sum>]() {
+                let l1 = vec![1 as $t; DATA_SIZE];
+                unsafe { crate::danger::op_sum::test_kahan_sum::<$t, $im>(l1) };
+            }
+
             #[test]
             fn [<test_ $im:lower _ $t _arithmetic_value>]() {
                 let (l1, _) = (vec![1 as $t; DATA_SIZE], vec![3 as $t; DATA_SIZE]);
@@ -96,12 +961,45 @@ macro_rules! test_suite {
                 test_arithmetic_vector_all::<$t, $im>(l1, l2);
             }
 
+            #[test]
+            fn [<test_ $im:lower _ $t _arithmetic_uninit>]() {
+                let (l1, l2) = (vec![1 as $t; DATA_SIZE], vec![3 as $t; DATA_SIZE]);
+                unsafe {
+                    crate::danger::op_arithmetic_vertical::tests::test_vector_uninit::<$t, $im>(l1, l2)
+                };
+            }
+
             #[test]
             fn [<test_ $im:lower _ $t _cmp_value>]() {
                 let (l1, _) = (vec![1 as $t; DATA_SIZE], vec![3 as $t; DATA_SIZE]);
                 test_cmp_value_all::<$t, $im>(l1, 2 as $t);
             }
 
+            #[test]
+            fn [<test_ $im:lower _ $t _cmp_count>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                test_cmp_count_all::<$t, $im>(l1.clone(), l1[7]);
+                test_cmp_count_all::<$t, $im>(l1, 2 as $t);
+
+                // Enough elements that every lane must be flushed several times.
+                let l1 = vec![1 as $t; if cfg!(miri) { 1_043 } else { 20_011 }];
+                test_cmp_count_all::<$t, $im>(l1, 1 as $t);
+            }
+
+            #[test]
+            fn [<test_ $im:lower _ $t _cmp_any_all>]() {
+                let (l1, _) = crate::test_utils::get_sample_vectors::<$t>(DATA_SIZE);
+                test_cmp_any_all::<$t, $im>(l1.clone(), l1[7]);
+                test_cmp_any_all::<$t, $im>(l1, 2 as $t);
+
+                // A uniform input means `all` must check every element, the single differing
+                // element then falls in the scalar remainder.
+                let mut l1 = vec![1 as $t; DATA_SIZE];
+                test_cmp_any_all::<$t, $im>(l1.clone(), 1 as $t);
+                l1[DATA_SIZE - 1] = 2 as $t;
+                test_cmp_any_all::<$t, $im>(l1, 1 as $t);
+            }
+
             #[test]
             fn [<test_ $im:lower _ $t _cmp_vector>]() {
                 let (l1, l2) = (vec![1 as $t; DATA_SIZE], vec![3 as $t; DATA_SIZE]);
@@ -301,13 +1199,25 @@ where
             l1.clone(),
             value,
         );
-        op_arithmetic_vertical::tests::test_broadcast_value_mul::<_, R>(l1, value)
+        op_arithmetic_vertical::tests::test_broadcast_value_lhs_sub::<_, R>(
+            value,
+            l1.clone(),
+        );
+        op_arithmetic_vertical::tests::test_broadcast_value_lhs_div::<_, R>(
+            value,
+            l1.clone(),
+        );
+        op_arithmetic_vertical::tests::test_broadcast_value_mul::<_, R>(
+            l1.clone(),
+            value,
+        );
+        op_arithmetic_vertical::tests::test_inplace_value_all::<_, R>(l1, value)
     };
 }
 
 fn test_arithmetic_vector_all<T, R>(l1: Vec<T>, l2: Vec<T>)
 where
-    T: Copy + PartialEq + Debug,
+    T: Copy + Default + PartialEq + Debug,
     R: SimdRegister<T>,
     AutoMath: Math<T>,
     for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
@@ -325,7 +1235,13 @@ where
             l1.clone(),
             l2.clone(),
         );
-        op_arithmetic_vertical::tests::test_simple_vector_mul::<_, R>(l1, l2);
+        op_arithmetic_vertical::tests::test_simple_vector_mul::<_, R>(
+            l1.clone(),
+            l2.clone(),
+        );
+        op_arithmetic_vertical::tests::test_alternating_sign_flip::<_, R>(l1.clone());
+        op_arithmetic_vertical::tests::test_neg_vertical::<_, R>(l1.clone());
+        op_arithmetic_vertical::tests::test_inplace_vector_all::<_, R>(l1, l2);
     };
 }
 
@@ -363,6 +1279,46 @@ where
     };
 }
 
+fn test_cmp_any_all<T, R>(l1: Vec<T>, value: T)
+where
+    T: Copy + Debug,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    unsafe {
+        op_cmp_any_all::tests::test_any_eq::<_, R>(l1.clone(), value);
+        op_cmp_any_all::tests::test_any_neq::<_, R>(l1.clone(), value);
+        op_cmp_any_all::tests::test_any_lt::<_, R>(l1.clone(), value);
+        op_cmp_any_all::tests::test_any_lte::<_, R>(l1.clone(), value);
+        op_cmp_any_all::tests::test_any_gt::<_, R>(l1.clone(), value);
+        op_cmp_any_all::tests::test_any_gte::<_, R>(l1.clone(), value);
+        op_cmp_any_all::tests::test_all_eq::<_, R>(l1.clone(), value);
+        op_cmp_any_all::tests::test_all_neq::<_, R>(l1.clone(), value);
+        op_cmp_any_all::tests::test_all_lt::<_, R>(l1.clone(), value);
+        op_cmp_any_all::tests::test_all_lte::<_, R>(l1.clone(), value);
+        op_cmp_any_all::tests::test_all_gt::<_, R>(l1.clone(), value);
+        op_cmp_any_all::tests::test_all_gte::<_, R>(l1, value);
+    };
+}
+
+fn test_cmp_count_all<T, R>(l1: Vec<T>, value: T)
+where
+    T: MaskCount + Debug,
+    R: SimdRegister<T>,
+    AutoMath: Math<T>,
+{
+    unsafe {
+        op_cmp_count::tests::test_count_eq::<_, R>(l1.clone(), value);
+        op_cmp_count::tests::test_count_neq::<_, R>(l1.clone(), value);
+        op_cmp_count::tests::test_count_lt::<_, R>(l1.clone(), value);
+        op_cmp_count::tests::test_count_lte::<_, R>(l1.clone(), value);
+        op_cmp_count::tests::test_count_gt::<_, R>(l1.clone(), value);
+        op_cmp_count::tests::test_count_gte::<_, R>(l1.clone(), value);
+        op_cmp_count::tests::test_count_nonzero::<_, R>(l1);
+        op_cmp_count::tests::test_count_tail_only::<_, R>();
+    };
+}
+
 test_suite!(f32, Fallback);
 test_suite!(f64, Fallback);
 test_suite!(i8, Fallback);
@@ -383,9 +1339,54 @@ test_cosine_extra!(u16, Fallback);
 test_cosine_extra!(u32, Fallback);
 test_cosine_extra!(u64, Fallback);
 
+test_bitwise_extra!(i8, Fallback);
+test_bitwise_extra!(i16, Fallback);
+test_bitwise_extra!(i32, Fallback);
+test_bitwise_extra!(i64, Fallback);
+test_bitwise_extra!(u8, Fallback);
+test_bitwise_extra!(u16, Fallback);
+test_bitwise_extra!(u32, Fallback);
+test_bitwise_extra!(u64, Fallback);
+
+test_saturating_extra!(i8, Fallback);
+test_saturating_extra!(i16, Fallback);
+test_saturating_extra!(i32, Fallback);
+test_saturating_extra!(i64, Fallback);
+test_saturating_extra!(u8, Fallback);
+test_saturating_extra!(u16, Fallback);
+test_saturating_extra!(u32, Fallback);
+test_saturating_extra!(u64, Fallback);
+
+test_cast_extra!(Fallback);
+test_f64acc_extra!(Fallback);
+
+test_wide_acc_extra!(i32, i64, Fallback);
+test_wide_acc_extra!(u32, u64, Fallback);
+test_wide_acc_extra!(u16, u32, Fallback);
+test_cosine_wide_acc_extra!(u8, u32, Fallback);
+test_cosine_wide_acc_extra!(u16, u64, Fallback);
+test_hamming_extra!(Fallback);
+
+test_dot_widen_extra!(i8, Fallback);
+test_dot_widen_extra!(u8, Fallback);
+test_dot_widen_extra!(i16, Fallback);
+
+test_float_extra!(f32, Fallback);
+test_float_extra!(f64, Fallback);
+
+test_stats_extra!(f32, Fallback);
+test_stats_extra!(f64, Fallback);
+test_stats_extra!(i32, Fallback);
+test_stats_extra!(i64, Fallback);
+
 test_nan_sanity!(f32, Fallback);
 test_nan_sanity!(f64, Fallback);
 
+#[cfg(feature = "half")]
+test_half_extra!(Fallback);
+#[cfg(feature = "half")]
+test_bf16_extra!(Fallback);
+
 #[cfg(all(target_feature = "avx2", test))]
 mod avx2_tests {
     use super::*;
@@ -410,11 +1411,54 @@ mod avx2_tests {
     test_cosine_extra!(u32, Avx2);
     test_cosine_extra!(u64, Avx2);
 
+    test_bitwise_extra!(i8, Avx2);
+    test_bitwise_extra!(i16, Avx2);
+    test_bitwise_extra!(i32, Avx2);
+    test_bitwise_extra!(i64, Avx2);
+    test_bitwise_extra!(u8, Avx2);
+    test_bitwise_extra!(u16, Avx2);
+    test_bitwise_extra!(u32, Avx2);
+    test_bitwise_extra!(u64, Avx2);
+
+    test_saturating_extra!(i8, Avx2);
+    test_saturating_extra!(i16, Avx2);
+    test_saturating_extra!(i32, Avx2);
+    test_saturating_extra!(i64, Avx2);
+    test_saturating_extra!(u8, Avx2);
+    test_saturating_extra!(u16, Avx2);
+    test_saturating_extra!(u32, Avx2);
+    test_saturating_extra!(u64, Avx2);
+
+    test_cast_extra!(Avx2);
+    test_f64acc_extra!(Avx2);
+
+    test_wide_acc_extra!(i32, i64, Avx2);
+    test_wide_acc_extra!(u32, u64, Avx2);
+    test_wide_acc_extra!(u16, u32, Avx2);
+    test_cosine_wide_acc_extra!(u8, u32, Avx2);
+    test_cosine_wide_acc_extra!(u16, u64, Avx2);
+    test_hamming_extra!(Avx2);
+
+    test_dot_widen_extra!(i8, Avx2);
+    test_dot_widen_extra!(u8, Avx2);
+    test_dot_widen_extra!(i16, Avx2);
+
+    test_float_extra!(f32, Avx2);
+    test_float_extra!(f64, Avx2);
+
+    test_stats_extra!(f32, Avx2);
+    test_stats_extra!(f64, Avx2);
+    test_stats_extra!(i32, Avx2);
+    test_stats_extra!(i64, Avx2);
+
     test_nan_sanity!(f32, Avx2);
     test_nan_sanity!(f64, Avx2);
+
+    #[cfg(feature = "half")]
+    test_bf16_extra!(Avx2);
 }
 
-#[cfg(all(target_feature = "avx512f", feature = "nightly", test))]
+#[cfg(all(target_feature = "avx512f", feature = "stable-avx512", test))]
 mod avx512_tests {
     use super::*;
 
@@ -431,17 +1475,96 @@ mod avx512_tests {
 
     test_cosine_extra!(f32, Avx512);
     test_cosine_extra!(f64, Avx512);
-    test_cosine_extra!(i32, Avx512);
-    test_cosine_extra!(i64, Avx512);
+    test_cosine_extra!(i32, Avx512, bounded_batch);
+    test_cosine_extra!(i64, Avx512, bounded_batch);
     test_cosine_extra!(u8, Avx512);
     test_cosine_extra!(u16, Avx512);
     test_cosine_extra!(u32, Avx512);
     test_cosine_extra!(u64, Avx512);
 
+    test_bitwise_extra!(i8, Avx512);
+    test_bitwise_extra!(i16, Avx512);
+    test_bitwise_extra!(i32, Avx512);
+    test_bitwise_extra!(i64, Avx512);
+    test_bitwise_extra!(u8, Avx512);
+    test_bitwise_extra!(u16, Avx512);
+    test_bitwise_extra!(u32, Avx512);
+    test_bitwise_extra!(u64, Avx512);
+
+    test_saturating_extra!(i8, Avx512);
+    test_saturating_extra!(i16, Avx512);
+    test_saturating_extra!(i32, Avx512);
+    test_saturating_extra!(i64, Avx512);
+    test_saturating_extra!(u8, Avx512);
+    test_saturating_extra!(u16, Avx512);
+    test_saturating_extra!(u32, Avx512);
+    test_saturating_extra!(u64, Avx512);
+
+    test_cast_extra!(Avx512);
+    test_f64acc_extra!(Avx512);
+
+    test_wide_acc_extra!(i32, i64, Avx512);
+    test_wide_acc_extra!(u32, u64, Avx512);
+    test_wide_acc_extra!(u16, u32, Avx512);
+    test_cosine_wide_acc_extra!(u8, u32, Avx512);
+    test_cosine_wide_acc_extra!(u16, u64, Avx512);
+    test_hamming_extra!(Avx512);
+
+    test_float_extra!(f32, Avx512);
+    test_float_extra!(f64, Avx512);
+
+    test_stats_extra!(f32, Avx512);
+    test_stats_extra!(f64, Avx512);
+    test_stats_extra!(i32, Avx512);
+    test_stats_extra!(i64, Avx512);
+
     test_nan_sanity!(f32, Avx512);
     test_nan_sanity!(f64, Avx512);
 }
 
+#[cfg(all(target_feature = "avx512vnni", feature = "stable-avx512", test))]
+mod avx512vnni_tests {
+    use super::*;
+
+    test_dot_widen_extra!(i8, Avx512Vnni);
+    test_dot_widen_extra!(u8, Avx512Vnni);
+    test_dot_widen_extra!(i16, Avx512Vnni);
+}
+
+#[cfg(all(
+    target_feature = "avx512bf16",
+    feature = "stable-avx512",
+    feature = "half",
+    test
+))]
+mod avx512bf16_tests {
+    use super::*;
+
+    #[test]
+    fn test_avx512bf16_bf16_dot() {
+        // Covers the dense lanes, the single registers and the scalar tail.
+        for size in [0, 31, 32, 256, DATA_SIZE, DATA_SIZE + 32 * 3] {
+            let (l1, l2) = crate::test_utils::get_sample_bf16_vectors(size);
+
+            let native = unsafe { Avx512Bf16::dot_f32acc(&l1, &l2) };
+            let emulated = unsafe {
+                op_dot::generic_dot_wide_acc::<_, f32, Fallback, AutoMath>(&l1, &l2)
+            };
+            assert!(
+                AutoMath::is_close(native, emulated),
+                "dot value missmatch for {size} elements {native:?} vs {emulated:?}"
+            );
+        }
+    }
+}
+
+#[cfg(all(target_feature = "avx512vpopcntdq", feature = "stable-avx512", test))]
+mod avx512vpopcntdq_tests {
+    use super::*;
+
+    test_hamming_extra!(Avx512Vpopcntdq);
+}
+
 #[cfg(all(target_feature = "avx2", target_feature = "fma", test))]
 mod avx2fma_tests {
     use super::*;
@@ -451,6 +1574,26 @@ mod avx2fma_tests {
 
     test_cosine_extra!(f32, Avx2Fma);
     test_cosine_extra!(f64, Avx2Fma);
+
+    test_f64acc_extra!(Avx2Fma);
+
+    test_float_extra!(f32, Avx2Fma);
+    test_float_extra!(f64, Avx2Fma);
+
+    test_stats_extra!(f32, Avx2Fma);
+    test_stats_extra!(f64, Avx2Fma);
+}
+
+#[cfg(all(
+    target_feature = "avx2",
+    target_feature = "f16c",
+    feature = "half",
+    test
+))]
+mod avx2f16c_tests {
+    use super::*;
+
+    test_half_extra!(Avx2F16C);
 }
 
 #[cfg(all(target_feature = "neon", test))]
@@ -470,15 +1613,153 @@ mod neon_tests {
 
     test_cosine_extra!(f32, Neon);
     test_cosine_extra!(f64, Neon);
-    test_cosine_extra!(i8, Neon);
+    test_cosine_extra!(i8, Neon, bounded_batch);
     // test_cosine_extra!(i16, Neon); - Divide by zero error from RNG.
-    test_cosine_extra!(i32, Neon);
-    test_cosine_extra!(i64, Neon);
+    test_cosine_extra!(i32, Neon, bounded_batch);
+    test_cosine_extra!(i64, Neon, bounded_batch);
     test_cosine_extra!(u8, Neon);
     test_cosine_extra!(u16, Neon);
     test_cosine_extra!(u32, Neon);
     test_cosine_extra!(u64, Neon);
 
+    test_bitwise_extra!(i8, Neon);
+    test_bitwise_extra!(i16, Neon);
+    test_bitwise_extra!(i32, Neon);
+    test_bitwise_extra!(i64, Neon);
+    test_bitwise_extra!(u8, Neon);
+    test_bitwise_extra!(u16, Neon);
+    test_bitwise_extra!(u32, Neon);
+    test_bitwise_extra!(u64, Neon);
+
+    test_saturating_extra!(i8, Neon);
+    test_saturating_extra!(i16, Neon);
+    test_saturating_extra!(i32, Neon);
+    test_saturating_extra!(i64, Neon);
+    test_saturating_extra!(u8, Neon);
+    test_saturating_extra!(u16, Neon);
+    test_saturating_extra!(u32, Neon);
+    test_saturating_extra!(u64, Neon);
+
+    test_cast_extra!(Neon);
+    test_f64acc_extra!(Neon);
+
+    test_wide_acc_extra!(i32, i64, Neon);
+    test_wide_acc_extra!(u32, u64, Neon);
+    test_wide_acc_extra!(u16, u32, Neon);
+    test_cosine_wide_acc_extra!(u8, u32, Neon);
+    test_cosine_wide_acc_extra!(u16, u64, Neon);
+    test_hamming_extra!(Neon);
+
+    test_dot_widen_extra!(i8, Neon);
+    test_dot_widen_extra!(u8, Neon);
+    test_dot_widen_extra!(i16, Neon);
+
+    test_float_extra!(f32, Neon);
+    test_float_extra!(f64, Neon);
+
+    test_stats_extra!(f32, Neon);
+    test_stats_extra!(f64, Neon);
+    test_stats_extra!(i32, Neon);
+    test_stats_extra!(i64, Neon);
+
     test_nan_sanity!(f32, Neon);
     test_nan_sanity!(f64, Neon);
+
+    #[test]
+    fn test_neon_f32_fmadd_is_fused() {
+        // `(1 + 2^-12)^2` is not representable as an `f32`, a fused multiply-add
+        // keeps the `2^-24` term which is otherwise lost when rounding the product.
+        let x = 1.0f32 + 2f32.powi(-12);
+        let acc = -(1.0f32 + 2f32.powi(-11));
+        let value = unsafe {
+            let res = <Neon as SimdRegister<f32>>::fmadd(
+                <Neon as SimdRegister<f32>>::filled(x),
+                <Neon as SimdRegister<f32>>::filled(x),
+                <Neon as SimdRegister<f32>>::filled(acc),
+            );
+            <Neon as SimdRegister<f32>>::sum_to_value(res)
+        };
+        assert_eq!(value, 4.0 * 2f32.powi(-24));
+    }
+
+    #[test]
+    fn test_neon_f64_fmadd_is_fused() {
+        // `(1 + 2^-27)^2` is not representable as an `f64`, a fused multiply-add
+        // keeps the `2^-54` term which is otherwise lost when rounding the product.
+        let x = 1.0f64 + 2f64.powi(-27);
+        let acc = -(1.0f64 + 2f64.powi(-26));
+        let value = unsafe {
+            let res = <Neon as SimdRegister<f64>>::fmadd(
+                <Neon as SimdRegister<f64>>::filled(x),
+                <Neon as SimdRegister<f64>>::filled(x),
+                <Neon as SimdRegister<f64>>::filled(acc),
+            );
+            <Neon as SimdRegister<f64>>::sum_to_value(res)
+        };
+        assert_eq!(value, 2.0 * 2f64.powi(-54));
+    }
+}
+
+/// The ordered reductions must produce exactly the same bits on every register
+/// available to the machine, not just approximately equal values.
+#[test]
+fn test_ordered_bit_identical_across_registers() {
+    use crate::danger::export_agg_ops::*;
+    use crate::danger::export_distance_ops::*;
+
+    for len in [0, 1, 7, 63, 64, 65, DATA_SIZE, 4099] {
+        let (l1, l2) = crate::test_utils::get_sample_vectors::<f32>(len);
+        let l1 = l1.iter().map(|v| v * 1.37e-3).collect::<Vec<_>>();
+
+        let expected = unsafe {
+            [
+                generic_fallback_dot_ordered(&l1, &l2).to_bits(),
+                generic_fallback_squared_norm_ordered(&l1).to_bits(),
+                generic_fallback_sum_ordered(&l1).to_bits(),
+            ]
+        };
+
+        #[allow(unused_mut)]
+        let mut results = Vec::new();
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        if crate::dispatch::is_avx2_available() {
+            results.push(("Avx2", unsafe {
+                [
+                    generic_avx2_dot_ordered(&l1, &l2).to_bits(),
+                    generic_avx2_squared_norm_ordered(&l1).to_bits(),
+                    generic_avx2_sum_ordered(&l1).to_bits(),
+                ]
+            }));
+        }
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            feature = "stable-avx512"
+        ))]
+        if crate::dispatch::is_avx512_available() {
+            results.push(("Avx512", unsafe {
+                [
+                    generic_avx512_dot_ordered(&l1, &l2).to_bits(),
+                    generic_avx512_squared_norm_ordered(&l1).to_bits(),
+                    generic_avx512_sum_ordered(&l1).to_bits(),
+                ]
+            }));
+        }
+        #[cfg(target_arch = "aarch64")]
+        if crate::dispatch::is_neon_available() {
+            results.push(("Neon", unsafe {
+                [
+                    generic_neon_dot_ordered(&l1, &l2).to_bits(),
+                    generic_neon_squared_norm_ordered(&l1).to_bits(),
+                    generic_neon_sum_ordered(&l1).to_bits(),
+                ]
+            }));
+        }
+
+        for (name, bits) in results {
+            assert_eq!(
+                bits, expected,
+                "{name} result bits differ from Fallback for length {len}",
+            );
+        }
+    }
 }
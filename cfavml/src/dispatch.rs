@@ -10,6 +10,7 @@
 ///
 /// - AVX512 (`avx512f` + `avx512bw`)
 /// - AVX2 + FMA
+/// - AVX2 + F16C
 /// - AVX2
 /// - Fallback
 ///
@@ -44,13 +45,14 @@ macro_rules! dispatch {
     (
         $(avx512 = $avx512_fn:expr,)?
         $(avx2fma = $avx2fma_fn:expr,)?
+        $(avx2f16c = $avx2f16c_fn:expr,)?
         $(avx2 = $avx2_fn:expr,)?
         $(neon = $neon_fn:expr,)?
         fallback = $fallback_fn:expr,
         args = $args:tt
     ) => {{
         $(
-            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+            #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "stable-avx512"))]
             if $crate::dispatch::is_avx512_available() {
                 return $avx512_fn $args;
             }
@@ -63,6 +65,13 @@ macro_rules! dispatch {
             }
         )?
 
+        $(
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            if $crate::dispatch::is_avx2_available() && $crate::dispatch::is_f16c_available() {
+                return $avx2f16c_fn $args;
+            }
+        )?
+
         $(
             #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
             if $crate::dispatch::is_avx2_available() {
@@ -81,7 +90,10 @@ macro_rules! dispatch {
     }};
 }
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
 #[inline(always)]
 /// Returns if AVX512 is available to the system.
 ///
@@ -106,6 +118,72 @@ pub fn is_avx512_available() -> bool {
     false
 }
 
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+#[inline(always)]
+/// Returns if AVX512 with the `avx512vpopcntdq` extension is available to the system.
+///
+/// If this is compiling for a no std target, this selection is done
+/// at compile time only.
+///
+/// NOTE:
+///
+/// Internally this checks `avx512f`, `avx512bw` and `avx512vpopcntdq` only.
+pub fn is_avx512_vpopcntdq_available() -> bool {
+    if cfg!(all(
+        target_feature = "avx512f",
+        target_feature = "avx512bw",
+        target_feature = "avx512vpopcntdq",
+    )) {
+        return true;
+    }
+
+    #[cfg(feature = "std")]
+    if std::arch::is_x86_feature_detected!("avx512f")
+        && std::arch::is_x86_feature_detected!("avx512bw")
+        && std::arch::is_x86_feature_detected!("avx512vpopcntdq")
+    {
+        return true;
+    }
+
+    false
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "stable-avx512"
+))]
+#[inline(always)]
+/// Returns if AVX512 with the `avx512bf16` extension is available to the system.
+///
+/// If this is compiling for a no std target, this selection is done
+/// at compile time only.
+///
+/// NOTE:
+///
+/// Internally this checks `avx512f`, `avx512bw` and `avx512bf16` only.
+pub fn is_avx512_bf16_available() -> bool {
+    if cfg!(all(
+        target_feature = "avx512f",
+        target_feature = "avx512bw",
+        target_feature = "avx512bf16",
+    )) {
+        return true;
+    }
+
+    #[cfg(feature = "std")]
+    if std::arch::is_x86_feature_detected!("avx512f")
+        && std::arch::is_x86_feature_detected!("avx512bw")
+        && std::arch::is_x86_feature_detected!("avx512bf16")
+    {
+        return true;
+    }
+
+    false
+}
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[inline(always)]
 /// Returns if AVX2 is available to the system.
@@ -144,6 +222,25 @@ pub fn is_fma_available() -> bool {
     false
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+/// Returns if F16C is available to the system.
+///
+/// If this is compiling for a no std target, this selection is done
+/// at compile time only.
+pub fn is_f16c_available() -> bool {
+    if cfg!(target_feature = "f16c") {
+        return true;
+    }
+
+    #[cfg(feature = "std")]
+    if std::arch::is_x86_feature_detected!("f16c") {
+        return true;
+    }
+
+    false
+}
+
 #[cfg(target_arch = "aarch64")]
 #[inline(always)]
 /// Returns if NEON is available to the system.
@@ -162,3 +259,482 @@ pub fn is_neon_available() -> bool {
 
     false
 }
+
+/// Wraps the unsafe `$func` in a function pointer once the required CPU features
+/// have been detected.
+macro_rules! dispatcher_fn {
+    ($func:path, ($($arg:ident: $arg_ty:ty),*) -> $ret:ty) => {
+        (|$($arg: $arg_ty),*| -> $ret { unsafe { $func($($arg),*) } })
+            as fn($($arg_ty),*) -> $ret
+    };
+}
+
+/// Defines the method calling the selected function pointer of the dispatcher.
+macro_rules! dispatcher_method {
+    ($(#[doc = $doc:expr])* $name:ident ($($arg:ident: $arg_ty:ty),*) -> $ret:ty) => {
+        #[inline]
+        $(#[doc = $doc])*
+        pub fn $name(&self, $($arg: $arg_ty),*) -> $ret {
+            (self.$name)($($arg),*)
+        }
+    };
+}
+
+/// Defines the free function calling the selected function pointer of the global dispatcher.
+macro_rules! dispatcher_free_fn {
+    ($(#[doc = $doc:expr])* $name:ident ($($arg:ident: $arg_ty:ty),*) -> $ret:ty) => {
+        #[inline]
+        $(#[doc = $doc])*
+        ///
+        /// The implementation is selected using the dispatcher returned by
+        /// [Dispatcher::global].
+        pub fn $name($($arg: $arg_ty),*) -> $ret {
+            Dispatcher::global().$name($($arg),*)
+        }
+    };
+}
+
+/// Defines the function returning the selected function pointer of the global dispatcher.
+macro_rules! dispatcher_resolve_fn {
+    ($resolve:ident, $name:ident ($($arg:ident: $arg_ty:ty),*) -> $ret:ty) => {
+        #[inline]
+        #[doc = concat!("Returns the implementation of [", stringify!($name), "] selected by [Dispatcher::global].")]
+        ///
+        /// Calling the returned function pointer is a single indirect call, allowing
+        /// the lookup of the global dispatcher to be hoisted out of hot loops entirely.
+        pub fn $resolve() -> fn($($arg_ty),*) -> $ret {
+            Dispatcher::global().$name
+        }
+    };
+}
+
+macro_rules! define_dispatcher {
+    (
+        $(
+            $(#[doc = $doc:expr])*
+            fn $name:ident $params:tt -> $ret:ty {
+                resolve = $resolve:ident,
+                $(avx512 = $avx512_fn:path,)?
+                $(avx2fma = $avx2fma_fn:path,)?
+                $(avx2 = $avx2_fn:path,)?
+                $(neon = $neon_fn:path,)?
+                fallback = $fallback_fn:path $(,)?
+            }
+        )*
+    ) => {
+        #[derive(Copy, Clone)]
+        /// A set of safe routines where the best implementation for the available
+        /// CPU features is selected once when the dispatcher is created.
+        ///
+        /// The safe generic functions, i.e. [cfavml::dot](crate::dot), check the available
+        /// CPU features on every call, the `Dispatcher` instead caches a function pointer
+        /// to the selected implementation of each routine, removing the repeated checks
+        /// from hot loops.
+        ///
+        /// ```
+        /// use cfavml::dispatch::Dispatcher;
+        ///
+        /// let dispatcher = Dispatcher::new();
+        ///
+        /// let a = [1.0, 2.0, 3.0];
+        /// let b = [4.0, 5.0, 6.0];
+        /// assert_eq!(dispatcher.dot_f32(&a, &b), 32.0);
+        /// ```
+        ///
+        /// Each routine is also exposed as a free function in this module which uses
+        /// the process wide [Dispatcher::global].
+        ///
+        /// ```
+        /// let a = [1.0, 2.0, 3.0];
+        /// let b = [4.0, 5.0, 6.0];
+        /// assert_eq!(cfavml::dispatch::dot_f32(&a, &b), 32.0);
+        /// ```
+        ///
+        /// The selected implementation can also be resolved once up front via the
+        /// `resolve_*` functions, i.e. [resolve_cosine_f32], leaving only a single
+        /// indirect call per invocation.
+        ///
+        /// ```
+        /// let cosine = cfavml::dispatch::resolve_cosine_f32();
+        ///
+        /// let query = [1.0, 0.0, 0.0];
+        /// let vectors = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+        /// for vector in vectors.iter() {
+        ///     let distance = cosine(&query, vector);
+        ///     assert!(distance == 0.0 || distance == 1.0);
+        /// }
+        /// ```
+        pub struct Dispatcher {
+            $($name: fn $params -> $ret,)*
+        }
+
+        impl Dispatcher {
+            /// Creates a new dispatcher, selecting the best implementation of each routine
+            /// for the CPU features available.
+            ///
+            /// If this is compiling for a no std target, this selection is done
+            /// at compile time only.
+            // Targets without any SIMD implementations never break out of the select block.
+            #[allow(unused_labels)]
+            pub fn new() -> Self {
+                Self {
+                    $(
+                        $name: 'select: {
+                            $(
+                                #[cfg(all(
+                                    any(target_arch = "x86", target_arch = "x86_64"),
+                                    feature = "stable-avx512"
+                                ))]
+                                if is_avx512_available() {
+                                    break 'select dispatcher_fn!($avx512_fn, $params -> $ret);
+                                }
+                            )?
+
+                            $(
+                                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                                if is_avx2_available() && is_fma_available() {
+                                    break 'select dispatcher_fn!($avx2fma_fn, $params -> $ret);
+                                }
+                            )?
+
+                            $(
+                                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                                if is_avx2_available() {
+                                    break 'select dispatcher_fn!($avx2_fn, $params -> $ret);
+                                }
+                            )?
+
+                            $(
+                                #[cfg(target_arch = "aarch64")]
+                                if is_neon_available() {
+                                    break 'select dispatcher_fn!($neon_fn, $params -> $ret);
+                                }
+                            )?
+
+                            dispatcher_fn!($fallback_fn, $params -> $ret)
+                        },
+                    )*
+                }
+            }
+
+            #[cfg(feature = "std")]
+            /// Returns the dispatcher shared by the whole process, the implementations are
+            /// selected the first time this is called and reused from then on.
+            pub fn global() -> &'static Self {
+                static GLOBAL: std::sync::OnceLock<Dispatcher> = std::sync::OnceLock::new();
+                GLOBAL.get_or_init(Self::new)
+            }
+
+            #[cfg(not(feature = "std"))]
+            #[inline]
+            /// Returns a dispatcher with the implementations selected at compile time.
+            ///
+            /// Without std there is no runtime detection, so the selection is
+            /// constant and nothing needs caching.
+            pub fn global() -> Self {
+                Self::new()
+            }
+
+            $(
+                dispatcher_method!($(#[doc = $doc])* $name $params -> $ret);
+            )*
+        }
+
+        impl Default for Dispatcher {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        $(
+            dispatcher_free_fn!($(#[doc = $doc])* $name $params -> $ret);
+        )*
+
+        $(
+            dispatcher_resolve_fn!($resolve, $name $params -> $ret);
+        )*
+    };
+}
+
+define_dispatcher! {
+    /// Calculates the dot product between `f32` vectors `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn dot_f32(a: &[f32], b: &[f32]) -> f32 {
+        resolve = resolve_dot_f32,
+        avx512 = crate::danger::export_distance_ops::generic_avx512_dot,
+        avx2fma = crate::danger::export_distance_ops::generic_avx2fma_dot,
+        avx2 = crate::danger::export_distance_ops::generic_avx2_dot,
+        neon = crate::danger::export_distance_ops::generic_neon_dot,
+        fallback = crate::danger::export_distance_ops::generic_fallback_dot,
+    }
+
+    /// Calculates the dot product between `f64` vectors `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn dot_f64(a: &[f64], b: &[f64]) -> f64 {
+        resolve = resolve_dot_f64,
+        avx512 = crate::danger::export_distance_ops::generic_avx512_dot,
+        avx2fma = crate::danger::export_distance_ops::generic_avx2fma_dot,
+        avx2 = crate::danger::export_distance_ops::generic_avx2_dot,
+        neon = crate::danger::export_distance_ops::generic_neon_dot,
+        fallback = crate::danger::export_distance_ops::generic_fallback_dot,
+    }
+
+    /// Calculates the cosine similarity distance between `f32` vectors `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn cosine_f32(a: &[f32], b: &[f32]) -> f32 {
+        resolve = resolve_cosine_f32,
+        avx512 = crate::danger::export_distance_ops::generic_avx512_cosine,
+        avx2fma = crate::danger::export_distance_ops::generic_avx2fma_cosine,
+        avx2 = crate::danger::export_distance_ops::generic_avx2_cosine,
+        neon = crate::danger::export_distance_ops::generic_neon_cosine,
+        fallback = crate::danger::export_distance_ops::generic_fallback_cosine,
+    }
+
+    /// Calculates the cosine similarity distance between `f64` vectors `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn cosine_f64(a: &[f64], b: &[f64]) -> f64 {
+        resolve = resolve_cosine_f64,
+        avx512 = crate::danger::export_distance_ops::generic_avx512_cosine,
+        avx2fma = crate::danger::export_distance_ops::generic_avx2fma_cosine,
+        avx2 = crate::danger::export_distance_ops::generic_avx2_cosine,
+        neon = crate::danger::export_distance_ops::generic_neon_cosine,
+        fallback = crate::danger::export_distance_ops::generic_fallback_cosine,
+    }
+
+    /// Calculates the squared Euclidean distance between `f32` vectors `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn squared_euclidean_f32(a: &[f32], b: &[f32]) -> f32 {
+        resolve = resolve_squared_euclidean_f32,
+        avx512 = crate::danger::export_distance_ops::generic_avx512_squared_euclidean,
+        avx2fma = crate::danger::export_distance_ops::generic_avx2fma_squared_euclidean,
+        avx2 = crate::danger::export_distance_ops::generic_avx2_squared_euclidean,
+        neon = crate::danger::export_distance_ops::generic_neon_squared_euclidean,
+        fallback = crate::danger::export_distance_ops::generic_fallback_squared_euclidean,
+    }
+
+    /// Calculates the squared Euclidean distance between `f64` vectors `a` and `b`.
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn squared_euclidean_f64(a: &[f64], b: &[f64]) -> f64 {
+        resolve = resolve_squared_euclidean_f64,
+        avx512 = crate::danger::export_distance_ops::generic_avx512_squared_euclidean,
+        avx2fma = crate::danger::export_distance_ops::generic_avx2fma_squared_euclidean,
+        avx2 = crate::danger::export_distance_ops::generic_avx2_squared_euclidean,
+        neon = crate::danger::export_distance_ops::generic_neon_squared_euclidean,
+        fallback = crate::danger::export_distance_ops::generic_fallback_squared_euclidean,
+    }
+
+    /// Calculates the squared L2 norm of `f32` vector `a`.
+    fn squared_norm_f32(a: &[f32]) -> f32 {
+        resolve = resolve_squared_norm_f32,
+        avx512 = crate::danger::export_distance_ops::generic_avx512_squared_norm,
+        avx2fma = crate::danger::export_distance_ops::generic_avx2fma_squared_norm,
+        avx2 = crate::danger::export_distance_ops::generic_avx2_squared_norm,
+        neon = crate::danger::export_distance_ops::generic_neon_squared_norm,
+        fallback = crate::danger::export_distance_ops::generic_fallback_squared_norm,
+    }
+
+    /// Calculates the squared L2 norm of `f64` vector `a`.
+    fn squared_norm_f64(a: &[f64]) -> f64 {
+        resolve = resolve_squared_norm_f64,
+        avx512 = crate::danger::export_distance_ops::generic_avx512_squared_norm,
+        avx2fma = crate::danger::export_distance_ops::generic_avx2fma_squared_norm,
+        avx2 = crate::danger::export_distance_ops::generic_avx2_squared_norm,
+        neon = crate::danger::export_distance_ops::generic_neon_squared_norm,
+        fallback = crate::danger::export_distance_ops::generic_fallback_squared_norm,
+    }
+
+    /// Calculates the sum of `f32` vector `a`.
+    fn sum_f32(a: &[f32]) -> f32 {
+        resolve = resolve_sum_f32,
+        avx512 = crate::danger::export_agg_ops::generic_avx512_sum,
+        avx2 = crate::danger::export_agg_ops::generic_avx2_sum,
+        neon = crate::danger::export_agg_ops::generic_neon_sum,
+        fallback = crate::danger::export_agg_ops::generic_fallback_sum,
+    }
+
+    /// Calculates the sum of `f64` vector `a`.
+    fn sum_f64(a: &[f64]) -> f64 {
+        resolve = resolve_sum_f64,
+        avx512 = crate::danger::export_agg_ops::generic_avx512_sum,
+        avx2 = crate::danger::export_agg_ops::generic_avx2_sum,
+        neon = crate::danger::export_agg_ops::generic_neon_sum,
+        fallback = crate::danger::export_agg_ops::generic_fallback_sum,
+    }
+
+    /// Performs an element wise addition of `f32` vectors `a` and `b`, writing the
+    /// result to `result`.
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a`, `b` and `result` are not equal in the length.
+    fn add_vertical_f32(a: &[f32], b: &[f32], result: &mut [f32]) -> () {
+        resolve = resolve_add_vertical_f32,
+        avx512 = crate::danger::export_arithmetic_ops::generic_avx512_add_vertical,
+        avx2 = crate::danger::export_arithmetic_ops::generic_avx2_add_vertical,
+        neon = crate::danger::export_arithmetic_ops::generic_neon_add_vertical,
+        fallback = crate::danger::export_arithmetic_ops::generic_fallback_add_vertical,
+    }
+
+    /// Performs an element wise addition of `f64` vectors `a` and `b`, writing the
+    /// result to `result`.
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a`, `b` and `result` are not equal in the length.
+    fn add_vertical_f64(a: &[f64], b: &[f64], result: &mut [f64]) -> () {
+        resolve = resolve_add_vertical_f64,
+        avx512 = crate::danger::export_arithmetic_ops::generic_avx512_add_vertical,
+        avx2 = crate::danger::export_arithmetic_ops::generic_avx2_add_vertical,
+        neon = crate::danger::export_arithmetic_ops::generic_neon_add_vertical,
+        fallback = crate::danger::export_arithmetic_ops::generic_fallback_add_vertical,
+    }
+
+    /// Performs an element wise multiplication of `f32` vectors `a` and `b`, writing the
+    /// result to `result`.
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a`, `b` and `result` are not equal in the length.
+    fn mul_vertical_f32(a: &[f32], b: &[f32], result: &mut [f32]) -> () {
+        resolve = resolve_mul_vertical_f32,
+        avx512 = crate::danger::export_arithmetic_ops::generic_avx512_mul_vertical,
+        avx2 = crate::danger::export_arithmetic_ops::generic_avx2_mul_vertical,
+        neon = crate::danger::export_arithmetic_ops::generic_neon_mul_vertical,
+        fallback = crate::danger::export_arithmetic_ops::generic_fallback_mul_vertical,
+    }
+
+    /// Performs an element wise multiplication of `f64` vectors `a` and `b`, writing the
+    /// result to `result`.
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a`, `b` and `result` are not equal in the length.
+    fn mul_vertical_f64(a: &[f64], b: &[f64], result: &mut [f64]) -> () {
+        resolve = resolve_mul_vertical_f64,
+        avx512 = crate::danger::export_arithmetic_ops::generic_avx512_mul_vertical,
+        avx2 = crate::danger::export_arithmetic_ops::generic_avx2_mul_vertical,
+        neon = crate::danger::export_arithmetic_ops::generic_neon_mul_vertical,
+        fallback = crate::danger::export_arithmetic_ops::generic_fallback_mul_vertical,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_dispatcher_matches_safe_api() {
+        let dispatcher = Dispatcher::new();
+
+        let (a, b) = get_sample_vectors::<f32>(1043);
+        assert_eq!(dispatcher.dot_f32(&a, &b), crate::dot(&a, &b));
+        assert_eq!(dispatcher.cosine_f32(&a, &b), crate::cosine(&a, &b));
+        assert_eq!(
+            dispatcher.squared_euclidean_f32(&a, &b),
+            crate::squared_euclidean(&a, &b),
+        );
+        assert_eq!(dispatcher.squared_norm_f32(&a), crate::squared_norm(&a));
+        assert_eq!(dispatcher.sum_f32(&a), crate::sum(&a));
+
+        let mut result = vec![0.0; a.len()];
+        let mut expected = vec![0.0; a.len()];
+        dispatcher.add_vertical_f32(&a, &b, &mut result);
+        crate::add_vertical(&a, &b, &mut expected);
+        assert_eq!(result, expected);
+        dispatcher.mul_vertical_f32(&a, &b, &mut result);
+        crate::mul_vertical(&a, &b, &mut expected);
+        assert_eq!(result, expected);
+
+        let (a, b) = get_sample_vectors::<f64>(1043);
+        assert_eq!(dispatcher.dot_f64(&a, &b), crate::dot(&a, &b));
+        assert_eq!(dispatcher.cosine_f64(&a, &b), crate::cosine(&a, &b));
+        assert_eq!(
+            dispatcher.squared_euclidean_f64(&a, &b),
+            crate::squared_euclidean(&a, &b),
+        );
+        assert_eq!(dispatcher.squared_norm_f64(&a), crate::squared_norm(&a));
+        assert_eq!(dispatcher.sum_f64(&a), crate::sum(&a));
+
+        let mut result = vec![0.0; a.len()];
+        let mut expected = vec![0.0; a.len()];
+        dispatcher.add_vertical_f64(&a, &b, &mut result);
+        crate::add_vertical(&a, &b, &mut expected);
+        assert_eq!(result, expected);
+        dispatcher.mul_vertical_f64(&a, &b, &mut result);
+        crate::mul_vertical(&a, &b, &mut expected);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_global_dispatch_matches_safe_api() {
+        let (a, b) = get_sample_vectors::<f32>(1043);
+        assert_eq!(dot_f32(&a, &b), crate::dot(&a, &b));
+        assert_eq!(cosine_f32(&a, &b), crate::cosine(&a, &b));
+        assert_eq!(
+            squared_euclidean_f32(&a, &b),
+            crate::squared_euclidean(&a, &b)
+        );
+
+        let (a, b) = get_sample_vectors::<f64>(1043);
+        assert_eq!(dot_f64(&a, &b), crate::dot(&a, &b));
+        assert_eq!(cosine_f64(&a, &b), crate::cosine(&a, &b));
+        assert_eq!(
+            squared_euclidean_f64(&a, &b),
+            crate::squared_euclidean(&a, &b)
+        );
+    }
+
+    #[test]
+    fn test_resolved_dispatch_matches_safe_api() {
+        let (a, b) = get_sample_vectors::<f32>(1043);
+        assert_eq!(resolve_dot_f32()(&a, &b), crate::dot(&a, &b));
+        assert_eq!(resolve_cosine_f32()(&a, &b), crate::cosine(&a, &b));
+        assert_eq!(resolve_sum_f32()(&a), crate::sum(&a));
+
+        let mut result = vec![0.0; a.len()];
+        let mut expected = vec![0.0; a.len()];
+        resolve_add_vertical_f32()(&a, &b, &mut result);
+        crate::add_vertical(&a, &b, &mut expected);
+        assert_eq!(result, expected);
+
+        let (a, b) = get_sample_vectors::<f64>(1043);
+        assert_eq!(resolve_dot_f64()(&a, &b), crate::dot(&a, &b));
+        assert_eq!(resolve_cosine_f64()(&a, &b), crate::cosine(&a, &b));
+        assert_eq!(
+            resolve_squared_euclidean_f64()(&a, &b),
+            crate::squared_euclidean(&a, &b),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_global_dispatch_length_mismatch_panics() {
+        cosine_f32(&[1.0, 2.0], &[1.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dispatcher_length_mismatch_panics() {
+        let dispatcher = Dispatcher::default();
+        dispatcher.dot_f32(&[1.0, 2.0], &[1.0]);
+    }
+}
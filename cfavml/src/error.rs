@@ -0,0 +1,171 @@
+//! Errors returned by the fallible `try_` variants of the safe routines.
+
+use core::fmt;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// An operand passed to a routine was not the length the routine expected.
+///
+/// ```rust
+/// use cfavml::SizeMismatchError;
+///
+/// let a = [1.0f32, 2.0, 3.0];
+/// let b = [4.0f32, 5.0, 6.0];
+/// let mut result = [0.0f32; 2];
+///
+/// let error = cfavml::try_add_vertical(&a, &b, &mut result).unwrap_err();
+/// assert_eq!(
+///     error,
+///     SizeMismatchError {
+///         operand: "lhs",
+///         expected: 2,
+///         actual: 3,
+///     },
+/// );
+/// ```
+pub struct SizeMismatchError {
+    /// The name of the operand with the wrong length, i.e. `lhs` or `result`.
+    pub operand: &'static str,
+    /// The length the routine expected the operand to be.
+    pub expected: usize,
+    /// The actual length of the operand.
+    pub actual: usize,
+}
+
+impl SizeMismatchError {
+    #[inline]
+    /// Returns an error if the `actual` length of `operand` is not the `expected` length.
+    pub(crate) fn check(
+        operand: &'static str,
+        expected: usize,
+        actual: usize,
+    ) -> Result<(), Self> {
+        if expected == actual {
+            Ok(())
+        } else {
+            Err(Self {
+                operand,
+                expected,
+                actual,
+            })
+        }
+    }
+}
+
+impl fmt::Display for SizeMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Buffer `{}` must be exactly {} elements in length but was {}",
+            self.operand, self.expected, self.actual,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SizeMismatchError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// An index passed to a routine was out of bounds of the buffer it selects from.
+///
+/// ```rust
+/// use cfavml::{IndexError, IndexOutOfBoundsError};
+///
+/// let a = [1.0f32, 2.0, 3.0];
+/// let mut result = [0.0f32; 2];
+///
+/// let error = cfavml::try_gather(&a, &[2, 3], &mut result).unwrap_err();
+/// assert_eq!(
+///     error,
+///     IndexError::OutOfBounds(IndexOutOfBoundsError {
+///         operand: "a",
+///         index: 3,
+///         len: 3,
+///     }),
+/// );
+/// ```
+pub struct IndexOutOfBoundsError {
+    /// The name of the operand being indexed, i.e. `a` or `result`.
+    pub operand: &'static str,
+    /// The first index which was out of bounds.
+    pub index: usize,
+    /// The length of the operand.
+    pub len: usize,
+}
+
+impl IndexOutOfBoundsError {
+    #[inline]
+    /// Returns an error with the first of the `indices` which is out of bounds of
+    /// an `operand` of `len` elements.
+    pub(crate) fn check(
+        operand: &'static str,
+        indices: &[u32],
+        len: usize,
+    ) -> Result<(), Self> {
+        match indices.iter().find(|&&index| index as usize >= len) {
+            None => Ok(()),
+            Some(&index) => Err(Self {
+                operand,
+                index: index as usize,
+                len,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for IndexOutOfBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Index {} is out of bounds of buffer `{}` with a length of {}",
+            self.index, self.operand, self.len,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexOutOfBoundsError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// An error returned by the fallible routines which select elements via an index vector.
+pub enum IndexError {
+    /// An operand was not the length the routine expected.
+    SizeMismatch(SizeMismatchError),
+    /// An index was out of bounds of the buffer it selects from.
+    OutOfBounds(IndexOutOfBoundsError),
+}
+
+impl From<SizeMismatchError> for IndexError {
+    fn from(error: SizeMismatchError) -> Self {
+        Self::SizeMismatch(error)
+    }
+}
+
+impl From<IndexOutOfBoundsError> for IndexError {
+    fn from(error: IndexOutOfBoundsError) -> Self {
+        Self::OutOfBounds(error)
+    }
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SizeMismatch(error) => error.fmt(f),
+            Self::OutOfBounds(error) => error.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IndexError {}
+
+#[inline]
+#[track_caller]
+/// Unwraps the result of a fallible routine, panicking with the error message if
+/// any operand had the wrong length or, for the indexed routines, any index was
+/// out of bounds.
+pub(crate) fn unwrap_size<T, E: fmt::Display>(result: Result<T, E>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(error) => panic!("{error}"),
+    }
+}
@@ -1,5 +1,5 @@
 #![allow(internal_features)]
-#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 #![cfg_attr(feature = "nightly", feature(core_intrinsics))]
 #![cfg_attr(
@@ -10,20 +10,47 @@
     all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"),
     feature(stdarch_x86_avx512)
 )]
+#![cfg_attr(
+    all(target_arch = "aarch64", feature = "nightly"),
+    feature(stdarch_aarch64_sve)
+)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod danger;
 pub mod dispatch;
+pub mod error;
 pub mod math;
 
 pub mod buffer;
 pub mod mem_loader;
 mod safe_function_ops;
+#[cfg(feature = "parallel")]
+mod safe_parallel_ops;
+#[cfg(feature = "alloc")]
+mod safe_spare_function_ops;
 pub mod safe_trait_agg_ops;
 pub mod safe_trait_arithmetic_ops;
+pub mod safe_trait_bitwise_ops;
 pub mod safe_trait_cmp_ops;
 pub mod safe_trait_distance_ops;
+pub mod safe_trait_float_ops;
+pub mod safe_trait_gather_ops;
+pub mod safe_trait_stats_ops;
+mod safe_try_function_ops;
 #[cfg(test)]
 mod test_utils;
+#[cfg(feature = "alloc")]
+pub mod top_k;
 
+pub use self::error::{IndexError, IndexOutOfBoundsError, SizeMismatchError};
 pub use self::safe_function_ops::*;
+#[cfg(feature = "parallel")]
+pub use self::safe_parallel_ops::*;
+#[cfg(feature = "alloc")]
+pub use self::safe_spare_function_ops::*;
+pub use self::safe_try_function_ops::*;
+#[cfg(feature = "alloc")]
+pub use self::top_k::top_k;
@@ -107,6 +107,11 @@ impl Math<f32> for FastMath {
         }
     }
 
+    #[inline(always)]
+    fn neg(a: f32) -> f32 {
+        -a
+    }
+
     #[cfg(test)]
     fn is_close(a: f32, b: f32) -> bool {
         let max = a.max(b);
@@ -218,6 +223,11 @@ impl Math<f64> for FastMath {
         }
     }
 
+    #[inline(always)]
+    fn neg(a: f64) -> f64 {
+        -a
+    }
+
     #[cfg(test)]
     fn is_close(a: f64, b: f64) -> bool {
         let max = a.max(b);
@@ -0,0 +1,136 @@
+use half::{bf16, f16};
+
+#[cfg(feature = "nightly")]
+use super::FastMath;
+use super::{CastAs, Math, StdMath};
+
+/// Implements the math operations on the half precision type `$t` by converting to `f32`,
+/// performing the respective `f32` operation of the math type and converting the result back.
+///
+/// `f32` has more than twice the precision of both `f16` and `bf16`, so the result of each
+/// operation is still correctly rounded.
+macro_rules! define_half_ops {
+    ($t:ident, $m:ty, tolerance = $tolerance:expr) => {
+        impl Math<$t> for $m {
+            #[inline(always)]
+            fn zero() -> $t {
+                $t::ZERO
+            }
+
+            #[inline(always)]
+            fn one() -> $t {
+                $t::ONE
+            }
+
+            #[inline(always)]
+            fn max() -> $t {
+                $t::INFINITY
+            }
+
+            #[inline(always)]
+            fn min() -> $t {
+                $t::NEG_INFINITY
+            }
+
+            #[inline(always)]
+            fn sqrt(a: $t) -> $t {
+                $t::from_f32(<$m as Math<f32>>::sqrt(a.to_f32()))
+            }
+
+            #[inline(always)]
+            fn abs(a: $t) -> $t {
+                $t::from_f32(<$m as Math<f32>>::abs(a.to_f32()))
+            }
+
+            #[inline(always)]
+            fn cmp_eq(a: $t, b: $t) -> bool {
+                a == b
+            }
+
+            #[inline(always)]
+            fn cmp_lt(a: $t, b: $t) -> bool {
+                a < b
+            }
+
+            #[inline(always)]
+            fn cmp_lte(a: $t, b: $t) -> bool {
+                a <= b
+            }
+
+            #[inline(always)]
+            fn cmp_gt(a: $t, b: $t) -> bool {
+                a > b
+            }
+
+            #[inline(always)]
+            fn cmp_gte(a: $t, b: $t) -> bool {
+                a >= b
+            }
+
+            #[inline(always)]
+            fn cmp_min(a: $t, b: $t) -> $t {
+                $t::from_f32(<$m as Math<f32>>::cmp_min(a.to_f32(), b.to_f32()))
+            }
+
+            #[inline(always)]
+            fn cmp_max(a: $t, b: $t) -> $t {
+                $t::from_f32(<$m as Math<f32>>::cmp_max(a.to_f32(), b.to_f32()))
+            }
+
+            #[inline(always)]
+            fn add(a: $t, b: $t) -> $t {
+                $t::from_f32(<$m as Math<f32>>::add(a.to_f32(), b.to_f32()))
+            }
+
+            #[inline(always)]
+            fn sub(a: $t, b: $t) -> $t {
+                $t::from_f32(<$m as Math<f32>>::sub(a.to_f32(), b.to_f32()))
+            }
+
+            #[inline(always)]
+            fn mul(a: $t, b: $t) -> $t {
+                $t::from_f32(<$m as Math<f32>>::mul(a.to_f32(), b.to_f32()))
+            }
+
+            #[inline(always)]
+            fn div(a: $t, b: $t) -> $t {
+                $t::from_f32(<$m as Math<f32>>::div(a.to_f32(), b.to_f32()))
+            }
+
+            #[inline(always)]
+            fn neg(a: $t) -> $t {
+                -a
+            }
+
+            #[cfg(test)]
+            fn is_close(a: $t, b: $t) -> bool {
+                // Values are only accurate to ~2-3 significant digits, and the fallback
+                // rounds to half precision after every operation.
+                let (a, b) = (a.to_f32(), b.to_f32());
+                let diff = (a - b).abs();
+                diff <= $tolerance || diff <= $tolerance * a.abs().max(b.abs())
+            }
+        }
+    };
+}
+
+define_half_ops!(f16, StdMath, tolerance = 0.01);
+#[cfg(feature = "nightly")]
+define_half_ops!(f16, FastMath, tolerance = 0.01);
+define_half_ops!(bf16, StdMath, tolerance = 0.05);
+#[cfg(feature = "nightly")]
+define_half_ops!(bf16, FastMath, tolerance = 0.05);
+
+impl CastAs<f32> for f16 {
+    #[inline(always)]
+    fn cast_as(self) -> f32 {
+        self.to_f32()
+    }
+}
+
+impl CastAs<f32> for bf16 {
+    #[inline(always)]
+    fn cast_as(self) -> f32 {
+        self.to_f32()
+    }
+}
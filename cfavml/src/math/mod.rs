@@ -1,6 +1,8 @@
 mod default;
 #[cfg(feature = "nightly")]
 mod fast_math;
+#[cfg(feature = "half")]
+mod half_float;
 
 pub use default::StdMath;
 #[cfg(feature = "nightly")]
@@ -65,6 +67,15 @@ pub trait Math<T> {
     /// `a / b`
     fn div(a: T, b: T) -> T;
 
+    #[inline]
+    /// `-a`
+    ///
+    /// By default this is `0 - a`, float implementations override this to flip
+    /// the sign bit so `-0.0` and `NaN` values are negated correctly.
+    fn neg(a: T) -> T {
+        Self::sub(Self::zero(), a)
+    }
+
     // No officer, nothing scuffed about this, no sir.
     #[cfg(test)]
     fn is_close(a: T, b: T) -> bool;
@@ -78,4 +89,232 @@ pub trait Math<T> {
             Self::zero()
         }
     }
+
+    #[inline]
+    /// Computes `sqrt(a² + b²)` without undue overflow or underflow of the
+    /// intermediate squares.
+    ///
+    /// If either value is infinite the result is infinite, otherwise if either
+    /// value is `NaN` the result is `NaN`.
+    fn hypot(a: T, b: T) -> T
+    where
+        T: Copy,
+    {
+        let a = Self::abs(a);
+        let b = Self::abs(b);
+
+        if Self::cmp_eq(a, Self::max()) || Self::cmp_eq(b, Self::max()) {
+            return Self::max();
+        } else if !Self::cmp_eq(a, a) {
+            return a;
+        } else if !Self::cmp_eq(b, b) {
+            return b;
+        }
+
+        let (hi, lo) = if Self::cmp_gt(a, b) { (a, b) } else { (b, a) };
+        if Self::cmp_eq(hi, Self::zero()) {
+            return Self::zero();
+        }
+
+        let ratio = Self::div(lo, hi);
+        Self::mul(
+            hi,
+            Self::sqrt(Self::add(Self::one(), Self::mul(ratio, ratio))),
+        )
+    }
+}
+
+/// Bit shift operations over the integer types where the shift amount is provided
+/// as the same type as the value being shifted.
+///
+/// The shift amount is masked to the bit width of the type, matching the behaviour
+/// of the standard library's `wrapping_shl` and `wrapping_shr` methods.
+pub trait WrappingShift: Copy {
+    /// `self.wrapping_shl(rhs)`
+    fn shl_wrapping(self, rhs: Self) -> Self;
+
+    /// `self.wrapping_shr(rhs)`
+    ///
+    /// This is an _arithmetic_ shift for signed types and a _logical_
+    /// shift for unsigned types.
+    fn shr_wrapping(self, rhs: Self) -> Self;
+}
+
+macro_rules! define_wrapping_shift {
+    ($($t:ty $(,)?)+) => {
+        $(
+            impl WrappingShift for $t {
+                #[inline(always)]
+                fn shl_wrapping(self, rhs: Self) -> Self {
+                    self.wrapping_shl(rhs as u32)
+                }
+
+                #[inline(always)]
+                fn shr_wrapping(self, rhs: Self) -> Self {
+                    self.wrapping_shr(rhs as u32)
+                }
+            }
+        )+
+    };
+}
+
+define_wrapping_shift!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Arithmetic operations over the integer types which clamp to the bounds of the
+/// type on overflow rather than wrapping.
+pub trait SaturatingArithmetic: Copy {
+    /// `self.saturating_add(rhs)`
+    fn add_saturating(self, rhs: Self) -> Self;
+
+    /// `self.saturating_sub(rhs)`
+    fn sub_saturating(self, rhs: Self) -> Self;
+}
+
+macro_rules! define_saturating_arithmetic {
+    ($($t:ty $(,)?)+) => {
+        $(
+            impl SaturatingArithmetic for $t {
+                #[inline(always)]
+                fn add_saturating(self, rhs: Self) -> Self {
+                    self.saturating_add(rhs)
+                }
+
+                #[inline(always)]
+                fn sub_saturating(self, rhs: Self) -> Self {
+                    self.saturating_sub(rhs)
+                }
+            }
+        )+
+    };
+}
+
+define_saturating_arithmetic!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Converts a value of one primitive type into `T` with the same semantics as an `as` cast.
+///
+/// - Float to int conversions truncate towards zero, saturate to the bounds of `T`
+///   and convert `NaN` to `0`.
+/// - Int to float and `f64` to `f32` conversions round to the nearest representable value.
+pub trait CastAs<T>: Copy {
+    /// `self as T`
+    fn cast_as(self) -> T;
+}
+
+macro_rules! define_cast_as {
+    ($($from:ty => $to:ty $(,)?)+) => {
+        $(
+            impl CastAs<$to> for $from {
+                #[inline(always)]
+                fn cast_as(self) -> $to {
+                    self as $to
+                }
+            }
+        )+
+    };
 }
+
+define_cast_as!(
+    f32 => f64,
+    f64 => f32,
+    f32 => i32,
+    i32 => f32,
+    i32 => i64,
+    u32 => u64,
+    u16 => u32,
+    u8 => u32,
+    u16 => u64,
+    u32 => f64,
+    u64 => f64,
+    i8 => f32,
+    u8 => f32,
+    i16 => f32,
+    u16 => f32,
+    usize => f32,
+    usize => f64,
+    usize => i32,
+    usize => i64,
+);
+
+/// The running sum of a window of values, i.e. for a moving average.
+///
+/// For the integer types the sum is held in a type twice the width of `Self`, so the sum
+/// of a window never overflows even when the mean of the window fits within `Self`.
+pub trait WindowSum: Copy {
+    /// If the sum is held in a wider type than `Self`.
+    const WIDENED: bool;
+
+    /// The type holding the sum.
+    type Sum: Copy;
+
+    /// Returns the sum of an empty window.
+    fn empty_sum() -> Self::Sum;
+
+    /// `sum + self`
+    fn add_to_sum(self, sum: Self::Sum) -> Self::Sum;
+
+    /// `sum - self`
+    fn sub_from_sum(self, sum: Self::Sum) -> Self::Sum;
+
+    /// `sum / window`, narrowed back to `Self`.
+    fn window_mean(sum: Self::Sum, window: usize) -> Self;
+}
+
+macro_rules! define_window_sum {
+    ($($t:ty => $sum:ty, widened = $widened:expr $(,)?)+) => {
+        $(
+            impl WindowSum for $t {
+                const WIDENED: bool = $widened;
+
+                type Sum = $sum;
+
+                #[inline(always)]
+                fn empty_sum() -> Self::Sum {
+                    0 as $sum
+                }
+
+                #[inline(always)]
+                fn add_to_sum(self, sum: Self::Sum) -> Self::Sum {
+                    sum + self as $sum
+                }
+
+                #[inline(always)]
+                fn sub_from_sum(self, sum: Self::Sum) -> Self::Sum {
+                    sum - self as $sum
+                }
+
+                #[inline(always)]
+                fn window_mean(sum: Self::Sum, window: usize) -> Self {
+                    (sum / window as $sum) as $t
+                }
+            }
+        )+
+    };
+}
+
+define_window_sum!(
+    f32 => f32, widened = false,
+    f64 => f64, widened = false,
+    i32 => i64, widened = true,
+    i64 => i128, widened = true,
+);
+
+/// Converts an accumulated `0` (false) / `1` (true) comparison mask value into a count.
+pub trait MaskCount: Copy {
+    /// `self as usize`
+    fn to_count(self) -> usize;
+}
+
+macro_rules! define_mask_count {
+    ($($t:ty $(,)?)+) => {
+        $(
+            impl MaskCount for $t {
+                #[inline(always)]
+                fn to_count(self) -> usize {
+                    self as usize
+                }
+            }
+        )+
+    };
+}
+
+define_mask_count!(f32, f64, i8, i16, i32, i64, u8, u16, u32, u64);
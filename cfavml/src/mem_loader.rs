@@ -60,6 +60,40 @@ pub trait MemLoader {
     /// positions of buffers.
     unsafe fn load<R: SimdRegister<Self::Value>>(&mut self) -> R::Register;
 
+    #[inline(always)]
+    /// Returns if [MemLoader::load_aligned_dense] and [MemLoader::load_aligned] can be
+    /// used, given every register is read from memory aligned to `align` bytes.
+    ///
+    /// By default the aligned loads are identical to the unaligned loads, so this
+    /// is always `true`.
+    fn is_aligned_to(&self, _align: usize) -> bool {
+        true
+    }
+
+    #[inline(always)]
+    /// The aligned variant of [MemLoader::load_dense].
+    ///
+    /// # Safety
+    ///
+    /// The same requirements as [MemLoader::load_dense] apply, in addition to
+    /// [MemLoader::is_aligned_to] returning `true` for the size of `R::Register`.
+    unsafe fn load_aligned_dense<R: SimdRegister<Self::Value>>(
+        &mut self,
+    ) -> DenseLane<R::Register> {
+        self.load_dense::<R>()
+    }
+
+    #[inline(always)]
+    /// The aligned variant of [MemLoader::load].
+    ///
+    /// # Safety
+    ///
+    /// The same requirements as [MemLoader::load] apply, in addition to
+    /// [MemLoader::is_aligned_to] returning `true` for the size of `R::Register`.
+    unsafe fn load_aligned<R: SimdRegister<Self::Value>>(&mut self) -> R::Register {
+        self.load::<R>()
+    }
+
     /// Performs an unsafe load of a single value from the [MemLoader] and advances
     /// the statemachine.
     ///
@@ -71,7 +105,7 @@ pub trait MemLoader {
     unsafe fn read(&mut self) -> Self::Value;
 }
 
-impl<'a, B, T> IntoMemLoader<T> for &'a B
+impl<B, T> IntoMemLoader<T> for &B
 where
     T: Copy,
     B: AsRef<[T]> + ?Sized,
@@ -140,7 +174,7 @@ where
 ///
 pub struct Projected<T>(pub T);
 
-impl<'a, B, T> IntoMemLoader<T> for Projected<&'a B>
+impl<B, T> IntoMemLoader<T> for Projected<&B>
 where
     T: Copy + Default,
     B: AsRef<[T]> + ?Sized,
@@ -176,6 +210,274 @@ where
     }
 }
 
+/// A wrapper that reads every `stride`th element of the input buffer, starting
+/// at the first element, as if they were a contiguous vector.
+///
+/// This allows operating on non-contiguous data, i.e. the column of a row-major matrix,
+/// without needing to copy or transpose it first.
+///
+/// The length of the strided vector is `ceil(slice.len() / stride)`, so the column `j`
+/// of a `rows x cols` matrix is represented as `Strided::new(&matrix[j..], cols)`.
+///
+/// ```rust
+/// use cfavml::mem_loader::Strided;
+///
+/// // A 3x2 matrix in row-major order.
+/// let matrix: [f32; 6] = [
+///     1.0, 2.0,
+///     3.0, 4.0,
+///     5.0, 6.0,
+/// ];
+/// let weights: [f32; 3] = [1.0, 0.5, 2.0];
+///
+/// let col_0 = cfavml::dot(Strided::new(&matrix, 2), &weights);
+/// assert_eq!(col_0, 12.5);
+///
+/// let col_1 = cfavml::dot(Strided::new(&matrix[1..], 2), &weights);
+/// assert_eq!(col_1, 16.0);
+/// ```
+pub struct Strided<T> {
+    data: T,
+    stride: usize,
+}
+
+impl<T> Strided<T> {
+    /// Creates a new strided view over `data` reading every `stride`th element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is `0`.
+    pub fn new(data: T, stride: usize) -> Self {
+        assert_ne!(stride, 0, "Stride must be greater than zero");
+        Self { data, stride }
+    }
+}
+
+impl<B, T> IntoMemLoader<T> for Strided<&B>
+where
+    T: Copy + Default,
+    B: AsRef<[T]> + ?Sized,
+{
+    type Loader = StridedLoader<T>;
+
+    fn into_projected_mem_loader(self, projected_len: usize) -> Self::Loader {
+        let loader = self.into_mem_loader();
+
+        assert_eq!(
+            loader.len, projected_len,
+            "Input strided slice does not match target output length, \
+            strided slices cannot be projected to a new size."
+        );
+
+        loader
+    }
+
+    fn into_mem_loader(self) -> Self::Loader {
+        let slice = self.data.as_ref();
+        StridedLoader {
+            data: slice.as_ptr(),
+            stride: self.stride,
+            len: slice.len().div_ceil(self.stride),
+            data_cursor: 0,
+        }
+    }
+}
+
+/// A [MemLoader] implementation that broadcasts a single value across a vector
+/// of `len` elements.
+///
+/// Unlike passing a scalar value directly, which is only projected to match the size
+/// of other inputs by routines that support projection, this loader has an explicit
+/// length so it can be used with any routine, i.e. computing the sum of a vector via
+/// the dot product:
+///
+/// ```rust
+/// use cfavml::mem_loader::BroadcastLoader;
+///
+/// let a: [f32; 4] = [1.0, 2.0, 3.0, 4.0];
+///
+/// let sum = cfavml::dot(BroadcastLoader::new(1.0, a.len()), &a);
+/// assert_eq!(sum, 10.0);
+/// ```
+pub struct BroadcastLoader<T> {
+    value: T,
+    len: usize,
+}
+
+impl<T> BroadcastLoader<T> {
+    /// Creates a new loader broadcasting `value` across `len` elements.
+    pub fn new(value: T, len: usize) -> Self {
+        Self { value, len }
+    }
+}
+
+impl<T: Copy> IntoMemLoader<T> for BroadcastLoader<T> {
+    type Loader = Self;
+
+    fn into_projected_mem_loader(self, projected_len: usize) -> Self::Loader {
+        assert_eq!(
+            self.len, projected_len,
+            "Broadcast length does not match target output length, \
+            broadcast values cannot be projected to a new size."
+        );
+
+        self
+    }
+
+    fn into_mem_loader(self) -> Self::Loader {
+        self
+    }
+}
+
+impl<T: Copy> MemLoader for BroadcastLoader<T> {
+    type Value = T;
+
+    #[inline(always)]
+    fn true_len(&self) -> usize {
+        1
+    }
+
+    #[inline(always)]
+    fn projected_len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    unsafe fn load_dense<R: SimdRegister<Self::Value>>(
+        &mut self,
+    ) -> DenseLane<R::Register> {
+        R::filled_dense(self.value)
+    }
+
+    #[inline(always)]
+    unsafe fn load<R: SimdRegister<Self::Value>>(&mut self) -> R::Register {
+        R::filled(self.value)
+    }
+
+    #[inline(always)]
+    unsafe fn read(&mut self) -> Self::Value {
+        self.value
+    }
+}
+
+/// The maximum length of the pattern a [ChunkedLoader] can repeat.
+const MAX_CHUNK_PATTERN_LEN: usize = 32;
+
+/// A [MemLoader] implementation that repeats a short pattern cyclically across
+/// a vector of `len` elements.
+///
+/// Unlike [Projected] slices, the length of the vector does not need to be a multiple
+/// of the pattern length, making this useful for tiling short patterns like alternating
+/// signs across a vector:
+///
+/// ```rust
+/// use cfavml::mem_loader::ChunkedLoader;
+///
+/// let a: [f32; 5] = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let mut result = [0.0; 5];
+///
+/// cfavml::mul_vertical(&a, ChunkedLoader::new(&[1.0, -1.0], a.len()), &mut result);
+/// assert_eq!(result, [1.0, -2.0, 3.0, -4.0, 5.0]);
+/// ```
+pub struct ChunkedLoader<'a, T> {
+    pattern: &'a [T],
+    len: usize,
+
+    // Generator state machine
+    pattern_cursor: usize,
+}
+
+impl<'a, T> ChunkedLoader<'a, T> {
+    /// Creates a new loader repeating `pattern` across `len` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pattern` is empty or longer than `32` elements.
+    pub fn new(pattern: &'a [T], len: usize) -> Self {
+        assert!(
+            !pattern.is_empty() && pattern.len() <= MAX_CHUNK_PATTERN_LEN,
+            "Chunk pattern must contain between 1 and {MAX_CHUNK_PATTERN_LEN} elements, got {}",
+            pattern.len(),
+        );
+        Self {
+            pattern,
+            len,
+            pattern_cursor: 0,
+        }
+    }
+}
+
+impl<'a, T: Copy + Default> IntoMemLoader<T> for ChunkedLoader<'a, T> {
+    type Loader = Self;
+
+    fn into_projected_mem_loader(self, projected_len: usize) -> Self::Loader {
+        assert_eq!(
+            self.len, projected_len,
+            "Chunked length does not match target output length, \
+            chunked patterns cannot be projected to a new size."
+        );
+
+        self
+    }
+
+    fn into_mem_loader(self) -> Self::Loader {
+        self
+    }
+}
+
+impl<'a, T: Copy + Default> MemLoader for ChunkedLoader<'a, T> {
+    type Value = T;
+
+    #[inline(always)]
+    fn true_len(&self) -> usize {
+        self.pattern.len()
+    }
+
+    #[inline(always)]
+    fn projected_len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    unsafe fn load_dense<R: SimdRegister<Self::Value>>(
+        &mut self,
+    ) -> DenseLane<R::Register> {
+        DenseLane {
+            a: self.load::<R>(),
+            b: self.load::<R>(),
+            c: self.load::<R>(),
+            d: self.load::<R>(),
+            e: self.load::<R>(),
+            f: self.load::<R>(),
+            g: self.load::<R>(),
+            h: self.load::<R>(),
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn load<R: SimdRegister<Self::Value>>(&mut self) -> R::Register {
+        let mut temp_buffer = [T::default(); SCRATCH_SPACE_SIZE];
+
+        // elements_per_lane != SCRATCH_SPACE_SIZE, this is cleaner than an iter chain.
+        #[allow(clippy::needless_range_loop)]
+        for i in 0..R::elements_per_lane() {
+            temp_buffer[i] = self.read();
+        }
+
+        R::load(temp_buffer.as_ptr())
+    }
+
+    #[inline(always)]
+    unsafe fn read(&mut self) -> Self::Value {
+        let value = *self.pattern.get_unchecked(self.pattern_cursor);
+        self.pattern_cursor += 1;
+        if self.pattern_cursor == self.pattern.len() {
+            self.pattern_cursor = 0;
+        }
+        value
+    }
+}
+
 macro_rules! impl_scalar_buffer_loader {
     ($t:ty) => {
         impl IntoMemLoader<$t> for $t {
@@ -208,6 +510,10 @@ impl_scalar_buffer_loader!(u8);
 impl_scalar_buffer_loader!(u16);
 impl_scalar_buffer_loader!(u32);
 impl_scalar_buffer_loader!(u64);
+#[cfg(feature = "half")]
+impl_scalar_buffer_loader!(half::f16);
+#[cfg(feature = "half")]
+impl_scalar_buffer_loader!(half::bf16);
 
 /// A [MemLoader] implementation that reads from a contiguous buffer represented
 /// as a data pointer which can be projected to a size greater than its own.
@@ -248,6 +554,27 @@ impl<T: Copy> MemLoader for PtrBufferLoader<T> {
         dense
     }
 
+    #[inline(always)]
+    fn is_aligned_to(&self, align: usize) -> bool {
+        (self.data as usize) % align == 0
+    }
+
+    #[inline(always)]
+    unsafe fn load_aligned_dense<R: SimdRegister<Self::Value>>(
+        &mut self,
+    ) -> DenseLane<R::Register> {
+        let dense = R::load_aligned_dense(self.data.add(self.data_cursor));
+        self.data_cursor += R::elements_per_dense();
+        dense
+    }
+
+    #[inline(always)]
+    unsafe fn load_aligned<R: SimdRegister<Self::Value>>(&mut self) -> R::Register {
+        let dense = R::load_aligned(self.data.add(self.data_cursor));
+        self.data_cursor += R::elements_per_lane();
+        dense
+    }
+
     #[inline(always)]
     unsafe fn read(&mut self) -> Self::Value {
         let value = self.data.add(self.data_cursor).read();
@@ -343,6 +670,72 @@ impl<T: Copy + Default> MemLoader for ProjectedPtrBufferLoader<T> {
     }
 }
 
+/// A [MemLoader] implementation that reads from a non-contiguous buffer represented
+/// as a data pointer, where each element is `stride` elements apart.
+///
+/// Each register is gathered into a scratch buffer before being loaded, with any
+/// elements beyond the end of the buffer being zero-padded.
+pub struct StridedLoader<T> {
+    data: *const T,
+    stride: usize,
+    len: usize,
+
+    // Generator state machine
+    data_cursor: usize,
+}
+
+impl<T: Copy + Default> MemLoader for StridedLoader<T> {
+    type Value = T;
+
+    #[inline(always)]
+    fn true_len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    fn projected_len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    unsafe fn load_dense<R: SimdRegister<Self::Value>>(
+        &mut self,
+    ) -> DenseLane<R::Register> {
+        DenseLane {
+            a: self.load::<R>(),
+            b: self.load::<R>(),
+            c: self.load::<R>(),
+            d: self.load::<R>(),
+            e: self.load::<R>(),
+            f: self.load::<R>(),
+            g: self.load::<R>(),
+            h: self.load::<R>(),
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn load<R: SimdRegister<Self::Value>>(&mut self) -> R::Register {
+        let mut temp_buffer = [T::default(); SCRATCH_SPACE_SIZE];
+
+        let n = R::elements_per_lane().min(self.len.saturating_sub(self.data_cursor));
+        // elements_per_lane != SCRATCH_SPACE_SIZE, this is cleaner than an iter chain.
+        #[allow(clippy::needless_range_loop)]
+        for i in 0..n {
+            temp_buffer[i] = self.data.add((self.data_cursor + i) * self.stride).read();
+        }
+        self.data_cursor += R::elements_per_lane();
+
+        R::load(temp_buffer.as_ptr())
+    }
+
+    #[inline(always)]
+    unsafe fn read(&mut self) -> Self::Value {
+        let value = self.data.add(self.data_cursor * self.stride).read();
+        self.data_cursor += 1;
+        value
+    }
+}
+
 /// A [MemLoader] implementation that holds a single value that has been broadcast
 /// to a desired size.
 pub struct ScalarBufferLoader<T> {
@@ -635,7 +1028,7 @@ mod tests {
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
         target_feature = "avx512f",
-        feature = "nightly"
+        feature = "stable-avx512"
     ))]
     #[test]
     fn test_buffer_projection_avx512_dense_load() {
@@ -727,7 +1120,7 @@ mod tests {
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
         target_feature = "avx512f",
-        feature = "nightly",
+        feature = "stable-avx512",
     ))]
     #[test]
     fn test_buffer_projection_avx512_load() {
@@ -784,4 +1177,490 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_strided_basic_read() {
+        let sample = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+        let mut loader = Strided::new(&sample, 3).into_mem_loader();
+        assert_eq!(loader.true_len(), 3);
+        assert_eq!(loader.projected_len(), 3);
+
+        unsafe {
+            assert_eq!(loader.read(), 1.0);
+            assert_eq!(loader.read(), 4.0);
+            assert_eq!(loader.read(), 7.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_strided_zero_stride_panic() {
+        let sample = [1.0, 2.0, 3.0];
+        let _strided = Strided::new(&sample, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_strided_projection_panic() {
+        let sample = [1.0, 2.0, 3.0, 4.0];
+        let _loader = Strided::new(&sample, 2).into_projected_mem_loader(4);
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    #[test]
+    fn test_strided_avx2_load_zero_pads() {
+        let sample = (0..30).map(|v| v as f32).collect::<Vec<_>>();
+        let mut loader = Strided::new(&sample, 3).into_mem_loader();
+        assert_eq!(loader.projected_len(), 10);
+
+        #[allow(clippy::missing_transmute_annotations)]
+        unsafe {
+            let reg = loader.load::<crate::danger::Avx2>();
+            assert_eq!(
+                core::mem::transmute::<_, [f32; 8]>(reg),
+                [0.0, 3.0, 6.0, 9.0, 12.0, 15.0, 18.0, 21.0]
+            );
+            let reg = loader.load::<crate::danger::Avx2>();
+            assert_eq!(
+                core::mem::transmute::<_, [f32; 8]>(reg),
+                [24.0, 27.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]
+            );
+        }
+    }
+
+    fn test_strided_ops<R: SimdRegister<f32>>() {
+        use crate::danger::{
+            generic_add_vertical,
+            generic_dot,
+            generic_squared_euclidean,
+        };
+        use crate::math::AutoMath;
+        use crate::test_utils::get_sample_vectors;
+
+        let (rows, cols) = (137, 19);
+        let (matrix, other) = get_sample_vectors::<f32>(rows * cols);
+        let other = &other[..rows];
+
+        for col in 0..cols {
+            let dense = matrix[col..]
+                .iter()
+                .step_by(cols)
+                .copied()
+                .collect::<Vec<_>>();
+            assert_eq!(dense.len(), rows);
+
+            unsafe {
+                let expected = generic_dot::<f32, R, AutoMath, _, _>(&dense, other);
+                let value = generic_dot::<f32, R, AutoMath, _, _>(
+                    Strided::new(&matrix[col..], cols),
+                    other,
+                );
+                assert_eq!(value, expected, "dot mismatch on column {col}");
+
+                let expected =
+                    generic_squared_euclidean::<f32, R, AutoMath, _, _>(&dense, other);
+                let value = generic_squared_euclidean::<f32, R, AutoMath, _, _>(
+                    other,
+                    Strided::new(&matrix[col..], cols),
+                );
+                assert_eq!(
+                    value, expected,
+                    "squared euclidean mismatch on column {col}"
+                );
+
+                let mut expected = vec![0.0f32; rows];
+                generic_add_vertical::<f32, R, AutoMath, _, _, _>(
+                    &dense,
+                    other,
+                    &mut expected,
+                );
+                let mut result = vec![0.0f32; rows];
+                generic_add_vertical::<f32, R, AutoMath, _, _, _>(
+                    Strided::new(&matrix[col..], cols),
+                    other,
+                    &mut result,
+                );
+                assert_eq!(result, expected, "add mismatch on column {col}");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_broadcast_projection_panic() {
+        let _loader = BroadcastLoader::new(1.0f32, 3).into_projected_mem_loader(4);
+    }
+
+    #[test]
+    fn test_broadcast_basic_read() {
+        let mut loader = BroadcastLoader::new(2.0f32, 3).into_mem_loader();
+        assert_eq!(loader.true_len(), 1);
+        assert_eq!(loader.projected_len(), 3);
+
+        unsafe {
+            assert_eq!(loader.read(), 2.0);
+            assert_eq!(loader.load::<Fallback>(), 2.0);
+            let dense = loader.load_dense::<Fallback>();
+            assert_eq!(dense.a, 2.0);
+            assert_eq!(dense.h, 2.0);
+        }
+    }
+
+    fn test_broadcast_ops<R: SimdRegister<f32>>() {
+        use crate::danger::{
+            generic_add_vertical,
+            generic_dot,
+            generic_mul_vertical,
+            generic_squared_euclidean,
+            generic_sum,
+        };
+        use crate::math::{AutoMath, Math};
+        use crate::test_utils::get_sample_vectors;
+
+        for len in [0, 1, 7, 137, 1043] {
+            let (a, _) = get_sample_vectors::<f32>(len);
+            let broadcast = vec![2.5f32; len];
+
+            unsafe {
+                let expected = generic_sum::<f32, R, AutoMath, _>(&a);
+                let value = generic_dot::<f32, R, AutoMath, _, _>(
+                    BroadcastLoader::new(1.0, len),
+                    &a,
+                );
+                assert!(
+                    AutoMath::is_close(value, expected),
+                    "sum mismatch {value} vs {expected} on length {len}",
+                );
+
+                let expected = generic_dot::<f32, R, AutoMath, _, _>(&broadcast, &a);
+                let value = generic_dot::<f32, R, AutoMath, _, _>(
+                    &a,
+                    BroadcastLoader::new(2.5, len),
+                );
+                assert_eq!(value, expected, "dot mismatch on length {len}");
+
+                let expected =
+                    generic_squared_euclidean::<f32, R, AutoMath, _, _>(&a, &broadcast);
+                let value = generic_squared_euclidean::<f32, R, AutoMath, _, _>(
+                    &a,
+                    BroadcastLoader::new(2.5, len),
+                );
+                assert_eq!(
+                    value, expected,
+                    "squared euclidean mismatch on length {len}"
+                );
+
+                let mut expected = vec![0.0f32; len];
+                generic_mul_vertical::<f32, R, AutoMath, _, _, _>(
+                    &a,
+                    2.5,
+                    &mut expected,
+                );
+                let mut result = vec![0.0f32; len];
+                generic_mul_vertical::<f32, R, AutoMath, _, _, _>(
+                    &a,
+                    BroadcastLoader::new(2.5, len),
+                    &mut result,
+                );
+                assert_eq!(result, expected, "mul mismatch on length {len}");
+
+                let mut expected = vec![0.0f32; len];
+                generic_add_vertical::<f32, R, AutoMath, _, _, _>(
+                    2.5,
+                    &a,
+                    &mut expected,
+                );
+                let mut result = vec![0.0f32; len];
+                generic_add_vertical::<f32, R, AutoMath, _, _, _>(
+                    BroadcastLoader::new(2.5, len),
+                    &a,
+                    &mut result,
+                );
+                assert_eq!(result, expected, "add mismatch on length {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_broadcast_fallback_ops() {
+        test_broadcast_ops::<Fallback>();
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    #[test]
+    fn test_broadcast_avx2_ops() {
+        test_broadcast_ops::<crate::danger::Avx2>();
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512f"
+    ))]
+    #[test]
+    fn test_broadcast_avx512_ops() {
+        test_broadcast_ops::<crate::danger::Avx512>();
+    }
+
+    #[test]
+    fn test_strided_fallback_ops() {
+        test_strided_ops::<Fallback>();
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    #[test]
+    fn test_strided_avx2_ops() {
+        test_strided_ops::<crate::danger::Avx2>();
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512f"
+    ))]
+    #[test]
+    fn test_strided_avx512_ops() {
+        test_strided_ops::<crate::danger::Avx512>();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunked_empty_pattern_panic() {
+        let _loader = ChunkedLoader::<f32>::new(&[], 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunked_long_pattern_panic() {
+        let pattern = [1.0f32; 33];
+        let _loader = ChunkedLoader::new(&pattern, 66);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunked_projection_panic() {
+        let _loader =
+            ChunkedLoader::new(&[1.0f32, -1.0], 3).into_projected_mem_loader(4);
+    }
+
+    #[test]
+    fn test_chunked_basic_read() {
+        let mut loader = ChunkedLoader::new(&[1.0f32, 2.0, 3.0], 7).into_mem_loader();
+        assert_eq!(loader.true_len(), 3);
+        assert_eq!(loader.projected_len(), 7);
+
+        unsafe {
+            assert_eq!(loader.read(), 1.0);
+            assert_eq!(loader.read(), 2.0);
+            assert_eq!(loader.read(), 3.0);
+            assert_eq!(loader.read(), 1.0);
+            assert_eq!(loader.load::<Fallback>(), 2.0);
+            let dense = loader.load_dense::<Fallback>();
+            assert_eq!(dense.a, 3.0);
+            assert_eq!(dense.b, 1.0);
+            assert_eq!(dense.h, 1.0);
+        }
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    #[test]
+    fn test_chunked_avx2_load_wraps_pattern() {
+        let mut loader = ChunkedLoader::new(&[1.0f32, 2.0, 3.0], 16).into_mem_loader();
+
+        #[allow(clippy::missing_transmute_annotations)]
+        unsafe {
+            let reg = loader.load::<crate::danger::Avx2>();
+            assert_eq!(
+                core::mem::transmute::<_, [f32; 8]>(reg),
+                [1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 1.0, 2.0]
+            );
+            let reg = loader.load::<crate::danger::Avx2>();
+            assert_eq!(
+                core::mem::transmute::<_, [f32; 8]>(reg),
+                [3.0, 1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 1.0]
+            );
+        }
+    }
+
+    fn test_chunked_ops<R: SimdRegister<f32>>() {
+        use crate::danger::{generic_add_vertical, generic_dot};
+        use crate::math::AutoMath;
+        use crate::test_utils::get_sample_vectors;
+
+        let pattern = [1.0f32, -2.0, 0.5, 3.0, -1.5];
+        for len in [0, 1, 7, 137, 1043] {
+            let (a, _) = get_sample_vectors::<f32>(len);
+            let tiled = pattern
+                .iter()
+                .copied()
+                .cycle()
+                .take(len)
+                .collect::<Vec<_>>();
+
+            unsafe {
+                let expected = generic_dot::<f32, R, AutoMath, _, _>(&a, &tiled);
+                let value = generic_dot::<f32, R, AutoMath, _, _>(
+                    &a,
+                    ChunkedLoader::new(&pattern, len),
+                );
+                assert_eq!(value, expected, "dot mismatch on length {len}");
+
+                let mut expected = vec![0.0f32; len];
+                generic_add_vertical::<f32, R, AutoMath, _, _, _>(
+                    &tiled,
+                    &a,
+                    &mut expected,
+                );
+                let mut result = vec![0.0f32; len];
+                generic_add_vertical::<f32, R, AutoMath, _, _, _>(
+                    ChunkedLoader::new(&pattern, len),
+                    &a,
+                    &mut result,
+                );
+                assert_eq!(result, expected, "add mismatch on length {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunked_fallback_ops() {
+        test_chunked_ops::<Fallback>();
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    #[test]
+    fn test_chunked_avx2_ops() {
+        test_chunked_ops::<crate::danger::Avx2>();
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512f"
+    ))]
+    #[test]
+    fn test_chunked_avx512_ops() {
+        test_chunked_ops::<crate::danger::Avx512>();
+    }
+
+    #[repr(C, align(64))]
+    struct AlignedStorage([f32; 1104]);
+
+    impl AlignedStorage {
+        fn new() -> Box<Self> {
+            Box::new(Self([0.0; 1104]))
+        }
+    }
+
+    #[test]
+    fn test_buffer_loader_alignment() {
+        let storage = AlignedStorage::new();
+
+        let loader = (&storage.0[..]).into_mem_loader();
+        assert!(loader.is_aligned_to(32));
+        assert!(loader.is_aligned_to(64));
+
+        let loader = (&storage.0[8..]).into_mem_loader();
+        assert!(loader.is_aligned_to(32));
+        assert!(!loader.is_aligned_to(64));
+
+        let loader = (&storage.0[1..]).into_mem_loader();
+        assert!(loader.is_aligned_to(4));
+        assert!(!loader.is_aligned_to(32));
+
+        // Loaders which never read registers directly from memory can always use
+        // the aligned path.
+        assert!(f32::into_projected_mem_loader(1.0, 10).is_aligned_to(64));
+    }
+
+    /// Runs the vertical routines over every combination of aligned and misaligned
+    /// inputs and outputs, the aligned loads fault if a misaligned buffer is ever
+    /// sent down the aligned path.
+    fn test_aligned_ops<R: SimdRegister<f32>>() {
+        use crate::danger::{generic_add_inplace, generic_add_vertical};
+        use crate::math::AutoMath;
+        use crate::test_utils::get_sample_vectors;
+
+        let mut a_storage = AlignedStorage::new();
+        let mut b_storage = AlignedStorage::new();
+        let mut result_storage = AlignedStorage::new();
+
+        for (a_offset, b_offset, result_offset) in
+            [(0, 0, 0), (1, 0, 0), (0, 3, 0), (0, 0, 5), (7, 2, 1)]
+        {
+            for len in [0, 1, 7, 137, 1043] {
+                let (a, b) = get_sample_vectors::<f32>(len);
+                let expected = a
+                    .iter()
+                    .zip(b.iter())
+                    .map(|(a, b)| a + b)
+                    .collect::<Vec<_>>();
+
+                let a_slice = &mut a_storage.0[a_offset..][..len];
+                a_slice.copy_from_slice(&a);
+                let b_slice = &mut b_storage.0[b_offset..][..len];
+                b_slice.copy_from_slice(&b);
+                let result = &mut result_storage.0[result_offset..][..len];
+
+                unsafe {
+                    generic_add_vertical::<f32, R, AutoMath, _, _, _>(
+                        &*a_slice, &*b_slice, result,
+                    );
+                }
+                assert_eq!(
+                    result, expected,
+                    "add mismatch on length {len} with offsets \
+                    {a_offset}, {b_offset}, {result_offset}",
+                );
+
+                unsafe {
+                    generic_add_inplace::<f32, R, AutoMath, _>(a_slice, &*b_slice);
+                }
+                assert_eq!(
+                    a_slice, expected,
+                    "add inplace mismatch on length {len} with offsets \
+                    {a_offset}, {b_offset}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_aligned_fallback_ops() {
+        test_aligned_ops::<Fallback>();
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    ))]
+    #[test]
+    fn test_aligned_avx2_ops() {
+        test_aligned_ops::<crate::danger::Avx2>();
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "stable-avx512",
+        target_feature = "avx512f"
+    ))]
+    #[test]
+    fn test_aligned_avx512_ops() {
+        test_aligned_ops::<crate::danger::Avx512>();
+    }
 }
@@ -7,11 +7,26 @@
 //! or `target-feature` Rust flags set otherwise this will always use the `Fallback` implementations.
 
 use crate::buffer::WriteOnlyBuffer;
+use crate::error::unwrap_size;
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 use crate::safe_trait_agg_ops::AggOps;
-use crate::safe_trait_arithmetic_ops::ArithmeticOps;
+use crate::safe_trait_arithmetic_ops::{ArithmeticOps, NegOps, SaturatingArithmeticOps};
+use crate::safe_trait_bitwise_ops::BitwiseOps;
 use crate::safe_trait_cmp_ops::CmpOps;
-use crate::safe_trait_distance_ops::DistanceOps;
+use crate::safe_trait_distance_ops::{
+    BinaryDistanceOps,
+    CheckedDistanceOps,
+    CompensatedReductionOps,
+    DistanceOps,
+    F64AccDistanceOps,
+    OrderedReductionOps,
+    WideAccCosineOps,
+    WideAccDistanceOps,
+};
+use crate::safe_trait_float_ops::FloatOps;
+use crate::safe_trait_gather_ops::GatherOps;
+use crate::safe_trait_stats_ops::StatsOps;
+use crate::safe_try_function_ops::*;
 
 #[inline]
 /// Calculates the cosine similarity distance of vectors `a` and `b`.
@@ -116,6 +131,47 @@ where
     T::dot(a, b)
 }
 
+#[inline]
+/// Calculates the weighted dot product of vectors `a` and `b`, scaling the product
+/// of each pair of elements by the matching weight in `w`.
+///
+/// This is commonly used for IDF or attention weighted similarity.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = vec![1.0f32, 2.0, 3.0, 4.0];
+/// let b = vec![0.5f32, 0.25, 2.0, 1.0];
+/// let w = vec![2.0f32, 1.0, 0.5, 0.0];
+///
+/// let distance = cfavml::weighted_dot(a.len(), &a, &b, &w);
+/// assert_eq!(distance, 4.5);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0
+///
+/// for i in range(dims):
+///     result += w[i] * (a[i] * b[i])
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a`, `b` and `w` are not exactly `dims`
+/// elements in length.
+pub fn weighted_dot<T>(dims: usize, a: &[T], b: &[T], w: &[T]) -> T
+where
+    T: DistanceOps,
+{
+    unwrap_size(try_weighted_dot(dims, a, b, w))
+}
+
 #[inline]
 /// Calculates the squared Euclidean distance of vectors `a` and `b`.
 ///
@@ -136,6 +192,9 @@ where
 /// assert_eq!(distance, 0.51);
 /// ```
 ///
+/// For the integer types the calculation wraps on overflow, see
+/// [squared_euclidean_wide_acc] and [checked_squared_euclidean] for alternatives.
+///
 /// ### Implementation Pseudocode
 ///
 /// _This is the logic of the routine being called._
@@ -165,22 +224,19 @@ where
 }
 
 #[inline]
-/// Calculates the squared L2 norm of vector `a`.
-///
-/// ### Examples
+/// Calculates the dot product of `f32` vectors `a` and `b`, accumulating in `f64`.
 ///
-/// We can create a single vector and calculate the squared L2 norm.
-/// Any type that implements `AsRef<[A]>` can be provided, where `A` is any type from:
-///
-/// > `f32`, `f64`, `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
+/// This is slower than [dot] but avoids the rounding error that builds up when
+/// accumulating long vectors in `f32`.
 ///
-/// _Although you likely want `f32` or `f64`._
+/// ### Examples
 ///
 /// ```rust
-/// let a = vec![1.0, 0.3, 0.2, 0.4, 0.2, 0.1, 0.3, 0.2];
+/// let a = vec![1.0f32, 2.0, 3.0, 4.0];
+/// let b = vec![0.5f32, 0.25, 2.0, 1.0];
 ///
-/// let norm = cfavml::squared_norm(&a);
-/// assert_eq!(norm, 1.47);
+/// let distance = cfavml::dot_f64acc(&a, &b);
+/// assert_eq!(distance, 11.0f64);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -188,40 +244,39 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// result = 0
+/// result = 0.0f64
 ///
 /// for i in range(dims):
-///     result += a[i] * a[i]
+///     result += f64(a[i]) * f64(b[i])
 ///
 /// return result
 /// ```
-pub fn squared_norm<T, B1>(a: B1) -> T
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn dot_f64acc<T>(a: &[T], b: &[T]) -> f64
 where
-    T: DistanceOps,
-    B1: IntoMemLoader<T>,
-    B1::Loader: MemLoader<Value = T>,
+    T: F64AccDistanceOps,
 {
-    T::squared_norm(a)
+    unwrap_size(try_dot_f64acc(a, b))
 }
 
 #[inline]
-/// Performs a horizontal sum of all elements in a returning the result.
-///
-/// ### Examples
-///
-/// We can create a single vector and calculate the squared L2 norm.
-/// Any type that implements `AsRef<[A]>` can be provided, where `A` is any type from:
+/// Calculates the squared Euclidean distance of `f32` vectors `a` and `b`,
+/// accumulating in `f64`.
 ///
-/// > `f32`, `f64`, `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
+/// This is slower than [squared_euclidean] but avoids the rounding error that builds
+/// up when accumulating long vectors in `f32`.
 ///
-/// It is worth noting however, the compiler can often match the speed of this particular
-/// routine if your operations are as simple as `my_vector.iter().sum()`.
+/// ### Examples
 ///
 /// ```rust
-/// let a = vec![1.0, 0.3, 0.2, 0.4, 0.2, 0.1, 0.3, 0.2];
+/// let a = vec![1.0f32, 2.0, 3.0, 4.0];
+/// let b = vec![0.5f32, 0.25, 2.0, 1.0];
 ///
-/// let total = cfavml::sum(&a);
-/// assert_eq!(total, 2.7);
+/// let distance = cfavml::squared_euclidean_f64acc(&a, &b);
+/// assert_eq!(distance, 13.3125f64);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -229,43 +284,38 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// result = 0
+/// result = 0.0f64
 ///
 /// for i in range(dims):
-///     result += a[i]
+///     diff = f64(a[i]) - f64(b[i])
+///     result += diff * diff
 ///
 /// return result
 /// ```
-pub fn sum<T, B1>(a: B1) -> T
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn squared_euclidean_f64acc<T>(a: &[T], b: &[T]) -> f64
 where
-    T: AggOps,
-    B1: IntoMemLoader<T>,
-    B1::Loader: MemLoader<Value = T>,
+    T: F64AccDistanceOps,
 {
-    T::sum(a)
+    unwrap_size(try_squared_euclidean_f64acc(a, b))
 }
 
 #[inline]
-/// Finds the horizontal max element of a given vector and returns the result.
-///
-/// ### Default Value Warning
+/// Calculates the squared L2 norm of the `f32` vector `a`, accumulating in `f64`.
 ///
-/// Beware of the default value returned when passing a zero-length array (which is technically allowed)
-///
-/// If you are using `f32` or `f64` types, this becomes `T::NEG_INFINITY` otherwise the
-/// default max value is `T:MIN`.
+/// This is slower than [squared_norm] but avoids the rounding error that builds
+/// up when accumulating long vectors in `f32`.
 ///
 /// ### Examples
 ///
-/// Any vector can be created and have the maximum value found within using the `max` operation, but
-/// be aware of the default value handling if you pass an empty array
-/// (see the "Default Value Warning" section for more.)
-///
 /// ```rust
-/// let a = vec![1.0, 0.3, 0.2, 0.4, 0.2, 0.1, 0.3, 0.2];
+/// let a = vec![1.0f32, 2.0, 3.0, 4.0];
 ///
-/// let total = cfavml::max(&a);
-/// assert_eq!(total, 1.0);
+/// let norm = cfavml::squared_norm_f64acc(&a);
+/// assert_eq!(norm, 30.0f64);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -273,139 +323,154 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// result = -inf
+/// result = 0.0f64
 ///
 /// for i in range(dims):
-///     result = max(result, a[i])
+///     result += f64(a[i]) ** 2
 ///
 /// return result
 /// ```
-pub fn max<T, B1>(a: B1) -> T
+pub fn squared_norm_f64acc<T>(a: &[T]) -> f64
 where
-    T: CmpOps,
-    B1: IntoMemLoader<T>,
-    B1::Loader: MemLoader<Value = T>,
+    T: F64AccDistanceOps,
 {
-    T::max(a)
+    T::squared_norm_f64acc(a)
 }
 
 #[inline]
-/// Takes the element wise max of vectors `a` and `b` of size `dims` and stores the result
-/// in `result` of size `dims`.
+/// Performs a horizontal sum of all elements in the `f32` vector `a`, accumulating
+/// in `f64`.
 ///
-/// ### Things To Know
-///
-/// ###### Supported patterns
+/// This is slower than [sum] but avoids the rounding error that builds up when
+/// accumulating long vectors in `f32`.
 ///
-/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
-/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
-/// read from before executing. For the most part, you don't need to worry about this outside of
-/// knowing you can by default, pass one combination of:
-///     
-/// - `lhs: vector` and `rhs: vector`  
-/// - `lhs: vector` and `rhs: broadcast value`  
-/// - `lhs: broadcast value` and `rhs: vector`  
-/// - `lhs: broadcast value` and `rhs: broadcast value`  
-///   * Not really that useful and is just an artefact of the memory management system.
+/// ### Examples
 ///
-/// ###### Broadcast values
+/// ```rust
+/// // The small values are entirely lost when accumulated in `f32`.
+/// let a = vec![1.0e8f32, 1.0, 1.0, 1.0, 1.0];
 ///
-/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
-/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
-/// outside the result buffer itself.
+/// let total = cfavml::sum_f64acc(&a);
+/// assert_eq!(total, 100_000_004.0f64);
+/// ```
 ///
-/// This means the following is possible:
-/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `1 + 1 == [1]`  w/result_buffer_len=1
+/// ### Implementation Pseudocode
 ///
-/// ###### Projecting Vectors
+/// _This is the logic of the routine being called._
 ///
-/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
-/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+/// ```ignore
+/// result = 0.0f64
 ///
-/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
-/// which exhibit the standard behaviour as you might expect.
+/// for i in range(dims):
+///     result += f64(a[i])
 ///
-/// When providing two slices as inputs they cannot be projected to a buffer
-/// that is larger their input sizes by default. This means providing two slices
-/// of `128` elements in length must take a result buffer of `128` elements in length.
+/// return result
+/// ```
+pub fn sum_f64acc<T>(a: &[T]) -> f64
+where
+    T: F64AccDistanceOps,
+{
+    T::sum_f64acc(a)
+}
+
+#[inline]
+/// Calculates the dot product of vectors `a` and `b` using compensated summation to
+/// accumulate the products.
 ///
-/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
-/// enables projecting of the input buffer to new sizes providing the new size is a
-/// multiple of the original size. When this buffer is projected, it is effectively
-/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+/// This is slower than [dot] but tracks the rounding error of each addition, giving a
+/// result close to accumulating in twice the precision without widening each element.
+/// Only the accumulation is compensated, the rounding of each product is not tracked.
 ///
 /// ### Examples
 ///
-/// ##### Two vectors
-///
 /// ```rust
-/// let lhs = [1.0, 1.0, 1.0, 1.0];
-/// let rhs = [2.0, 2.5, 1.0, -2.0];
+/// let a = vec![1.0e8f32, 1.0, -1.0e8, 1.0];
+/// let b = vec![1.0f32; 4];
 ///
-/// let mut result = [0.0; 4];
-/// cfavml::max_vertical(&lhs, &rhs, &mut result);
-/// assert_eq!(result, [2.0, 2.5, 1.0, 1.0]);
+/// let distance = cfavml::dot_compensated(&a, &b);
+/// assert_eq!(distance, 2.0);
 /// ```
 ///
-/// ##### One vector & broadcast value
+/// ### Implementation Pseudocode
 ///
-/// ```rust
-/// let lhs = [2.0, 3.0, -1.0, -0.5];
+/// _This is the logic of the routine being called._
 ///
-/// let mut result = [0.0; 4];
-/// cfavml::max_vertical(&lhs, 0.0, &mut result);
-/// assert_eq!(result, [2.0, 3.0, 0.0, 0.0]);
-/// ```
+/// ```ignore
+/// result = 0
+/// comp = 0
+/// comp2 = 0
 ///
-/// ##### Two broadcast values
+/// for i in range(dims):
+///     result, err = two_sum(result, a[i] * b[i])
+///     comp, err = two_sum(comp, err)
+///     comp2 += err
 ///
-/// ```rust
-/// let mut result = [0.0; 4];
-/// cfavml::max_vertical(1.0, 0.0, &mut result);
-/// assert_eq!(result, [1.0; 4]);
+/// return result + (comp + comp2)
 /// ```
 ///
-/// ##### With projected vectors
+/// ### Panics
 ///
-/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
-/// vectors than the original inputs, which can allow for much better performance and
-/// memory usage when working with matrices.
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn dot_compensated<T>(a: &[T], b: &[T]) -> T
+where
+    T: CompensatedReductionOps,
+{
+    unwrap_size(try_dot_compensated(a, b))
+}
+
+#[inline]
+/// Performs a horizontal sum of all elements in vector `a` using compensated summation.
 ///
-/// NOTE:
-/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
-/// how it behaves, it does not replace your matrix library's own broadcasting system.
+/// This is slower than [sum] but tracks the rounding error of each addition, giving a
+/// result close to accumulating in twice the precision without widening each element.
 ///
-/// ```rust
-/// use cfavml::mem_loader::Projected;
+/// ### Examples
 ///
-/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
-/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+/// ```rust
+/// let a = vec![1.0e8f32, 1.0, -1.0e8, 1.0];
 ///
-/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
-/// cfavml::max_vertical(Projected(&lhs), Projected(&rhs), &mut result);
-/// assert_eq!(result, [1.0, 2.5, 1.0, 3.5]);
+/// let total = cfavml::sum_compensated(&a);
+/// assert_eq!(total, 2.0);
 /// ```
 ///
-/// ##### With `MaybeUninit`
+/// ### Implementation Pseudocode
 ///
-/// Often if you are working with new-allocations, you do not want to initialize the data twice,
-/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
-/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+/// _This is the logic of the routine being called._
 ///
-/// ```rust
-/// use core::mem::MaybeUninit;
+/// ```ignore
+/// result = 0
+/// comp = 0
+/// comp2 = 0
 ///
-/// let lhs = [1.0, 1.0, 1.0, 1.0];
-/// let rhs = [2.0, 2.5, 1.0, -2.0];
+/// for i in range(dims):
+///     result, err = two_sum(result, a[i])
+///     comp, err = two_sum(comp, err)
+///     comp2 += err
 ///
-/// let mut result = Vec::with_capacity(4);
-/// unsafe { result.set_len(4) };
-/// cfavml::max_vertical(&lhs, &rhs, &mut result);
+/// return result + (comp + comp2)
+/// ```
+pub fn sum_compensated<T>(a: &[T]) -> T
+where
+    T: CompensatedReductionOps,
+{
+    T::sum_compensated(a)
+}
+
+#[inline]
+/// Calculates the dot product of vectors `a` and `b` in a fixed accumulation order.
 ///
-/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
-/// assert_eq!(result, [2.0, 2.5, 1.0, 1.0]);
+/// Unlike [dot], the result is bit-identical regardless of which CPU features are
+/// available to the machine, at the cost of some throughput. This is useful when
+/// results must be reproducible across machines.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = vec![1.0f32, 2.0, 3.0, 4.0];
+/// let b = vec![0.5f32, 0.25, 2.0, 1.0];
+///
+/// let distance = cfavml::dot_ordered(&a, &b);
+/// assert_eq!(distance, 11.0);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -413,51 +478,40 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// result = [0; dims]
+/// lanes = [0; 64]
 ///
 /// for i in range(dims):
-///     result[i] = max(a[i], b[i])
+///     lanes[i % 64] += a[i] * b[i]
 ///
-/// return result
+/// while len(lanes) > 1:
+///     lanes = [lanes[j] + lanes[j + 1] for j in range(0, len(lanes), 2)]
+///
+/// return lanes[0]
 /// ```
 ///
-/// # Panics
+/// ### Panics
 ///
-/// If vectors `a` and `b` cannot be projected to the target size of `result`.
-/// Note that the projection rules are tied to the `MemLoader` implementation.
-pub fn max_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn dot_ordered<T>(a: &[T], b: &[T]) -> T
 where
-    T: CmpOps,
-    B1: IntoMemLoader<T>,
-    B1::Loader: MemLoader<Value = T>,
-    B2: IntoMemLoader<T>,
-    B2::Loader: MemLoader<Value = T>,
-    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+    T: OrderedReductionOps,
 {
-    T::max_vertical(lhs, rhs, result)
+    unwrap_size(try_dot_ordered(a, b))
 }
 
 #[inline]
-/// Finds the horizontal min element of a given vector and returns the result.
-///
-/// ### Default Value Warning
-///
-/// Beware of the default value returned when passing a zero-length array (which is technically allowed)
+/// Calculates the squared L2 norm of vector `a` in a fixed accumulation order.
 ///
-/// If you are using `f32` or `f64` types, this becomes `T::NEG_INFINITY` otherwise the
-/// default max value is `T:MIN`.
+/// Unlike [squared_norm], the result is bit-identical regardless of which CPU features
+/// are available to the machine, at the cost of some throughput.
 ///
 /// ### Examples
 ///
-/// Any vector can be created and have the minimum value found within using the `min` operation, but
-/// be aware of the default value handling if you pass an empty array
-/// (see the "Default Value Warning" section for more.)
-///
 /// ```rust
-/// let a = vec![1.0, 0.3, 0.2, 0.4, 0.2, 0.1, 0.3, 0.2];
+/// let a = vec![3.0f32, 4.0];
 ///
-/// let total = cfavml::min(&a);
-/// assert_eq!(total, 0.1);
+/// let norm = cfavml::squared_norm_ordered(&a);
+/// assert_eq!(norm, 25.0);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -465,25 +519,4289 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// result = inf
+/// lanes = [0; 64]
 ///
 /// for i in range(dims):
-///     result = min(result, a[i])
+///     lanes[i % 64] += a[i] * a[i]
+///
+/// while len(lanes) > 1:
+///     lanes = [lanes[j] + lanes[j + 1] for j in range(0, len(lanes), 2)]
+///
+/// return lanes[0]
+/// ```
+pub fn squared_norm_ordered<T>(a: &[T]) -> T
+where
+    T: OrderedReductionOps,
+{
+    T::squared_norm_ordered(a)
+}
+
+#[inline]
+/// Performs a horizontal sum of all elements in vector `a` in a fixed accumulation
+/// order.
+///
+/// Unlike [sum], the result is bit-identical regardless of which CPU features are
+/// available to the machine, at the cost of some throughput.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = vec![0.5f64, 1.5, 2.0];
+///
+/// let total = cfavml::sum_ordered(&a);
+/// assert_eq!(total, 4.0);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// lanes = [0; 64]
+///
+/// for i in range(dims):
+///     lanes[i % 64] += a[i]
+///
+/// while len(lanes) > 1:
+///     lanes = [lanes[j] + lanes[j + 1] for j in range(0, len(lanes), 2)]
+///
+/// return lanes[0]
+/// ```
+pub fn sum_ordered<T>(a: &[T]) -> T
+where
+    T: OrderedReductionOps,
+{
+    T::sum_ordered(a)
+}
+
+#[inline]
+/// Calculates the dot product of integer vectors `a` and `b`, widening each element
+/// and accumulating in the wider integer type.
+///
+/// Unlike [dot] a single product can never wrap, making this suitable for quantized
+/// `i16` vectors and `i32` vectors of ordinary values. The accumulation itself still
+/// wraps on overflow of the wider type.
+///
+/// ### Examples
+///
+/// Any `i16`, `u16`, `i32` or `u32` slice can be provided, producing an `i32`, `u32`,
+/// `i64` or `u64` respectively.
+/// With the `half` feature enabled, `bf16` slices can also be provided producing
+/// an `f32`.
+///
+/// ```rust
+/// let a = vec![i16::MAX, 2];
+/// let b = vec![i16::MAX, -3];
+///
+/// let distance = cfavml::dot_wide_acc(&a, &b);
+/// assert_eq!(distance, 1073676283i32);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0
+///
+/// for i in range(dims):
+///     result = result.wrapping_add(Acc(a[i]).wrapping_mul(Acc(b[i])))
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn dot_wide_acc<T>(a: &[T], b: &[T]) -> T::Acc
+where
+    T: WideAccDistanceOps,
+{
+    unwrap_size(try_dot_wide_acc(a, b))
+}
+
+#[inline]
+/// Calculates the cosine similarity distance of integer vectors `a` and `b`, widening
+/// each element and accumulating in a wider integer type.
+///
+/// Unlike [cosine] the distance is returned as an `f32` rather than being truncated to
+/// the integer element type.
+///
+/// ### Examples
+///
+/// Any `u8` or `u16` slice can be provided, accumulating in a `u32` or `u64`
+/// respectively.
+///
+/// ```rust
+/// let a = vec![3u8, 4];
+/// let b = vec![4u8, 3];
+///
+/// let distance = cfavml::cosine_wide_acc(&a, &b);
+/// assert_eq!(distance, 0.04);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0
+/// norm_a = 0
+/// norm_b = 0
+///
+/// for i in range(dims):
+///     result = result.wrapping_add(Acc(a[i]) * Acc(b[i]))
+///     norm_a = norm_a.wrapping_add(Acc(a[i]) * Acc(a[i]))
+///     norm_b = norm_b.wrapping_add(Acc(b[i]) * Acc(b[i]))
+///
+/// if norm_a == 0 and norm_b == 0:
+///     return 0.0
+/// elif norm_a == 0 or norm_b == 0:
+///     return 1.0
+/// else:
+///     return 1.0 - (result / sqrt(norm_a * norm_b))
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn cosine_wide_acc<T>(a: &[T], b: &[T]) -> f32
+where
+    T: WideAccCosineOps,
+{
+    unwrap_size(try_cosine_wide_acc(a, b))
+}
+
+#[inline]
+/// Calculates the dot product between the `query` vector and each of the `dims` element
+/// vectors within `data` selected by `indices`, writing the dot product of the vector
+/// selected by `indices[n]` to `result[n]`.
+///
+/// This is intended for candidates scattered throughout a large buffer, i.e. those
+/// selected by an index. The cache lines of the next candidate are prefetched while
+/// the current candidate is computed, which hides the stall on the first loads of each
+/// candidate. The results are identical to calling [dot] on each vector.
+///
+/// ### Examples
+///
+/// The `i`th vector of `data` is the `dims` elements starting at `data[i * dims]`.
+///
+/// ```rust
+/// let query = [1.0, 2.0];
+/// let data = [
+///     1.0, 0.0,
+///     0.0, 1.0,
+///     1.0, 1.0,
+/// ];
+///
+/// let mut result = [0.0f32; 3];
+/// cfavml::dot_indexed(2, &query, &data, &[2, 0, 2], &mut result);
+/// assert_eq!(result, [3.0, 1.0, 3.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for n in range(len(indices)):
+///     i = indices[n]
+///     result[n] = dot(query, data[i * dims:(i + 1) * dims])
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// If `query` is not exactly `dims` elements in length, `result` is not exactly
+/// `indices.len()` elements in length or any of the `indices` are out of bounds
+/// of `data`.
+pub fn dot_indexed<T, B>(
+    dims: usize,
+    query: &[T],
+    data: &[T],
+    indices: &[usize],
+    result: &mut [B],
+) where
+    T: DistanceOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    T::dot_indexed(dims, query, data, indices, result)
+}
+
+#[inline]
+/// Calculates the matrix-vector product of the `rows x cols` row-major `matrix` and the
+/// `cols` element vector `x`, writing the `rows` element result to `out`.
+///
+/// This is [batch_dot] with `x` as the query, so the results are identical.
+///
+/// ### Examples
+///
+/// ```rust
+/// let matrix = [
+///     1.0, 0.0, 2.0,
+///     0.5, 0.5, 0.5,
+/// ];
+/// let x = [1.0, 2.0, 3.0];
+///
+/// let mut out = [0.0f32; 2];
+/// cfavml::gemv(2, 3, &matrix, &x, &mut out);
+/// assert_eq!(out, [7.0, 3.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(rows):
+///     out[i] = dot(matrix[i * cols:(i + 1) * cols], x)
+///
+/// return out
+/// ```
+///
+/// ### Panics
+///
+/// If `matrix` is not exactly `rows * cols` elements in length, `x` is not exactly
+/// `cols` elements in length or `out` is not exactly `rows` elements in length.
+pub fn gemv<T>(rows: usize, cols: usize, matrix: &[T], x: &[T], out: &mut [T])
+where
+    T: DistanceOps,
+{
+    unwrap_size(try_gemv(rows, cols, matrix, x, out))
+}
+
+#[inline]
+/// Calculates the Hamming distance of the bit-packed vectors `a` and `b`, that is,
+/// the number of bits which differ between them.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = vec![0b1011u64, u64::MAX];
+/// let b = vec![0b0110u64, 0];
+///
+/// let distance = cfavml::binary_hamming(&a, &b);
+/// assert_eq!(distance, 67);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0
+///
+/// for i in range(words):
+///     result += popcount(a[i] ^ b[i])
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn binary_hamming<T>(a: &[T], b: &[T]) -> u32
+where
+    T: BinaryDistanceOps,
+{
+    unwrap_size(try_binary_hamming(a, b))
+}
+
+#[inline]
+/// Calculates the Jaccard similarity of the bit-packed vectors `a` and `b`, that is,
+/// the number of bits set in both vectors divided by the number of bits set in either.
+///
+/// If neither vector has any bits set, the vectors are identical and the similarity
+/// is `1.0`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = vec![0b1011u64, 0];
+/// let b = vec![0b0110u64, 0];
+///
+/// let similarity = cfavml::binary_jaccard(&a, &b);
+/// assert_eq!(similarity, 0.25);
+///
+/// let empty = vec![0u64; 2];
+/// assert_eq!(cfavml::binary_jaccard(&empty, &empty), 1.0);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// intersection = 0
+/// union = 0
+///
+/// for i in range(words):
+///     intersection += popcount(a[i] & b[i])
+///     union += popcount(a[i] | b[i])
+///
+/// if union == 0:
+///     return 1.0
+///
+/// return intersection / union
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn binary_jaccard<T>(a: &[T], b: &[T]) -> f32
+where
+    T: BinaryDistanceOps,
+{
+    unwrap_size(try_binary_jaccard(a, b))
+}
+
+#[inline]
+/// Calculates the squared Euclidean distance of integer vectors `a` and `b`, widening
+/// each element and accumulating in the wider integer type.
+///
+/// Unlike [squared_euclidean] a single squared difference can never wrap, which for
+/// `i32` and `u32` vectors happens with fairly ordinary values. The accumulation itself
+/// still wraps on overflow of the wider type.
+///
+/// ### Examples
+///
+/// Any `i16`, `u16`, `i32` or `u32` slice can be provided, producing an `i32`, `u32`,
+/// `i64` or `u64` respectively.
+/// With the `half` feature enabled, `bf16` slices can also be provided producing
+/// an `f32`.
+///
+/// ```rust
+/// let a = vec![i32::MAX, 0];
+/// let b = vec![0, -3];
+///
+/// let distance = cfavml::squared_euclidean_wide_acc(&a, &b);
+/// assert_eq!(distance, 4611686014132420618i64);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0
+///
+/// for i in range(dims):
+///     diff = Acc(a[i]) - Acc(b[i])
+///     result = result.wrapping_add(diff.wrapping_mul(diff))
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn squared_euclidean_wide_acc<T>(a: &[T], b: &[T]) -> T::Acc
+where
+    T: WideAccDistanceOps,
+{
+    unwrap_size(try_squared_euclidean_wide_acc(a, b))
+}
+
+#[inline]
+/// Calculates the squared Euclidean distance of integer vectors `a` and `b`, returning
+/// `None` if the calculation overflows.
+///
+/// This is computed one element at a time so is considerably slower than
+/// [squared_euclidean], which wraps on overflow instead.
+///
+/// ### Examples
+///
+/// Any `i64` or `u64` slice can be provided.
+///
+/// ```rust
+/// let a = vec![3u64, 1];
+/// let b = vec![1u64, 4];
+/// assert_eq!(cfavml::checked_squared_euclidean(&a, &b), Some(13));
+///
+/// let a = vec![u64::MAX, 1];
+/// let b = vec![0u64, 4];
+/// assert_eq!(cfavml::checked_squared_euclidean(&a, &b), None);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0
+///
+/// for i in range(dims):
+///     diff = abs(a[i] - b[i])
+///     result = result.checked_add(diff.checked_mul(diff)?)?
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// This function will panic if vectors `a` and `b` do not match in size.
+pub fn checked_squared_euclidean<T>(a: &[T], b: &[T]) -> Option<T>
+where
+    T: CheckedDistanceOps,
+{
+    unwrap_size(try_checked_squared_euclidean(a, b))
+}
+
+#[inline]
+/// Calculates the squared L2 norm of vector `a`.
+///
+/// ### Examples
+///
+/// We can create a single vector and calculate the squared L2 norm.
+/// Any type that implements `AsRef<[A]>` can be provided, where `A` is any type from:
+///
+/// > `f32`, `f64`, `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
+///
+/// _Although you likely want `f32` or `f64`._
+///
+/// ```rust
+/// let a = vec![1.0, 0.3, 0.2, 0.4, 0.2, 0.1, 0.3, 0.2];
+///
+/// let norm = cfavml::squared_norm(&a);
+/// assert_eq!(norm, 1.47);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0
+///
+/// for i in range(dims):
+///     result += a[i] * a[i]
+///
+/// return result
+/// ```
+pub fn squared_norm<T, B1>(a: B1) -> T
+where
+    T: DistanceOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::squared_norm(a)
+}
+
+#[inline]
+/// Calculates the cosine similarity distance between the `query` vector and each vector
+/// within the contiguous `batch` of vectors, writing the distance of the `i`th vector
+/// to `result[i]`.
+///
+/// This is more efficient than calling [cosine] for each vector in the batch, as the norm
+/// of the query is only computed once and each load of the query is reused across several
+/// candidates at once. The results are identical to calling [cosine] on each vector.
+///
+/// ### Examples
+///
+/// The `batch` must contain `result.len()` vectors each of `query.len()` elements laid out
+/// one after the other.
+///
+/// ```rust
+/// let query = [1.0, 0.0, 0.0, 0.0];
+/// let batch = [
+///     1.0, 0.0, 0.0, 0.0,
+///     0.0, 1.0, 0.0, 0.0,
+///     0.0, 0.0, 0.0, 0.0,
+/// ];
+///
+/// let mut result = [0.0f32; 3];
+/// cfavml::cosine_batch(&query, &batch, &mut result);
+/// assert_eq!(result, [0.0, 1.0, 1.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for n in range(len(result)):
+///     result[n] = cosine(query, batch[n * dims:(n + 1) * dims])
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// If `batch` is not exactly `query.len() * result.len()` elements in length.
+pub fn cosine_batch<T, B>(query: &[T], batch: &[T], result: &mut [B])
+where
+    T: DistanceOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    unwrap_size(try_cosine_batch(query, batch, result))
+}
+
+#[inline]
+/// Calculates the dot product between the `query` vector and each vector
+/// within the contiguous `batch` of vectors, writing the dot product of the `i`th vector
+/// to `result[i]`.
+///
+/// This is more efficient than calling [dot] for each vector in the batch, as each load
+/// of the query is reused across several candidates at once.
+/// The results are identical to calling [dot] on each vector.
+///
+/// ### Examples
+///
+/// The `batch` must contain `result.len()` vectors each of `query.len()` elements laid out
+/// one after the other.
+///
+/// ```rust
+/// let query = [1.0, 2.0, 3.0, 4.0];
+/// let batch = [
+///     1.0, 0.0, 0.0, 0.0,
+///     0.5, 0.5, 0.5, 0.5,
+///     1.0, 1.0, 1.0, 1.0,
+/// ];
+///
+/// let mut result = [0.0f32; 3];
+/// cfavml::dot_batch(&query, &batch, &mut result);
+/// assert_eq!(result, [1.0, 5.0, 10.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for n in range(len(result)):
+///     result[n] = dot(query, batch[n * dims:(n + 1) * dims])
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// If `batch` is not exactly `query.len() * result.len()` elements in length.
+pub fn dot_batch<T, B>(query: &[T], batch: &[T], result: &mut [B])
+where
+    T: DistanceOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    unwrap_size(try_dot_batch(query, batch, result))
+}
+
+#[inline]
+/// Calculates the squared Euclidean distance between the `query` vector and each vector
+/// within the contiguous `batch` of vectors, writing the distance of the `i`th vector
+/// to `result[i]`.
+///
+/// This is more efficient than calling [squared_euclidean] for each vector in the batch,
+/// as each load of the query is reused across several candidates at once.
+/// The results are identical to calling [squared_euclidean] on each vector.
+///
+/// ### Examples
+///
+/// The `batch` must contain `result.len()` vectors each of `query.len()` elements laid out
+/// one after the other.
+///
+/// ```rust
+/// let query = [1.0, 2.0, 3.0, 4.0];
+/// let batch = [
+///     1.0, 0.0, 0.0, 0.0,
+///     0.5, 0.5, 0.5, 0.5,
+///     1.0, 1.0, 1.0, 1.0,
+/// ];
+///
+/// let mut result = [0.0f32; 3];
+/// cfavml::squared_euclidean_batch(&query, &batch, &mut result);
+/// assert_eq!(result, [29.0, 21.0, 14.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for n in range(len(result)):
+///     result[n] = squared_euclidean(query, batch[n * dims:(n + 1) * dims])
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// If `batch` is not exactly `query.len() * result.len()` elements in length.
+pub fn squared_euclidean_batch<T, B>(query: &[T], batch: &[T], result: &mut [B])
+where
+    T: DistanceOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    unwrap_size(try_squared_euclidean_batch(query, batch, result))
+}
+
+#[inline]
+/// Calculates the dot product between every vector in `a` and every vector in `b`,
+/// writing the row-major `a_rows x b_rows` matrix of results to `result`.
+///
+/// This is considerably faster than calling [dot] for every pair of vectors, as the
+/// output is computed in register tiles where each load of a vector from `a` is reused
+/// against a block of vectors from `b` and vice versa.
+///
+/// ### Examples
+///
+/// `a` and `b` must contain `a_rows` and `b_rows` vectors respectively, each of `dims`
+/// elements laid out one after the other.
+///
+/// ```rust
+/// let a = [
+///     1.0, 2.0, 3.0,
+///     0.0, 1.0, 0.0,
+/// ];
+/// let b = [
+///     1.0, 0.0, 0.0,
+///     1.0, 1.0, 1.0,
+///     0.0, 0.0, 2.0,
+/// ];
+///
+/// let mut result = [0.0f32; 2 * 3];
+/// cfavml::dot_matrix(3, 2, 3, &a, &b, &mut result);
+/// assert_eq!(result, [
+///     1.0, 6.0, 6.0,
+///     0.0, 1.0, 0.0,
+/// ]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(a_rows):
+///     for j in range(b_rows):
+///         result[i * b_rows + j] = dot(a[i * dims:(i + 1) * dims], b[j * dims:(j + 1) * dims])
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// If `a` is not exactly `dims * a_rows` elements in length, `b` is not exactly
+/// `dims * b_rows` elements in length or `result` is not exactly `a_rows * b_rows`
+/// elements in length.
+pub fn dot_matrix<T>(
+    dims: usize,
+    a_rows: usize,
+    b_rows: usize,
+    a: &[T],
+    b: &[T],
+    result: &mut [T],
+) where
+    T: DistanceOps,
+{
+    unwrap_size(try_dot_matrix(dims, a_rows, b_rows, a, b, result))
+}
+
+#[inline]
+/// Calculates the squared Euclidean distance between every vector in `a` and every
+/// vector in `b`, writing the row-major `a_rows x b_rows` matrix of results to `result`.
+///
+/// The distances are computed via the `‖a‖² + ‖b‖² − 2a·b` decomposition, with the norm
+/// of each vector computed once and the dot products computed in the same register tiles
+/// as [dot_matrix]. For floats this means results can differ slightly from
+/// [squared_euclidean] and may be marginally negative for (almost) identical vectors,
+/// so clamp the output to zero if that matters for your use case.
+///
+/// ### Examples
+///
+/// `a` and `b` must contain `a_rows` and `b_rows` vectors respectively, each of `dims`
+/// elements laid out one after the other.
+///
+/// ```rust
+/// let a = [
+///     1.0, 2.0, 3.0,
+///     0.0, 1.0, 0.0,
+/// ];
+/// let b = [
+///     1.0, 0.0, 0.0,
+///     1.0, 1.0, 1.0,
+///     0.0, 0.0, 2.0,
+/// ];
+///
+/// let mut result = [0.0f32; 2 * 3];
+/// cfavml::squared_euclidean_matrix(3, 2, 3, &a, &b, &mut result);
+/// assert_eq!(result, [
+///     13.0, 5.0, 6.0,
+///     2.0, 2.0, 5.0,
+/// ]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(a_rows):
+///     for j in range(b_rows):
+///         result[i * b_rows + j] = squared_euclidean(a[i * dims:(i + 1) * dims], b[j * dims:(j + 1) * dims])
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// If `a` is not exactly `dims * a_rows` elements in length, `b` is not exactly
+/// `dims * b_rows` elements in length or `result` is not exactly `a_rows * b_rows`
+/// elements in length.
+pub fn squared_euclidean_matrix<T>(
+    dims: usize,
+    a_rows: usize,
+    b_rows: usize,
+    a: &[T],
+    b: &[T],
+    result: &mut [T],
+) where
+    T: DistanceOps,
+{
+    unwrap_size(try_squared_euclidean_matrix(
+        dims, a_rows, b_rows, a, b, result,
+    ))
+}
+
+#[inline]
+/// Calculates the dot product between every pair of vectors in `matrix`, writing the
+/// symmetric row-major `rows x rows` Gram matrix of results to `result`.
+///
+/// As the result is symmetric only the upper triangle is computed, using the same register
+/// tiles as [dot_matrix] and mirroring each value into the lower triangle. The rows are
+/// processed in blocks so the vectors being worked on stay in cache for large matrices.
+///
+/// ### Examples
+///
+/// `matrix` must contain `rows` vectors, each of `dims` elements laid out one after the other.
+///
+/// ```rust
+/// let matrix = [
+///     1.0, 2.0,
+///     0.0, 1.0,
+///     3.0, 0.0,
+/// ];
+///
+/// let mut result = [0.0f32; 3 * 3];
+/// cfavml::gram_matrix(3, 2, &matrix, &mut result);
+/// assert_eq!(result, [
+///     5.0, 2.0, 3.0,
+///     2.0, 1.0, 0.0,
+///     3.0, 0.0, 9.0,
+/// ]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(rows):
+///     for j in range(i, rows):
+///         value = dot(matrix[i * dims:(i + 1) * dims], matrix[j * dims:(j + 1) * dims])
+///         result[i * rows + j] = value
+///         result[j * rows + i] = value
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// If `matrix` is not exactly `rows * dims` elements in length or `result` is not
+/// exactly `rows * rows` elements in length.
+pub fn gram_matrix<T>(rows: usize, dims: usize, matrix: &[T], result: &mut [T])
+where
+    T: DistanceOps,
+{
+    unwrap_size(try_gram_matrix(rows, dims, matrix, result))
+}
+
+#[inline]
+/// Calculates the dot product between each of the `n_rows` rows of `matrix` and the
+/// `query` vector, writing the dot product of the `i`th row to `scores[i]`.
+///
+/// Several rows are processed at once, each with its own accumulators, so unlike
+/// [dot_batch] the results may differ slightly from calling [dot] on each row.
+///
+/// ### Examples
+///
+/// `matrix` must contain `n_rows` vectors, each of `dims` elements laid out one after
+/// the other.
+///
+/// ```rust
+/// let query = [1.0, 2.0, 3.0, 4.0];
+/// let matrix = [
+///     1.0, 0.0, 0.0, 0.0,
+///     0.5, 0.5, 0.5, 0.5,
+///     1.0, 1.0, 1.0, 1.0,
+/// ];
+///
+/// let mut scores = [0.0f32; 3];
+/// cfavml::batch_dot(3, 4, &matrix, &query, &mut scores);
+/// assert_eq!(scores, [1.0, 5.0, 10.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(n_rows):
+///     scores[i] = dot(matrix[i * dims:(i + 1) * dims], query)
+///
+/// return scores
+/// ```
+///
+/// ### Panics
+///
+/// If `matrix` is not exactly `n_rows * dims` elements in length, `query` is not
+/// exactly `dims` elements in length or `scores` is not exactly `n_rows` elements
+/// in length.
+pub fn batch_dot<T>(
+    n_rows: usize,
+    dims: usize,
+    matrix: &[T],
+    query: &[T],
+    scores: &mut [T],
+) where
+    T: DistanceOps,
+{
+    unwrap_size(try_batch_dot(n_rows, dims, matrix, query, scores))
+}
+
+#[inline]
+/// Calculates the squared Euclidean distance between every pair of the `n` vectors in
+/// `matrix`, writing the distances to the upper triangle of the row-major `n x n`
+/// `result` matrix.
+///
+/// Each vector is compared against all of the vectors following it, the diagonal and
+/// lower triangle of `result` are left untouched.
+///
+/// ### Examples
+///
+/// `matrix` must contain `n` vectors, each of `dims` elements laid out one after the
+/// other.
+///
+/// ```rust
+/// let matrix = [
+///     1.0, 2.0,
+///     0.0, 1.0,
+///     3.0, 0.0,
+/// ];
+///
+/// let mut result = [0.0f32; 3 * 3];
+/// cfavml::pairwise_distances(3, 2, &matrix, &mut result);
+/// assert_eq!(result, [
+///     0.0, 2.0, 8.0,
+///     0.0, 0.0, 10.0,
+///     0.0, 0.0, 0.0,
+/// ]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(n):
+///     for j in range(i + 1, n):
+///         diff = matrix[i * dims:(i + 1) * dims] - matrix[j * dims:(j + 1) * dims]
+///         result[i * n + j] = sum(diff ** 2)
+///
+/// return result
+/// ```
+///
+/// ### Panics
+///
+/// If `matrix` is not exactly `n * dims` elements in length or `result` is not
+/// exactly `n * n` elements in length.
+pub fn pairwise_distances<T>(n: usize, dims: usize, matrix: &[T], result: &mut [T])
+where
+    T: DistanceOps,
+{
+    unwrap_size(try_pairwise_distances(n, dims, matrix, result))
+}
+
+#[inline]
+/// Performs a horizontal sum of all elements in a returning the result.
+///
+/// ### Examples
+///
+/// We can create a single vector and calculate the squared L2 norm.
+/// Any type that implements `AsRef<[A]>` can be provided, where `A` is any type from:
+///
+/// > `f32`, `f64`, `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
+///
+/// It is worth noting however, the compiler can often match the speed of this particular
+/// routine if your operations are as simple as `my_vector.iter().sum()`.
+///
+/// ```rust
+/// let a = vec![1.0, 0.3, 0.2, 0.4, 0.2, 0.1, 0.3, 0.2];
+///
+/// let total = cfavml::sum(&a);
+/// assert_eq!(total, 2.7);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = 0
+///
+/// for i in range(dims):
+///     result += a[i]
+///
+/// return result
+/// ```
+pub fn sum<T, B1>(a: B1) -> T
+where
+    T: AggOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::sum(a)
+}
+
+#[inline]
+/// Finds the horizontal max element of a given vector and returns the result.
+///
+/// ### Default Value Warning
+///
+/// Beware of the default value returned when passing a zero-length array (which is technically allowed)
+///
+/// If you are using `f32` or `f64` types, this becomes `T::NEG_INFINITY` otherwise the
+/// default max value is `T:MIN`.
+///
+/// ### Examples
+///
+/// Any vector can be created and have the maximum value found within using the `max` operation, but
+/// be aware of the default value handling if you pass an empty array
+/// (see the "Default Value Warning" section for more.)
+///
+/// ```rust
+/// let a = vec![1.0, 0.3, 0.2, 0.4, 0.2, 0.1, 0.3, 0.2];
+///
+/// let total = cfavml::max(&a);
+/// assert_eq!(total, 1.0);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = -inf
+///
+/// for i in range(dims):
+///     result = max(result, a[i])
+///
+/// return result
+/// ```
+pub fn max<T, B1>(a: B1) -> T
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::max(a)
+}
+
+#[inline]
+/// Takes the element wise max of vectors `a` and `b` of size `dims` and stores the result
+/// in `result` of size `dims`.
+///
+/// ### Things To Know
+///
+/// ###### Supported patterns
+///
+/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
+/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
+/// read from before executing. For the most part, you don't need to worry about this outside of
+/// knowing you can by default, pass one combination of:
+///     
+/// - `lhs: vector` and `rhs: vector`  
+/// - `lhs: vector` and `rhs: broadcast value`  
+/// - `lhs: broadcast value` and `rhs: vector`  
+/// - `lhs: broadcast value` and `rhs: broadcast value`  
+///   * Not really that useful and is just an artefact of the memory management system.
+///
+/// ###### Broadcast values
+///
+/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
+/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
+/// outside the result buffer itself.
+///
+/// This means the following is possible:
+/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `1 + 1 == [1]`  w/result_buffer_len=1
+///
+/// ###### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+/// enables projecting of the input buffer to new sizes providing the new size is a
+/// multiple of the original size. When this buffer is projected, it is effectively
+/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+///
+/// ### Examples
+///
+/// ##### Two vectors
+///
+/// ```rust
+/// let lhs = [1.0, 1.0, 1.0, 1.0];
+/// let rhs = [2.0, 2.5, 1.0, -2.0];
+///
+/// let mut result = [0.0; 4];
+/// cfavml::max_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [2.0, 2.5, 1.0, 1.0]);
+/// ```
+///
+/// ##### One vector & broadcast value
+///
+/// ```rust
+/// let lhs = [2.0, 3.0, -1.0, -0.5];
+///
+/// let mut result = [0.0; 4];
+/// cfavml::max_vertical(&lhs, 0.0, &mut result);
+/// assert_eq!(result, [2.0, 3.0, 0.0, 0.0]);
+/// ```
+///
+/// ##### Two broadcast values
+///
+/// ```rust
+/// let mut result = [0.0; 4];
+/// cfavml::max_vertical(1.0, 0.0, &mut result);
+/// assert_eq!(result, [1.0; 4]);
+/// ```
+///
+/// ##### With projected vectors
+///
+/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
+/// vectors than the original inputs, which can allow for much better performance and
+/// memory usage when working with matrices.
+///
+/// NOTE:
+/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
+/// how it behaves, it does not replace your matrix library's own broadcasting system.
+///
+/// ```rust
+/// use cfavml::mem_loader::Projected;
+///
+/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
+/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+///
+/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
+/// cfavml::max_vertical(Projected(&lhs), Projected(&rhs), &mut result);
+/// assert_eq!(result, [1.0, 2.5, 1.0, 3.5]);
+/// ```
+///
+/// ##### With `MaybeUninit`
+///
+/// Often if you are working with new-allocations, you do not want to initialize the data twice,
+/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
+/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+///
+/// let lhs = [1.0, 1.0, 1.0, 1.0];
+/// let rhs = [2.0, 2.5, 1.0, -2.0];
+///
+/// let mut result = Vec::with_capacity(4);
+/// unsafe { result.set_len(4) };
+/// cfavml::max_vertical(&lhs, &rhs, &mut result);
+///
+/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
+/// assert_eq!(result, [2.0, 2.5, 1.0, 1.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = [0; dims]
+///
+/// for i in range(dims):
+///     result[i] = max(a[i], b[i])
+///
+/// return result
+/// ```
+///
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn max_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::max_vertical(lhs, rhs, result)
+}
+
+#[inline]
+/// Finds the horizontal min element of a given vector and returns the result.
+///
+/// ### Default Value Warning
+///
+/// Beware of the default value returned when passing a zero-length array (which is technically allowed)
+///
+/// If you are using `f32` or `f64` types, this becomes `T::NEG_INFINITY` otherwise the
+/// default max value is `T:MIN`.
+///
+/// ### Examples
+///
+/// Any vector can be created and have the minimum value found within using the `min` operation, but
+/// be aware of the default value handling if you pass an empty array
+/// (see the "Default Value Warning" section for more.)
+///
+/// ```rust
+/// let a = vec![1.0, 0.3, 0.2, 0.4, 0.2, 0.1, 0.3, 0.2];
+///
+/// let total = cfavml::min(&a);
+/// assert_eq!(total, 0.1);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = inf
+///
+/// for i in range(dims):
+///     result = min(result, a[i])
+///
+/// return result
+/// ```
+pub fn min<T, B1>(a: B1) -> T
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::min(a)
+}
+
+#[inline]
+/// Takes the element wise min of vectors `a` and `b` of size `dims` and stores the result
+/// in `result` of size `dims`.
+///
+/// ### Things To Know
+///
+/// ###### Supported patterns
+///
+/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
+/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
+/// read from before executing. For the most part, you don't need to worry about this outside of
+/// knowing you can by default, pass one combination of:
+///     
+/// - `lhs: vector` and `rhs: vector`  
+/// - `lhs: vector` and `rhs: broadcast value`  
+/// - `lhs: broadcast value` and `rhs: vector`  
+/// - `lhs: broadcast value` and `rhs: broadcast value`  
+///   * Not really that useful and is just an artefact of the memory management system.
+///
+/// ###### Broadcast values
+///
+/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
+/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
+/// outside the result buffer itself.
+///
+/// This means the following is possible:
+/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `1 + 1 == [1]`  w/result_buffer_len=1
+///
+/// ###### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+/// enables projecting of the input buffer to new sizes providing the new size is a
+/// multiple of the original size. When this buffer is projected, it is effectively
+/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+///
+/// ### Examples
+///
+/// ##### Two vectors
+///
+/// ```rust
+/// let lhs = [1.0, 1.0, 1.0, 1.0];
+/// let rhs = [2.0, 2.5, 1.0, -2.0];
+///
+/// let mut result = [0.0; 4];
+/// cfavml::min_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [1.0, 1.0, 1.0, -2.0]);
+/// ```
+///
+/// ##### One vector & broadcast value
+///
+/// ```rust
+/// let lhs = [2.0, 3.0, -1.0, -0.5];
+///
+/// let mut result = [0.0; 4];
+/// cfavml::min_vertical(&lhs, 0.0, &mut result);
+/// assert_eq!(result, [0.0, 0.0, -1.0, -0.5]);
+/// ```
+///
+/// ##### Two broadcast values
+///
+/// ```rust
+/// let mut result = [0.0; 4];
+/// cfavml::min_vertical(1.0, -5.0, &mut result);
+/// assert_eq!(result, [-5.0; 4]);
+/// ```
+///
+/// ##### With projected vectors
+///
+/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
+/// vectors than the original inputs, which can allow for much better performance and
+/// memory usage when working with matrices.
+///
+/// NOTE:
+/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
+/// how it behaves, it does not replace your matrix library's own broadcasting system.
+///
+/// ```rust
+/// use cfavml::mem_loader::Projected;
+///
+/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
+/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+///
+/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
+/// cfavml::min_vertical(Projected(&lhs), Projected(&rhs), &mut result);
+/// assert_eq!(result, [1.0, 1.0, 0.5, 2.5]);
+/// ```
+///
+/// ##### With `MaybeUninit`
+///
+/// Often if you are working with new-allocations, you do not want to initialize the data twice,
+/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
+/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+///
+/// let lhs = [1.0, -1.0, 0.5, 1.0];
+/// let rhs = [2.0, 2.5, 1.0, -2.0];
+///
+/// let mut result = Vec::with_capacity(4);
+/// unsafe { result.set_len(4) };
+/// cfavml::min_vertical(&lhs, &rhs, &mut result);
+///
+/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
+/// assert_eq!(result, [1.0, -1.0, 0.5, -2.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = [0; dims]
+///
+/// for i in range(dims):
+///     result[i] = min(a[i], b[i])
+///
+/// return result
+/// ```
+///
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn min_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::min_vertical(lhs, rhs, result)
+}
+
+#[inline]
+/// Computes the running max of vector `a`, writing the max of elements `a[0..=i]`
+/// to `result[i]`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = [1.0, 3.0, 2.0, 5.0, 4.0];
+///
+/// let mut result = [0.0f32; 5];
+/// cfavml::cumulative_max(&a, &mut result);
+/// assert_eq!(result, [1.0, 3.0, 3.0, 5.0, 5.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = [0; dims]
+/// running = -inf
+///
+/// for i in range(dims):
+///     running = max(running, a[i])
+///     result[i] = running
+///
+/// return result
+/// ```
+///
+/// ### Result buffer
+///
+/// The result buffer can be either an initialized slice i.e. `&mut [T]`
+/// or it can be a slice holding potentially uninitialized data i.e. `&mut [MaybeUninit<T>]`.
+///
+/// Once the operation is complete, it is safe to assume the data written is fully initialized.
+///
+/// # Panics
+///
+/// If vectors `a` and `result` are not equal in length.
+pub fn cumulative_max<T, B>(a: &[T], result: &mut [B])
+where
+    T: CmpOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    unwrap_size(try_cumulative_max(a, result))
+}
+
+#[inline]
+/// Computes the running min of vector `a`, writing the min of elements `a[0..=i]`
+/// to `result[i]`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = [4.0, 5.0, 2.0, 3.0, 1.0];
+///
+/// let mut result = [0.0f32; 5];
+/// cfavml::cumulative_min(&a, &mut result);
+/// assert_eq!(result, [4.0, 4.0, 2.0, 2.0, 1.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = [0; dims]
+/// running = inf
+///
+/// for i in range(dims):
+///     running = min(running, a[i])
+///     result[i] = running
+///
+/// return result
+/// ```
+///
+/// ### Result buffer
+///
+/// The result buffer can be either an initialized slice i.e. `&mut [T]`
+/// or it can be a slice holding potentially uninitialized data i.e. `&mut [MaybeUninit<T>]`.
+///
+/// Once the operation is complete, it is safe to assume the data written is fully initialized.
+///
+/// # Panics
+///
+/// If vectors `a` and `result` are not equal in length.
+pub fn cumulative_min<T, B>(a: &[T], result: &mut [B])
+where
+    T: CmpOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    unwrap_size(try_cumulative_min(a, result))
+}
+
+#[inline]
+/// Checks each element pair of elements from vectors `a` and `b` comparing if
+/// element `a` is **_equal to_** element `b`, storing the output as `1` (true) or `0` (false)
+/// in `result`.
+///
+/// ### Things To Know
+///
+/// ###### Supported patterns
+///
+/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
+/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
+/// read from before executing. For the most part, you don't need to worry about this outside of
+/// knowing you can by default, pass one combination of:
+///     
+/// - `lhs: vector` and `rhs: vector`  
+/// - `lhs: vector` and `rhs: broadcast value`  
+/// - `lhs: broadcast value` and `rhs: vector`  
+/// - `lhs: broadcast value` and `rhs: broadcast value`  
+///   * Not really that useful and is just an artefact of the memory management system.
+///
+/// ###### Broadcast values
+///
+/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
+/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
+/// outside the result buffer itself.
+///
+/// This means the following is possible:
+/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `1 + 1 == [1]`  w/result_buffer_len=1
+///
+/// ###### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+/// enables projecting of the input buffer to new sizes providing the new size is a
+/// multiple of the original size. When this buffer is projected, it is effectively
+/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+///
+/// ###### Masks
+///
+/// CFAVML follows the same pattern as numpy, which is it representing boolean results as
+/// either `1` or `0` in the respective type. This allows you to do various bit manipulation
+/// and arithmetic techniques for processing values within the vector.
+///
+/// ### Examples
+///
+/// ##### Two vectors
+///
+/// ```rust
+/// let lhs = [1.0, 2.3, 2.0, 1.0];
+/// let rhs = [2.0, 0.7, 2.0, -2.0];
+///
+/// let mut mask = [0.0f32; 4];
+/// cfavml::eq_vertical(&lhs, &rhs, &mut mask);
+/// assert_eq!(mask, [0.0, 0.0, 1.0, 0.0]);   // Value at index 2 as equal!
+///
+/// // Now we can use it to zero any values that don't match.
+/// let mut match_or_zeroes = [0.0f32; 4];
+/// cfavml::mul_vertical(&lhs, &mask, &mut match_or_zeroes);
+///
+/// // Our original match is extracted and the rest are `0.0`
+/// // For convenience, I've used `0.0` as the non-match value,
+/// // but if you switch `mask` and `lhs` around you can get
+/// // a `NaN` mask which may be more useful depending on application.
+/// assert_eq!(match_or_zeroes, [0.0, 0.0, 2.0, 0.0]);    
+/// ```
+///
+/// ##### One vector & broadcast value
+///
+/// ```rust
+/// let lhs = [2.0, f32::NAN, -1.0, -0.5];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::eq_vertical(&lhs, -0.5, &mut result);
+/// assert_eq!(result, [0.0, 0.0, 0.0, 1.0]);  // NaN is always false on eq checks.
+/// ```
+///
+/// ##### Two broadcast values
+///
+/// ```rust
+/// let mut result = [0.0f32; 4];
+/// cfavml::eq_vertical(1.0, -5.0, &mut result);
+/// assert_eq!(result, [0.0; 4]);
+/// ```
+///
+/// ##### With projected vectors
+///
+/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
+/// vectors than the original inputs, which can allow for much better performance and
+/// memory usage when working with matrices.
+///
+/// NOTE:
+/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
+/// how it behaves, it does not replace your matrix library's own broadcasting system.
+///
+/// ```rust
+/// use cfavml::mem_loader::Projected;
+///
+/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
+/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+///
+/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
+/// cfavml::eq_vertical(Projected(&lhs), Projected(&rhs), &mut result);
+/// assert_eq!(result, [1.0, 0.0, 0.0, 0.0]);
+/// ```
+///
+/// ##### With `MaybeUninit`
+///
+/// Often if you are working with new-allocations, you do not want to initialize the data twice,
+/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
+/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+///
+/// let lhs = [1.0, -1.0, 0.5, 1.0];
+/// let rhs = [1.0, 2.5, 0.5, -2.0];
+///
+/// let mut result = Vec::with_capacity(4);
+/// unsafe { result.set_len(4) };
+/// cfavml::eq_vertical(&lhs, &rhs, &mut result);
+///
+/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
+/// assert_eq!(result, [1.0, 0.0, 1.0, 0.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// mask = [0; dims]
+///
+/// for i in range(dims):
+///     mask[i] = a[i] == b[i] ? 1 : 0
+///
+/// return mask
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 == 0.0 -> true`
+/// - `0.0 == NaN -> false`
+/// - `NaN == NaN -> false`
+///
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn eq_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::eq_vertical(lhs, rhs, result)
+}
+
+#[inline]
+/// Checks each element pair of elements from vectors `a` and `b` comparing if
+/// element `a` is **_not equal to_** element `b`, storing the output as `1` (true)
+/// or `0` (false) in `result`.
+///
+/// ### Things To Know
+///
+/// ###### Supported patterns
+///
+/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
+/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
+/// read from before executing. For the most part, you don't need to worry about this outside of
+/// knowing you can by default, pass one combination of:
+///     
+/// - `lhs: vector` and `rhs: vector`  
+/// - `lhs: vector` and `rhs: broadcast value`  
+/// - `lhs: broadcast value` and `rhs: vector`  
+/// - `lhs: broadcast value` and `rhs: broadcast value`  
+///   * Not really that useful and is just an artefact of the memory management system.
+///
+/// ###### Broadcast values
+///
+/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
+/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
+/// outside the result buffer itself.
+///
+/// This means the following is possible:
+/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `1 + 1 == [1]`  w/result_buffer_len=1
+///
+/// ###### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+/// enables projecting of the input buffer to new sizes providing the new size is a
+/// multiple of the original size. When this buffer is projected, it is effectively
+/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+///
+/// ###### Masks
+///
+/// CFAVML follows the same pattern as numpy, which is it representing boolean results as
+/// either `1` or `0` in the respective type. This allows you to do various bit manipulation
+/// and arithmetic techniques for processing values within the vector.
+///
+/// ### Examples
+///
+/// ##### Two vectors
+///
+/// ```rust
+/// let lhs = [1.0, 2.3, 2.0, 1.0];
+/// let rhs = [2.0, 0.7, 2.0, -2.0];
+///
+/// let mut mask = [0.0f32; 4];
+/// cfavml::neq_vertical(&lhs, &rhs, &mut mask);
+/// assert_eq!(mask, [1.0, 1.0, 0.0, 1.0]);   // All values except index 2 are not equal!
+///
+/// // Now we can use it to zero any values that are equal
+/// let mut match_or_zeroes = [0.0f32; 4];
+/// cfavml::mul_vertical(&lhs, &mask, &mut match_or_zeroes);
+///
+/// // Our original match is extracted and the rest are `0.0`
+/// // For convenience, I've used `0.0` as the non-match value,
+/// // but if you switch `mask` and `lhs` around you can get
+/// // a `NaN` mask which may be more useful depending on application.
+/// assert_eq!(match_or_zeroes, [1.0, 2.3, 0.0, 1.0]);    
+/// ```
+///
+/// ##### One vector & broadcast value
+///
+/// ```rust
+/// let lhs = [2.0, f32::NAN, -1.0, -0.5];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::neq_vertical(&lhs, -0.5, &mut result);
+/// assert_eq!(result, [1.0, 1.0, 1.0, 0.0]);  // NaN is always false on eq checks.
+/// ```
+///
+/// ##### Two broadcast values
+///
+/// ```rust
+/// let mut result = [0.0f32; 4];
+/// cfavml::neq_vertical(1.0, -5.0, &mut result);
+/// assert_eq!(result, [1.0; 4]);
+/// ```
+///
+/// ##### With projected vectors
+///
+/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
+/// vectors than the original inputs, which can allow for much better performance and
+/// memory usage when working with matrices.
+///
+/// NOTE:
+/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
+/// how it behaves, it does not replace your matrix library's own broadcasting system.
+///
+/// ```rust
+/// use cfavml::mem_loader::Projected;
+///
+/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
+/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+///
+/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
+/// cfavml::neq_vertical(Projected(&lhs), Projected(&rhs), &mut result);
+/// assert_eq!(result, [0.0, 1.0, 1.0, 1.0]);
+/// ```
+///
+/// ##### With `MaybeUninit`
+///
+/// Often if you are working with new-allocations, you do not want to initialize the data twice,
+/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
+/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+///
+/// let lhs = [1.0, -1.0, 0.5, 1.0];
+/// let rhs = [1.0, 2.5, 0.5, -2.0];
+///
+/// let mut result = Vec::with_capacity(4);
+/// unsafe { result.set_len(4) };
+/// cfavml::neq_vertical(&lhs, &rhs, &mut result);
+///
+/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
+/// assert_eq!(result, [0.0, 1.0, 0.0, 1.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// mask = [0; dims]
+///
+/// for i in range(dims):
+///     mask[i] = a[i] != b[i] ? 1 : 0
+///
+/// return mask
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 != 1.0 -> true`
+/// - `0.0 != NaN -> true`
+/// - `NaN != NaN -> true`
+///
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn neq_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::neq_vertical(lhs, rhs, result)
+}
+
+#[inline]
+/// Checks each element pair of elements from vectors `a` and `b` comparing if
+/// element `a` is **_less than_** element `b`, storing the output as `1` (true)
+/// or `0` (false) in `result`.
+///
+/// ### Things To Know
+///
+/// ###### Supported patterns
+///
+/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
+/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
+/// read from before executing. For the most part, you don't need to worry about this outside of
+/// knowing you can by default, pass one combination of:
+///     
+/// - `lhs: vector` and `rhs: vector`  
+/// - `lhs: vector` and `rhs: broadcast value`  
+/// - `lhs: broadcast value` and `rhs: vector`  
+/// - `lhs: broadcast value` and `rhs: broadcast value`  
+///   * Not really that useful and is just an artefact of the memory management system.
+///
+/// ###### Broadcast values
+///
+/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
+/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
+/// outside the result buffer itself.
+///
+/// This means the following is possible:
+/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `1 + 1 == [1]`  w/result_buffer_len=1
+///
+/// ###### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+/// enables projecting of the input buffer to new sizes providing the new size is a
+/// multiple of the original size. When this buffer is projected, it is effectively
+/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+///
+/// ###### Masks
+///
+/// CFAVML follows the same pattern as numpy, which is it representing boolean results as
+/// either `1` or `0` in the respective type. This allows you to do various bit manipulation
+/// and arithmetic techniques for processing values within the vector.
+///
+/// ### Examples
+///
+/// ##### Two vectors
+///
+/// ```rust
+/// let lhs = [-1.0, 2.3, 2.0, 1.0];
+/// let rhs = [2.0, 0.7, 2.0, -2.0];
+///
+/// let mut mask = [0.0f32; 4];
+/// cfavml::lt_vertical(&lhs, &rhs, &mut mask);
+/// assert_eq!(mask, [1.0, 0.0, 0.0, 0.0]);  // Only index 0 of lhs is less than the rhs.
+///
+/// // Now we can use it to zero any values that greater than or equal.
+/// let mut match_or_zeroes = [0.0f32; 4];
+/// cfavml::mul_vertical(&lhs, &mask, &mut match_or_zeroes);
+///
+/// // Our original match is extracted and the rest are `0.0`
+/// // For convenience, I've used `0.0` as the non-match value,
+/// // but if you switch `mask` and `lhs` around you can get
+/// // a `NaN` mask which may be more useful depending on application.
+/// assert_eq!(match_or_zeroes, [-1.0, 0.0, 0.0, 0.0]);    
+/// ```
+///
+/// ##### One vector & broadcast value
+///
+/// ```rust
+/// let lhs = [2.0, f32::NAN, -1.0, -0.5];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::lt_vertical(&lhs, -0.5, &mut result);
+/// assert_eq!(result, [0.0, 0.0, 1.0, 0.0]);  // NaN is always false on lt checks.
+/// ```
+///
+/// ##### Two broadcast values
+///
+/// ```rust
+/// let mut result = [0.0f32; 4];
+/// cfavml::lt_vertical(-1.0, 5.0, &mut result);
+/// assert_eq!(result, [1.0; 4]);
+/// ```
+///
+/// ##### With projected vectors
+///
+/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
+/// vectors than the original inputs, which can allow for much better performance and
+/// memory usage when working with matrices.
+///
+/// NOTE:
+/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
+/// how it behaves, it does not replace your matrix library's own broadcasting system.
+///
+/// ```rust
+/// use cfavml::mem_loader::Projected;
+///
+/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
+/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+///
+/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
+/// cfavml::lt_vertical(Projected(&lhs), Projected(&rhs), &mut result);
+/// assert_eq!(result, [0.0, 1.0, 1.0, 0.0]);
+/// ```
+///
+/// ##### With `MaybeUninit`
+///
+/// Often if you are working with new-allocations, you do not want to initialize the data twice,
+/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
+/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+///
+/// let lhs = [1.0, -1.0, 0.5, 1.0];
+/// let rhs = [1.0, 2.5, 0.5, -2.0];
+///
+/// let mut result = Vec::with_capacity(4);
+/// unsafe { result.set_len(4) };
+/// cfavml::lt_vertical(&lhs, &rhs, &mut result);
+///
+/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
+/// assert_eq!(result, [0.0, 1.0, 0.0, 0.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// mask = [0; dims]
+///
+/// for i in range(dims):
+///     mask[i] = a[i] < b[i] ? 1 : 0
+///
+/// return mask
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 < 1.0 -> true`
+/// - `0.0 < NaN -> false`
+/// - `NaN < 1.0 -> false`
+/// - `NaN < NaN -> false`
+///
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn lt_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::lt_vertical(lhs, rhs, result)
+}
+
+#[inline]
+/// Checks each element pair of elements from vectors `a` and `b` comparing if
+/// element `a` is **_less than or equal to_** element `b`, storing the output as `1` (true)
+/// or `0` (false) in `result`.
+///
+/// ### Things To Know
+///
+/// ###### Supported patterns
+///
+/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
+/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
+/// read from before executing. For the most part, you don't need to worry about this outside of
+/// knowing you can by default, pass one combination of:
+///     
+/// - `lhs: vector` and `rhs: vector`  
+/// - `lhs: vector` and `rhs: broadcast value`  
+/// - `lhs: broadcast value` and `rhs: vector`  
+/// - `lhs: broadcast value` and `rhs: broadcast value`  
+///   * Not really that useful and is just an artefact of the memory management system.
+///
+/// ###### Broadcast values
+///
+/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
+/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
+/// outside the result buffer itself.
+///
+/// This means the following is possible:
+/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `1 + 1 == [1]`  w/result_buffer_len=1
+///
+/// ###### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+/// enables projecting of the input buffer to new sizes providing the new size is a
+/// multiple of the original size. When this buffer is projected, it is effectively
+/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+///
+/// ###### Masks
+///
+/// CFAVML follows the same pattern as numpy, which is it representing boolean results as
+/// either `1` or `0` in the respective type. This allows you to do various bit manipulation
+/// and arithmetic techniques for processing values within the vector.
+///
+/// ### Examples
+///
+/// ##### Two vectors
+///
+/// ```rust
+/// let lhs = [-1.0, 2.3, 2.0, 1.0];
+/// let rhs = [2.0, 0.7, 2.0, -2.0];
+///
+/// let mut mask = [0.0f32; 4];
+/// cfavml::lte_vertical(&lhs, &rhs, &mut mask);
+/// assert_eq!(mask, [1.0, 0.0, 1.0, 0.0]);  // Index 0 & 2 are lte to rhs.
+///
+/// // Now we can use it to zero any values that are greater.
+/// let mut match_or_zeroes = [0.0f32; 4];
+/// cfavml::mul_vertical(&lhs, &mask, &mut match_or_zeroes);
+///
+/// // Our original match is extracted and the rest are `0.0`
+/// // For convenience, I've used `0.0` as the non-match value,
+/// // but if you switch `mask` and `lhs` around you can get
+/// // a `NaN` mask which may be more useful depending on application.
+/// assert_eq!(match_or_zeroes, [-1.0, 0.0, 2.0, 0.0]);    
+/// ```
+///
+/// ##### One vector & broadcast value
+///
+/// ```rust
+/// let lhs = [2.0, f32::NAN, -1.0, -0.5];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::lte_vertical(&lhs, -0.5, &mut result);
+/// assert_eq!(result, [0.0, 0.0, 1.0, 1.0]);  // NaN is always false on lte checks.
+/// ```
+///
+/// ##### Two broadcast values
+///
+/// ```rust
+/// let mut result = [0.0f32; 4];
+/// cfavml::lte_vertical(5.0, 5.0, &mut result);
+/// assert_eq!(result, [1.0; 4]);
+/// ```
+///
+/// ##### With projected vectors
+///
+/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
+/// vectors than the original inputs, which can allow for much better performance and
+/// memory usage when working with matrices.
+///
+/// NOTE:
+/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
+/// how it behaves, it does not replace your matrix library's own broadcasting system.
+///
+/// ```rust
+/// use cfavml::mem_loader::Projected;
+///
+/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
+/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+///
+/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
+/// cfavml::lte_vertical(Projected(&lhs), Projected(&rhs), &mut result);
+/// assert_eq!(result, [1.0, 1.0, 1.0, 0.0]);
+/// ```
+///
+/// ##### With `MaybeUninit`
+///
+/// Often if you are working with new-allocations, you do not want to initialize the data twice,
+/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
+/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+///
+/// let lhs = [1.0, -1.0, 0.5, 1.0];
+/// let rhs = [1.0, 2.5, 0.5, -2.0];
+///
+/// let mut result = Vec::with_capacity(4);
+/// unsafe { result.set_len(4) };
+/// cfavml::lte_vertical(&lhs, &rhs, &mut result);
+///
+/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
+/// assert_eq!(result, [1.0, 1.0, 1.0, 0.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// mask = [0; dims]
+///
+/// for i in range(dims):
+///     mask[i] = a[i] <= b[i] ? 1 : 0
+///
+/// return mask
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 <= 1.0 -> true`
+/// - `1.0 <= 1.0 -> true`
+/// - `0.0 <= NaN -> false`
+/// - `NaN <= 1.0 -> false`
+/// - `NaN <= NaN -> false`
+///
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn lte_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::lte_vertical(lhs, rhs, result)
+}
+
+#[inline]
+/// Checks each element pair of elements from vectors `a` and `b` comparing if
+/// element `a` is **_greater than_** element `b`, storing the output as `1` (true) or `0` (false)
+/// in `result`.
+///
+/// ### Things To Know
+///
+/// ###### Supported patterns
+///
+/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
+/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
+/// read from before executing. For the most part, you don't need to worry about this outside of
+/// knowing you can by default, pass one combination of:
+///     
+/// - `lhs: vector` and `rhs: vector`  
+/// - `lhs: vector` and `rhs: broadcast value`  
+/// - `lhs: broadcast value` and `rhs: vector`  
+/// - `lhs: broadcast value` and `rhs: broadcast value`  
+///   * Not really that useful and is just an artefact of the memory management system.
+///
+/// ###### Broadcast values
+///
+/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
+/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
+/// outside the result buffer itself.
+///
+/// This means the following is possible:
+/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `1 + 1 == [1]`  w/result_buffer_len=1
+///
+/// ###### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+/// enables projecting of the input buffer to new sizes providing the new size is a
+/// multiple of the original size. When this buffer is projected, it is effectively
+/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+///
+/// ###### Masks
+///
+/// CFAVML follows the same pattern as numpy, which is it representing boolean results as
+/// either `1` or `0` in the respective type. This allows you to do various bit manipulation
+/// and arithmetic techniques for processing values within the vector.
+///
+/// ### Examples
+///
+/// ##### Two vectors
+///
+/// ```rust
+/// let lhs = [-1.0, 2.3, 2.0, 1.0];
+/// let rhs = [2.0, 0.7, 2.0, -2.0];
+///
+/// let mut mask = [0.0f32; 4];
+/// cfavml::gt_vertical(&lhs, &rhs, &mut mask);
+/// assert_eq!(mask, [0.0, 1.0, 0.0, 1.0]);  // Index 1 & 3 are greater than the rhs.
+///
+/// // Now we can use it to zero any values that are less than or equal.
+/// let mut match_or_zeroes = [0.0f32; 4];
+/// cfavml::mul_vertical(&lhs, &mask, &mut match_or_zeroes);
+///
+/// // Our original match is extracted and the rest are `0.0`
+/// // For convenience, I've used `0.0` as the non-match value,
+/// // but if you switch `mask` and `lhs` around you can get
+/// // a `NaN` mask which may be more useful depending on application.
+/// assert_eq!(match_or_zeroes, [0.0, 2.3, 0.0, 1.0]);    
+/// ```
+///
+/// ##### One vector & broadcast value
+///
+/// ```rust
+/// let lhs = [2.0, f32::NAN, -1.0, -0.5];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::gt_vertical(&lhs, -0.5, &mut result);
+/// assert_eq!(result, [1.0, 0.0, 0.0, 0.0]);  // NaN is always false on gt checks.
+/// ```
+///
+/// ##### Two broadcast values
+///
+/// ```rust
+/// let mut result = [0.0f32; 4];
+/// cfavml::gt_vertical(6.0, 5.0, &mut result);
+/// assert_eq!(result, [1.0; 4]);
+/// ```
+///
+/// ##### With projected vectors
+///
+/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
+/// vectors than the original inputs, which can allow for much better performance and
+/// memory usage when working with matrices.
+///
+/// NOTE:
+/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
+/// how it behaves, it does not replace your matrix library's own broadcasting system.
+///
+/// ```rust
+/// use cfavml::mem_loader::Projected;
+///
+/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
+/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+///
+/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
+/// cfavml::gt_vertical(Projected(&lhs), Projected(&rhs), &mut result);
+/// assert_eq!(result, [0.0, 0.0, 0.0, 1.0]);
+/// ```
+///
+/// ##### With `MaybeUninit`
+///
+/// Often if you are working with new-allocations, you do not want to initialize the data twice,
+/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
+/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+///
+/// let lhs = [1.0, -1.0, 0.5, 1.0];
+/// let rhs = [1.0, 2.5, 0.5, -2.0];
+///
+/// let mut result = Vec::with_capacity(4);
+/// unsafe { result.set_len(4) };
+/// cfavml::gt_vertical(&lhs, &rhs, &mut result);
+///
+/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
+/// assert_eq!(result, [0.0, 0.0, 0.0, 1.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// mask = [0; dims]
+///
+/// for i in range(dims):
+///     mask[i] = a[i] > b[i] ? 1 : 0
+///
+/// return mask
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 > 0.0 -> true`
+/// - `1.0 > NaN -> false`
+/// - `NaN > 1.0 -> false`
+/// - `NaN > NaN -> false`
+///
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn gt_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::gt_vertical(lhs, rhs, result)
+}
+
+#[inline]
+/// Checks each element pair of elements from vectors `a` and `b` comparing if
+/// element `a` is **_greater than or equal to_** element `b`, storing the output as `1` (true)
+/// or `0` (false) in `result`.
+///
+/// ### Things To Know
+///
+/// ###### Supported patterns
+///
+/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
+/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
+/// read from before executing. For the most part, you don't need to worry about this outside of
+/// knowing you can by default, pass one combination of:
+///     
+/// - `lhs: vector` and `rhs: vector`  
+/// - `lhs: vector` and `rhs: broadcast value`  
+/// - `lhs: broadcast value` and `rhs: vector`  
+/// - `lhs: broadcast value` and `rhs: broadcast value`  
+///   * Not really that useful and is just an artefact of the memory management system.
+///
+/// ###### Broadcast values
+///
+/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
+/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
+/// outside the result buffer itself.
+///
+/// This means the following is possible:
+/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `1 + 1 == [1]`  w/result_buffer_len=1
+///
+/// ###### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+/// enables projecting of the input buffer to new sizes providing the new size is a
+/// multiple of the original size. When this buffer is projected, it is effectively
+/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+///
+/// ###### Masks
+///
+/// CFAVML follows the same pattern as numpy, which is it representing boolean results as
+/// either `1` or `0` in the respective type. This allows you to do various bit manipulation
+/// and arithmetic techniques for processing values within the vector.
+///
+/// ### Examples
+///
+/// ##### Two vectors
+///
+/// ```rust
+/// let lhs = [-1.0, 2.3, 2.0, 1.0];
+/// let rhs = [2.0, 0.7, 2.0, -2.0];
+///
+/// let mut mask = [0.0f32; 4];
+/// cfavml::gte_vertical(&lhs, &rhs, &mut mask);
+/// assert_eq!(mask, [0.0, 1.0, 1.0, 1.0]);  // Index 1, 2 & 3 are greater than or eq to rhs.
+///
+/// // Now we can use it to zero any values that are less than the rhs.
+/// let mut match_or_zeroes = [0.0f32; 4];
+/// cfavml::mul_vertical(&lhs, &mask, &mut match_or_zeroes);
+///
+/// // Our original match is extracted and the rest are `0.0`
+/// // For convenience, I've used `0.0` as the non-match value,
+/// // but if you switch `mask` and `lhs` around you can get
+/// // a `NaN` mask which may be more useful depending on application.
+/// assert_eq!(match_or_zeroes, [0.0, 2.3, 2.0, 1.0]);    
+/// ```
+///
+/// ##### One vector & broadcast value
+///
+/// ```rust
+/// let lhs = [2.0, f32::NAN, -1.0, -0.5];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::gte_vertical(&lhs, -0.5, &mut result);
+/// assert_eq!(result, [1.0, 0.0, 0.0, 1.0]);  // NaN is always false on gte checks.
+/// ```
+///
+/// ##### Two broadcast values
+///
+/// ```rust
+/// let mut result = [0.0f32; 4];
+/// cfavml::gte_vertical(5.0, 5.0, &mut result);
+/// assert_eq!(result, [1.0; 4]);
+/// ```
+///
+/// ##### With projected vectors
+///
+/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
+/// vectors than the original inputs, which can allow for much better performance and
+/// memory usage when working with matrices.
+///
+/// NOTE:
+/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
+/// how it behaves, it does not replace your matrix library's own broadcasting system.
+///
+/// ```rust
+/// use cfavml::mem_loader::Projected;
+///
+/// let lhs = [1.0, 1.0, 0.5, 1.0];    // Pretend this is a 2x2 matrix
+/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+///
+/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
+/// cfavml::gte_vertical(Projected(&lhs), Projected(&rhs), &mut result);
+/// assert_eq!(result, [1.0, 0.0, 0.0, 0.0]);
+/// ```
+///
+/// ##### With `MaybeUninit`
+///
+/// Often if you are working with new-allocations, you do not want to initialize the data twice,
+/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
+/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+///
+/// let lhs = [1.0, -1.0, 0.5, 1.0];
+/// let rhs = [1.0, 2.5, 0.5, -2.0];
+///
+/// let mut result = Vec::with_capacity(4);
+/// unsafe { result.set_len(4) };
+/// cfavml::gte_vertical(&lhs, &rhs, &mut result);
+///
+/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
+/// assert_eq!(result, [1.0, 0.0, 1.0, 1.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// mask = [0; dims]
+///
+/// for i in range(dims):
+///     mask[i] = a[i] >= b[i] ? 1 : 0
+///
+/// return mask
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 >= 0.0 -> true`
+/// - `1.0 >= NaN -> false`
+/// - `NaN >= 1.0 -> false`
+/// - `NaN >= NaN -> false`
+///
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn gte_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::gte_vertical(lhs, rhs, result)
+}
+
+/// Selects each element from `lhs` where the matching element in `mask` is non-zero,
+/// otherwise selecting the element from `rhs`, storing the output in `result`.
+///
+/// This is designed to work with the `1` (true) and `0` (false) masks produced by the
+/// comparison routines like [gt_vertical], although any non-zero mask value selects from `lhs`.
+///
+/// ### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of the input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide any combination of _slices_ and _broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// ### Examples
+///
+/// ##### Conditional assignment from a comparison mask
+///
+/// ```rust
+/// let lhs = [1.0, -2.0, 3.0, -4.0];
+/// let rhs = [0.5, 0.5, 0.5, 0.5];
+///
+/// let mut mask = [0.0f32; 4];
+/// cfavml::gt_vertical(&lhs, &rhs, &mut mask);
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::select_vertical(&mask, &lhs, &rhs, &mut result);
+/// assert_eq!(result, [1.0, 0.5, 3.0, 0.5]);
+/// ```
+///
+/// ##### Clamping negative values to zero
+///
+/// ```rust
+/// let values = [5, -3, 0, -1, 7];
+///
+/// let mut mask = [0i32; 5];
+/// cfavml::gt_vertical(&values, 0, &mut mask);
+///
+/// let mut result = [0i32; 5];
+/// cfavml::select_vertical(&mask, &values, 0, &mut result);
+/// assert_eq!(result, [5, 0, 0, 0, 7]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = [0; dims]
+///
+/// for i in range(dims):
+///     result[i] = mask[i] != 0 ? a[i] : b[i]
+///
+/// return result
+/// ```
+///
+/// ### Note on `f32/f64` masks
+///
+/// For `f32` and `f64` types, both `0.0` and `-0.0` are treated as zero and select from `rhs`,
+/// a `NaN` mask value is non-zero and selects from `lhs`.
+///
+/// # Panics
+///
+/// If vectors `mask`, `lhs` and `rhs` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn select_vertical<T, B1, B2, B3, B4>(mask: B1, lhs: B2, rhs: B3, result: &mut [B4])
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    B3: IntoMemLoader<T>,
+    B3::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B4]: WriteOnlyBuffer<Item = T>,
+{
+    T::select_vertical(mask, lhs, rhs, result)
+}
+
+#[inline]
+/// Counts the elements of vector `a` that are **_equal to_** the broadcast `value`.
+///
+/// This is considerably cheaper than producing a mask with [eq_vertical] and summing it,
+/// as the comparison masks are accumulated in registers rather than being written out.
+/// An empty input returns `0`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// let count = cfavml::count_eq_value(&values, 2.0);
+/// assert_eq!(count, 2);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// count = 0
+///
+/// for i in range(dims):
+///     if a[i] == value:
+///         count += 1
+///
+/// return count
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 == 1.0 -> true`
+/// - `1.0 == NaN -> false`
+/// - `NaN == NaN -> false`
+pub fn count_eq_value<T, B1>(a: B1, value: T) -> usize
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::count_eq_value(a, value)
+}
+
+#[inline]
+/// Counts the elements of vector `a` that are **_not equal to_** the broadcast `value`.
+///
+/// This is considerably cheaper than producing a mask with [neq_vertical] and summing it,
+/// as the comparison masks are accumulated in registers rather than being written out.
+/// An empty input returns `0`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// let count = cfavml::count_neq_value(&values, 2.0);
+/// assert_eq!(count, 3);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// count = 0
+///
+/// for i in range(dims):
+///     if a[i] != value:
+///         count += 1
+///
+/// return count
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are never equal to anything, even each other,
+/// so they are always counted by this routine.
+///
+/// - `0.0 != 1.0 -> true`
+/// - `0.0 != NaN -> true`
+/// - `NaN != NaN -> true`
+pub fn count_neq_value<T, B1>(a: B1, value: T) -> usize
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::count_neq_value(a, value)
+}
+
+#[inline]
+/// Counts the elements of vector `a` that are **_less than_** the broadcast `value`.
+///
+/// This is considerably cheaper than producing a mask with [lt_vertical] and summing it,
+/// as the comparison masks are accumulated in registers rather than being written out.
+/// An empty input returns `0`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// let count = cfavml::count_lt_value(&values, 2.0);
+/// assert_eq!(count, 1);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// count = 0
+///
+/// for i in range(dims):
+///     if a[i] < value:
+///         count += 1
+///
+/// return count
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 < 1.0 -> true`
+/// - `1.0 < NaN -> false`
+/// - `NaN < 1.0 -> false`
+/// - `NaN < NaN -> false`
+pub fn count_lt_value<T, B1>(a: B1, value: T) -> usize
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::count_lt_value(a, value)
+}
+
+#[inline]
+/// Counts the elements of vector `a` that are **_less than or equal to_** the broadcast `value`.
+///
+/// This is considerably cheaper than producing a mask with [lte_vertical] and summing it,
+/// as the comparison masks are accumulated in registers rather than being written out.
+/// An empty input returns `0`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// let count = cfavml::count_lte_value(&values, 2.0);
+/// assert_eq!(count, 3);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// count = 0
+///
+/// for i in range(dims):
+///     if a[i] <= value:
+///         count += 1
+///
+/// return count
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 <= 1.0 -> true`
+/// - `1.0 <= NaN -> false`
+/// - `NaN <= 1.0 -> false`
+/// - `NaN <= NaN -> false`
+pub fn count_lte_value<T, B1>(a: B1, value: T) -> usize
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::count_lte_value(a, value)
+}
+
+#[inline]
+/// Counts the elements of vector `a` that are **_greater than_** the broadcast `value`.
+///
+/// This is considerably cheaper than producing a mask with [gt_vertical] and summing it,
+/// as the comparison masks are accumulated in registers rather than being written out.
+/// An empty input returns `0`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// let count = cfavml::count_gt_value(&values, 2.0);
+/// assert_eq!(count, 2);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// count = 0
+///
+/// for i in range(dims):
+///     if a[i] > value:
+///         count += 1
+///
+/// return count
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 > 0.0 -> true`
+/// - `1.0 > NaN -> false`
+/// - `NaN > 1.0 -> false`
+/// - `NaN > NaN -> false`
+pub fn count_gt_value<T, B1>(a: B1, value: T) -> usize
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::count_gt_value(a, value)
+}
+
+#[inline]
+/// Counts the elements of vector `a` that are **_greater than or equal to_** the broadcast `value`.
+///
+/// This is considerably cheaper than producing a mask with [gte_vertical] and summing it,
+/// as the comparison masks are accumulated in registers rather than being written out.
+/// An empty input returns `0`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// let count = cfavml::count_gte_value(&values, 2.0);
+/// assert_eq!(count, 4);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// count = 0
+///
+/// for i in range(dims):
+///     if a[i] >= value:
+///         count += 1
+///
+/// return count
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 >= 0.0 -> true`
+/// - `1.0 >= NaN -> false`
+/// - `NaN >= 1.0 -> false`
+/// - `NaN >= NaN -> false`
+pub fn count_gte_value<T, B1>(a: B1, value: T) -> usize
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::count_gte_value(a, value)
+}
+
+#[inline]
+/// Counts the elements of vector `a` that are **_non-zero_**.
+///
+/// Each register is reduced to a bitmask of its non-zero elements and the set bits are
+/// counted, which makes this a cheap way of counting a mask produced by [gt_vertical]
+/// or the other comparison routines. An empty input returns `0`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4, 0, 7, 0, 1];
+///
+/// let count = cfavml::count_nonzero(&values);
+/// assert_eq!(count, 3);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// count = 0
+///
+/// for i in range(dims):
+///     if a[i] != 0:
+///         count += 1
+///
+/// return count
+/// ```
+///
+/// ### Note on `f32/f64` types
+///
+/// For `f32` and `f64` types both `0.0` and `-0.0` are treated as zero, `NaN` values
+/// are always counted as being non-zero.
+pub fn count_nonzero<T, B1>(a: B1) -> usize
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::count_nonzero(a)
+}
+
+#[inline]
+/// Returns if **_any_** element of vector `a` is **_equal to_** the broadcast `value`.
+///
+/// The comparison masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a match is found. An empty input returns `false`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// assert!(cfavml::any_eq_value(&values, 2.0));
+/// assert!(!cfavml::any_eq_value(&values, 3.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if a[i] == value:
+///         return true
+///
+/// return false
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 == 1.0 -> true`
+/// - `1.0 == NaN -> false`
+/// - `NaN == NaN -> false`
+pub fn any_eq_value<T, B1>(a: B1, value: T) -> bool
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::any_eq_value(a, value)
+}
+
+#[inline]
+/// Returns if **_any_** element of vector `a` is **_not equal to_** the broadcast `value`.
+///
+/// The comparison masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a match is found. An empty input returns `false`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// assert!(cfavml::any_neq_value(&values, 2.0));
+/// assert!(!cfavml::any_neq_value(&[2.0, 2.0], 2.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if a[i] != value:
+///         return true
+///
+/// return false
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are never equal to anything, even each other,
+/// so they always match in this routine.
+///
+/// - `0.0 != 1.0 -> true`
+/// - `0.0 != NaN -> true`
+/// - `NaN != NaN -> true`
+pub fn any_neq_value<T, B1>(a: B1, value: T) -> bool
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::any_neq_value(a, value)
+}
+
+#[inline]
+/// Returns if **_any_** element of vector `a` is **_less than_** the broadcast `value`.
+///
+/// The comparison masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a match is found. An empty input returns `false`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// assert!(cfavml::any_lt_value(&values, 2.0));
+/// assert!(!cfavml::any_lt_value(&values, 1.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if a[i] < value:
+///         return true
+///
+/// return false
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 < 1.0 -> true`
+/// - `1.0 < NaN -> false`
+/// - `NaN < 1.0 -> false`
+/// - `NaN < NaN -> false`
+pub fn any_lt_value<T, B1>(a: B1, value: T) -> bool
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::any_lt_value(a, value)
+}
+
+#[inline]
+/// Returns if **_any_** element of vector `a` is **_less than or equal to_** the broadcast `value`.
+///
+/// The comparison masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a match is found. An empty input returns `false`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// assert!(cfavml::any_lte_value(&values, 1.0));
+/// assert!(!cfavml::any_lte_value(&values, 0.5));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if a[i] <= value:
+///         return true
+///
+/// return false
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 <= 1.0 -> true`
+/// - `1.0 <= NaN -> false`
+/// - `NaN <= 1.0 -> false`
+/// - `NaN <= NaN -> false`
+pub fn any_lte_value<T, B1>(a: B1, value: T) -> bool
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::any_lte_value(a, value)
+}
+
+#[inline]
+/// Returns if **_any_** element of vector `a` is **_greater than_** the broadcast `value`.
+///
+/// The comparison masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a match is found. An empty input returns `false`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// assert!(cfavml::any_gt_value(&values, 4.0));
+/// assert!(!cfavml::any_gt_value(&values, 7.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if a[i] > value:
+///         return true
+///
+/// return false
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 > 0.0 -> true`
+/// - `1.0 > NaN -> false`
+/// - `NaN > 1.0 -> false`
+/// - `NaN > NaN -> false`
+pub fn any_gt_value<T, B1>(a: B1, value: T) -> bool
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::any_gt_value(a, value)
+}
+
+#[inline]
+/// Returns if **_any_** element of vector `a` is **_greater than or equal to_** the broadcast `value`.
+///
+/// The comparison masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a match is found. An empty input returns `false`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// assert!(cfavml::any_gte_value(&values, 7.0));
+/// assert!(!cfavml::any_gte_value(&values, 8.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if a[i] >= value:
+///         return true
+///
+/// return false
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 >= 0.0 -> true`
+/// - `1.0 >= NaN -> false`
+/// - `NaN >= 1.0 -> false`
+/// - `NaN >= NaN -> false`
+pub fn any_gte_value<T, B1>(a: B1, value: T) -> bool
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::any_gte_value(a, value)
+}
+
+#[inline]
+/// Returns if **_all_** elements of vector `a` are **_equal to_** the broadcast `value`.
+///
+/// The comparison masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a mismatch is found. An empty input returns `true`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// assert!(!cfavml::all_eq_value(&values, 2.0));
+/// assert!(cfavml::all_eq_value(&[2.0, 2.0], 2.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if not (a[i] == value):
+///         return false
+///
+/// return true
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 == 1.0 -> true`
+/// - `1.0 == NaN -> false`
+/// - `NaN == NaN -> false`
+pub fn all_eq_value<T, B1>(a: B1, value: T) -> bool
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::all_eq_value(a, value)
+}
+
+#[inline]
+/// Returns if **_all_** elements of vector `a` are **_not equal to_** the broadcast `value`.
+///
+/// The comparison masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a mismatch is found. An empty input returns `true`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// assert!(cfavml::all_neq_value(&values, 3.0));
+/// assert!(!cfavml::all_neq_value(&values, 2.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if not (a[i] != value):
+///         return false
+///
+/// return true
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are never equal to anything, even each other,
+/// so they always match in this routine.
+///
+/// - `0.0 != 1.0 -> true`
+/// - `0.0 != NaN -> true`
+/// - `NaN != NaN -> true`
+pub fn all_neq_value<T, B1>(a: B1, value: T) -> bool
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::all_neq_value(a, value)
+}
+
+#[inline]
+/// Returns if **_all_** elements of vector `a` are **_less than_** the broadcast `value`.
+///
+/// The comparison masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a mismatch is found. An empty input returns `true`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// assert!(cfavml::all_lt_value(&values, 8.0));
+/// assert!(!cfavml::all_lt_value(&values, 7.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if not (a[i] < value):
+///         return false
+///
+/// return true
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 < 1.0 -> true`
+/// - `1.0 < NaN -> false`
+/// - `NaN < 1.0 -> false`
+/// - `NaN < NaN -> false`
+pub fn all_lt_value<T, B1>(a: B1, value: T) -> bool
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::all_lt_value(a, value)
+}
+
+#[inline]
+/// Returns if **_all_** elements of vector `a` are **_less than or equal to_** the broadcast `value`.
+///
+/// The comparison masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a mismatch is found. An empty input returns `true`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// assert!(cfavml::all_lte_value(&values, 7.0));
+/// assert!(!cfavml::all_lte_value(&values, 4.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if not (a[i] <= value):
+///         return false
+///
+/// return true
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 <= 1.0 -> true`
+/// - `1.0 <= NaN -> false`
+/// - `NaN <= 1.0 -> false`
+/// - `NaN <= NaN -> false`
+pub fn all_lte_value<T, B1>(a: B1, value: T) -> bool
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::all_lte_value(a, value)
+}
+
+#[inline]
+/// Returns if **_all_** elements of vector `a` are **_greater than_** the broadcast `value`.
+///
+/// The comparison masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a mismatch is found. An empty input returns `true`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// assert!(cfavml::all_gt_value(&values, 0.0));
+/// assert!(!cfavml::all_gt_value(&values, 1.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if not (a[i] > value):
+///         return false
+///
+/// return true
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 > 0.0 -> true`
+/// - `1.0 > NaN -> false`
+/// - `NaN > 1.0 -> false`
+/// - `NaN > NaN -> false`
+pub fn all_gt_value<T, B1>(a: B1, value: T) -> bool
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::all_gt_value(a, value)
+}
+
+#[inline]
+/// Returns if **_all_** elements of vector `a` are **_greater than or equal to_** the broadcast `value`.
+///
+/// The comparison masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a mismatch is found. An empty input returns `true`.
+///
+/// ### Examples
+///
+/// ```rust
+/// let values = [4.0, 2.0, 7.0, 2.0, 1.0];
+///
+/// assert!(cfavml::all_gte_value(&values, 1.0));
+/// assert!(!cfavml::all_gte_value(&values, 2.0));
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if not (a[i] >= value):
+///         return false
+///
+/// return true
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 >= 0.0 -> true`
+/// - `1.0 >= NaN -> false`
+/// - `NaN >= 1.0 -> false`
+/// - `NaN >= NaN -> false`
+pub fn all_gte_value<T, B1>(a: B1, value: T) -> bool
+where
+    T: CmpOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::all_gte_value(a, value)
+}
+
+#[inline]
+/// Checks each element pair of elements from vectors `a` and `b` comparing if
+/// element `a` is **_equal to_** element `b`, writing the result of each check as a
+/// single bit of `result`.
+///
+/// Bit `i` of the output is the result for element `i`, bits are packed least
+/// significant bit first within each byte, i.e. element `0` is bit `0` of byte `0`.
+/// The trailing bits of the last byte past the length of `a` are zeroed and any
+/// bytes of `result` past `a.len().div_ceil(8)` are left untouched.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = [1, 2, 3, 4, 5];
+/// let b = [1, 1, 3, 5, 4];
+///
+/// let mut result = [0xFF; 1];
+/// cfavml::eq_bitmask(&a, &b, &mut result);
+/// assert_eq!(result, [0b0000_0101]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// bitmask = [0; ceil(dims / 8)]
+///
+/// for i in range(dims):
+///     if a[i] == b[i]:
+///         bitmask[i / 8] |= 1 << (i % 8)
+///
+/// return bitmask
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 == 0.0 -> true`
+/// - `0.0 == NaN -> false`
+/// - `NaN == NaN -> false`
+///
+/// # Panics
+///
+/// If vectors `a` and `b` are not equal in length or `result` is less than
+/// `a.len().div_ceil(8)` bytes in length.
+pub fn eq_bitmask<T>(a: &[T], b: &[T], result: &mut [u8])
+where
+    T: CmpOps,
+{
+    T::eq_bitmask(a.len(), a, b, result)
+}
+
+#[inline]
+/// Checks each element pair of elements from vectors `a` and `b` comparing if
+/// element `a` is **_not equal to_** element `b`, writing the result of each check as a
+/// single bit of `result`.
+///
+/// Bit `i` of the output is the result for element `i`, bits are packed least
+/// significant bit first within each byte, i.e. element `0` is bit `0` of byte `0`.
+/// The trailing bits of the last byte past the length of `a` are zeroed and any
+/// bytes of `result` past `a.len().div_ceil(8)` are left untouched.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = [1, 2, 3, 4, 5];
+/// let b = [1, 1, 3, 5, 4];
+///
+/// let mut result = [0xFF; 1];
+/// cfavml::neq_bitmask(&a, &b, &mut result);
+/// assert_eq!(result, [0b0001_1010]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// bitmask = [0; ceil(dims / 8)]
+///
+/// for i in range(dims):
+///     if a[i] != b[i]:
+///         bitmask[i / 8] |= 1 << (i % 8)
+///
+/// return bitmask
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 != 1.0 -> true`
+/// - `0.0 != NaN -> true`
+/// - `NaN != NaN -> true`
+///
+/// # Panics
+///
+/// If vectors `a` and `b` are not equal in length or `result` is less than
+/// `a.len().div_ceil(8)` bytes in length.
+pub fn neq_bitmask<T>(a: &[T], b: &[T], result: &mut [u8])
+where
+    T: CmpOps,
+{
+    T::neq_bitmask(a.len(), a, b, result)
+}
+
+#[inline]
+/// Checks each element pair of elements from vectors `a` and `b` comparing if
+/// element `a` is **_less than_** element `b`, writing the result of each check as a
+/// single bit of `result`.
+///
+/// Bit `i` of the output is the result for element `i`, bits are packed least
+/// significant bit first within each byte, i.e. element `0` is bit `0` of byte `0`.
+/// The trailing bits of the last byte past the length of `a` are zeroed and any
+/// bytes of `result` past `a.len().div_ceil(8)` are left untouched.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = [1, 2, 3, 4, 5];
+/// let b = [1, 1, 3, 5, 4];
+///
+/// let mut result = [0xFF; 1];
+/// cfavml::lt_bitmask(&a, &b, &mut result);
+/// assert_eq!(result, [0b0000_1000]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// bitmask = [0; ceil(dims / 8)]
+///
+/// for i in range(dims):
+///     if a[i] < b[i]:
+///         bitmask[i / 8] |= 1 << (i % 8)
+///
+/// return bitmask
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 < 1.0 -> true`
+/// - `0.0 < NaN -> false`
+/// - `NaN < 1.0 -> false`
+/// - `NaN < NaN -> false`
+///
+/// # Panics
+///
+/// If vectors `a` and `b` are not equal in length or `result` is less than
+/// `a.len().div_ceil(8)` bytes in length.
+pub fn lt_bitmask<T>(a: &[T], b: &[T], result: &mut [u8])
+where
+    T: CmpOps,
+{
+    T::lt_bitmask(a.len(), a, b, result)
+}
+
+#[inline]
+/// Checks each element pair of elements from vectors `a` and `b` comparing if
+/// element `a` is **_less than or equal to_** element `b`, writing the result of each check as a
+/// single bit of `result`.
+///
+/// Bit `i` of the output is the result for element `i`, bits are packed least
+/// significant bit first within each byte, i.e. element `0` is bit `0` of byte `0`.
+/// The trailing bits of the last byte past the length of `a` are zeroed and any
+/// bytes of `result` past `a.len().div_ceil(8)` are left untouched.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = [1, 2, 3, 4, 5];
+/// let b = [1, 1, 3, 5, 4];
+///
+/// let mut result = [0xFF; 1];
+/// cfavml::lte_bitmask(&a, &b, &mut result);
+/// assert_eq!(result, [0b0000_1101]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// bitmask = [0; ceil(dims / 8)]
+///
+/// for i in range(dims):
+///     if a[i] <= b[i]:
+///         bitmask[i / 8] |= 1 << (i % 8)
+///
+/// return bitmask
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `0.0 <= 1.0 -> true`
+/// - `1.0 <= 1.0 -> true`
+/// - `0.0 <= NaN -> false`
+/// - `NaN <= 1.0 -> false`
+/// - `NaN <= NaN -> false`
+///
+/// # Panics
+///
+/// If vectors `a` and `b` are not equal in length or `result` is less than
+/// `a.len().div_ceil(8)` bytes in length.
+pub fn lte_bitmask<T>(a: &[T], b: &[T], result: &mut [u8])
+where
+    T: CmpOps,
+{
+    T::lte_bitmask(a.len(), a, b, result)
+}
+
+#[inline]
+/// Checks each element pair of elements from vectors `a` and `b` comparing if
+/// element `a` is **_greater than_** element `b`, writing the result of each check as a
+/// single bit of `result`.
+///
+/// Bit `i` of the output is the result for element `i`, bits are packed least
+/// significant bit first within each byte, i.e. element `0` is bit `0` of byte `0`.
+/// The trailing bits of the last byte past the length of `a` are zeroed and any
+/// bytes of `result` past `a.len().div_ceil(8)` are left untouched.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = [1, 2, 3, 4, 5];
+/// let b = [1, 1, 3, 5, 4];
+///
+/// let mut result = [0xFF; 1];
+/// cfavml::gt_bitmask(&a, &b, &mut result);
+/// assert_eq!(result, [0b0001_0010]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// bitmask = [0; ceil(dims / 8)]
+///
+/// for i in range(dims):
+///     if a[i] > b[i]:
+///         bitmask[i / 8] |= 1 << (i % 8)
+///
+/// return bitmask
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 > 0.0 -> true`
+/// - `1.0 > NaN -> false`
+/// - `NaN > 1.0 -> false`
+/// - `NaN > NaN -> false`
+///
+/// # Panics
+///
+/// If vectors `a` and `b` are not equal in length or `result` is less than
+/// `a.len().div_ceil(8)` bytes in length.
+pub fn gt_bitmask<T>(a: &[T], b: &[T], result: &mut [u8])
+where
+    T: CmpOps,
+{
+    T::gt_bitmask(a.len(), a, b, result)
+}
+
+#[inline]
+/// Checks each element pair of elements from vectors `a` and `b` comparing if
+/// element `a` is **_greater than or equal to_** element `b`, writing the result of each check as a
+/// single bit of `result`.
+///
+/// Bit `i` of the output is the result for element `i`, bits are packed least
+/// significant bit first within each byte, i.e. element `0` is bit `0` of byte `0`.
+/// The trailing bits of the last byte past the length of `a` are zeroed and any
+/// bytes of `result` past `a.len().div_ceil(8)` are left untouched.
+///
+/// ### Examples
+///
+/// ```rust
+/// let a = [1, 2, 3, 4, 5];
+/// let b = [1, 1, 3, 5, 4];
+///
+/// let mut result = [0xFF; 1];
+/// cfavml::gte_bitmask(&a, &b, &mut result);
+/// assert_eq!(result, [0b0001_0111]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// bitmask = [0; ceil(dims / 8)]
+///
+/// for i in range(dims):
+///     if a[i] >= b[i]:
+///         bitmask[i / 8] |= 1 << (i % 8)
+///
+/// return bitmask
+/// ```
+///
+/// ### Note on `NaN` handling on `f32/f64` types
+///
+/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+/// Even when compared against each other.
+///
+/// - `1.0 >= 0.0 -> true`
+/// - `1.0 >= NaN -> false`
+/// - `NaN >= 1.0 -> false`
+/// - `NaN >= NaN -> false`
+///
+/// # Panics
+///
+/// If vectors `a` and `b` are not equal in length or `result` is less than
+/// `a.len().div_ceil(8)` bytes in length.
+pub fn gte_bitmask<T>(a: &[T], b: &[T], result: &mut [u8])
+where
+    T: CmpOps,
+{
+    T::gte_bitmask(a.len(), a, b, result)
+}
+
+/// Performs an element wise addition of two input buffers `a` and `b` that can
+/// be projected to the desired output size of `result`.
+///
+/// ### Things To Know
+///
+/// ###### Supported patterns
+///
+/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
+/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
+/// read from before executing. For the most part, you don't need to worry about this outside of
+/// knowing you can by default, pass one combination of:
+///     
+/// - `lhs: vector` and `rhs: vector`  
+/// - `lhs: vector` and `rhs: broadcast value`  
+/// - `lhs: broadcast value` and `rhs: vector`  
+/// - `lhs: broadcast value` and `rhs: broadcast value`  
+///   * Not really that useful and is just an artefact of the memory management system.
+///
+/// ###### Broadcast values
+///
+/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
+/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
+/// outside the result buffer itself.
+///
+/// This means the following is possible:
+/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `1 + 1 == [1]`  w/result_buffer_len=1
+///
+/// ###### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+/// enables projecting of the input buffer to new sizes providing the new size is a
+/// multiple of the original size. When this buffer is projected, it is effectively
+/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+///
+/// ### Examples
+///
+/// ##### Two vectors
+///
+/// ```rust
+/// let lhs = [-1.0, 2.3, 2.0, 1.0];
+/// let rhs = [2.0, 0.7, 2.0, -2.0];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::add_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [1.0, 3.0, 4.0, -1.0]);
+/// ```
+///
+/// ##### One vector & broadcast value
+///
+/// ```rust
+/// let lhs = [2.0, 0.5, -1.0, -0.5];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::add_vertical(&lhs, -0.5, &mut result);
+/// assert_eq!(result, [1.5, 0.0, -1.5, -1.0]);
+/// ```
+///
+/// ##### Two broadcast values
+///
+/// ```rust
+/// let mut result = [0.0f32; 4];
+/// cfavml::add_vertical(5.0, 5.0, &mut result);
+/// assert_eq!(result, [10.0; 4]);
+/// ```
+///
+/// ##### With projected vectors
+///
+/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
+/// vectors than the original inputs, which can allow for much better performance and
+/// memory usage when working with matrices.
+///
+/// NOTE:
+/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
+/// how it behaves, it does not replace your matrix library's own broadcasting system.
+///
+/// ```rust
+/// use cfavml::mem_loader::Projected;
+///
+/// let lhs = [1.0, 1.0, 0.5, 1.0];    // Pretend this is a 2x2 matrix
+/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+///
+/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
+/// cfavml::add_vertical(Projected(&lhs), Projected(&rhs), &mut result);
+/// assert_eq!(result, [2.0, 3.5, 1.5, 3.5]);
+/// ```
+///
+/// ##### With `MaybeUninit`
+///
+/// Often if you are working with new-allocations, you do not want to initialize the data twice,
+/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
+/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+///
+/// let lhs = [1.0, -1.0, 0.5, 1.0];
+/// let rhs = [1.0, 2.5, 0.5, -2.0];
+///
+/// let mut result = Vec::with_capacity(4);
+/// unsafe { result.set_len(4) };
+/// cfavml::add_vertical(&lhs, &rhs, &mut result);
+///
+/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
+/// assert_eq!(result, [2.0, 1.5, 1.0, -1.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = [0; dims]
+///
+/// for i in range(dims):
+///     result[i] = a[i] + b[i]
+///
+/// return result
+/// ```
+///
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn add_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: ArithmeticOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::add_vertical(lhs, rhs, result)
+}
+
+/// Performs an element wise addition of two input buffers `a` and `b` that can
+/// be projected to the desired output size of `result`, writing the result with
+/// non-temporal stores where available.
+///
+/// Non-temporal stores bypass the cache, so adding two vectors much larger than
+/// the cache does not evict everything else the rest of the program is working with.
+/// Where the output fits in cache, or will be read again straight away, [add_vertical]
+/// is typically faster.
+///
+/// AVX2 and AVX512 use non-temporal stores for everything after the first element
+/// of `result` aligned to the register width, other implementations behave the same
+/// as [add_vertical].
+///
+/// ### Examples
+///
+/// ```rust
+/// let lhs = vec![1.0f32; 1043];
+/// let rhs = vec![2.5f32; 1043];
+///
+/// let mut result = vec![0.0f32; 1043];
+/// cfavml::add_streaming(&lhs, &rhs, &mut result);
+/// assert_eq!(result, vec![3.5; 1043]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = [0; dims]
+///
+/// for i in range(dims):
+///     result[i] = a[i] + b[i]
+///
+/// return result
+/// ```
+///
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn add_streaming<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: ArithmeticOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::add_streaming(lhs, rhs, result)
+}
+
+/// Performs an element wise subtraction of two input buffers `a` and `b` that can
+/// be projected to the desired output size of `result`.
+///
+/// ### Things To Know
+///
+/// ###### Supported patterns
+///
+/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
+/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
+/// read from before executing. For the most part, you don't need to worry about this outside of
+/// knowing you can by default, pass one combination of:
+///     
+/// - `lhs: vector` and `rhs: vector`  
+/// - `lhs: vector` and `rhs: broadcast value`  
+/// - `lhs: broadcast value` and `rhs: vector`  
+/// - `lhs: broadcast value` and `rhs: broadcast value`  
+///   * Not really that useful and is just an artefact of the memory management system.
+///
+/// ###### Broadcast values
+///
+/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
+/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
+/// outside the result buffer itself.
+///
+/// This means the following is possible:
+/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `1 + 1 == [1]`  w/result_buffer_len=1
+///
+/// ###### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+/// enables projecting of the input buffer to new sizes providing the new size is a
+/// multiple of the original size. When this buffer is projected, it is effectively
+/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+///
+/// ### Examples
+///
+/// ##### Two vectors
+///
+/// ```rust
+/// let lhs = [-1.0, 2.3, 2.0, 1.0];
+/// let rhs = [2.0, 0.7, 2.0, -2.0];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::sub_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [-3.0, 1.5999999, 0.0, 3.0]);
+/// ```
+///
+/// ##### One vector & broadcast value
+///
+/// ```rust
+/// let lhs = [2.0, 0.5, -1.0, -0.5];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::sub_vertical(&lhs, -0.5, &mut result);
+/// assert_eq!(result, [2.5, 1.0, -0.5, 0.0]);
+/// ```
+///
+/// ##### Broadcast value & one vector
+///
+/// The broadcast value can be provided as the `lhs` operand, which is useful
+/// for things like `1.0 - x` without allocating a filled vector first.
+///
+/// ```rust
+/// let rhs = [2.0, 0.5, -1.0, -0.5];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::sub_vertical(1.0, &rhs, &mut result);
+/// assert_eq!(result, [-1.0, 0.5, 2.0, 1.5]);
+/// ```
+///
+/// ##### Two broadcast values
+///
+/// ```rust
+/// let mut result = [0.0f32; 4];
+/// cfavml::sub_vertical(5.0, 5.0, &mut result);
+/// assert_eq!(result, [0.0; 4]);
+/// ```
+///
+/// ##### With projected vectors
+///
+/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
+/// vectors than the original inputs, which can allow for much better performance and
+/// memory usage when working with matrices.
+///
+/// NOTE:
+/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
+/// how it behaves, it does not replace your matrix library's own broadcasting system.
+///
+/// ```rust
+/// use cfavml::mem_loader::Projected;
+///
+/// let lhs = [1.0, 1.0, 0.5, 1.0];    // Pretend this is a 2x2 matrix
+/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+///
+/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
+/// cfavml::sub_vertical(Projected(&lhs), Projected(&rhs), &mut result);
+/// assert_eq!(result, [0.0, -1.5, -0.5, -1.5]);
+/// ```
+///
+/// ##### With `MaybeUninit`
+///
+/// Often if you are working with new-allocations, you do not want to initialize the data twice,
+/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
+/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+///
+/// let lhs = [1.0, -1.0, 0.5, 1.0];
+/// let rhs = [1.0, 2.5, 0.5, -2.0];
+///
+/// let mut result = Vec::with_capacity(4);
+/// unsafe { result.set_len(4) };
+/// cfavml::sub_vertical(&lhs, &rhs, &mut result);
+///
+/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
+/// assert_eq!(result, [0.0, -3.5, 0.0, 3.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = [0; dims]
+///
+/// for i in range(dims):
+///     result[i] = a[i] - b[i]
 ///
 /// return result
 /// ```
-pub fn min<T, B1>(a: B1) -> T
+///
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn sub_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
 where
-    T: CmpOps,
+    T: ArithmeticOps,
     B1: IntoMemLoader<T>,
     B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
 {
-    T::min(a)
+    T::sub_vertical(lhs, rhs, result)
 }
 
-#[inline]
-/// Takes the element wise min of vectors `a` and `b` of size `dims` and stores the result
-/// in `result` of size `dims`.
+/// Performs an element wise multiply of two input buffers `a` and `b` that can
+/// be projected to the desired output size of `result`.
+///
+/// ### Things To Know
+///
+/// ###### Supported patterns
+///
+/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
+/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
+/// read from before executing. For the most part, you don't need to worry about this outside of
+/// knowing you can by default, pass one combination of:
+///     
+/// - `lhs: vector` and `rhs: vector`  
+/// - `lhs: vector` and `rhs: broadcast value`  
+/// - `lhs: broadcast value` and `rhs: vector`  
+/// - `lhs: broadcast value` and `rhs: broadcast value`  
+///   * Not really that useful and is just an artefact of the memory management system.
+///
+/// ###### Broadcast values
+///
+/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
+/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
+/// outside the result buffer itself.
+///
+/// This means the following is possible:
+/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
+/// - `1 + 1 == [1]`  w/result_buffer_len=1
+///
+/// ###### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+/// enables projecting of the input buffer to new sizes providing the new size is a
+/// multiple of the original size. When this buffer is projected, it is effectively
+/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+///
+/// ### Examples
+///
+/// ##### Two vectors
+///
+/// ```rust
+/// let lhs = [-1.0, 2.3, 2.0, 1.0];
+/// let rhs = [2.0, 0.7, 2.0, -2.0];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::mul_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [-2.0, 1.6099999, 4.0, -2.0]);
+/// ```
+///
+/// ##### One vector & broadcast value
+///
+/// ```rust
+/// let lhs = [2.0, 0.5, -1.0, -0.5];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::mul_vertical(&lhs, -0.5, &mut result);
+/// assert_eq!(result, [-1.0, -0.25, 0.5, 0.25]);
+/// ```
+///
+/// ##### Two broadcast values
+///
+/// ```rust
+/// let mut result = [0.0f32; 4];
+/// cfavml::mul_vertical(5.0, 5.0, &mut result);
+/// assert_eq!(result, [25.0; 4]);
+/// ```
+///
+/// ##### With projected vectors
+///
+/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
+/// vectors than the original inputs, which can allow for much better performance and
+/// memory usage when working with matrices.
+///
+/// NOTE:
+/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
+/// how it behaves, it does not replace your matrix library's own broadcasting system.
+///
+/// ```rust
+/// use cfavml::mem_loader::Projected;
+///
+/// let lhs = [1.0, 1.0, 0.5, 1.0];    // Pretend this is a 2x2 matrix
+/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+///
+/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
+/// cfavml::mul_vertical(Projected(&lhs), Projected(&rhs), &mut result);
+/// assert_eq!(result, [1.0, 2.5, 0.5, 2.5]);
+/// ```
+///
+/// ##### With `MaybeUninit`
+///
+/// Often if you are working with new-allocations, you do not want to initialize the data twice,
+/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
+/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+///
+/// ```rust
+/// use core::mem::MaybeUninit;
+///
+/// let lhs = [1.0, -1.0, 0.5, 1.0];
+/// let rhs = [1.0, 2.5, 0.5, -2.0];
+///
+/// let mut result = Vec::with_capacity(4);
+/// unsafe { result.set_len(4) };
+/// cfavml::mul_vertical(&lhs, &rhs, &mut result);
+///
+/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
+/// assert_eq!(result, [1.0, -2.5, 0.25, -2.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = [0; dims]
+///
+/// for i in range(dims):
+///     result[i] = a[i] * b[i]
+///
+/// return result
+/// ```
+///
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn mul_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: ArithmeticOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::mul_vertical(lhs, rhs, result)
+}
+
+/// Performs an element wise division of two input buffers `a` and `b` that can
+/// be projected to the desired output size of `result`.
+///
+/// ## WARNING - You probably don't want to divide!
+///
+/// If you are attempting to divide a vector by a broadcast value or vice versa, you are
+/// infinitely better off calculating the inverse and using a _multiply_ operation instead.
+///
+/// **This routine will not do this for you** so you are potentially missing out on a large
+/// chunk of performance.
+///
+/// ```rust
+/// let example = [1.0, 2.0, 3.0];
+/// let mut result = [0.0; 3];
+/// // BAD! Don't do this!
+/// cfavml::div_vertical(&example, 2.0, &mut result);
+/// assert_eq!(result, [0.5, 1.0, 1.5]);
+///
+/// // Do this instead! It is infinitely faster! (Well not quite but trust me)
+/// cfavml::mul_vertical(&example, 1.0 / 2.0, &mut result);
+/// assert_eq!(result, [0.5, 1.0, 1.5]);
+/// ```
 ///
 /// ### Things To Know
 ///
@@ -530,33 +4848,46 @@ where
 ///
 /// ### Examples
 ///
-/// ##### Two vectors
+/// ##### Two vectors
+///
+/// ```rust
+/// let lhs = [1.0, 2.0, 4.0, 1.0];
+/// let rhs = [2.0, 0.8, 2.0, -2.0];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::div_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [0.5, 2.5, 2.0, -0.5]);
+/// ```
+///
+/// ##### One vector & broadcast value
 ///
 /// ```rust
-/// let lhs = [1.0, 1.0, 1.0, 1.0];
-/// let rhs = [2.0, 2.5, 1.0, -2.0];
+/// let lhs = [2.0, 0.5, -1.0, -0.5];
 ///
-/// let mut result = [0.0; 4];
-/// cfavml::min_vertical(&lhs, &rhs, &mut result);
-/// assert_eq!(result, [1.0, 1.0, 1.0, -2.0]);
+/// let mut result = [0.0f32; 4];
+/// cfavml::div_vertical(&lhs, -0.5, &mut result);
+/// assert_eq!(result, [-4.0, -1.0, 2.0, 1.0]);
 /// ```
 ///
-/// ##### One vector & broadcast value
+/// ##### Broadcast value & one vector
+///
+/// The broadcast value can be provided as the `lhs` operand, which is useful
+/// for things like `1.0 / x` without allocating a filled vector first.
 ///
 /// ```rust
-/// let lhs = [2.0, 3.0, -1.0, -0.5];
+/// let rhs = [2.0, 0.5, -1.0, -0.5];
 ///
-/// let mut result = [0.0; 4];
-/// cfavml::min_vertical(&lhs, 0.0, &mut result);
-/// assert_eq!(result, [0.0, 0.0, -1.0, -0.5]);
+/// let mut result = [0.0f32; 4];
+/// cfavml::div_vertical(1.0, &rhs, &mut result);
+/// assert_eq!(result, [0.5, 2.0, -1.0, -2.0]);
 /// ```
 ///
 /// ##### Two broadcast values
 ///
 /// ```rust
-/// let mut result = [0.0; 4];
-/// cfavml::min_vertical(1.0, -5.0, &mut result);
-/// assert_eq!(result, [-5.0; 4]);
+/// let mut result = [0.0f32; 4];
+/// cfavml::div_vertical(5.0, 5.0, &mut result);
+/// assert_eq!(result, [1.0; 4]);
 /// ```
 ///
 /// ##### With projected vectors
@@ -572,12 +4903,12 @@ where
 /// ```rust
 /// use cfavml::mem_loader::Projected;
 ///
-/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
+/// let lhs = [1.0, -1.0, 0.5, 1.0];    // Pretend this is a 2x2 matrix
 /// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
 ///
 /// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
-/// cfavml::min_vertical(Projected(&lhs), Projected(&rhs), &mut result);
-/// assert_eq!(result, [1.0, 1.0, 0.5, 2.5]);
+/// cfavml::div_vertical(Projected(&lhs), Projected(&rhs), &mut result);
+/// assert_eq!(result, [1.0, -0.4, 0.5, 0.4]);
 /// ```
 ///
 /// ##### With `MaybeUninit`
@@ -590,14 +4921,14 @@ where
 /// use core::mem::MaybeUninit;
 ///
 /// let lhs = [1.0, -1.0, 0.5, 1.0];
-/// let rhs = [2.0, 2.5, 1.0, -2.0];
+/// let rhs = [1.0, 2.5, 0.5, -2.0];
 ///
 /// let mut result = Vec::with_capacity(4);
 /// unsafe { result.set_len(4) };
-/// cfavml::min_vertical(&lhs, &rhs, &mut result);
+/// cfavml::div_vertical(&lhs, &rhs, &mut result);
 ///
 /// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
-/// assert_eq!(result, [1.0, -1.0, 0.5, -2.0]);
+/// assert_eq!(result, [1.0, -0.4, 1.0, -0.5]);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -608,7 +4939,7 @@ where
 /// result = [0; dims]
 ///
 /// for i in range(dims):
-///     result[i] = min(a[i], b[i])
+///     result[i] = a[i] / b[i]
 ///
 /// return result
 /// ```
@@ -617,152 +4948,289 @@ where
 ///
 /// If vectors `a` and `b` cannot be projected to the target size of `result`.
 /// Note that the projection rules are tied to the `MemLoader` implementation.
-pub fn min_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+pub fn div_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
 where
-    T: CmpOps,
+    T: ArithmeticOps,
     B1: IntoMemLoader<T>,
     B1::Loader: MemLoader<Value = T>,
     B2: IntoMemLoader<T>,
     B2::Loader: MemLoader<Value = T>,
     for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
 {
-    T::min_vertical(lhs, rhs, result)
+    T::div_vertical(lhs, rhs, result)
 }
 
 #[inline]
-/// Checks each element pair of elements from vectors `a` and `b` comparing if
-/// element `a` is **_equal to_** element `b`, storing the output as `1` (true) or `0` (false)
-/// in `result`.
+/// Performs an element wise addition of vectors `a` and `b` in place, writing the
+/// result back to `a`.
 ///
-/// ### Things To Know
+/// This avoids allocating a separate output buffer for update steps like `a += b`.
 ///
-/// ###### Supported patterns
+/// ### Examples
 ///
-/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
-/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
-/// read from before executing. For the most part, you don't need to worry about this outside of
-/// knowing you can by default, pass one combination of:
-///     
-/// - `lhs: vector` and `rhs: vector`  
-/// - `lhs: vector` and `rhs: broadcast value`  
-/// - `lhs: broadcast value` and `rhs: vector`  
-/// - `lhs: broadcast value` and `rhs: broadcast value`  
-///   * Not really that useful and is just an artefact of the memory management system.
+/// ```rust
+/// let mut a = [1.0, 2.0, 3.0, 4.0];
+/// let b = [0.5, 0.5, 1.0, -1.0];
 ///
-/// ###### Broadcast values
+/// cfavml::add_inplace(&mut a, &b);
+/// assert_eq!(a, [1.5, 2.5, 4.0, 3.0]);
+/// ```
 ///
-/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
-/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
-/// outside the result buffer itself.
+/// ### Implementation Pseudocode
 ///
-/// This means the following is possible:
-/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `1 + 1 == [1]`  w/result_buffer_len=1
+/// _This is the logic of the routine being called._
 ///
-/// ###### Projecting Vectors
+/// ```ignore
+/// for i in range(dims):
+///     a[i] = a[i] + b[i]
+/// ```
 ///
-/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
-/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+/// ### Panics
 ///
-/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
-/// which exhibit the standard behaviour as you might expect.
+/// If vectors `a` and `b` are not equal in length.
+pub fn add_inplace<T>(a: &mut [T], b: &[T])
+where
+    T: ArithmeticOps,
+{
+    unwrap_size(try_add_inplace(a, b))
+}
+
+#[inline]
+/// Performs an element wise addition of vector `a` and a single broadcast `value`
+/// in place, writing the result back to `a`.
 ///
-/// When providing two slices as inputs they cannot be projected to a buffer
-/// that is larger their input sizes by default. This means providing two slices
-/// of `128` elements in length must take a result buffer of `128` elements in length.
+/// ### Examples
 ///
-/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
-/// enables projecting of the input buffer to new sizes providing the new size is a
-/// multiple of the original size. When this buffer is projected, it is effectively
-/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+/// ```rust
+/// let mut a = [1.0, 2.0, 3.0, 4.0];
 ///
-/// ###### Masks
+/// cfavml::add_value_inplace(&mut a, 2.0);
+/// assert_eq!(a, [3.0, 4.0, 5.0, 6.0]);
+/// ```
 ///
-/// CFAVML follows the same pattern as numpy, which is it representing boolean results as
-/// either `1` or `0` in the respective type. This allows you to do various bit manipulation
-/// and arithmetic techniques for processing values within the vector.
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     a[i] = a[i] + value
+/// ```
+pub fn add_value_inplace<T>(a: &mut [T], value: T)
+where
+    T: ArithmeticOps,
+{
+    T::add_value_inplace(a, value)
+}
+
+#[inline]
+/// Performs an element wise subtraction of vectors `a` and `b` in place, writing the
+/// result back to `a`.
+///
+/// This avoids allocating a separate output buffer for update steps like `a -= b`.
 ///
 /// ### Examples
 ///
-/// ##### Two vectors
+/// ```rust
+/// let mut a = [1.0, 2.0, 3.0, 4.0];
+/// let b = [0.5, 0.5, 1.0, -1.0];
+///
+/// cfavml::sub_inplace(&mut a, &b);
+/// assert_eq!(a, [0.5, 1.5, 2.0, 5.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     a[i] = a[i] - b[i]
+/// ```
+///
+/// ### Panics
+///
+/// If vectors `a` and `b` are not equal in length.
+pub fn sub_inplace<T>(a: &mut [T], b: &[T])
+where
+    T: ArithmeticOps,
+{
+    unwrap_size(try_sub_inplace(a, b))
+}
+
+#[inline]
+/// Performs an element wise subtraction of vector `a` and a single broadcast `value`
+/// in place, writing the result back to `a`.
+///
+/// ### Examples
 ///
 /// ```rust
-/// let lhs = [1.0, 2.3, 2.0, 1.0];
-/// let rhs = [2.0, 0.7, 2.0, -2.0];
+/// let mut a = [1.0, 2.0, 3.0, 4.0];
 ///
-/// let mut mask = [0.0f32; 4];
-/// cfavml::eq_vertical(&lhs, &rhs, &mut mask);
-/// assert_eq!(mask, [0.0, 0.0, 1.0, 0.0]);   // Value at index 2 as equal!
+/// cfavml::sub_value_inplace(&mut a, 2.0);
+/// assert_eq!(a, [-1.0, 0.0, 1.0, 2.0]);
+/// ```
 ///
-/// // Now we can use it to zero any values that don't match.
-/// let mut match_or_zeroes = [0.0f32; 4];
-/// cfavml::mul_vertical(&lhs, &mask, &mut match_or_zeroes);
+/// ### Implementation Pseudocode
 ///
-/// // Our original match is extracted and the rest are `0.0`
-/// // For convenience, I've used `0.0` as the non-match value,
-/// // but if you switch `mask` and `lhs` around you can get
-/// // a `NaN` mask which may be more useful depending on application.
-/// assert_eq!(match_or_zeroes, [0.0, 0.0, 2.0, 0.0]);    
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     a[i] = a[i] - value
 /// ```
+pub fn sub_value_inplace<T>(a: &mut [T], value: T)
+where
+    T: ArithmeticOps,
+{
+    T::sub_value_inplace(a, value)
+}
+
+#[inline]
+/// Performs an element wise multiplication of vectors `a` and `b` in place, writing the
+/// result back to `a`.
 ///
-/// ##### One vector & broadcast value
+/// This avoids allocating a separate output buffer for update steps like `a *= b`.
+///
+/// ### Examples
 ///
 /// ```rust
-/// let lhs = [2.0, f32::NAN, -1.0, -0.5];
+/// let mut a = [1.0, 2.0, 3.0, 4.0];
+/// let b = [0.5, 0.5, 1.0, -1.0];
 ///
-/// let mut result = [0.0f32; 4];
-/// cfavml::eq_vertical(&lhs, -0.5, &mut result);
-/// assert_eq!(result, [0.0, 0.0, 0.0, 1.0]);  // NaN is always false on eq checks.
+/// cfavml::mul_inplace(&mut a, &b);
+/// assert_eq!(a, [0.5, 1.0, 3.0, -4.0]);
 /// ```
 ///
-/// ##### Two broadcast values
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     a[i] = a[i] * b[i]
+/// ```
+///
+/// ### Panics
+///
+/// If vectors `a` and `b` are not equal in length.
+pub fn mul_inplace<T>(a: &mut [T], b: &[T])
+where
+    T: ArithmeticOps,
+{
+    unwrap_size(try_mul_inplace(a, b))
+}
+
+#[inline]
+/// Performs an element wise multiplication of vector `a` and a single broadcast `value`
+/// in place, writing the result back to `a`.
+///
+/// ### Examples
 ///
 /// ```rust
-/// let mut result = [0.0f32; 4];
-/// cfavml::eq_vertical(1.0, -5.0, &mut result);
-/// assert_eq!(result, [0.0; 4]);
+/// let mut a = [1.0, 2.0, 3.0, 4.0];
+///
+/// cfavml::mul_value_inplace(&mut a, 2.0);
+/// assert_eq!(a, [2.0, 4.0, 6.0, 8.0]);
 /// ```
 ///
-/// ##### With projected vectors
+/// ### Implementation Pseudocode
 ///
-/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
-/// vectors than the original inputs, which can allow for much better performance and
-/// memory usage when working with matrices.
+/// _This is the logic of the routine being called._
 ///
-/// NOTE:
-/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
-/// how it behaves, it does not replace your matrix library's own broadcasting system.
+/// ```ignore
+/// for i in range(dims):
+///     a[i] = a[i] * value
+/// ```
+pub fn mul_value_inplace<T>(a: &mut [T], value: T)
+where
+    T: ArithmeticOps,
+{
+    T::mul_value_inplace(a, value)
+}
+
+#[inline]
+/// Performs an element wise division of vectors `a` and `b` in place, writing the
+/// result back to `a`.
+///
+/// This avoids allocating a separate output buffer for update steps like `a /= b`.
+///
+/// ### Examples
 ///
 /// ```rust
-/// use cfavml::mem_loader::Projected;
+/// let mut a = [1.0, 2.0, 3.0, 4.0];
+/// let b = [0.5, 0.5, 1.0, -1.0];
 ///
-/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
-/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+/// cfavml::div_inplace(&mut a, &b);
+/// assert_eq!(a, [2.0, 4.0, 3.0, -4.0]);
+/// ```
 ///
-/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
-/// cfavml::eq_vertical(Projected(&lhs), Projected(&rhs), &mut result);
-/// assert_eq!(result, [1.0, 0.0, 0.0, 0.0]);
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     a[i] = a[i] / b[i]
 /// ```
 ///
-/// ##### With `MaybeUninit`
+/// ### Panics
 ///
-/// Often if you are working with new-allocations, you do not want to initialize the data twice,
-/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
-/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+/// If vectors `a` and `b` are not equal in length.
+pub fn div_inplace<T>(a: &mut [T], b: &[T])
+where
+    T: ArithmeticOps,
+{
+    unwrap_size(try_div_inplace(a, b))
+}
+
+#[inline]
+/// Performs an element wise division of vector `a` and a single broadcast `value`
+/// in place, writing the result back to `a`.
+///
+/// ### Examples
 ///
 /// ```rust
-/// use core::mem::MaybeUninit;
+/// let mut a = [1.0, 2.0, 3.0, 4.0];
+///
+/// cfavml::div_value_inplace(&mut a, 2.0);
+/// assert_eq!(a, [0.5, 1.0, 1.5, 2.0]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     a[i] = a[i] / value
+/// ```
+pub fn div_value_inplace<T>(a: &mut [T], value: T)
+where
+    T: ArithmeticOps,
+{
+    T::div_value_inplace(a, value)
+}
+
+#[inline]
+/// Computes the outer product of the `n` element vector `a` and the `m` element vector `b`,
+/// writing the row-major `n x m` matrix to `result`.
 ///
-/// let lhs = [1.0, -1.0, 0.5, 1.0];
-/// let rhs = [1.0, 2.5, 0.5, -2.0];
+/// Each element of `a` is broadcast and multiplied against the whole of `b`, filling
+/// one row of `result` at a time. This is the building block of rank-1 updates.
 ///
-/// let mut result = Vec::with_capacity(4);
-/// unsafe { result.set_len(4) };
-/// cfavml::eq_vertical(&lhs, &rhs, &mut result);
+/// ### Examples
 ///
-/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
-/// assert_eq!(result, [1.0, 0.0, 1.0, 0.0]);
+/// ```rust
+/// let a = [1.0, 2.0];
+/// let b = [1.0, 0.5, -1.0];
+///
+/// let mut result = [0.0f32; 2 * 3];
+/// cfavml::outer_product(2, 3, &a, &b, &mut result);
+/// assert_eq!(result, [
+///     1.0, 0.5, -1.0,
+///     2.0, 1.0, -2.0,
+/// ]);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -770,71 +5238,39 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// mask = [0; dims]
+/// result = [0; n * m]
 ///
-/// for i in range(dims):
-///     mask[i] = a[i] == b[i] ? 1 : 0
+/// for i in range(n):
+///     for j in range(m):
+///         result[i * m + j] = a[i] * b[j]
 ///
-/// return mask
+/// return result
 /// ```
 ///
-/// ### Note on `NaN` handling on `f32/f64` types
-///
-/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
-/// Even when compared against each other.
-///
-/// - `0.0 == 0.0 -> true`
-/// - `0.0 == NaN -> false`
-/// - `NaN == NaN -> false`
-///
-/// # Panics
+/// ### Panics
 ///
-/// If vectors `a` and `b` cannot be projected to the target size of `result`.
-/// Note that the projection rules are tied to the `MemLoader` implementation.
-pub fn eq_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+/// If `a` is not exactly `n` elements in length, `b` is not exactly `m` elements
+/// in length or `result` is not exactly `n * m` elements in length.
+pub fn outer_product<T>(n: usize, m: usize, a: &[T], b: &[T], result: &mut [T])
 where
-    T: CmpOps,
-    B1: IntoMemLoader<T>,
-    B1::Loader: MemLoader<Value = T>,
-    B2: IntoMemLoader<T>,
-    B2::Loader: MemLoader<Value = T>,
-    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+    T: ArithmeticOps,
 {
-    T::eq_vertical(lhs, rhs, result)
+    unwrap_size(try_outer_product(n, m, a, b, result))
 }
 
-#[inline]
-/// Checks each element pair of elements from vectors `a` and `b` comparing if
-/// element `a` is **_not equal to_** element `b`, storing the output as `1` (true)
-/// or `0` (false) in `result`.
-///
-/// ### Things To Know
-///
-/// ###### Supported patterns
+/// Performs an element wise saturating addition of two input buffers `lhs` and `rhs` that can
+/// be projected to the desired output size of `result`.
 ///
-/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
-/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
-/// read from before executing. For the most part, you don't need to worry about this outside of
-/// knowing you can by default, pass one combination of:
-///     
-/// - `lhs: vector` and `rhs: vector`  
-/// - `lhs: vector` and `rhs: broadcast value`  
-/// - `lhs: broadcast value` and `rhs: vector`  
-/// - `lhs: broadcast value` and `rhs: broadcast value`  
-///   * Not really that useful and is just an artefact of the memory management system.
+/// This operation is only available for the integer types:
 ///
-/// ###### Broadcast values
+/// > `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
 ///
-/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
-/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
-/// outside the result buffer itself.
+/// ### Saturation
 ///
-/// This means the following is possible:
-/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `1 + 1 == [1]`  w/result_buffer_len=1
+/// Rather than wrapping on overflow, each result is clamped to the bounds of the type,
+/// matching the behaviour of [`saturating_add`](i16::saturating_add).
 ///
-/// ###### Projecting Vectors
+/// ### Projecting Vectors
 ///
 /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
 /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
@@ -846,97 +5282,27 @@ where
 /// that is larger their input sizes by default. This means providing two slices
 /// of `128` elements in length must take a result buffer of `128` elements in length.
 ///
-/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
-/// enables projecting of the input buffer to new sizes providing the new size is a
-/// multiple of the original size. When this buffer is projected, it is effectively
-/// repeated `N` times, where `N` is how many times the old size fits into the new size.
-///
-/// ###### Masks
-///
-/// CFAVML follows the same pattern as numpy, which is it representing boolean results as
-/// either `1` or `0` in the respective type. This allows you to do various bit manipulation
-/// and arithmetic techniques for processing values within the vector.
-///
 /// ### Examples
 ///
 /// ##### Two vectors
 ///
 /// ```rust
-/// let lhs = [1.0, 2.3, 2.0, 1.0];
-/// let rhs = [2.0, 0.7, 2.0, -2.0];
+/// let lhs = [i16::MAX, 100, i16::MIN, -5];
+/// let rhs = [1, 200, -1, 10];
 ///
-/// let mut mask = [0.0f32; 4];
-/// cfavml::neq_vertical(&lhs, &rhs, &mut mask);
-/// assert_eq!(mask, [1.0, 1.0, 0.0, 1.0]);   // All values except index 2 are not equal!
-///
-/// // Now we can use it to zero any values that are equal
-/// let mut match_or_zeroes = [0.0f32; 4];
-/// cfavml::mul_vertical(&lhs, &mask, &mut match_or_zeroes);
-///
-/// // Our original match is extracted and the rest are `0.0`
-/// // For convenience, I've used `0.0` as the non-match value,
-/// // but if you switch `mask` and `lhs` around you can get
-/// // a `NaN` mask which may be more useful depending on application.
-/// assert_eq!(match_or_zeroes, [1.0, 2.3, 0.0, 1.0]);    
+/// let mut result = [0i16; 4];
+/// cfavml::saturating_add_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [i16::MAX, 300, i16::MIN, 5]);
 /// ```
 ///
 /// ##### One vector & broadcast value
 ///
 /// ```rust
-/// let lhs = [2.0, f32::NAN, -1.0, -0.5];
-///
-/// let mut result = [0.0f32; 4];
-/// cfavml::neq_vertical(&lhs, -0.5, &mut result);
-/// assert_eq!(result, [1.0, 1.0, 1.0, 0.0]);  // NaN is always false on eq checks.
-/// ```
-///
-/// ##### Two broadcast values
-///
-/// ```rust
-/// let mut result = [0.0f32; 4];
-/// cfavml::neq_vertical(1.0, -5.0, &mut result);
-/// assert_eq!(result, [1.0; 4]);
-/// ```
-///
-/// ##### With projected vectors
-///
-/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
-/// vectors than the original inputs, which can allow for much better performance and
-/// memory usage when working with matrices.
-///
-/// NOTE:
-/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
-/// how it behaves, it does not replace your matrix library's own broadcasting system.
-///
-/// ```rust
-/// use cfavml::mem_loader::Projected;
-///
-/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
-/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
-///
-/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
-/// cfavml::neq_vertical(Projected(&lhs), Projected(&rhs), &mut result);
-/// assert_eq!(result, [0.0, 1.0, 1.0, 1.0]);
-/// ```
-///
-/// ##### With `MaybeUninit`
-///
-/// Often if you are working with new-allocations, you do not want to initialize the data twice,
-/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
-/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
-///
-/// ```rust
-/// use core::mem::MaybeUninit;
-///
-/// let lhs = [1.0, -1.0, 0.5, 1.0];
-/// let rhs = [1.0, 2.5, 0.5, -2.0];
-///
-/// let mut result = Vec::with_capacity(4);
-/// unsafe { result.set_len(4) };
-/// cfavml::neq_vertical(&lhs, &rhs, &mut result);
+/// let lhs = [0, 100, 200, 255];
 ///
-/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
-/// assert_eq!(result, [0.0, 1.0, 0.0, 1.0]);
+/// let mut result = [0u8; 4];
+/// cfavml::saturating_add_vertical(&lhs, 100, &mut result);
+/// assert_eq!(result, [100, 200, 255, 255]);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -944,71 +5310,43 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// mask = [0; dims]
+/// result = [0; dims]
 ///
 /// for i in range(dims):
-///     mask[i] = a[i] != b[i] ? 1 : 0
+///     result[i] = clamp(a[i] + b[i], T::MIN, T::MAX)
 ///
-/// return mask
+/// return result
 /// ```
 ///
-/// ### Note on `NaN` handling on `f32/f64` types
-///
-/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
-/// Even when compared against each other.
-///
-/// - `0.0 != 1.0 -> true`
-/// - `0.0 != NaN -> true`
-/// - `NaN != NaN -> true`
-///
 /// # Panics
 ///
 /// If vectors `a` and `b` cannot be projected to the target size of `result`.
 /// Note that the projection rules are tied to the `MemLoader` implementation.
-pub fn neq_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+pub fn saturating_add_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
 where
-    T: CmpOps,
+    T: SaturatingArithmeticOps,
     B1: IntoMemLoader<T>,
     B1::Loader: MemLoader<Value = T>,
     B2: IntoMemLoader<T>,
     B2::Loader: MemLoader<Value = T>,
     for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
 {
-    T::neq_vertical(lhs, rhs, result)
+    T::saturating_add_vertical(lhs, rhs, result)
 }
 
-#[inline]
-/// Checks each element pair of elements from vectors `a` and `b` comparing if
-/// element `a` is **_less than_** element `b`, storing the output as `1` (true)
-/// or `0` (false) in `result`.
-///
-/// ### Things To Know
-///
-/// ###### Supported patterns
+/// Performs an element wise saturating subtraction of input buffer `rhs` from `lhs`, where
+/// both can be projected to the desired output size of `result`.
 ///
-/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
-/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
-/// read from before executing. For the most part, you don't need to worry about this outside of
-/// knowing you can by default, pass one combination of:
-///     
-/// - `lhs: vector` and `rhs: vector`  
-/// - `lhs: vector` and `rhs: broadcast value`  
-/// - `lhs: broadcast value` and `rhs: vector`  
-/// - `lhs: broadcast value` and `rhs: broadcast value`  
-///   * Not really that useful and is just an artefact of the memory management system.
+/// This operation is only available for the integer types:
 ///
-/// ###### Broadcast values
+/// > `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
 ///
-/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
-/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
-/// outside the result buffer itself.
+/// ### Saturation
 ///
-/// This means the following is possible:
-/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `1 + 1 == [1]`  w/result_buffer_len=1
+/// Rather than wrapping on overflow, each result is clamped to the bounds of the type,
+/// matching the behaviour of [`saturating_sub`](i16::saturating_sub).
 ///
-/// ###### Projecting Vectors
+/// ### Projecting Vectors
 ///
 /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
 /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
@@ -1020,97 +5358,87 @@ where
 /// that is larger their input sizes by default. This means providing two slices
 /// of `128` elements in length must take a result buffer of `128` elements in length.
 ///
-/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
-/// enables projecting of the input buffer to new sizes providing the new size is a
-/// multiple of the original size. When this buffer is projected, it is effectively
-/// repeated `N` times, where `N` is how many times the old size fits into the new size.
-///
-/// ###### Masks
-///
-/// CFAVML follows the same pattern as numpy, which is it representing boolean results as
-/// either `1` or `0` in the respective type. This allows you to do various bit manipulation
-/// and arithmetic techniques for processing values within the vector.
-///
 /// ### Examples
 ///
 /// ##### Two vectors
 ///
 /// ```rust
-/// let lhs = [-1.0, 2.3, 2.0, 1.0];
-/// let rhs = [2.0, 0.7, 2.0, -2.0];
-///
-/// let mut mask = [0.0f32; 4];
-/// cfavml::lt_vertical(&lhs, &rhs, &mut mask);
-/// assert_eq!(mask, [1.0, 0.0, 0.0, 0.0]);  // Only index 0 of lhs is less than the rhs.
-///
-/// // Now we can use it to zero any values that greater than or equal.
-/// let mut match_or_zeroes = [0.0f32; 4];
-/// cfavml::mul_vertical(&lhs, &mask, &mut match_or_zeroes);
+/// let lhs = [i16::MIN, 100, i16::MAX, -5];
+/// let rhs = [1, 200, -1, 10];
 ///
-/// // Our original match is extracted and the rest are `0.0`
-/// // For convenience, I've used `0.0` as the non-match value,
-/// // but if you switch `mask` and `lhs` around you can get
-/// // a `NaN` mask which may be more useful depending on application.
-/// assert_eq!(match_or_zeroes, [-1.0, 0.0, 0.0, 0.0]);    
+/// let mut result = [0i16; 4];
+/// cfavml::saturating_sub_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [i16::MIN, -100, i16::MAX, -15]);
 /// ```
 ///
 /// ##### One vector & broadcast value
 ///
 /// ```rust
-/// let lhs = [2.0, f32::NAN, -1.0, -0.5];
+/// let lhs = [0, 100, 200, 255];
 ///
-/// let mut result = [0.0f32; 4];
-/// cfavml::lt_vertical(&lhs, -0.5, &mut result);
-/// assert_eq!(result, [0.0, 0.0, 1.0, 0.0]);  // NaN is always false on lt checks.
+/// let mut result = [0u8; 4];
+/// cfavml::saturating_sub_vertical(&lhs, 150, &mut result);
+/// assert_eq!(result, [0, 0, 50, 105]);
 /// ```
 ///
-/// ##### Two broadcast values
+/// ### Implementation Pseudocode
 ///
-/// ```rust
-/// let mut result = [0.0f32; 4];
-/// cfavml::lt_vertical(-1.0, 5.0, &mut result);
-/// assert_eq!(result, [1.0; 4]);
-/// ```
+/// _This is the logic of the routine being called._
 ///
-/// ##### With projected vectors
+/// ```ignore
+/// result = [0; dims]
 ///
-/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
-/// vectors than the original inputs, which can allow for much better performance and
-/// memory usage when working with matrices.
+/// for i in range(dims):
+///     result[i] = clamp(a[i] - b[i], T::MIN, T::MAX)
 ///
-/// NOTE:
-/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
-/// how it behaves, it does not replace your matrix library's own broadcasting system.
+/// return result
+/// ```
 ///
-/// ```rust
-/// use cfavml::mem_loader::Projected;
+/// # Panics
+///
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn saturating_sub_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: SaturatingArithmeticOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::saturating_sub_vertical(lhs, rhs, result)
+}
+
+/// Performs an element wise negation of the input buffer `a` that can
+/// be projected to the desired output size of `result`.
 ///
-/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
-/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+/// Integer types wrap on overflow matching `wrapping_neg`, so negating `MIN` returns `MIN`.
+/// Float types flip the sign bit, so `0.0` negates to `-0.0` and `NaN` values have their
+/// sign flipped, matching scalar negation.
 ///
-/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
-/// cfavml::lt_vertical(Projected(&lhs), Projected(&rhs), &mut result);
-/// assert_eq!(result, [0.0, 1.0, 1.0, 0.0]);
-/// ```
+/// This operation is only available for the signed integer and float types:
 ///
-/// ##### With `MaybeUninit`
+/// > `f32`, `f64`, `i8`, `i16`, `i32`, `i64`
 ///
-/// Often if you are working with new-allocations, you do not want to initialize the data twice,
-/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
-/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+/// ### Examples
 ///
 /// ```rust
-/// use core::mem::MaybeUninit;
+/// let a = [1.5f32, -2.0, 0.0, -0.0];
 ///
-/// let lhs = [1.0, -1.0, 0.5, 1.0];
-/// let rhs = [1.0, 2.5, 0.5, -2.0];
+/// let mut result = [0.0f32; 4];
+/// cfavml::neg_vertical(&a, &mut result);
+/// assert_eq!(result, [-1.5, 2.0, -0.0, 0.0]);
+/// assert!(result[2].is_sign_negative());
+/// assert!(result[3].is_sign_positive());
+/// ```
 ///
-/// let mut result = Vec::with_capacity(4);
-/// unsafe { result.set_len(4) };
-/// cfavml::lt_vertical(&lhs, &rhs, &mut result);
+/// ```rust
+/// let a = [1i8, -100, 0, i8::MIN];
 ///
-/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
-/// assert_eq!(result, [0.0, 1.0, 0.0, 0.0]);
+/// let mut result = [0i8; 4];
+/// cfavml::neg_vertical(&a, &mut result);
+/// assert_eq!(result, [-1, 100, 0, i8::MIN]);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -1118,72 +5446,36 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// mask = [0; dims]
+/// result = [0; dims]
 ///
 /// for i in range(dims):
-///     mask[i] = a[i] < b[i] ? 1 : 0
+///     result[i] = -a[i]
 ///
-/// return mask
+/// return result
 /// ```
 ///
-/// ### Note on `NaN` handling on `f32/f64` types
-///
-/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
-/// Even when compared against each other.
-///
-/// - `0.0 < 1.0 -> true`
-/// - `0.0 < NaN -> false`
-/// - `NaN < 1.0 -> false`
-/// - `NaN < NaN -> false`
-///
 /// # Panics
 ///
-/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// If vector `a` cannot be projected to the target size of `result`.
 /// Note that the projection rules are tied to the `MemLoader` implementation.
-pub fn lt_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+pub fn neg_vertical<T, B1, B2>(a: B1, result: &mut [B2])
 where
-    T: CmpOps,
+    T: NegOps,
     B1: IntoMemLoader<T>,
     B1::Loader: MemLoader<Value = T>,
-    B2: IntoMemLoader<T>,
-    B2::Loader: MemLoader<Value = T>,
-    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
 {
-    T::lt_vertical(lhs, rhs, result)
+    T::neg_vertical(a, result)
 }
 
-#[inline]
-/// Checks each element pair of elements from vectors `a` and `b` comparing if
-/// element `a` is **_less than or equal to_** element `b`, storing the output as `1` (true)
-/// or `0` (false) in `result`.
-///
-/// ### Things To Know
-///
-/// ###### Supported patterns
-///
-/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
-/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
-/// read from before executing. For the most part, you don't need to worry about this outside of
-/// knowing you can by default, pass one combination of:
-///     
-/// - `lhs: vector` and `rhs: vector`  
-/// - `lhs: vector` and `rhs: broadcast value`  
-/// - `lhs: broadcast value` and `rhs: vector`  
-/// - `lhs: broadcast value` and `rhs: broadcast value`  
-///   * Not really that useful and is just an artefact of the memory management system.
-///
-/// ###### Broadcast values
+/// Performs an element wise bitwise _and_ of two input buffers `a` and `b` that can
+/// be projected to the desired output size of `result`.
 ///
-/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
-/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
-/// outside the result buffer itself.
+/// This operation is only available for the integer types:
 ///
-/// This means the following is possible:
-/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `1 + 1 == [1]`  w/result_buffer_len=1
+/// > `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
 ///
-/// ###### Projecting Vectors
+/// ### Projecting Vectors
 ///
 /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
 /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
@@ -1195,97 +5487,98 @@ where
 /// that is larger their input sizes by default. This means providing two slices
 /// of `128` elements in length must take a result buffer of `128` elements in length.
 ///
-/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
-/// enables projecting of the input buffer to new sizes providing the new size is a
-/// multiple of the original size. When this buffer is projected, it is effectively
-/// repeated `N` times, where `N` is how many times the old size fits into the new size.
-///
-/// ###### Masks
-///
-/// CFAVML follows the same pattern as numpy, which is it representing boolean results as
-/// either `1` or `0` in the respective type. This allows you to do various bit manipulation
-/// and arithmetic techniques for processing values within the vector.
-///
 /// ### Examples
 ///
 /// ##### Two vectors
 ///
 /// ```rust
-/// let lhs = [-1.0, 2.3, 2.0, 1.0];
-/// let rhs = [2.0, 0.7, 2.0, -2.0];
-///
-/// let mut mask = [0.0f32; 4];
-/// cfavml::lte_vertical(&lhs, &rhs, &mut mask);
-/// assert_eq!(mask, [1.0, 0.0, 1.0, 0.0]);  // Index 0 & 2 are lte to rhs.
-///
-/// // Now we can use it to zero any values that are greater.
-/// let mut match_or_zeroes = [0.0f32; 4];
-/// cfavml::mul_vertical(&lhs, &mask, &mut match_or_zeroes);
+/// let lhs = [0b1100, 0b1010, 0b1111, 0b0000];
+/// let rhs = [0b1010, 0b1010, 0b0000, 0b0101];
 ///
-/// // Our original match is extracted and the rest are `0.0`
-/// // For convenience, I've used `0.0` as the non-match value,
-/// // but if you switch `mask` and `lhs` around you can get
-/// // a `NaN` mask which may be more useful depending on application.
-/// assert_eq!(match_or_zeroes, [-1.0, 0.0, 2.0, 0.0]);    
+/// let mut result = [0u32; 4];
+/// cfavml::bitand_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [0b1000, 0b1010, 0b0000, 0b0000]);
 /// ```
 ///
 /// ##### One vector & broadcast value
 ///
 /// ```rust
-/// let lhs = [2.0, f32::NAN, -1.0, -0.5];
+/// let lhs = [0b1100, 0b1010, 0b1111, 0b0000];
 ///
-/// let mut result = [0.0f32; 4];
-/// cfavml::lte_vertical(&lhs, -0.5, &mut result);
-/// assert_eq!(result, [0.0, 0.0, 1.0, 1.0]);  // NaN is always false on lte checks.
+/// let mut result = [0u32; 4];
+/// cfavml::bitand_vertical(&lhs, 0b0110, &mut result);
+/// assert_eq!(result, [0b0100, 0b0010, 0b0110, 0b0000]);
 /// ```
 ///
-/// ##### Two broadcast values
+/// ### Implementation Pseudocode
 ///
-/// ```rust
-/// let mut result = [0.0f32; 4];
-/// cfavml::lte_vertical(5.0, 5.0, &mut result);
-/// assert_eq!(result, [1.0; 4]);
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = [0; dims]
+///
+/// for i in range(dims):
+///     result[i] = a[i] & b[i]
+///
+/// return result
 /// ```
 ///
-/// ##### With projected vectors
+/// # Panics
 ///
-/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
-/// vectors than the original inputs, which can allow for much better performance and
-/// memory usage when working with matrices.
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn bitand_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: BitwiseOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::bitand_vertical(lhs, rhs, result)
+}
+
+/// Performs an element wise bitwise _or_ of two input buffers `a` and `b` that can
+/// be projected to the desired output size of `result`.
 ///
-/// NOTE:
-/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
-/// how it behaves, it does not replace your matrix library's own broadcasting system.
+/// This operation is only available for the integer types:
 ///
-/// ```rust
-/// use cfavml::mem_loader::Projected;
+/// > `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
 ///
-/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
-/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+/// ### Projecting Vectors
 ///
-/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
-/// cfavml::lte_vertical(Projected(&lhs), Projected(&rhs), &mut result);
-/// assert_eq!(result, [1.0, 1.0, 1.0, 0.0]);
-/// ```
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
 ///
-/// ##### With `MaybeUninit`
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
 ///
-/// Often if you are working with new-allocations, you do not want to initialize the data twice,
-/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
-/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// ### Examples
+///
+/// ##### Two vectors
 ///
 /// ```rust
-/// use core::mem::MaybeUninit;
+/// let lhs = [0b1100, 0b1010, 0b1111, 0b0000];
+/// let rhs = [0b1010, 0b1010, 0b0000, 0b0101];
 ///
-/// let lhs = [1.0, -1.0, 0.5, 1.0];
-/// let rhs = [1.0, 2.5, 0.5, -2.0];
+/// let mut result = [0u32; 4];
+/// cfavml::bitor_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [0b1110, 0b1010, 0b1111, 0b0101]);
+/// ```
 ///
-/// let mut result = Vec::with_capacity(4);
-/// unsafe { result.set_len(4) };
-/// cfavml::lte_vertical(&lhs, &rhs, &mut result);
+/// ##### One vector & broadcast value
 ///
-/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
-/// assert_eq!(result, [1.0, 1.0, 1.0, 0.0]);
+/// ```rust
+/// let lhs = [0b1100, 0b1010, 0b1111, 0b0000];
+///
+/// let mut result = [0u32; 4];
+/// cfavml::bitor_vertical(&lhs, 0b0001, &mut result);
+/// assert_eq!(result, [0b1101, 0b1011, 0b1111, 0b0001]);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -1293,73 +5586,38 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// mask = [0; dims]
+/// result = [0; dims]
 ///
 /// for i in range(dims):
-///     mask[i] = a[i] <= b[i] ? 1 : 0
+///     result[i] = a[i] | b[i]
 ///
-/// return mask
+/// return result
 /// ```
 ///
-/// ### Note on `NaN` handling on `f32/f64` types
-///
-/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
-/// Even when compared against each other.
-///
-/// - `0.0 <= 1.0 -> true`
-/// - `1.0 <= 1.0 -> true`
-/// - `0.0 <= NaN -> false`
-/// - `NaN <= 1.0 -> false`
-/// - `NaN <= NaN -> false`
-///
 /// # Panics
 ///
 /// If vectors `a` and `b` cannot be projected to the target size of `result`.
 /// Note that the projection rules are tied to the `MemLoader` implementation.
-pub fn lte_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+pub fn bitor_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
 where
-    T: CmpOps,
+    T: BitwiseOps,
     B1: IntoMemLoader<T>,
     B1::Loader: MemLoader<Value = T>,
     B2: IntoMemLoader<T>,
     B2::Loader: MemLoader<Value = T>,
     for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
 {
-    T::lte_vertical(lhs, rhs, result)
+    T::bitor_vertical(lhs, rhs, result)
 }
 
-#[inline]
-/// Checks each element pair of elements from vectors `a` and `b` comparing if
-/// element `a` is **_greater than_** element `b`, storing the output as `1` (true) or `0` (false)
-/// in `result`.
-///
-/// ### Things To Know
-///
-/// ###### Supported patterns
-///
-/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
-/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
-/// read from before executing. For the most part, you don't need to worry about this outside of
-/// knowing you can by default, pass one combination of:
-///     
-/// - `lhs: vector` and `rhs: vector`  
-/// - `lhs: vector` and `rhs: broadcast value`  
-/// - `lhs: broadcast value` and `rhs: vector`  
-/// - `lhs: broadcast value` and `rhs: broadcast value`  
-///   * Not really that useful and is just an artefact of the memory management system.
-///
-/// ###### Broadcast values
+/// Performs an element wise bitwise _xor_ of two input buffers `a` and `b` that can
+/// be projected to the desired output size of `result`.
 ///
-/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
-/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
-/// outside the result buffer itself.
+/// This operation is only available for the integer types:
 ///
-/// This means the following is possible:
-/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `1 + 1 == [1]`  w/result_buffer_len=1
+/// > `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
 ///
-/// ###### Projecting Vectors
+/// ### Projecting Vectors
 ///
 /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
 /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
@@ -1371,97 +5629,73 @@ where
 /// that is larger their input sizes by default. This means providing two slices
 /// of `128` elements in length must take a result buffer of `128` elements in length.
 ///
-/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
-/// enables projecting of the input buffer to new sizes providing the new size is a
-/// multiple of the original size. When this buffer is projected, it is effectively
-/// repeated `N` times, where `N` is how many times the old size fits into the new size.
-///
-/// ###### Masks
-///
-/// CFAVML follows the same pattern as numpy, which is it representing boolean results as
-/// either `1` or `0` in the respective type. This allows you to do various bit manipulation
-/// and arithmetic techniques for processing values within the vector.
-///
-/// ### Examples
-///
-/// ##### Two vectors
-///
-/// ```rust
-/// let lhs = [-1.0, 2.3, 2.0, 1.0];
-/// let rhs = [2.0, 0.7, 2.0, -2.0];
-///
-/// let mut mask = [0.0f32; 4];
-/// cfavml::gt_vertical(&lhs, &rhs, &mut mask);
-/// assert_eq!(mask, [0.0, 1.0, 0.0, 1.0]);  // Index 1 & 3 are greater than the rhs.
-///
-/// // Now we can use it to zero any values that are less than or equal.
-/// let mut match_or_zeroes = [0.0f32; 4];
-/// cfavml::mul_vertical(&lhs, &mask, &mut match_or_zeroes);
-///
-/// // Our original match is extracted and the rest are `0.0`
-/// // For convenience, I've used `0.0` as the non-match value,
-/// // but if you switch `mask` and `lhs` around you can get
-/// // a `NaN` mask which may be more useful depending on application.
-/// assert_eq!(match_or_zeroes, [0.0, 2.3, 0.0, 1.0]);    
-/// ```
-///
-/// ##### One vector & broadcast value
+/// ### Examples
+///
+/// ##### Two vectors
 ///
 /// ```rust
-/// let lhs = [2.0, f32::NAN, -1.0, -0.5];
+/// let lhs = [0b1100, 0b1010, 0b1111, 0b0000];
+/// let rhs = [0b1010, 0b1010, 0b0000, 0b0101];
 ///
-/// let mut result = [0.0f32; 4];
-/// cfavml::gt_vertical(&lhs, -0.5, &mut result);
-/// assert_eq!(result, [1.0, 0.0, 0.0, 0.0]);  // NaN is always false on gt checks.
+/// let mut result = [0u32; 4];
+/// cfavml::bitxor_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [0b0110, 0b0000, 0b1111, 0b0101]);
 /// ```
 ///
-/// ##### Two broadcast values
+/// ##### One vector & broadcast value
 ///
 /// ```rust
-/// let mut result = [0.0f32; 4];
-/// cfavml::gt_vertical(6.0, 5.0, &mut result);
-/// assert_eq!(result, [1.0; 4]);
-/// ```
+/// let lhs = [0b1100, 0b1010, 0b1111, 0b0000];
 ///
-/// ##### With projected vectors
+/// let mut result = [0u32; 4];
+/// cfavml::bitxor_vertical(&lhs, 0b1111, &mut result);
+/// assert_eq!(result, [0b0011, 0b0101, 0b0000, 0b1111]);
+/// ```
 ///
-/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
-/// vectors than the original inputs, which can allow for much better performance and
-/// memory usage when working with matrices.
+/// ### Implementation Pseudocode
 ///
-/// NOTE:
-/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
-/// how it behaves, it does not replace your matrix library's own broadcasting system.
+/// _This is the logic of the routine being called._
 ///
-/// ```rust
-/// use cfavml::mem_loader::Projected;
+/// ```ignore
+/// result = [0; dims]
 ///
-/// let lhs = [1.0, 1.0, 0.5, 3.5];    // Pretend this is a 2x2 matrix
-/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+/// for i in range(dims):
+///     result[i] = a[i] ^ b[i]
 ///
-/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
-/// cfavml::gt_vertical(Projected(&lhs), Projected(&rhs), &mut result);
-/// assert_eq!(result, [0.0, 0.0, 0.0, 1.0]);
+/// return result
 /// ```
 ///
-/// ##### With `MaybeUninit`
+/// # Panics
 ///
-/// Often if you are working with new-allocations, you do not want to initialize the data twice,
-/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
-/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn bitxor_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: BitwiseOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::bitxor_vertical(lhs, rhs, result)
+}
+
+/// Performs an element wise bitwise _not_ of the input buffer `a` that can
+/// be projected to the desired output size of `result`, flipping every bit.
 ///
-/// ```rust
-/// use core::mem::MaybeUninit;
+/// This operation is only available for the integer types:
 ///
-/// let lhs = [1.0, -1.0, 0.5, 1.0];
-/// let rhs = [1.0, 2.5, 0.5, -2.0];
+/// > `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
 ///
-/// let mut result = Vec::with_capacity(4);
-/// unsafe { result.set_len(4) };
-/// cfavml::gt_vertical(&lhs, &rhs, &mut result);
+/// ### Examples
 ///
-/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
-/// assert_eq!(result, [0.0, 0.0, 0.0, 1.0]);
+/// ```rust
+/// let a = [0b1100_1100u8, 0b1111_0000, 0, u8::MAX];
+///
+/// let mut result = [0u8; 4];
+/// cfavml::bitnot_vertical(&a, &mut result);
+/// assert_eq!(result, [0b0011_0011, 0b0000_1111, u8::MAX, 0]);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -1469,72 +5703,42 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// mask = [0; dims]
+/// result = [0; dims]
 ///
 /// for i in range(dims):
-///     mask[i] = a[i] > b[i] ? 1 : 0
+///     result[i] = !a[i]
 ///
-/// return mask
+/// return result
 /// ```
 ///
-/// ### Note on `NaN` handling on `f32/f64` types
-///
-/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
-/// Even when compared against each other.
-///
-/// - `1.0 > 0.0 -> true`
-/// - `1.0 > NaN -> false`
-/// - `NaN > 1.0 -> false`
-/// - `NaN > NaN -> false`
-///
 /// # Panics
 ///
-/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// If vector `a` cannot be projected to the target size of `result`.
 /// Note that the projection rules are tied to the `MemLoader` implementation.
-pub fn gt_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+pub fn bitnot_vertical<T, B1, B2>(a: B1, result: &mut [B2])
 where
-    T: CmpOps,
+    T: BitwiseOps,
     B1: IntoMemLoader<T>,
     B1::Loader: MemLoader<Value = T>,
-    B2: IntoMemLoader<T>,
-    B2::Loader: MemLoader<Value = T>,
-    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
 {
-    T::gt_vertical(lhs, rhs, result)
+    T::bitnot_vertical(a, result)
 }
 
-#[inline]
-/// Checks each element pair of elements from vectors `a` and `b` comparing if
-/// element `a` is **_greater than or equal to_** element `b`, storing the output as `1` (true)
-/// or `0` (false) in `result`.
-///
-/// ### Things To Know
-///
-/// ###### Supported patterns
+/// Performs an element wise _shift left_ of input buffer `lhs` by the amounts in `rhs`,
+/// where both can be projected to the desired output size of `result`.
 ///
-/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
-/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
-/// read from before executing. For the most part, you don't need to worry about this outside of
-/// knowing you can by default, pass one combination of:
-///     
-/// - `lhs: vector` and `rhs: vector`  
-/// - `lhs: vector` and `rhs: broadcast value`  
-/// - `lhs: broadcast value` and `rhs: vector`  
-/// - `lhs: broadcast value` and `rhs: broadcast value`  
-///   * Not really that useful and is just an artefact of the memory management system.
+/// This operation is only available for the integer types:
 ///
-/// ###### Broadcast values
+/// > `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
 ///
-/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
-/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
-/// outside the result buffer itself.
+/// ### Shift Amounts
 ///
-/// This means the following is possible:
-/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `1 + 1 == [1]`  w/result_buffer_len=1
+/// The shift amount is masked to the bit width of the type, matching the behaviour
+/// of [`wrapping_shl`](u32::wrapping_shl). This means shifting a `u32` by `32` leaves
+/// the value unchanged, and shifting it by `33` is the same as shifting it by `1`.
 ///
-/// ###### Projecting Vectors
+/// ### Projecting Vectors
 ///
 /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
 /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
@@ -1546,97 +5750,107 @@ where
 /// that is larger their input sizes by default. This means providing two slices
 /// of `128` elements in length must take a result buffer of `128` elements in length.
 ///
-/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
-/// enables projecting of the input buffer to new sizes providing the new size is a
-/// multiple of the original size. When this buffer is projected, it is effectively
-/// repeated `N` times, where `N` is how many times the old size fits into the new size.
-///
-/// ###### Masks
-///
-/// CFAVML follows the same pattern as numpy, which is it representing boolean results as
-/// either `1` or `0` in the respective type. This allows you to do various bit manipulation
-/// and arithmetic techniques for processing values within the vector.
-///
 /// ### Examples
 ///
 /// ##### Two vectors
 ///
 /// ```rust
-/// let lhs = [-1.0, 2.3, 2.0, 1.0];
-/// let rhs = [2.0, 0.7, 2.0, -2.0];
-///
-/// let mut mask = [0.0f32; 4];
-/// cfavml::gte_vertical(&lhs, &rhs, &mut mask);
-/// assert_eq!(mask, [0.0, 1.0, 1.0, 1.0]);  // Index 1, 2 & 3 are greater than or eq to rhs.
-///
-/// // Now we can use it to zero any values that are less than the rhs.
-/// let mut match_or_zeroes = [0.0f32; 4];
-/// cfavml::mul_vertical(&lhs, &mask, &mut match_or_zeroes);
+/// let lhs = [1, 1, 1, 1];
+/// let rhs = [0, 4, 32, 33];
 ///
-/// // Our original match is extracted and the rest are `0.0`
-/// // For convenience, I've used `0.0` as the non-match value,
-/// // but if you switch `mask` and `lhs` around you can get
-/// // a `NaN` mask which may be more useful depending on application.
-/// assert_eq!(match_or_zeroes, [0.0, 2.3, 2.0, 1.0]);    
+/// let mut result = [0u32; 4];
+/// cfavml::shl_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [1, 16, 1, 2]);
 /// ```
 ///
 /// ##### One vector & broadcast value
 ///
 /// ```rust
-/// let lhs = [2.0, f32::NAN, -1.0, -0.5];
+/// let lhs = [0b0001, 0b0011, 0b0101, 0b1000];
 ///
-/// let mut result = [0.0f32; 4];
-/// cfavml::gte_vertical(&lhs, -0.5, &mut result);
-/// assert_eq!(result, [1.0, 0.0, 0.0, 1.0]);  // NaN is always false on gte checks.
+/// let mut result = [0u8; 4];
+/// cfavml::shl_vertical(&lhs, 2, &mut result);
+/// assert_eq!(result, [0b0100, 0b1100, 0b1_0100, 0b10_0000]);
 /// ```
 ///
-/// ##### Two broadcast values
+/// ### Implementation Pseudocode
 ///
-/// ```rust
-/// let mut result = [0.0f32; 4];
-/// cfavml::gte_vertical(5.0, 5.0, &mut result);
-/// assert_eq!(result, [1.0; 4]);
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// result = [0; dims]
+///
+/// for i in range(dims):
+///     result[i] = a[i] << (b[i] & (BITS - 1))
+///
+/// return result
 /// ```
 ///
-/// ##### With projected vectors
+/// # Panics
 ///
-/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
-/// vectors than the original inputs, which can allow for much better performance and
-/// memory usage when working with matrices.
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn shl_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: BitwiseOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::shl_vertical(lhs, rhs, result)
+}
+
+/// Performs an element wise _shift right_ of input buffer `lhs` by the amounts in `rhs`,
+/// where both can be projected to the desired output size of `result`.
 ///
-/// NOTE:
-/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
-/// how it behaves, it does not replace your matrix library's own broadcasting system.
+/// This operation is only available for the integer types:
 ///
-/// ```rust
-/// use cfavml::mem_loader::Projected;
+/// > `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`
 ///
-/// let lhs = [1.0, 1.0, 0.5, 1.0];    // Pretend this is a 2x2 matrix
-/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+/// Signed types perform an _arithmetic_ shift which extends the sign bit, unsigned
+/// types perform a _logical_ shift which shifts in zeroes.
 ///
-/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
-/// cfavml::gte_vertical(Projected(&lhs), Projected(&rhs), &mut result);
-/// assert_eq!(result, [1.0, 0.0, 0.0, 0.0]);
-/// ```
+/// ### Shift Amounts
 ///
-/// ##### With `MaybeUninit`
+/// The shift amount is masked to the bit width of the type, matching the behaviour
+/// of [`wrapping_shr`](u32::wrapping_shr). This means shifting a `u32` by `32` leaves
+/// the value unchanged, and shifting it by `33` is the same as shifting it by `1`.
 ///
-/// Often if you are working with new-allocations, you do not want to initialize the data twice,
-/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
-/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+/// ### Projecting Vectors
+///
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+///
+/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+/// which exhibit the standard behaviour as you might expect.
+///
+/// When providing two slices as inputs they cannot be projected to a buffer
+/// that is larger their input sizes by default. This means providing two slices
+/// of `128` elements in length must take a result buffer of `128` elements in length.
+///
+/// ### Examples
+///
+/// ##### Two vectors
 ///
 /// ```rust
-/// use core::mem::MaybeUninit;
+/// let lhs = [-16, -16, 16, 16];
+/// let rhs = [2, 32, 2, 33];
 ///
-/// let lhs = [1.0, -1.0, 0.5, 1.0];
-/// let rhs = [1.0, 2.5, 0.5, -2.0];
+/// let mut result = [0i32; 4];
+/// cfavml::shr_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result, [-4, -16, 4, 8]);
+/// ```
 ///
-/// let mut result = Vec::with_capacity(4);
-/// unsafe { result.set_len(4) };
-/// cfavml::gte_vertical(&lhs, &rhs, &mut result);
+/// ##### One vector & broadcast value
 ///
-/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
-/// assert_eq!(result, [1.0, 0.0, 1.0, 1.0]);
+/// ```rust
+/// let lhs = [0b1000_0000, 0b0100_0000, 0b0000_0011, u8::MAX];
+///
+/// let mut result = [0u8; 4];
+/// cfavml::shr_vertical(&lhs, 1, &mut result);
+/// assert_eq!(result, [0b0100_0000, 0b0010_0000, 0b0000_0001, 0b0111_1111]);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -1644,70 +5858,44 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// mask = [0; dims]
+/// result = [0; dims]
 ///
 /// for i in range(dims):
-///     mask[i] = a[i] >= b[i] ? 1 : 0
+///     result[i] = a[i] >> (b[i] & (BITS - 1))
 ///
-/// return mask
+/// return result
 /// ```
 ///
-/// ### Note on `NaN` handling on `f32/f64` types
-///
-/// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
-/// Even when compared against each other.
-///
-/// - `1.0 >= 0.0 -> true`
-/// - `1.0 >= NaN -> false`
-/// - `NaN >= 1.0 -> false`
-/// - `NaN >= NaN -> false`
-///
 /// # Panics
 ///
 /// If vectors `a` and `b` cannot be projected to the target size of `result`.
 /// Note that the projection rules are tied to the `MemLoader` implementation.
-pub fn gte_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+pub fn shr_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
 where
-    T: CmpOps,
-    B1: IntoMemLoader<T>,
-    B1::Loader: MemLoader<Value = T>,
-    B2: IntoMemLoader<T>,
-    B2::Loader: MemLoader<Value = T>,
-    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
-{
-    T::gte_vertical(lhs, rhs, result)
-}
-
-/// Performs an element wise addition of two input buffers `a` and `b` that can
-/// be projected to the desired output size of `result`.
-///
-/// ### Things To Know
-///
-/// ###### Supported patterns
-///
-/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
-/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
-/// read from before executing. For the most part, you don't need to worry about this outside of
-/// knowing you can by default, pass one combination of:
-///     
-/// - `lhs: vector` and `rhs: vector`  
-/// - `lhs: vector` and `rhs: broadcast value`  
-/// - `lhs: broadcast value` and `rhs: vector`  
-/// - `lhs: broadcast value` and `rhs: broadcast value`  
-///   * Not really that useful and is just an artefact of the memory management system.
+    T: BitwiseOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::shr_vertical(lhs, rhs, result)
+}
+
+/// Computes the element wise hypotenuse `sqrt(a² + b²)` of two input buffers `a` and `b`
+/// that can be projected to the desired output size of `result`.
 ///
-/// ###### Broadcast values
+/// This operation is only available for the float types.
 ///
-/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
-/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
-/// outside the result buffer itself.
+/// ### Overflow & Special Values
 ///
-/// This means the following is possible:
-/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `1 + 1 == [1]`  w/result_buffer_len=1
+/// The squares are never computed directly, instead the larger of the two absolute values
+/// is factored out so intermediate values cannot overflow or underflow unless the result does.
 ///
-/// ###### Projecting Vectors
+/// If either element is infinite the result is infinite, even if the other element is `NaN`,
+/// otherwise if either element is `NaN` the result is `NaN`.
+///
+/// ### Projecting Vectors
 ///
 /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
 /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
@@ -1719,81 +5907,93 @@ where
 /// that is larger their input sizes by default. This means providing two slices
 /// of `128` elements in length must take a result buffer of `128` elements in length.
 ///
-/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
-/// enables projecting of the input buffer to new sizes providing the new size is a
-/// multiple of the original size. When this buffer is projected, it is effectively
-/// repeated `N` times, where `N` is how many times the old size fits into the new size.
-///
 /// ### Examples
 ///
 /// ##### Two vectors
 ///
 /// ```rust
-/// let lhs = [-1.0, 2.3, 2.0, 1.0];
-/// let rhs = [2.0, 0.7, 2.0, -2.0];
+/// let lhs = [3.0, -5.0, 0.0, 3e30];
+/// let rhs = [4.0, 12.0, 0.0, 4e30];
 ///
 /// let mut result = [0.0f32; 4];
-/// cfavml::add_vertical(&lhs, &rhs, &mut result);
-/// assert_eq!(result, [1.0, 3.0, 4.0, -1.0]);
+/// cfavml::hypot_vertical(&lhs, &rhs, &mut result);
+/// assert_eq!(result[..3], [5.0, 13.0, 0.0]);
+/// assert!((result[3] / 5e30 - 1.0).abs() < 1e-6);
 /// ```
 ///
 /// ##### One vector & broadcast value
 ///
 /// ```rust
-/// let lhs = [2.0, 0.5, -1.0, -0.5];
+/// let lhs = [3.0, 5.0, f64::INFINITY, f64::NAN];
 ///
-/// let mut result = [0.0f32; 4];
-/// cfavml::add_vertical(&lhs, -0.5, &mut result);
-/// assert_eq!(result, [1.5, 0.0, -1.5, -1.0]);
+/// let mut result = [0.0f64; 4];
+/// cfavml::hypot_vertical(&lhs, 4.0, &mut result);
+/// assert_eq!(result[..3], [5.0, 6.4031242374328485, f64::INFINITY]);
+/// assert!(result[3].is_nan());
 /// ```
 ///
-/// ##### Two broadcast values
+/// ### Implementation Pseudocode
 ///
-/// ```rust
-/// let mut result = [0.0f32; 4];
-/// cfavml::add_vertical(5.0, 5.0, &mut result);
-/// assert_eq!(result, [10.0; 4]);
-/// ```
+/// _This is the logic of the routine being called._
 ///
-/// ##### With projected vectors
+/// ```ignore
+/// result = [0; dims]
 ///
-/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
-/// vectors than the original inputs, which can allow for much better performance and
-/// memory usage when working with matrices.
+/// for i in range(dims):
+///     hi = max(abs(a[i]), abs(b[i]))
+///     lo = min(abs(a[i]), abs(b[i]))
 ///
-/// NOTE:
-/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
-/// how it behaves, it does not replace your matrix library's own broadcasting system.
+///     if hi == 0:
+///         result[i] = 0
+///     else:
+///         ratio = lo / hi
+///         result[i] = hi * sqrt(1 + ratio * ratio)
 ///
-/// ```rust
-/// use cfavml::mem_loader::Projected;
+/// return result
+/// ```
 ///
-/// let lhs = [1.0, 1.0, 0.5, 1.0];    // Pretend this is a 2x2 matrix
-/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+/// # Panics
 ///
-/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
-/// cfavml::add_vertical(Projected(&lhs), Projected(&rhs), &mut result);
-/// assert_eq!(result, [2.0, 3.5, 1.5, 3.5]);
-/// ```
+/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn hypot_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+where
+    T: FloatOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    B2: IntoMemLoader<T>,
+    B2::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+{
+    T::hypot_vertical(lhs, rhs, result)
+}
+
+#[inline]
+/// Checks each element of vector `a` if the element is `NaN`, storing the output
+/// as `1` (true) or `0` (false) in `result`.
 ///
-/// ##### With `MaybeUninit`
+/// This operation is only available for the float types.
 ///
-/// Often if you are working with new-allocations, you do not want to initialize the data twice,
-/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
-/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+/// ### Projecting Vectors
 ///
-/// ```rust
-/// use core::mem::MaybeUninit;
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of the input buffer implementing `IntoMemLoader<T>`.
 ///
-/// let lhs = [1.0, -1.0, 0.5, 1.0];
-/// let rhs = [1.0, 2.5, 0.5, -2.0];
+/// By default, you can provide _a slice_ or _a broadcast value_, which exhibit the standard
+/// behaviour as you might expect.
 ///
-/// let mut result = Vec::with_capacity(4);
-/// unsafe { result.set_len(4) };
-/// cfavml::add_vertical(&lhs, &rhs, &mut result);
+/// When providing a slice as the input it cannot be projected to a buffer
+/// that is larger than its input size by default. This means providing a slice
+/// of `128` elements in length must take a result buffer of `128` elements in length.
 ///
-/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
-/// assert_eq!(result, [2.0, 1.5, 1.0, -1.0]);
+/// ### Examples
+///
+/// ```rust
+/// let values = [1.0, f32::NAN, f32::INFINITY, -f32::NAN];
+///
+/// let mut result = [0.0f32; 4];
+/// cfavml::is_nan_vector(&values, &mut result);
+/// assert_eq!(result, [0.0, 1.0, 0.0, 1.0]);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -1801,146 +6001,109 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// result = [0; dims]
+/// mask = [0; dims]
 ///
 /// for i in range(dims):
-///     result[i] = a[i] + b[i]
+///     mask[i] = is_nan(a[i]) ? 1 : 0
 ///
-/// return result
+/// return mask
 /// ```
 ///
 /// # Panics
 ///
-/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// If vector `a` cannot be projected to the target size of `result`.
 /// Note that the projection rules are tied to the `MemLoader` implementation.
-pub fn add_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+pub fn is_nan_vector<T, B1, B2>(a: B1, result: &mut [B2])
 where
-    T: ArithmeticOps,
+    T: FloatOps,
     B1: IntoMemLoader<T>,
     B1::Loader: MemLoader<Value = T>,
-    B2: IntoMemLoader<T>,
-    B2::Loader: MemLoader<Value = T>,
-    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
 {
-    T::add_vertical(lhs, rhs, result)
+    T::is_nan_vector(a, result)
 }
 
-/// Performs an element wise subtraction of two input buffers `a` and `b` that can
-/// be projected to the desired output size of `result`.
-///
-/// ### Things To Know
-///
-/// ###### Supported patterns
-///
-/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
-/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
-/// read from before executing. For the most part, you don't need to worry about this outside of
-/// knowing you can by default, pass one combination of:
-///     
-/// - `lhs: vector` and `rhs: vector`  
-/// - `lhs: vector` and `rhs: broadcast value`  
-/// - `lhs: broadcast value` and `rhs: vector`  
-/// - `lhs: broadcast value` and `rhs: broadcast value`  
-///   * Not really that useful and is just an artefact of the memory management system.
-///
-/// ###### Broadcast values
-///
-/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
-/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
-/// outside the result buffer itself.
+#[inline]
+/// Checks each element of vector `a` if the element is finite, i.e. neither infinite
+/// nor `NaN`, storing the output as `1` (true) or `0` (false) in `result`.
 ///
-/// This means the following is possible:
-/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `1 + 1 == [1]`  w/result_buffer_len=1
+/// This operation is only available for the float types.
 ///
-/// ###### Projecting Vectors
+/// ### Projecting Vectors
 ///
 /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
-/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+/// broadcasting of the input buffer implementing `IntoMemLoader<T>`.
 ///
-/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
-/// which exhibit the standard behaviour as you might expect.
+/// By default, you can provide _a slice_ or _a broadcast value_, which exhibit the standard
+/// behaviour as you might expect.
 ///
-/// When providing two slices as inputs they cannot be projected to a buffer
-/// that is larger their input sizes by default. This means providing two slices
+/// When providing a slice as the input it cannot be projected to a buffer
+/// that is larger than its input size by default. This means providing a slice
 /// of `128` elements in length must take a result buffer of `128` elements in length.
 ///
-/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
-/// enables projecting of the input buffer to new sizes providing the new size is a
-/// multiple of the original size. When this buffer is projected, it is effectively
-/// repeated `N` times, where `N` is how many times the old size fits into the new size.
-///
 /// ### Examples
 ///
-/// ##### Two vectors
-///
 /// ```rust
-/// let lhs = [-1.0, 2.3, 2.0, 1.0];
-/// let rhs = [2.0, 0.7, 2.0, -2.0];
+/// let values = [1.0, f64::NAN, f64::NEG_INFINITY, f64::MIN_POSITIVE / 2.0];
 ///
-/// let mut result = [0.0f32; 4];
-/// cfavml::sub_vertical(&lhs, &rhs, &mut result);
-/// assert_eq!(result, [-3.0, 1.5999999, 0.0, 3.0]);
+/// let mut result = [0.0f64; 4];
+/// cfavml::is_finite_vector(&values, &mut result);
+/// assert_eq!(result, [1.0, 0.0, 0.0, 1.0]);
 /// ```
 ///
-/// ##### One vector & broadcast value
+/// ### Implementation Pseudocode
 ///
-/// ```rust
-/// let lhs = [2.0, 0.5, -1.0, -0.5];
+/// _This is the logic of the routine being called._
 ///
-/// let mut result = [0.0f32; 4];
-/// cfavml::sub_vertical(&lhs, -0.5, &mut result);
-/// assert_eq!(result, [2.5, 1.0, -0.5, 0.0]);
-/// ```
+/// ```ignore
+/// mask = [0; dims]
 ///
-/// ##### Two broadcast values
+/// for i in range(dims):
+///     mask[i] = is_finite(a[i]) ? 1 : 0
 ///
-/// ```rust
-/// let mut result = [0.0f32; 4];
-/// cfavml::sub_vertical(5.0, 5.0, &mut result);
-/// assert_eq!(result, [0.0; 4]);
+/// return mask
 /// ```
 ///
-/// ##### With projected vectors
+/// # Panics
 ///
-/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
-/// vectors than the original inputs, which can allow for much better performance and
-/// memory usage when working with matrices.
+/// If vector `a` cannot be projected to the target size of `result`.
+/// Note that the projection rules are tied to the `MemLoader` implementation.
+pub fn is_finite_vector<T, B1, B2>(a: B1, result: &mut [B2])
+where
+    T: FloatOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
+{
+    T::is_finite_vector(a, result)
+}
+
+#[inline]
+/// Checks each element of vector `a` if the element is positive or negative infinity,
+/// storing the output as `1` (true) or `0` (false) in `result`.
 ///
-/// NOTE:
-/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
-/// how it behaves, it does not replace your matrix library's own broadcasting system.
+/// This operation is only available for the float types.
 ///
-/// ```rust
-/// use cfavml::mem_loader::Projected;
+/// ### Projecting Vectors
 ///
-/// let lhs = [1.0, 1.0, 0.5, 1.0];    // Pretend this is a 2x2 matrix
-/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+/// broadcasting of the input buffer implementing `IntoMemLoader<T>`.
 ///
-/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
-/// cfavml::sub_vertical(Projected(&lhs), Projected(&rhs), &mut result);
-/// assert_eq!(result, [0.0, -1.5, -0.5, -1.5]);
-/// ```
+/// By default, you can provide _a slice_ or _a broadcast value_, which exhibit the standard
+/// behaviour as you might expect.
 ///
-/// ##### With `MaybeUninit`
+/// When providing a slice as the input it cannot be projected to a buffer
+/// that is larger than its input size by default. This means providing a slice
+/// of `128` elements in length must take a result buffer of `128` elements in length.
 ///
-/// Often if you are working with new-allocations, you do not want to initialize the data twice,
-/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
-/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+/// ### Examples
 ///
 /// ```rust
-/// use core::mem::MaybeUninit;
-///
-/// let lhs = [1.0, -1.0, 0.5, 1.0];
-/// let rhs = [1.0, 2.5, 0.5, -2.0];
-///
-/// let mut result = Vec::with_capacity(4);
-/// unsafe { result.set_len(4) };
-/// cfavml::sub_vertical(&lhs, &rhs, &mut result);
+/// let values = [f32::MAX, f32::NAN, f32::INFINITY, f32::NEG_INFINITY];
 ///
-/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
-/// assert_eq!(result, [0.0, -3.5, 0.0, 3.0]);
+/// let mut result = [0.0f32; 4];
+/// cfavml::is_infinite_vector(&values, &mut result);
+/// assert_eq!(result, [0.0, 0.0, 1.0, 1.0]);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -1948,146 +6111,158 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// result = [0; dims]
+/// mask = [0; dims]
 ///
 /// for i in range(dims):
-///     result[i] = a[i] - b[i]
+///     mask[i] = is_infinite(a[i]) ? 1 : 0
 ///
-/// return result
+/// return mask
 /// ```
 ///
 /// # Panics
 ///
-/// If vectors `a` and `b` cannot be projected to the target size of `result`.
+/// If vector `a` cannot be projected to the target size of `result`.
 /// Note that the projection rules are tied to the `MemLoader` implementation.
-pub fn sub_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+pub fn is_infinite_vector<T, B1, B2>(a: B1, result: &mut [B2])
 where
-    T: ArithmeticOps,
+    T: FloatOps,
     B1: IntoMemLoader<T>,
     B1::Loader: MemLoader<Value = T>,
-    B2: IntoMemLoader<T>,
-    B2::Loader: MemLoader<Value = T>,
-    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+    for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = T>,
 {
-    T::sub_vertical(lhs, rhs, result)
+    T::is_infinite_vector(a, result)
 }
 
-/// Performs an element wise multiply of two input buffers `a` and `b` that can
-/// be projected to the desired output size of `result`.
+#[inline]
+/// Returns if **_any_** element of vector `a` is `NaN`.
 ///
-/// ### Things To Know
+/// This operation is only available for the float types.
 ///
-/// ###### Supported patterns
+/// The classification masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a `NaN` is found. An empty input returns `false`.
 ///
-/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
-/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
-/// read from before executing. For the most part, you don't need to worry about this outside of
-/// knowing you can by default, pass one combination of:
-///     
-/// - `lhs: vector` and `rhs: vector`  
-/// - `lhs: vector` and `rhs: broadcast value`  
-/// - `lhs: broadcast value` and `rhs: vector`  
-/// - `lhs: broadcast value` and `rhs: broadcast value`  
-///   * Not really that useful and is just an artefact of the memory management system.
+/// ### Examples
 ///
-/// ###### Broadcast values
+/// ```rust
+/// let values = [4.0, f32::INFINITY, f32::NAN];
 ///
-/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
-/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
-/// outside the result buffer itself.
+/// assert!(cfavml::any_nan(&values));
+/// assert!(!cfavml::any_nan(&values[..2]));
+/// ```
 ///
-/// This means the following is possible:
-/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `1 + 1 == [1]`  w/result_buffer_len=1
+/// ### Implementation Pseudocode
 ///
-/// ###### Projecting Vectors
+/// _This is the logic of the routine being called._
 ///
-/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
-/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+/// ```ignore
+/// for i in range(dims):
+///     if is_nan(a[i]):
+///         return true
 ///
-/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
-/// which exhibit the standard behaviour as you might expect.
+/// return false
+/// ```
+pub fn any_nan<T, B1>(a: B1) -> bool
+where
+    T: FloatOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::any_nan(a)
+}
+
+#[inline]
+/// Returns if **_any_** element of vector `a` is positive or negative infinity.
 ///
-/// When providing two slices as inputs they cannot be projected to a buffer
-/// that is larger their input sizes by default. This means providing two slices
-/// of `128` elements in length must take a result buffer of `128` elements in length.
+/// This operation is only available for the float types.
 ///
-/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
-/// enables projecting of the input buffer to new sizes providing the new size is a
-/// multiple of the original size. When this buffer is projected, it is effectively
-/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+/// The classification masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as an infinite value is found. An empty input returns `false`.
 ///
 /// ### Examples
 ///
-/// ##### Two vectors
-///
 /// ```rust
-/// let lhs = [-1.0, 2.3, 2.0, 1.0];
-/// let rhs = [2.0, 0.7, 2.0, -2.0];
+/// let values = [4.0, f64::NAN, f64::NEG_INFINITY];
 ///
-/// let mut result = [0.0f32; 4];
-/// cfavml::mul_vertical(&lhs, &rhs, &mut result);
-/// assert_eq!(result, [-2.0, 1.6099999, 4.0, -2.0]);
+/// assert!(cfavml::any_infinite(&values));
+/// assert!(!cfavml::any_infinite(&values[..2]));
 /// ```
 ///
-/// ##### One vector & broadcast value
-///
-/// ```rust
-/// let lhs = [2.0, 0.5, -1.0, -0.5];
+/// ### Implementation Pseudocode
 ///
-/// let mut result = [0.0f32; 4];
-/// cfavml::mul_vertical(&lhs, -0.5, &mut result);
-/// assert_eq!(result, [-1.0, -0.25, 0.5, 0.25]);
-/// ```
+/// _This is the logic of the routine being called._
 ///
-/// ##### Two broadcast values
+/// ```ignore
+/// for i in range(dims):
+///     if is_infinite(a[i]):
+///         return true
 ///
-/// ```rust
-/// let mut result = [0.0f32; 4];
-/// cfavml::mul_vertical(5.0, 5.0, &mut result);
-/// assert_eq!(result, [25.0; 4]);
+/// return false
 /// ```
+pub fn any_infinite<T, B1>(a: B1) -> bool
+where
+    T: FloatOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::any_infinite(a)
+}
+
+#[inline]
+/// Returns if **_all_** elements of vector `a` are finite, i.e. neither infinite nor `NaN`.
 ///
-/// ##### With projected vectors
+/// This operation is only available for the float types.
 ///
-/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
-/// vectors than the original inputs, which can allow for much better performance and
-/// memory usage when working with matrices.
+/// The classification masks are reduced in registers rather than being written out, and the
+/// routine returns as soon as a non-finite value is found. An empty input returns `true`.
 ///
-/// NOTE:
-/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
-/// how it behaves, it does not replace your matrix library's own broadcasting system.
+/// ### Examples
 ///
 /// ```rust
-/// use cfavml::mem_loader::Projected;
+/// let values = [4.0, f32::MAX, -0.0, f32::NAN];
 ///
-/// let lhs = [1.0, 1.0, 0.5, 1.0];    // Pretend this is a 2x2 matrix
-/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+/// assert!(cfavml::all_finite(&values[..3]));
+/// assert!(!cfavml::all_finite(&values));
+/// ```
 ///
-/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
-/// cfavml::mul_vertical(Projected(&lhs), Projected(&rhs), &mut result);
-/// assert_eq!(result, [1.0, 2.5, 0.5, 2.5]);
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// for i in range(dims):
+///     if not is_finite(a[i]):
+///         return false
+///
+/// return true
 /// ```
+pub fn all_finite<T, B1>(a: B1) -> bool
+where
+    T: FloatOps,
+    B1: IntoMemLoader<T>,
+    B1::Loader: MemLoader<Value = T>,
+{
+    T::all_finite(a)
+}
+
+#[inline]
+/// Divides each element of vector `a` by the L2 norm of `a`, writing the
+/// normalized vector to `result`.
 ///
-/// ##### With `MaybeUninit`
+/// This operation is only available for the float types.
 ///
-/// Often if you are working with new-allocations, you do not want to initialize the data twice,
-/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
-/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+/// If the norm of `a` is zero, `result` is filled with zeroes rather than `NaN`.
 ///
-/// ```rust
-/// use core::mem::MaybeUninit;
+/// ### Examples
 ///
-/// let lhs = [1.0, -1.0, 0.5, 1.0];
-/// let rhs = [1.0, 2.5, 0.5, -2.0];
+/// ```rust
+/// let a = [3.0f32, 0.0, 4.0];
 ///
-/// let mut result = Vec::with_capacity(4);
-/// unsafe { result.set_len(4) };
-/// cfavml::mul_vertical(&lhs, &rhs, &mut result);
+/// let mut result = [0.0f32; 3];
+/// cfavml::l2_normalize_vertical(3, &a, &mut result);
+/// assert_eq!(result, [0.6, 0.0, 0.8]);
 ///
-/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
-/// assert_eq!(result, [1.0, -2.5, 0.25, -2.0]);
+/// cfavml::l2_normalize_vertical(3, &[0.0; 3], &mut result);
+/// assert_eq!(result, [0.0; 3]);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -2097,164 +6272,162 @@ where
 /// ```ignore
 /// result = [0; dims]
 ///
+/// norm = sqrt(squared_norm(a))
+/// if norm == 0:
+///     return result
+///
 /// for i in range(dims):
-///     result[i] = a[i] * b[i]
+///     result[i] = a[i] / norm
 ///
 /// return result
 /// ```
 ///
 /// # Panics
 ///
-/// If vectors `a` and `b` cannot be projected to the target size of `result`.
-/// Note that the projection rules are tied to the `MemLoader` implementation.
-pub fn mul_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+/// If vectors `a` and `result` are not exactly `dims` elements in length.
+pub fn l2_normalize_vertical<T>(dims: usize, a: &[T], result: &mut [T])
 where
-    T: ArithmeticOps,
-    B1: IntoMemLoader<T>,
-    B1::Loader: MemLoader<Value = T>,
-    B2: IntoMemLoader<T>,
-    B2::Loader: MemLoader<Value = T>,
-    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+    T: FloatOps,
 {
-    T::mul_vertical(lhs, rhs, result)
+    unwrap_size(try_l2_normalize_vertical(dims, a, result))
 }
 
-/// Performs an element wise division of two input buffers `a` and `b` that can
-/// be projected to the desired output size of `result`.
+#[inline]
+/// Divides each element of vector `a` by the L2 norm of `a` in place.
 ///
-/// ## WARNING - You probably don't want to divide!
+/// This operation is only available for the float types.
 ///
-/// If you are attempting to divide a vector by a broadcast value or vice versa, you are
-/// infinitely better off calculating the inverse and using a _multiply_ operation instead.
+/// If the norm of `a` is zero, `a` is filled with zeroes rather than `NaN`.
 ///
-/// **This routine will not do this for you** so you are potentially missing out on a large
-/// chunk of performance.
+/// ### Examples
 ///
 /// ```rust
-/// let example = [1.0, 2.0, 3.0];
-/// let mut result = [0.0; 3];
-/// // BAD! Don't do this!
-/// cfavml::div_vertical(&example, 2.0, &mut result);
-/// assert_eq!(result, [0.5, 1.0, 1.5]);
+/// let mut a = [0.0f64, 6.0, 8.0];
 ///
-/// // Do this instead! It is infinitely faster! (Well not quite but trust me)
-/// cfavml::mul_vertical(&example, 1.0 / 2.0, &mut result);
-/// assert_eq!(result, [0.5, 1.0, 1.5]);
+/// cfavml::l2_normalize_inplace(&mut a);
+/// assert_eq!(a, [0.0, 0.6, 0.8]);
 /// ```
 ///
-/// ### Things To Know
-///
-/// ###### Supported patterns
-///
-/// Unlike horizontal operations, vertical ops can execute over a much wider variety of data
-/// depending on the `MemLoader` which is a trait used to control how inputs are projected and
-/// read from before executing. For the most part, you don't need to worry about this outside of
-/// knowing you can by default, pass one combination of:
-///     
-/// - `lhs: vector` and `rhs: vector`  
-/// - `lhs: vector` and `rhs: broadcast value`  
-/// - `lhs: broadcast value` and `rhs: vector`  
-/// - `lhs: broadcast value` and `rhs: broadcast value`  
-///   * Not really that useful and is just an artefact of the memory management system.
+/// ### Implementation Pseudocode
 ///
-/// ###### Broadcast values
+/// _This is the logic of the routine being called._
 ///
-/// When a broadcast value is provided, CFAVML will stretch that value out to match the size of
-/// the _result_ buffer (not the other input buffer!) this does not cost additional allocations
-/// outside the result buffer itself.
+/// ```ignore
+/// norm = sqrt(squared_norm(a))
+/// if norm == 0:
+///     a = [0; dims]
+///     return
 ///
-/// This means the following is possible:
-/// - `[0, 0, 0] + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `0 + 1 == [1, 1, 1]`  w/result_buffer_len=3
-/// - `1 + 1 == [1]`  w/result_buffer_len=1
+/// for i in range(dims):
+///     a[i] = a[i] / norm
+/// ```
+pub fn l2_normalize_inplace<T>(a: &mut [T])
+where
+    T: FloatOps,
+{
+    T::l2_normalize_inplace(a)
+}
+
+#[inline]
+/// Gathers the elements of `a` selected by `indices`, writing the element selected
+/// by `indices[i]` to `result[i]`.
 ///
-/// ###### Projecting Vectors
+/// The indices are 32-bit, on AVX2 the 32-bit types are gathered a whole register at a
+/// time with the hardware gather instructions.
 ///
-/// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
-/// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+/// ### Examples
 ///
-/// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
-/// which exhibit the standard behaviour as you might expect.
+/// ```rust
+/// let a = [10.0, 20.0, 30.0, 40.0];
 ///
-/// When providing two slices as inputs they cannot be projected to a buffer
-/// that is larger their input sizes by default. This means providing two slices
-/// of `128` elements in length must take a result buffer of `128` elements in length.
+/// let mut result = [0.0f32; 5];
+/// cfavml::gather(&a, &[3, 0, 0, 2, 1], &mut result);
+/// assert_eq!(result, [40.0, 10.0, 10.0, 30.0, 20.0]);
+/// ```
 ///
-/// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
-/// enables projecting of the input buffer to new sizes providing the new size is a
-/// multiple of the original size. When this buffer is projected, it is effectively
-/// repeated `N` times, where `N` is how many times the old size fits into the new size.
+/// ### Implementation Pseudocode
 ///
-/// ### Examples
+/// _This is the logic of the routine being called._
 ///
-/// ##### Two vectors
+/// ```ignore
+/// result = [0; dims]
 ///
-/// ```rust
-/// let lhs = [1.0, 2.0, 4.0, 1.0];
-/// let rhs = [2.0, 0.8, 2.0, -2.0];
+/// for i in range(dims):
+///     result[i] = a[indices[i]]
 ///
-/// let mut result = [0.0f32; 4];
-/// cfavml::div_vertical(&lhs, &rhs, &mut result);
-/// assert_eq!(result, [0.5, 2.5, 2.0, -0.5]);
+/// return result
 /// ```
 ///
-/// ##### One vector & broadcast value
+/// # Panics
 ///
-/// ```rust
-/// let lhs = [2.0, 0.5, -1.0, -0.5];
+/// If `indices` and `result` are not equal in length or any of the `indices` are out
+/// of bounds of `a`.
+pub fn gather<T, B>(a: &[T], indices: &[u32], result: &mut [B])
+where
+    T: GatherOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    unwrap_size(try_gather(a, indices, result))
+}
+
+#[inline]
+/// Scatters the elements of `a` to the positions of `result` selected by `indices`,
+/// writing `a[i]` to `result[indices[i]]`.
 ///
-/// let mut result = [0.0f32; 4];
-/// cfavml::div_vertical(&lhs, -0.5, &mut result);
-/// assert_eq!(result, [-4.0, -1.0, 2.0, 1.0]);
-/// ```
+/// The elements are written in order, so if `indices` contains duplicates the last
+/// element of `a` written to that index wins. Elements of `result` which are not
+/// selected by any index are left untouched.
 ///
-/// ##### Two broadcast values
+/// ### Examples
 ///
 /// ```rust
-/// let mut result = [0.0f32; 4];
-/// cfavml::div_vertical(5.0, 5.0, &mut result);
-/// assert_eq!(result, [1.0; 4]);
+/// let a = [1, 2, 3, 4];
+///
+/// let mut result = [0u32; 4];
+/// cfavml::scatter(&a, &[2, 0, 2, 1], &mut result);
+/// assert_eq!(result, [2, 4, 3, 0]);
 /// ```
 ///
-/// ##### With projected vectors
+/// ### Implementation Pseudocode
 ///
-/// Using the [Projected](crate::mem_loader::Projected) wrapper type, we can create larger output
-/// vectors than the original inputs, which can allow for much better performance and
-/// memory usage when working with matrices.
+/// _This is the logic of the routine being called._
 ///
-/// NOTE:
-/// Projection != Matrix broadcasting, please read the documentation of `Projected` to understand
-/// how it behaves, it does not replace your matrix library's own broadcasting system.
+/// ```ignore
+/// for i in range(dims):
+///     result[indices[i]] = a[i]
+/// ```
 ///
-/// ```rust
-/// use cfavml::mem_loader::Projected;
+/// # Panics
 ///
-/// let lhs = [1.0, -1.0, 0.5, 1.0];    // Pretend this is a 2x2 matrix
-/// let rhs = [1.0, 2.5];               // Pretend this is a 1x2 matrix
+/// If `a` and `indices` are not equal in length or any of the `indices` are out
+/// of bounds of `result`.
+pub fn scatter<T>(a: &[T], indices: &[u32], result: &mut [T])
+where
+    T: GatherOps,
+{
+    unwrap_size(try_scatter(a, indices, result))
+}
+
+#[inline]
+/// Computes the mean of each `window` sized window of vector `a`, writing the mean of
+/// `a[i..i + window]` to `result[i]`.
 ///
-/// let mut result = [0.0; 4];          // Our output is a 2x2 matrix
-/// cfavml::div_vertical(Projected(&lhs), Projected(&rhs), &mut result);
-/// assert_eq!(result, [1.0, -0.4, 0.5, 0.4]);
-/// ```
+/// This operation is only available for the `f32`, `f64`, `i32` and `i64` types.
 ///
-/// ##### With `MaybeUninit`
+/// Like [slice::windows], there is one result per window, `a.len() - window + 1` results,
+/// or no results if `window` is larger than `a`. The sum of each window is updated from the
+/// sum of the previous window, so for the float types rounding error accumulates over long
+/// series, for the integer types the sum is held in a type twice the width of `T` so the
+/// results are exact even when the sum of a window overflows `T`.
 ///
-/// Often if you are working with new-allocations, you do not want to initialize the data twice,
-/// CFAVML guarantees that the output buffer will never be read from, so it is safe to provide
-/// uninitialized buffers for the result, this is what the `WriteOnlyBuffer` trait is about.
+/// ### Examples
 ///
 /// ```rust
-/// use core::mem::MaybeUninit;
-///
-/// let lhs = [1.0, -1.0, 0.5, 1.0];
-/// let rhs = [1.0, 2.5, 0.5, -2.0];
-///
-/// let mut result = Vec::with_capacity(4);
-/// unsafe { result.set_len(4) };
-/// cfavml::div_vertical(&lhs, &rhs, &mut result);
+/// let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
 ///
-/// let result = unsafe { core::mem::transmute::<Vec<MaybeUninit<f32>>, Vec<f32>>(result) };
-/// assert_eq!(result, [1.0, -0.4, 1.0, -0.5]);
+/// let mut result = [0.0f32; 4];
+/// cfavml::moving_average(3, &a, &mut result);
+/// assert_eq!(result, [2.0, 3.0, 4.0, 5.0]);
 /// ```
 ///
 /// ### Implementation Pseudocode
@@ -2262,26 +6435,21 @@ where
 /// _This is the logic of the routine being called._
 ///
 /// ```ignore
-/// result = [0; dims]
+/// result = [0; dims - window + 1]
 ///
-/// for i in range(dims):
-///     result[i] = a[i] / b[i]
+/// for i in range(dims - window + 1):
+///     result[i] = sum(a[i:i + window]) / window
 ///
 /// return result
 /// ```
 ///
 /// # Panics
 ///
-/// If vectors `a` and `b` cannot be projected to the target size of `result`.
-/// Note that the projection rules are tied to the `MemLoader` implementation.
-pub fn div_vertical<T, B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+/// If `window` is zero or `result` is not exactly `a.len() - window + 1` elements in length.
+pub fn moving_average<T, B>(window: usize, a: &[T], result: &mut [B])
 where
-    T: ArithmeticOps,
-    B1: IntoMemLoader<T>,
-    B1::Loader: MemLoader<Value = T>,
-    B2: IntoMemLoader<T>,
-    B2::Loader: MemLoader<Value = T>,
-    for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = T>,
+    T: StatsOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
 {
-    T::div_vertical(lhs, rhs, result)
+    unwrap_size(try_moving_average(window, a, result))
 }
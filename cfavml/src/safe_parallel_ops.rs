@@ -0,0 +1,203 @@
+//! Multi-threaded variants of the batched distance routines.
+//!
+//! The rows of the output are split into contiguous blocks which are spread across the
+//! CFAVML threadpool from [cfavml_utils], each block being computed with the same SIMD
+//! kernels as the single threaded routines. The results are identical to the single
+//! threaded routines, regardless of how many threads the pool has.
+//!
+//! A pool can be shared with the rest of the application by passing it in via
+//! [MaybeBorrowedPool], or the global pool can be used via [get_or_init_pool].
+
+pub use cfavml_utils::{get_or_init_pool, MaybeBorrowedPool};
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::error::{unwrap_size, SizeMismatchError};
+use crate::safe_trait_distance_ops::DistanceOps;
+
+/// The number of blocks each thread of the pool is given on average.
+///
+/// Spawning more blocks than threads allows the pool to balance the work when some
+/// blocks take longer than others, i.e. the shrinking rows of the pairwise distances.
+const BLOCKS_PER_THREAD: usize = 4;
+
+#[inline]
+/// Calculates the dot product between the `query` vector and each vector
+/// within the contiguous `batch` of vectors using the threads of `pool`, writing
+/// the dot product of the `i`th vector to `result[i]`.
+///
+/// The results are identical to [dot_batch](crate::dot_batch).
+///
+/// ### Examples
+///
+/// ```rust
+/// let query = [1.0, 2.0, 3.0, 4.0];
+/// let batch = [
+///     1.0, 0.0, 0.0, 0.0,
+///     0.5, 0.5, 0.5, 0.5,
+///     1.0, 1.0, 1.0, 1.0,
+/// ];
+///
+/// let pool = cfavml::get_or_init_pool();
+///
+/// let mut result = [0.0f32; 3];
+/// cfavml::par_dot_batch(&pool, &query, &batch, &mut result);
+/// assert_eq!(result, [1.0, 5.0, 10.0]);
+/// ```
+///
+/// ### Panics
+///
+/// If `batch` is not exactly `query.len() * result.len()` elements in length.
+pub fn par_dot_batch<T, B>(
+    pool: &MaybeBorrowedPool,
+    query: &[T],
+    batch: &[T],
+    result: &mut [B],
+) where
+    T: DistanceOps + Sync,
+    B: Send,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    let dims = query.len();
+    unwrap_size(SizeMismatchError::check(
+        "batch",
+        dims * result.len(),
+        batch.len(),
+    ));
+
+    let rows_per_block = rows_per_block(pool, result.len());
+    pool.scope(|scope| {
+        for (block, result) in result.chunks_mut(rows_per_block).enumerate() {
+            let batch = &batch[block * rows_per_block * dims..][..result.len() * dims];
+            scope.spawn(move |_| T::dot_batch(query, batch, result));
+        }
+    });
+}
+
+#[inline]
+/// Calculates the squared Euclidean distance between every pair of the `n` vectors in
+/// `matrix` using the threads of `pool`, writing the distances to the upper triangle of
+/// the row-major `n x n` `result` matrix.
+///
+/// Each vector is compared against all of the vectors following it, the diagonal and
+/// lower triangle of `result` are left untouched. The results are identical to
+/// [pairwise_distances](crate::pairwise_distances).
+///
+/// ### Examples
+///
+/// ```rust
+/// let matrix = [
+///     1.0, 2.0,
+///     0.0, 1.0,
+///     3.0, 0.0,
+/// ];
+///
+/// let pool = cfavml::get_or_init_pool();
+///
+/// let mut result = [0.0f32; 3 * 3];
+/// cfavml::par_pairwise_euclidean(&pool, 3, 2, &matrix, &mut result);
+/// assert_eq!(result, [
+///     0.0, 2.0, 8.0,
+///     0.0, 0.0, 10.0,
+///     0.0, 0.0, 0.0,
+/// ]);
+/// ```
+///
+/// ### Panics
+///
+/// If `matrix` is not exactly `n * dims` elements in length or `result` is not
+/// exactly `n * n` elements in length.
+pub fn par_pairwise_euclidean<T>(
+    pool: &MaybeBorrowedPool,
+    n: usize,
+    dims: usize,
+    matrix: &[T],
+    result: &mut [T],
+) where
+    T: DistanceOps + Send + Sync,
+    for<'a> &'a mut [T]: WriteOnlyBuffer<Item = T>,
+{
+    unwrap_size(SizeMismatchError::check("matrix", n * dims, matrix.len()));
+    unwrap_size(SizeMismatchError::check("result", n * n, result.len()));
+
+    if n == 0 {
+        return;
+    }
+
+    let rows_per_block = rows_per_block(pool, n);
+    pool.scope(|scope| {
+        for (block, rows) in result.chunks_mut(rows_per_block * n).enumerate() {
+            scope.spawn(move |_| {
+                for (offset, row) in rows.chunks_mut(n).enumerate() {
+                    let i = block * rows_per_block + offset;
+                    let (query, following) = matrix[i * dims..].split_at(dims);
+                    T::squared_euclidean_batch(query, following, &mut row[i + 1..]);
+                }
+            });
+        }
+    });
+}
+
+/// Returns the number of rows each block spawned onto the pool should compute.
+fn rows_per_block(pool: &MaybeBorrowedPool, rows: usize) -> usize {
+    let num_blocks = pool.current_num_threads() * BLOCKS_PER_THREAD;
+    rows.div_ceil(num_blocks).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_par_dot_batch_matches_single_threaded() {
+        let pool = get_or_init_pool();
+
+        for (dims, rows) in [(1, 1), (7, 3), (64, 131), (131, 17), (3, 0)] {
+            let (query, _) = get_sample_vectors::<f32>(dims);
+            let (batch, _) = get_sample_vectors::<f32>(dims * rows);
+
+            let mut expected = vec![0.0; rows];
+            crate::dot_batch(&query, &batch, &mut expected);
+
+            let mut result = vec![0.0; rows];
+            par_dot_batch(&pool, &query, &batch, &mut result);
+            assert_eq!(
+                result, expected,
+                "mismatch with dims {dims} and rows {rows}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_par_pairwise_euclidean_matches_single_threaded() {
+        let pool = get_or_init_pool();
+
+        for (n, dims) in [(1, 3), (2, 1), (37, 17), (131, 64), (0, 8)] {
+            let (matrix, _) = get_sample_vectors::<f32>(n * dims);
+
+            let mut expected = vec![0.0; n * n];
+            crate::pairwise_distances(n, dims, &matrix, &mut expected);
+
+            let mut result = vec![0.0; n * n];
+            par_pairwise_euclidean(&pool, n, dims, &matrix, &mut result);
+            assert_eq!(result, expected, "mismatch with n {n} and dims {dims}");
+        }
+    }
+
+    #[test]
+    fn test_par_length_mismatch() {
+        let pool = get_or_init_pool();
+
+        let outcome = catch_unwind(AssertUnwindSafe(|| {
+            par_dot_batch(&pool, &[1.0f32, 2.0], &[1.0; 5], &mut [0.0; 3])
+        }));
+        assert!(outcome.is_err());
+
+        let outcome = catch_unwind(AssertUnwindSafe(|| {
+            par_pairwise_euclidean(&pool, 3, 2, &[1.0f32; 6], &mut [0.0; 8])
+        }));
+        assert!(outcome.is_err());
+    }
+}
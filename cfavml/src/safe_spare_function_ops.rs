@@ -0,0 +1,346 @@
+//! Safe routines writing their output into the spare capacity of a `Vec`.
+//!
+//! These avoid initializing the output buffer before it is immediately overwritten,
+//! relying on the [initialization guarantee](crate::buffer::WriteOnlyBuffer#initialization-guarantee)
+//! of the vertical routines.
+
+use alloc::vec::Vec;
+
+use crate::buffer::TrustedBufferElement;
+use crate::error::{unwrap_size, SizeMismatchError};
+use crate::safe_trait_arithmetic_ops::ArithmeticOps;
+use crate::safe_trait_gather_ops::GatherOps;
+
+macro_rules! define_spare_vertical_op {
+    (
+        $(#[doc = $doc:expr])*
+        $name:ident => $op:ident, $trait:ident
+    ) => {
+        #[inline]
+        $(#[doc = $doc])*
+        ///
+        /// The `lhs.len()` results are appended to `out` by writing directly into its spare
+        /// capacity, reserving more if required, so the output is never initialized twice.
+        ///
+        /// # Panics
+        ///
+        /// If vectors `lhs` and `rhs` are not equal in length, in which case `out` is left
+        /// untouched other than potentially reserving capacity.
+        pub fn $name<T>(lhs: &[T], rhs: &[T], out: &mut Vec<T>)
+        where
+            T: $trait + TrustedBufferElement,
+        {
+            unwrap_size(SizeMismatchError::check("rhs", lhs.len(), rhs.len()));
+
+            let len = lhs.len();
+            out.reserve(len);
+            T::$op(lhs, rhs, &mut out.spare_capacity_mut()[..len]);
+
+            // SAFETY: `T` is sealed to the types implemented by CFAVML, whose vertical
+            // routines write every element of the output before returning normally.
+            unsafe { out.set_len(out.len() + len) };
+        }
+    };
+}
+
+define_spare_vertical_op!(
+    /// Performs an element wise addition of vectors `lhs` and `rhs`, appending the
+    /// results to `out`.
+    ///
+    /// ```rust
+    /// let lhs = [1.0, -1.0, 0.5, 1.0];
+    /// let rhs = [1.0, 2.5, 0.5, -2.0];
+    ///
+    /// let mut result = Vec::new();
+    /// cfavml::add_into_spare(&lhs, &rhs, &mut result);
+    /// assert_eq!(result, [2.0, 1.5, 1.0, -1.0]);
+    /// ```
+    add_into_spare => add_vertical, ArithmeticOps
+);
+define_spare_vertical_op!(
+    /// Performs an element wise subtraction of vectors `lhs` and `rhs`, appending the
+    /// results to `out`.
+    ///
+    /// ```rust
+    /// let lhs = [1.0, -1.0, 0.5, 1.0];
+    /// let rhs = [1.0, 2.5, 0.5, -2.0];
+    ///
+    /// let mut result = vec![7.0];
+    /// cfavml::sub_into_spare(&lhs, &rhs, &mut result);
+    /// assert_eq!(result, [7.0, 0.0, -3.5, 0.0, 3.0]);
+    /// ```
+    sub_into_spare => sub_vertical, ArithmeticOps
+);
+define_spare_vertical_op!(
+    /// Performs an element wise multiplication of vectors `lhs` and `rhs`, appending the
+    /// results to `out`.
+    ///
+    /// ```rust
+    /// let lhs = [1, -1, 5, 1];
+    /// let rhs = [1, 2, 5, -2];
+    ///
+    /// let mut result = Vec::with_capacity(4);
+    /// cfavml::mul_into_spare(&lhs, &rhs, &mut result);
+    /// assert_eq!(result, [1, -2, 25, -2]);
+    /// ```
+    mul_into_spare => mul_vertical, ArithmeticOps
+);
+define_spare_vertical_op!(
+    /// Performs an element wise division of vectors `lhs` and `rhs`, appending the
+    /// results to `out`.
+    ///
+    /// ```rust
+    /// let lhs = [1.0, -1.0, 0.5, 1.0];
+    /// let rhs = [1.0, 2.0, 0.5, -2.0];
+    ///
+    /// let mut result = Vec::new();
+    /// cfavml::div_into_spare(&lhs, &rhs, &mut result);
+    /// assert_eq!(result, [1.0, -0.5, 1.0, -0.5]);
+    /// ```
+    div_into_spare => div_vertical, ArithmeticOps
+);
+
+#[inline]
+/// Compacts the elements of `values` where the corresponding element of `mask` is
+/// non-zero, appending them to `out` in their original order and returning the number
+/// of elements appended.
+///
+/// Each register of `mask` is reduced to a bitmask, so runs of unselected elements
+/// are skipped a register at a time, making this considerably faster than filtering
+/// an iterator when the mask is sparse.
+///
+/// ```rust
+/// let mask = [0.0, 1.0, 1.0, 0.0, 1.0];
+/// let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+///
+/// let mut result = Vec::new();
+/// let count = cfavml::compact(&mask, &values, &mut result);
+/// assert_eq!(count, 3);
+/// assert_eq!(result, [2.0, 3.0, 5.0]);
+/// ```
+///
+/// The masks produced by the comparison routines can be used directly:
+///
+/// ```rust
+/// let values = [5, -2, 8, 0, -7, 3];
+///
+/// let mut mask = [0; 6];
+/// cfavml::gt_vertical(&values, 0, &mut mask);
+///
+/// let mut result = Vec::new();
+/// cfavml::compact(&mask, &values, &mut result);
+/// assert_eq!(result, [5, 8, 3]);
+/// ```
+///
+/// # Panics
+///
+/// If vectors `mask` and `values` are not equal in length, in which case `out` is left
+/// untouched other than potentially reserving capacity.
+pub fn compact<T>(mask: &[T], values: &[T], out: &mut Vec<T>) -> usize
+where
+    T: GatherOps + TrustedBufferElement,
+{
+    unwrap_size(SizeMismatchError::check("values", mask.len(), values.len()));
+
+    let dims = mask.len();
+    out.reserve(dims);
+    let count = T::compact(dims, mask, values, &mut out.spare_capacity_mut()[..dims]);
+    assert!(
+        count <= dims,
+        "Compaction cannot produce more than `dims` elements"
+    );
+
+    // SAFETY: `T` is sealed to the types implemented by CFAVML, whose compaction
+    // routines write the first `count` elements of the output before returning.
+    unsafe { out.set_len(out.len() + count) };
+
+    count
+}
+
+#[inline]
+/// Appends the index of each non-zero element of `mask` to `out` in ascending order,
+/// returning the number of indices appended.
+///
+/// ```rust
+/// let mask = [0u8, 1, 1, 0, 1];
+///
+/// let mut indices = Vec::new();
+/// let count = cfavml::mask_to_indices(&mask, &mut indices);
+/// assert_eq!(count, 3);
+/// assert_eq!(indices, [1, 2, 4]);
+/// ```
+///
+/// # Panics
+///
+/// If `mask` is larger than `u32::MAX + 1` elements in length.
+pub fn mask_to_indices<T>(mask: &[T], out: &mut Vec<u32>) -> usize
+where
+    T: GatherOps + TrustedBufferElement,
+{
+    let dims = mask.len();
+    out.reserve(dims);
+    let count = T::mask_to_indices(dims, mask, &mut out.spare_capacity_mut()[..dims]);
+    assert!(
+        count <= dims,
+        "Compaction cannot produce more than `dims` elements"
+    );
+
+    // SAFETY: `T` is sealed to the types implemented by CFAVML, whose compaction
+    // routines write the first `count` elements of the output before returning.
+    unsafe { out.set_len(out.len() + count) };
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+    use crate::math::{AutoMath, Math};
+    use crate::test_utils::get_sample_vectors;
+
+    type SpareCase<T> = (&'static str, fn(&[T], &[T], &mut Vec<T>), fn(T, T) -> T);
+
+    fn check_spare_ops<T>(cases: [SpareCase<T>; 4])
+    where
+        T: ArithmeticOps + TrustedBufferElement + PartialEq + std::fmt::Debug,
+        AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let (l1, l2) = get_sample_vectors::<T>(if cfg!(miri) { 133 } else { 1043 });
+
+        // Odd lengths so every routine has to complete the tail one element at a time.
+        for len in [0, 1, 3, 7, 15, 31, 33, 67, 131, l1.len()] {
+            let (a, b) = (&l1[..len], &l2[..len]);
+            let expected = |op: fn(T, T) -> T| {
+                a.iter()
+                    .copied()
+                    .zip(b.iter().copied())
+                    .map(|(a, b)| op(a, b))
+                    .collect::<Vec<T>>()
+            };
+
+            for (name, spare_op, op) in cases {
+                let mut result = Vec::new();
+                spare_op(a, b, &mut result);
+                assert_eq!(result, expected(op), "{name} mismatch with len {len}");
+
+                // Results are appended after any existing elements.
+                let existing = l1[..3].to_vec();
+                let mut result = existing.clone();
+                spare_op(a, b, &mut result);
+                assert_eq!(result[..3], existing, "{name} overwrote existing elements");
+                assert_eq!(result[3..], expected(op), "{name} mismatch with len {len}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_into_spare_f32() {
+        check_spare_ops::<f32>([
+            ("add", add_into_spare, AutoMath::add),
+            ("sub", sub_into_spare, AutoMath::sub),
+            ("mul", mul_into_spare, AutoMath::mul),
+            ("div", div_into_spare, AutoMath::div),
+        ]);
+    }
+
+    #[test]
+    fn test_into_spare_u8() {
+        check_spare_ops::<u8>([
+            ("add", add_into_spare, AutoMath::add),
+            ("sub", sub_into_spare, AutoMath::sub),
+            ("mul", mul_into_spare, AutoMath::mul),
+            ("div", div_into_spare, AutoMath::div),
+        ]);
+    }
+
+    #[test]
+    fn test_into_spare_length_mismatch() {
+        let mut result = vec![1.0f32];
+        let outcome = catch_unwind(AssertUnwindSafe(|| {
+            add_into_spare(&[1.0, 2.0], &[1.0], &mut result)
+        }));
+        assert!(outcome.is_err());
+        assert_eq!(result, [1.0], "output should be left untouched");
+
+        let outcome = catch_unwind(AssertUnwindSafe(|| {
+            compact(&[1.0, 0.0], &[1.0], &mut result)
+        }));
+        assert!(outcome.is_err());
+        assert_eq!(result, [1.0], "output should be left untouched");
+    }
+
+    fn check_compact<T>()
+    where
+        T: GatherOps + TrustedBufferElement + PartialEq + std::fmt::Debug,
+        AutoMath: Math<T>,
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        let (values, _) = get_sample_vectors::<T>(if cfg!(miri) { 133 } else { 1043 });
+        let len = values.len();
+
+        let zero = AutoMath::zero();
+        let one = AutoMath::one();
+        let masks = [
+            ("all-zero", vec![zero; len]),
+            ("all-one", vec![one; len]),
+            (
+                "sparse",
+                (0..len)
+                    .map(|i| {
+                        if i % 13 == 5 || i == len - 1 {
+                            one
+                        } else {
+                            zero
+                        }
+                    })
+                    .collect(),
+            ),
+        ];
+
+        for (name, mask) in masks {
+            let expected_indices = (0..len as u32)
+                .filter(|&i| mask[i as usize] != zero)
+                .collect::<Vec<_>>();
+            let expected = expected_indices
+                .iter()
+                .map(|&i| values[i as usize])
+                .collect::<Vec<_>>();
+
+            let existing = values[..3].to_vec();
+            let mut result = existing.clone();
+            let count = compact(&mask, &values, &mut result);
+            assert_eq!(count, expected.len(), "{name} count mismatch");
+            assert_eq!(result[..3], existing, "{name} overwrote existing elements");
+            assert_eq!(result[3..], expected, "{name} values mismatch");
+
+            let mut indices = vec![7];
+            let count = mask_to_indices(&mask, &mut indices);
+            assert_eq!(count, expected_indices.len(), "{name} count mismatch");
+            assert_eq!(indices[0], 7, "{name} overwrote existing elements");
+            assert_eq!(indices[1..], expected_indices, "{name} indices mismatch");
+        }
+    }
+
+    #[test]
+    fn test_compact_f32() {
+        check_compact::<f32>();
+    }
+
+    #[test]
+    fn test_compact_i16() {
+        check_compact::<i16>();
+    }
+
+    #[test]
+    fn test_compact_u8() {
+        check_compact::<u8>();
+    }
+
+    #[test]
+    fn test_compact_u64() {
+        check_compact::<u64>();
+    }
+}
@@ -54,6 +54,36 @@ pub trait ArithmeticOps: Sized + Copy {
         B2::Loader: MemLoader<Value = Self>,
         for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
 
+    /// Performs an element wise addition of two input buffers `lhs` and `rhs` that can
+    /// be projected to the desired output size of `result`, writing the result with
+    /// non-temporal stores where available.
+    ///
+    /// This is intended for outputs much larger than the cache, see
+    /// [cfavml::add_streaming](crate::add_streaming) for more details.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     result[i] = a[i] + b[i]
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn add_streaming<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
+
     /// Performs an element wise subtraction of two input buffers `a` and `b` that can
     /// be projected to the desired output size of `result`.
     ///
@@ -188,6 +218,149 @@ pub trait ArithmeticOps: Sized + Copy {
         B2: IntoMemLoader<Self>,
         B2::Loader: MemLoader<Value = Self>,
         for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
+
+    /// Computes the outer product of the `n` element vector `a` and the `m` element
+    /// vector `b`, writing the row-major `n x m` matrix to `result`.
+    ///
+    /// See [cfavml::outer_product](crate::outer_product) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; n * m]
+    ///
+    /// for i in range(n):
+    ///     for j in range(m):
+    ///         result[i * m + j] = a[i] * b[j]
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `a` is not exactly `n` elements in length, `b` is not exactly `m` elements
+    /// in length or `result` is not exactly `n * m` elements in length.
+    fn outer_product(n: usize, m: usize, a: &[Self], b: &[Self], result: &mut [Self]);
+
+    /// Performs an element wise addition of vectors `a` and `b` in place, writing the
+    /// result back to `a`.
+    ///
+    /// See [cfavml::add_inplace](crate::add_inplace) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     a[i] = a[i] + b[i]
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in length.
+    fn add_inplace(a: &mut [Self], b: &[Self]);
+
+    /// Performs an element wise addition of vector `a` and a single broadcast `value`
+    /// in place, writing the result back to `a`.
+    ///
+    /// See [cfavml::add_value_inplace](crate::add_value_inplace) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     a[i] = a[i] + value
+    /// ```
+    fn add_value_inplace(a: &mut [Self], value: Self);
+
+    /// Performs an element wise subtraction of vectors `a` and `b` in place, writing the
+    /// result back to `a`.
+    ///
+    /// See [cfavml::sub_inplace](crate::sub_inplace) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     a[i] = a[i] - b[i]
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in length.
+    fn sub_inplace(a: &mut [Self], b: &[Self]);
+
+    /// Performs an element wise subtraction of vector `a` and a single broadcast `value`
+    /// in place, writing the result back to `a`.
+    ///
+    /// See [cfavml::sub_value_inplace](crate::sub_value_inplace) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     a[i] = a[i] - value
+    /// ```
+    fn sub_value_inplace(a: &mut [Self], value: Self);
+
+    /// Performs an element wise multiplication of vectors `a` and `b` in place, writing the
+    /// result back to `a`.
+    ///
+    /// See [cfavml::mul_inplace](crate::mul_inplace) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     a[i] = a[i] * b[i]
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in length.
+    fn mul_inplace(a: &mut [Self], b: &[Self]);
+
+    /// Performs an element wise multiplication of vector `a` and a single broadcast `value`
+    /// in place, writing the result back to `a`.
+    ///
+    /// See [cfavml::mul_value_inplace](crate::mul_value_inplace) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     a[i] = a[i] * value
+    /// ```
+    fn mul_value_inplace(a: &mut [Self], value: Self);
+
+    /// Performs an element wise division of vectors `a` and `b` in place, writing the
+    /// result back to `a`.
+    ///
+    /// See [cfavml::div_inplace](crate::div_inplace) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     a[i] = a[i] / b[i]
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in length.
+    fn div_inplace(a: &mut [Self], b: &[Self]);
+
+    /// Performs an element wise division of vector `a` and a single broadcast `value`
+    /// in place, writing the result back to `a`.
+    ///
+    /// See [cfavml::div_value_inplace](crate::div_value_inplace) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     a[i] = a[i] / value
+    /// ```
+    fn div_value_inplace(a: &mut [Self], value: Self);
 }
 
 macro_rules! arithmetic_ops {
@@ -212,6 +385,26 @@ macro_rules! arithmetic_ops {
                 }
             }
 
+            fn add_streaming<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_add_vertical_nt,
+                        avx2 = export_arithmetic_ops::generic_avx2_add_vertical_nt,
+                        neon = export_arithmetic_ops::generic_neon_add_vertical_nt,
+                        fallback =
+                            export_arithmetic_ops::generic_fallback_add_vertical_nt,
+                        args = (lhs, rhs, result)
+                    );
+                }
+            }
+
             fn sub_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
             where
                 B1: IntoMemLoader<Self>,
@@ -268,6 +461,120 @@ macro_rules! arithmetic_ops {
                     );
                 }
             }
+
+            fn outer_product(
+                n: usize,
+                m: usize,
+                a: &[Self],
+                b: &[Self],
+                result: &mut [Self],
+            ) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_outer_product,
+                        avx2 = export_arithmetic_ops::generic_avx2_outer_product,
+                        neon = export_arithmetic_ops::generic_neon_outer_product,
+                        fallback = export_arithmetic_ops::generic_fallback_outer_product,
+                        args = (n, m, a, b, result)
+                    );
+                }
+            }
+
+            fn add_inplace(a: &mut [Self], b: &[Self]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_add_inplace,
+                        avx2 = export_arithmetic_ops::generic_avx2_add_inplace,
+                        neon = export_arithmetic_ops::generic_neon_add_inplace,
+                        fallback = export_arithmetic_ops::generic_fallback_add_inplace,
+                        args = (a, b)
+                    );
+                }
+            }
+
+            fn add_value_inplace(a: &mut [Self], value: Self) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_add_inplace,
+                        avx2 = export_arithmetic_ops::generic_avx2_add_inplace,
+                        neon = export_arithmetic_ops::generic_neon_add_inplace,
+                        fallback = export_arithmetic_ops::generic_fallback_add_inplace,
+                        args = (a, value)
+                    );
+                }
+            }
+
+            fn sub_inplace(a: &mut [Self], b: &[Self]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_sub_inplace,
+                        avx2 = export_arithmetic_ops::generic_avx2_sub_inplace,
+                        neon = export_arithmetic_ops::generic_neon_sub_inplace,
+                        fallback = export_arithmetic_ops::generic_fallback_sub_inplace,
+                        args = (a, b)
+                    );
+                }
+            }
+
+            fn sub_value_inplace(a: &mut [Self], value: Self) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_sub_inplace,
+                        avx2 = export_arithmetic_ops::generic_avx2_sub_inplace,
+                        neon = export_arithmetic_ops::generic_neon_sub_inplace,
+                        fallback = export_arithmetic_ops::generic_fallback_sub_inplace,
+                        args = (a, value)
+                    );
+                }
+            }
+
+            fn mul_inplace(a: &mut [Self], b: &[Self]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_mul_inplace,
+                        avx2 = export_arithmetic_ops::generic_avx2_mul_inplace,
+                        neon = export_arithmetic_ops::generic_neon_mul_inplace,
+                        fallback = export_arithmetic_ops::generic_fallback_mul_inplace,
+                        args = (a, b)
+                    );
+                }
+            }
+
+            fn mul_value_inplace(a: &mut [Self], value: Self) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_mul_inplace,
+                        avx2 = export_arithmetic_ops::generic_avx2_mul_inplace,
+                        neon = export_arithmetic_ops::generic_neon_mul_inplace,
+                        fallback = export_arithmetic_ops::generic_fallback_mul_inplace,
+                        args = (a, value)
+                    );
+                }
+            }
+
+            fn div_inplace(a: &mut [Self], b: &[Self]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_div_inplace,
+                        avx2 = export_arithmetic_ops::generic_avx2_div_inplace,
+                        neon = export_arithmetic_ops::generic_neon_div_inplace,
+                        fallback = export_arithmetic_ops::generic_fallback_div_inplace,
+                        args = (a, b)
+                    );
+                }
+            }
+
+            fn div_value_inplace(a: &mut [Self], value: Self) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_div_inplace,
+                        avx2 = export_arithmetic_ops::generic_avx2_div_inplace,
+                        neon = export_arithmetic_ops::generic_neon_div_inplace,
+                        fallback = export_arithmetic_ops::generic_fallback_div_inplace,
+                        args = (a, value)
+                    );
+                }
+            }
         }
     };
 }
@@ -282,3 +589,395 @@ arithmetic_ops!(u8);
 arithmetic_ops!(u16);
 arithmetic_ops!(u32);
 arithmetic_ops!(u64);
+
+#[cfg(feature = "half")]
+impl ArithmeticOps for half::f16 {
+    fn add_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+    {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_add_vertical,
+                fallback = export_arithmetic_ops::generic_fallback_add_vertical,
+                args = (lhs, rhs, result)
+            );
+        }
+    }
+
+    fn add_streaming<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+    {
+        // The `f16` registers convert to and from `f32`, so there is no benefit
+        // to streaming the writes.
+        Self::add_vertical(lhs, rhs, result)
+    }
+
+    fn sub_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+    {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_sub_vertical,
+                fallback = export_arithmetic_ops::generic_fallback_sub_vertical,
+                args = (lhs, rhs, result)
+            );
+        }
+    }
+
+    fn mul_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+    {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_mul_vertical,
+                fallback = export_arithmetic_ops::generic_fallback_mul_vertical,
+                args = (lhs, rhs, result)
+            );
+        }
+    }
+
+    fn div_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+    {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_div_vertical,
+                fallback = export_arithmetic_ops::generic_fallback_div_vertical,
+                args = (lhs, rhs, result)
+            );
+        }
+    }
+
+    fn outer_product(n: usize, m: usize, a: &[Self], b: &[Self], result: &mut [Self]) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_outer_product,
+                fallback = export_arithmetic_ops::generic_fallback_outer_product,
+                args = (n, m, a, b, result)
+            );
+        }
+    }
+
+    fn add_inplace(a: &mut [Self], b: &[Self]) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_add_inplace,
+                fallback = export_arithmetic_ops::generic_fallback_add_inplace,
+                args = (a, b)
+            );
+        }
+    }
+
+    fn add_value_inplace(a: &mut [Self], value: Self) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_add_inplace,
+                fallback = export_arithmetic_ops::generic_fallback_add_inplace,
+                args = (a, value)
+            );
+        }
+    }
+
+    fn sub_inplace(a: &mut [Self], b: &[Self]) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_sub_inplace,
+                fallback = export_arithmetic_ops::generic_fallback_sub_inplace,
+                args = (a, b)
+            );
+        }
+    }
+
+    fn sub_value_inplace(a: &mut [Self], value: Self) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_sub_inplace,
+                fallback = export_arithmetic_ops::generic_fallback_sub_inplace,
+                args = (a, value)
+            );
+        }
+    }
+
+    fn mul_inplace(a: &mut [Self], b: &[Self]) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_mul_inplace,
+                fallback = export_arithmetic_ops::generic_fallback_mul_inplace,
+                args = (a, b)
+            );
+        }
+    }
+
+    fn mul_value_inplace(a: &mut [Self], value: Self) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_mul_inplace,
+                fallback = export_arithmetic_ops::generic_fallback_mul_inplace,
+                args = (a, value)
+            );
+        }
+    }
+
+    fn div_inplace(a: &mut [Self], b: &[Self]) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_div_inplace,
+                fallback = export_arithmetic_ops::generic_fallback_div_inplace,
+                args = (a, b)
+            );
+        }
+    }
+
+    fn div_value_inplace(a: &mut [Self], value: Self) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_arithmetic_ops::generic_avx2f16c_div_inplace,
+                fallback = export_arithmetic_ops::generic_fallback_div_inplace,
+                args = (a, value)
+            );
+        }
+    }
+}
+
+/// Saturating arithmetic operations over vectors.
+///
+/// These are only implemented for the integer types.
+pub trait SaturatingArithmeticOps: Sized + Copy {
+    /// Performs an element wise saturating addition of two input buffers `lhs` and `rhs` that can
+    /// be projected to the desired output size of `result`.
+    ///
+    /// Rather than wrapping on overflow, each result is clamped to the bounds of the type,
+    /// matching `saturating_add`.
+    ///
+    /// See [cfavml::saturating_add_vertical](crate::saturating_add_vertical) for examples.
+    ///
+    /// ### Projecting Vectors
+    ///
+    /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+    /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+    ///
+    /// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+    /// which exhibit the standard behaviour as you might expect.
+    ///
+    /// When providing two slices as inputs they cannot be projected to a buffer
+    /// that is larger their input sizes by default. This means providing two slices
+    /// of `128` elements in length must take a result buffer of `128` elements in length.
+    ///
+    /// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+    /// enables projecting of the input buffer to new sizes providing the new size is a
+    /// multiple of the original size. When this buffer is projected, it is effectively
+    /// repeated `N` times, where `N` is how many times the old size fits into the new size.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     result[i] = clamp(a[i] + b[i], T::MIN, T::MAX)
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn saturating_add_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
+
+    /// Performs an element wise saturating subtraction of input buffer `rhs` from `lhs`, where
+    /// both can be projected to the desired output size of `result`.
+    ///
+    /// Rather than wrapping on overflow, each result is clamped to the bounds of the type,
+    /// matching `saturating_sub`.
+    ///
+    /// See [cfavml::saturating_sub_vertical](crate::saturating_sub_vertical) for examples.
+    ///
+    /// ### Projecting Vectors
+    ///
+    /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+    /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+    ///
+    /// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+    /// which exhibit the standard behaviour as you might expect.
+    ///
+    /// When providing two slices as inputs they cannot be projected to a buffer
+    /// that is larger their input sizes by default. This means providing two slices
+    /// of `128` elements in length must take a result buffer of `128` elements in length.
+    ///
+    /// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+    /// enables projecting of the input buffer to new sizes providing the new size is a
+    /// multiple of the original size. When this buffer is projected, it is effectively
+    /// repeated `N` times, where `N` is how many times the old size fits into the new size.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     result[i] = clamp(a[i] - b[i], T::MIN, T::MAX)
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn saturating_sub_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
+}
+
+macro_rules! saturating_arithmetic_ops {
+    ($t:ty) => {
+        impl SaturatingArithmeticOps for $t {
+            fn saturating_add_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_saturating_add_vertical,
+                        avx2 = export_arithmetic_ops::generic_avx2_saturating_add_vertical,
+                        neon = export_arithmetic_ops::generic_neon_saturating_add_vertical,
+                        fallback = export_arithmetic_ops::generic_fallback_saturating_add_vertical,
+                        args = (lhs, rhs, result)
+                    );
+                }
+            }
+
+            fn saturating_sub_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_saturating_sub_vertical,
+                        avx2 = export_arithmetic_ops::generic_avx2_saturating_sub_vertical,
+                        neon = export_arithmetic_ops::generic_neon_saturating_sub_vertical,
+                        fallback = export_arithmetic_ops::generic_fallback_saturating_sub_vertical,
+                        args = (lhs, rhs, result)
+                    );
+                }
+            }
+        }
+    };
+}
+
+saturating_arithmetic_ops!(i8);
+saturating_arithmetic_ops!(i16);
+saturating_arithmetic_ops!(i32);
+saturating_arithmetic_ops!(i64);
+saturating_arithmetic_ops!(u8);
+saturating_arithmetic_ops!(u16);
+saturating_arithmetic_ops!(u32);
+saturating_arithmetic_ops!(u64);
+
+/// Negation operations over vectors.
+///
+/// These are only implemented for the signed integer and float types.
+pub trait NegOps: Sized + Copy {
+    /// Performs an element wise negation of the input buffer `a` that can
+    /// be projected to the desired output size of `result`.
+    ///
+    /// Integer types wrap on overflow matching `wrapping_neg`, float types flip the sign
+    /// bit so `-0.0` and `NaN` values match scalar negation.
+    ///
+    /// See [cfavml::neg_vertical](crate::neg_vertical) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     result[i] = -a[i]
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vector `a` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn neg_vertical<B1, B2>(a: B1, result: &mut [B2])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = Self>;
+}
+
+macro_rules! neg_ops {
+    ($t:ty) => {
+        impl NegOps for $t {
+            fn neg_vertical<B1, B2>(a: B1, result: &mut [B2])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_arithmetic_ops::generic_avx512_neg_vertical,
+                        avx2 = export_arithmetic_ops::generic_avx2_neg_vertical,
+                        neon = export_arithmetic_ops::generic_neon_neg_vertical,
+                        fallback = export_arithmetic_ops::generic_fallback_neg_vertical,
+                        args = (a, result)
+                    );
+                }
+            }
+        }
+    };
+}
+
+neg_ops!(f32);
+neg_ops!(f64);
+neg_ops!(i8);
+neg_ops!(i16);
+neg_ops!(i32);
+neg_ops!(i64);
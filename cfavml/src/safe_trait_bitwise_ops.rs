@@ -0,0 +1,396 @@
+//! Safe but somewhat low-level variants of the bitwise operations in CFAVML.
+//!
+//! In general, I would recommend using the higher level generic functions api which provides
+//! some syntax sugar over these traits.
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::export_bitwise_ops;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+/// Various bitwise operations over vectors.
+///
+/// These are only implemented for the integer types.
+pub trait BitwiseOps: Sized + Copy {
+    /// Performs an element wise bitwise _and_ of two input buffers `lhs` and `rhs` that can
+    /// be projected to the desired output size of `result`.
+    ///
+    /// See [cfavml::bitand_vertical](crate::bitand_vertical) for examples.
+    ///
+    /// ### Projecting Vectors
+    ///
+    /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+    /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+    ///
+    /// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+    /// which exhibit the standard behaviour as you might expect.
+    ///
+    /// When providing two slices as inputs they cannot be projected to a buffer
+    /// that is larger their input sizes by default. This means providing two slices
+    /// of `128` elements in length must take a result buffer of `128` elements in length.
+    ///
+    /// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+    /// enables projecting of the input buffer to new sizes providing the new size is a
+    /// multiple of the original size. When this buffer is projected, it is effectively
+    /// repeated `N` times, where `N` is how many times the old size fits into the new size.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     result[i] = a[i] & b[i]
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn bitand_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
+
+    /// Performs an element wise bitwise _or_ of two input buffers `lhs` and `rhs` that can
+    /// be projected to the desired output size of `result`.
+    ///
+    /// See [cfavml::bitor_vertical](crate::bitor_vertical) for examples.
+    ///
+    /// ### Projecting Vectors
+    ///
+    /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+    /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+    ///
+    /// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+    /// which exhibit the standard behaviour as you might expect.
+    ///
+    /// When providing two slices as inputs they cannot be projected to a buffer
+    /// that is larger their input sizes by default. This means providing two slices
+    /// of `128` elements in length must take a result buffer of `128` elements in length.
+    ///
+    /// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+    /// enables projecting of the input buffer to new sizes providing the new size is a
+    /// multiple of the original size. When this buffer is projected, it is effectively
+    /// repeated `N` times, where `N` is how many times the old size fits into the new size.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     result[i] = a[i] | b[i]
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn bitor_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
+
+    /// Performs an element wise bitwise _xor_ of two input buffers `lhs` and `rhs` that can
+    /// be projected to the desired output size of `result`.
+    ///
+    /// See [cfavml::bitxor_vertical](crate::bitxor_vertical) for examples.
+    ///
+    /// ### Projecting Vectors
+    ///
+    /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+    /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+    ///
+    /// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+    /// which exhibit the standard behaviour as you might expect.
+    ///
+    /// When providing two slices as inputs they cannot be projected to a buffer
+    /// that is larger their input sizes by default. This means providing two slices
+    /// of `128` elements in length must take a result buffer of `128` elements in length.
+    ///
+    /// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+    /// enables projecting of the input buffer to new sizes providing the new size is a
+    /// multiple of the original size. When this buffer is projected, it is effectively
+    /// repeated `N` times, where `N` is how many times the old size fits into the new size.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     result[i] = a[i] ^ b[i]
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn bitxor_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
+
+    /// Performs an element wise bitwise _not_ of the input buffer `a` that can
+    /// be projected to the desired output size of `result`.
+    ///
+    /// See [cfavml::bitnot_vertical](crate::bitnot_vertical) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     result[i] = !a[i]
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vector `a` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn bitnot_vertical<B1, B2>(a: B1, result: &mut [B2])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = Self>;
+
+    /// Performs an element wise _shift left_ of input buffer `lhs` by the amounts in `rhs`, where
+    /// both can be projected to the desired output size of `result`.
+    ///
+    /// The shift amount is masked to the bit width of the type, matching `wrapping_shl`.
+    ///
+    /// See [cfavml::shl_vertical](crate::shl_vertical) for examples.
+    ///
+    /// ### Projecting Vectors
+    ///
+    /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+    /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+    ///
+    /// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+    /// which exhibit the standard behaviour as you might expect.
+    ///
+    /// When providing two slices as inputs they cannot be projected to a buffer
+    /// that is larger their input sizes by default. This means providing two slices
+    /// of `128` elements in length must take a result buffer of `128` elements in length.
+    ///
+    /// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+    /// enables projecting of the input buffer to new sizes providing the new size is a
+    /// multiple of the original size. When this buffer is projected, it is effectively
+    /// repeated `N` times, where `N` is how many times the old size fits into the new size.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     result[i] = a[i] << (b[i] & (BITS - 1))
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn shl_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
+
+    /// Performs an element wise _shift right_ of input buffer `lhs` by the amounts in `rhs`, where
+    /// both can be projected to the desired output size of `result`.
+    ///
+    /// Signed types perform an arithmetic shift and unsigned types a logical shift,
+    /// the shift amount is masked to the bit width of the type, matching `wrapping_shr`.
+    ///
+    /// See [cfavml::shr_vertical](crate::shr_vertical) for examples.
+    ///
+    /// ### Projecting Vectors
+    ///
+    /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+    /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+    ///
+    /// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+    /// which exhibit the standard behaviour as you might expect.
+    ///
+    /// When providing two slices as inputs they cannot be projected to a buffer
+    /// that is larger their input sizes by default. This means providing two slices
+    /// of `128` elements in length must take a result buffer of `128` elements in length.
+    ///
+    /// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+    /// enables projecting of the input buffer to new sizes providing the new size is a
+    /// multiple of the original size. When this buffer is projected, it is effectively
+    /// repeated `N` times, where `N` is how many times the old size fits into the new size.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     result[i] = a[i] >> (b[i] & (BITS - 1))
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn shr_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
+}
+
+macro_rules! bitwise_ops {
+    ($t:ty) => {
+        impl BitwiseOps for $t {
+            fn bitand_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_bitwise_ops::generic_avx512_bitand_vertical,
+                        avx2 = export_bitwise_ops::generic_avx2_bitand_vertical,
+                        neon = export_bitwise_ops::generic_neon_bitand_vertical,
+                        fallback = export_bitwise_ops::generic_fallback_bitand_vertical,
+                        args = (lhs, rhs, result)
+                    );
+                }
+            }
+
+            fn bitor_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_bitwise_ops::generic_avx512_bitor_vertical,
+                        avx2 = export_bitwise_ops::generic_avx2_bitor_vertical,
+                        neon = export_bitwise_ops::generic_neon_bitor_vertical,
+                        fallback = export_bitwise_ops::generic_fallback_bitor_vertical,
+                        args = (lhs, rhs, result)
+                    );
+                }
+            }
+
+            fn bitxor_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_bitwise_ops::generic_avx512_bitxor_vertical,
+                        avx2 = export_bitwise_ops::generic_avx2_bitxor_vertical,
+                        neon = export_bitwise_ops::generic_neon_bitxor_vertical,
+                        fallback = export_bitwise_ops::generic_fallback_bitxor_vertical,
+                        args = (lhs, rhs, result)
+                    );
+                }
+            }
+
+            fn bitnot_vertical<B1, B2>(a: B1, result: &mut [B2])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_bitwise_ops::generic_avx512_bitnot_vertical,
+                        avx2 = export_bitwise_ops::generic_avx2_bitnot_vertical,
+                        neon = export_bitwise_ops::generic_neon_bitnot_vertical,
+                        fallback = export_bitwise_ops::generic_fallback_bitnot_vertical,
+                        args = (a, result)
+                    );
+                }
+            }
+
+            fn shl_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_bitwise_ops::generic_avx512_shl_vertical,
+                        avx2 = export_bitwise_ops::generic_avx2_shl_vertical,
+                        neon = export_bitwise_ops::generic_neon_shl_vertical,
+                        fallback = export_bitwise_ops::generic_fallback_shl_vertical,
+                        args = (lhs, rhs, result)
+                    );
+                }
+            }
+
+            fn shr_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_bitwise_ops::generic_avx512_shr_vertical,
+                        avx2 = export_bitwise_ops::generic_avx2_shr_vertical,
+                        neon = export_bitwise_ops::generic_neon_shr_vertical,
+                        fallback = export_bitwise_ops::generic_fallback_shr_vertical,
+                        args = (lhs, rhs, result)
+                    );
+                }
+            }
+        }
+    };
+}
+
+bitwise_ops!(i8);
+bitwise_ops!(i16);
+bitwise_ops!(i32);
+bitwise_ops!(i64);
+bitwise_ops!(u8);
+bitwise_ops!(u16);
+bitwise_ops!(u32);
+bitwise_ops!(u64);
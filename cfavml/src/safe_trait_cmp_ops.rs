@@ -150,6 +150,56 @@ pub trait CmpOps: Sized + Copy {
         B2::Loader: MemLoader<Value = Self>,
         for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
 
+    /// Computes the running max of vector `a`, writing the max of elements `a[0..=i]`
+    /// to `result[i]`.
+    ///
+    /// See [cfavml::cumulative_max](crate::cumulative_max) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    /// running = -inf
+    ///
+    /// for i in range(dims):
+    ///     running = max(running, a[i])
+    ///     result[i] = running
+    ///
+    /// return result
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the size of vector `a` or `result` does not match `dims`.
+    fn cumulative_max<B>(dims: usize, a: &[Self], result: &mut [B])
+    where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>;
+
+    /// Computes the running min of vector `a`, writing the min of elements `a[0..=i]`
+    /// to `result[i]`.
+    ///
+    /// See [cfavml::cumulative_min](crate::cumulative_min) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    /// running = inf
+    ///
+    /// for i in range(dims):
+    ///     running = min(running, a[i])
+    ///     result[i] = running
+    ///
+    /// return result
+    /// ```
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the size of vector `a` or `result` does not match `dims`.
+    fn cumulative_min<B>(dims: usize, a: &[Self], result: &mut [B])
+    where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>;
+
     /// Checks each element pair from vectors `a` and `b` of size `dims`  comparing
     /// if element `a` is **_equal to_** element `b` returning a mask vector of the same type.
     ///
@@ -509,117 +559,961 @@ pub trait CmpOps: Sized + Copy {
         B2: IntoMemLoader<Self>,
         B2::Loader: MemLoader<Value = Self>,
         for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
-}
 
-macro_rules! cmp_ops {
-    ($t:ty) => {
-        impl CmpOps for $t {
-            fn max<B1>(a: B1) -> Self
-            where
-                B1: IntoMemLoader<Self>,
-                B1::Loader: MemLoader<Value = Self>,
-            {
-                unsafe {
-                    crate::dispatch!(
-                        avx512 = export_cmp_ops::generic_avx512_cmp_max,
-                        avx2 = export_cmp_ops::generic_avx2_cmp_max,
-                        neon = export_cmp_ops::generic_neon_cmp_max,
-                        fallback = export_cmp_ops::generic_fallback_cmp_max,
-                        args = (a)
-                    )
-                }
-            }
+    /// Selects each element from `lhs` where the matching element in `mask` is non-zero,
+    /// otherwise selecting the element from `rhs`, storing the output in `result`.
+    ///
+    /// See [cfavml::select_vertical](crate::select_vertical) for examples.
+    ///
+    /// ### Projecting Vectors
+    ///
+    /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+    /// broadcasting of the input buffers implementing `IntoMemLoader<T>`.
+    ///
+    /// By default, you can provide any combination of _slices_ and _broadcast values_,
+    /// which exhibit the standard behaviour as you might expect.
+    ///
+    /// When providing slices as inputs they cannot be projected to a buffer
+    /// that is larger their input sizes by default. This means providing slices
+    /// of `128` elements in length must take a result buffer of `128` elements in length.
+    ///
+    /// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+    /// enables projecting of the input buffer to new sizes providing the new size is a
+    /// multiple of the original size. When this buffer is projected, it is effectively
+    /// repeated `N` times, where `N` is how many times the old size fits into the new size.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     result[i] = mask[i] != 0 ? a[i] : b[i]
+    ///
+    /// return result
+    /// ```
+    ///
+    /// ### Note on `f32/f64` masks
+    ///
+    /// For `f32` and `f64` types, both `0.0` and `-0.0` are treated as zero and select from `rhs`,
+    /// a `NaN` mask value is non-zero and selects from `lhs`.
+    ///
+    /// ### Result buffer
+    ///
+    /// The result buffer can be either an initialized slice i.e. `&mut [Self]`
+    /// or it can be a slice holding potentially uninitialized data i.e. `&mut [MaybeUninit<Self>]`.
+    ///
+    /// Once the operation is complete, it is safe to assume the data written is fully initialized.
+    ///
+    /// ### Panics
+    ///
+    /// If vectors `mask`, `lhs` and `rhs` cannot be projected to the target size of `result`.
+    fn select_vertical<B1, B2, B3, B4>(mask: B1, lhs: B2, rhs: B3, result: &mut [B4])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        B3: IntoMemLoader<Self>,
+        B3::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B4]: WriteOnlyBuffer<Item = Self>;
 
-            fn max_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
-            where
-                B1: IntoMemLoader<Self>,
-                B1::Loader: MemLoader<Value = Self>,
-                B2: IntoMemLoader<Self>,
-                B2::Loader: MemLoader<Value = Self>,
-                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
-            {
-                unsafe {
-                    crate::dispatch!(
-                        avx512 = export_cmp_ops::generic_avx512_cmp_max_vertical,
-                        avx2 = export_cmp_ops::generic_avx2_cmp_max_vertical,
-                        neon = export_cmp_ops::generic_neon_cmp_max_vertical,
-                        fallback = export_cmp_ops::generic_fallback_cmp_max_vertical,
-                        args = (lhs, rhs, result)
-                    )
-                }
-            }
+    /// Counts the elements of vector `a` that are **_equal to_** the broadcast `value`.
+    ///
+    /// See [cfavml::count_eq_value](crate::count_eq_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// count = 0
+    ///
+    /// for i in range(dims):
+    ///     if a[i] == value:
+    ///         count += 1
+    ///
+    /// return count
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `1.0 == 1.0 -> true`
+    /// - `1.0 == NaN -> false`
+    /// - `NaN == NaN -> false`
+    fn count_eq_value<B1>(a: B1, value: Self) -> usize
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
 
-            fn min<B1>(a: B1) -> Self
-            where
-                B1: IntoMemLoader<Self>,
-                B1::Loader: MemLoader<Value = Self>,
-            {
-                unsafe {
-                    crate::dispatch!(
-                        avx512 = export_cmp_ops::generic_avx512_cmp_min,
-                        avx2 = export_cmp_ops::generic_avx2_cmp_min,
-                        neon = export_cmp_ops::generic_neon_cmp_min,
-                        fallback = export_cmp_ops::generic_fallback_cmp_min,
-                        args = (a)
-                    )
-                }
-            }
+    /// Counts the elements of vector `a` that are **_not equal to_** the broadcast `value`.
+    ///
+    /// See [cfavml::count_neq_value](crate::count_neq_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// count = 0
+    ///
+    /// for i in range(dims):
+    ///     if a[i] != value:
+    ///         count += 1
+    ///
+    /// return count
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are never equal to anything, even each other,
+    /// so they are always counted by this routine.
+    ///
+    /// - `0.0 != 1.0 -> true`
+    /// - `0.0 != NaN -> true`
+    /// - `NaN != NaN -> true`
+    fn count_neq_value<B1>(a: B1, value: Self) -> usize
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
 
-            fn min_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
-            where
-                B1: IntoMemLoader<Self>,
-                B1::Loader: MemLoader<Value = Self>,
-                B2: IntoMemLoader<Self>,
-                B2::Loader: MemLoader<Value = Self>,
-                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
-            {
-                unsafe {
-                    crate::dispatch!(
-                        avx512 = export_cmp_ops::generic_avx512_cmp_min_vertical,
-                        avx2 = export_cmp_ops::generic_avx2_cmp_min_vertical,
-                        neon = export_cmp_ops::generic_neon_cmp_min_vertical,
-                        fallback = export_cmp_ops::generic_fallback_cmp_min_vertical,
-                        args = (lhs, rhs, result)
-                    )
-                }
-            }
+    /// Counts the elements of vector `a` that are **_less than_** the broadcast `value`.
+    ///
+    /// See [cfavml::count_lt_value](crate::count_lt_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// count = 0
+    ///
+    /// for i in range(dims):
+    ///     if a[i] < value:
+    ///         count += 1
+    ///
+    /// return count
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `0.0 < 1.0 -> true`
+    /// - `1.0 < NaN -> false`
+    /// - `NaN < 1.0 -> false`
+    /// - `NaN < NaN -> false`
+    fn count_lt_value<B1>(a: B1, value: Self) -> usize
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
 
-            fn eq_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
-            where
-                B1: IntoMemLoader<Self>,
-                B1::Loader: MemLoader<Value = Self>,
-                B2: IntoMemLoader<Self>,
-                B2::Loader: MemLoader<Value = Self>,
-                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
-            {
-                unsafe {
-                    crate::dispatch!(
-                        avx512 = export_cmp_ops::generic_avx512_cmp_eq_vertical,
-                        avx2 = export_cmp_ops::generic_avx2_cmp_eq_vertical,
-                        neon = export_cmp_ops::generic_neon_cmp_eq_vertical,
-                        fallback = export_cmp_ops::generic_fallback_cmp_eq_vertical,
-                        args = (lhs, rhs, result)
-                    )
-                }
-            }
+    /// Counts the elements of vector `a` that are **_less than or equal to_** the broadcast `value`.
+    ///
+    /// See [cfavml::count_lte_value](crate::count_lte_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// count = 0
+    ///
+    /// for i in range(dims):
+    ///     if a[i] <= value:
+    ///         count += 1
+    ///
+    /// return count
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `0.0 <= 1.0 -> true`
+    /// - `1.0 <= NaN -> false`
+    /// - `NaN <= 1.0 -> false`
+    /// - `NaN <= NaN -> false`
+    fn count_lte_value<B1>(a: B1, value: Self) -> usize
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
 
-            fn neq_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
-            where
-                B1: IntoMemLoader<Self>,
-                B1::Loader: MemLoader<Value = Self>,
-                B2: IntoMemLoader<Self>,
-                B2::Loader: MemLoader<Value = Self>,
-                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
-            {
-                unsafe {
-                    crate::dispatch!(
-                        avx512 = export_cmp_ops::generic_avx512_cmp_neq_vertical,
-                        avx2 = export_cmp_ops::generic_avx2_cmp_neq_vertical,
-                        neon = export_cmp_ops::generic_neon_cmp_neq_vertical,
-                        fallback = export_cmp_ops::generic_fallback_cmp_neq_vertical,
-                        args = (lhs, rhs, result)
-                    )
-                }
+    /// Counts the elements of vector `a` that are **_greater than_** the broadcast `value`.
+    ///
+    /// See [cfavml::count_gt_value](crate::count_gt_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// count = 0
+    ///
+    /// for i in range(dims):
+    ///     if a[i] > value:
+    ///         count += 1
+    ///
+    /// return count
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `1.0 > 0.0 -> true`
+    /// - `1.0 > NaN -> false`
+    /// - `NaN > 1.0 -> false`
+    /// - `NaN > NaN -> false`
+    fn count_gt_value<B1>(a: B1, value: Self) -> usize
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Counts the elements of vector `a` that are **_greater than or equal to_** the broadcast `value`.
+    ///
+    /// See [cfavml::count_gte_value](crate::count_gte_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// count = 0
+    ///
+    /// for i in range(dims):
+    ///     if a[i] >= value:
+    ///         count += 1
+    ///
+    /// return count
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `1.0 >= 0.0 -> true`
+    /// - `1.0 >= NaN -> false`
+    /// - `NaN >= 1.0 -> false`
+    /// - `NaN >= NaN -> false`
+    fn count_gte_value<B1>(a: B1, value: Self) -> usize
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Counts the elements of vector `a` that are **_non-zero_**.
+    ///
+    /// See [cfavml::count_nonzero](crate::count_nonzero) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// count = 0
+    ///
+    /// for i in range(dims):
+    ///     if a[i] != 0:
+    ///         count += 1
+    ///
+    /// return count
+    /// ```
+    ///
+    /// ### Note on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types both `0.0` and `-0.0` are treated as zero, `NaN` values
+    /// are always counted as being non-zero.
+    fn count_nonzero<B1>(a: B1) -> usize
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_any_** element of vector `a` is **_equal to_** the broadcast `value`.
+    ///
+    /// See [cfavml::any_eq_value](crate::any_eq_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if a[i] == value:
+    ///         return true
+    ///
+    /// return false
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `1.0 == 1.0 -> true`
+    /// - `1.0 == NaN -> false`
+    /// - `NaN == NaN -> false`
+    fn any_eq_value<B1>(a: B1, value: Self) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_any_** element of vector `a` is **_not equal to_** the broadcast `value`.
+    ///
+    /// See [cfavml::any_neq_value](crate::any_neq_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if a[i] != value:
+    ///         return true
+    ///
+    /// return false
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are never equal to anything, even each other,
+    /// so they always match in this routine.
+    ///
+    /// - `0.0 != 1.0 -> true`
+    /// - `0.0 != NaN -> true`
+    /// - `NaN != NaN -> true`
+    fn any_neq_value<B1>(a: B1, value: Self) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_any_** element of vector `a` is **_less than_** the broadcast `value`.
+    ///
+    /// See [cfavml::any_lt_value](crate::any_lt_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if a[i] < value:
+    ///         return true
+    ///
+    /// return false
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `0.0 < 1.0 -> true`
+    /// - `1.0 < NaN -> false`
+    /// - `NaN < 1.0 -> false`
+    /// - `NaN < NaN -> false`
+    fn any_lt_value<B1>(a: B1, value: Self) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_any_** element of vector `a` is **_less than or equal to_** the broadcast `value`.
+    ///
+    /// See [cfavml::any_lte_value](crate::any_lte_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if a[i] <= value:
+    ///         return true
+    ///
+    /// return false
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `0.0 <= 1.0 -> true`
+    /// - `1.0 <= NaN -> false`
+    /// - `NaN <= 1.0 -> false`
+    /// - `NaN <= NaN -> false`
+    fn any_lte_value<B1>(a: B1, value: Self) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_any_** element of vector `a` is **_greater than_** the broadcast `value`.
+    ///
+    /// See [cfavml::any_gt_value](crate::any_gt_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if a[i] > value:
+    ///         return true
+    ///
+    /// return false
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `1.0 > 0.0 -> true`
+    /// - `1.0 > NaN -> false`
+    /// - `NaN > 1.0 -> false`
+    /// - `NaN > NaN -> false`
+    fn any_gt_value<B1>(a: B1, value: Self) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_any_** element of vector `a` is **_greater than or equal to_** the broadcast `value`.
+    ///
+    /// See [cfavml::any_gte_value](crate::any_gte_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if a[i] >= value:
+    ///         return true
+    ///
+    /// return false
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `1.0 >= 0.0 -> true`
+    /// - `1.0 >= NaN -> false`
+    /// - `NaN >= 1.0 -> false`
+    /// - `NaN >= NaN -> false`
+    fn any_gte_value<B1>(a: B1, value: Self) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_all_** elements of vector `a` are **_equal to_** the broadcast `value`.
+    ///
+    /// See [cfavml::all_eq_value](crate::all_eq_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if not (a[i] == value):
+    ///         return false
+    ///
+    /// return true
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `1.0 == 1.0 -> true`
+    /// - `1.0 == NaN -> false`
+    /// - `NaN == NaN -> false`
+    fn all_eq_value<B1>(a: B1, value: Self) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_all_** elements of vector `a` are **_not equal to_** the broadcast `value`.
+    ///
+    /// See [cfavml::all_neq_value](crate::all_neq_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if not (a[i] != value):
+    ///         return false
+    ///
+    /// return true
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are never equal to anything, even each other,
+    /// so they always match in this routine.
+    ///
+    /// - `0.0 != 1.0 -> true`
+    /// - `0.0 != NaN -> true`
+    /// - `NaN != NaN -> true`
+    fn all_neq_value<B1>(a: B1, value: Self) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_all_** elements of vector `a` are **_less than_** the broadcast `value`.
+    ///
+    /// See [cfavml::all_lt_value](crate::all_lt_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if not (a[i] < value):
+    ///         return false
+    ///
+    /// return true
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `0.0 < 1.0 -> true`
+    /// - `1.0 < NaN -> false`
+    /// - `NaN < 1.0 -> false`
+    /// - `NaN < NaN -> false`
+    fn all_lt_value<B1>(a: B1, value: Self) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_all_** elements of vector `a` are **_less than or equal to_** the broadcast `value`.
+    ///
+    /// See [cfavml::all_lte_value](crate::all_lte_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if not (a[i] <= value):
+    ///         return false
+    ///
+    /// return true
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `0.0 <= 1.0 -> true`
+    /// - `1.0 <= NaN -> false`
+    /// - `NaN <= 1.0 -> false`
+    /// - `NaN <= NaN -> false`
+    fn all_lte_value<B1>(a: B1, value: Self) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_all_** elements of vector `a` are **_greater than_** the broadcast `value`.
+    ///
+    /// See [cfavml::all_gt_value](crate::all_gt_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if not (a[i] > value):
+    ///         return false
+    ///
+    /// return true
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `1.0 > 0.0 -> true`
+    /// - `1.0 > NaN -> false`
+    /// - `NaN > 1.0 -> false`
+    /// - `NaN > NaN -> false`
+    fn all_gt_value<B1>(a: B1, value: Self) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_all_** elements of vector `a` are **_greater than or equal to_** the broadcast `value`.
+    ///
+    /// See [cfavml::all_gte_value](crate::all_gte_value) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if not (a[i] >= value):
+    ///         return false
+    ///
+    /// return true
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `1.0 >= 0.0 -> true`
+    /// - `1.0 >= NaN -> false`
+    /// - `NaN >= 1.0 -> false`
+    /// - `NaN >= NaN -> false`
+    fn all_gte_value<B1>(a: B1, value: Self) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Checks each element pair from vectors `a` and `b` of size `dims` comparing
+    /// if element `a` is **_equal to_** element `b`, writing the result of each check
+    /// as a single bit of `result`.
+    ///
+    /// See [cfavml::eq_bitmask](crate::eq_bitmask) for examples.
+    ///
+    /// Bits are packed least significant bit first within each byte, the trailing
+    /// bits of the last byte past `dims` are zeroed.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// bitmask = [0; ceil(dims / 8)]
+    ///
+    /// for i in range(dims):
+    ///     if a[i] == b[i]:
+    ///         bitmask[i / 8] |= 1 << (i % 8)
+    ///
+    /// return bitmask
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `0.0 == 0.0 -> true`
+    /// - `0.0 == NaN -> false`
+    /// - `NaN == NaN -> false`
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the size of vector `a` or `b` does not match `dims` or `result`
+    /// is less than `dims.div_ceil(8)` bytes in length.
+    fn eq_bitmask(dims: usize, a: &[Self], b: &[Self], result: &mut [u8]);
+
+    /// Checks each element pair from vectors `a` and `b` of size `dims` comparing
+    /// if element `a` is **_not equal to_** element `b`, writing the result of each check
+    /// as a single bit of `result`.
+    ///
+    /// See [cfavml::neq_bitmask](crate::neq_bitmask) for examples.
+    ///
+    /// Bits are packed least significant bit first within each byte, the trailing
+    /// bits of the last byte past `dims` are zeroed.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// bitmask = [0; ceil(dims / 8)]
+    ///
+    /// for i in range(dims):
+    ///     if a[i] != b[i]:
+    ///         bitmask[i / 8] |= 1 << (i % 8)
+    ///
+    /// return bitmask
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `0.0 != 1.0 -> true`
+    /// - `0.0 != NaN -> true`
+    /// - `NaN != NaN -> true`
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the size of vector `a` or `b` does not match `dims` or `result`
+    /// is less than `dims.div_ceil(8)` bytes in length.
+    fn neq_bitmask(dims: usize, a: &[Self], b: &[Self], result: &mut [u8]);
+
+    /// Checks each element pair from vectors `a` and `b` of size `dims` comparing
+    /// if element `a` is **_less than_** element `b`, writing the result of each check
+    /// as a single bit of `result`.
+    ///
+    /// See [cfavml::lt_bitmask](crate::lt_bitmask) for examples.
+    ///
+    /// Bits are packed least significant bit first within each byte, the trailing
+    /// bits of the last byte past `dims` are zeroed.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// bitmask = [0; ceil(dims / 8)]
+    ///
+    /// for i in range(dims):
+    ///     if a[i] < b[i]:
+    ///         bitmask[i / 8] |= 1 << (i % 8)
+    ///
+    /// return bitmask
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `0.0 < 1.0 -> true`
+    /// - `0.0 < NaN -> false`
+    /// - `NaN < 1.0 -> false`
+    /// - `NaN < NaN -> false`
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the size of vector `a` or `b` does not match `dims` or `result`
+    /// is less than `dims.div_ceil(8)` bytes in length.
+    fn lt_bitmask(dims: usize, a: &[Self], b: &[Self], result: &mut [u8]);
+
+    /// Checks each element pair from vectors `a` and `b` of size `dims` comparing
+    /// if element `a` is **_less than or equal to_** element `b`, writing the result of each check
+    /// as a single bit of `result`.
+    ///
+    /// See [cfavml::lte_bitmask](crate::lte_bitmask) for examples.
+    ///
+    /// Bits are packed least significant bit first within each byte, the trailing
+    /// bits of the last byte past `dims` are zeroed.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// bitmask = [0; ceil(dims / 8)]
+    ///
+    /// for i in range(dims):
+    ///     if a[i] <= b[i]:
+    ///         bitmask[i / 8] |= 1 << (i % 8)
+    ///
+    /// return bitmask
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `0.0 <= 1.0 -> true`
+    /// - `1.0 <= 1.0 -> true`
+    /// - `0.0 <= NaN -> false`
+    /// - `NaN <= 1.0 -> false`
+    /// - `NaN <= NaN -> false`
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the size of vector `a` or `b` does not match `dims` or `result`
+    /// is less than `dims.div_ceil(8)` bytes in length.
+    fn lte_bitmask(dims: usize, a: &[Self], b: &[Self], result: &mut [u8]);
+
+    /// Checks each element pair from vectors `a` and `b` of size `dims` comparing
+    /// if element `a` is **_greater than_** element `b`, writing the result of each check
+    /// as a single bit of `result`.
+    ///
+    /// See [cfavml::gt_bitmask](crate::gt_bitmask) for examples.
+    ///
+    /// Bits are packed least significant bit first within each byte, the trailing
+    /// bits of the last byte past `dims` are zeroed.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// bitmask = [0; ceil(dims / 8)]
+    ///
+    /// for i in range(dims):
+    ///     if a[i] > b[i]:
+    ///         bitmask[i / 8] |= 1 << (i % 8)
+    ///
+    /// return bitmask
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `1.0 > 0.0 -> true`
+    /// - `1.0 > NaN -> false`
+    /// - `NaN > 1.0 -> false`
+    /// - `NaN > NaN -> false`
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the size of vector `a` or `b` does not match `dims` or `result`
+    /// is less than `dims.div_ceil(8)` bytes in length.
+    fn gt_bitmask(dims: usize, a: &[Self], b: &[Self], result: &mut [u8]);
+
+    /// Checks each element pair from vectors `a` and `b` of size `dims` comparing
+    /// if element `a` is **_greater than or equal to_** element `b`, writing the result of each check
+    /// as a single bit of `result`.
+    ///
+    /// See [cfavml::gte_bitmask](crate::gte_bitmask) for examples.
+    ///
+    /// Bits are packed least significant bit first within each byte, the trailing
+    /// bits of the last byte past `dims` are zeroed.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// bitmask = [0; ceil(dims / 8)]
+    ///
+    /// for i in range(dims):
+    ///     if a[i] >= b[i]:
+    ///         bitmask[i / 8] |= 1 << (i % 8)
+    ///
+    /// return bitmask
+    /// ```
+    ///
+    /// ### Note on `NaN` handling on `f32/f64` types
+    ///
+    /// For `f32` and `f64` types, `NaN` values are handled as always being `false` in **ANY** comparison.
+    /// Even when compared against each other.
+    ///
+    /// - `1.0 >= 0.0 -> true`
+    /// - `1.0 >= NaN -> false`
+    /// - `NaN >= 1.0 -> false`
+    /// - `NaN >= NaN -> false`
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the size of vector `a` or `b` does not match `dims` or `result`
+    /// is less than `dims.div_ceil(8)` bytes in length.
+    fn gte_bitmask(dims: usize, a: &[Self], b: &[Self], result: &mut [u8]);
+}
+
+macro_rules! cmp_ops {
+    ($t:ty) => {
+        impl CmpOps for $t {
+            fn max<B1>(a: B1) -> Self
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cmp_max,
+                        avx2 = export_cmp_ops::generic_avx2_cmp_max,
+                        neon = export_cmp_ops::generic_neon_cmp_max,
+                        fallback = export_cmp_ops::generic_fallback_cmp_max,
+                        args = (a)
+                    )
+                }
+            }
+
+            fn max_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cmp_max_vertical,
+                        avx2 = export_cmp_ops::generic_avx2_cmp_max_vertical,
+                        neon = export_cmp_ops::generic_neon_cmp_max_vertical,
+                        fallback = export_cmp_ops::generic_fallback_cmp_max_vertical,
+                        args = (lhs, rhs, result)
+                    )
+                }
+            }
+
+            fn min<B1>(a: B1) -> Self
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cmp_min,
+                        avx2 = export_cmp_ops::generic_avx2_cmp_min,
+                        neon = export_cmp_ops::generic_neon_cmp_min,
+                        fallback = export_cmp_ops::generic_fallback_cmp_min,
+                        args = (a)
+                    )
+                }
+            }
+
+            fn min_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cmp_min_vertical,
+                        avx2 = export_cmp_ops::generic_avx2_cmp_min_vertical,
+                        neon = export_cmp_ops::generic_neon_cmp_min_vertical,
+                        fallback = export_cmp_ops::generic_fallback_cmp_min_vertical,
+                        args = (lhs, rhs, result)
+                    )
+                }
+            }
+
+            fn cumulative_max<B>(dims: usize, a: &[Self], result: &mut [B])
+            where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cumulative_max,
+                        avx2 = export_cmp_ops::generic_avx2_cumulative_max,
+                        neon = export_cmp_ops::generic_neon_cumulative_max,
+                        fallback = export_cmp_ops::generic_fallback_cumulative_max,
+                        args = (dims, a, result)
+                    )
+                }
+            }
+
+            fn cumulative_min<B>(dims: usize, a: &[Self], result: &mut [B])
+            where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cumulative_min,
+                        avx2 = export_cmp_ops::generic_avx2_cumulative_min,
+                        neon = export_cmp_ops::generic_neon_cumulative_min,
+                        fallback = export_cmp_ops::generic_fallback_cumulative_min,
+                        args = (dims, a, result)
+                    )
+                }
+            }
+
+            fn eq_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cmp_eq_vertical,
+                        avx2 = export_cmp_ops::generic_avx2_cmp_eq_vertical,
+                        neon = export_cmp_ops::generic_neon_cmp_eq_vertical,
+                        fallback = export_cmp_ops::generic_fallback_cmp_eq_vertical,
+                        args = (lhs, rhs, result)
+                    )
+                }
+            }
+
+            fn neq_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cmp_neq_vertical,
+                        avx2 = export_cmp_ops::generic_avx2_cmp_neq_vertical,
+                        neon = export_cmp_ops::generic_neon_cmp_neq_vertical,
+                        fallback = export_cmp_ops::generic_fallback_cmp_neq_vertical,
+                        args = (lhs, rhs, result)
+                    )
+                }
             }
 
             fn lt_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
@@ -697,6 +1591,407 @@ macro_rules! cmp_ops {
                     )
                 }
             }
+
+            fn select_vertical<B1, B2, B3, B4>(
+                mask: B1,
+                lhs: B2,
+                rhs: B3,
+                result: &mut [B4],
+            ) where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                B3: IntoMemLoader<Self>,
+                B3::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B4]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_select_vertical,
+                        avx2 = export_cmp_ops::generic_avx2_select_vertical,
+                        neon = export_cmp_ops::generic_neon_select_vertical,
+                        fallback = export_cmp_ops::generic_fallback_select_vertical,
+                        args = (mask, lhs, rhs, result)
+                    )
+                }
+            }
+
+            fn count_eq_value<B1>(a: B1, value: Self) -> usize
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_count_eq_value,
+                        avx2 = export_cmp_ops::generic_avx2_count_eq_value,
+                        neon = export_cmp_ops::generic_neon_count_eq_value,
+                        fallback = export_cmp_ops::generic_fallback_count_eq_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn count_neq_value<B1>(a: B1, value: Self) -> usize
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_count_neq_value,
+                        avx2 = export_cmp_ops::generic_avx2_count_neq_value,
+                        neon = export_cmp_ops::generic_neon_count_neq_value,
+                        fallback = export_cmp_ops::generic_fallback_count_neq_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn count_lt_value<B1>(a: B1, value: Self) -> usize
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_count_lt_value,
+                        avx2 = export_cmp_ops::generic_avx2_count_lt_value,
+                        neon = export_cmp_ops::generic_neon_count_lt_value,
+                        fallback = export_cmp_ops::generic_fallback_count_lt_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn count_lte_value<B1>(a: B1, value: Self) -> usize
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_count_lte_value,
+                        avx2 = export_cmp_ops::generic_avx2_count_lte_value,
+                        neon = export_cmp_ops::generic_neon_count_lte_value,
+                        fallback = export_cmp_ops::generic_fallback_count_lte_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn count_gt_value<B1>(a: B1, value: Self) -> usize
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_count_gt_value,
+                        avx2 = export_cmp_ops::generic_avx2_count_gt_value,
+                        neon = export_cmp_ops::generic_neon_count_gt_value,
+                        fallback = export_cmp_ops::generic_fallback_count_gt_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn count_gte_value<B1>(a: B1, value: Self) -> usize
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_count_gte_value,
+                        avx2 = export_cmp_ops::generic_avx2_count_gte_value,
+                        neon = export_cmp_ops::generic_neon_count_gte_value,
+                        fallback = export_cmp_ops::generic_fallback_count_gte_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn count_nonzero<B1>(a: B1) -> usize
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_count_nonzero,
+                        avx2 = export_cmp_ops::generic_avx2_count_nonzero,
+                        neon = export_cmp_ops::generic_neon_count_nonzero,
+                        fallback = export_cmp_ops::generic_fallback_count_nonzero,
+                        args = (a)
+                    )
+                }
+            }
+
+            fn any_eq_value<B1>(a: B1, value: Self) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_any_eq_value,
+                        avx2 = export_cmp_ops::generic_avx2_any_eq_value,
+                        neon = export_cmp_ops::generic_neon_any_eq_value,
+                        fallback = export_cmp_ops::generic_fallback_any_eq_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn any_neq_value<B1>(a: B1, value: Self) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_any_neq_value,
+                        avx2 = export_cmp_ops::generic_avx2_any_neq_value,
+                        neon = export_cmp_ops::generic_neon_any_neq_value,
+                        fallback = export_cmp_ops::generic_fallback_any_neq_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn any_lt_value<B1>(a: B1, value: Self) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_any_lt_value,
+                        avx2 = export_cmp_ops::generic_avx2_any_lt_value,
+                        neon = export_cmp_ops::generic_neon_any_lt_value,
+                        fallback = export_cmp_ops::generic_fallback_any_lt_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn any_lte_value<B1>(a: B1, value: Self) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_any_lte_value,
+                        avx2 = export_cmp_ops::generic_avx2_any_lte_value,
+                        neon = export_cmp_ops::generic_neon_any_lte_value,
+                        fallback = export_cmp_ops::generic_fallback_any_lte_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn any_gt_value<B1>(a: B1, value: Self) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_any_gt_value,
+                        avx2 = export_cmp_ops::generic_avx2_any_gt_value,
+                        neon = export_cmp_ops::generic_neon_any_gt_value,
+                        fallback = export_cmp_ops::generic_fallback_any_gt_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn any_gte_value<B1>(a: B1, value: Self) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_any_gte_value,
+                        avx2 = export_cmp_ops::generic_avx2_any_gte_value,
+                        neon = export_cmp_ops::generic_neon_any_gte_value,
+                        fallback = export_cmp_ops::generic_fallback_any_gte_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn all_eq_value<B1>(a: B1, value: Self) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_all_eq_value,
+                        avx2 = export_cmp_ops::generic_avx2_all_eq_value,
+                        neon = export_cmp_ops::generic_neon_all_eq_value,
+                        fallback = export_cmp_ops::generic_fallback_all_eq_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn all_neq_value<B1>(a: B1, value: Self) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_all_neq_value,
+                        avx2 = export_cmp_ops::generic_avx2_all_neq_value,
+                        neon = export_cmp_ops::generic_neon_all_neq_value,
+                        fallback = export_cmp_ops::generic_fallback_all_neq_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn all_lt_value<B1>(a: B1, value: Self) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_all_lt_value,
+                        avx2 = export_cmp_ops::generic_avx2_all_lt_value,
+                        neon = export_cmp_ops::generic_neon_all_lt_value,
+                        fallback = export_cmp_ops::generic_fallback_all_lt_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn all_lte_value<B1>(a: B1, value: Self) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_all_lte_value,
+                        avx2 = export_cmp_ops::generic_avx2_all_lte_value,
+                        neon = export_cmp_ops::generic_neon_all_lte_value,
+                        fallback = export_cmp_ops::generic_fallback_all_lte_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn all_gt_value<B1>(a: B1, value: Self) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_all_gt_value,
+                        avx2 = export_cmp_ops::generic_avx2_all_gt_value,
+                        neon = export_cmp_ops::generic_neon_all_gt_value,
+                        fallback = export_cmp_ops::generic_fallback_all_gt_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn all_gte_value<B1>(a: B1, value: Self) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_all_gte_value,
+                        avx2 = export_cmp_ops::generic_avx2_all_gte_value,
+                        neon = export_cmp_ops::generic_neon_all_gte_value,
+                        fallback = export_cmp_ops::generic_fallback_all_gte_value,
+                        args = (a, value)
+                    )
+                }
+            }
+
+            fn eq_bitmask(dims: usize, a: &[Self], b: &[Self], result: &mut [u8]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cmp_eq_bitmask,
+                        avx2 = export_cmp_ops::generic_avx2_cmp_eq_bitmask,
+                        neon = export_cmp_ops::generic_neon_cmp_eq_bitmask,
+                        fallback = export_cmp_ops::generic_fallback_cmp_eq_bitmask,
+                        args = (dims, a, b, result)
+                    )
+                }
+            }
+
+            fn neq_bitmask(dims: usize, a: &[Self], b: &[Self], result: &mut [u8]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cmp_neq_bitmask,
+                        avx2 = export_cmp_ops::generic_avx2_cmp_neq_bitmask,
+                        neon = export_cmp_ops::generic_neon_cmp_neq_bitmask,
+                        fallback = export_cmp_ops::generic_fallback_cmp_neq_bitmask,
+                        args = (dims, a, b, result)
+                    )
+                }
+            }
+
+            fn lt_bitmask(dims: usize, a: &[Self], b: &[Self], result: &mut [u8]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cmp_lt_bitmask,
+                        avx2 = export_cmp_ops::generic_avx2_cmp_lt_bitmask,
+                        neon = export_cmp_ops::generic_neon_cmp_lt_bitmask,
+                        fallback = export_cmp_ops::generic_fallback_cmp_lt_bitmask,
+                        args = (dims, a, b, result)
+                    )
+                }
+            }
+
+            fn lte_bitmask(dims: usize, a: &[Self], b: &[Self], result: &mut [u8]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cmp_lte_bitmask,
+                        avx2 = export_cmp_ops::generic_avx2_cmp_lte_bitmask,
+                        neon = export_cmp_ops::generic_neon_cmp_lte_bitmask,
+                        fallback = export_cmp_ops::generic_fallback_cmp_lte_bitmask,
+                        args = (dims, a, b, result)
+                    )
+                }
+            }
+
+            fn gt_bitmask(dims: usize, a: &[Self], b: &[Self], result: &mut [u8]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cmp_gt_bitmask,
+                        avx2 = export_cmp_ops::generic_avx2_cmp_gt_bitmask,
+                        neon = export_cmp_ops::generic_neon_cmp_gt_bitmask,
+                        fallback = export_cmp_ops::generic_fallback_cmp_gt_bitmask,
+                        args = (dims, a, b, result)
+                    )
+                }
+            }
+
+            fn gte_bitmask(dims: usize, a: &[Self], b: &[Self], result: &mut [u8]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_cmp_ops::generic_avx512_cmp_gte_bitmask,
+                        avx2 = export_cmp_ops::generic_avx2_cmp_gte_bitmask,
+                        neon = export_cmp_ops::generic_neon_cmp_gte_bitmask,
+                        fallback = export_cmp_ops::generic_fallback_cmp_gte_bitmask,
+                        args = (dims, a, b, result)
+                    )
+                }
+            }
         }
     };
 }
@@ -3,7 +3,8 @@
 //! In general, I would recommend using the higher level generic functions api which provides
 //! some syntax sugar over these traits.
 
-use crate::danger::export_distance_ops;
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::{export_agg_ops, export_distance_ops};
 use crate::mem_loader::{IntoMemLoader, MemLoader};
 
 /// Various spacial distance operations between vectors.
@@ -103,6 +104,169 @@ pub trait DistanceOps: Sized + Copy {
     where
         B1: IntoMemLoader<Self>,
         B1::Loader: MemLoader<Value = Self>;
+
+    /// Calculates the cosine similarity distance between the `query` vector and each
+    /// vector within the contiguous `batch` of vectors, writing the distance of the
+    /// `i`th vector to `result[i]`.
+    ///
+    /// See [cfavml::cosine_batch](crate::cosine_batch) for examples.
+    ///
+    /// # Panics
+    ///
+    /// If `batch` is not exactly `query.len() * result.len()` elements in length.
+    fn cosine_batch<B>(query: &[Self], batch: &[Self], result: &mut [B])
+    where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>;
+
+    /// Calculates the dot product between the `query` vector and each
+    /// vector within the contiguous `batch` of vectors, writing the dot product of the
+    /// `i`th vector to `result[i]`.
+    ///
+    /// See [cfavml::dot_batch](crate::dot_batch) for examples.
+    ///
+    /// # Panics
+    ///
+    /// If `batch` is not exactly `query.len() * result.len()` elements in length.
+    fn dot_batch<B>(query: &[Self], batch: &[Self], result: &mut [B])
+    where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>;
+
+    /// Calculates the dot product between the `query` vector and each of the `dims`
+    /// element vectors within `data` selected by `indices`, writing the dot product of
+    /// the vector selected by `indices[n]` to `result[n]`.
+    ///
+    /// See [cfavml::dot_indexed](crate::dot_indexed) for examples.
+    ///
+    /// # Panics
+    ///
+    /// If `query` is not exactly `dims` elements in length, `result` is not exactly
+    /// `indices.len()` elements in length or any of the `indices` are out of bounds of
+    /// `data`.
+    fn dot_indexed<B>(
+        dims: usize,
+        query: &[Self],
+        data: &[Self],
+        indices: &[usize],
+        result: &mut [B],
+    ) where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>;
+
+    /// Calculates the weighted dot product between vectors `a` and `b`, scaling the
+    /// product of each pair of elements by the matching weight in `w`.
+    ///
+    /// See [cfavml::weighted_dot](crate::weighted_dot) for examples.
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a`, `b` and `w` are not exactly `dims` elements in length.
+    fn weighted_dot(dims: usize, a: &[Self], b: &[Self], w: &[Self]) -> Self;
+
+    /// Calculates the matrix-vector product of the `rows x cols` row-major `matrix` and
+    /// the `cols` element vector `x`, writing the `rows` element result to `out`.
+    ///
+    /// This is [batch_dot](DistanceOps::batch_dot) with `x` as the query.
+    ///
+    /// See [cfavml::gemv](crate::gemv) for examples.
+    ///
+    /// # Panics
+    ///
+    /// If `matrix` is not exactly `rows * cols` elements in length, `x` is not exactly
+    /// `cols` elements in length or `out` is not exactly `rows` elements in length.
+    fn gemv(rows: usize, cols: usize, matrix: &[Self], x: &[Self], out: &mut [Self]) {
+        Self::batch_dot(rows, cols, matrix, x, out)
+    }
+
+    /// Calculates the squared Euclidean distance between the `query` vector and each
+    /// vector within the contiguous `batch` of vectors, writing the distance of the
+    /// `i`th vector to `result[i]`.
+    ///
+    /// See [cfavml::squared_euclidean_batch](crate::squared_euclidean_batch) for examples.
+    ///
+    /// # Panics
+    ///
+    /// If `batch` is not exactly `query.len() * result.len()` elements in length.
+    fn squared_euclidean_batch<B>(query: &[Self], batch: &[Self], result: &mut [B])
+    where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>;
+
+    /// Calculates the dot product between every vector in `a` and every vector in `b`,
+    /// writing the row-major `a_rows x b_rows` matrix of results to `result`.
+    ///
+    /// See [cfavml::dot_matrix](crate::dot_matrix) for examples.
+    ///
+    /// # Panics
+    ///
+    /// If `a` is not exactly `dims * a_rows` elements in length, `b` is not exactly
+    /// `dims * b_rows` elements in length or `result` is not exactly `a_rows * b_rows`
+    /// elements in length.
+    fn dot_matrix(
+        dims: usize,
+        a_rows: usize,
+        b_rows: usize,
+        a: &[Self],
+        b: &[Self],
+        result: &mut [Self],
+    );
+
+    /// Calculates the squared Euclidean distance between every vector in `a` and every
+    /// vector in `b`, writing the row-major `a_rows x b_rows` matrix of results to `result`.
+    ///
+    /// See [cfavml::squared_euclidean_matrix](crate::squared_euclidean_matrix) for examples.
+    ///
+    /// # Panics
+    ///
+    /// If `a` is not exactly `dims * a_rows` elements in length, `b` is not exactly
+    /// `dims * b_rows` elements in length or `result` is not exactly `a_rows * b_rows`
+    /// elements in length.
+    fn squared_euclidean_matrix(
+        dims: usize,
+        a_rows: usize,
+        b_rows: usize,
+        a: &[Self],
+        b: &[Self],
+        result: &mut [Self],
+    );
+
+    /// Calculates the dot product between every pair of vectors in `matrix`, writing the
+    /// symmetric row-major `rows x rows` Gram matrix of results to `result`.
+    ///
+    /// See [cfavml::gram_matrix](crate::gram_matrix) for examples.
+    ///
+    /// # Panics
+    ///
+    /// If `matrix` is not exactly `rows * dims` elements in length or `result` is not
+    /// exactly `rows * rows` elements in length.
+    fn gram_matrix(rows: usize, dims: usize, matrix: &[Self], result: &mut [Self]);
+
+    /// Calculates the squared Euclidean distance between every pair of the `n` vectors
+    /// in `matrix`, writing the distance between the `i`th and `j`th vector, where
+    /// `i < j`, to `result[i * n + j]`.
+    ///
+    /// See [cfavml::pairwise_distances](crate::pairwise_distances) for examples.
+    ///
+    /// # Panics
+    ///
+    /// If `matrix` is not exactly `n * dims` elements in length or `result` is not
+    /// exactly `n * n` elements in length.
+    fn pairwise_distances(n: usize, dims: usize, matrix: &[Self], result: &mut [Self]);
+
+    /// Calculates the dot product between each of the `n_rows` rows of `matrix` and
+    /// the `query` vector, writing the dot product of the `i`th row to `scores[i]`.
+    ///
+    /// See [cfavml::batch_dot](crate::batch_dot) for examples.
+    ///
+    /// # Panics
+    ///
+    /// If `matrix` is not exactly `n_rows * dims` elements in length, `query` is not
+    /// exactly `dims` elements in length or `scores` is not exactly `n_rows` elements
+    /// in length.
+    fn batch_dot(
+        n_rows: usize,
+        dims: usize,
+        matrix: &[Self],
+        query: &[Self],
+        scores: &mut [Self],
+    );
 }
 
 macro_rules! float_distance_ops {
@@ -182,6 +346,178 @@ macro_rules! float_distance_ops {
                     )
                 }
             }
+
+            fn cosine_batch<B>(query: &[Self], batch: &[Self], result: &mut [B])
+            where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_cosine_batch,
+                        avx2fma = export_distance_ops::generic_avx2fma_cosine_batch,
+                        avx2 = export_distance_ops::generic_avx2_cosine_batch,
+                        neon = export_distance_ops::generic_neon_cosine_batch,
+                        fallback = export_distance_ops::generic_fallback_cosine_batch,
+                        args = (query, batch, result)
+                    )
+                }
+            }
+
+            fn dot_batch<B>(query: &[Self], batch: &[Self], result: &mut [B])
+            where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_batch,
+                        avx2fma = export_distance_ops::generic_avx2fma_dot_batch,
+                        avx2 = export_distance_ops::generic_avx2_dot_batch,
+                        neon = export_distance_ops::generic_neon_dot_batch,
+                        fallback = export_distance_ops::generic_fallback_dot_batch,
+                        args = (query, batch, result)
+                    )
+                }
+            }
+
+            fn dot_indexed<B>(
+                dims: usize,
+                query: &[Self],
+                data: &[Self],
+                indices: &[usize],
+                result: &mut [B],
+            ) where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_indexed,
+                        avx2fma = export_distance_ops::generic_avx2fma_dot_indexed,
+                        avx2 = export_distance_ops::generic_avx2_dot_indexed,
+                        neon = export_distance_ops::generic_neon_dot_indexed,
+                        fallback = export_distance_ops::generic_fallback_dot_indexed,
+                        args = (dims, query, data, indices, result)
+                    )
+                }
+            }
+
+            fn weighted_dot(dims: usize, a: &[Self], b: &[Self], w: &[Self]) -> Self {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_weighted_dot,
+                        avx2fma = export_distance_ops::generic_avx2fma_weighted_dot,
+                        avx2 = export_distance_ops::generic_avx2_weighted_dot,
+                        neon = export_distance_ops::generic_neon_weighted_dot,
+                        fallback = export_distance_ops::generic_fallback_weighted_dot,
+                        args = (dims, a, b, w)
+                    )
+                }
+            }
+
+            fn squared_euclidean_batch<B>(query: &[Self], batch: &[Self], result: &mut [B])
+            where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_squared_euclidean_batch,
+                        avx2fma = export_distance_ops::generic_avx2fma_squared_euclidean_batch,
+                        avx2 = export_distance_ops::generic_avx2_squared_euclidean_batch,
+                        neon = export_distance_ops::generic_neon_squared_euclidean_batch,
+                        fallback = export_distance_ops::generic_fallback_squared_euclidean_batch,
+                        args = (query, batch, result)
+                    )
+                }
+            }
+
+            fn dot_matrix(
+                dims: usize,
+                a_rows: usize,
+                b_rows: usize,
+                a: &[Self],
+                b: &[Self],
+                result: &mut [Self],
+            ) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_matrix,
+                        avx2fma = export_distance_ops::generic_avx2fma_dot_matrix,
+                        avx2 = export_distance_ops::generic_avx2_dot_matrix,
+                        neon = export_distance_ops::generic_neon_dot_matrix,
+                        fallback = export_distance_ops::generic_fallback_dot_matrix,
+                        args = (dims, a_rows, b_rows, a, b, result)
+                    )
+                }
+            }
+
+            fn squared_euclidean_matrix(
+                dims: usize,
+                a_rows: usize,
+                b_rows: usize,
+                a: &[Self],
+                b: &[Self],
+                result: &mut [Self],
+            ) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_squared_euclidean_matrix,
+                        avx2fma = export_distance_ops::generic_avx2fma_squared_euclidean_matrix,
+                        avx2 = export_distance_ops::generic_avx2_squared_euclidean_matrix,
+                        neon = export_distance_ops::generic_neon_squared_euclidean_matrix,
+                        fallback = export_distance_ops::generic_fallback_squared_euclidean_matrix,
+                        args = (dims, a_rows, b_rows, a, b, result)
+                    )
+                }
+            }
+
+            fn gram_matrix(rows: usize, dims: usize, matrix: &[Self], result: &mut [Self]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_gram_matrix,
+                        avx2fma = export_distance_ops::generic_avx2fma_gram_matrix,
+                        avx2 = export_distance_ops::generic_avx2_gram_matrix,
+                        neon = export_distance_ops::generic_neon_gram_matrix,
+                        fallback = export_distance_ops::generic_fallback_gram_matrix,
+                        args = (rows, dims, matrix, result)
+                    )
+                }
+            }
+
+            fn pairwise_distances(
+                n: usize,
+                dims: usize,
+                matrix: &[Self],
+                result: &mut [Self],
+            ) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_pairwise_distances,
+                        avx2fma = export_distance_ops::generic_avx2fma_pairwise_distances,
+                        avx2 = export_distance_ops::generic_avx2_pairwise_distances,
+                        neon = export_distance_ops::generic_neon_pairwise_distances,
+                        fallback = export_distance_ops::generic_fallback_pairwise_distances,
+                        args = (n, dims, matrix, result)
+                    )
+                }
+            }
+
+            fn batch_dot(
+                n_rows: usize,
+                dims: usize,
+                matrix: &[Self],
+                query: &[Self],
+                scores: &mut [Self],
+            ) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_batch_dot,
+                        avx2fma = export_distance_ops::generic_avx2fma_batch_dot,
+                        avx2 = export_distance_ops::generic_avx2_batch_dot,
+                        neon = export_distance_ops::generic_neon_batch_dot,
+                        fallback = export_distance_ops::generic_fallback_batch_dot,
+                        args = (n_rows, dims, matrix, query, scores)
+                    )
+                }
+            }
         }
     };
 }
@@ -259,6 +595,168 @@ macro_rules! scalar_distance_ops {
                     )
                 }
             }
+
+            fn cosine_batch<B>(query: &[Self], batch: &[Self], result: &mut [B])
+            where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_cosine_batch,
+                        avx2 = export_distance_ops::generic_avx2_cosine_batch,
+                        neon = export_distance_ops::generic_neon_cosine_batch,
+                        fallback = export_distance_ops::generic_fallback_cosine_batch,
+                        args = (query, batch, result)
+                    )
+                }
+            }
+
+            fn dot_batch<B>(query: &[Self], batch: &[Self], result: &mut [B])
+            where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_batch,
+                        avx2 = export_distance_ops::generic_avx2_dot_batch,
+                        neon = export_distance_ops::generic_neon_dot_batch,
+                        fallback = export_distance_ops::generic_fallback_dot_batch,
+                        args = (query, batch, result)
+                    )
+                }
+            }
+
+            fn dot_indexed<B>(
+                dims: usize,
+                query: &[Self],
+                data: &[Self],
+                indices: &[usize],
+                result: &mut [B],
+            ) where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_indexed,
+                        avx2 = export_distance_ops::generic_avx2_dot_indexed,
+                        neon = export_distance_ops::generic_neon_dot_indexed,
+                        fallback = export_distance_ops::generic_fallback_dot_indexed,
+                        args = (dims, query, data, indices, result)
+                    )
+                }
+            }
+
+            fn weighted_dot(dims: usize, a: &[Self], b: &[Self], w: &[Self]) -> Self {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_weighted_dot,
+                        avx2 = export_distance_ops::generic_avx2_weighted_dot,
+                        neon = export_distance_ops::generic_neon_weighted_dot,
+                        fallback = export_distance_ops::generic_fallback_weighted_dot,
+                        args = (dims, a, b, w)
+                    )
+                }
+            }
+
+            fn squared_euclidean_batch<B>(query: &[Self], batch: &[Self], result: &mut [B])
+            where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_squared_euclidean_batch,
+                        avx2 = export_distance_ops::generic_avx2_squared_euclidean_batch,
+                        neon = export_distance_ops::generic_neon_squared_euclidean_batch,
+                        fallback = export_distance_ops::generic_fallback_squared_euclidean_batch,
+                        args = (query, batch, result)
+                    )
+                }
+            }
+
+            fn dot_matrix(
+                dims: usize,
+                a_rows: usize,
+                b_rows: usize,
+                a: &[Self],
+                b: &[Self],
+                result: &mut [Self],
+            ) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_matrix,
+                        avx2 = export_distance_ops::generic_avx2_dot_matrix,
+                        neon = export_distance_ops::generic_neon_dot_matrix,
+                        fallback = export_distance_ops::generic_fallback_dot_matrix,
+                        args = (dims, a_rows, b_rows, a, b, result)
+                    )
+                }
+            }
+
+            fn squared_euclidean_matrix(
+                dims: usize,
+                a_rows: usize,
+                b_rows: usize,
+                a: &[Self],
+                b: &[Self],
+                result: &mut [Self],
+            ) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_squared_euclidean_matrix,
+                        avx2 = export_distance_ops::generic_avx2_squared_euclidean_matrix,
+                        neon = export_distance_ops::generic_neon_squared_euclidean_matrix,
+                        fallback = export_distance_ops::generic_fallback_squared_euclidean_matrix,
+                        args = (dims, a_rows, b_rows, a, b, result)
+                    )
+                }
+            }
+
+            fn gram_matrix(rows: usize, dims: usize, matrix: &[Self], result: &mut [Self]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_gram_matrix,
+                        avx2 = export_distance_ops::generic_avx2_gram_matrix,
+                        neon = export_distance_ops::generic_neon_gram_matrix,
+                        fallback = export_distance_ops::generic_fallback_gram_matrix,
+                        args = (rows, dims, matrix, result)
+                    )
+                }
+            }
+
+            fn pairwise_distances(
+                n: usize,
+                dims: usize,
+                matrix: &[Self],
+                result: &mut [Self],
+            ) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_pairwise_distances,
+                        avx2 = export_distance_ops::generic_avx2_pairwise_distances,
+                        neon = export_distance_ops::generic_neon_pairwise_distances,
+                        fallback = export_distance_ops::generic_fallback_pairwise_distances,
+                        args = (n, dims, matrix, result)
+                    )
+                }
+            }
+
+            fn batch_dot(
+                n_rows: usize,
+                dims: usize,
+                matrix: &[Self],
+                query: &[Self],
+                scores: &mut [Self],
+            ) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_batch_dot,
+                        avx2 = export_distance_ops::generic_avx2_batch_dot,
+                        neon = export_distance_ops::generic_neon_batch_dot,
+                        fallback = export_distance_ops::generic_fallback_batch_dot,
+                        args = (n_rows, dims, matrix, query, scores)
+                    )
+                }
+            }
         }
     };
 }
@@ -273,3 +771,926 @@ scalar_distance_ops!(u8);
 scalar_distance_ops!(u16);
 scalar_distance_ops!(u32);
 scalar_distance_ops!(u64);
+
+#[cfg(feature = "half")]
+impl DistanceOps for half::f16 {
+    fn cosine<B1, B2>(a: B1, b: B2) -> Self
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+    {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_cosine,
+                fallback = export_distance_ops::generic_fallback_cosine,
+                args = (a, b)
+            )
+        }
+    }
+
+    fn dot<B1, B2>(a: B1, b: B2) -> Self
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+    {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_dot,
+                fallback = export_distance_ops::generic_fallback_dot,
+                args = (a, b)
+            )
+        }
+    }
+
+    fn squared_euclidean<B1, B2>(a: B1, b: B2) -> Self
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+    {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_squared_euclidean,
+                fallback = export_distance_ops::generic_fallback_squared_euclidean,
+                args = (a, b)
+            )
+        }
+    }
+
+    fn squared_norm<B1>(a: B1) -> Self
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+    {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_squared_norm,
+                fallback = export_distance_ops::generic_fallback_squared_norm,
+                args = (a)
+            )
+        }
+    }
+
+    fn cosine_batch<B>(query: &[Self], batch: &[Self], result: &mut [B])
+    where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+    {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_cosine_batch,
+                fallback = export_distance_ops::generic_fallback_cosine_batch,
+                args = (query, batch, result)
+            )
+        }
+    }
+
+    fn dot_batch<B>(query: &[Self], batch: &[Self], result: &mut [B])
+    where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+    {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_dot_batch,
+                fallback = export_distance_ops::generic_fallback_dot_batch,
+                args = (query, batch, result)
+            )
+        }
+    }
+
+    fn dot_indexed<B>(
+        dims: usize,
+        query: &[Self],
+        data: &[Self],
+        indices: &[usize],
+        result: &mut [B],
+    ) where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+    {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_dot_indexed,
+                fallback = export_distance_ops::generic_fallback_dot_indexed,
+                args = (dims, query, data, indices, result)
+            )
+        }
+    }
+
+    fn weighted_dot(dims: usize, a: &[Self], b: &[Self], w: &[Self]) -> Self {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_weighted_dot,
+                fallback = export_distance_ops::generic_fallback_weighted_dot,
+                args = (dims, a, b, w)
+            )
+        }
+    }
+
+    fn squared_euclidean_batch<B>(query: &[Self], batch: &[Self], result: &mut [B])
+    where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+    {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_squared_euclidean_batch,
+                fallback = export_distance_ops::generic_fallback_squared_euclidean_batch,
+                args = (query, batch, result)
+            )
+        }
+    }
+
+    fn dot_matrix(
+        dims: usize,
+        a_rows: usize,
+        b_rows: usize,
+        a: &[Self],
+        b: &[Self],
+        result: &mut [Self],
+    ) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_dot_matrix,
+                fallback = export_distance_ops::generic_fallback_dot_matrix,
+                args = (dims, a_rows, b_rows, a, b, result)
+            )
+        }
+    }
+
+    fn squared_euclidean_matrix(
+        dims: usize,
+        a_rows: usize,
+        b_rows: usize,
+        a: &[Self],
+        b: &[Self],
+        result: &mut [Self],
+    ) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c =
+                    export_distance_ops::generic_avx2f16c_squared_euclidean_matrix,
+                fallback =
+                    export_distance_ops::generic_fallback_squared_euclidean_matrix,
+                args = (dims, a_rows, b_rows, a, b, result)
+            )
+        }
+    }
+
+    fn gram_matrix(rows: usize, dims: usize, matrix: &[Self], result: &mut [Self]) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_gram_matrix,
+                fallback = export_distance_ops::generic_fallback_gram_matrix,
+                args = (rows, dims, matrix, result)
+            )
+        }
+    }
+
+    fn pairwise_distances(n: usize, dims: usize, matrix: &[Self], result: &mut [Self]) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_pairwise_distances,
+                fallback = export_distance_ops::generic_fallback_pairwise_distances,
+                args = (n, dims, matrix, result)
+            )
+        }
+    }
+
+    fn batch_dot(
+        n_rows: usize,
+        dims: usize,
+        matrix: &[Self],
+        query: &[Self],
+        scores: &mut [Self],
+    ) {
+        unsafe {
+            crate::dispatch!(
+                avx2f16c = export_distance_ops::generic_avx2f16c_batch_dot,
+                fallback = export_distance_ops::generic_fallback_batch_dot,
+                args = (n_rows, dims, matrix, query, scores)
+            )
+        }
+    }
+}
+
+/// Distance operations and reductions over `f32` vectors which accumulate in `f64`.
+///
+/// These trade some throughput for precision, avoiding the error that builds up
+/// when summing long or badly conditioned vectors in `f32`.
+pub trait F64AccDistanceOps: Sized + Copy {
+    /// Calculates the dot product between vectors `a` and `b` accumulating in `f64`.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0.0f64;
+    ///
+    /// for i in range(dims):
+    ///     result += f64(a[i]) * f64(b[i])
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn dot_f64acc(a: &[Self], b: &[Self]) -> f64;
+
+    /// Calculates the squared Euclidean distance between vectors `a` and `b`
+    /// accumulating in `f64`.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0.0f64;
+    ///
+    /// for i in range(dims):
+    ///     diff = f64(a[i]) - f64(b[i])
+    ///     result += diff ** 2
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn squared_euclidean_f64acc(a: &[Self], b: &[Self]) -> f64;
+
+    /// Calculates the squared L2 norm of vector `a` accumulating in `f64`.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0.0f64;
+    ///
+    /// for i in range(dims):
+    ///     result += f64(a[i]) ** 2
+    ///
+    /// return result
+    /// ```
+    fn squared_norm_f64acc(a: &[Self]) -> f64;
+
+    /// Performs a horizontal sum of all elements in vector `a` accumulating in `f64`.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0.0f64;
+    ///
+    /// for i in range(dims):
+    ///     result += f64(a[i])
+    ///
+    /// return result
+    /// ```
+    fn sum_f64acc(a: &[Self]) -> f64;
+}
+
+impl F64AccDistanceOps for f32 {
+    fn dot_f64acc(a: &[Self], b: &[Self]) -> f64 {
+        unsafe {
+            crate::dispatch!(
+                avx512 = export_distance_ops::f32_avx512_dot_f64acc,
+                avx2fma = export_distance_ops::f32_avx2fma_dot_f64acc,
+                avx2 = export_distance_ops::f32_avx2_dot_f64acc,
+                neon = export_distance_ops::f32_neon_dot_f64acc,
+                fallback = export_distance_ops::f32_fallback_dot_f64acc,
+                args = (a, b)
+            )
+        }
+    }
+
+    fn squared_euclidean_f64acc(a: &[Self], b: &[Self]) -> f64 {
+        unsafe {
+            crate::dispatch!(
+                avx512 = export_distance_ops::f32_avx512_squared_euclidean_f64acc,
+                avx2fma = export_distance_ops::f32_avx2fma_squared_euclidean_f64acc,
+                avx2 = export_distance_ops::f32_avx2_squared_euclidean_f64acc,
+                neon = export_distance_ops::f32_neon_squared_euclidean_f64acc,
+                fallback = export_distance_ops::f32_fallback_squared_euclidean_f64acc,
+                args = (a, b)
+            )
+        }
+    }
+
+    fn squared_norm_f64acc(a: &[Self]) -> f64 {
+        unsafe {
+            crate::dispatch!(
+                avx512 = export_distance_ops::f32_avx512_squared_norm_f64acc,
+                avx2fma = export_distance_ops::f32_avx2fma_squared_norm_f64acc,
+                avx2 = export_distance_ops::f32_avx2_squared_norm_f64acc,
+                neon = export_distance_ops::f32_neon_squared_norm_f64acc,
+                fallback = export_distance_ops::f32_fallback_squared_norm_f64acc,
+                args = (a)
+            )
+        }
+    }
+
+    fn sum_f64acc(a: &[Self]) -> f64 {
+        unsafe {
+            crate::dispatch!(
+                avx512 = export_agg_ops::f32_avx512_sum_f64acc,
+                avx2 = export_agg_ops::f32_avx2_sum_f64acc,
+                neon = export_agg_ops::f32_neon_sum_f64acc,
+                fallback = export_agg_ops::f32_fallback_sum_f64acc,
+                args = (a)
+            )
+        }
+    }
+}
+
+/// Reductions which accumulate in a fixed order independent of the SIMD register used.
+///
+/// The regular routines accumulate into as many lanes as the selected register has, so
+/// the rounding of the result can differ depending on which CPU features are available.
+/// These routines return bit-identical results across every implementation for the same
+/// input, at the cost of some throughput.
+///
+/// With the `nightly` feature enabled, the fallback implementation uses fast-math
+/// operations which the compiler may reassociate, so the guarantee only holds
+/// without it.
+pub trait OrderedReductionOps: Sized + Copy {
+    /// Calculates the dot product between vectors `a` and `b` in a fixed order.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// lanes = [0; 64]
+    ///
+    /// for i in range(dims):
+    ///     lanes[i % 64] += a[i] * b[i]
+    ///
+    /// while len(lanes) > 1:
+    ///     lanes = [lanes[j] + lanes[j + 1] for j in range(0, len(lanes), 2)]
+    ///
+    /// return lanes[0]
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn dot_ordered(a: &[Self], b: &[Self]) -> Self;
+
+    /// Calculates the squared L2 norm of vector `a` in a fixed order.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// lanes = [0; 64]
+    ///
+    /// for i in range(dims):
+    ///     lanes[i % 64] += a[i] ** 2
+    ///
+    /// while len(lanes) > 1:
+    ///     lanes = [lanes[j] + lanes[j + 1] for j in range(0, len(lanes), 2)]
+    ///
+    /// return lanes[0]
+    /// ```
+    fn squared_norm_ordered(a: &[Self]) -> Self;
+
+    /// Performs a horizontal sum of all elements in `a` in a fixed order.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// lanes = [0; 64]
+    ///
+    /// for i in range(dims):
+    ///     lanes[i % 64] += a[i]
+    ///
+    /// while len(lanes) > 1:
+    ///     lanes = [lanes[j] + lanes[j + 1] for j in range(0, len(lanes), 2)]
+    ///
+    /// return lanes[0]
+    /// ```
+    fn sum_ordered(a: &[Self]) -> Self;
+}
+
+macro_rules! ordered_reduction_ops {
+    ($t:ty) => {
+        impl OrderedReductionOps for $t {
+            fn dot_ordered(a: &[Self], b: &[Self]) -> Self {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_ordered,
+                        avx2 = export_distance_ops::generic_avx2_dot_ordered,
+                        neon = export_distance_ops::generic_neon_dot_ordered,
+                        fallback = export_distance_ops::generic_fallback_dot_ordered,
+                        args = (a, b)
+                    )
+                }
+            }
+
+            fn squared_norm_ordered(a: &[Self]) -> Self {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 =
+                            export_distance_ops::generic_avx512_squared_norm_ordered,
+                        avx2 = export_distance_ops::generic_avx2_squared_norm_ordered,
+                        neon = export_distance_ops::generic_neon_squared_norm_ordered,
+                        fallback =
+                            export_distance_ops::generic_fallback_squared_norm_ordered,
+                        args = (a)
+                    )
+                }
+            }
+
+            fn sum_ordered(a: &[Self]) -> Self {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_agg_ops::generic_avx512_sum_ordered,
+                        avx2 = export_agg_ops::generic_avx2_sum_ordered,
+                        neon = export_agg_ops::generic_neon_sum_ordered,
+                        fallback = export_agg_ops::generic_fallback_sum_ordered,
+                        args = (a)
+                    )
+                }
+            }
+        }
+    };
+}
+
+ordered_reduction_ops!(f32);
+ordered_reduction_ops!(f64);
+
+/// Reductions which use compensated summation to track the rounding error of
+/// each addition.
+///
+/// These give a result close to what accumulating in twice the precision would, even
+/// when large values cancel one another out, at the cost of some throughput and without
+/// doubling the memory bandwidth needed like widening each element would.
+pub trait CompensatedReductionOps: Sized + Copy {
+    /// Performs a horizontal sum of all elements in `a` using compensated summation.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0
+    /// comp = 0
+    /// comp2 = 0
+    ///
+    /// for i in range(dims):
+    ///     result, err = two_sum(result, a[i])
+    ///     comp, err = two_sum(comp, err)
+    ///     comp2 += err
+    ///
+    /// return result + (comp + comp2)
+    /// ```
+    fn sum_compensated(a: &[Self]) -> Self;
+
+    /// Calculates the dot product between vectors `a` and `b` using compensated
+    /// summation to accumulate the products.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0
+    /// comp = 0
+    /// comp2 = 0
+    ///
+    /// for i in range(dims):
+    ///     result, err = two_sum(result, a[i] * b[i])
+    ///     comp, err = two_sum(comp, err)
+    ///     comp2 += err
+    ///
+    /// return result + (comp + comp2)
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn dot_compensated(a: &[Self], b: &[Self]) -> Self;
+}
+
+macro_rules! compensated_reduction_ops {
+    ($t:ty) => {
+        impl CompensatedReductionOps for $t {
+            fn sum_compensated(a: &[Self]) -> Self {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_agg_ops::generic_avx512_sum_compensated,
+                        avx2 = export_agg_ops::generic_avx2_sum_compensated,
+                        neon = export_agg_ops::generic_neon_sum_compensated,
+                        fallback = export_agg_ops::generic_fallback_sum_compensated,
+                        args = (a)
+                    )
+                }
+            }
+
+            fn dot_compensated(a: &[Self], b: &[Self]) -> Self {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_distance_ops::generic_avx512_dot_compensated,
+                        avx2 = export_distance_ops::generic_avx2_dot_compensated,
+                        neon = export_distance_ops::generic_neon_dot_compensated,
+                        fallback = export_distance_ops::generic_fallback_dot_compensated,
+                        args = (a, b)
+                    )
+                }
+            }
+        }
+    };
+}
+
+compensated_reduction_ops!(f32);
+compensated_reduction_ops!(f64);
+
+/// Distance operations over integer vectors which accumulate in a wider integer type.
+///
+/// The element type routines of [DistanceOps] wrap as soon as a single product or
+/// squared difference overflows the element type, which for `i32` and `u32` vectors
+/// happens with fairly ordinary values. These routines widen each element first, so
+/// only the accumulation of the wider type can wrap.
+///
+/// With the `half` feature enabled this is also implemented for `bf16` vectors, which
+/// are accumulated as `f32` values.
+pub trait WideAccDistanceOps: Sized + Copy {
+    /// The wider type the result is accumulated and returned as.
+    type Acc;
+
+    /// Calculates the dot product between vectors `a` and `b` accumulating
+    /// in [Self::Acc].
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0;
+    ///
+    /// for i in range(dims):
+    ///     result = result.wrapping_add(Acc(a[i]).wrapping_mul(Acc(b[i])))
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn dot_wide_acc(a: &[Self], b: &[Self]) -> Self::Acc;
+
+    /// Calculates the squared Euclidean distance between vectors `a` and `b`
+    /// accumulating in [Self::Acc].
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0;
+    ///
+    /// for i in range(dims):
+    ///     diff = Acc(a[i]) - Acc(b[i])
+    ///     result = result.wrapping_add(diff.wrapping_mul(diff))
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn squared_euclidean_wide_acc(a: &[Self], b: &[Self]) -> Self::Acc;
+}
+
+macro_rules! wide_acc_distance_ops {
+    (
+        $t:ident => $acc:ident,
+        dot = [$($dot_arch:ident = $dot:ident),+ $(,)?],
+        squared_euclidean = [$($euclid_arch:ident = $euclid:ident),+ $(,)?] $(,)?
+    ) => {
+        impl WideAccDistanceOps for $t {
+            type Acc = $acc;
+
+            fn dot_wide_acc(a: &[Self], b: &[Self]) -> Self::Acc {
+                unsafe {
+                    crate::dispatch!(
+                        $($dot_arch = export_distance_ops::$dot,)+
+                        args = (a, b)
+                    )
+                }
+            }
+
+            fn squared_euclidean_wide_acc(a: &[Self], b: &[Self]) -> Self::Acc {
+                unsafe {
+                    crate::dispatch!(
+                        $($euclid_arch = export_distance_ops::$euclid,)+
+                        args = (a, b)
+                    )
+                }
+            }
+        }
+    };
+}
+
+// The `i16` products always fit within an `i32` so these use the widening dot
+// product registers, i.e. `vpmaddwd` on x86.
+wide_acc_distance_ops!(
+    i16 => i32,
+    dot = [
+        avx2 = generic_avx2_dot_widen,
+        neon = generic_neon_dot_widen,
+        fallback = generic_fallback_dot_widen,
+    ],
+    squared_euclidean = [
+        avx2 = generic_avx2_squared_euclidean_widen,
+        neon = generic_neon_squared_euclidean_widen,
+        fallback = generic_fallback_squared_euclidean_widen,
+    ],
+);
+wide_acc_distance_ops!(
+    u16 => u32,
+    dot = [
+        avx512 = u16_avx512_dot_u32acc,
+        avx2 = u16_avx2_dot_u32acc,
+        neon = u16_neon_dot_u32acc,
+        fallback = u16_fallback_dot_u32acc,
+    ],
+    squared_euclidean = [
+        avx512 = u16_avx512_squared_euclidean_u32acc,
+        avx2 = u16_avx2_squared_euclidean_u32acc,
+        neon = u16_neon_squared_euclidean_u32acc,
+        fallback = u16_fallback_squared_euclidean_u32acc,
+    ],
+);
+wide_acc_distance_ops!(
+    i32 => i64,
+    dot = [
+        avx512 = i32_avx512_dot_i64acc,
+        avx2 = i32_avx2_dot_i64acc,
+        neon = i32_neon_dot_i64acc,
+        fallback = i32_fallback_dot_i64acc,
+    ],
+    squared_euclidean = [
+        avx512 = i32_avx512_squared_euclidean_i64acc,
+        avx2 = i32_avx2_squared_euclidean_i64acc,
+        neon = i32_neon_squared_euclidean_i64acc,
+        fallback = i32_fallback_squared_euclidean_i64acc,
+    ],
+);
+wide_acc_distance_ops!(
+    u32 => u64,
+    dot = [
+        avx512 = u32_avx512_dot_u64acc,
+        avx2 = u32_avx2_dot_u64acc,
+        neon = u32_neon_dot_u64acc,
+        fallback = u32_fallback_dot_u64acc,
+    ],
+    squared_euclidean = [
+        avx512 = u32_avx512_squared_euclidean_u64acc,
+        avx2 = u32_avx2_squared_euclidean_u64acc,
+        neon = u32_neon_squared_euclidean_u64acc,
+        fallback = u32_fallback_squared_euclidean_u64acc,
+    ],
+);
+
+// `bf16` vectors are accumulated as `f32`, using the native `vdpbf16ps` dot product
+// when AVX512 BF16 is available and widening each value to `f32` otherwise.
+#[cfg(feature = "half")]
+impl WideAccDistanceOps for half::bf16 {
+    type Acc = f32;
+
+    fn dot_wide_acc(a: &[Self], b: &[Self]) -> Self::Acc {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            feature = "stable-avx512"
+        ))]
+        if crate::dispatch::is_avx512_bf16_available() {
+            return unsafe { export_distance_ops::bf16_avx512bf16_dot_f32acc(a, b) };
+        }
+
+        unsafe {
+            crate::dispatch!(
+                avx2fma = export_distance_ops::bf16_avx2fma_dot_f32acc,
+                avx2 = export_distance_ops::bf16_avx2_dot_f32acc,
+                fallback = export_distance_ops::bf16_fallback_dot_f32acc,
+                args = (a, b)
+            )
+        }
+    }
+
+    fn squared_euclidean_wide_acc(a: &[Self], b: &[Self]) -> Self::Acc {
+        unsafe {
+            crate::dispatch!(
+                avx2fma = export_distance_ops::bf16_avx2fma_squared_euclidean_f32acc,
+                avx2 = export_distance_ops::bf16_avx2_squared_euclidean_f32acc,
+                fallback = export_distance_ops::bf16_fallback_squared_euclidean_f32acc,
+                args = (a, b)
+            )
+        }
+    }
+}
+
+/// The cosine distance over integer vectors with each element widened and accumulated
+/// in a wider integer type.
+///
+/// The element type cosine routine of [DistanceOps] returns the distance as the element
+/// type, which truncates it to `0` or `1` for the integer types. These routines return
+/// the distance as an `f32` instead.
+pub trait WideAccCosineOps: Sized + Copy {
+    /// Calculates the cosine similarity distance between vectors `a` and `b`
+    /// accumulating the dot product and norms in a wider integer type.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0
+    /// norm_a = 0
+    /// norm_b = 0
+    ///
+    /// for i in range(dims):
+    ///     result = result.wrapping_add(Acc(a[i]) * Acc(b[i]))
+    ///     norm_a = norm_a.wrapping_add(Acc(a[i]) * Acc(a[i]))
+    ///     norm_b = norm_b.wrapping_add(Acc(b[i]) * Acc(b[i]))
+    ///
+    /// if norm_a == 0 and norm_b == 0:
+    ///     return 0.0
+    /// elif norm_a == 0 or norm_b == 0:
+    ///     return 1.0
+    /// else:
+    ///     return 1.0 - (result / sqrt(norm_a * norm_b))
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn cosine_wide_acc(a: &[Self], b: &[Self]) -> f32;
+}
+
+macro_rules! wide_acc_cosine_ops {
+    ($t:ident, [$($arch:ident = $op:ident),+ $(,)?] $(,)?) => {
+        impl WideAccCosineOps for $t {
+            fn cosine_wide_acc(a: &[Self], b: &[Self]) -> f32 {
+                unsafe {
+                    crate::dispatch!(
+                        $($arch = export_distance_ops::$op,)+
+                        args = (a, b)
+                    )
+                }
+            }
+        }
+    };
+}
+
+wide_acc_cosine_ops!(
+    u8,
+    [
+        avx512 = u8_avx512_cosine_u32acc,
+        avx2 = u8_avx2_cosine_u32acc,
+        neon = u8_neon_cosine_u32acc,
+        fallback = u8_fallback_cosine_u32acc,
+    ],
+);
+wide_acc_cosine_ops!(
+    u16,
+    [
+        avx512 = u16_avx512_cosine_u64acc,
+        avx2 = u16_avx2_cosine_u64acc,
+        neon = u16_neon_cosine_u64acc,
+        fallback = u16_fallback_cosine_u64acc,
+    ],
+);
+
+/// Distance operations over bit-packed vectors.
+pub trait BinaryDistanceOps: Sized + Copy {
+    /// Calculates the Hamming distance between the bit-packed vectors `a` and `b`,
+    /// i.e. the number of bits which differ between them.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0
+    ///
+    /// for i in range(words):
+    ///     result += popcount(a[i] ^ b[i])
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn binary_hamming(a: &[Self], b: &[Self]) -> u32;
+
+    /// Calculates the Jaccard similarity between the bit-packed vectors `a` and `b`,
+    /// i.e. the number of bits set in both divided by the number of bits set in either.
+    ///
+    /// If neither vector has any bits set the similarity is `1.0`.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// intersection = 0
+    /// union = 0
+    ///
+    /// for i in range(words):
+    ///     intersection += popcount(a[i] & b[i])
+    ///     union += popcount(a[i] | b[i])
+    ///
+    /// if union == 0:
+    ///     return 1.0
+    ///
+    /// return intersection / union
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn binary_jaccard(a: &[Self], b: &[Self]) -> f32;
+}
+
+macro_rules! binary_distance_op {
+    (
+        $name:ident -> $ret:ty,
+        vpopcntdq = $vpopcntdq:ident,
+        [$($arch:ident = $op:ident),+ $(,)?] $(,)?
+    ) => {
+        fn $name(a: &[Self], b: &[Self]) -> $ret {
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "stable-avx512"
+            ))]
+            if crate::dispatch::is_avx512_vpopcntdq_available() {
+                return unsafe { export_distance_ops::$vpopcntdq(a.len(), a, b) };
+            }
+
+            // The x86 routines also enable `popcnt`, which every CPU supporting AVX2
+            // provides, for the scalar tail.
+            unsafe {
+                crate::dispatch!(
+                    $($arch = export_distance_ops::$op,)+
+                    args = (a.len(), a, b)
+                )
+            }
+        }
+    };
+}
+
+impl BinaryDistanceOps for u64 {
+    binary_distance_op!(
+        binary_hamming -> u32,
+        vpopcntdq = u64_avx512vpopcntdq_binary_hamming,
+        [
+            avx512 = u64_avx512_binary_hamming,
+            avx2 = u64_avx2_binary_hamming,
+            neon = u64_neon_binary_hamming,
+            fallback = u64_fallback_binary_hamming,
+        ],
+    );
+
+    binary_distance_op!(
+        binary_jaccard -> f32,
+        vpopcntdq = u64_avx512vpopcntdq_binary_jaccard,
+        [
+            avx512 = u64_avx512_binary_jaccard,
+            avx2 = u64_avx2_binary_jaccard,
+            neon = u64_neon_binary_jaccard,
+            fallback = u64_fallback_binary_jaccard,
+        ],
+    );
+}
+
+/// Distance operations over integer vectors which detect overflow rather than wrapping.
+///
+/// These are computed one element at a time and are considerably slower than the
+/// [DistanceOps] routines, they are intended for validating inputs where wrapping would
+/// silently produce the wrong result.
+pub trait CheckedDistanceOps: Sized + Copy {
+    /// Calculates the squared Euclidean distance between vectors `a` and `b`, returning
+    /// `None` if any step of the calculation overflows.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = 0;
+    ///
+    /// for i in range(dims):
+    ///     diff = abs(a[i] - b[i])
+    ///     result = result.checked_add(diff.checked_mul(diff)?)?
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` are not equal in the length.
+    fn checked_squared_euclidean(a: &[Self], b: &[Self]) -> Option<Self>;
+}
+
+macro_rules! checked_distance_ops {
+    ($t:ident) => {
+        impl CheckedDistanceOps for $t {
+            fn checked_squared_euclidean(a: &[Self], b: &[Self]) -> Option<Self> {
+                assert_eq!(a.len(), b.len(), "Buffers `a` and `b` do not match in size");
+
+                let mut total: Self = 0;
+                for (a, b) in a.iter().zip(b) {
+                    let diff = a.abs_diff(*b);
+                    let squared = Self::try_from(diff.checked_mul(diff)?).ok()?;
+                    total = total.checked_add(squared)?;
+                }
+                Some(total)
+            }
+        }
+    };
+}
+
+checked_distance_ops!(i64);
+checked_distance_ops!(u64);
@@ -0,0 +1,406 @@
+//! Safe but somewhat low-level variants of the float only operations in CFAVML.
+//!
+//! In general, I would recommend using the higher level generic functions api which provides
+//! some syntax sugar over these traits.
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::export_float_ops;
+use crate::mem_loader::{IntoMemLoader, MemLoader};
+
+/// Various operations over vectors that only apply to the float types.
+pub trait FloatOps: Sized + Copy {
+    /// Computes the element wise hypotenuse `sqrt(a² + b²)` of two input buffers `lhs`
+    /// and `rhs` that can be projected to the desired output size of `result`.
+    ///
+    /// See [cfavml::hypot_vertical](crate::hypot_vertical) for examples.
+    ///
+    /// ### Overflow & Special Values
+    ///
+    /// The squares are never computed directly, so intermediate values cannot overflow
+    /// or underflow unless the result does.
+    ///
+    /// If either element is infinite the result is infinite, even if the other element is `NaN`,
+    /// otherwise if either element is `NaN` the result is `NaN`.
+    ///
+    /// ### Projecting Vectors
+    ///
+    /// CFAVML allows for working over a wide variety of buffers for applications, projection is effectively
+    /// broadcasting of two input buffers implementing `IntoMemLoader<T>`.
+    ///
+    /// By default, you can provide _two slices_, _one slice and a broadcast value_, or _two broadcast values_,
+    /// which exhibit the standard behaviour as you might expect.
+    ///
+    /// When providing two slices as inputs they cannot be projected to a buffer
+    /// that is larger their input sizes by default. This means providing two slices
+    /// of `128` elements in length must take a result buffer of `128` elements in length.
+    ///
+    /// You can wrap your inputs in a [Projected](crate::mem_loader::Projected) wrapper which
+    /// enables projecting of the input buffer to new sizes providing the new size is a
+    /// multiple of the original size. When this buffer is projected, it is effectively
+    /// repeated `N` times, where `N` is how many times the old size fits into the new size.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     hi = max(abs(a[i]), abs(b[i]))
+    ///     lo = min(abs(a[i]), abs(b[i]))
+    ///
+    ///     if hi == 0:
+    ///         result[i] = 0
+    ///     else:
+    ///         ratio = lo / hi
+    ///         result[i] = hi * sqrt(1 + ratio * ratio)
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `b` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn hypot_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        B2: IntoMemLoader<Self>,
+        B2::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>;
+
+    /// Checks each element of vector `a` if the element is `NaN`, storing the output
+    /// as `1` (true) or `0` (false) in `result`.
+    ///
+    /// See [cfavml::is_nan_vector](crate::is_nan_vector) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// mask = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     mask[i] = is_nan(a[i]) ? 1 : 0
+    ///
+    /// return mask
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vector `a` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn is_nan_vector<B1, B2>(a: B1, result: &mut [B2])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = Self>;
+
+    /// Checks each element of vector `a` if the element is finite, i.e. neither infinite
+    /// nor `NaN`, storing the output as `1` (true) or `0` (false) in `result`.
+    ///
+    /// See [cfavml::is_finite_vector](crate::is_finite_vector) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// mask = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     mask[i] = is_finite(a[i]) ? 1 : 0
+    ///
+    /// return mask
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vector `a` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn is_finite_vector<B1, B2>(a: B1, result: &mut [B2])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = Self>;
+
+    /// Checks each element of vector `a` if the element is positive or negative infinity,
+    /// storing the output as `1` (true) or `0` (false) in `result`.
+    ///
+    /// See [cfavml::is_infinite_vector](crate::is_infinite_vector) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// mask = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     mask[i] = is_infinite(a[i]) ? 1 : 0
+    ///
+    /// return mask
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vector `a` cannot be projected to the target size of `result`.
+    /// Note that the projection rules are tied to the `MemLoader` implementation.
+    fn is_infinite_vector<B1, B2>(a: B1, result: &mut [B2])
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>,
+        for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = Self>;
+
+    /// Returns if **_any_** element of vector `a` is `NaN`.
+    ///
+    /// See [cfavml::any_nan](crate::any_nan) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if is_nan(a[i]):
+    ///         return true
+    ///
+    /// return false
+    /// ```
+    fn any_nan<B1>(a: B1) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_any_** element of vector `a` is positive or negative infinity.
+    ///
+    /// See [cfavml::any_infinite](crate::any_infinite) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if is_infinite(a[i]):
+    ///         return true
+    ///
+    /// return false
+    /// ```
+    fn any_infinite<B1>(a: B1) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Returns if **_all_** elements of vector `a` are finite, i.e. neither infinite nor `NaN`.
+    ///
+    /// See [cfavml::all_finite](crate::all_finite) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     if not is_finite(a[i]):
+    ///         return false
+    ///
+    /// return true
+    /// ```
+    fn all_finite<B1>(a: B1) -> bool
+    where
+        B1: IntoMemLoader<Self>,
+        B1::Loader: MemLoader<Value = Self>;
+
+    /// Divides each element of vector `a` by the L2 norm of `a`, writing the
+    /// normalized vector to `result`.
+    ///
+    /// See [cfavml::l2_normalize_vertical](crate::l2_normalize_vertical) for examples.
+    ///
+    /// If the norm of `a` is zero, `result` is filled with zeroes.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// norm = sqrt(squared_norm(a))
+    /// if norm == 0:
+    ///     return result
+    ///
+    /// for i in range(dims):
+    ///     result[i] = a[i] / norm
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If vectors `a` and `result` are not exactly `dims` elements in length.
+    fn l2_normalize_vertical(dims: usize, a: &[Self], result: &mut [Self]);
+
+    /// Divides each element of vector `a` by the L2 norm of `a` in place.
+    ///
+    /// See [cfavml::l2_normalize_inplace](crate::l2_normalize_inplace) for examples.
+    ///
+    /// If the norm of `a` is zero, `a` is filled with zeroes.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// norm = sqrt(squared_norm(a))
+    /// if norm == 0:
+    ///     a = [0; dims]
+    ///     return
+    ///
+    /// for i in range(dims):
+    ///     a[i] = a[i] / norm
+    /// ```
+    fn l2_normalize_inplace(a: &mut [Self]);
+}
+
+macro_rules! float_ops {
+    ($t:ty) => {
+        impl FloatOps for $t {
+            fn hypot_vertical<B1, B2, B3>(lhs: B1, rhs: B2, result: &mut [B3])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                B2: IntoMemLoader<Self>,
+                B2::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B3]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_float_ops::generic_avx512_hypot_vertical,
+                        avx2fma = export_float_ops::generic_avx2fma_hypot_vertical,
+                        avx2 = export_float_ops::generic_avx2_hypot_vertical,
+                        neon = export_float_ops::generic_neon_hypot_vertical,
+                        fallback = export_float_ops::generic_fallback_hypot_vertical,
+                        args = (lhs, rhs, result)
+                    );
+                }
+            }
+
+            fn is_nan_vector<B1, B2>(a: B1, result: &mut [B2])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_float_ops::generic_avx512_is_nan_vector,
+                        avx2 = export_float_ops::generic_avx2_is_nan_vector,
+                        neon = export_float_ops::generic_neon_is_nan_vector,
+                        fallback = export_float_ops::generic_fallback_is_nan_vector,
+                        args = (a, result)
+                    )
+                }
+            }
+
+            fn is_finite_vector<B1, B2>(a: B1, result: &mut [B2])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_float_ops::generic_avx512_is_finite_vector,
+                        avx2 = export_float_ops::generic_avx2_is_finite_vector,
+                        neon = export_float_ops::generic_neon_is_finite_vector,
+                        fallback = export_float_ops::generic_fallback_is_finite_vector,
+                        args = (a, result)
+                    )
+                }
+            }
+
+            fn is_infinite_vector<B1, B2>(a: B1, result: &mut [B2])
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+                for<'a> &'a mut [B2]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_float_ops::generic_avx512_is_infinite_vector,
+                        avx2 = export_float_ops::generic_avx2_is_infinite_vector,
+                        neon = export_float_ops::generic_neon_is_infinite_vector,
+                        fallback = export_float_ops::generic_fallback_is_infinite_vector,
+                        args = (a, result)
+                    )
+                }
+            }
+
+            fn any_nan<B1>(a: B1) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_float_ops::generic_avx512_any_nan,
+                        avx2 = export_float_ops::generic_avx2_any_nan,
+                        neon = export_float_ops::generic_neon_any_nan,
+                        fallback = export_float_ops::generic_fallback_any_nan,
+                        args = (a)
+                    )
+                }
+            }
+
+            fn any_infinite<B1>(a: B1) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_float_ops::generic_avx512_any_infinite,
+                        avx2 = export_float_ops::generic_avx2_any_infinite,
+                        neon = export_float_ops::generic_neon_any_infinite,
+                        fallback = export_float_ops::generic_fallback_any_infinite,
+                        args = (a)
+                    )
+                }
+            }
+
+            fn all_finite<B1>(a: B1) -> bool
+            where
+                B1: IntoMemLoader<Self>,
+                B1::Loader: MemLoader<Value = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_float_ops::generic_avx512_all_finite,
+                        avx2 = export_float_ops::generic_avx2_all_finite,
+                        neon = export_float_ops::generic_neon_all_finite,
+                        fallback = export_float_ops::generic_fallback_all_finite,
+                        args = (a)
+                    )
+                }
+            }
+
+            fn l2_normalize_vertical(dims: usize, a: &[Self], result: &mut [Self]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_float_ops::generic_avx512_l2_normalize_vertical,
+                        avx2fma =
+                            export_float_ops::generic_avx2fma_l2_normalize_vertical,
+                        avx2 = export_float_ops::generic_avx2_l2_normalize_vertical,
+                        neon = export_float_ops::generic_neon_l2_normalize_vertical,
+                        fallback =
+                            export_float_ops::generic_fallback_l2_normalize_vertical,
+                        args = (dims, a, result)
+                    )
+                }
+            }
+
+            fn l2_normalize_inplace(a: &mut [Self]) {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_float_ops::generic_avx512_l2_normalize_inplace,
+                        avx2fma = export_float_ops::generic_avx2fma_l2_normalize_inplace,
+                        avx2 = export_float_ops::generic_avx2_l2_normalize_inplace,
+                        neon = export_float_ops::generic_neon_l2_normalize_inplace,
+                        fallback =
+                            export_float_ops::generic_fallback_l2_normalize_inplace,
+                        args = (a)
+                    )
+                }
+            }
+        }
+    };
+}
+
+float_ops!(f32);
+float_ops!(f64);
@@ -0,0 +1,193 @@
+//! Safe but somewhat low-level variants of the gather, scatter and compaction operations in CFAVML.
+//!
+//! In general, I would recommend using the higher level generic functions api which provides
+//! some syntax sugar over these traits.
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::export_gather_ops;
+
+/// Operations rearranging the elements of a vector by an index vector or mask.
+pub trait GatherOps: Sized + Copy {
+    /// Gathers the elements of `a` selected by `indices`, writing the element selected
+    /// by `indices[i]` to `result[i]`.
+    ///
+    /// See [cfavml::gather](crate::gather) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims]
+    ///
+    /// for i in range(dims):
+    ///     result[i] = a[indices[i]]
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `indices` and `result` are not exactly `dims` elements in length or any of
+    /// the `indices` are out of bounds of `a`.
+    fn gather<B>(dims: usize, a: &[Self], indices: &[u32], result: &mut [B])
+    where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>;
+
+    /// Scatters the elements of `a` to the positions of `result` selected by `indices`,
+    /// writing `a[i]` to `result[indices[i]]`.
+    ///
+    /// See [cfavml::scatter](crate::scatter) for examples.
+    ///
+    /// If `indices` contains duplicates the last element of `a` written to that index wins.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// for i in range(dims):
+    ///     result[indices[i]] = a[i]
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `a` and `indices` are not exactly `dims` elements in length or any of
+    /// the `indices` are out of bounds of `result`.
+    fn scatter(dims: usize, a: &[Self], indices: &[u32], result: &mut [Self]);
+
+    /// Compacts the elements of `values` where the corresponding element of `mask` is
+    /// non-zero, writing them to the start of `result` in their original order and
+    /// returning the number of elements written.
+    ///
+    /// See [cfavml::compact](crate::compact) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// count = 0
+    ///
+    /// for i in range(dims):
+    ///     if mask[i] != 0:
+    ///         result[count] = values[i]
+    ///         count += 1
+    ///
+    /// return count
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `mask` and `values` are not exactly `dims` elements in length or `result` is
+    /// less than `dims` elements in length.
+    fn compact<B>(
+        dims: usize,
+        mask: &[Self],
+        values: &[Self],
+        result: &mut [B],
+    ) -> usize
+    where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>;
+
+    /// Writes the index of each non-zero element of `mask` to the start of `result` in
+    /// ascending order, returning the number of indices written.
+    ///
+    /// See [cfavml::mask_to_indices](crate::mask_to_indices) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// count = 0
+    ///
+    /// for i in range(dims):
+    ///     if mask[i] != 0:
+    ///         result[count] = i
+    ///         count += 1
+    ///
+    /// return count
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `mask` is not exactly `dims` elements in length, `result` is less than `dims`
+    /// elements in length or `dims` is larger than `u32::MAX + 1`.
+    fn mask_to_indices<B>(dims: usize, mask: &[Self], result: &mut [B]) -> usize
+    where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = u32>;
+}
+
+macro_rules! gather_ops {
+    ($t:ty) => {
+        impl GatherOps for $t {
+            fn gather<B>(dims: usize, a: &[Self], indices: &[u32], result: &mut [B])
+            where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                assert!(
+                    indices.iter().all(|&index| (index as usize) < a.len()),
+                    "Index out of bounds of buffer `a`"
+                );
+
+                unsafe {
+                    crate::dispatch!(
+                        avx2 = export_gather_ops::generic_avx2_gather,
+                        fallback = export_gather_ops::generic_fallback_gather,
+                        args = (dims, a, indices, result)
+                    )
+                }
+            }
+
+            fn scatter(dims: usize, a: &[Self], indices: &[u32], result: &mut [Self]) {
+                assert!(
+                    indices.iter().all(|&index| (index as usize) < result.len()),
+                    "Index out of bounds of buffer `result`"
+                );
+
+                unsafe {
+                    crate::dispatch!(
+                        avx2 = export_gather_ops::generic_avx2_scatter,
+                        fallback = export_gather_ops::generic_fallback_scatter,
+                        args = (dims, a, indices, result)
+                    )
+                }
+            }
+
+            fn compact<B>(
+                dims: usize,
+                mask: &[Self],
+                values: &[Self],
+                result: &mut [B],
+            ) -> usize
+            where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx2 = export_gather_ops::generic_avx2_compact,
+                        fallback = export_gather_ops::generic_fallback_compact,
+                        args = (dims, mask, values, result)
+                    )
+                }
+            }
+
+            fn mask_to_indices<B>(dims: usize, mask: &[Self], result: &mut [B]) -> usize
+            where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = u32>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx2 = export_gather_ops::generic_avx2_mask_to_indices,
+                        fallback = export_gather_ops::generic_fallback_mask_to_indices,
+                        args = (dims, mask, result)
+                    )
+                }
+            }
+        }
+    };
+}
+
+gather_ops!(f32);
+gather_ops!(f64);
+gather_ops!(i8);
+gather_ops!(i16);
+gather_ops!(i32);
+gather_ops!(i64);
+gather_ops!(u8);
+gather_ops!(u16);
+gather_ops!(u32);
+gather_ops!(u64);
@@ -0,0 +1,66 @@
+//! Safe but somewhat low-level variants of the statistical operations in CFAVML.
+//!
+//! In general, I would recommend using the higher level generic functions api which provides
+//! some syntax sugar over these traits.
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::danger::export_stats_ops;
+
+/// Statistical operations over vectors.
+///
+/// These are only implemented for the `f32`, `f64`, `i32` and `i64` types.
+pub trait StatsOps: Sized + Copy {
+    /// Computes the mean of each `window` sized window of vector `a`, writing the mean of
+    /// `a[i..i + window]` to `result[i]`.
+    ///
+    /// See [cfavml::moving_average](crate::moving_average) for examples.
+    ///
+    /// ### Implementation Pseudocode
+    ///
+    /// ```ignore
+    /// result = [0; dims - window + 1]
+    ///
+    /// for i in range(dims - window + 1):
+    ///     result[i] = sum(a[i:i + window]) / window
+    ///
+    /// return result
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If `window` is zero, `a` is not exactly `dims` elements in length or `result` is not
+    /// exactly `dims - window + 1` elements in length.
+    fn moving_average<B>(dims: usize, window: usize, a: &[Self], result: &mut [B])
+    where
+        for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>;
+}
+
+macro_rules! stats_ops {
+    ($t:ty) => {
+        impl StatsOps for $t {
+            fn moving_average<B>(
+                dims: usize,
+                window: usize,
+                a: &[Self],
+                result: &mut [B],
+            ) where
+                for<'a> &'a mut [B]: WriteOnlyBuffer<Item = Self>,
+            {
+                unsafe {
+                    crate::dispatch!(
+                        avx512 = export_stats_ops::generic_avx512_moving_average,
+                        avx2 = export_stats_ops::generic_avx2_moving_average,
+                        neon = export_stats_ops::generic_neon_moving_average,
+                        fallback = export_stats_ops::generic_fallback_moving_average,
+                        args = (dims, window, a, result)
+                    )
+                }
+            }
+        }
+    };
+}
+
+stats_ops!(f32);
+stats_ops!(f64);
+stats_ops!(i32);
+stats_ops!(i64);
@@ -0,0 +1,1008 @@
+//! Fallible variants of the generic functions which validate the length of every operand.
+//!
+//! Each `try_` routine returns a [SizeMismatchError] naming the first operand with the
+//! wrong length instead of panicking, which is useful when the buffers are supplied by
+//! a user rather than the program itself.
+//!
+//! Unlike the generic functions these only accept slices, values which are broadcast to
+//! a new size, i.e. scalars or [Projected](crate::mem_loader::Projected) buffers, must use
+//! the panicking routines.
+
+use crate::buffer::WriteOnlyBuffer;
+use crate::error::{IndexError, IndexOutOfBoundsError, SizeMismatchError};
+use crate::safe_trait_arithmetic_ops::{ArithmeticOps, NegOps, SaturatingArithmeticOps};
+use crate::safe_trait_bitwise_ops::BitwiseOps;
+use crate::safe_trait_cmp_ops::CmpOps;
+use crate::safe_trait_distance_ops::{
+    BinaryDistanceOps,
+    CheckedDistanceOps,
+    CompensatedReductionOps,
+    DistanceOps,
+    F64AccDistanceOps,
+    OrderedReductionOps,
+    WideAccCosineOps,
+    WideAccDistanceOps,
+};
+use crate::safe_trait_float_ops::FloatOps;
+use crate::safe_trait_gather_ops::GatherOps;
+use crate::safe_trait_stats_ops::StatsOps;
+
+macro_rules! define_try_pair_op {
+    ($try_name:ident => $name:ident, $bound:path, $ret:ty, $call:path $(,)?) => {
+        #[inline]
+        #[doc = concat!("Fallible variant of [", stringify!($name), "](crate::", stringify!($name), ").")]
+        ///
+        /// # Errors
+        ///
+        /// Returns a [SizeMismatchError] if vectors `a` and `b` are not equal in length.
+        pub fn $try_name<T>(a: &[T], b: &[T]) -> Result<$ret, SizeMismatchError>
+        where
+            T: $bound,
+        {
+            SizeMismatchError::check("b", a.len(), b.len())?;
+            Ok($call(a, b))
+        }
+    };
+}
+
+macro_rules! define_try_vertical_op {
+    ($try_name:ident => $name:ident, $bound:path $(,)?) => {
+        #[inline]
+        #[doc = concat!("Fallible variant of [", stringify!($name), "](crate::", stringify!($name), ").")]
+        ///
+        /// # Errors
+        ///
+        /// Returns a [SizeMismatchError] if vectors `lhs` and `rhs` are not the same
+        /// length as `result`.
+        pub fn $try_name<T, B>(
+            lhs: &[T],
+            rhs: &[T],
+            result: &mut [B],
+        ) -> Result<(), SizeMismatchError>
+        where
+            T: $bound,
+            for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+        {
+            SizeMismatchError::check("lhs", result.len(), lhs.len())?;
+            SizeMismatchError::check("rhs", result.len(), rhs.len())?;
+            crate::$name(lhs, rhs, result);
+            Ok(())
+        }
+    };
+}
+
+macro_rules! define_try_unary_vertical_op {
+    ($try_name:ident => $name:ident, $bound:path $(,)?) => {
+        #[inline]
+        #[doc = concat!("Fallible variant of [", stringify!($name), "](crate::", stringify!($name), ").")]
+        ///
+        /// # Errors
+        ///
+        /// Returns a [SizeMismatchError] if vector `a` is not the same length as `result`.
+        pub fn $try_name<T, B>(a: &[T], result: &mut [B]) -> Result<(), SizeMismatchError>
+        where
+            T: $bound,
+            for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+        {
+            SizeMismatchError::check("a", result.len(), a.len())?;
+            crate::$name(a, result);
+            Ok(())
+        }
+    };
+}
+
+macro_rules! define_try_inplace_op {
+    ($try_name:ident => $name:ident $(,)?) => {
+        #[inline]
+        #[doc = concat!("Fallible variant of [", stringify!($name), "](crate::", stringify!($name), ").")]
+        ///
+        /// # Errors
+        ///
+        /// Returns a [SizeMismatchError] if vectors `a` and `b` are not equal in length.
+        pub fn $try_name<T>(a: &mut [T], b: &[T]) -> Result<(), SizeMismatchError>
+        where
+            T: ArithmeticOps,
+        {
+            SizeMismatchError::check("b", a.len(), b.len())?;
+            T::$name(a, b);
+            Ok(())
+        }
+    };
+}
+
+macro_rules! define_try_batch_op {
+    ($try_name:ident => $name:ident $(,)?) => {
+        #[inline]
+        #[doc = concat!("Fallible variant of [", stringify!($name), "](crate::", stringify!($name), ").")]
+        ///
+        /// # Errors
+        ///
+        /// Returns a [SizeMismatchError] if `batch` is not exactly
+        /// `query.len() * result.len()` elements in length.
+        pub fn $try_name<T, B>(
+            query: &[T],
+            batch: &[T],
+            result: &mut [B],
+        ) -> Result<(), SizeMismatchError>
+        where
+            T: DistanceOps,
+            for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+        {
+            SizeMismatchError::check("batch", query.len() * result.len(), batch.len())?;
+            T::$name(query, batch, result);
+            Ok(())
+        }
+    };
+}
+
+macro_rules! define_try_matrix_op {
+    ($try_name:ident => $name:ident $(,)?) => {
+        #[inline]
+        #[doc = concat!("Fallible variant of [", stringify!($name), "](crate::", stringify!($name), ").")]
+        ///
+        /// # Errors
+        ///
+        /// Returns a [SizeMismatchError] if `a` is not exactly `dims * a_rows` elements
+        /// in length, `b` is not exactly `dims * b_rows` elements in length or `result`
+        /// is not exactly `a_rows * b_rows` elements in length.
+        pub fn $try_name<T>(
+            dims: usize,
+            a_rows: usize,
+            b_rows: usize,
+            a: &[T],
+            b: &[T],
+            result: &mut [T],
+        ) -> Result<(), SizeMismatchError>
+        where
+            T: DistanceOps,
+        {
+            SizeMismatchError::check("a", dims * a_rows, a.len())?;
+            SizeMismatchError::check("b", dims * b_rows, b.len())?;
+            SizeMismatchError::check("result", a_rows * b_rows, result.len())?;
+            T::$name(dims, a_rows, b_rows, a, b, result);
+            Ok(())
+        }
+    };
+}
+
+define_try_pair_op!(try_cosine => cosine, DistanceOps, T, crate::cosine);
+define_try_pair_op!(try_dot => dot, DistanceOps, T, crate::dot);
+define_try_pair_op!(
+    try_squared_euclidean => squared_euclidean,
+    DistanceOps,
+    T,
+    crate::squared_euclidean,
+);
+define_try_pair_op!(try_dot_f64acc => dot_f64acc, F64AccDistanceOps, f64, T::dot_f64acc);
+define_try_pair_op!(
+    try_squared_euclidean_f64acc => squared_euclidean_f64acc,
+    F64AccDistanceOps,
+    f64,
+    T::squared_euclidean_f64acc,
+);
+define_try_pair_op!(
+    try_dot_compensated => dot_compensated,
+    CompensatedReductionOps,
+    T,
+    T::dot_compensated,
+);
+define_try_pair_op!(try_dot_ordered => dot_ordered, OrderedReductionOps, T, T::dot_ordered);
+define_try_pair_op!(
+    try_dot_wide_acc => dot_wide_acc,
+    WideAccDistanceOps,
+    T::Acc,
+    T::dot_wide_acc,
+);
+define_try_pair_op!(
+    try_squared_euclidean_wide_acc => squared_euclidean_wide_acc,
+    WideAccDistanceOps,
+    T::Acc,
+    T::squared_euclidean_wide_acc,
+);
+define_try_pair_op!(
+    try_cosine_wide_acc => cosine_wide_acc,
+    WideAccCosineOps,
+    f32,
+    T::cosine_wide_acc,
+);
+define_try_pair_op!(
+    try_binary_hamming => binary_hamming,
+    BinaryDistanceOps,
+    u32,
+    T::binary_hamming,
+);
+define_try_pair_op!(
+    try_binary_jaccard => binary_jaccard,
+    BinaryDistanceOps,
+    f32,
+    T::binary_jaccard,
+);
+define_try_pair_op!(
+    try_checked_squared_euclidean => checked_squared_euclidean,
+    CheckedDistanceOps,
+    Option<T>,
+    T::checked_squared_euclidean,
+);
+
+define_try_batch_op!(try_cosine_batch => cosine_batch);
+define_try_batch_op!(try_dot_batch => dot_batch);
+define_try_batch_op!(try_squared_euclidean_batch => squared_euclidean_batch);
+
+define_try_matrix_op!(try_dot_matrix => dot_matrix);
+define_try_matrix_op!(try_squared_euclidean_matrix => squared_euclidean_matrix);
+
+define_try_vertical_op!(try_max_vertical => max_vertical, CmpOps);
+define_try_vertical_op!(try_min_vertical => min_vertical, CmpOps);
+define_try_vertical_op!(try_eq_vertical => eq_vertical, CmpOps);
+define_try_vertical_op!(try_neq_vertical => neq_vertical, CmpOps);
+define_try_vertical_op!(try_lt_vertical => lt_vertical, CmpOps);
+define_try_vertical_op!(try_lte_vertical => lte_vertical, CmpOps);
+define_try_vertical_op!(try_gt_vertical => gt_vertical, CmpOps);
+define_try_vertical_op!(try_gte_vertical => gte_vertical, CmpOps);
+define_try_vertical_op!(try_add_vertical => add_vertical, ArithmeticOps);
+define_try_vertical_op!(try_add_streaming => add_streaming, ArithmeticOps);
+define_try_vertical_op!(try_sub_vertical => sub_vertical, ArithmeticOps);
+define_try_vertical_op!(try_mul_vertical => mul_vertical, ArithmeticOps);
+define_try_vertical_op!(try_div_vertical => div_vertical, ArithmeticOps);
+define_try_vertical_op!(
+    try_saturating_add_vertical => saturating_add_vertical,
+    SaturatingArithmeticOps,
+);
+define_try_vertical_op!(
+    try_saturating_sub_vertical => saturating_sub_vertical,
+    SaturatingArithmeticOps,
+);
+define_try_unary_vertical_op!(try_neg_vertical => neg_vertical, NegOps);
+define_try_vertical_op!(try_bitand_vertical => bitand_vertical, BitwiseOps);
+define_try_vertical_op!(try_bitor_vertical => bitor_vertical, BitwiseOps);
+define_try_vertical_op!(try_bitxor_vertical => bitxor_vertical, BitwiseOps);
+define_try_vertical_op!(try_shl_vertical => shl_vertical, BitwiseOps);
+define_try_vertical_op!(try_shr_vertical => shr_vertical, BitwiseOps);
+define_try_vertical_op!(try_hypot_vertical => hypot_vertical, FloatOps);
+
+define_try_unary_vertical_op!(try_bitnot_vertical => bitnot_vertical, BitwiseOps);
+define_try_unary_vertical_op!(try_is_nan_vector => is_nan_vector, FloatOps);
+define_try_unary_vertical_op!(try_is_finite_vector => is_finite_vector, FloatOps);
+define_try_unary_vertical_op!(try_is_infinite_vector => is_infinite_vector, FloatOps);
+
+define_try_inplace_op!(try_add_inplace => add_inplace);
+define_try_inplace_op!(try_sub_inplace => sub_inplace);
+define_try_inplace_op!(try_mul_inplace => mul_inplace);
+define_try_inplace_op!(try_div_inplace => div_inplace);
+
+#[inline]
+/// Fallible variant of [weighted_dot](crate::weighted_dot).
+///
+/// # Errors
+///
+/// Returns a [SizeMismatchError] if vectors `a`, `b` and `w` are not exactly `dims`
+/// elements in length.
+pub fn try_weighted_dot<T>(
+    dims: usize,
+    a: &[T],
+    b: &[T],
+    w: &[T],
+) -> Result<T, SizeMismatchError>
+where
+    T: DistanceOps,
+{
+    SizeMismatchError::check("a", dims, a.len())?;
+    SizeMismatchError::check("b", dims, b.len())?;
+    SizeMismatchError::check("w", dims, w.len())?;
+    Ok(T::weighted_dot(dims, a, b, w))
+}
+
+#[inline]
+/// Fallible variant of [gemv](crate::gemv).
+///
+/// # Errors
+///
+/// Returns a [SizeMismatchError] if `matrix` is not exactly `rows * cols` elements in
+/// length, `x` is not exactly `cols` elements in length or `out` is not exactly `rows`
+/// elements in length.
+pub fn try_gemv<T>(
+    rows: usize,
+    cols: usize,
+    matrix: &[T],
+    x: &[T],
+    out: &mut [T],
+) -> Result<(), SizeMismatchError>
+where
+    T: DistanceOps,
+{
+    SizeMismatchError::check("matrix", rows * cols, matrix.len())?;
+    SizeMismatchError::check("x", cols, x.len())?;
+    SizeMismatchError::check("out", rows, out.len())?;
+    T::gemv(rows, cols, matrix, x, out);
+    Ok(())
+}
+
+#[inline]
+/// Fallible variant of [batch_dot](crate::batch_dot).
+///
+/// # Errors
+///
+/// Returns a [SizeMismatchError] if `matrix` is not exactly `n_rows * dims` elements in
+/// length, `query` is not exactly `dims` elements in length or `scores` is not exactly
+/// `n_rows` elements in length.
+pub fn try_batch_dot<T>(
+    n_rows: usize,
+    dims: usize,
+    matrix: &[T],
+    query: &[T],
+    scores: &mut [T],
+) -> Result<(), SizeMismatchError>
+where
+    T: DistanceOps,
+{
+    SizeMismatchError::check("matrix", n_rows * dims, matrix.len())?;
+    SizeMismatchError::check("query", dims, query.len())?;
+    SizeMismatchError::check("scores", n_rows, scores.len())?;
+    T::batch_dot(n_rows, dims, matrix, query, scores);
+    Ok(())
+}
+
+#[inline]
+/// Fallible variant of [gram_matrix](crate::gram_matrix).
+///
+/// # Errors
+///
+/// Returns a [SizeMismatchError] if `matrix` is not exactly `rows * dims` elements in
+/// length or `result` is not exactly `rows * rows` elements in length.
+pub fn try_gram_matrix<T>(
+    rows: usize,
+    dims: usize,
+    matrix: &[T],
+    result: &mut [T],
+) -> Result<(), SizeMismatchError>
+where
+    T: DistanceOps,
+{
+    SizeMismatchError::check("matrix", rows * dims, matrix.len())?;
+    SizeMismatchError::check("result", rows * rows, result.len())?;
+    T::gram_matrix(rows, dims, matrix, result);
+    Ok(())
+}
+
+#[inline]
+/// Fallible variant of [pairwise_distances](crate::pairwise_distances).
+///
+/// # Errors
+///
+/// Returns a [SizeMismatchError] if `matrix` is not exactly `n * dims` elements in
+/// length or `result` is not exactly `n * n` elements in length.
+pub fn try_pairwise_distances<T>(
+    n: usize,
+    dims: usize,
+    matrix: &[T],
+    result: &mut [T],
+) -> Result<(), SizeMismatchError>
+where
+    T: DistanceOps,
+{
+    SizeMismatchError::check("matrix", n * dims, matrix.len())?;
+    SizeMismatchError::check("result", n * n, result.len())?;
+    T::pairwise_distances(n, dims, matrix, result);
+    Ok(())
+}
+
+#[inline]
+/// Fallible variant of [select_vertical](crate::select_vertical).
+///
+/// # Errors
+///
+/// Returns a [SizeMismatchError] if vectors `mask`, `lhs` and `rhs` are not the same
+/// length as `result`.
+pub fn try_select_vertical<T, B>(
+    mask: &[T],
+    lhs: &[T],
+    rhs: &[T],
+    result: &mut [B],
+) -> Result<(), SizeMismatchError>
+where
+    T: CmpOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    SizeMismatchError::check("mask", result.len(), mask.len())?;
+    SizeMismatchError::check("lhs", result.len(), lhs.len())?;
+    SizeMismatchError::check("rhs", result.len(), rhs.len())?;
+    crate::select_vertical(mask, lhs, rhs, result);
+    Ok(())
+}
+
+#[inline]
+/// Fallible variant of [outer_product](crate::outer_product).
+///
+/// # Errors
+///
+/// Returns a [SizeMismatchError] if `a` is not exactly `n` elements in length, `b` is
+/// not exactly `m` elements in length or `result` is not exactly `n * m` elements
+/// in length.
+pub fn try_outer_product<T>(
+    n: usize,
+    m: usize,
+    a: &[T],
+    b: &[T],
+    result: &mut [T],
+) -> Result<(), SizeMismatchError>
+where
+    T: ArithmeticOps,
+{
+    SizeMismatchError::check("a", n, a.len())?;
+    SizeMismatchError::check("b", m, b.len())?;
+    SizeMismatchError::check("result", n * m, result.len())?;
+    T::outer_product(n, m, a, b, result);
+    Ok(())
+}
+
+#[inline]
+/// Fallible variant of [l2_normalize_vertical](crate::l2_normalize_vertical).
+///
+/// # Errors
+///
+/// Returns a [SizeMismatchError] if vectors `a` and `result` are not exactly `dims`
+/// elements in length.
+pub fn try_l2_normalize_vertical<T>(
+    dims: usize,
+    a: &[T],
+    result: &mut [T],
+) -> Result<(), SizeMismatchError>
+where
+    T: FloatOps,
+{
+    SizeMismatchError::check("a", dims, a.len())?;
+    SizeMismatchError::check("result", dims, result.len())?;
+    T::l2_normalize_vertical(dims, a, result);
+    Ok(())
+}
+
+#[inline]
+/// Fallible variant of [cumulative_max](crate::cumulative_max).
+///
+/// # Errors
+///
+/// Returns a [SizeMismatchError] if vectors `a` and `result` are not equal in length.
+pub fn try_cumulative_max<T, B>(
+    a: &[T],
+    result: &mut [B],
+) -> Result<(), SizeMismatchError>
+where
+    T: CmpOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    SizeMismatchError::check("result", a.len(), result.raw_buffer_len())?;
+    T::cumulative_max(a.len(), a, result);
+    Ok(())
+}
+
+#[inline]
+/// Fallible variant of [cumulative_min](crate::cumulative_min).
+///
+/// # Errors
+///
+/// Returns a [SizeMismatchError] if vectors `a` and `result` are not equal in length.
+pub fn try_cumulative_min<T, B>(
+    a: &[T],
+    result: &mut [B],
+) -> Result<(), SizeMismatchError>
+where
+    T: CmpOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    SizeMismatchError::check("result", a.len(), result.raw_buffer_len())?;
+    T::cumulative_min(a.len(), a, result);
+    Ok(())
+}
+
+#[inline]
+/// Fallible variant of [moving_average](crate::moving_average).
+///
+/// # Errors
+///
+/// Returns a [SizeMismatchError] if `result` is not exactly `a.len() - window + 1`
+/// elements in length.
+///
+/// # Panics
+///
+/// If `window` is zero.
+pub fn try_moving_average<T, B>(
+    window: usize,
+    a: &[T],
+    result: &mut [B],
+) -> Result<(), SizeMismatchError>
+where
+    T: StatsOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    let num_windows = (a.len() + 1).saturating_sub(window);
+    SizeMismatchError::check("result", num_windows, result.raw_buffer_len())?;
+    T::moving_average(a.len(), window, a, result);
+    Ok(())
+}
+
+#[inline]
+/// Fallible variant of [gather](crate::gather).
+///
+/// # Errors
+///
+/// Returns an [IndexError] if `indices` and `result` are not equal in length or any of
+/// the `indices` are out of bounds of `a`.
+pub fn try_gather<T, B>(
+    a: &[T],
+    indices: &[u32],
+    result: &mut [B],
+) -> Result<(), IndexError>
+where
+    T: GatherOps,
+    for<'a> &'a mut [B]: WriteOnlyBuffer<Item = T>,
+{
+    SizeMismatchError::check("result", indices.len(), result.raw_buffer_len())?;
+    IndexOutOfBoundsError::check("a", indices, a.len())?;
+    T::gather(indices.len(), a, indices, result);
+    Ok(())
+}
+
+#[inline]
+/// Fallible variant of [scatter](crate::scatter).
+///
+/// # Errors
+///
+/// Returns an [IndexError] if `a` and `indices` are not equal in length or any of
+/// the `indices` are out of bounds of `result`.
+pub fn try_scatter<T>(
+    a: &[T],
+    indices: &[u32],
+    result: &mut [T],
+) -> Result<(), IndexError>
+where
+    T: GatherOps,
+{
+    SizeMismatchError::check("indices", a.len(), indices.len())?;
+    IndexOutOfBoundsError::check("result", indices, result.len())?;
+    T::scatter(a.len(), a, indices, result);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use super::*;
+
+    const LEN: usize = 9;
+
+    fn buf<T: Default + Clone>(len: usize) -> Vec<T> {
+        vec![T::default(); len]
+    }
+
+    /// Calls the routine with every operand at its expected length, then with each
+    /// operand one element too short and one element too long.
+    ///
+    /// The fallible variant must return an error naming an operand whose length is
+    /// wrong and the panicking variant must panic.
+    fn check_operands<const N: usize>(
+        operands: [&'static str; N],
+        expected: [usize; N],
+        try_fn: impl Fn([usize; N]) -> Result<(), SizeMismatchError>,
+        panic_fn: impl Fn([usize; N]),
+    ) {
+        assert_eq!(try_fn(expected), Ok(()));
+        panic_fn(expected);
+
+        for i in 0..N {
+            let short = expected[i].checked_sub(1);
+            let long = Some(expected[i] + 1);
+
+            for actual in [short, long].into_iter().flatten() {
+                let mut lens = expected;
+                lens[i] = actual;
+
+                let error = try_fn(lens).expect_err("routine should reject lengths");
+                let position = operands
+                    .iter()
+                    .position(|name| *name == error.operand)
+                    .unwrap_or_else(|| panic!("unknown operand {error:?}"));
+                assert_eq!(
+                    error.actual, lens[position],
+                    "wrong actual length {error:?}"
+                );
+                assert_ne!(
+                    error.expected, error.actual,
+                    "lengths should differ {error:?}"
+                );
+
+                let result = catch_unwind(AssertUnwindSafe(|| panic_fn(lens)));
+                assert!(
+                    result.is_err(),
+                    "routine should panic with lengths {lens:?}"
+                );
+            }
+        }
+    }
+
+    macro_rules! test_pair_op {
+        ($t:ty, $name:ident) => {
+            paste::paste! {
+                #[test]
+                fn [<test_try_ $name>]() {
+                    check_operands(
+                        ["a", "b"],
+                        [LEN, LEN],
+                        |[a, b]| [<try_ $name>](&buf::<$t>(a), &buf::<$t>(b)).map(|_| ()),
+                        |[a, b]| {
+                            crate::$name(&buf::<$t>(a), &buf::<$t>(b));
+                        },
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! test_vertical_op {
+        ($t:ty, $name:ident) => {
+            paste::paste! {
+                #[test]
+                fn [<test_try_ $name>]() {
+                    check_operands(
+                        ["lhs", "rhs", "result"],
+                        [LEN, LEN, LEN],
+                        |[l, r, o]| {
+                            [<try_ $name>](&buf::<$t>(l), &buf::<$t>(r), &mut buf::<$t>(o))
+                        },
+                        |[l, r, o]| crate::$name(&buf::<$t>(l), &buf::<$t>(r), &mut buf::<$t>(o)),
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! test_unary_vertical_op {
+        ($t:ty, $name:ident) => {
+            paste::paste! {
+                #[test]
+                fn [<test_try_ $name>]() {
+                    check_operands(
+                        ["a", "result"],
+                        [LEN, LEN],
+                        |[a, o]| [<try_ $name>](&buf::<$t>(a), &mut buf::<$t>(o)),
+                        |[a, o]| crate::$name(&buf::<$t>(a), &mut buf::<$t>(o)),
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! test_inplace_op {
+        ($t:ty, $name:ident) => {
+            paste::paste! {
+                #[test]
+                fn [<test_try_ $name>]() {
+                    check_operands(
+                        ["a", "b"],
+                        [LEN, LEN],
+                        |[a, b]| [<try_ $name>](&mut buf::<$t>(a), &buf::<$t>(b)),
+                        |[a, b]| crate::$name(&mut buf::<$t>(a), &buf::<$t>(b)),
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! test_batch_op {
+        ($t:ty, $name:ident) => {
+            paste::paste! {
+                #[test]
+                fn [<test_try_ $name>]() {
+                    check_operands(
+                        ["query", "batch", "result"],
+                        [LEN, LEN * 3, 3],
+                        |[q, b, o]| {
+                            [<try_ $name>](&buf::<$t>(q), &buf::<$t>(b), &mut buf::<$t>(o))
+                        },
+                        |[q, b, o]| crate::$name(&buf::<$t>(q), &buf::<$t>(b), &mut buf::<$t>(o)),
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! test_matrix_op {
+        ($t:ty, $name:ident) => {
+            paste::paste! {
+                #[test]
+                fn [<test_try_ $name>]() {
+                    check_operands(
+                        ["a", "b", "result"],
+                        [LEN * 2, LEN * 3, 6],
+                        |[a, b, o]| {
+                            [<try_ $name>](
+                                LEN,
+                                2,
+                                3,
+                                &buf::<$t>(a),
+                                &buf::<$t>(b),
+                                &mut buf::<$t>(o),
+                            )
+                        },
+                        |[a, b, o]| {
+                            crate::$name(
+                                LEN,
+                                2,
+                                3,
+                                &buf::<$t>(a),
+                                &buf::<$t>(b),
+                                &mut buf::<$t>(o),
+                            )
+                        },
+                    );
+                }
+            }
+        };
+    }
+
+    test_pair_op!(f32, cosine);
+    test_pair_op!(f32, dot);
+    test_pair_op!(f32, squared_euclidean);
+    test_pair_op!(f32, dot_f64acc);
+    test_pair_op!(f32, squared_euclidean_f64acc);
+    test_pair_op!(f32, dot_compensated);
+    test_pair_op!(f32, dot_ordered);
+    test_pair_op!(i16, dot_wide_acc);
+    test_pair_op!(i16, squared_euclidean_wide_acc);
+    test_pair_op!(u8, cosine_wide_acc);
+    test_pair_op!(u64, binary_hamming);
+    test_pair_op!(u64, binary_jaccard);
+    test_pair_op!(i64, checked_squared_euclidean);
+
+    test_batch_op!(f32, cosine_batch);
+    test_batch_op!(f32, dot_batch);
+    test_batch_op!(f32, squared_euclidean_batch);
+
+    test_matrix_op!(f32, dot_matrix);
+    test_matrix_op!(f32, squared_euclidean_matrix);
+
+    test_vertical_op!(f32, max_vertical);
+    test_vertical_op!(f32, min_vertical);
+    test_vertical_op!(f32, eq_vertical);
+    test_vertical_op!(f32, neq_vertical);
+    test_vertical_op!(f32, lt_vertical);
+    test_vertical_op!(f32, lte_vertical);
+    test_vertical_op!(f32, gt_vertical);
+    test_vertical_op!(f32, gte_vertical);
+    test_vertical_op!(f32, add_vertical);
+    test_vertical_op!(f32, add_streaming);
+    test_vertical_op!(f32, sub_vertical);
+    test_vertical_op!(f32, mul_vertical);
+    test_vertical_op!(f32, div_vertical);
+    test_vertical_op!(i32, saturating_add_vertical);
+    test_vertical_op!(i32, saturating_sub_vertical);
+    test_unary_vertical_op!(f32, neg_vertical);
+    test_vertical_op!(i32, bitand_vertical);
+    test_vertical_op!(i32, bitor_vertical);
+    test_vertical_op!(i32, bitxor_vertical);
+    test_vertical_op!(i32, shl_vertical);
+    test_vertical_op!(i32, shr_vertical);
+    test_vertical_op!(f32, hypot_vertical);
+
+    test_unary_vertical_op!(i32, bitnot_vertical);
+    test_unary_vertical_op!(f32, is_nan_vector);
+    test_unary_vertical_op!(f32, is_finite_vector);
+    test_unary_vertical_op!(f32, is_infinite_vector);
+
+    test_inplace_op!(f32, add_inplace);
+    test_inplace_op!(f32, sub_inplace);
+    test_inplace_op!(f32, mul_inplace);
+    test_inplace_op!(f32, div_inplace);
+
+    #[test]
+    fn test_try_weighted_dot() {
+        check_operands(
+            ["a", "b", "w"],
+            [LEN, LEN, LEN],
+            |[a, b, w]| {
+                try_weighted_dot(LEN, &buf::<f32>(a), &buf(b), &buf(w)).map(|_| ())
+            },
+            |[a, b, w]| {
+                crate::weighted_dot(LEN, &buf::<f32>(a), &buf(b), &buf(w));
+            },
+        );
+    }
+
+    #[test]
+    fn test_try_gemv() {
+        check_operands(
+            ["matrix", "x", "out"],
+            [LEN * 3, LEN, 3],
+            |[m, x, o]| try_gemv(3, LEN, &buf::<f32>(m), &buf(x), &mut buf(o)),
+            |[m, x, o]| crate::gemv(3, LEN, &buf::<f32>(m), &buf(x), &mut buf(o)),
+        );
+    }
+
+    #[test]
+    fn test_try_batch_dot() {
+        check_operands(
+            ["matrix", "query", "scores"],
+            [LEN * 3, LEN, 3],
+            |[m, q, o]| try_batch_dot(3, LEN, &buf::<f32>(m), &buf(q), &mut buf(o)),
+            |[m, q, o]| crate::batch_dot(3, LEN, &buf::<f32>(m), &buf(q), &mut buf(o)),
+        );
+    }
+
+    #[test]
+    fn test_try_gram_matrix() {
+        check_operands(
+            ["matrix", "result"],
+            [LEN * 3, 9],
+            |[m, o]| try_gram_matrix(3, LEN, &buf::<f32>(m), &mut buf(o)),
+            |[m, o]| crate::gram_matrix(3, LEN, &buf::<f32>(m), &mut buf(o)),
+        );
+    }
+
+    #[test]
+    fn test_try_pairwise_distances() {
+        check_operands(
+            ["matrix", "result"],
+            [LEN * 3, 9],
+            |[m, o]| try_pairwise_distances(3, LEN, &buf::<f32>(m), &mut buf(o)),
+            |[m, o]| crate::pairwise_distances(3, LEN, &buf::<f32>(m), &mut buf(o)),
+        );
+    }
+
+    #[test]
+    fn test_try_select_vertical() {
+        check_operands(
+            ["mask", "lhs", "rhs", "result"],
+            [LEN, LEN, LEN, LEN],
+            |[k, l, r, o]| {
+                try_select_vertical(&buf::<f32>(k), &buf(l), &buf(r), &mut buf::<f32>(o))
+            },
+            |[k, l, r, o]| {
+                crate::select_vertical(
+                    &buf::<f32>(k),
+                    &buf(l),
+                    &buf(r),
+                    &mut buf::<f32>(o),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn test_try_outer_product() {
+        check_operands(
+            ["a", "b", "result"],
+            [LEN, 3, LEN * 3],
+            |[a, b, o]| try_outer_product(LEN, 3, &buf::<f32>(a), &buf(b), &mut buf(o)),
+            |[a, b, o]| {
+                crate::outer_product(LEN, 3, &buf::<f32>(a), &buf(b), &mut buf(o))
+            },
+        );
+    }
+
+    #[test]
+    fn test_try_l2_normalize_vertical() {
+        check_operands(
+            ["a", "result"],
+            [LEN, LEN],
+            |[a, o]| try_l2_normalize_vertical(LEN, &buf::<f32>(a), &mut buf(o)),
+            |[a, o]| crate::l2_normalize_vertical(LEN, &buf::<f32>(a), &mut buf(o)),
+        );
+    }
+
+    #[test]
+    fn test_try_cumulative() {
+        let a = [2, 1, 3, 0];
+        let mut result = [0i32; 4];
+        assert_eq!(try_cumulative_max(&a, &mut result), Ok(()));
+        assert_eq!(result, [2, 2, 3, 3]);
+        assert_eq!(try_cumulative_min(&a, &mut result), Ok(()));
+        assert_eq!(result, [2, 1, 1, 0]);
+
+        let error = SizeMismatchError {
+            operand: "result",
+            expected: 4,
+            actual: 3,
+        };
+        assert_eq!(try_cumulative_max(&a, &mut result[..3]), Err(error));
+        assert_eq!(try_cumulative_min(&a, &mut result[..3]), Err(error));
+    }
+
+    #[test]
+    fn test_try_moving_average() {
+        let a = [1, 2, 3, 4, 5, 6i32];
+        let mut result = [0i32; 3];
+        assert_eq!(try_moving_average(4, &a, &mut result), Ok(()));
+        assert_eq!(result, [2, 3, 4]);
+
+        assert_eq!(
+            try_moving_average(3, &a, &mut result),
+            Err(SizeMismatchError {
+                operand: "result",
+                expected: 4,
+                actual: 3,
+            }),
+        );
+        // Like `slice::windows`, a window larger than `a` has no results.
+        assert_eq!(try_moving_average(7, &a, &mut result[..0]), Ok(()));
+        assert!(catch_unwind(AssertUnwindSafe(|| {
+            crate::moving_average(0, &a, &mut result)
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn test_try_gather() {
+        let a = [1.0f32, 2.0, 3.0];
+        let mut result = [0.0f32; 3];
+        assert_eq!(try_gather(&a, &[2, 0, 2], &mut result), Ok(()));
+        assert_eq!(result, [3.0, 1.0, 3.0]);
+
+        assert_eq!(
+            try_gather(&a, &[2, 0], &mut result),
+            Err(IndexError::SizeMismatch(SizeMismatchError {
+                operand: "result",
+                expected: 2,
+                actual: 3,
+            })),
+        );
+        assert_eq!(
+            try_gather(&a, &[2, 3, 4], &mut result),
+            Err(IndexError::OutOfBounds(IndexOutOfBoundsError {
+                operand: "a",
+                index: 3,
+                len: 3,
+            })),
+        );
+        assert!(catch_unwind(AssertUnwindSafe(|| {
+            crate::gather(&a, &[0, u32::MAX, 0], &mut result)
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn test_try_scatter() {
+        let a = [1.0f32, 2.0, 3.0];
+        let mut result = [0.0f32; 2];
+        assert_eq!(try_scatter(&a, &[1, 0, 1], &mut result), Ok(()));
+        assert_eq!(result, [2.0, 3.0]);
+
+        assert_eq!(
+            try_scatter(&a, &[1, 0], &mut result),
+            Err(IndexError::SizeMismatch(SizeMismatchError {
+                operand: "indices",
+                expected: 3,
+                actual: 2,
+            })),
+        );
+        assert_eq!(
+            try_scatter(&a, &[1, 0, 2], &mut result),
+            Err(IndexError::OutOfBounds(IndexOutOfBoundsError {
+                operand: "result",
+                index: 2,
+                len: 2,
+            })),
+        );
+        assert!(catch_unwind(AssertUnwindSafe(|| {
+            crate::scatter(&a, &[0, 5, 0], &mut result)
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn test_size_mismatch_error_display() {
+        let error =
+            try_add_vertical(&[1.0f32; 3], &[1.0; 2], &mut [0.0f32; 3]).unwrap_err();
+        assert_eq!(
+            error,
+            SizeMismatchError {
+                operand: "rhs",
+                expected: 3,
+                actual: 2,
+            },
+        );
+        assert_eq!(
+            error.to_string(),
+            "Buffer `rhs` must be exactly 3 elements in length but was 2",
+        );
+    }
+}
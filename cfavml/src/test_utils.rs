@@ -82,3 +82,21 @@ where
 
     dist
 }
+
+#[cfg(feature = "half")]
+/// Returns the `f32` sample vectors converted to `f16` up front.
+pub fn get_sample_half_vectors(size: usize) -> (Vec<half::f16>, Vec<half::f16>) {
+    let (x, y) = get_sample_vectors::<f32>(size);
+    let x = x.into_iter().map(half::f16::from_f32).collect();
+    let y = y.into_iter().map(half::f16::from_f32).collect();
+    (x, y)
+}
+
+#[cfg(feature = "half")]
+/// Returns the `f32` sample vectors converted to `bf16` up front.
+pub fn get_sample_bf16_vectors(size: usize) -> (Vec<half::bf16>, Vec<half::bf16>) {
+    let (x, y) = get_sample_vectors::<f32>(size);
+    let x = x.into_iter().map(half::bf16::from_f32).collect();
+    let y = y.into_iter().map(half::bf16::from_f32).collect();
+    (x, y)
+}
@@ -0,0 +1,248 @@
+//! Top-k selection over a vector of scores.
+//!
+//! This is typically the step after computing a large batch of distances, where only the
+//! best `k` candidates are of interest and fully sorting the score vector would be wasteful.
+//!
+//! The selection works over fixed-size blocks, each block is checked via the SIMD
+//! [CmpOps::any_gt_value] routine, if no element of the block can beat the current worst
+//! candidate the whole block is skipped without inspecting the individual elements.
+//! Surviving blocks are then scanned and fed into a bounded heap of size `k`.
+//!
+//! The horizontal [CmpOps::max] is deliberately not used for pruning, the SIMD max
+//! does not propagate `NaN` consistently, so a `NaN` in one lane can hide a larger value
+//! in another, whereas `NaN` lanes always compare as `false` in [CmpOps::any_gt_value].
+
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::safe_trait_cmp_ops::CmpOps;
+
+/// The number of elements in each block that is pruned as a whole.
+const BLOCK_SIZE: usize = 1024;
+
+#[inline]
+/// Selects the `k` largest values from `scores` returning their `(index, value)` pairs.
+///
+/// ### Ordering
+///
+/// The returned pairs are sorted by value in descending order, values that compare
+/// equal are ordered by their index in ascending order, this means that the selection
+/// is deterministic when `scores` contains duplicate values, the earliest occurrences
+/// are always preferred.
+///
+/// If `k` is greater than or equal to the length of `scores`, every score is returned
+/// sorted with the above rules.
+///
+/// ### NaN Handling
+///
+/// `NaN` scores are never selected, they are skipped entirely, which means the returned
+/// vector may contain fewer than `k` elements if `scores` contains `NaN` values.
+///
+/// ### Examples
+///
+/// ```rust
+/// let scores = [0.2, 0.9, 0.1, 0.9, f32::NAN, 0.5];
+///
+/// let top = cfavml::top_k(3, &scores);
+/// assert_eq!(top, [(1, 0.9), (3, 0.9), (5, 0.5)]);
+/// ```
+///
+/// ### Implementation Pseudocode
+///
+/// _This is the logic of the routine being called._
+///
+/// ```ignore
+/// heap = BoundedMinHeap(k)
+///
+/// for block in chunks(scores, BLOCK_SIZE):
+///     if heap.is_full() and not any(block[i] > heap.min()):
+///         continue
+///
+///     for i in range(len(block)):
+///         if block[i] is not NaN:
+///             heap.push_if_better((i, block[i]))
+///
+/// return sorted_descending(heap)
+/// ```
+pub fn top_k<T>(k: usize, scores: &[T]) -> Vec<(usize, T)>
+where
+    T: CmpOps + PartialOrd,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut heap = BinaryHeap::with_capacity(k.min(scores.len()) + 1);
+
+    for (block_idx, block) in scores.chunks(BLOCK_SIZE).enumerate() {
+        let offset = block_idx * BLOCK_SIZE;
+
+        if heap.len() >= k {
+            let worst: &Candidate<T> = heap.peek().unwrap();
+
+            // Any element equal to the current worst candidate loses the tie-break
+            // as it has a larger index, so only strictly greater elements are of interest.
+            // `NaN` elements compare as `false` so can never prevent a block being skipped.
+            if !T::any_gt_value(block, worst.value) {
+                continue;
+            }
+        }
+
+        for (i, value) in block.iter().copied().enumerate() {
+            // Skip NaN values, they can never be selected.
+            #[allow(clippy::eq_op)]
+            if value != value {
+                continue;
+            }
+
+            let candidate = Candidate {
+                index: offset + i,
+                value,
+            };
+
+            if heap.len() < k {
+                heap.push(candidate);
+            } else if candidate < *heap.peek().unwrap() {
+                heap.pop();
+                heap.push(candidate);
+            }
+        }
+    }
+
+    let sorted = heap.into_sorted_vec();
+    sorted.into_iter().map(|c| (c.index, c.value)).collect()
+}
+
+#[derive(Debug, Copy, Clone)]
+/// A single selected score.
+///
+/// The ordering is _inverted_ so that the [BinaryHeap] keeps the worst candidate at
+/// the top, a candidate is considered worse if it has a smaller value, or an equal
+/// value with a larger index.
+///
+/// `NaN` values must never be stored in a candidate.
+struct Candidate<T> {
+    index: usize,
+    value: T,
+}
+
+impl<T: PartialOrd> PartialEq for Candidate<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd> Eq for Candidate<T> {}
+
+impl<T: PartialOrd> PartialOrd for Candidate<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> Ord for Candidate<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .value
+            .partial_cmp(&self.value)
+            .expect("NaN values should never be selected")
+            .then_with(|| self.index.cmp(&other.index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_top_k<T: Copy + PartialOrd>(k: usize, scores: &[T]) -> Vec<(usize, T)> {
+        #[allow(clippy::eq_op)]
+        let mut pairs = scores
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|(_, v)| v == v)
+            .collect::<Vec<_>>();
+        pairs.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(a.0.cmp(&b.0)));
+        pairs.truncate(k);
+        pairs
+    }
+
+    macro_rules! define_top_k_tests {
+        ($t:ident) => {
+            paste::paste! {
+                #[test]
+                fn [< test_top_k_ $t >]() {
+                    let (scores, _) = crate::test_utils::get_sample_vectors::<$t>(5_317);
+
+                    for k in [0, 1, 10, 1_000, 5_317, 10_000] {
+                        let expected = naive_top_k(k, &scores);
+                        let result = top_k(k, &scores);
+                        assert_eq!(result, expected, "Top k mismatch with k={k}");
+                    }
+                }
+
+                #[test]
+                fn [< test_top_k_duplicates_ $t >]() {
+                    let mut scores = vec![1 as $t; 3_001];
+                    scores[2_500] = 2 as $t;
+
+                    let result = top_k(4, &scores);
+                    assert_eq!(
+                        result,
+                        [(2_500, 2 as $t), (0, 1 as $t), (1, 1 as $t), (2, 1 as $t)],
+                    );
+                }
+            }
+        };
+    }
+
+    define_top_k_tests!(f32);
+    define_top_k_tests!(f64);
+    define_top_k_tests!(i8);
+    define_top_k_tests!(i16);
+    define_top_k_tests!(i32);
+    define_top_k_tests!(i64);
+    define_top_k_tests!(u8);
+    define_top_k_tests!(u16);
+    define_top_k_tests!(u32);
+    define_top_k_tests!(u64);
+
+    #[test]
+    fn test_top_k_nan_skipped() {
+        let mut scores = vec![0.5f32; 2_050];
+        scores[3] = f32::NAN;
+        scores[1_030] = f32::NAN;
+        scores[2_049] = 1.0;
+
+        let result = top_k(2, &scores);
+        assert_eq!(result, [(2_049, 1.0), (0, 0.5)]);
+
+        let result = top_k(10_000, &scores);
+        assert_eq!(result.len(), 2_048);
+        assert!(result.iter().all(|(_, v)| !v.is_nan()));
+
+        let result = top_k(3, &[f32::NAN; 4]);
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_top_k_nan_beside_larger_value() {
+        // The `NaN` values sit in the same lanes as the larger value within each
+        // register, which can hide it from a horizontal SIMD max of the block.
+        let mut scores = vec![0.1f32; 2_048];
+        scores[0] = 0.5;
+        scores[1_024..].fill(0.2);
+        scores[1_027] = 0.9;
+        for i in (1_024 + 8 + 3..2_048).step_by(8) {
+            scores[i] = f32::NAN;
+        }
+
+        let result = top_k(1, &scores);
+        assert_eq!(result, [(1_027, 0.9)]);
+
+        let expected = naive_top_k(10, &scores);
+        let result = top_k(10, &scores);
+        assert_eq!(result, expected);
+    }
+}
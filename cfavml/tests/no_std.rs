@@ -0,0 +1,58 @@
+//! Exercises the safe API from a `no_std` crate using only caller provided buffers.
+//!
+//! Run with `cargo test -p cfavml --no-default-features --test no_std` to check the
+//! routines without the `std` or `alloc` features, where the implementation is selected
+//! at compile time from the enabled target features.
+#![no_std]
+
+const DIMS: usize = 67;
+
+fn sample_vectors() -> ([f32; DIMS], [f32; DIMS]) {
+    let mut a = [0.0; DIMS];
+    let mut b = [0.0; DIMS];
+    for i in 0..DIMS {
+        a[i] = i as f32 * 0.5;
+        b[i] = (DIMS - i) as f32 * 0.25;
+    }
+    (a, b)
+}
+
+#[test]
+fn test_no_std_dot() {
+    let (a, b) = sample_vectors();
+
+    let expected = a.iter().zip(b.iter()).fold(0.0, |acc, (a, b)| acc + a * b);
+    assert_eq!(cfavml::dot(&a, &b), expected);
+
+    let a = [3i32; DIMS];
+    let b = [-2i32; DIMS];
+    assert_eq!(cfavml::dot(&a, &b), -6 * DIMS as i32);
+}
+
+#[test]
+fn test_no_std_add() {
+    let (a, b) = sample_vectors();
+
+    let mut result = [0.0f32; DIMS];
+    cfavml::add_vertical(&a, &b, &mut result);
+    for i in 0..DIMS {
+        assert_eq!(result[i], a[i] + b[i]);
+    }
+
+    // Adding a broadcast value.
+    cfavml::add_vertical(&a, 1.0, &mut result);
+    for i in 0..DIMS {
+        assert_eq!(result[i], a[i] + 1.0);
+    }
+}
+
+#[test]
+fn test_no_std_sum() {
+    let (a, _) = sample_vectors();
+
+    let expected = a.iter().sum::<f32>();
+    assert_eq!(cfavml::sum(&a), expected);
+
+    let values = [7u8; DIMS];
+    assert_eq!(cfavml::sum(&values), (7 * DIMS) as u8);
+}